@@ -0,0 +1,297 @@
+// Host glue shared by the niet2code PolkaVM contracts.
+//
+// - `ContractHost`: `verifier_core::host::Host` over the pallet-revive host functions
+//   (caller, storage, events, BN254 precompiles), plus the block timestamp, keccak256
+//   and calls into other contracts.
+// - `call_data` / `return_value`: the entry points' I/O.
+// - A static bump allocator and a trapping panic handler for the `no_std` blob.
+//
+// With the `native-host` feature the uapi host is replaced by the in-process mock in
+// `native_host`, and the crate builds with std so contracts run under `cargo test`.
+
+#![cfg_attr(not(feature = "native-host"), no_std)]
+#![cfg_attr(not(feature = "native-host"), feature(alloc_error_handler))]
+
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::{
+    alloc::{GlobalAlloc, Layout},
+    cell::UnsafeCell,
+    ptr::null_mut,
+};
+
+#[cfg(not(any(feature = "polkavm", feature = "native-host")))]
+compile_error!("enable `polkavm` (the PolkaVM host) or `native-host` (the in-process mock)");
+
+#[cfg(not(feature = "native-host"))]
+use uapi::{CallFlags, HostFn, HostFnImpl as api, ReturnErrorCode, ReturnFlags, StorageFlags};
+
+#[cfg(feature = "native-host")]
+pub mod native_host;
+#[cfg(feature = "native-host")]
+use native_host::{CallFlags, HostFnImpl as api, ReturnErrorCode, ReturnFlags, StorageFlags};
+
+use verifier_core::host::{Address, Host};
+#[cfg(feature = "precompiles")]
+use verifier_core::precompile::{Bn254Precompile, PrecompileError, G1_LEN};
+use verifier_core::registry::STORAGE_CHUNK_LEN;
+
+//--------------------------------------------------------------------------
+// 1.  Static bump allocator (512 KiB)
+//--------------------------------------------------------------------------
+
+#[cfg(not(feature = "native-host"))]
+const HEAP_SIZE: usize = 512 * 1024;
+
+/// Never frees; returns null (→ trap) once `N` bytes are used
+#[cfg_attr(feature = "native-host", allow(dead_code))]
+pub struct Bump<const N: usize> {
+    heap: UnsafeCell<[u8; N]>,
+    offset: UnsafeCell<usize>,
+}
+
+// PolkaVM contracts are single-threaded; native tests use their own instances.
+unsafe impl<const N: usize> Sync for Bump<N> {}
+
+#[cfg_attr(feature = "native-host", allow(dead_code))]
+impl<const N: usize> Bump<N> {
+    pub const fn new() -> Self {
+        Self {
+            heap: UnsafeCell::new([0; N]),
+            offset: UnsafeCell::new(0),
+        }
+    }
+}
+
+impl<const N: usize> Default for Bump<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl<const N: usize> GlobalAlloc for Bump<N> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let base = self.heap.get() as usize;
+        let offset = &mut *self.offset.get();
+
+        // Align the address, not just the offset: the heap itself is only byte-aligned
+        let start = match (base + *offset).checked_next_multiple_of(layout.align()) {
+            Some(addr) => addr - base,
+            None => return null_mut(),
+        };
+
+        match start.checked_add(layout.size()) {
+            Some(end) if end <= N => {
+                *offset = end;
+                (base + start) as *mut u8
+            }
+            _ => null_mut(), // triggers trap → ContractTrapped
+        }
+    }
+
+    unsafe fn dealloc(&self, _ptr: *mut u8, _layout: Layout) {
+        /* no-op */
+    }
+}
+
+#[cfg(not(feature = "native-host"))]
+#[global_allocator]
+static ALLOC: Bump<HEAP_SIZE> = Bump::new();
+
+#[cfg(not(feature = "native-host"))]
+#[alloc_error_handler]
+fn oom(_: Layout) -> ! {
+    unsafe {
+        core::arch::asm!("unimp");
+        core::hint::unreachable_unchecked()
+    }
+}
+
+//--------------------------------------------------------------------------
+// 2.  Panic → PolkaVM trap
+//--------------------------------------------------------------------------
+
+#[cfg(not(feature = "native-host"))]
+#[panic_handler]
+fn panic(_: &core::panic::PanicInfo) -> ! {
+    unsafe {
+        core::arch::asm!("unimp");
+        core::hint::unreachable_unchecked()
+    }
+}
+
+//--------------------------------------------------------------------------
+// 3.  Entry point I/O
+//--------------------------------------------------------------------------
+
+/// The input of the current `deploy()` or `call()`
+pub fn call_data() -> Vec<u8> {
+    let mut data = vec![0u8; api::call_data_size() as usize];
+    api::call_data_copy(&mut data, 0);
+    data
+}
+
+/// End the entry point with `data`, rolling back its storage writes if `revert`
+pub fn return_value(revert: bool, data: &[u8]) -> ! {
+    let flags = if revert { ReturnFlags::REVERT } else { ReturnFlags::empty() };
+    api::return_value(flags, data)
+}
+
+//--------------------------------------------------------------------------
+// 4.  Host adapter
+//--------------------------------------------------------------------------
+
+/// Largest return data `call_contract` keeps
+pub const CALL_OUTPUT_LEN: usize = 1024;
+
+/// Why a call into another contract produced no result
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallError {
+    /// The callee reverted with this data
+    Reverted(Vec<u8>),
+    /// The callee trapped, ran out of resources or does not exist
+    Failed,
+}
+
+pub struct ContractHost;
+
+impl ContractHost {
+    /// Block timestamp, as `now` reports it
+    pub fn now(&self) -> u64 {
+        let mut buf = [0u8; 32];
+        api::now(&mut buf);
+        // A little-endian U256; timestamps fit the low 8 bytes
+        u64::from_le_bytes(buf[..8].try_into().expect("8-byte slice"))
+    }
+
+    pub fn keccak_256(&self, input: &[u8]) -> [u8; 32] {
+        let mut output = [0u8; 32];
+        api::hash_keccak_256(input, &mut output);
+        output
+    }
+
+    /// Call `callee` with `input` and no value, forwarding all remaining resources
+    pub fn call_contract(&mut self, callee: &Address, input: &[u8]) -> Result<Vec<u8>, CallError> {
+        let mut buf = [0u8; CALL_OUTPUT_LEN];
+        let mut output = &mut buf[..];
+        let result = api::call(
+            CallFlags::empty(),
+            callee,
+            u64::MAX,       // ref_time: whatever is left
+            u64::MAX,       // proof_size: whatever is left
+            &[u8::MAX; 32], // no storage deposit limit
+            &[0u8; 32],     // no value
+            input,
+            Some(&mut output),
+        );
+        match result {
+            Ok(()) => Ok(output.to_vec()),
+            Err(ReturnErrorCode::CalleeReverted) => Err(CallError::Reverted(output.to_vec())),
+            Err(_) => Err(CallError::Failed),
+        }
+    }
+}
+
+impl Host for ContractHost {
+    fn caller(&self) -> Address {
+        let mut caller = [0u8; 20];
+        api::caller(&mut caller);
+        caller
+    }
+
+    fn get_storage(&self, key: &[u8; 32]) -> Option<Vec<u8>> {
+        let mut buf = [0u8; STORAGE_CHUNK_LEN];
+        let mut output = &mut buf[..];
+        api::get_storage(StorageFlags::empty(), key, &mut output).ok()?;
+        Some(output.to_vec())
+    }
+
+    fn set_storage(&mut self, key: &[u8; 32], value: &[u8]) {
+        api::set_storage(StorageFlags::empty(), key, value);
+    }
+
+    fn deposit_event(&mut self, topics: &[[u8; 32]], data: &[u8]) {
+        api::deposit_event(topics, data);
+    }
+
+    #[cfg(feature = "precompiles")]
+    fn call_precompile(&mut self, precompile: Bn254Precompile, input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+        // The largest output is a G1 point (`ecAdd` / `ecMul`)
+        let mut buf = [0u8; G1_LEN];
+        let mut output = &mut buf[..];
+        api::call(
+            CallFlags::empty(),
+            &precompile.address(),
+            u64::MAX,       // ref_time: whatever is left
+            u64::MAX,       // proof_size: whatever is left
+            &[u8::MAX; 32], // no storage deposit limit
+            &[0u8; 32],     // no value
+            input,
+            Some(&mut output),
+        )
+        .map_err(|_| PrecompileError::Failed)?;
+        Ok(output.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bump_allocator_runs_out_of_memory() {
+        let bump = Bump::<64>::new();
+        unsafe {
+            let first = bump.alloc(Layout::from_size_align(40, 8).unwrap());
+            assert!(!first.is_null());
+            assert_eq!(first as usize % 8, 0);
+
+            assert!(bump.alloc(Layout::from_size_align(32, 1).unwrap()).is_null());
+
+            let rest = bump.alloc(Layout::from_size_align(8, 1).unwrap());
+            assert!(!rest.is_null());
+            assert!(rest as usize >= first as usize + 40);
+        }
+    }
+
+    #[test]
+    fn test_bump_allocator_aligns_addresses() {
+        let bump = Bump::<256>::new();
+        unsafe {
+            bump.alloc(Layout::from_size_align(1, 1).unwrap());
+            let aligned = bump.alloc(Layout::from_size_align(16, 32).unwrap());
+            assert!(!aligned.is_null());
+            assert_eq!(aligned as usize % 32, 0);
+        }
+    }
+
+    #[test]
+    fn test_call_contract_reports_reverts() {
+        native_host::reset(&[]);
+        let callee = [0x42; 20];
+        native_host::set_contract(callee, |input| native_host::ContractReturn {
+            flags: if input.is_empty() { ReturnFlags::REVERT } else { ReturnFlags::empty() },
+            data: input.to_vec(),
+        });
+
+        assert_eq!(ContractHost.call_contract(&callee, b"echo"), Ok(b"echo".to_vec()));
+        assert_eq!(ContractHost.call_contract(&callee, b""), Err(CallError::Reverted(Vec::new())));
+        assert_eq!(native_host::contract_calls(), vec![(callee, b"echo".to_vec()), (callee, Vec::new())]);
+    }
+
+    #[test]
+    fn test_now_and_keccak() {
+        native_host::reset(&[]);
+        native_host::set_timestamp(1_700_000_000);
+        assert_eq!(ContractHost.now(), 1_700_000_000);
+        assert_eq!(
+            ContractHost.keccak_256(b""),
+            [
+                0xc5, 0xd2, 0x46, 0x01, 0x86, 0xf7, 0x23, 0x3c, 0x92, 0x7e, 0x7d, 0xb2, 0xdc, 0xc7, 0x03, 0xc0, 0xe5, 0x00, 0xb6,
+                0x53, 0xca, 0x82, 0x27, 0x3b, 0x7b, 0xfa, 0xd8, 0x04, 0x5d, 0x85, 0xa4, 0x70
+            ]
+        );
+    }
+}
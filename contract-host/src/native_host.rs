@@ -0,0 +1,331 @@
+// In-process stand-in for `pallet-revive-uapi`, enabled by the `native-host` feature.
+// Mirrors the subset of `HostFn` the contracts use, with the same names and
+// signatures, so `deploy()` and `call()` compile unchanged and run under
+// `cargo test` on the build machine. State is per thread, so tests stay isolated.
+// Other contracts are stood in for by closures registered with `set_contract`.
+//
+// `return_value` diverges like the real host function: it unwinds with a
+// `ContractReturn` payload that `run` catches and hands back to the test.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use sha3::{Digest, Keccak256};
+use verifier_core::precompile::{self, Bn254Precompile};
+
+/// Flags passed to `return_value` (bit 0 = revert, as in uapi)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReturnFlags(u32);
+
+impl ReturnFlags {
+    pub const REVERT: ReturnFlags = ReturnFlags(1);
+
+    pub fn empty() -> Self {
+        ReturnFlags(0)
+    }
+
+    pub fn contains(&self, other: ReturnFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// Storage flags (only the default, persistent storage is modelled)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StorageFlags(u32);
+
+impl StorageFlags {
+    pub fn empty() -> Self {
+        StorageFlags(0)
+    }
+}
+
+/// Flags passed to `call` (none are modelled)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CallFlags(u32);
+
+impl CallFlags {
+    pub fn empty() -> Self {
+        CallFlags(0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReturnErrorCode {
+    KeyNotFound,
+    CalleeTrapped,
+    CalleeReverted,
+}
+
+/// What the contract handed to `return_value`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractReturn {
+    pub flags: ReturnFlags,
+    pub data: Vec<u8>,
+}
+
+impl ContractReturn {
+    pub fn reverted(&self) -> bool {
+        self.flags.contains(ReturnFlags::REVERT)
+    }
+}
+
+/// A contract reachable through `call`: input in, what it returned out
+type MockContract = Rc<dyn Fn(&[u8]) -> ContractReturn>;
+
+struct HostState {
+    caller: [u8; 20],
+    call_data: Vec<u8>,
+    timestamp: u64,
+    storage: HashMap<Vec<u8>, Vec<u8>>,
+    events: Vec<Event>,
+    /// Whether 0x06-0x08 answer like the BN254 precompiles or like empty accounts
+    precompiles: bool,
+    precompile_calls: Vec<PrecompileCall>,
+    contracts: HashMap<[u8; 20], MockContract>,
+    contract_calls: Vec<([u8; 20], Vec<u8>)>,
+}
+
+impl Default for HostState {
+    fn default() -> Self {
+        Self {
+            caller: [0; 20],
+            call_data: Vec::new(),
+            timestamp: 0,
+            storage: HashMap::new(),
+            events: Vec::new(),
+            // A build without `arkworks` can only verify through the precompiles
+            precompiles: !cfg!(feature = "arkworks"),
+            precompile_calls: Vec::new(),
+            contracts: HashMap::new(),
+            contract_calls: Vec::new(),
+        }
+    }
+}
+
+/// A call that reached one of the emulated precompiles
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrecompileCall {
+    pub precompile: Bn254Precompile,
+    pub input_len: usize,
+    /// Time spent in the software stand-in, to subtract from contract-side timings
+    pub elapsed: Duration,
+}
+
+/// An event passed to `deposit_event`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    pub topics: Vec<[u8; 32]>,
+    pub data: Vec<u8>,
+}
+
+thread_local! {
+    static STATE: RefCell<HostState> = RefCell::new(HostState::default());
+}
+
+pub struct HostFnImpl;
+
+impl HostFnImpl {
+    pub fn caller(output: &mut [u8; 20]) {
+        STATE.with(|s| *output = s.borrow().caller)
+    }
+
+    pub fn call_data_size() -> u64 {
+        STATE.with(|s| s.borrow().call_data.len() as u64)
+    }
+
+    /// Copy call data from `offset`; bytes past the end are zeroed, as on-chain
+    pub fn call_data_copy(output: &mut [u8], offset: u32) {
+        STATE.with(|s| {
+            let state = s.borrow();
+            let start = (offset as usize).min(state.call_data.len());
+            let available = &state.call_data[start..];
+            let n = available.len().min(output.len());
+            output[..n].copy_from_slice(&available[..n]);
+            output[n..].fill(0);
+        })
+    }
+
+    pub fn return_value(flags: ReturnFlags, return_value: &[u8]) -> ! {
+        panic::panic_any(ContractReturn {
+            flags,
+            data: return_value.to_vec(),
+        })
+    }
+
+    /// Returns the size of the previous value, if any
+    pub fn set_storage(_flags: StorageFlags, key: &[u8], value: &[u8]) -> Option<u32> {
+        STATE.with(|s| {
+            s.borrow_mut()
+                .storage
+                .insert(key.to_vec(), value.to_vec())
+                .map(|old| old.len() as u32)
+        })
+    }
+
+    pub fn deposit_event(topics: &[[u8; 32]], data: &[u8]) {
+        STATE.with(|s| {
+            s.borrow_mut().events.push(Event {
+                topics: topics.to_vec(),
+                data: data.to_vec(),
+            })
+        });
+    }
+
+    /// Block timestamp as a little-endian U256
+    pub fn now(output: &mut [u8; 32]) {
+        STATE.with(|s| {
+            output.fill(0);
+            output[..8].copy_from_slice(&s.borrow().timestamp.to_le_bytes());
+        })
+    }
+
+    pub fn hash_keccak_256(input: &[u8], output: &mut [u8; 32]) {
+        output.copy_from_slice(&Keccak256::digest(input));
+    }
+
+    /// The BN254 precompiles and contracts registered with `set_contract` answer.
+    /// Any other callee behaves like an account without code: the call succeeds
+    /// and returns no data.
+    #[allow(clippy::too_many_arguments)]
+    pub fn call(
+        _flags: CallFlags,
+        callee: &[u8; 20],
+        _ref_time_limit: u64,
+        _proof_size_limit: u64,
+        _deposit_limit: &[u8; 32],
+        _value: &[u8; 32],
+        input_data: &[u8],
+        output: Option<&mut &mut [u8]>,
+    ) -> Result<(), ReturnErrorCode> {
+        let precompile = [Bn254Precompile::Add, Bn254Precompile::Mul, Bn254Precompile::Pairing]
+            .into_iter()
+            .find(|precompile| &precompile.address() == callee)
+            .filter(|_| STATE.with(|s| s.borrow().precompiles));
+        let contract = STATE.with(|s| s.borrow().contracts.get(callee).cloned());
+        let mut reverted = false;
+        let result = match (precompile, contract) {
+            (Some(precompile), _) => {
+                let start = Instant::now();
+                let result = precompile::emulate(precompile, input_data);
+                STATE.with(|s| {
+                    s.borrow_mut().precompile_calls.push(PrecompileCall {
+                        precompile,
+                        input_len: input_data.len(),
+                        elapsed: start.elapsed(),
+                    })
+                });
+                result.map_err(|_| ReturnErrorCode::CalleeTrapped)?
+            }
+            (None, Some(contract)) => {
+                STATE.with(|s| s.borrow_mut().contract_calls.push((*callee, input_data.to_vec())));
+                let ret = contract(input_data);
+                reverted = ret.reverted();
+                ret.data
+            }
+            (None, None) => Vec::new(),
+        };
+
+        if let Some(output) = output {
+            let n = result.len().min(output.len());
+            output[..n].copy_from_slice(&result[..n]);
+            let out = core::mem::take(output);
+            *output = &mut out[..n];
+        }
+        if reverted {
+            return Err(ReturnErrorCode::CalleeReverted);
+        }
+        Ok(())
+    }
+
+    /// Copy the value into `output` and shrink it to the value's length
+    pub fn get_storage(_flags: StorageFlags, key: &[u8], output: &mut &mut [u8]) -> Result<(), ReturnErrorCode> {
+        STATE.with(|s| {
+            let state = s.borrow();
+            let value = state.storage.get(key).ok_or(ReturnErrorCode::KeyNotFound)?;
+            let n = value.len().min(output.len());
+            output[..n].copy_from_slice(&value[..n]);
+            let out = core::mem::take(output);
+            *output = &mut out[..n];
+            Ok(())
+        })
+    }
+}
+
+/// Reset the host: empty storage and the given call data
+pub fn reset(call_data: &[u8]) {
+    STATE.with(|s| {
+        *s.borrow_mut() = HostState {
+            call_data: call_data.to_vec(),
+            ..HostState::default()
+        }
+    });
+}
+
+/// Replace the call data, keeping storage (for a sequence of calls)
+pub fn set_call_data(call_data: &[u8]) {
+    STATE.with(|s| s.borrow_mut().call_data = call_data.to_vec());
+}
+
+/// Set the account the next entry point runs as
+pub fn set_caller(caller: [u8; 20]) {
+    STATE.with(|s| s.borrow_mut().caller = caller);
+}
+
+/// Set the block timestamp `now` reports
+pub fn set_timestamp(timestamp: u64) {
+    STATE.with(|s| s.borrow_mut().timestamp = timestamp);
+}
+
+/// Answer calls to `address` with `contract` until the next `reset`
+pub fn set_contract(address: [u8; 20], contract: impl Fn(&[u8]) -> ContractReturn + 'static) {
+    STATE.with(|s| s.borrow_mut().contracts.insert(address, Rc::new(contract)));
+}
+
+/// Calls into contracts registered with `set_contract` since the last `reset`
+pub fn contract_calls() -> Vec<([u8; 20], Vec<u8>)> {
+    STATE.with(|s| s.borrow().contract_calls.clone())
+}
+
+/// Events emitted since the last `reset`
+pub fn events() -> Vec<Event> {
+    STATE.with(|s| s.borrow().events.clone())
+}
+
+/// Make the BN254 precompile addresses answer (or not) until the next `reset`
+pub fn set_precompiles(enabled: bool) {
+    STATE.with(|s| s.borrow_mut().precompiles = enabled);
+}
+
+/// Precompile calls since the last `reset`, oldest first
+pub fn precompile_calls() -> Vec<PrecompileCall> {
+    STATE.with(|s| s.borrow().precompile_calls.clone())
+}
+
+/// Run an entry point and capture what it passed to `return_value`.
+/// `None` means the entry point returned without calling it.
+pub fn run(entry: impl FnOnce()) -> Option<ContractReturn> {
+    silence_contract_returns();
+    match panic::catch_unwind(AssertUnwindSafe(entry)) {
+        Ok(()) => None,
+        Err(payload) => match payload.downcast::<ContractReturn>() {
+            Ok(ret) => Some(*ret),
+            Err(payload) => panic::resume_unwind(payload),
+        },
+    }
+}
+
+/// Keep the default panic hook from printing every `return_value` unwind
+fn silence_contract_returns() {
+    static INSTALL: std::sync::Once = std::sync::Once::new();
+    INSTALL.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if info.payload().downcast_ref::<ContractReturn>().is_none() {
+                default_hook(info);
+            }
+        }));
+    });
+}
@@ -0,0 +1,29 @@
+// Writes the canonical test-vector fixtures used by cross-implementation tests.
+// Usage: gen-test-vectors [--seed <u64>] [out_dir]   (default out_dir: <repo>/test-vectors/mul)
+
+use prover::test_vectors::{
+    generate_test_vectors, write_test_vectors, TEST_VECTOR_A, TEST_VECTOR_B, TEST_VECTOR_SEED,
+};
+use std::path::PathBuf;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut seed = TEST_VECTOR_SEED;
+    let mut out_dir = PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/../test-vectors/mul"));
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => {
+                let value = args.next().ok_or("--seed requires a value")?;
+                seed = value.parse()?;
+            }
+            _ => out_dir = PathBuf::from(arg),
+        }
+    }
+
+    let vectors = generate_test_vectors(seed, TEST_VECTOR_A, TEST_VECTOR_B)?;
+    write_test_vectors(&vectors, &out_dir)?;
+
+    println!("✅ Test vectors (seed {:#018x}) written to {}", seed, out_dir.display());
+    Ok(())
+}
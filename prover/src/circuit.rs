@@ -7,7 +7,43 @@ use ark_r1cs_std::fields::fp::FpVar;
 use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
 use ark_bn254::Fr;
 use ark_r1cs_std::eq::EqGadget;
+pub use verifier_core::nullifier::NullifierMode;
 
+/// Identifier and version of the a * b = c circuit, used when pinning its keys.
+/// Bump the version whenever the constraints change.
+pub const MUL_CIRCUIT_ID: &str = "mul";
+pub const MUL_CIRCUIT_VERSION: u32 = 1;
+
+/// Entry in the circuit registry. `id` is the `circuitId` the verifier contract
+/// dispatches on; never reuse an id for a different circuit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitSpec {
+    pub id: u64,
+    pub name: &'static str,
+    pub version: u32,
+    pub public_inputs: usize,
+    /// Replay protection the verifier contract applies to this circuit's proofs
+    pub nullifier: NullifierMode,
+}
+
+pub const MUL_CIRCUIT: CircuitSpec = CircuitSpec {
+    id: 1,
+    name: MUL_CIRCUIT_ID,
+    version: MUL_CIRCUIT_VERSION,
+    public_inputs: 1,
+    nullifier: NullifierMode::Off,
+};
+
+/// Every circuit the prover can generate keys for
+pub const CIRCUITS: &[CircuitSpec] = &[MUL_CIRCUIT];
+
+pub fn circuit_by_name(name: &str) -> Option<&'static CircuitSpec> {
+    CIRCUITS.iter().find(|c| c.name == name)
+}
+
+pub fn circuit_by_id(id: u64) -> Option<&'static CircuitSpec> {
+    CIRCUITS.iter().find(|c| c.id == id)
+}
 
 pub struct MulCircuit {
     pub a: Option<Fr>,
@@ -0,0 +1,31 @@
+// Error type returned by every fallible public function in the prover crate.
+// Callers (the CLI, services embedding the prover) match on the variant instead
+// of parsing messages, e.g. to choose a process exit code.
+
+use ark_relations::r1cs::SynthesisError;
+use ark_serialize::SerializationError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ProverError {
+    /// Constraint synthesis, setup, proving or verification failed inside arkworks
+    #[error("constraint synthesis failed: {0}")]
+    Synthesis(#[from] SynthesisError),
+
+    /// A key, proof or field element could not be (de)serialized
+    #[error("serialization failed: {0}")]
+    Serialization(#[from] SerializationError),
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Caller-supplied data is malformed (bad lengths, unparsable key modules, ...)
+    #[error("invalid input: {0}")]
+    InvalidInput(String),
+
+    /// A verifying key does not match the key it is expected to be
+    #[error("verifying key mismatch: {0}")]
+    KeyMismatch(String),
+}
+
+pub type Result<T, E = ProverError> = std::result::Result<T, E>;
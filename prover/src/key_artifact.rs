@@ -0,0 +1,586 @@
+// Verifying-key artifacts shared by the CLI, the prover binary and verifier-contract.
+// An artifact records the encoding its bytes were serialized with, is validated by
+// a deserialize round trip before it is written, and is emitted as a Rust module
+// whose constants let the contract assert the expected encoding at compile time.
+//
+// The contract embeds the `prepared` encoding: an uncompressed `PreparedVerifyingKey`
+// with e(alpha, beta) and the G2 line coefficients for -gamma and -delta already
+// computed, so `call()` skips `prepare_verifying_key`. It is ~60x larger than the
+// plain key (see `test-vectors/mul/verifier_keys.rs`).
+//
+// One contract serves every circuit in `circuit::CIRCUITS`: `VerifierKeyTable`
+// renders a `pub mod circuit_<id>` per key plus a `VERIFIER_KEYS` table of
+// `verifier_core::dispatch::VerifierKeyEntry` that `call()` looks keys up in.
+// `VerifierKeyEntry` and `NullifierMode` must be in scope at the include site.
+
+use ark_bn254::Bn254;
+use ark_groth16::{prepare_verifying_key, PreparedVerifyingKey, VerifyingKey};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use std::fs;
+use std::path::Path;
+
+use crate::circuit::{CircuitSpec, NullifierMode};
+use crate::error::{ProverError, Result};
+use crate::{verifying_key_fingerprint, verifying_key_hash};
+use crate::workspace::Workspace;
+use verifier_core::precompile;
+
+/// Encoding the verifier contract deserializes `VERIFYING_KEY_BYTES` with
+pub const CONTRACT_ENCODING: KeyEncoding = KeyEncoding::Prepared;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyEncoding {
+    Compressed,
+    Uncompressed,
+    /// Uncompressed `PreparedVerifyingKey` (includes the plain key)
+    Prepared,
+}
+
+impl KeyEncoding {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            KeyEncoding::Compressed => "compressed",
+            KeyEncoding::Uncompressed => "uncompressed",
+            KeyEncoding::Prepared => "prepared",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "compressed" => Some(KeyEncoding::Compressed),
+            "uncompressed" => Some(KeyEncoding::Uncompressed),
+            "prepared" => Some(KeyEncoding::Prepared),
+            _ => None,
+        }
+    }
+
+    pub fn serialize(&self, vk: &VerifyingKey<Bn254>) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        match self {
+            KeyEncoding::Compressed => vk.serialize_compressed(&mut bytes)?,
+            KeyEncoding::Uncompressed => vk.serialize_uncompressed(&mut bytes)?,
+            KeyEncoding::Prepared => prepare_verifying_key(vk).serialize_uncompressed(&mut bytes)?,
+        }
+        Ok(bytes)
+    }
+
+    pub fn deserialize(&self, bytes: &[u8]) -> Result<VerifyingKey<Bn254>> {
+        let vk = match self {
+            KeyEncoding::Compressed => VerifyingKey::<Bn254>::deserialize_compressed(bytes)?,
+            KeyEncoding::Uncompressed => VerifyingKey::<Bn254>::deserialize_uncompressed(bytes)?,
+            KeyEncoding::Prepared => {
+                let pvk = PreparedVerifyingKey::<Bn254>::deserialize_uncompressed(bytes)?;
+                if pvk != prepare_verifying_key(&pvk.vk) {
+                    return Err(ProverError::KeyMismatch(
+                        "prepared verifying key does not match its embedded key".into(),
+                    ));
+                }
+                pvk.vk
+            }
+        };
+        Ok(vk)
+    }
+}
+
+/// Serialized verifying key together with its encoding and fingerprint
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifyingKeyArtifact {
+    pub encoding: KeyEncoding,
+    pub bytes: Vec<u8>,
+    pub fingerprint: String,
+}
+
+impl VerifyingKeyArtifact {
+    /// Serialize `vk` and check that the bytes deserialize back to the same key
+    pub fn new(vk: &VerifyingKey<Bn254>, encoding: KeyEncoding) -> Result<Self> {
+        let bytes = encoding.serialize(vk)?;
+        if encoding.deserialize(&bytes)? != *vk {
+            return Err(ProverError::KeyMismatch(format!(
+                "verifying key does not round-trip in {} encoding",
+                encoding.as_str()
+            )));
+        }
+
+        Ok(Self {
+            encoding,
+            bytes,
+            fingerprint: verifying_key_fingerprint(vk)?,
+        })
+    }
+
+    /// Decode the key, using the recorded encoding
+    pub fn verifying_key(&self) -> Result<VerifyingKey<Bn254>> {
+        self.encoding.deserialize(&self.bytes)
+    }
+
+    /// Render the artifact as a Rust module for `include!` in the contract
+    pub fn to_rust_module(&self) -> String {
+        let mut out = String::new();
+        out.push_str("// Auto-generated by prover::key_artifact. Do not edit.\n");
+        out.push_str(&format!(
+            "pub const VERIFYING_KEY_ENCODING: &str = \"{}\";\n",
+            self.encoding.as_str()
+        ));
+        out.push_str(&format!(
+            "pub const VERIFYING_KEY_COMPRESSED: bool = {};\n",
+            self.encoding == KeyEncoding::Compressed
+        ));
+        out.push_str(&format!(
+            "pub const VERIFYING_KEY_PREPARED: bool = {};\n",
+            self.encoding == KeyEncoding::Prepared
+        ));
+        out.push_str(&format!("pub const VERIFYING_KEY_LEN: usize = {};\n", self.bytes.len()));
+        out.push_str(&format!("pub const VERIFYING_KEY_FINGERPRINT: &str = \"{}\";\n", self.fingerprint));
+        out.push_str("pub const VERIFYING_KEY_BYTES: &[u8] = &[\n");
+        out.push_str(&byte_rows(&self.bytes, "    "));
+        out.push_str("];\n");
+        out
+    }
+
+    /// Parse a module produced by `to_rust_module`, re-validating the key
+    pub fn from_rust_module(source: &str) -> Result<Self> {
+        let encoding_name = string_const(source, "VERIFYING_KEY_ENCODING").ok_or_else(|| {
+            ProverError::InvalidInput("VERIFYING_KEY_ENCODING not found; regenerate the module with `prove`".into())
+        })?;
+        let encoding = KeyEncoding::parse(encoding_name)
+            .ok_or_else(|| ProverError::InvalidInput(format!("unknown verifying key encoding: {}", encoding_name)))?;
+        let bytes = byte_array_const(source, "VERIFYING_KEY_BYTES")?;
+
+        let artifact = Self::new(&encoding.deserialize(&bytes)?, encoding)?;
+        if artifact.bytes != bytes {
+            return Err(ProverError::InvalidInput("VERIFYING_KEY_BYTES is not in canonical form".into()));
+        }
+        Ok(artifact)
+    }
+
+    pub fn write_rust_module(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, self.to_rust_module())?;
+        Ok(())
+    }
+
+    pub fn read_rust_module(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_rust_module(&fs::read_to_string(path)?)
+    }
+}
+
+/// Contract key for one circuit of the registry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifierKeyTableEntry {
+    pub circuit_id: u64,
+    pub circuit_name: String,
+    pub circuit_version: u32,
+    pub nullifier: NullifierMode,
+    pub artifact: VerifyingKeyArtifact,
+}
+
+impl VerifierKeyTableEntry {
+    /// Build the entry for `circuit`, checking the key's public input count against the registry
+    pub fn new(circuit: &CircuitSpec, vk: &VerifyingKey<Bn254>) -> Result<Self> {
+        let inputs = vk.gamma_abc_g1.len().saturating_sub(1);
+        if inputs != circuit.public_inputs {
+            return Err(ProverError::KeyMismatch(format!(
+                "verifying key has {} public inputs, circuit {} expects {}",
+                inputs, circuit.name, circuit.public_inputs
+            )));
+        }
+        if let NullifierMode::PublicInput(index) = circuit.nullifier {
+            if index >= inputs {
+                return Err(ProverError::InvalidInput(format!(
+                    "circuit {} uses public input {} as nullifier but has {} inputs",
+                    circuit.name, index, inputs
+                )));
+            }
+        }
+
+        Ok(Self {
+            circuit_id: circuit.id,
+            circuit_name: circuit.name.to_string(),
+            circuit_version: circuit.version,
+            nullifier: circuit.nullifier,
+            artifact: VerifyingKeyArtifact::new(vk, CONTRACT_ENCODING)?,
+        })
+    }
+
+    fn module_name(&self) -> String {
+        format!("circuit_{}", self.circuit_id)
+    }
+}
+
+/// Every key compiled into the verifier contract, ordered by circuit id
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VerifierKeyTable {
+    pub entries: Vec<VerifierKeyTableEntry>,
+}
+
+impl VerifierKeyTable {
+    /// Add or replace the key for `entry.circuit_id`
+    pub fn upsert(&mut self, entry: VerifierKeyTableEntry) {
+        self.entries.retain(|e| e.circuit_id != entry.circuit_id);
+        self.entries.push(entry);
+        self.entries.sort_by_key(|e| e.circuit_id);
+    }
+
+    pub fn get(&self, circuit_id: u64) -> Option<&VerifierKeyTableEntry> {
+        self.entries.iter().find(|e| e.circuit_id == circuit_id)
+    }
+
+    pub fn by_name(&self, circuit_name: &str) -> Option<&VerifierKeyTableEntry> {
+        self.entries.iter().find(|e| e.circuit_name == circuit_name)
+    }
+
+    /// Render the table as a Rust module for `include!` in the contract
+    pub fn to_rust_module(&self) -> Result<String> {
+        let mut out = String::new();
+        out.push_str("// Auto-generated by prover::key_artifact. Do not edit.\n");
+        out.push_str(&format!(
+            "pub const VERIFYING_KEY_ENCODING: &str = \"{}\";\n",
+            CONTRACT_ENCODING.as_str()
+        ));
+        out.push_str(&format!(
+            "pub const VERIFYING_KEY_COMPRESSED: bool = {};\n",
+            CONTRACT_ENCODING == KeyEncoding::Compressed
+        ));
+        out.push_str(&format!(
+            "pub const VERIFYING_KEY_PREPARED: bool = {};\n",
+            CONTRACT_ENCODING == KeyEncoding::Prepared
+        ));
+
+        for entry in &self.entries {
+            let hash = verifying_key_hash(&entry.artifact.verifying_key()?)?;
+            out.push_str(&format!("\npub mod {} {{\n", entry.module_name()));
+            out.push_str(&format!("    pub const CIRCUIT_ID: u64 = {};\n", entry.circuit_id));
+            out.push_str(&format!("    pub const CIRCUIT_NAME: &str = \"{}\";\n", entry.circuit_name));
+            out.push_str(&format!("    pub const CIRCUIT_VERSION: u32 = {};\n", entry.circuit_version));
+            out.push_str(&format!(
+                "    pub const NULLIFIER: super::NullifierMode = {};\n",
+                nullifier_literal(entry.nullifier)
+            ));
+            out.push_str("    pub const VERIFYING_KEY_HASH: [u8; 32] = [\n");
+            out.push_str(&byte_rows(&hash, "        "));
+            out.push_str("    ];\n");
+            out.push_str("    pub const VERIFYING_KEY_EVM: &[u8] = &[\n");
+            out.push_str(&byte_rows(&precompile::encode_verifying_key(&entry.artifact.verifying_key()?), "        "));
+            out.push_str("    ];\n");
+            for line in entry.artifact.to_rust_module().lines().skip(1) {
+                if line.is_empty() {
+                    out.push('\n');
+                } else {
+                    out.push_str(&format!("    {}\n", line));
+                }
+            }
+            out.push_str("}\n");
+        }
+
+        out.push_str("\npub const VERIFIER_KEYS: &[VerifierKeyEntry] = &[\n");
+        for entry in &self.entries {
+            let name = entry.module_name();
+            out.push_str(&format!(
+                "    VerifierKeyEntry {{\n        circuit_id: {name}::CIRCUIT_ID,\n        circuit_version: {name}::CIRCUIT_VERSION,\n        key_hash: {name}::VERIFYING_KEY_HASH,\n        prepared_key: {name}::VERIFYING_KEY_BYTES,\n        evm_key: {name}::VERIFYING_KEY_EVM,\n        nullifier: {name}::NULLIFIER,\n    }},\n"
+            ));
+        }
+        out.push_str("];\n");
+        Ok(out)
+    }
+
+    /// Parse a module produced by `to_rust_module`, re-validating every key
+    pub fn from_rust_module(source: &str) -> Result<Self> {
+        if !source.contains("VERIFIER_KEYS") {
+            return Err(ProverError::InvalidInput(
+                "VERIFIER_KEYS not found; regenerate the module with `prove`".into(),
+            ));
+        }
+
+        let mut table = Self::default();
+        for section in source.split("pub mod circuit_").skip(1) {
+            let section = &section[..section.find("\n}").unwrap_or(section.len())];
+            let circuit_id = int_const(section, "CIRCUIT_ID")?;
+            let circuit_version = u32::try_from(int_const(section, "CIRCUIT_VERSION")?)
+                .map_err(|_| ProverError::InvalidInput("CIRCUIT_VERSION out of range".into()))?;
+            let circuit_name = string_const(section, "CIRCUIT_NAME")
+                .ok_or_else(|| ProverError::InvalidInput(format!("CIRCUIT_NAME not found for circuit {}", circuit_id)))?;
+            let nullifier = nullifier_const(section)?;
+            let artifact = VerifyingKeyArtifact::from_rust_module(section)?;
+            if artifact.encoding != CONTRACT_ENCODING {
+                return Err(ProverError::KeyMismatch(format!(
+                    "circuit {} key uses {} encoding, the contract expects {}",
+                    circuit_id,
+                    artifact.encoding.as_str(),
+                    CONTRACT_ENCODING.as_str()
+                )));
+            }
+            if byte_array_const(section, "VERIFYING_KEY_EVM")? != precompile::encode_verifying_key(&artifact.verifying_key()?) {
+                return Err(ProverError::KeyMismatch(format!(
+                    "VERIFYING_KEY_EVM does not match the key for circuit {}",
+                    circuit_id
+                )));
+            }
+            if byte_array_const(section, "VERIFYING_KEY_HASH")? != verifying_key_hash(&artifact.verifying_key()?)? {
+                return Err(ProverError::KeyMismatch(format!(
+                    "VERIFYING_KEY_HASH does not match the key for circuit {}",
+                    circuit_id
+                )));
+            }
+            if table.get(circuit_id).is_some() {
+                return Err(ProverError::InvalidInput(format!("duplicate key for circuit {}", circuit_id)));
+            }
+
+            table.upsert(VerifierKeyTableEntry {
+                circuit_id,
+                circuit_name: circuit_name.to_string(),
+                circuit_version,
+                nullifier,
+                artifact,
+            });
+        }
+        Ok(table)
+    }
+
+    pub fn write_rust_module(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, self.to_rust_module()?)?;
+        Ok(())
+    }
+
+    pub fn read_rust_module(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_rust_module(&fs::read_to_string(path)?)
+    }
+}
+
+/// Export the key for `circuit` into the workspace module embedded by
+/// `verifier-contract`, keeping the keys already there for other circuits
+pub fn export_verifying_key_to_rs(
+    workspace: &Workspace,
+    circuit: &CircuitSpec,
+    vk: &VerifyingKey<Bn254>,
+) -> Result<VerifyingKeyArtifact> {
+    let path = workspace.verifier_keys_rs_path();
+    let mut table = if path.exists() {
+        VerifierKeyTable::read_rust_module(&path)?
+    } else {
+        VerifierKeyTable::default()
+    };
+
+    let entry = VerifierKeyTableEntry::new(circuit, vk)?;
+    let artifact = entry.artifact.clone();
+    table.upsert(entry);
+    table.write_rust_module(&path)?;
+    Ok(artifact)
+}
+
+fn nullifier_literal(mode: NullifierMode) -> String {
+    match mode {
+        NullifierMode::Off => "super::NullifierMode::Off".to_string(),
+        NullifierMode::ProofHash => "super::NullifierMode::ProofHash".to_string(),
+        NullifierMode::PublicInput(index) => format!("super::NullifierMode::PublicInput({})", index),
+    }
+}
+
+/// Parse the `NULLIFIER` const written by `nullifier_literal`
+fn nullifier_const(section: &str) -> Result<NullifierMode> {
+    let invalid = || ProverError::InvalidInput("NULLIFIER not found; regenerate the module with `prove`".into());
+    let rest = &section[section.find("NULLIFIER:").ok_or_else(invalid)?..];
+    let value = rest[rest.find('=').ok_or_else(invalid)? + 1..rest.find(';').ok_or_else(invalid)?].trim();
+    let variant = value.rsplit("NullifierMode::").next().unwrap_or(value);
+
+    match variant {
+        "Off" => Ok(NullifierMode::Off),
+        "ProofHash" => Ok(NullifierMode::ProofHash),
+        _ => variant
+            .strip_prefix("PublicInput(")
+            .and_then(|index| index.strip_suffix(')'))
+            .and_then(|index| index.trim().parse().ok())
+            .map(NullifierMode::PublicInput)
+            .ok_or_else(|| ProverError::InvalidInput(format!("invalid NULLIFIER: {}", value))),
+    }
+}
+
+fn byte_rows(bytes: &[u8], indent: &str) -> String {
+    let mut out = String::new();
+    for chunk in bytes.chunks(16) {
+        out.push_str(indent);
+        out.push_str(&chunk.iter().map(|byte| format!("0x{:02x},", byte)).collect::<Vec<_>>().join(" "));
+        out.push('\n');
+    }
+    out
+}
+
+/// Value of `NAME: <int type> = <value>;`
+fn int_const(source: &str, name: &str) -> Result<u64> {
+    let pattern = format!("{}:", name);
+    source
+        .find(&pattern)
+        .map(|i| &source[i..])
+        .and_then(|rest| Some(&rest[rest.find('=')? + 1..rest.find(';')?]))
+        .and_then(|value| value.trim().parse().ok())
+        .ok_or_else(|| ProverError::InvalidInput(format!("{} not found", name)))
+}
+
+fn string_const<'a>(source: &'a str, name: &str) -> Option<&'a str> {
+    let rest = &source[source.find(name)?..];
+    let start = rest.find('"')? + 1;
+    let end = start + rest[start..].find('"')?;
+    Some(&rest[start..end])
+}
+
+/// Extract the bytes of `NAME: &[u8] = &[...]` (decimal or 0x-hex literals)
+fn byte_array_const(source: &str, name: &str) -> Result<Vec<u8>> {
+    let start = source
+        .find(name)
+        .and_then(|i| source[i..].find('=').map(|j| i + j))
+        .and_then(|i| source[i..].find('[').map(|j| i + j + 1))
+        .ok_or_else(|| ProverError::InvalidInput(format!("{} array not found", name)))?;
+    let end = source[start..]
+        .find(']')
+        .map(|j| start + j)
+        .ok_or_else(|| ProverError::InvalidInput(format!("unterminated {} array", name)))?;
+
+    source[start..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .map(|token| {
+            let parsed = match token.strip_prefix("0x") {
+                Some(hex) => u8::from_str_radix(hex, 16),
+                None => token.parse::<u8>(),
+            };
+            parsed.map_err(|_| ProverError::InvalidInput(format!("invalid byte literal: {}", token)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::circuit::MUL_CIRCUIT;
+    use crate::{generate_proof, seeded_rng};
+
+    fn test_vk() -> VerifyingKey<Bn254> {
+        generate_proof(3, 4, &mut seeded_rng(5)).expect("proof generation failed").2.vk
+    }
+
+    #[test]
+    fn test_rust_module_round_trip_all_encodings() {
+        let vk = test_vk();
+        for encoding in [KeyEncoding::Compressed, KeyEncoding::Uncompressed, KeyEncoding::Prepared] {
+            let artifact = VerifyingKeyArtifact::new(&vk, encoding).unwrap();
+            let parsed = VerifyingKeyArtifact::from_rust_module(&artifact.to_rust_module()).unwrap();
+
+            assert_eq!(parsed, artifact);
+            assert_eq!(parsed.verifying_key().unwrap(), vk);
+        }
+    }
+
+    #[test]
+    fn test_export_verifying_key_to_rs() {
+        let vk = test_vk();
+        let home = std::env::temp_dir().join(format!("niet2code-artifact-{}", std::process::id()));
+        let workspace = Workspace::new(&home, "test");
+        let artifact = export_verifying_key_to_rs(&workspace, &MUL_CIRCUIT, &vk).expect("export failed");
+        // Re-exporting replaces the entry instead of adding a second one
+        export_verifying_key_to_rs(&workspace, &MUL_CIRCUIT, &vk).expect("re-export failed");
+
+        let written = VerifierKeyTable::read_rust_module(workspace.verifier_keys_rs_path()).expect("read back failed");
+        let _ = fs::remove_dir_all(&home);
+        assert_eq!(written.entries.len(), 1);
+        let entry = written.get(MUL_CIRCUIT.id).unwrap();
+        assert_eq!(entry.circuit_name, MUL_CIRCUIT.name);
+        assert_eq!(entry.artifact, artifact);
+        assert_eq!(entry.artifact.encoding, CONTRACT_ENCODING);
+        assert_eq!(entry.artifact.verifying_key().unwrap(), vk);
+    }
+
+    #[test]
+    fn test_key_table_round_trip_keeps_every_circuit() {
+        let other = CircuitSpec {
+            id: 7,
+            name: "other",
+            version: 3,
+            public_inputs: 1,
+            nullifier: NullifierMode::PublicInput(0),
+        };
+        let mut table = VerifierKeyTable::default();
+        table.upsert(VerifierKeyTableEntry::new(&other, &test_vk()).unwrap());
+        table.upsert(VerifierKeyTableEntry::new(&MUL_CIRCUIT, &test_vk()).unwrap());
+
+        let parsed = VerifierKeyTable::from_rust_module(&table.to_rust_module().unwrap()).unwrap();
+        assert_eq!(parsed, table);
+        assert_eq!(parsed.entries.iter().map(|e| e.circuit_id).collect::<Vec<_>>(), vec![1, 7]);
+        assert_eq!(parsed.by_name("other").unwrap().circuit_version, 3);
+        assert_eq!(parsed.by_name("other").unwrap().nullifier, NullifierMode::PublicInput(0));
+        assert_eq!(parsed.get(MUL_CIRCUIT.id).unwrap().nullifier, MUL_CIRCUIT.nullifier);
+    }
+
+    #[test]
+    fn test_key_table_rejects_tampered_hash() {
+        let mut table = VerifierKeyTable::default();
+        table.upsert(VerifierKeyTableEntry::new(&MUL_CIRCUIT, &test_vk()).unwrap());
+        let module = table.to_rust_module().unwrap();
+
+        for name in ["VERIFYING_KEY_HASH", "VERIFYING_KEY_EVM"] {
+            let start = module.find(name).unwrap();
+            let byte = start + module[start..].find("0x").unwrap();
+            let tampered = format!("{}0x{:02x}{}", &module[..byte], u8::from_str_radix(&module[byte + 2..byte + 4], 16).unwrap() ^ 1, &module[byte + 4..]);
+
+            assert!(matches!(
+                VerifierKeyTable::from_rust_module(&tampered),
+                Err(ProverError::KeyMismatch(_))
+            ), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_key_table_checks_public_input_count() {
+        let wide = CircuitSpec {
+            public_inputs: 2,
+            ..MUL_CIRCUIT
+        };
+        assert!(matches!(
+            VerifierKeyTableEntry::new(&wide, &test_vk()),
+            Err(ProverError::KeyMismatch(_))
+        ));
+
+        let bad_nullifier = CircuitSpec {
+            nullifier: NullifierMode::PublicInput(1),
+            ..MUL_CIRCUIT
+        };
+        assert!(matches!(
+            VerifierKeyTableEntry::new(&bad_nullifier, &test_vk()),
+            Err(ProverError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_module_with_wrong_encoding_is_rejected() {
+        let artifact = VerifyingKeyArtifact::new(&test_vk(), KeyEncoding::Compressed).unwrap();
+        let mislabeled = artifact.to_rust_module().replace("\"compressed\"", "\"uncompressed\"");
+
+        assert!(matches!(
+            VerifyingKeyArtifact::from_rust_module(&mislabeled),
+            Err(ProverError::Serialization(_) | ProverError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_legacy_module_without_encoding_is_rejected() {
+        let legacy = "pub const VERIFYING_KEY_BYTES: &[u8] = &[1, 2, 3];";
+        assert!(matches!(
+            VerifyingKeyArtifact::from_rust_module(legacy),
+            Err(ProverError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_byte_array_accepts_both_literal_styles() {
+        let decimal = "pub const VERIFYING_KEY_BYTES: &[u8] = &[1, 2, 255];";
+        let hex = "pub const VERIFYING_KEY_BYTES: &[u8] = &[\n    0x01, 0x02, 0xff,\n];\n";
+        assert_eq!(byte_array_const(decimal, "VERIFYING_KEY_BYTES").unwrap(), vec![1, 2, 255]);
+        assert_eq!(byte_array_const(hex, "VERIFYING_KEY_BYTES").unwrap(), vec![1, 2, 255]);
+    }
+}
@@ -3,32 +3,59 @@
 
 // Includes:
 // - `generate_proof`: produces a Groth16 proof and public output for a * b = c
-// - `verify_proof`: checks validity of a proof against a verifying key
-// - `export_verifying_key_to_rs`: outputs verifying key as a Rust byte array for embedding
+// - `verify_proof`: checks validity of a proof against a verifying key (via verifier-core)
+// - `key_artifact`: verifying key export for on-chain embedding (see key_artifact.rs)
 // - `load_verifying_key_from_file`: loads a verifying key from a binary file
+// - `workspace`: resolves where keys, proofs and CLI state live (see workspace.rs)
+// - `seeded_rng`: deterministic ChaCha RNG for reproducible test vectors
+// - `verifying_key_fingerprint`: stable identifier of a verifying key for pinning
+// - `error`: `ProverError`, returned by every fallible public function (see error.rs)
 
 pub mod circuit;
+pub mod error;
+pub mod key_artifact;
+pub mod test_vectors;
 pub mod utils;
+pub mod workspace;
 
 use ark_bn254::{Bn254, Fr};
-use ark_groth16::{Groth16, Proof, ProvingKey, VerifyingKey, prepare_verifying_key};
-use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
-use rand::thread_rng;
-use std::fs::{self, File};
-use std::io::{BufReader, Write};
+use ark_groth16::{Groth16, Proof, ProvingKey, VerifyingKey};
+use verifier_core::{RejectReason, Verdict};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use rand::{CryptoRng, RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use sha2::{Digest, Sha256};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
 
 use crate::circuit::MulCircuit;
 
-/// Generate a Groth16 proof for a * b = c
-pub fn generate_proof(a: u64, b: u64) -> Result<(Proof<Bn254>, Fr, ProvingKey<Bn254>), Box<dyn std::error::Error>> {
-    let mut rng = thread_rng();
+pub use crate::error::{ProverError, Result};
+
+/// Proof, public output `c` and the proving key it was generated with
+pub type ProofBundle = (Proof<Bn254>, Fr, ProvingKey<Bn254>);
 
+/// Deterministic RNG for reproducible keys and proofs.
+///
+/// Test use only: anyone who knows the seed can recompute the toxic waste
+/// of the setup, so keys generated this way must never secure real value.
+pub fn seeded_rng(seed: u64) -> ChaCha20Rng {
+    ChaCha20Rng::seed_from_u64(seed)
+}
+
+/// Generate a Groth16 proof for a * b = c
+pub fn generate_proof<R: RngCore + CryptoRng>(
+    a: u64,
+    b: u64,
+    rng: &mut R,
+) -> Result<ProofBundle> {
     let a_fr = Fr::from(a);
     let b_fr = Fr::from(b);
     let c = a_fr * b_fr;
 
     let circuit = MulCircuit { a: None, b: None, c: None };
-    let pk = Groth16::<Bn254>::generate_random_parameters_with_reduction(circuit, &mut rng)?;
+    let pk = Groth16::<Bn254>::generate_random_parameters_with_reduction(circuit, rng)?;
 
     let instance = MulCircuit {
         a: Some(a_fr),
@@ -36,42 +63,127 @@ pub fn generate_proof(a: u64, b: u64) -> Result<(Proof<Bn254>, Fr, ProvingKey<Bn
         c: Some(c),
     };
 
-    let proof = Groth16::<Bn254>::create_random_proof_with_reduction(instance, &pk, &mut rng)?;
+    let proof = Groth16::<Bn254>::create_random_proof_with_reduction(instance, &pk, rng)?;
     Ok((proof, c, pk))
 }
 
-/// Verify a Groth16 proof against public input c
-pub fn verify_proof(proof: &Proof<Bn254>, c: Fr, vk: &VerifyingKey<Bn254>) -> Result<bool, Box<dyn std::error::Error>> {
-    let pvk = prepare_verifying_key(vk);
-    let result = Groth16::<Bn254>::verify_proof(&pvk, proof, &[c])?;
-    Ok(result)
+/// Verify a Groth16 proof against public input c (same code path as the verifier contract)
+pub fn verify_proof(proof: &Proof<Bn254>, c: Fr, vk: &VerifyingKey<Bn254>) -> Result<bool> {
+    match verifier_core::verify(vk, proof, &[c]) {
+        Verdict::Valid => Ok(true),
+        Verdict::Invalid => Ok(false),
+        Verdict::Rejected(reason) => Err(reject_error(reason)),
+    }
 }
 
-/// Export verifying key to a byte array source file for on-chain embedding
-pub fn export_verifying_key_to_rs(vk: &VerifyingKey<Bn254>) -> Result<(), Box<dyn std::error::Error>> {
-    fs::create_dir_all("../keys")?;
+/// The error kind behind a rejected verification: only a missing or retired key
+/// is a key mismatch; undecodable points are serialization errors, and anything
+/// else is bad input
+pub fn reject_error(reason: RejectReason) -> ProverError {
+    match reason {
+        RejectReason::UnknownCircuit | RejectReason::DeprecatedKey => ProverError::KeyMismatch(reason.to_string()),
+        RejectReason::MalformedVerifyingKey | RejectReason::MalformedProof => ProverError::Serialization(SerializationError::InvalidData),
+        RejectReason::MalformedCalldata
+        | RejectReason::NullifierUsed
+        | RejectReason::NonCanonicalPublicInput
+        | RejectReason::PublicInputCount { .. }
+        | RejectReason::PrecompileFailed => ProverError::InvalidInput(reason.to_string()),
+    }
+}
 
+/// Fingerprint of a verifying key: SHA-256 over its canonical compressed
+/// serialization, so the same key yields the same fingerprint regardless of
+/// the encoding it was stored in.
+pub fn verifying_key_fingerprint(vk: &VerifyingKey<Bn254>) -> Result<String> {
+    Ok(format!("sha256:{}", hex_encode(&verifying_key_hash(vk)?)))
+}
+
+/// Raw digest behind `verifying_key_fingerprint` (what the contract's `vkHash` returns)
+pub fn verifying_key_hash(vk: &VerifyingKey<Bn254>) -> Result<[u8; 32]> {
     let mut vk_bytes = Vec::new();
-    vk.serialize_uncompressed(&mut vk_bytes)?;
-
-    let mut out = File::create("../keys/verifying_key_bytes.rs")?;
-    writeln!(out, "// Auto-generated verifying key byte array\npub const VERIFYING_KEY_BYTES: &[u8] = &[")?;
-    for chunk in vk_bytes.chunks(16) {
-        write!(out, "    ")?;
-        for byte in chunk {
-            write!(out, "0x{:02x}, ", byte)?;
-        }
-        writeln!(out)?;
-    }
-    writeln!(out, "];\n")?;
+    vk.serialize_compressed(&mut vk_bytes)?;
+    Ok(Sha256::digest(&vk_bytes).into())
+}
 
-    Ok(())
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
 }
 
 /// Load a verifying key from a binary file
-pub fn load_verifying_key_from_file(path: &str) -> Result<VerifyingKey<Bn254>, Box<dyn std::error::Error>> {
+pub fn load_verifying_key_from_file(path: impl AsRef<Path>) -> Result<VerifyingKey<Bn254>> {
     let file = File::open(path)?;
     let reader = BufReader::new(file);
     let vk = VerifyingKey::<Bn254>::deserialize_uncompressed(reader)?;
     Ok(vk)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::thread_rng;
+
+    #[test]
+    fn test_valid_proof_verifies() {
+        let (proof, c, pk) = generate_proof(3, 4, &mut thread_rng()).expect("proof generation failed");
+
+        let is_valid = verify_proof(&proof, c, &pk.vk).expect("verification failed");
+        assert!(is_valid, "Expected valid proof to verify successfully");
+    }
+
+    #[test]
+    fn test_invalid_public_input_fails() {
+        let (proof, _c, pk) = generate_proof(3, 4, &mut thread_rng()).expect("proof generation failed");
+        let invalid_c = Fr::from(999u64);
+
+        let is_valid = verify_proof(&proof, invalid_c, &pk.vk).expect("verification failed");
+        assert!(!is_valid, "Expected invalid proof to fail verification");
+    }
+
+    #[test]
+    fn test_wrong_input_count_is_invalid_input() {
+        let (proof, c, pk) = generate_proof(3, 4, &mut seeded_rng(1)).expect("proof generation failed");
+        let mut vk = pk.vk.clone();
+        vk.gamma_abc_g1.push(vk.alpha_g1);
+
+        let err = verify_proof(&proof, c, &vk).unwrap_err();
+        assert!(matches!(err, ProverError::InvalidInput(_)), "{}", err);
+        assert!(matches!(reject_error(RejectReason::UnknownCircuit), ProverError::KeyMismatch(_)));
+        assert!(matches!(reject_error(RejectReason::MalformedProof), ProverError::Serialization(_)));
+    }
+
+    #[test]
+    fn test_seeded_proofs_are_reproducible() {
+        let (proof_a, c_a, pk_a) = generate_proof(3, 4, &mut seeded_rng(7)).expect("proof generation failed");
+        let (proof_b, c_b, pk_b) = generate_proof(3, 4, &mut seeded_rng(7)).expect("proof generation failed");
+
+        assert_eq!(proof_a, proof_b);
+        assert_eq!(c_a, c_b);
+        assert_eq!(pk_a.vk, pk_b.vk);
+    }
+
+    #[test]
+    fn test_fingerprint_is_stable_and_key_specific() {
+        let (_, _, pk_a) = generate_proof(3, 4, &mut seeded_rng(1)).expect("proof generation failed");
+        let (_, _, pk_b) = generate_proof(3, 4, &mut seeded_rng(2)).expect("proof generation failed");
+
+        let fp_a = verifying_key_fingerprint(&pk_a.vk).unwrap();
+        assert_eq!(fp_a, verifying_key_fingerprint(&pk_a.vk).unwrap());
+        assert_ne!(fp_a, verifying_key_fingerprint(&pk_b.vk).unwrap());
+        assert!(fp_a.starts_with("sha256:") && fp_a.len() == 7 + 64);
+    }
+
+    #[test]
+    fn test_missing_key_file_is_io_error() {
+        let err = load_verifying_key_from_file("/nonexistent/verifying_key.bin").unwrap_err();
+        assert!(matches!(err, ProverError::Io(_)));
+    }
+
+    #[test]
+    fn test_garbage_key_file_is_serialization_error() {
+        let path = std::env::temp_dir().join(format!("niet2code-garbage-vk-{}.bin", std::process::id()));
+        std::fs::write(&path, [0xffu8; 16]).unwrap();
+        let err = load_verifying_key_from_file(&path).unwrap_err();
+        let _ = std::fs::remove_file(&path);
+        assert!(matches!(err, ProverError::Serialization(_)));
+    }
+}
@@ -0,0 +1,152 @@
+// Canonical test vectors for cross-implementation testing.
+// Keys and proofs are generated from a fixed ChaCha seed so every run yields
+// byte-identical fixtures. Other verifiers (the PolkaVM contract, the Solidity
+// verifier) can be tested against the files checked in under `test-vectors/`.
+//
+// Files written per vector set:
+// - proving_key.bin: uncompressed proving key
+// - verifying_key.bin: uncompressed verifying key
+// - prepared_verifying_key.bin: uncompressed prepared verifying key (contract encoding)
+// - proof.bin: compressed proof
+// - evm_verifying_key.bin / evm_proof.bin: key and proof in the BN254 precompile layout
+// - public_input.bin: uncompressed public input c
+// - calldata.bin: ABI-encoded `verify(circuitId, proof, [c])` for `MUL_CIRCUIT`
+// - evm_calldata.bin: the same call carrying `evm_proof.bin`
+// - verifier_keys.rs: contract key table, used by verifier-contract's native tests
+// - vectors.txt: seed and circuit inputs used to generate the set
+
+use ark_serialize::CanonicalSerialize;
+use std::fs;
+use std::path::Path;
+
+use crate::error::Result;
+use crate::circuit::MUL_CIRCUIT;
+use crate::key_artifact::{KeyEncoding, VerifierKeyTable, VerifierKeyTableEntry};
+use crate::utils::encode_calldata;
+use crate::{generate_proof, seeded_rng};
+use verifier_core::precompile;
+
+/// Seed used for the checked-in fixtures
+pub const TEST_VECTOR_SEED: u64 = 0x6e69_6574_3263_6f64;
+
+/// Circuit inputs used for the checked-in fixtures (a * b = c)
+pub const TEST_VECTOR_A: u64 = 3;
+pub const TEST_VECTOR_B: u64 = 4;
+
+/// Serialized artifacts of one deterministic proving run
+pub struct TestVectors {
+    pub seed: u64,
+    pub a: u64,
+    pub b: u64,
+    pub proving_key: Vec<u8>,
+    pub verifying_key: Vec<u8>,
+    pub prepared_verifying_key: Vec<u8>,
+    pub proof: Vec<u8>,
+    pub public_input: Vec<u8>,
+    pub calldata: Vec<u8>,
+    pub evm_verifying_key: Vec<u8>,
+    pub evm_proof: Vec<u8>,
+    pub evm_calldata: Vec<u8>,
+    pub verifier_keys_module: String,
+}
+
+/// Generate test vectors for a * b = c from the given seed
+pub fn generate_test_vectors(seed: u64, a: u64, b: u64) -> Result<TestVectors> {
+    let (proof, c, pk) = generate_proof(a, b, &mut seeded_rng(seed))?;
+
+    let mut proving_key = Vec::new();
+    pk.serialize_uncompressed(&mut proving_key)?;
+
+    let mut verifying_key = Vec::new();
+    pk.vk.serialize_uncompressed(&mut verifying_key)?;
+
+    let prepared_verifying_key = KeyEncoding::Prepared.serialize(&pk.vk)?;
+
+    let mut proof_bytes = Vec::new();
+    proof.serialize_compressed(&mut proof_bytes)?;
+
+    let mut public_input = Vec::new();
+    c.serialize_uncompressed(&mut public_input)?;
+
+    let calldata = encode_calldata(MUL_CIRCUIT.id, &proof, &[c])?;
+    let evm_calldata = verifier_core::encode_evm_calldata(MUL_CIRCUIT.id, &proof, &[c]);
+    let mut keys = VerifierKeyTable::default();
+    keys.upsert(VerifierKeyTableEntry::new(&MUL_CIRCUIT, &pk.vk)?);
+    let verifier_keys_module = keys.to_rust_module()?;
+
+    Ok(TestVectors {
+        seed,
+        a,
+        b,
+        proving_key,
+        verifying_key,
+        prepared_verifying_key,
+        proof: proof_bytes,
+        public_input,
+        calldata,
+        evm_verifying_key: precompile::encode_verifying_key(&pk.vk),
+        evm_proof: precompile::encode_proof(&proof).to_vec(),
+        evm_calldata,
+        verifier_keys_module,
+    })
+}
+
+/// Write a vector set to `dir`, creating it if needed
+pub fn write_test_vectors(vectors: &TestVectors, dir: &Path) -> Result<()> {
+    fs::create_dir_all(dir)?;
+
+    fs::write(dir.join("proving_key.bin"), &vectors.proving_key)?;
+    fs::write(dir.join("verifying_key.bin"), &vectors.verifying_key)?;
+    fs::write(dir.join("prepared_verifying_key.bin"), &vectors.prepared_verifying_key)?;
+    fs::write(dir.join("proof.bin"), &vectors.proof)?;
+    fs::write(dir.join("public_input.bin"), &vectors.public_input)?;
+    fs::write(dir.join("calldata.bin"), &vectors.calldata)?;
+    fs::write(dir.join("evm_verifying_key.bin"), &vectors.evm_verifying_key)?;
+    fs::write(dir.join("evm_proof.bin"), &vectors.evm_proof)?;
+    fs::write(dir.join("evm_calldata.bin"), &vectors.evm_calldata)?;
+    fs::write(dir.join("verifier_keys.rs"), &vectors.verifier_keys_module)?;
+    fs::write(
+        dir.join("vectors.txt"),
+        format!(
+            "# Generated by `cargo run -p prover --bin gen-test-vectors`. Do not edit.\n\
+             seed = {:#018x}\n\
+             a = {}\n\
+             b = {}\n\
+             c = {}\n",
+            vectors.seed,
+            vectors.a,
+            vectors.b,
+            vectors.a * vectors.b,
+        ),
+    )?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn fixture_dir() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../test-vectors/mul")
+    }
+
+    #[test]
+    fn test_vectors_match_checked_in_fixtures() {
+        let vectors = generate_test_vectors(TEST_VECTOR_SEED, TEST_VECTOR_A, TEST_VECTOR_B)
+            .expect("test vector generation failed");
+        let dir = fixture_dir();
+
+        assert_eq!(vectors.proving_key, fs::read(dir.join("proving_key.bin")).unwrap());
+        assert_eq!(vectors.verifying_key, fs::read(dir.join("verifying_key.bin")).unwrap());
+        assert_eq!(vectors.prepared_verifying_key, fs::read(dir.join("prepared_verifying_key.bin")).unwrap());
+        assert_eq!(vectors.proof, fs::read(dir.join("proof.bin")).unwrap());
+        assert_eq!(vectors.public_input, fs::read(dir.join("public_input.bin")).unwrap());
+        assert_eq!(vectors.calldata, fs::read(dir.join("calldata.bin")).unwrap());
+        assert_eq!(vectors.evm_verifying_key, fs::read(dir.join("evm_verifying_key.bin")).unwrap());
+        assert_eq!(vectors.evm_proof, fs::read(dir.join("evm_proof.bin")).unwrap());
+        assert_eq!(vectors.evm_calldata, fs::read(dir.join("evm_calldata.bin")).unwrap());
+        assert_eq!(vectors.verifier_keys_module, fs::read_to_string(dir.join("verifier_keys.rs")).unwrap());
+    }
+}
@@ -1,34 +1,40 @@
 // Utility functions for serializing zkSNARK components to disk.
-// Includes helpers to save:
-// - Verifying key to ../keys/verifying_key.bin
-// - zkSNARK proof to ../proofs/proof.bin
-// - Public input to ../proofs/public_input.bin
-// - calldata to ../calldata.bin
+// All paths come from the `Workspace` (see workspace.rs). Includes helpers to save:
+// - Proving key to <project>/keys/proving_key.bin
+// - Verifying key to <project>/keys/verifying_key.bin
+// - zkSNARK proof to <project>/proofs/proof.bin
+// - Public input to <project>/proofs/public_input.bin
+// - calldata to <project>/calldata.bin (or an explicit path)
 
 use ark_bn254::{Fr};
 use ark_groth16::{Proof, ProvingKey, VerifyingKey};
-use ark_ff::PrimeField;
 use std::fs::File;
 use std::io::Write;
+use std::path::Path;
 use ark_serialize::CanonicalSerialize;
-use ark_ff::BigInteger;
 
+use crate::error::Result;
+use crate::workspace::Workspace;
 
-pub fn save_proving_key(pk: &ProvingKey<ark_bn254::Bn254>) -> std::io::Result<()> {
-    let mut file = File::create("../keys/proving_key.bin")?;
-    pk.serialize_uncompressed(&mut file)
-        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+/// Sizes of the proof and inputs inside `verify` calldata, defined by `verifier_core`
+pub use verifier_core::{PROOF_LEN as COMPRESSED_PROOF_LEN, PUBLIC_INPUT_LEN};
+
+
+pub fn save_proving_key(workspace: &Workspace, pk: &ProvingKey<ark_bn254::Bn254>) -> Result<()> {
+    workspace.ensure_dirs()?;
+    let mut file = File::create(workspace.proving_key_path())?;
+    pk.serialize_uncompressed(&mut file)?;
     Ok(())
 }
 
-pub fn save_verifying_key(vk: &VerifyingKey<ark_bn254::Bn254>) -> std::io::Result<()> {
-    let out_path = "../keys/verifying_key.bin";
+pub fn save_verifying_key(workspace: &Workspace, vk: &VerifyingKey<ark_bn254::Bn254>) -> Result<()> {
+    workspace.ensure_dirs()?;
+    let out_path = workspace.verifying_key_path();
 
     let mut buf = Vec::new();
-    vk.serialize_uncompressed(&mut buf)
-        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+    vk.serialize_uncompressed(&mut buf)?;
 
-    println!("📦 Saved verifying key ({} bytes) to: {}", buf.len(), out_path);
+    println!("📦 Saved verifying key ({} bytes) to: {}", buf.len(), out_path.display());
 
     let mut file = File::create(out_path)?;
     file.write_all(&buf)?;
@@ -36,28 +42,28 @@ pub fn save_verifying_key(vk: &VerifyingKey<ark_bn254::Bn254>) -> std::io::Resul
 }
 
 
-pub fn save_proof(proof: &Proof<ark_bn254::Bn254>) -> std::io::Result<()> {
+pub fn save_proof(workspace: &Workspace, proof: &Proof<ark_bn254::Bn254>) -> Result<()> {
+    workspace.ensure_dirs()?;
     let mut buf = Vec::new();
-    proof.serialize_compressed(&mut buf)
-        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+    proof.serialize_compressed(&mut buf)?;
 
-    let out_path = "../proofs/proof.bin";
+    let out_path = workspace.proof_path();
     println!("🔍 Compressed proof size: {} bytes", buf.len());
-    println!("📦 Saved proof to: {}", out_path);
+    println!("📦 Saved proof to: {}", out_path.display());
 
     let mut file = File::create(out_path)?;
     file.write_all(&buf)?;
     Ok(())
 }
 
-pub fn save_public_input(c: &Fr) -> std::io::Result<()> {
-    let out_path = "../proofs/public_input.bin";
+pub fn save_public_input(workspace: &Workspace, c: &Fr) -> Result<()> {
+    workspace.ensure_dirs()?;
+    let out_path = workspace.public_input_path();
 
     let mut buf = Vec::new();
-    c.serialize_uncompressed(&mut buf)
-        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+    c.serialize_uncompressed(&mut buf)?;
 
-    println!("📦 Saved public input ({} bytes) to: {}", buf.len(), out_path);
+    println!("📦 Saved public input ({} bytes) to: {}", buf.len(), out_path.display());
 
     let mut file = File::create(out_path)?;
     file.write_all(&buf)?;
@@ -65,54 +71,27 @@ pub fn save_public_input(c: &Fr) -> std::io::Result<()> {
 }
 
 
-fn wrap_serialize_error<E: std::fmt::Display>(err: E) -> std::io::Error {
-    std::io::Error::new(std::io::ErrorKind::Other, format!("{}", err))
+/// Encode `verify(circuitId, proof, inputs)` calldata for the verifier contract
+pub fn encode_calldata(circuit_id: u64, proof: &Proof<ark_bn254::Bn254>, public_inputs: &[Fr]) -> Result<Vec<u8>> {
+    Ok(verifier_core::encode_calldata(circuit_id, proof, public_inputs)?)
 }
 
-pub fn save_calldata<F: PrimeField>(
+pub fn save_calldata(
+    circuit_id: u64,
     proof: &Proof<ark_bn254::Bn254>,
-    public_input: &F,
-    path: &str,
-) -> std::io::Result<()> {
-    let mut buf = Vec::new();
-
-    // 4-byte dummy selector
-    buf.extend_from_slice(&[0u8; 4]);
-
-    // Compress and serialize Groth16 proof
-    let mut proof_bytes = Vec::new();
-    proof.serialize_compressed(&mut proof_bytes).map_err(wrap_serialize_error)?;
-    assert_eq!(proof_bytes.len(), 128);
-    buf.extend_from_slice(&proof_bytes);
-
-    // Serialize public input
-    let bytes = public_input.into_bigint().to_bytes_be();
-    assert_eq!(bytes.len(), 32);
-    buf.extend_from_slice(&bytes);
-
-    // Final length check: 4 (selector) + 128 (proof) + 32 (input) = 164
-    assert_eq!(buf.len(), 164);
-
+    public_inputs: &[Fr],
+    path: impl AsRef<Path>,
+) -> Result<()> {
+    let path = path.as_ref();
+    let buf = encode_calldata(circuit_id, proof, public_inputs)?;
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
     let mut file = File::create(path)?;
     file.write_all(&buf)?;
 
-    println!("📦 Saved calldata ({} bytes) to: {}", buf.len(), path);
-
-    Ok(())
-}
-
+    println!("📦 Saved calldata ({} bytes) to: {}", buf.len(), path.display());
 
-pub fn export_verifying_key_to_rs(
-    vk: &VerifyingKey<ark_bn254::Bn254>
-) -> std::io::Result<()> {
-    let mut buf = Vec::new();
-    vk.serialize_compressed(&mut buf)
-        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
-
-    std::fs::create_dir_all("../keys")?;
-    std::fs::write(
-        "../keys/verifying_key_bytes.rs",
-        format!("pub const VERIFYING_KEY_BYTES: &[u8] = &{:?};", buf),
-    )?;
     Ok(())
 }
@@ -0,0 +1,169 @@
+// Workspace layout for keys, proofs and CLI state.
+// The home directory is resolved from (in order) an explicit `--home`, the
+// NIET2CODE_HOME environment variable, or the XDG data dir
+// ($XDG_DATA_HOME/niet2code, falling back to ~/.local/share/niet2code).
+//
+// Layout:
+//   <home>/                           shared state (trust store, builder stats, Privy state, ...)
+//   <home>/keystore/                  wallets, one Web3 Secret Storage (v3) file per name
+//   <home>/projects/<project>/keys/   proving_key.bin, verifying_key.bin, verifier_keys.rs
+//   <home>/projects/<project>/proofs/ proof.bin, public_input.bin
+//   <home>/projects/<project>/calldata.bin
+//   <home>/projects/<project>/build/  contract.polkavm and its build manifest
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{ProverError, Result};
+
+pub const HOME_ENV: &str = "NIET2CODE_HOME";
+pub const DEFAULT_PROJECT: &str = "default";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Workspace {
+    home: PathBuf,
+    project: String,
+}
+
+impl Workspace {
+    pub fn new(home: impl Into<PathBuf>, project: &str) -> Self {
+        Self {
+            home: home.into(),
+            project: project.to_string(),
+        }
+    }
+
+    /// Resolve the home directory from `--home`, NIET2CODE_HOME or the XDG data dir
+    pub fn resolve(home: Option<&Path>, project: Option<&str>) -> Result<Self> {
+        let home = match home {
+            Some(path) => path.to_path_buf(),
+            None => default_home()?,
+        };
+        let project = project.unwrap_or(DEFAULT_PROJECT);
+        if project.is_empty() || project.contains(['/', '\\']) || project == "." || project == ".." {
+            return Err(ProverError::InvalidInput(format!("invalid project name: {:?}", project)));
+        }
+        Ok(Self::new(home, project))
+    }
+
+    pub fn home(&self) -> &Path {
+        &self.home
+    }
+
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+
+    pub fn project_dir(&self) -> PathBuf {
+        self.home.join("projects").join(&self.project)
+    }
+
+    pub fn keys_dir(&self) -> PathBuf {
+        self.project_dir().join("keys")
+    }
+
+    pub fn proofs_dir(&self) -> PathBuf {
+        self.project_dir().join("proofs")
+    }
+
+    pub fn proving_key_path(&self) -> PathBuf {
+        self.keys_dir().join("proving_key.bin")
+    }
+
+    pub fn verifying_key_path(&self) -> PathBuf {
+        self.keys_dir().join("verifying_key.bin")
+    }
+
+    /// Rust module embedded by `verifier-contract` (see `key_artifact`)
+    pub fn verifier_keys_rs_path(&self) -> PathBuf {
+        self.keys_dir().join("verifier_keys.rs")
+    }
+
+    pub fn proof_path(&self) -> PathBuf {
+        self.proofs_dir().join("proof.bin")
+    }
+
+    pub fn public_input_path(&self) -> PathBuf {
+        self.proofs_dir().join("public_input.bin")
+    }
+
+    pub fn calldata_path(&self) -> PathBuf {
+        self.project_dir().join("calldata.bin")
+    }
+
+    pub fn build_dir(&self) -> PathBuf {
+        self.project_dir().join("build")
+    }
+
+    /// PolkaVM blob written by `verifier build`
+    pub fn verifier_blob_path(&self) -> PathBuf {
+        self.build_dir().join("contract.polkavm")
+    }
+
+    /// Encrypted wallets, shared by all projects
+    pub fn keystore_dir(&self) -> PathBuf {
+        self.home.join("keystore")
+    }
+
+    /// Home-level state file shared by all projects (e.g. `ledger.jsonl`)
+    pub fn state_file(&self, name: &str) -> PathBuf {
+        self.home.join(name)
+    }
+
+    /// Create the home and project directories
+    pub fn ensure_dirs(&self) -> Result<()> {
+        fs::create_dir_all(self.keys_dir())?;
+        fs::create_dir_all(self.proofs_dir())?;
+        Ok(())
+    }
+}
+
+fn default_home() -> Result<PathBuf> {
+    if let Some(home) = non_empty_env(HOME_ENV) {
+        return Ok(PathBuf::from(home));
+    }
+    if let Some(data_home) = non_empty_env("XDG_DATA_HOME") {
+        return Ok(PathBuf::from(data_home).join("niet2code"));
+    }
+    match non_empty_env("HOME") {
+        Some(user_home) => Ok(PathBuf::from(user_home).join(".local/share/niet2code")),
+        None => Err(ProverError::InvalidInput(format!("cannot resolve niet2code home: pass --home or set {}", HOME_ENV))),
+    }
+}
+
+fn non_empty_env(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explicit_home_and_project_layout() {
+        let ws = Workspace::resolve(Some(Path::new("/tmp/n2c")), Some("demo")).unwrap();
+
+        assert_eq!(ws.verifying_key_path(), PathBuf::from("/tmp/n2c/projects/demo/keys/verifying_key.bin"));
+        assert_eq!(ws.proof_path(), PathBuf::from("/tmp/n2c/projects/demo/proofs/proof.bin"));
+        assert_eq!(ws.calldata_path(), PathBuf::from("/tmp/n2c/projects/demo/calldata.bin"));
+        assert_eq!(ws.verifier_blob_path(), PathBuf::from("/tmp/n2c/projects/demo/build/contract.polkavm"));
+        assert_eq!(ws.state_file("builder_stats.json"), PathBuf::from("/tmp/n2c/builder_stats.json"));
+        assert_eq!(ws.keystore_dir(), PathBuf::from("/tmp/n2c/keystore"));
+    }
+
+    #[test]
+    fn test_default_project() {
+        let ws = Workspace::resolve(Some(Path::new("/tmp/n2c")), None).unwrap();
+        assert_eq!(ws.project(), DEFAULT_PROJECT);
+    }
+
+    #[test]
+    fn test_project_names_cannot_escape_home() {
+        for name in ["", "..", "a/b", "a\\b"] {
+            assert!(matches!(
+                Workspace::resolve(Some(Path::new("/tmp/n2c")), Some(name)),
+                Err(ProverError::InvalidInput(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,407 @@
+/*!
+    Builder Registry Contract for PVM - niet2code Builder Edition
+
+    PolkaVM port of the Solidity `niet2codeBuilder` registry from
+    `scripts/deploy_mantle.sh`: builders register an alias, submit proofs and
+    record deployments, and anyone can read per-builder and platform stats.
+
+    ## Highlights:
+
+    - Same selectors, return types, events and `require` revert reasons as the
+      Solidity contract, so existing callers keep working.
+    - `verifyProof` runs the real Groth16 check: it calls the deployed
+      `verifier-contract`'s `verify(circuitId, proof, inputs)` instead of the
+      Solidity `_validateProofStructure` byte scan.
+    - Host functions, the bump allocator and the panic handler come from
+      `contract-host`, shared with `verifier-contract`.
+
+    ## Interface:
+
+    - constructor `(address verifier, uint256 circuitId)`: the verifier contract and
+      the circuit `verifyProof` checks proofs against
+    - `registerBuilder(string aliasName)` (0x90aa2690), 1 to 32 bytes
+    - `verifyProof(bytes proofBytes, bytes32[] publicInputs) returns (bool)` (0xbbcb7c74),
+      registered builders only. `true` if the verifier accepts the proof; `false`
+      if it rejects the proof (`InvalidProof()`, `MalformedProof()`,
+      `NullifierAlreadyUsed()`, ...). Other verifier failures revert with the
+      verifier's revert data.
+    - `recordDeployment(address contractAddr)` (0xceaafa07), registered builders only
+    - `getBuilderStats(address) returns (string, uint256, uint256, uint256, uint256)` (0xd50a5950)
+    - `getPlatformStats() returns (uint256, uint256, uint256)` (0x136d8883)
+    - `getContractInfo() returns (string, string, string)` (0x7cc1f867)
+    - `proofs(bytes32)`, `totalBuilders()`, `totalProofsVerified()`, `totalGasSaved()`:
+      the Solidity public getters
+    - Events: `BuilderRegistered(address indexed, string)`,
+      `ProofVerified(address indexed, bytes32, bool)`, `ContractDeployed(address indexed, address)`
+
+    Storage layout: see `registry.rs`.
+
+    ## Native Tests:
+
+    With `--no-default-features --features native-host` the uapi host is replaced by
+    the in-process mock in `contract-host`, and a stand-in verifier contract checks
+    proofs with `verifier_core::verify_calldata` against the test-vector key (`make test`).
+
+*/
+
+#![cfg_attr(not(feature = "native-host"), no_std)]
+#![cfg_attr(not(feature = "native-host"), no_main)]
+
+extern crate alloc;
+
+mod registry;
+
+use contract_host::ContractHost;
+#[cfg(test)]
+use contract_host::native_host;
+
+//--------------------------------------------------------------------------
+// PolkaVM entry points
+//--------------------------------------------------------------------------
+
+#[no_mangle]
+#[cfg_attr(not(feature = "native-host"), polkavm_derive::polkavm_export)]
+pub extern "C" fn deploy() {
+    handle_deploy();
+}
+
+#[no_mangle]
+#[cfg_attr(not(feature = "native-host"), polkavm_derive::polkavm_export)]
+pub extern "C" fn call() {
+    handle_call();
+}
+
+/// Bodies of `deploy()` / `call()`. Kept as Rust-ABI functions so the native mock
+/// host can unwind out of `return_value` (unwinding through `extern "C"` aborts).
+fn handle_deploy() {
+    let input = contract_host::call_data();
+    if let Err(response) = registry::deploy(&mut ContractHost, &input) {
+        contract_host::return_value(response.revert, &response.data);
+    }
+}
+
+fn handle_call() {
+    let calldata = contract_host::call_data();
+    let response = registry::dispatch(&mut ContractHost, &calldata);
+    contract_host::return_value(response.revert, &response.data);
+}
+
+#[cfg(feature = "native-host")]
+fn main() {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use native_host::{ContractReturn, ReturnFlags};
+    use registry::*;
+    use verifier_core::abi::{self, WORD};
+    use verifier_core::errors::ContractError;
+
+    const CALLDATA: &[u8] = include_bytes!("../../test-vectors/mul/calldata.bin");
+    const VERIFYING_KEY: &[u8] = include_bytes!("../../test-vectors/mul/verifying_key.bin");
+
+    const VERIFIER: [u8; 20] = [0x0f; 20];
+    const BUILDER: [u8; 20] = [0x11; 20];
+    const OTHER: [u8; 20] = [0x22; 20];
+    const CIRCUIT_ID: u64 = 1;
+    const T0: u64 = 1_700_000_000;
+
+    /// Stand-in for verifier-contract: `verify` checked against the test-vector key,
+    /// failures reverting with the verifier's custom errors
+    fn stand_in_verifier(input: &[u8]) -> ContractReturn {
+        match ContractError::from_verdict(&verifier_core::verify_calldata(input, VERIFYING_KEY)) {
+            None => ContractReturn {
+                flags: ReturnFlags::empty(),
+                data: abi::encode_bool(true).to_vec(),
+            },
+            Some(error) => ContractReturn {
+                flags: ReturnFlags::REVERT,
+                data: error.encode(),
+            },
+        }
+    }
+
+    fn constructor_input(verifier: [u8; 20], circuit_id: u64) -> Vec<u8> {
+        let mut input = abi::encode_address(&verifier).to_vec();
+        input.extend_from_slice(&abi::uint_word(circuit_id));
+        input
+    }
+
+    /// Fresh host with the registry deployed against the stand-in verifier
+    fn deploy_registry() {
+        native_host::reset(&constructor_input(VERIFIER, CIRCUIT_ID));
+        native_host::set_timestamp(T0);
+        native_host::set_contract(VERIFIER, stand_in_verifier);
+        assert_eq!(native_host::run(handle_deploy), None);
+    }
+
+    fn call_as(caller: [u8; 20], calldata: &[u8]) -> ContractReturn {
+        native_host::set_caller(caller);
+        native_host::set_call_data(calldata);
+        native_host::run(handle_call).expect("call() did not return a value")
+    }
+
+    fn with_selector(selector: [u8; 4], args: &[u8]) -> Vec<u8> {
+        let mut calldata = selector.to_vec();
+        calldata.extend_from_slice(args);
+        calldata
+    }
+
+    fn register_call(alias: &str) -> Vec<u8> {
+        with_selector(REGISTER_BUILDER_SELECTOR, &abi::encode_string(alias))
+    }
+
+    /// `verifyProof(proofBytes, publicInputs)`
+    fn verify_proof_call(proof: &[u8], inputs: &[[u8; 32]]) -> Vec<u8> {
+        let mut args = abi::uint_word(2 * WORD as u64).to_vec();
+        let inputs_offset = 3 * WORD + proof.len().div_ceil(WORD) * WORD;
+        args.extend_from_slice(&abi::uint_word(inputs_offset as u64));
+        args.extend_from_slice(&abi::encode_bytes_tail(proof));
+        args.extend_from_slice(&abi::uint_word(inputs.len() as u64));
+        for input in inputs {
+            args.extend_from_slice(input);
+        }
+        with_selector(VERIFY_PROOF_SELECTOR, &args)
+    }
+
+    /// The test-vector proof and its public input (12)
+    fn test_vector_proof() -> (Vec<u8>, [u8; 32]) {
+        let args = &CALLDATA[4..];
+        let proof = abi::read_bytes(args, WORD).unwrap().to_vec();
+        let input = abi::read_words(args, 2 * WORD).unwrap().try_into().unwrap();
+        (proof, input)
+    }
+
+    fn stats_of(builder: [u8; 20]) -> (Vec<u8>, [u64; 4]) {
+        let ret = call_as(OTHER, &with_selector(GET_BUILDER_STATS_SELECTOR, &abi::encode_address(&builder)));
+        assert!(!ret.reverted());
+        let word = |i: usize| u64::from_be_bytes(ret.data[i * WORD + 24..(i + 1) * WORD].try_into().unwrap());
+        assert_eq!(word(0), 5 * WORD as u64);
+        let alias = abi::read_bytes(&ret.data, 0).unwrap().to_vec();
+        (alias, [word(1), word(2), word(3), word(4)])
+    }
+
+    fn platform_stats() -> [u64; 3] {
+        let ret = call_as(OTHER, &GET_PLATFORM_STATS_SELECTOR);
+        let word = |i: usize| u64::from_be_bytes(ret.data[i * WORD + 24..(i + 1) * WORD].try_into().unwrap());
+        [word(0), word(1), word(2)]
+    }
+
+    fn reverted_with_reason(ret: &ContractReturn, reason: &str) -> bool {
+        ret.reverted() && ret.data == revert_reason(reason).data
+    }
+
+    #[test]
+    fn test_selectors_and_topics_match_signatures() {
+        let keccak = |signature: &str| ContractHost.keccak_256(signature.as_bytes());
+        for (selector, signature) in [
+            (REGISTER_BUILDER_SELECTOR, "registerBuilder(string)"),
+            (VERIFY_PROOF_SELECTOR, "verifyProof(bytes,bytes32[])"),
+            (RECORD_DEPLOYMENT_SELECTOR, "recordDeployment(address)"),
+            (GET_BUILDER_STATS_SELECTOR, "getBuilderStats(address)"),
+            (GET_PLATFORM_STATS_SELECTOR, "getPlatformStats()"),
+            (GET_CONTRACT_INFO_SELECTOR, "getContractInfo()"),
+            (PROOFS_SELECTOR, "proofs(bytes32)"),
+            (TOTAL_BUILDERS_SELECTOR, "totalBuilders()"),
+            (TOTAL_PROOFS_VERIFIED_SELECTOR, "totalProofsVerified()"),
+            (TOTAL_GAS_SAVED_SELECTOR, "totalGasSaved()"),
+            (ERROR_STRING_SELECTOR, "Error(string)"),
+        ] {
+            assert_eq!(selector, keccak(signature)[..4], "{}", signature);
+        }
+        assert_eq!(BUILDER_REGISTERED_TOPIC, keccak("BuilderRegistered(address,string)"));
+        assert_eq!(PROOF_VERIFIED_TOPIC, keccak("ProofVerified(address,bytes32,bool)"));
+        assert_eq!(CONTRACT_DEPLOYED_TOPIC, keccak("ContractDeployed(address,address)"));
+    }
+
+    #[test]
+    fn test_deploy_requires_a_verifier() {
+        deploy_registry();
+        assert_eq!(read_config(&ContractHost), Some(Config { verifier: VERIFIER, circuit_id: CIRCUIT_ID }));
+
+        native_host::reset(&[]);
+        assert!(native_host::run(handle_deploy).unwrap().reverted());
+        native_host::reset(&constructor_input([0; 20], CIRCUIT_ID));
+        assert!(reverted_with_reason(&native_host::run(handle_deploy).unwrap(), "Invalid verifier address"));
+    }
+
+    #[test]
+    fn test_register_builder() {
+        deploy_registry();
+        assert!(!call_as(BUILDER, &register_call("anon")).reverted());
+        assert_eq!(stats_of(BUILDER), (b"anon".to_vec(), [0, 0, 0, T0]));
+        assert_eq!(platform_stats(), [1, 0, 0]);
+        assert_eq!(
+            native_host::events(),
+            vec![native_host::Event {
+                topics: vec![BUILDER_REGISTERED_TOPIC, abi::encode_address(&BUILDER)],
+                data: abi::encode_string("anon"),
+            }]
+        );
+
+        // Registering again resets the profile but is not a new builder
+        native_host::set_timestamp(T0 + 60);
+        assert!(!call_as(BUILDER, &register_call("renamed")).reverted());
+        assert_eq!(stats_of(BUILDER), (b"renamed".to_vec(), [0, 0, 0, T0 + 60]));
+        assert_eq!(platform_stats(), [1, 0, 0]);
+
+        assert!(reverted_with_reason(&call_as(OTHER, &register_call("")), "aliasName cannot be empty"));
+        assert!(reverted_with_reason(&call_as(OTHER, &register_call(&"x".repeat(33))), "aliasName too long"));
+        assert_eq!(stats_of(OTHER), (Vec::new(), [0, 0, 0, 0]));
+    }
+
+    #[test]
+    fn test_valid_proof_passes_the_verifier() {
+        let (proof, input) = test_vector_proof();
+        deploy_registry();
+        call_as(BUILDER, &register_call("anon"));
+
+        let ret = call_as(BUILDER, &verify_proof_call(&proof, &[input]));
+        assert!(!ret.reverted());
+        assert_eq!(ret.data, abi::encode_bool(true));
+        // Forwarded unchanged, with the configured circuit id
+        assert_eq!(native_host::contract_calls(), vec![(VERIFIER, CALLDATA.to_vec())]);
+
+        assert_eq!(stats_of(BUILDER).1, [1, 0, 10 + 20, T0]);
+        assert_eq!(platform_stats(), [1, 1, GAS_SAVED_PER_PROOF]);
+
+        let mut packed = proof.clone();
+        packed.extend_from_slice(&input);
+        let proof_hash = ContractHost.keccak_256(&packed);
+        let event = native_host::events().pop().unwrap();
+        assert_eq!(event.topics, vec![PROOF_VERIFIED_TOPIC, abi::encode_address(&BUILDER)]);
+        assert_eq!(event.data, [proof_hash, abi::encode_bool(true)].concat());
+
+        let ret = call_as(OTHER, &with_selector(PROOFS_SELECTOR, &proof_hash));
+        assert_eq!(&ret.data[..WORD], proof_hash);
+        assert_eq!(&ret.data[WORD..2 * WORD], abi::encode_address(&BUILDER));
+        assert_eq!(&ret.data[2 * WORD..4 * WORD], [abi::uint_word(T0), abi::encode_bool(true)].concat());
+        assert_eq!(abi::read_bytes(&ret.data, 4 * WORD).unwrap(), NETWORK.as_bytes());
+    }
+
+    #[test]
+    fn test_rejected_proof_counts_as_invalid() {
+        let (mut proof, mut input) = test_vector_proof();
+        deploy_registry();
+        call_as(BUILDER, &register_call("anon"));
+
+        input[31] ^= 0x01;
+        assert_eq!(call_as(BUILDER, &verify_proof_call(&proof, &[input])).data, abi::encode_bool(false));
+        // Nonzero but not a valid proof: the Solidity byte scan would have accepted it
+        proof.iter_mut().for_each(|byte| *byte = 0x01);
+        assert_eq!(call_as(BUILDER, &verify_proof_call(&proof, &[input])).data, abi::encode_bool(false));
+
+        assert_eq!(stats_of(BUILDER).1, [2, 0, 0, T0]);
+        assert_eq!(platform_stats(), [1, 0, 0]);
+        assert_eq!(native_host::events().pop().unwrap().data[WORD..], abi::encode_bool(false));
+    }
+
+    #[test]
+    fn test_verify_proof_requirements() {
+        let (proof, input) = test_vector_proof();
+        deploy_registry();
+        assert!(reverted_with_reason(&call_as(BUILDER, &verify_proof_call(&proof, &[input])), "Builder not registered"));
+
+        call_as(BUILDER, &register_call("anon"));
+        assert!(reverted_with_reason(&call_as(BUILDER, &verify_proof_call(&proof[..127], &[input])), "Invalid proof length"));
+        assert!(reverted_with_reason(&call_as(BUILDER, &verify_proof_call(&proof, &[])), "Public inputs required"));
+        let truncated = verify_proof_call(&proof, &[input]);
+        assert!(call_as(BUILDER, &truncated[..truncated.len() - 1]).data.is_empty());
+        assert!(native_host::contract_calls().is_empty());
+    }
+
+    #[test]
+    fn test_verifier_failures_revert() {
+        let (proof, input) = test_vector_proof();
+        deploy_registry();
+        call_as(BUILDER, &register_call("anon"));
+
+        // Not a proof problem: passed through instead of recorded as invalid
+        native_host::set_contract(VERIFIER, |_| ContractReturn {
+            flags: ReturnFlags::REVERT,
+            data: ContractError::PrecompileFailed.encode(),
+        });
+        let ret = call_as(BUILDER, &verify_proof_call(&proof, &[input]));
+        assert!(ret.reverted());
+        assert_eq!(ContractError::decode(&ret.data), Some(ContractError::PrecompileFailed));
+
+        // No code at the verifier address
+        native_host::set_contract(VERIFIER, |_| ContractReturn {
+            flags: ReturnFlags::empty(),
+            data: Vec::new(),
+        });
+        let ret = call_as(BUILDER, &verify_proof_call(&proof, &[input]));
+        assert!(ret.reverted() && ret.data.is_empty());
+        assert_eq!(stats_of(BUILDER).1, [0, 0, 0, T0]);
+    }
+
+    #[test]
+    fn test_legacy_bool_verifier_is_understood() {
+        let (proof, input) = test_vector_proof();
+        deploy_registry();
+        call_as(BUILDER, &register_call("anon"));
+        native_host::set_contract(VERIFIER, |_| ContractReturn {
+            flags: ReturnFlags::empty(),
+            data: abi::encode_bool(false).to_vec(),
+        });
+        assert_eq!(call_as(BUILDER, &verify_proof_call(&proof, &[input])).data, abi::encode_bool(false));
+    }
+
+    #[test]
+    fn test_record_deployment() {
+        const DEPLOYED: [u8; 20] = [0x33; 20];
+        deploy_registry();
+        let record = with_selector(RECORD_DEPLOYMENT_SELECTOR, &abi::encode_address(&DEPLOYED));
+        assert!(reverted_with_reason(&call_as(BUILDER, &record), "Builder not registered"));
+
+        call_as(BUILDER, &register_call("anon"));
+        let zero = with_selector(RECORD_DEPLOYMENT_SELECTOR, &[0u8; 32]);
+        assert!(reverted_with_reason(&call_as(BUILDER, &zero), "Invalid contract address"));
+
+        let ret = call_as(BUILDER, &record);
+        assert!(!ret.reverted() && ret.data.is_empty());
+        assert_eq!(stats_of(BUILDER).1, [0, 1, 15 + 20, T0]);
+        assert_eq!(
+            native_host::events().pop().unwrap(),
+            native_host::Event {
+                topics: vec![CONTRACT_DEPLOYED_TOPIC, abi::encode_address(&BUILDER)],
+                data: abi::encode_address(&DEPLOYED).to_vec(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_privacy_score_caps_and_decays() {
+        let profile = |proofs, deployments, last_activity| BuilderProfile {
+            proofs_verified: proofs,
+            contracts_deployed: deployments,
+            last_activity,
+            ..BuilderProfile::default()
+        };
+        const DAY: u64 = 86_400;
+        assert_eq!(privacy_score(&profile(1, 0, T0), T0), 30);
+        assert_eq!(privacy_score(&profile(1, 0, T0), T0 + 2 * DAY), 20);
+        assert_eq!(privacy_score(&profile(1, 0, T0), T0 + 8 * DAY), 15);
+        assert_eq!(privacy_score(&profile(1, 0, T0), T0 + 31 * DAY), 10);
+        assert_eq!(privacy_score(&profile(5, 5, T0), T0), MAX_PRIVACY_SCORE);
+    }
+
+    #[test]
+    fn test_views_and_unknown_selector() {
+        deploy_registry();
+        let info = call_as(OTHER, &GET_CONTRACT_INFO_SELECTOR).data;
+        let field = |head: usize| abi::read_bytes(&info, head * WORD).unwrap().to_vec();
+        assert_eq!(field(0), CONTRACT_NAME.as_bytes());
+        assert_eq!(field(1), CONTRACT_VERSION.as_bytes());
+        assert_eq!(field(2), CONTRACT_DESCRIPTION.as_bytes());
+
+        call_as(BUILDER, &register_call("anon"));
+        assert_eq!(call_as(OTHER, &TOTAL_BUILDERS_SELECTOR).data, abi::uint_word(1));
+        assert_eq!(call_as(OTHER, &TOTAL_PROOFS_VERIFIED_SELECTOR).data, abi::uint_word(0));
+        assert_eq!(call_as(OTHER, &TOTAL_GAS_SAVED_SELECTOR).data, abi::uint_word(0));
+
+        let unknown = call_as(OTHER, &[0xde, 0xad, 0xbe, 0xef]);
+        assert!(unknown.reverted() && unknown.data.is_empty());
+        assert!(call_as(OTHER, &[0x13]).reverted());
+    }
+}
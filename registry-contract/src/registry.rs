@@ -0,0 +1,512 @@
+// Builder registry, ported from the Solidity `niet2codeBuilder` contract in
+// `scripts/deploy_mantle.sh`. Selectors, return types, events and revert reasons
+// match it. The difference is `verifyProof`: instead of `_validateProofStructure`
+// (any non-zero byte passed) it forwards the proof to the deployed verifier
+// contract's `verify(circuitId, proof, inputs)`, so only proofs that pass the
+// Groth16 check count.
+//
+// Storage layout (32-byte keys: a tag byte, then the listed fields, zero padded;
+// integers are big-endian u64, the ABI returns them as uint256):
+//
+// | Key                          | Value                                                        |
+// |------------------------------|--------------------------------------------------------------|
+// | `0x01`                       | `Config`: verifier address (20) ‖ circuit id (8)             |
+// | `0x02 ‖ builder (20)`        | `BuilderProfile`: active (1) ‖ proofsVerified (8) ‖          |
+// |                              | contractsDeployed (8) ‖ privacyScore (8) ‖ joinedAt (8) ‖    |
+// |                              | lastActivity (8) ‖ alias length (1) ‖ alias (≤ 32)           |
+// | `0x03 ‖ proofHash[..31]`     | `ProofRecord`: verifier (20) ‖ timestamp (8) ‖ isValid (1)   |
+// | `0x04`                       | `Totals`: builders (8) ‖ proofsVerified (8) ‖ gasSaved (8)   |
+//
+// `Config` is written once by `deploy()`. Missing keys read as zero, like unset
+// Solidity storage.
+
+use alloc::vec::Vec;
+
+use contract_host::{CallError, ContractHost};
+use verifier_core::abi::{self, WORD};
+use verifier_core::dispatch::Response;
+use verifier_core::errors::ContractError;
+use verifier_core::host::{Address, Host};
+
+/// keccak256("registerBuilder(string)")[..4]
+pub const REGISTER_BUILDER_SELECTOR: [u8; 4] = [0x90, 0xaa, 0x26, 0x90];
+/// keccak256("verifyProof(bytes,bytes32[])")[..4]
+pub const VERIFY_PROOF_SELECTOR: [u8; 4] = [0xbb, 0xcb, 0x7c, 0x74];
+/// keccak256("recordDeployment(address)")[..4]
+pub const RECORD_DEPLOYMENT_SELECTOR: [u8; 4] = [0xce, 0xaa, 0xfa, 0x07];
+/// keccak256("getBuilderStats(address)")[..4]
+pub const GET_BUILDER_STATS_SELECTOR: [u8; 4] = [0xd5, 0x0a, 0x59, 0x50];
+/// keccak256("getPlatformStats()")[..4]
+pub const GET_PLATFORM_STATS_SELECTOR: [u8; 4] = [0x13, 0x6d, 0x88, 0x83];
+/// keccak256("getContractInfo()")[..4]
+pub const GET_CONTRACT_INFO_SELECTOR: [u8; 4] = [0x7c, 0xc1, 0xf8, 0x67];
+/// keccak256("proofs(bytes32)")[..4]
+pub const PROOFS_SELECTOR: [u8; 4] = [0x44, 0x4d, 0x95, 0xb0];
+/// keccak256("totalBuilders()")[..4]
+pub const TOTAL_BUILDERS_SELECTOR: [u8; 4] = [0x04, 0xdc, 0xb4, 0x0a];
+/// keccak256("totalProofsVerified()")[..4]
+pub const TOTAL_PROOFS_VERIFIED_SELECTOR: [u8; 4] = [0xd2, 0x46, 0xa2, 0xa3];
+/// keccak256("totalGasSaved()")[..4]
+pub const TOTAL_GAS_SAVED_SELECTOR: [u8; 4] = [0xe3, 0x12, 0x14, 0x8d];
+
+/// keccak256("BuilderRegistered(address,string)")
+pub const BUILDER_REGISTERED_TOPIC: [u8; 32] = [
+    0xa3, 0x0b, 0x1b, 0xc9, 0x03, 0xf2, 0x49, 0x47, 0x03, 0x80, 0xba, 0x4d, 0x18, 0x36, 0xf1, 0x74, 0x3a, 0xf9, 0xea, 0xdf,
+    0x38, 0x31, 0xe4, 0x65, 0x86, 0x03, 0xd8, 0x10, 0x5e, 0xaa, 0x8b, 0x6d,
+];
+/// keccak256("ProofVerified(address,bytes32,bool)")
+pub const PROOF_VERIFIED_TOPIC: [u8; 32] = [
+    0xe8, 0xad, 0x06, 0xfe, 0xcf, 0xe3, 0xc1, 0x3c, 0xc8, 0x49, 0x98, 0x44, 0x9b, 0x19, 0x33, 0x65, 0x45, 0x18, 0xc4, 0xba,
+    0x62, 0x32, 0x2f, 0x6f, 0x5f, 0x07, 0x9c, 0xa0, 0xe8, 0xe8, 0x22, 0x33,
+];
+/// keccak256("ContractDeployed(address,address)")
+pub const CONTRACT_DEPLOYED_TOPIC: [u8; 32] = [
+    0x33, 0xc9, 0x81, 0xba, 0xba, 0x08, 0x1f, 0x8f, 0xd2, 0xc5, 0x2a, 0xc6, 0xad, 0x1e, 0xa9, 0x5b, 0x68, 0x14, 0xb4, 0x37,
+    0x66, 0x40, 0xf5, 0x56, 0x89, 0x05, 0x1f, 0x65, 0x84, 0x72, 0x96, 0x88,
+];
+
+/// keccak256("Error(string)")[..4], the selector of Solidity `require` reasons
+pub const ERROR_STRING_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// Returned by `getContractInfo()`
+pub const CONTRACT_NAME: &str = "niet2codeBuilder";
+pub const CONTRACT_VERSION: &str = "1.0.0";
+pub const CONTRACT_DESCRIPTION: &str = "Anonymous Smart Contract Verification - Cookathon 2025";
+/// `network` field of `proofs(bytes32)`
+pub const NETWORK: &str = "polkavm";
+
+pub const MAX_ALIAS_LEN: usize = 32;
+/// Minimum `proofBytes` length accepted by `verifyProof`
+pub const MIN_PROOF_LEN: usize = 128;
+/// Added to `totalGasSaved` per valid proof
+pub const GAS_SAVED_PER_PROOF: u64 = 75_000;
+pub const MAX_PRIVACY_SCORE: u64 = 100;
+
+const DAY: u64 = 24 * 60 * 60;
+
+const CONFIG_TAG: u8 = 0x01;
+const BUILDER_TAG: u8 = 0x02;
+const PROOF_TAG: u8 = 0x03;
+const TOTALS_TAG: u8 = 0x04;
+
+/// Set by `deploy()`: where `verifyProof` sends proofs, and for which circuit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    pub verifier: Address,
+    pub circuit_id: u64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BuilderProfile {
+    pub alias: Vec<u8>,
+    pub proofs_verified: u64,
+    pub contracts_deployed: u64,
+    pub privacy_score: u64,
+    pub joined_at: u64,
+    pub last_activity: u64,
+    pub active: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProofRecord {
+    pub verifier: Address,
+    pub timestamp: u64,
+    pub valid: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Totals {
+    pub builders: u64,
+    pub proofs_verified: u64,
+    pub gas_saved: u64,
+}
+
+impl Config {
+    const LEN: usize = 20 + 8;
+
+    fn encode(&self) -> [u8; Self::LEN] {
+        let mut out = [0u8; Self::LEN];
+        out[..20].copy_from_slice(&self.verifier);
+        out[20..].copy_from_slice(&self.circuit_id.to_be_bytes());
+        out
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::LEN {
+            return None;
+        }
+        Some(Self {
+            verifier: bytes[..20].try_into().ok()?,
+            circuit_id: u64::from_be_bytes(bytes[20..].try_into().ok()?),
+        })
+    }
+}
+
+impl BuilderProfile {
+    const FIXED_LEN: usize = 1 + 5 * 8 + 1;
+
+    fn encode(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(Self::FIXED_LEN + self.alias.len());
+        out.push(self.active as u8);
+        for value in [self.proofs_verified, self.contracts_deployed, self.privacy_score, self.joined_at, self.last_activity] {
+            out.extend_from_slice(&value.to_be_bytes());
+        }
+        out.push(self.alias.len() as u8);
+        out.extend_from_slice(&self.alias);
+        out
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        let (fixed, alias) = bytes.split_at_checked(Self::FIXED_LEN)?;
+        let word = |i: usize| fixed[1 + 8 * i..9 + 8 * i].try_into().ok().map(u64::from_be_bytes);
+        if alias.len() != fixed[Self::FIXED_LEN - 1] as usize {
+            return None;
+        }
+        Some(Self {
+            alias: alias.to_vec(),
+            proofs_verified: word(0)?,
+            contracts_deployed: word(1)?,
+            privacy_score: word(2)?,
+            joined_at: word(3)?,
+            last_activity: word(4)?,
+            active: fixed[0] == 1,
+        })
+    }
+}
+
+impl ProofRecord {
+    const LEN: usize = 20 + 8 + 1;
+
+    fn encode(&self) -> [u8; Self::LEN] {
+        let mut out = [0u8; Self::LEN];
+        out[..20].copy_from_slice(&self.verifier);
+        out[20..28].copy_from_slice(&self.timestamp.to_be_bytes());
+        out[28] = self.valid as u8;
+        out
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::LEN {
+            return None;
+        }
+        Some(Self {
+            verifier: bytes[..20].try_into().ok()?,
+            timestamp: u64::from_be_bytes(bytes[20..28].try_into().ok()?),
+            valid: bytes[28] == 1,
+        })
+    }
+}
+
+impl Totals {
+    const LEN: usize = 3 * 8;
+
+    fn encode(&self) -> [u8; Self::LEN] {
+        let mut out = [0u8; Self::LEN];
+        out[..8].copy_from_slice(&self.builders.to_be_bytes());
+        out[8..16].copy_from_slice(&self.proofs_verified.to_be_bytes());
+        out[16..].copy_from_slice(&self.gas_saved.to_be_bytes());
+        out
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::LEN {
+            return None;
+        }
+        Some(Self {
+            builders: u64::from_be_bytes(bytes[..8].try_into().ok()?),
+            proofs_verified: u64::from_be_bytes(bytes[8..16].try_into().ok()?),
+            gas_saved: u64::from_be_bytes(bytes[16..].try_into().ok()?),
+        })
+    }
+}
+
+fn storage_key(tag: u8, id: &[u8]) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    key[0] = tag;
+    let n = id.len().min(31);
+    key[1..1 + n].copy_from_slice(&id[..n]);
+    key
+}
+
+pub fn read_config<H: Host>(host: &H) -> Option<Config> {
+    host.get_storage(&storage_key(CONFIG_TAG, &[])).and_then(|bytes| Config::decode(&bytes))
+}
+
+pub fn read_profile<H: Host>(host: &H, builder: &Address) -> BuilderProfile {
+    host.get_storage(&storage_key(BUILDER_TAG, builder))
+        .and_then(|bytes| BuilderProfile::decode(&bytes))
+        .unwrap_or_default()
+}
+
+fn write_profile<H: Host>(host: &mut H, builder: &Address, profile: &BuilderProfile) {
+    host.set_storage(&storage_key(BUILDER_TAG, builder), &profile.encode());
+}
+
+pub fn read_proof<H: Host>(host: &H, proof_hash: &[u8; 32]) -> Option<ProofRecord> {
+    host.get_storage(&storage_key(PROOF_TAG, proof_hash)).and_then(|bytes| ProofRecord::decode(&bytes))
+}
+
+pub fn read_totals<H: Host>(host: &H) -> Totals {
+    host.get_storage(&storage_key(TOTALS_TAG, &[]))
+        .and_then(|bytes| Totals::decode(&bytes))
+        .unwrap_or_default()
+}
+
+fn write_totals<H: Host>(host: &mut H, totals: &Totals) {
+    host.set_storage(&storage_key(TOTALS_TAG, &[]), &totals.encode());
+}
+
+/// `_updatePrivacyScore`: 10 per proof, 15 per deployment, plus an activity
+/// bonus for how recently the builder was active, capped at 100
+pub fn privacy_score(profile: &BuilderProfile, now: u64) -> u64 {
+    let idle = now.saturating_sub(profile.last_activity);
+    let activity_bonus = match idle {
+        _ if idle < DAY => 20,
+        _ if idle < 7 * DAY => 10,
+        _ if idle < 30 * DAY => 5,
+        _ => 0,
+    };
+    let score = profile
+        .proofs_verified
+        .saturating_mul(10)
+        .saturating_add(profile.contracts_deployed.saturating_mul(15))
+        .saturating_add(activity_bonus);
+    score.min(MAX_PRIVACY_SCORE)
+}
+
+/// Body of `deploy()`: the constructor takes `(address verifier, uint256 circuitId)`
+pub fn deploy(host: &mut ContractHost, input: &[u8]) -> Result<(), Response> {
+    let verifier = read_address(input, 0).ok_or_else(revert_empty)?;
+    let circuit_id = abi::read_u64(input, WORD).ok_or_else(|| revert_reason("circuitId out of range"))?;
+    if verifier == [0u8; 20] {
+        return Err(revert_reason("Invalid verifier address"));
+    }
+    host.set_storage(&storage_key(CONFIG_TAG, &[]), &Config { verifier, circuit_id }.encode());
+    Ok(())
+}
+
+/// Route calldata by selector. Like the Solidity contract, unknown selectors and
+/// undecodable arguments revert without data and failed `require`s revert with
+/// `Error(string)`.
+pub fn dispatch(host: &mut ContractHost, calldata: &[u8]) -> Response {
+    let Ok((selector, args)) = abi::split_selector(calldata) else {
+        return revert_empty();
+    };
+    let result = match selector {
+        REGISTER_BUILDER_SELECTOR => register_builder(host, args),
+        VERIFY_PROOF_SELECTOR => verify_proof(host, args),
+        RECORD_DEPLOYMENT_SELECTOR => record_deployment(host, args),
+        GET_BUILDER_STATS_SELECTOR => builder_stats(host, args),
+        GET_PLATFORM_STATS_SELECTOR => {
+            let totals = read_totals(host);
+            Ok(ok(words(&[totals.builders, totals.proofs_verified, totals.gas_saved])))
+        }
+        GET_CONTRACT_INFO_SELECTOR => Ok(ok(encode_strings(&[
+            CONTRACT_NAME.as_bytes(),
+            CONTRACT_VERSION.as_bytes(),
+            CONTRACT_DESCRIPTION.as_bytes(),
+        ]))),
+        PROOFS_SELECTOR => proof_record(host, args),
+        TOTAL_BUILDERS_SELECTOR => Ok(ok(words(&[read_totals(host).builders]))),
+        TOTAL_PROOFS_VERIFIED_SELECTOR => Ok(ok(words(&[read_totals(host).proofs_verified]))),
+        TOTAL_GAS_SAVED_SELECTOR => Ok(ok(words(&[read_totals(host).gas_saved]))),
+        _ => Err(revert_empty()),
+    };
+    result.unwrap_or_else(|response| response)
+}
+
+fn register_builder(host: &mut ContractHost, args: &[u8]) -> Result<Response, Response> {
+    let alias = abi::read_bytes(args, 0).map_err(|_| revert_empty())?;
+    if alias.is_empty() {
+        return Err(revert_reason("aliasName cannot be empty"));
+    }
+    if alias.len() > MAX_ALIAS_LEN {
+        return Err(revert_reason("aliasName too long"));
+    }
+
+    let builder = host.caller();
+    if !read_profile(host, &builder).active {
+        let mut totals = read_totals(host);
+        totals.builders += 1;
+        write_totals(host, &totals);
+    }
+    let now = host.now();
+    let profile = BuilderProfile {
+        alias: alias.to_vec(),
+        joined_at: now,
+        last_activity: now,
+        active: true,
+        ..BuilderProfile::default()
+    };
+    write_profile(host, &builder, &profile);
+
+    let mut data = abi::uint_word(WORD as u64).to_vec();
+    data.extend_from_slice(&abi::encode_bytes_tail(alias));
+    host.deposit_event(&[BUILDER_REGISTERED_TOPIC, abi::encode_address(&builder)], &data);
+    Ok(ok(Vec::new()))
+}
+
+fn verify_proof(host: &mut ContractHost, args: &[u8]) -> Result<Response, Response> {
+    let builder = host.caller();
+    let mut profile = registered(host, &builder)?;
+    let proof = abi::read_bytes(args, 0).map_err(|_| revert_empty())?;
+    let inputs = abi::read_words(args, WORD).map_err(|_| revert_empty())?;
+    if proof.len() < MIN_PROOF_LEN {
+        return Err(revert_reason("Invalid proof length"));
+    }
+    if inputs.is_empty() {
+        return Err(revert_reason("Public inputs required"));
+    }
+
+    // keccak256(abi.encodePacked(proofBytes, publicInputs))
+    let mut packed = Vec::with_capacity(proof.len() + inputs.len());
+    packed.extend_from_slice(proof);
+    packed.extend_from_slice(inputs);
+    let proof_hash = host.keccak_256(&packed);
+
+    let config = read_config(host).ok_or_else(revert_empty)?;
+    let valid = check_with_verifier(host, &config, proof, inputs)?;
+
+    let now = host.now();
+    host.set_storage(
+        &storage_key(PROOF_TAG, &proof_hash),
+        &ProofRecord { verifier: builder, timestamp: now, valid }.encode(),
+    );
+    profile.proofs_verified += 1;
+    profile.last_activity = now;
+    if valid {
+        let mut totals = read_totals(host);
+        totals.proofs_verified += 1;
+        totals.gas_saved += GAS_SAVED_PER_PROOF;
+        write_totals(host, &totals);
+        profile.privacy_score = privacy_score(&profile, now);
+    }
+    write_profile(host, &builder, &profile);
+
+    let mut data = proof_hash.to_vec();
+    data.extend_from_slice(&abi::encode_bool(valid));
+    host.deposit_event(&[PROOF_VERIFIED_TOPIC, abi::encode_address(&builder)], &data);
+    Ok(ok(abi::encode_bool(valid)))
+}
+
+/// Ask the verifier contract. Rejections of the proof itself count as an invalid
+/// proof; anything else (no verifier, a missing precompile, ...) reverts the call,
+/// passing the verifier's revert data through.
+fn check_with_verifier(host: &mut ContractHost, config: &Config, proof: &[u8], inputs: &[u8]) -> Result<bool, Response> {
+    let call = abi::encode_raw_verify_call(config.circuit_id, proof, inputs);
+    match host.call_contract(&config.verifier, &call) {
+        Ok(data) if data == abi::encode_bool(true) => Ok(true),
+        // Verifiers built with `legacy-bool`
+        Ok(data) if data == abi::encode_bool(false) => Ok(false),
+        Ok(_) | Err(CallError::Failed) => Err(revert_empty()),
+        Err(CallError::Reverted(data)) => match ContractError::decode(&data) {
+            Some(
+                ContractError::InvalidProof
+                | ContractError::MalformedProof
+                | ContractError::NonCanonicalPublicInput
+                | ContractError::PublicInputCount { .. }
+                | ContractError::UnknownCircuit
+                | ContractError::DeprecatedKey
+                | ContractError::NullifierAlreadyUsed,
+            ) => Ok(false),
+            _ => Err(Response { revert: true, data }),
+        },
+    }
+}
+
+fn record_deployment(host: &mut ContractHost, args: &[u8]) -> Result<Response, Response> {
+    let builder = host.caller();
+    let mut profile = registered(host, &builder)?;
+    let contract = read_address(args, 0).ok_or_else(revert_empty)?;
+    if contract == [0u8; 20] {
+        return Err(revert_reason("Invalid contract address"));
+    }
+
+    let now = host.now();
+    profile.contracts_deployed += 1;
+    profile.last_activity = now;
+    profile.privacy_score = privacy_score(&profile, now);
+    write_profile(host, &builder, &profile);
+
+    host.deposit_event(&[CONTRACT_DEPLOYED_TOPIC, abi::encode_address(&builder)], &abi::encode_address(&contract));
+    Ok(ok(Vec::new()))
+}
+
+/// `getBuilderStats(address)` → (aliasName, proofsVerified, contractsDeployed, privacyScore, joinedAt)
+fn builder_stats(host: &mut ContractHost, args: &[u8]) -> Result<Response, Response> {
+    let builder = read_address(args, 0).ok_or_else(revert_empty)?;
+    let profile = read_profile(host, &builder);
+    let mut data = words(&[5 * WORD as u64, profile.proofs_verified, profile.contracts_deployed, profile.privacy_score, profile.joined_at]);
+    data.extend_from_slice(&abi::encode_bytes_tail(&profile.alias));
+    Ok(ok(data))
+}
+
+/// `proofs(bytes32)` → (proofHash, verifier, timestamp, isValid, network); zeros if unknown
+fn proof_record(host: &mut ContractHost, args: &[u8]) -> Result<Response, Response> {
+    let proof_hash = abi::decode_bytes32_arg(args).map_err(|_| revert_empty())?;
+    let (hash, record, network) = match read_proof(host, &proof_hash) {
+        Some(record) => (proof_hash, record, NETWORK),
+        None => ([0u8; 32], ProofRecord::default(), ""),
+    };
+
+    let mut data = hash.to_vec();
+    data.extend_from_slice(&abi::encode_address(&record.verifier));
+    data.extend_from_slice(&words(&[record.timestamp, record.valid as u64, 5 * WORD as u64]));
+    data.extend_from_slice(&abi::encode_bytes_tail(network.as_bytes()));
+    Ok(ok(data))
+}
+
+/// `onlyRegisteredBuilder`
+fn registered(host: &ContractHost, builder: &Address) -> Result<BuilderProfile, Response> {
+    let profile = read_profile(host, builder);
+    if !profile.active {
+        return Err(revert_reason("Builder not registered"));
+    }
+    Ok(profile)
+}
+
+/// An `address` word; the 12 high bytes must be zero
+fn read_address(args: &[u8], offset: usize) -> Option<Address> {
+    let word = abi::read_word(args, offset)?;
+    if word[..WORD - 20].iter().any(|&b| b != 0) {
+        return None;
+    }
+    word[WORD - 20..].try_into().ok()
+}
+
+fn words(values: &[u64]) -> Vec<u8> {
+    values.iter().flat_map(|&value| abi::uint_word(value)).collect()
+}
+
+/// ABI encoding of a tuple of `string`s
+fn encode_strings(values: &[&[u8]]) -> Vec<u8> {
+    let mut heads = Vec::with_capacity(values.len() * WORD);
+    let mut tails = Vec::new();
+    for value in values {
+        heads.extend_from_slice(&abi::uint_word((values.len() * WORD + tails.len()) as u64));
+        tails.extend_from_slice(&abi::encode_bytes_tail(value));
+    }
+    heads.extend_from_slice(&tails);
+    heads
+}
+
+fn ok(data: impl Into<Vec<u8>>) -> Response {
+    Response {
+        revert: false,
+        data: data.into(),
+    }
+}
+
+fn revert_empty() -> Response {
+    Response {
+        revert: true,
+        data: Vec::new(),
+    }
+}
+
+/// Revert with `Error(string)`, as a failed Solidity `require(cond, reason)` does
+pub fn revert_reason(reason: &str) -> Response {
+    let mut data = ERROR_STRING_SELECTOR.to_vec();
+    data.extend_from_slice(&abi::encode_string(reason));
+    Response { revert: true, data }
+}
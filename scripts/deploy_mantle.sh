@@ -472,10 +472,11 @@ main() {
     echo "   • Transaction: $(cat deployment.json | grep transactionHash | cut -d'"' -f4)"
     echo ""
     echo "🎯 Next Steps:"
-    echo "   1. cd zk-cli && cargo run -- register --aliasName 'YourName' --network mantle-testnet"
-    echo "   2. cargo run -- prove --a 5 --b 6 --c 30 --network mantle-testnet"
-    echo "   3. cargo run -- submit-proof --network mantle-testnet"
-    echo "   4. cargo run -- dashboard --network mantle-testnet"
+    echo "   1. cargo run -p niet2code-cli -- import-broadcast broadcast/Deploy.s.sol/$CHAIN_ID/run-latest.json --network mantle-$NETWORK"
+    echo "   2. cargo run -p niet2code-cli -- register --alias 'YourName' --network mantle-$NETWORK"
+    echo "   3. cargo run -p niet2code-cli -- prove --a 5 --b 6 --c 30 --network mantle-$NETWORK"
+    echo "   4. cargo run -p niet2code-cli -- submit-proof --network mantle-$NETWORK"
+    echo "   5. cargo run -p niet2code-cli -- dashboard --network mantle-$NETWORK"
     echo ""
     echo "🚀 Ready for partner integrations!"
 }
@@ -0,0 +1,2317 @@
+// Auto-generated by prover::key_artifact. Do not edit.
+pub const VERIFYING_KEY_ENCODING: &str = "prepared";
+pub const VERIFYING_KEY_COMPRESSED: bool = false;
+pub const VERIFYING_KEY_PREPARED: bool = true;
+
+pub mod circuit_1 {
+    pub const CIRCUIT_ID: u64 = 1;
+    pub const CIRCUIT_NAME: &str = "mul";
+    pub const CIRCUIT_VERSION: u32 = 1;
+    pub const NULLIFIER: super::NullifierMode = super::NullifierMode::Off;
+    pub const VERIFYING_KEY_HASH: [u8; 32] = [
+        0x58, 0x88, 0x6c, 0xa9, 0x20, 0x75, 0x2e, 0x44, 0x6b, 0xc7, 0x92, 0x10, 0xf0, 0xd3, 0xeb, 0xef,
+        0xb0, 0x2b, 0x06, 0x4e, 0x46, 0x18, 0xb4, 0x86, 0x3f, 0x12, 0x98, 0xee, 0xe8, 0xe2, 0xb8, 0x9f,
+    ];
+    pub const VERIFYING_KEY_EVM: &[u8] = &[
+        0x17, 0x3f, 0xe5, 0xd5, 0xff, 0xd1, 0x36, 0x68, 0x60, 0x04, 0xe7, 0x11, 0xd8, 0x74, 0xfb, 0xb2,
+        0x53, 0x03, 0x8e, 0xc7, 0xcc, 0xfb, 0xae, 0xc4, 0xad, 0xdd, 0xe5, 0x21, 0x75, 0xde, 0x39, 0x9a,
+        0x19, 0x60, 0x23, 0xea, 0xe3, 0x0d, 0xcc, 0x46, 0x73, 0x35, 0x2f, 0x21, 0x40, 0x86, 0xe1, 0xc3,
+        0xf3, 0xc2, 0x8d, 0x48, 0xa3, 0xfa, 0xee, 0x9a, 0x1e, 0x4f, 0xef, 0x49, 0x73, 0x30, 0x37, 0x29,
+        0x11, 0x94, 0x35, 0x87, 0xd6, 0x4f, 0xdf, 0x50, 0x0b, 0xd6, 0xb7, 0xb2, 0x22, 0xbf, 0x2d, 0xe6,
+        0x9e, 0xaa, 0x5f, 0x55, 0xaa, 0x84, 0x69, 0x19, 0x4b, 0x01, 0x55, 0x3c, 0x3c, 0x55, 0x59, 0x76,
+        0x1e, 0x50, 0xa8, 0xaa, 0x90, 0x14, 0xf5, 0xa4, 0x9d, 0xf8, 0x0b, 0x63, 0xeb, 0x9e, 0x84, 0x15,
+        0x1e, 0x53, 0xae, 0x8b, 0x6f, 0x37, 0xe0, 0xbd, 0x9b, 0xf6, 0xed, 0x4f, 0xaa, 0x62, 0x6d, 0x70,
+        0x02, 0xc1, 0xfd, 0x82, 0xd8, 0x66, 0x61, 0x17, 0xe9, 0xf7, 0x47, 0xf3, 0x75, 0xf8, 0x5e, 0x67,
+        0xc2, 0x4d, 0x61, 0xb1, 0xf0, 0xf3, 0x8a, 0x1a, 0x6b, 0x2a, 0x2a, 0xaf, 0xfe, 0x23, 0xb5, 0xfe,
+        0x29, 0xd9, 0x1f, 0x59, 0x92, 0x45, 0x94, 0xe7, 0xcd, 0x89, 0xd3, 0xce, 0xeb, 0x38, 0x2b, 0x85,
+        0xe1, 0x7e, 0x2a, 0xa1, 0xec, 0xa1, 0x40, 0x3b, 0xb3, 0x4a, 0xd2, 0x9e, 0x96, 0x4c, 0xfe, 0xeb,
+        0x16, 0x02, 0x63, 0x6b, 0x37, 0x0e, 0xea, 0xc7, 0x0c, 0x77, 0x06, 0x08, 0xb0, 0xfa, 0x8b, 0x58,
+        0x5e, 0xe0, 0x15, 0xf6, 0x99, 0xd7, 0x0d, 0x6e, 0xf9, 0x39, 0xc5, 0x10, 0xfe, 0x7e, 0x62, 0xce,
+        0x11, 0xca, 0xe6, 0xa9, 0xc7, 0xf1, 0x27, 0xbf, 0x50, 0x26, 0x28, 0x73, 0x02, 0x29, 0x18, 0xc1,
+        0x81, 0x59, 0xe9, 0xca, 0x43, 0xb8, 0xd1, 0x47, 0x8e, 0xb9, 0xf8, 0x7f, 0xa4, 0x79, 0x69, 0x01,
+        0x2f, 0x5e, 0x21, 0x0e, 0xbd, 0x2b, 0x02, 0x22, 0x44, 0xbb, 0x0f, 0xea, 0xfc, 0x32, 0x52, 0x2e,
+        0xcd, 0xab, 0xb7, 0x08, 0x4b, 0x58, 0x24, 0x91, 0x90, 0xfa, 0xf4, 0x93, 0x47, 0x94, 0x01, 0xcb,
+        0x00, 0xa3, 0xb1, 0x4e, 0x3d, 0x81, 0x44, 0xfc, 0xcf, 0xdc, 0x59, 0x59, 0x62, 0x84, 0x93, 0xe3,
+        0xe2, 0xb9, 0xb2, 0xe4, 0x11, 0x65, 0xc6, 0xdc, 0xcb, 0x08, 0x69, 0x20, 0x1e, 0xac, 0xd3, 0xe0,
+        0x26, 0x10, 0x33, 0xe0, 0x7e, 0xdc, 0x0b, 0xbf, 0x8a, 0xfc, 0xc7, 0x42, 0x7a, 0xb2, 0x0a, 0x91,
+        0x90, 0x88, 0x7e, 0xa3, 0x30, 0x1d, 0xcf, 0xf6, 0xc6, 0x79, 0x52, 0xe3, 0xe3, 0x44, 0x5a, 0x12,
+        0x29, 0xd1, 0xe2, 0x3e, 0x73, 0xb1, 0x9e, 0x2f, 0x8d, 0x38, 0x3a, 0x48, 0xfa, 0x41, 0xf6, 0x07,
+        0xc5, 0x5e, 0x98, 0x9f, 0xcd, 0xf8, 0x95, 0x92, 0xd8, 0xd3, 0x63, 0xe3, 0x97, 0x96, 0x37, 0xa4,
+        0x1f, 0x5a, 0xa8, 0x05, 0x90, 0xbc, 0xc1, 0x66, 0x8e, 0xe7, 0xb0, 0xa5, 0x1f, 0x4e, 0x45, 0x28,
+        0x3b, 0x04, 0xbf, 0x30, 0xf0, 0xa0, 0x4f, 0x42, 0xaa, 0x44, 0x6a, 0x0b, 0x7c, 0xeb, 0x75, 0x4a,
+        0x0f, 0x5d, 0x69, 0xb9, 0xd8, 0xf2, 0x97, 0x9e, 0x87, 0x46, 0xbd, 0x91, 0xbf, 0x57, 0x95, 0xad,
+        0x09, 0xed, 0x09, 0x81, 0xe9, 0x0b, 0x13, 0xef, 0x55, 0x0b, 0x3a, 0x2a, 0x45, 0xf6, 0xb5, 0x76,
+        0x2a, 0xe8, 0xe2, 0x69, 0x3c, 0x6b, 0x59, 0x40, 0x00, 0x32, 0xf8, 0xea, 0x54, 0x8d, 0xb5, 0xfb,
+        0x68, 0xad, 0x39, 0x67, 0xed, 0xdb, 0x59, 0xbc, 0x2d, 0x39, 0x19, 0x4b, 0x29, 0x8c, 0xbd, 0x53,
+        0x02, 0x5e, 0x1e, 0x21, 0xbf, 0x05, 0xee, 0x58, 0xa8, 0x2f, 0xbf, 0xc4, 0xce, 0xac, 0x9b, 0x7c,
+        0x71, 0x61, 0x3e, 0x6c, 0x43, 0xd9, 0x16, 0x1a, 0xbc, 0x11, 0xa2, 0xdc, 0xf0, 0xe2, 0x4f, 0x7d,
+        0x05, 0xeb, 0x01, 0xae, 0x1f, 0xe3, 0xb3, 0x67, 0x67, 0xe3, 0xbd, 0x6b, 0x4a, 0x2a, 0xda, 0x96,
+        0x25, 0xc4, 0x8b, 0x1c, 0x52, 0xa2, 0x14, 0x16, 0x05, 0x3e, 0xec, 0x73, 0x69, 0x07, 0x3b, 0x15,
+        0x1e, 0xdd, 0x3c, 0xf4, 0x6a, 0x0a, 0xfe, 0xde, 0xf2, 0x1f, 0xf3, 0x8f, 0xd9, 0x81, 0xb9, 0x8b,
+        0x53, 0xa8, 0xd3, 0x8d, 0xfa, 0x95, 0x33, 0x88, 0xfb, 0x14, 0x62, 0x68, 0x37, 0x63, 0x14, 0xd4,
+    ];
+    pub const VERIFYING_KEY_ENCODING: &str = "prepared";
+    pub const VERIFYING_KEY_COMPRESSED: bool = false;
+    pub const VERIFYING_KEY_PREPARED: bool = true;
+    pub const VERIFYING_KEY_LEN: usize = 35930;
+    pub const VERIFYING_KEY_FINGERPRINT: &str = "sha256:58886ca920752e446bc79210f0d3ebefb02b064e4618b4863f1298eee8e2b89f";
+    pub const VERIFYING_KEY_BYTES: &[u8] = &[
+        0x9a, 0x39, 0xde, 0x75, 0x21, 0xe5, 0xdd, 0xad, 0xc4, 0xae, 0xfb, 0xcc, 0xc7, 0x8e, 0x03, 0x53,
+        0xb2, 0xfb, 0x74, 0xd8, 0x11, 0xe7, 0x04, 0x60, 0x68, 0x36, 0xd1, 0xff, 0xd5, 0xe5, 0x3f, 0x17,
+        0x29, 0x37, 0x30, 0x73, 0x49, 0xef, 0x4f, 0x1e, 0x9a, 0xee, 0xfa, 0xa3, 0x48, 0x8d, 0xc2, 0xf3,
+        0xc3, 0xe1, 0x86, 0x40, 0x21, 0x2f, 0x35, 0x73, 0x46, 0xcc, 0x0d, 0xe3, 0xea, 0x23, 0x60, 0x99,
+        0x70, 0x6d, 0x62, 0xaa, 0x4f, 0xed, 0xf6, 0x9b, 0xbd, 0xe0, 0x37, 0x6f, 0x8b, 0xae, 0x53, 0x1e,
+        0x15, 0x84, 0x9e, 0xeb, 0x63, 0x0b, 0xf8, 0x9d, 0xa4, 0xf5, 0x14, 0x90, 0xaa, 0xa8, 0x50, 0x1e,
+        0x76, 0x59, 0x55, 0x3c, 0x3c, 0x55, 0x01, 0x4b, 0x19, 0x69, 0x84, 0xaa, 0x55, 0x5f, 0xaa, 0x9e,
+        0xe6, 0x2d, 0xbf, 0x22, 0xb2, 0xb7, 0xd6, 0x0b, 0x50, 0xdf, 0x4f, 0xd6, 0x87, 0x35, 0x94, 0x11,
+        0xeb, 0xfe, 0x4c, 0x96, 0x9e, 0xd2, 0x4a, 0xb3, 0x3b, 0x40, 0xa1, 0xec, 0xa1, 0x2a, 0x7e, 0xe1,
+        0x85, 0x2b, 0x38, 0xeb, 0xce, 0xd3, 0x89, 0xcd, 0xe7, 0x94, 0x45, 0x92, 0x59, 0x1f, 0xd9, 0x29,
+        0xfe, 0xb5, 0x23, 0xfe, 0xaf, 0x2a, 0x2a, 0x6b, 0x1a, 0x8a, 0xf3, 0xf0, 0xb1, 0x61, 0x4d, 0xc2,
+        0x67, 0x5e, 0xf8, 0x75, 0xf3, 0x47, 0xf7, 0xe9, 0x17, 0x61, 0x66, 0xd8, 0x82, 0xfd, 0xc1, 0x02,
+        0x01, 0x69, 0x79, 0xa4, 0x7f, 0xf8, 0xb9, 0x8e, 0x47, 0xd1, 0xb8, 0x43, 0xca, 0xe9, 0x59, 0x81,
+        0xc1, 0x18, 0x29, 0x02, 0x73, 0x28, 0x26, 0x50, 0xbf, 0x27, 0xf1, 0xc7, 0xa9, 0xe6, 0xca, 0x11,
+        0xce, 0x62, 0x7e, 0xfe, 0x10, 0xc5, 0x39, 0xf9, 0x6e, 0x0d, 0xd7, 0x99, 0xf6, 0x15, 0xe0, 0x5e,
+        0x58, 0x8b, 0xfa, 0xb0, 0x08, 0x06, 0x77, 0x0c, 0xc7, 0xea, 0x0e, 0x37, 0x6b, 0x63, 0x02, 0x16,
+        0xe0, 0xd3, 0xac, 0x1e, 0x20, 0x69, 0x08, 0xcb, 0xdc, 0xc6, 0x65, 0x11, 0xe4, 0xb2, 0xb9, 0xe2,
+        0xe3, 0x93, 0x84, 0x62, 0x59, 0x59, 0xdc, 0xcf, 0xfc, 0x44, 0x81, 0x3d, 0x4e, 0xb1, 0xa3, 0x00,
+        0xcb, 0x01, 0x94, 0x47, 0x93, 0xf4, 0xfa, 0x90, 0x91, 0x24, 0x58, 0x4b, 0x08, 0xb7, 0xab, 0xcd,
+        0x2e, 0x52, 0x32, 0xfc, 0xea, 0x0f, 0xbb, 0x44, 0x22, 0x02, 0x2b, 0xbd, 0x0e, 0x21, 0x5e, 0xaf,
+        0xa4, 0x37, 0x96, 0x97, 0xe3, 0x63, 0xd3, 0xd8, 0x92, 0x95, 0xf8, 0xcd, 0x9f, 0x98, 0x5e, 0xc5,
+        0x07, 0xf6, 0x41, 0xfa, 0x48, 0x3a, 0x38, 0x8d, 0x2f, 0x9e, 0xb1, 0x73, 0x3e, 0xe2, 0xd1, 0x29,
+        0x12, 0x5a, 0x44, 0xe3, 0xe3, 0x52, 0x79, 0xc6, 0xf6, 0xcf, 0x1d, 0x30, 0xa3, 0x7e, 0x88, 0x90,
+        0x91, 0x0a, 0xb2, 0x7a, 0x42, 0xc7, 0xfc, 0x8a, 0xbf, 0x0b, 0xdc, 0x7e, 0xe0, 0x33, 0x10, 0x26,
+        0x76, 0xb5, 0xf6, 0x45, 0x2a, 0x3a, 0x0b, 0x55, 0xef, 0x13, 0x0b, 0xe9, 0x81, 0x09, 0xed, 0x09,
+        0xad, 0x95, 0x57, 0xbf, 0x91, 0xbd, 0x46, 0x87, 0x9e, 0x97, 0xf2, 0xd8, 0xb9, 0x69, 0x5d, 0x0f,
+        0x4a, 0x75, 0xeb, 0x7c, 0x0b, 0x6a, 0x44, 0xaa, 0x42, 0x4f, 0xa0, 0xf0, 0x30, 0xbf, 0x04, 0x3b,
+        0x28, 0x45, 0x4e, 0x1f, 0xa5, 0xb0, 0xe7, 0x8e, 0x66, 0xc1, 0xbc, 0x90, 0x05, 0xa8, 0x5a, 0x9f,
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x53, 0xbd, 0x8c, 0x29, 0x4b, 0x19, 0x39, 0x2d,
+        0xbc, 0x59, 0xdb, 0xed, 0x67, 0x39, 0xad, 0x68, 0xfb, 0xb5, 0x8d, 0x54, 0xea, 0xf8, 0x32, 0x00,
+        0x40, 0x59, 0x6b, 0x3c, 0x69, 0xe2, 0xe8, 0x2a, 0x7d, 0x4f, 0xe2, 0xf0, 0xdc, 0xa2, 0x11, 0xbc,
+        0x1a, 0x16, 0xd9, 0x43, 0x6c, 0x3e, 0x61, 0x71, 0x7c, 0x9b, 0xac, 0xce, 0xc4, 0xbf, 0x2f, 0xa8,
+        0x58, 0xee, 0x05, 0xbf, 0x21, 0x1e, 0x5e, 0x02, 0x15, 0x3b, 0x07, 0x69, 0x73, 0xec, 0x3e, 0x05,
+        0x16, 0x14, 0xa2, 0x52, 0x1c, 0x8b, 0xc4, 0x25, 0x96, 0xda, 0x2a, 0x4a, 0x6b, 0xbd, 0xe3, 0x67,
+        0x67, 0xb3, 0xe3, 0x1f, 0xae, 0x01, 0xeb, 0x05, 0xd4, 0x14, 0x63, 0x37, 0x68, 0x62, 0x14, 0xfb,
+        0x88, 0x33, 0x95, 0xfa, 0x8d, 0xd3, 0xa8, 0x53, 0x8b, 0xb9, 0x81, 0xd9, 0x8f, 0xf3, 0x1f, 0xf2,
+        0xde, 0xfe, 0x0a, 0x6a, 0xf4, 0x3c, 0xdd, 0x9e, 0x24, 0x25, 0xa5, 0xc2, 0x37, 0xeb, 0xa2, 0xca,
+        0xb4, 0x22, 0x76, 0xf8, 0x45, 0xae, 0x8d, 0xeb, 0x2a, 0x2b, 0x39, 0xd5, 0xac, 0x48, 0x7f, 0xea,
+        0x2f, 0xf3, 0x6c, 0x00, 0x22, 0x21, 0x16, 0x0e, 0x10, 0x7a, 0x85, 0xe4, 0xf7, 0xd2, 0xa8, 0x8f,
+        0x86, 0x3a, 0x5b, 0x15, 0xfa, 0x5c, 0x5f, 0x20, 0xf4, 0x74, 0x5b, 0x33, 0xd8, 0x66, 0x21, 0x70,
+        0xdc, 0x87, 0x53, 0xc9, 0x6d, 0x85, 0xf1, 0x2f, 0xf4, 0xf2, 0x20, 0x1f, 0xc9, 0x7b, 0xa6, 0xd0,
+        0xbb, 0xf5, 0x8a, 0x05, 0xba, 0x6a, 0x85, 0x47, 0xe8, 0x16, 0x7d, 0x08, 0x3e, 0xba, 0xcb, 0x20,
+        0x57, 0xaa, 0x83, 0x1a, 0x4e, 0xdd, 0xea, 0x1d, 0xe0, 0x9a, 0x14, 0x80, 0xb2, 0xa5, 0x91, 0x3a,
+        0x5d, 0x23, 0x4d, 0xd1, 0x2d, 0x9b, 0x90, 0x77, 0x96, 0xfd, 0xc1, 0x9d, 0x68, 0x52, 0x3d, 0x7d,
+        0x46, 0x52, 0xdb, 0x74, 0x41, 0xce, 0x52, 0x28, 0x60, 0xfe, 0x7c, 0x2e, 0xed, 0x57, 0x5a, 0x7b,
+        0x55, 0x68, 0x76, 0x8d, 0x24, 0x3d, 0x85, 0x33, 0x5a, 0xfc, 0xb1, 0xb6, 0x83, 0xeb, 0xb7, 0x0a,
+        0x56, 0x01, 0x00, 0xa0, 0xd3, 0x6c, 0x7b, 0x13, 0xfc, 0x3d, 0x7c, 0x2c, 0x36, 0x24, 0x3e, 0x9a,
+        0x39, 0x26, 0xbc, 0x74, 0xdf, 0xc5, 0x72, 0x4b, 0x81, 0x24, 0x0d, 0x0b, 0xee, 0x53, 0x41, 0x32,
+        0xd4, 0xdd, 0xb8, 0x5d, 0x8a, 0xbd, 0x29, 0x04, 0x24, 0xaf, 0x90, 0x5e, 0xdd, 0x37, 0x2a, 0x8b,
+        0xf0, 0x84, 0x34, 0x3b, 0x98, 0x1e, 0x39, 0xd9, 0x86, 0x03, 0x1c, 0xdb, 0x7e, 0x7b, 0xd0, 0xaa,
+        0xfb, 0x14, 0x43, 0x82, 0xe8, 0x73, 0xdd, 0x13, 0x0c, 0x76, 0x3e, 0x5e, 0xe9, 0x2d, 0xea, 0xa6,
+        0x48, 0xa2, 0x65, 0xb2, 0xc0, 0x95, 0xb2, 0x7f, 0x24, 0x3e, 0x19, 0x3c, 0x5d, 0x33, 0x7b, 0x35,
+        0x1a, 0x43, 0x36, 0xb3, 0xf3, 0xe1, 0x29, 0x17, 0x85, 0x87, 0x59, 0xc8, 0xd3, 0x5d, 0x22, 0x87,
+        0x12, 0x41, 0x7f, 0x9e, 0xbb, 0x32, 0x85, 0xf4, 0x87, 0x13, 0xec, 0xc8, 0xd8, 0x90, 0x2a, 0xa2,
+        0x9c, 0x41, 0xc8, 0x01, 0xf2, 0xc7, 0x9f, 0x27, 0xc8, 0xad, 0x7f, 0x30, 0xc9, 0xf9, 0x7d, 0x68,
+        0xed, 0x57, 0x7a, 0x80, 0x35, 0x48, 0x7e, 0xd3, 0x61, 0x7c, 0xab, 0x8b, 0x25, 0xbc, 0xf9, 0x2e,
+        0x75, 0x63, 0x36, 0xe0, 0x43, 0xc1, 0x93, 0x1a, 0x34, 0x3f, 0x6d, 0x6b, 0x7c, 0x9b, 0x09, 0xd1,
+        0x1d, 0xa2, 0xdb, 0x3c, 0x7c, 0x0a, 0xb3, 0xa0, 0x15, 0xfe, 0x99, 0x57, 0x22, 0x6d, 0x20, 0xfc,
+        0xc7, 0x56, 0x33, 0x10, 0x6d, 0xb4, 0xe7, 0x0e, 0x35, 0xac, 0x70, 0xf3, 0xbd, 0x2d, 0xba, 0x47,
+        0x30, 0xac, 0x88, 0xb5, 0x35, 0x24, 0xa8, 0x4a, 0xde, 0x21, 0xb9, 0x67, 0x27, 0x36, 0xf8, 0x7c,
+        0x17, 0x79, 0x15, 0xaa, 0x01, 0x68, 0xaa, 0x13, 0x5b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xc0, 0xa7, 0x59, 0x3d, 0x40, 0xd2, 0x10, 0x96, 0xb9, 0x8d, 0xcb, 0x22, 0xc8, 0x65, 0x73, 0xc5,
+        0xc7, 0x27, 0x09, 0xc5, 0xb2, 0xb2, 0xb8, 0x9f, 0xf9, 0x89, 0x02, 0x7b, 0x9c, 0x62, 0x47, 0x01,
+        0x4f, 0x06, 0xab, 0xb6, 0x0f, 0x5d, 0xd5, 0xe5, 0x95, 0x7e, 0x3e, 0x2e, 0x7f, 0x03, 0xd6, 0x03,
+        0x00, 0x4c, 0xe3, 0x76, 0x1f, 0xda, 0x25, 0xd1, 0x1a, 0x64, 0x24, 0x99, 0xaa, 0xf3, 0x57, 0x2e,
+        0xd5, 0x7e, 0x28, 0x4f, 0x86, 0x6e, 0x4d, 0x3a, 0x9d, 0xa2, 0xd5, 0xe4, 0x9a, 0xc7, 0x2a, 0xbe,
+        0x58, 0x74, 0xe2, 0x48, 0x26, 0x02, 0x3c, 0xc6, 0x88, 0x4d, 0x29, 0xda, 0x62, 0x42, 0xc8, 0x05,
+        0x3d, 0xcc, 0xad, 0xd1, 0x66, 0xe1, 0x13, 0x0c, 0xda, 0xe4, 0xcd, 0x5c, 0x56, 0x43, 0xbf, 0xcc,
+        0xbc, 0x34, 0x6b, 0xcb, 0xdb, 0x0f, 0x58, 0x5c, 0xb1, 0xa9, 0xf4, 0xa9, 0x52, 0xbb, 0xb4, 0x00,
+        0x88, 0x97, 0xd2, 0x79, 0xb1, 0xf5, 0x82, 0x7d, 0xe6, 0xa7, 0x58, 0xb5, 0x41, 0x10, 0x4f, 0x87,
+        0x1e, 0x3d, 0x02, 0x5a, 0x71, 0xc4, 0x4c, 0x81, 0x23, 0x5a, 0x72, 0x7a, 0x7b, 0x2c, 0x28, 0x06,
+        0x7d, 0x8f, 0xbb, 0xbf, 0xbd, 0xb2, 0xa6, 0x0c, 0xd7, 0x50, 0xf8, 0x95, 0x50, 0x76, 0x6d, 0x1b,
+        0x90, 0x13, 0x5b, 0x3b, 0xa1, 0x68, 0x1f, 0xb0, 0x3c, 0x4d, 0xcc, 0xab, 0xba, 0x1a, 0xe7, 0x06,
+        0xaa, 0xd7, 0x60, 0xdd, 0xa3, 0x6b, 0x65, 0x1c, 0xb1, 0xab, 0xcb, 0x21, 0x90, 0x7e, 0xb1, 0xa3,
+        0x50, 0xc6, 0x69, 0x98, 0x51, 0x0e, 0xad, 0x3e, 0x23, 0xc9, 0x16, 0x01, 0x34, 0xfb, 0x8c, 0x24,
+        0x64, 0x5e, 0x15, 0x54, 0x34, 0x20, 0xd0, 0xbb, 0xe5, 0x18, 0x79, 0xd9, 0x18, 0x41, 0x85, 0xdd,
+        0x3c, 0xbc, 0x5a, 0xc0, 0x61, 0x7e, 0x44, 0x45, 0xee, 0xb9, 0x68, 0xc3, 0x1e, 0xb8, 0x5a, 0x22,
+        0xe1, 0x46, 0x38, 0x12, 0x32, 0xd8, 0x4a, 0x96, 0xad, 0x52, 0x71, 0x50, 0xaa, 0x4c, 0xe5, 0xa3,
+        0x24, 0x23, 0xa1, 0x8e, 0xfc, 0xd5, 0x37, 0x3f, 0x58, 0xdd, 0x30, 0x84, 0xfe, 0xb5, 0xeb, 0x08,
+        0x47, 0xf2, 0xba, 0x63, 0x60, 0x0b, 0x87, 0x04, 0xf2, 0x72, 0xc2, 0x6a, 0x58, 0x5b, 0xf2, 0x8c,
+        0x06, 0xb4, 0x9d, 0xf9, 0x6f, 0xef, 0x74, 0xcb, 0x18, 0x69, 0xcf, 0x1d, 0xe8, 0x82, 0x73, 0x2f,
+        0xb2, 0x4a, 0x26, 0x0c, 0x9b, 0xfa, 0x5b, 0xd8, 0x4d, 0x67, 0x28, 0x87, 0x03, 0x1a, 0x06, 0x8b,
+        0x56, 0x6b, 0xde, 0x7f, 0xe9, 0x10, 0xa9, 0x07, 0xaa, 0x2e, 0x01, 0x2b, 0x92, 0x51, 0xb9, 0x24,
+        0xb4, 0x1b, 0x6f, 0xb7, 0x3a, 0x92, 0x33, 0xb3, 0x9c, 0x8e, 0x54, 0x65, 0x0f, 0x98, 0x40, 0x56,
+        0xe9, 0x96, 0xb9, 0x04, 0x7d, 0xa1, 0xbd, 0x87, 0x06, 0x69, 0xda, 0xe4, 0xd3, 0x1f, 0x18, 0x26,
+        0x31, 0xbd, 0xa2, 0xf2, 0xd1, 0xb5, 0xeb, 0x69, 0xd3, 0x71, 0x59, 0xe6, 0x3e, 0xa8, 0xb3, 0xd3,
+        0x30, 0xa6, 0xa7, 0x7b, 0xa7, 0x39, 0xe8, 0x3e, 0x56, 0x29, 0x00, 0x15, 0xea, 0x5c, 0x02, 0x0e,
+        0xfd, 0xa1, 0xa9, 0x5c, 0x91, 0xe1, 0xf5, 0x8e, 0xc9, 0x7a, 0xb8, 0x5a, 0x59, 0x61, 0x0f, 0x12,
+        0xe0, 0x72, 0x35, 0xa4, 0x76, 0x14, 0xc6, 0x06, 0xe9, 0xd0, 0xeb, 0xf8, 0xc6, 0xb8, 0x1e, 0x1c,
+        0x74, 0x82, 0xc9, 0xd0, 0xd9, 0x58, 0x94, 0x02, 0x73, 0xb5, 0x6e, 0x47, 0xcd, 0x2e, 0xb9, 0x14,
+        0x93, 0xa6, 0x7e, 0xee, 0x6e, 0x0f, 0x45, 0x6a, 0x26, 0x05, 0x3d, 0x8a, 0x9e, 0xb1, 0x5d, 0x10,
+        0x44, 0xfa, 0x3a, 0xae, 0xc8, 0x0d, 0xda, 0xb2, 0x4a, 0xde, 0x7a, 0xdc, 0xeb, 0xe9, 0xfd, 0xe1,
+        0x13, 0x0b, 0xb6, 0x24, 0x8a, 0x98, 0x17, 0xdc, 0xcc, 0xa5, 0x8e, 0x47, 0xc1, 0xb1, 0x3c, 0x21,
+        0x22, 0xd3, 0xb2, 0xeb, 0x1d, 0xa8, 0x76, 0xae, 0xfb, 0x7f, 0x44, 0x09, 0xb2, 0x80, 0x03, 0xe7,
+        0x4f, 0xc1, 0xaa, 0x9f, 0x18, 0x71, 0x7d, 0x33, 0x74, 0x2c, 0x01, 0x6f, 0x77, 0x57, 0x3f, 0x14,
+        0xad, 0x1d, 0x9d, 0xf2, 0x61, 0x06, 0xf3, 0xb4, 0x3f, 0x78, 0x2d, 0x68, 0xe4, 0x68, 0x00, 0x3c,
+        0xf3, 0xbf, 0x5b, 0x04, 0x7e, 0xdb, 0xf8, 0x5c, 0xc3, 0x23, 0xa6, 0xd9, 0xfc, 0x6b, 0x15, 0x03,
+        0x96, 0x28, 0x41, 0xcd, 0xad, 0xf3, 0x9e, 0x96, 0x4b, 0xd6, 0xab, 0x94, 0x31, 0x1a, 0x8f, 0xb4,
+        0xd9, 0xf3, 0xc0, 0xa9, 0xf4, 0xb1, 0xb6, 0x58, 0x3c, 0xf4, 0xcf, 0x73, 0x72, 0x5f, 0x95, 0x24,
+        0x9f, 0xbc, 0xfb, 0x07, 0xd8, 0xe6, 0x19, 0x70, 0xc7, 0xc6, 0x0b, 0x79, 0xa1, 0x68, 0xfe, 0x7b,
+        0xf9, 0x67, 0x60, 0x83, 0x7a, 0x9d, 0x17, 0xe3, 0x25, 0xd1, 0x8d, 0x39, 0x34, 0xaa, 0x04, 0x09,
+        0x77, 0x57, 0x11, 0xa2, 0x99, 0xff, 0x23, 0x2b, 0x81, 0x54, 0x22, 0x48, 0x4e, 0x78, 0x1d, 0xc6,
+        0x6e, 0xcf, 0x2a, 0xcd, 0xf8, 0x85, 0x5b, 0x3a, 0xc1, 0xb4, 0xb1, 0x64, 0x5f, 0x16, 0xf8, 0x26,
+        0xfe, 0x0b, 0xe5, 0x40, 0x30, 0xc4, 0xdc, 0x2f, 0x7d, 0x5d, 0xd2, 0x43, 0x5f, 0x06, 0x17, 0xa8,
+        0x62, 0x24, 0xdc, 0xa0, 0xc5, 0x3c, 0x5c, 0xd1, 0x1a, 0x17, 0xc5, 0x14, 0xd9, 0x6f, 0x73, 0x04,
+        0xe1, 0xfc, 0x70, 0x7e, 0xdc, 0x0b, 0x72, 0xe8, 0x32, 0x90, 0xbd, 0xbd, 0x66, 0x67, 0x6d, 0x69,
+        0x70, 0xb2, 0x4f, 0x2e, 0x9d, 0x93, 0x1a, 0xff, 0x55, 0x1e, 0xa5, 0x71, 0x03, 0x0a, 0x05, 0x04,
+        0x31, 0xb6, 0x10, 0x26, 0xf4, 0xe2, 0xd2, 0x24, 0x04, 0x03, 0xc0, 0xe0, 0x99, 0x73, 0xa6, 0xfc,
+        0xf3, 0xd2, 0x5d, 0xfc, 0x93, 0x6d, 0x53, 0x92, 0x62, 0xfe, 0x3a, 0x18, 0x38, 0x05, 0x72, 0x19,
+        0x5f, 0x09, 0x90, 0xe5, 0x90, 0xb0, 0x19, 0x89, 0x49, 0x72, 0x66, 0xf7, 0x6f, 0xdf, 0xd7, 0xa2,
+        0xd5, 0x19, 0xa7, 0xf9, 0x61, 0x61, 0x69, 0x15, 0xb2, 0x6d, 0x7a, 0x5c, 0x4f, 0xac, 0x9b, 0x1d,
+        0x94, 0x1c, 0xb8, 0xfa, 0xe8, 0x7a, 0x1e, 0x6b, 0x12, 0x75, 0x92, 0xb2, 0x76, 0x38, 0x95, 0x81,
+        0x00, 0xa5, 0x3e, 0x7f, 0x38, 0x28, 0x0b, 0x87, 0xe3, 0x89, 0xf8, 0x6d, 0xdd, 0x69, 0x34, 0x0a,
+        0x35, 0x5a, 0xe1, 0xa3, 0x6e, 0xca, 0x90, 0x90, 0x05, 0x3a, 0xa4, 0xbd, 0x3b, 0xc0, 0x48, 0xb4,
+        0xc9, 0xce, 0xbe, 0xd9, 0xa5, 0xe5, 0x7e, 0xcf, 0xc7, 0x3c, 0x02, 0xe9, 0x08, 0x49, 0xe7, 0x24,
+        0x6b, 0xcb, 0x53, 0xaf, 0xd1, 0xfe, 0x66, 0xb2, 0x76, 0xbf, 0x58, 0xfa, 0xe0, 0xcb, 0x1b, 0x6e,
+        0x88, 0x1e, 0x82, 0xe3, 0x47, 0x1b, 0xdd, 0xf7, 0x23, 0x43, 0x0a, 0x17, 0x1e, 0x7c, 0x48, 0x0c,
+        0xdf, 0x44, 0x1f, 0x64, 0x45, 0x04, 0x97, 0xa4, 0xe3, 0xd2, 0x3a, 0x05, 0xf8, 0x4e, 0x3d, 0x41,
+        0xfc, 0x31, 0xf8, 0x20, 0xa8, 0x4c, 0xa7, 0x5f, 0x6a, 0xea, 0x53, 0xc2, 0xcc, 0x48, 0x1a, 0x16,
+        0x49, 0x01, 0xa9, 0xc8, 0x8b, 0x5c, 0x35, 0x18, 0x6c, 0xbd, 0xc5, 0xfa, 0x07, 0xd9, 0x21, 0x54,
+        0x3d, 0xaa, 0xe8, 0x4e, 0xc8, 0xf2, 0x7e, 0x38, 0xaa, 0x87, 0xa3, 0x1a, 0xd9, 0x9b, 0x02, 0x0f,
+        0x38, 0x3c, 0x6e, 0xa1, 0x1a, 0x3b, 0xdb, 0x16, 0x28, 0x3d, 0xc4, 0x00, 0x68, 0x51, 0xba, 0x2f,
+        0xa6, 0x19, 0xdb, 0x0f, 0x58, 0x7e, 0x76, 0x74, 0x13, 0x31, 0x22, 0xb0, 0x94, 0xd3, 0x9d, 0x1f,
+        0xac, 0xe8, 0xae, 0x76, 0x65, 0x85, 0xd1, 0x2f, 0xc1, 0xb6, 0x9c, 0xc4, 0xdf, 0xea, 0x4f, 0xbe,
+        0x9b, 0x05, 0xeb, 0xdb, 0x9c, 0x37, 0xaa, 0xa1, 0xa3, 0x9e, 0x4a, 0x8b, 0x64, 0x57, 0xf7, 0x0a,
+        0x32, 0xd5, 0xfc, 0x3e, 0x0b, 0xf2, 0xb4, 0x8e, 0x39, 0x23, 0x8a, 0x02, 0xd5, 0x11, 0x13, 0xae,
+        0xf2, 0x08, 0x94, 0x4e, 0xf5, 0x04, 0x39, 0xdd, 0x4e, 0xd5, 0x50, 0x52, 0x67, 0x27, 0x6d, 0x1f,
+        0x04, 0x7e, 0x03, 0xd9, 0x23, 0x05, 0x4b, 0x52, 0xb8, 0x68, 0x49, 0xd4, 0xfd, 0x02, 0x78, 0xb8,
+        0x82, 0x36, 0x13, 0x31, 0xe1, 0x8b, 0xd8, 0xc5, 0x3b, 0x5f, 0xa5, 0x3f, 0x2f, 0x10, 0xc2, 0x00,
+        0xaf, 0xf4, 0x3b, 0x79, 0x14, 0xfb, 0x0c, 0xd1, 0xb6, 0xc8, 0x86, 0x19, 0x07, 0x13, 0x03, 0xce,
+        0x76, 0x64, 0x9f, 0xf2, 0x61, 0xe6, 0x0f, 0xef, 0x30, 0x8f, 0xb5, 0x3b, 0xed, 0xe4, 0x07, 0x2f,
+        0x20, 0x71, 0x2f, 0xd5, 0x5d, 0x00, 0x8e, 0x99, 0x7b, 0xbf, 0x1b, 0x08, 0x91, 0xdd, 0xc4, 0xeb,
+        0x97, 0xcc, 0xce, 0x0e, 0x58, 0xc6, 0x36, 0x29, 0xd5, 0xb8, 0x49, 0x18, 0xdd, 0xab, 0x35, 0x1e,
+        0x3f, 0x3c, 0x49, 0x0f, 0x4b, 0x81, 0x42, 0x22, 0xf1, 0xfb, 0x6a, 0xa8, 0xe9, 0xbd, 0xe3, 0x11,
+        0x55, 0xb4, 0x7d, 0xe9, 0x01, 0x34, 0x16, 0x26, 0x72, 0x5b, 0xfa, 0x74, 0x4c, 0x1c, 0xc1, 0x16,
+        0x71, 0x2a, 0x8e, 0xc6, 0x30, 0x6a, 0x01, 0xdb, 0x34, 0x38, 0xa6, 0xb4, 0x5d, 0x01, 0x58, 0x13,
+        0xc0, 0xb5, 0x48, 0x0b, 0xa7, 0x28, 0x3d, 0xc5, 0x7a, 0x70, 0x86, 0x76, 0x43, 0x7e, 0xff, 0x2a,
+        0x11, 0x9a, 0xba, 0x22, 0x91, 0x70, 0x0a, 0xa4, 0x9a, 0x16, 0xc9, 0x68, 0xb1, 0xbc, 0x20, 0xf4,
+        0x66, 0x69, 0xcf, 0x55, 0xec, 0x61, 0x4a, 0x31, 0x6f, 0xb5, 0xcd, 0xe9, 0x1b, 0x9b, 0xda, 0x1a,
+        0xea, 0x4c, 0x06, 0x59, 0x4b, 0x86, 0x2e, 0x19, 0xc4, 0x0d, 0x5e, 0x8a, 0x99, 0xb3, 0xb0, 0x1b,
+        0xd2, 0x62, 0x54, 0x3b, 0x8a, 0xe5, 0x89, 0x89, 0xb1, 0x3b, 0x23, 0xb1, 0x55, 0x48, 0xc6, 0x0f,
+        0xd4, 0xd9, 0xeb, 0x11, 0x86, 0x72, 0xaa, 0xab, 0x16, 0x51, 0xf1, 0x64, 0xf1, 0x6b, 0xe3, 0x4d,
+        0xe2, 0x61, 0xcf, 0x65, 0xd4, 0x5e, 0x29, 0x21, 0x96, 0xed, 0x45, 0xc9, 0x8d, 0x24, 0x75, 0x26,
+        0x58, 0x42, 0x0f, 0xe3, 0x6a, 0xcd, 0x21, 0x9b, 0x25, 0x6d, 0x97, 0x3d, 0xca, 0x7e, 0x75, 0xba,
+        0x13, 0x2b, 0x1f, 0x34, 0x11, 0xd2, 0xdc, 0xe5, 0x80, 0x91, 0x54, 0x4e, 0xd7, 0x3e, 0xa9, 0x1b,
+        0xe9, 0x3a, 0x07, 0xb5, 0x60, 0x5d, 0x2e, 0xb9, 0xcc, 0x22, 0xd1, 0x31, 0xf3, 0x22, 0xf5, 0x08,
+        0xa5, 0x04, 0x4e, 0xf0, 0x51, 0xb8, 0xca, 0x00, 0xa8, 0xb1, 0xd3, 0x34, 0x54, 0x4f, 0xc2, 0x0a,
+        0xf7, 0xa6, 0x85, 0x45, 0x10, 0x81, 0xd1, 0x2c, 0x4a, 0xd7, 0x47, 0x9d, 0x2a, 0xa2, 0x0d, 0xa7,
+        0x51, 0x41, 0xb9, 0x24, 0x07, 0x76, 0x48, 0x8d, 0xf8, 0xa4, 0xde, 0x3c, 0x20, 0xd6, 0xdb, 0x18,
+        0x4a, 0x71, 0xa4, 0xa0, 0x42, 0x67, 0x93, 0x67, 0x8d, 0x93, 0x79, 0xdc, 0x3b, 0x3a, 0xa9, 0x16,
+        0xa0, 0x7e, 0xf8, 0x06, 0x8f, 0x9e, 0x93, 0xaa, 0xc3, 0x12, 0xc6, 0x0b, 0x5c, 0xcd, 0x5a, 0x19,
+        0xa4, 0x91, 0x63, 0xf4, 0x56, 0xf3, 0xd5, 0x22, 0xa4, 0x9e, 0xbf, 0x0c, 0xdf, 0x51, 0xc1, 0x7b,
+        0x8c, 0x14, 0xfd, 0x89, 0x61, 0x69, 0x77, 0xa5, 0xd9, 0xce, 0xb8, 0x60, 0x97, 0xe4, 0x1b, 0x28,
+        0x7d, 0xe2, 0x49, 0xd6, 0x09, 0xe9, 0xbd, 0x65, 0x14, 0x51, 0x9b, 0xc4, 0xfa, 0xe2, 0xde, 0x5a,
+        0xdb, 0xa7, 0x43, 0xed, 0x01, 0x2a, 0xf5, 0xaa, 0x1e, 0x64, 0x03, 0x78, 0x6c, 0xd5, 0xdf, 0x27,
+        0x36, 0xa7, 0x06, 0x6e, 0xdc, 0x90, 0xe5, 0xaa, 0xe4, 0xe4, 0x3c, 0xd1, 0x59, 0xb5, 0x7e, 0x90,
+        0x2f, 0xec, 0x92, 0xcb, 0x92, 0x5f, 0xdd, 0x55, 0x25, 0x4e, 0x92, 0xb2, 0x57, 0x16, 0xdc, 0x10,
+        0x35, 0x25, 0xe5, 0xad, 0x37, 0xdc, 0x86, 0xbc, 0x42, 0xc7, 0x9b, 0xef, 0x5c, 0x3e, 0x46, 0x90,
+        0xb8, 0x01, 0x8f, 0xf1, 0x72, 0xf6, 0x9c, 0x75, 0x2a, 0xf7, 0x97, 0xe4, 0x02, 0x0e, 0xc7, 0x08,
+        0x14, 0xf4, 0x57, 0x6c, 0x7f, 0xbe, 0xf0, 0xda, 0xfa, 0xf7, 0xbc, 0xa5, 0xb6, 0x68, 0x1e, 0x73,
+        0x6d, 0x72, 0xd6, 0xf4, 0xe4, 0x53, 0x90, 0xc5, 0x2b, 0xf6, 0x0f, 0x44, 0x43, 0x4d, 0xa1, 0x0b,
+        0x02, 0xed, 0x02, 0x9a, 0xa4, 0x4a, 0x38, 0xa0, 0x7b, 0x9e, 0x76, 0x4d, 0xf8, 0xd5, 0xd4, 0xcc,
+        0x8d, 0xed, 0x83, 0xf4, 0x8f, 0xce, 0x76, 0x05, 0x78, 0x9e, 0x93, 0x26, 0xaa, 0x5f, 0xe3, 0x22,
+        0xf0, 0xcc, 0x8d, 0x3c, 0xbc, 0x9c, 0xfc, 0x4b, 0x09, 0xba, 0xe5, 0x58, 0x7b, 0xd1, 0xdd, 0xea,
+        0xb1, 0x54, 0x5f, 0x93, 0xbf, 0x64, 0xb2, 0xac, 0xdc, 0x2b, 0xbd, 0xff, 0xd1, 0xaf, 0x92, 0x2e,
+        0xea, 0xd2, 0xaf, 0xc1, 0xaa, 0x12, 0x29, 0x27, 0x30, 0x8a, 0xd8, 0xac, 0xbd, 0x00, 0x5e, 0x9b,
+        0xec, 0x5b, 0x25, 0xe8, 0xe0, 0x7a, 0x40, 0xe6, 0x38, 0x65, 0x01, 0x70, 0x6d, 0x80, 0x82, 0x17,
+        0x7f, 0xe1, 0x82, 0x9f, 0xc3, 0x06, 0x29, 0xbc, 0x4b, 0x04, 0x2b, 0x42, 0x4e, 0x17, 0x83, 0x52,
+        0xa1, 0xfa, 0xbe, 0x23, 0x60, 0xd7, 0xf3, 0x5d, 0x06, 0x24, 0xfc, 0x16, 0x30, 0x4f, 0xe9, 0x2a,
+        0x65, 0x0c, 0xdd, 0xb9, 0x6e, 0xa1, 0xf6, 0x87, 0x6e, 0xd9, 0x5a, 0x39, 0x88, 0x08, 0x57, 0x53,
+        0x27, 0xdf, 0x83, 0x78, 0x79, 0xbb, 0xef, 0xfb, 0x18, 0xe2, 0x72, 0xef, 0x9a, 0x66, 0x7e, 0x17,
+        0xc9, 0x6e, 0x4c, 0xc5, 0x0c, 0x2a, 0xee, 0x78, 0x32, 0xb1, 0xd1, 0x63, 0x71, 0xc8, 0xc3, 0xea,
+        0xe8, 0xb6, 0x71, 0x4c, 0x02, 0x85, 0x51, 0xe9, 0x31, 0x78, 0x5e, 0xc6, 0x19, 0x61, 0x70, 0x10,
+        0x5b, 0xa6, 0xa9, 0xbe, 0xd8, 0xd2, 0xb7, 0xcd, 0x8a, 0x93, 0x03, 0x45, 0x8e, 0x0c, 0x79, 0xeb,
+        0x93, 0xa7, 0x6a, 0xbb, 0xfb, 0x7e, 0xe3, 0x6f, 0xba, 0x41, 0xc6, 0x24, 0x01, 0xb4, 0xfd, 0x08,
+        0x81, 0x9f, 0x15, 0x5a, 0x80, 0x5a, 0xd2, 0x83, 0x4a, 0x88, 0x31, 0xd6, 0xe2, 0xf1, 0xec, 0x60,
+        0x64, 0x71, 0xf9, 0x35, 0x14, 0xca, 0xcc, 0xcb, 0x18, 0x19, 0xbe, 0x33, 0x01, 0x74, 0xe9, 0x06,
+        0xb8, 0xd5, 0x38, 0x0c, 0x59, 0x56, 0x76, 0x91, 0xb1, 0x2b, 0x19, 0xab, 0x76, 0xa9, 0x76, 0xca,
+        0xe4, 0xe1, 0x31, 0x17, 0xf7, 0x45, 0x20, 0xb0, 0x5d, 0x88, 0x32, 0xe5, 0x90, 0x87, 0xbe, 0x20,
+        0xab, 0x37, 0xa0, 0x6f, 0xb8, 0x93, 0xf8, 0xb6, 0x0f, 0x8b, 0x5a, 0x68, 0x63, 0xc0, 0x78, 0xc0,
+        0x28, 0x55, 0x82, 0xc1, 0x51, 0xfd, 0x6a, 0x9f, 0xa1, 0x7b, 0x2f, 0xcf, 0x5c, 0x21, 0x62, 0x03,
+        0xbc, 0xc5, 0x69, 0x43, 0x50, 0x75, 0x4c, 0x7d, 0xa4, 0x66, 0x76, 0x6e, 0xaa, 0x4a, 0x03, 0x7b,
+        0xbd, 0xa6, 0xb6, 0xe9, 0x3a, 0xe9, 0xee, 0x6b, 0xcc, 0xd5, 0x35, 0x13, 0x67, 0x7a, 0xb5, 0x29,
+        0x0e, 0x35, 0x65, 0x4d, 0x2c, 0x6c, 0x91, 0x2f, 0x8c, 0x34, 0x79, 0x29, 0x0e, 0xc7, 0x40, 0x77,
+        0x2e, 0xb0, 0x27, 0xcd, 0x67, 0xe0, 0xa1, 0x42, 0xba, 0xfb, 0x4a, 0xc8, 0x38, 0x64, 0x0f, 0x17,
+        0xad, 0xf1, 0xff, 0x5d, 0xd1, 0xc3, 0xf7, 0x07, 0xda, 0x24, 0xa7, 0x64, 0xdc, 0xf8, 0x4c, 0xf0,
+        0xf9, 0x61, 0xf9, 0xc2, 0x8b, 0x9c, 0xab, 0x4f, 0x7d, 0xfb, 0xaa, 0x46, 0x0b, 0x66, 0xa6, 0x0c,
+        0x25, 0x89, 0x1f, 0xd5, 0x78, 0xf3, 0xa2, 0x61, 0x1f, 0x65, 0xe7, 0xe7, 0x61, 0x81, 0xe8, 0x3e,
+        0xc8, 0xae, 0x81, 0xc5, 0xea, 0x22, 0x44, 0x73, 0xa3, 0xc2, 0x6a, 0xc0, 0x70, 0xa3, 0xe5, 0x0d,
+        0x8e, 0xf5, 0x70, 0xdb, 0x10, 0x1a, 0x25, 0x27, 0x3a, 0x52, 0xe1, 0x94, 0x92, 0x71, 0x18, 0xd5,
+        0x64, 0x61, 0x11, 0xa2, 0x1f, 0xd1, 0xf5, 0x3e, 0xdb, 0xf6, 0xae, 0x64, 0x44, 0x0c, 0x93, 0x0d,
+        0x33, 0x14, 0x83, 0xcd, 0xda, 0xdb, 0x1d, 0x76, 0xca, 0x03, 0x0f, 0x9b, 0x0e, 0x0c, 0xe9, 0x98,
+        0x6a, 0xb0, 0xe7, 0x5e, 0xa9, 0x10, 0x13, 0x59, 0x4c, 0xa4, 0x5c, 0x85, 0x1b, 0x95, 0x4d, 0x13,
+        0x43, 0xdc, 0x48, 0xa4, 0x07, 0x00, 0x5d, 0xac, 0x7b, 0x1b, 0xe3, 0x65, 0x01, 0x25, 0xdc, 0x3c,
+        0x42, 0xe8, 0x49, 0xe3, 0x4c, 0xbf, 0x77, 0xd6, 0xf3, 0xc6, 0x18, 0xfe, 0x27, 0x03, 0x81, 0x06,
+        0xa7, 0xda, 0xc8, 0x36, 0x97, 0x11, 0x23, 0x5d, 0x9d, 0x1e, 0xf2, 0x0c, 0x1a, 0xaa, 0xe1, 0xa8,
+        0xff, 0xa4, 0x27, 0xa2, 0x7a, 0xbc, 0xd7, 0x9f, 0xc0, 0x47, 0x2f, 0x7b, 0x56, 0x58, 0x9b, 0x1e,
+        0xb0, 0x10, 0xac, 0x60, 0x74, 0xe6, 0x5f, 0x55, 0xf5, 0xf6, 0x23, 0x1e, 0x67, 0x23, 0xca, 0x1d,
+        0xda, 0xfa, 0x0e, 0x38, 0x94, 0x4d, 0x01, 0x9d, 0x9e, 0x23, 0x20, 0xe3, 0x4b, 0xfd, 0xdc, 0x03,
+        0x84, 0x06, 0xa8, 0x1f, 0x48, 0x11, 0x87, 0x4e, 0xbb, 0x9a, 0x0a, 0x2a, 0x5d, 0x1f, 0x4b, 0x4d,
+        0x36, 0xa2, 0x3f, 0x93, 0xae, 0x3f, 0x6a, 0xdc, 0x80, 0x86, 0x3d, 0x02, 0xde, 0xb2, 0xbc, 0x15,
+        0x9c, 0x09, 0xc2, 0xf2, 0x65, 0x91, 0xe0, 0x4e, 0x87, 0x44, 0x71, 0xb4, 0x14, 0x07, 0x58, 0x11,
+        0x9e, 0x68, 0x13, 0xc1, 0x3e, 0xe8, 0x36, 0x33, 0x48, 0x85, 0x00, 0xb8, 0x44, 0x66, 0xee, 0x00,
+        0xf8, 0xf2, 0xb5, 0xc8, 0x17, 0xbf, 0xbf, 0x81, 0x10, 0x95, 0x3e, 0x26, 0x37, 0x48, 0xd9, 0x3e,
+        0xd1, 0x67, 0x70, 0x20, 0x92, 0x7e, 0x4e, 0x6b, 0x71, 0xce, 0x1e, 0x11, 0x2b, 0x96, 0x03, 0x0c,
+        0xa2, 0xed, 0x9f, 0xe9, 0xce, 0xcd, 0xcd, 0xa1, 0xb2, 0x9f, 0xf7, 0xd7, 0x43, 0xe1, 0xb6, 0xda,
+        0xb1, 0x0e, 0x19, 0xd9, 0xa5, 0x01, 0xc1, 0xc4, 0xa1, 0xdf, 0x5c, 0x73, 0x49, 0xf9, 0x80, 0x1c,
+        0xfd, 0xa5, 0xd1, 0xe5, 0x90, 0x84, 0xa0, 0x03, 0xbd, 0xfd, 0xe7, 0x86, 0xf8, 0xcb, 0x70, 0x8c,
+        0x3d, 0x68, 0x99, 0x10, 0x70, 0xc6, 0xf7, 0x5d, 0xaa, 0x59, 0xdb, 0xc3, 0xdb, 0x64, 0xda, 0x04,
+        0xf1, 0x7b, 0xd6, 0x36, 0xb2, 0x0a, 0x2e, 0xd1, 0xde, 0xc2, 0x0a, 0x91, 0x9b, 0x23, 0x0d, 0xc2,
+        0x0d, 0x5d, 0x9e, 0x28, 0xe6, 0x00, 0x18, 0x42, 0xe4, 0x76, 0x8a, 0xc2, 0xfb, 0xb2, 0xfb, 0x1f,
+        0x75, 0x48, 0x2f, 0xc4, 0xe1, 0x91, 0x4f, 0x1b, 0x2d, 0xe6, 0x64, 0x98, 0xfe, 0xb3, 0xb6, 0xd6,
+        0xa1, 0xe6, 0xe0, 0xe3, 0xaa, 0x07, 0x05, 0xf2, 0x01, 0xef, 0x3a, 0x8a, 0x29, 0xb3, 0xa8, 0x20,
+        0x11, 0x7e, 0xbb, 0x93, 0xde, 0x0f, 0x3f, 0xb6, 0x6a, 0xed, 0xcb, 0x39, 0x1a, 0xc0, 0x39, 0x53,
+        0x57, 0x31, 0x35, 0xcd, 0x88, 0x5f, 0xf6, 0x57, 0xd0, 0xd3, 0x0e, 0x12, 0x5e, 0xbe, 0x7e, 0x2c,
+        0x87, 0x89, 0x8f, 0x6d, 0x9a, 0xe0, 0xc5, 0x1a, 0x38, 0xc4, 0x7e, 0x75, 0xd7, 0x24, 0x6d, 0x99,
+        0xb8, 0x63, 0x79, 0xc7, 0x05, 0x87, 0xb8, 0x0a, 0xb8, 0x8d, 0x35, 0x27, 0xfb, 0xc7, 0xc2, 0x21,
+        0x2d, 0x9d, 0x1d, 0xa3, 0xe7, 0x8f, 0x3c, 0xbd, 0x0f, 0x36, 0x79, 0x7c, 0x20, 0xf6, 0x9c, 0x7c,
+        0x74, 0x00, 0x01, 0xbb, 0x7c, 0x42, 0x7c, 0x11, 0xda, 0xa3, 0x8a, 0x87, 0x81, 0x75, 0x25, 0x11,
+        0x09, 0xaa, 0x99, 0x0a, 0x4d, 0x84, 0xdb, 0xa3, 0xd8, 0xcf, 0xbe, 0x27, 0xf2, 0x36, 0x1a, 0x53,
+        0x1d, 0xdb, 0x1f, 0xc4, 0xf7, 0x04, 0x9c, 0xee, 0x1a, 0x3d, 0xda, 0x9e, 0xb4, 0xb5, 0x11, 0x06,
+        0x70, 0xcb, 0x9b, 0xca, 0x79, 0x07, 0x54, 0x91, 0xd4, 0x51, 0x16, 0x00, 0xcf, 0x19, 0xc7, 0xc6,
+        0xfd, 0xe4, 0x0e, 0x42, 0xfb, 0x5e, 0xaf, 0xef, 0xfd, 0x11, 0xaa, 0x3f, 0x1e, 0xec, 0x9f, 0x1d,
+        0xda, 0x32, 0xb4, 0x17, 0xb3, 0xcc, 0xf9, 0x9d, 0x8c, 0xd8, 0x74, 0xb5, 0x08, 0x1e, 0xd6, 0x32,
+        0xd7, 0x3f, 0xed, 0x40, 0xb9, 0xcc, 0x9a, 0x6b, 0x6d, 0x5e, 0x2c, 0x07, 0x73, 0xbd, 0xe5, 0x1b,
+        0xb0, 0xef, 0x62, 0xcc, 0xc1, 0xa7, 0x6e, 0xc4, 0x1b, 0x13, 0xe5, 0xc4, 0xed, 0x97, 0xe1, 0xae,
+        0x8b, 0xef, 0xeb, 0xfb, 0x87, 0xe1, 0xf7, 0x55, 0x11, 0x1c, 0x92, 0xb3, 0x33, 0x69, 0xbc, 0x08,
+        0x0d, 0xfb, 0x90, 0xe1, 0x35, 0xca, 0x27, 0xdb, 0x67, 0x45, 0x8d, 0x75, 0xa0, 0xc6, 0x58, 0xfa,
+        0xd4, 0x84, 0x1e, 0xad, 0x0c, 0x89, 0xc7, 0xbf, 0x15, 0x48, 0xbf, 0x83, 0x4b, 0x7b, 0x3c, 0x0c,
+        0x00, 0xd6, 0x62, 0xd8, 0x99, 0xa3, 0x69, 0x2c, 0x48, 0x86, 0x9c, 0x26, 0x63, 0x91, 0xb1, 0x96,
+        0x07, 0x98, 0x45, 0x4e, 0x6a, 0xbd, 0x20, 0x5b, 0x09, 0x10, 0x2b, 0x3b, 0xca, 0x35, 0xd0, 0x19,
+        0x01, 0xd7, 0xb1, 0xe3, 0x18, 0xdb, 0xf7, 0x8d, 0x27, 0x30, 0x07, 0x6d, 0x6b, 0x9b, 0x2b, 0x72,
+        0xe8, 0x67, 0x72, 0x21, 0x04, 0x5d, 0xbf, 0x12, 0x40, 0x5c, 0xd9, 0xbc, 0x49, 0x83, 0xb7, 0x14,
+        0x66, 0x32, 0x6a, 0x90, 0x78, 0x79, 0x41, 0xe5, 0xee, 0x0e, 0xd7, 0x1c, 0xd2, 0xe4, 0x03, 0x08,
+        0xa7, 0x1e, 0xd4, 0xc6, 0x8d, 0x7c, 0xcc, 0x6a, 0x67, 0x41, 0xea, 0xa5, 0xaa, 0xa2, 0xc2, 0x23,
+        0x17, 0x59, 0x50, 0x7a, 0xa5, 0x78, 0x0e, 0x3b, 0x0e, 0x3b, 0x6f, 0xab, 0xff, 0xa2, 0x5b, 0xa2,
+        0xaf, 0x7d, 0x32, 0x7f, 0xe5, 0x7b, 0x4c, 0xcf, 0x65, 0x36, 0xac, 0xda, 0x96, 0xef, 0x67, 0x0e,
+        0x8e, 0x69, 0xfd, 0x8b, 0x4a, 0x14, 0xd7, 0x55, 0x50, 0xa2, 0xed, 0xce, 0xe1, 0x3d, 0xf8, 0xe5,
+        0x9c, 0x80, 0x9f, 0xa4, 0x59, 0x7a, 0xb7, 0xd5, 0x77, 0x46, 0xaa, 0x99, 0x6b, 0x89, 0x2b, 0x21,
+        0x16, 0xac, 0xea, 0xa4, 0xf1, 0xf5, 0xa2, 0x6e, 0x47, 0x0d, 0x9a, 0x33, 0x2d, 0x13, 0x76, 0xc8,
+        0x0c, 0x73, 0x9f, 0xd0, 0xee, 0x4e, 0xc9, 0x40, 0x79, 0x25, 0xb5, 0xb9, 0xd2, 0x7f, 0x34, 0x29,
+        0x88, 0xd0, 0x76, 0xba, 0x12, 0xf4, 0xe4, 0x4c, 0x35, 0xfd, 0xeb, 0x96, 0xee, 0x8b, 0x3e, 0x67,
+        0x02, 0x94, 0x32, 0x4a, 0x4a, 0xc5, 0xfa, 0x27, 0x37, 0x08, 0x99, 0x6a, 0x62, 0x5a, 0x64, 0x14,
+        0xaf, 0xb4, 0xdb, 0x91, 0xdd, 0x4b, 0xf3, 0xda, 0x0d, 0x2d, 0xc1, 0xec, 0xf9, 0x58, 0x34, 0x46,
+        0x6a, 0x2c, 0xcc, 0x8b, 0x20, 0x8d, 0x73, 0x5f, 0xf5, 0x6d, 0x88, 0xd1, 0xee, 0x6c, 0x35, 0x15,
+        0xe4, 0xa3, 0x83, 0x94, 0x5a, 0x72, 0x5f, 0x5d, 0xf1, 0xab, 0x71, 0xde, 0x81, 0x50, 0xcb, 0x93,
+        0xfe, 0xf0, 0x75, 0x74, 0x9e, 0xc3, 0x69, 0xbf, 0xcd, 0xa1, 0x03, 0x8a, 0xb9, 0x30, 0x81, 0x1d,
+        0xbb, 0xfa, 0x1d, 0xbe, 0x43, 0x06, 0xd7, 0xb2, 0xe5, 0x0e, 0x00, 0xdf, 0xb2, 0xcf, 0x9d, 0x6a,
+        0x2c, 0x25, 0xa3, 0x3f, 0x64, 0x0b, 0x88, 0xe5, 0x46, 0x6a, 0x3a, 0x54, 0x8d, 0x38, 0x46, 0x2a,
+        0x6e, 0x6f, 0x72, 0x1e, 0xca, 0x29, 0x7e, 0xf1, 0x81, 0x66, 0x57, 0x2c, 0xb3, 0xd8, 0xe0, 0xc0,
+        0xa0, 0xc1, 0x03, 0x67, 0xd2, 0x79, 0x9f, 0x05, 0xec, 0xef, 0xd2, 0xef, 0x88, 0xc1, 0xd1, 0x03,
+        0xf0, 0xdf, 0x8c, 0x35, 0xe1, 0x70, 0x0c, 0x46, 0xc6, 0x45, 0x3d, 0xa9, 0x1a, 0xc2, 0x45, 0x1f,
+        0x02, 0x36, 0x02, 0xc4, 0xc6, 0x6e, 0xd2, 0xe9, 0x13, 0x0c, 0xec, 0x2a, 0x97, 0x6a, 0xa4, 0x26,
+        0xd8, 0x4b, 0x72, 0x81, 0x3a, 0xfd, 0x50, 0xfa, 0x35, 0xa5, 0x7a, 0x36, 0xd5, 0x49, 0x28, 0x91,
+        0x32, 0x6c, 0x16, 0x57, 0x96, 0x04, 0xb1, 0x80, 0xb7, 0xf1, 0x46, 0x11, 0xbc, 0x26, 0x15, 0x15,
+        0x3b, 0x9a, 0x29, 0x8c, 0xaa, 0x9c, 0x8d, 0xdd, 0xde, 0xe6, 0x6b, 0xd2, 0x83, 0x44, 0xf2, 0xed,
+        0x77, 0xd3, 0x49, 0x19, 0x47, 0xcc, 0x00, 0x34, 0x17, 0x84, 0x11, 0x9a, 0x01, 0x99, 0x2d, 0x0e,
+        0x02, 0x90, 0x4a, 0xe1, 0x29, 0x5f, 0x3d, 0x92, 0xa6, 0x73, 0x05, 0x67, 0x2a, 0x47, 0xbc, 0x17,
+        0x6a, 0xbf, 0xd8, 0x7c, 0xd6, 0x89, 0xf2, 0x33, 0x38, 0xc0, 0x1f, 0xa6, 0x92, 0x46, 0x01, 0x2e,
+        0xab, 0xf8, 0x21, 0x38, 0xc3, 0x22, 0x74, 0x65, 0x3a, 0x17, 0x39, 0xd8, 0xb8, 0xae, 0xfb, 0xdf,
+        0x1b, 0x8f, 0x78, 0xe2, 0x4b, 0x94, 0x6e, 0x96, 0xdb, 0x1d, 0xb5, 0x33, 0x31, 0x28, 0x0f, 0x0b,
+        0x32, 0x12, 0xc8, 0xe5, 0x13, 0xa5, 0xbe, 0x0b, 0xea, 0x02, 0xe3, 0xa0, 0xd5, 0x32, 0x83, 0x6d,
+        0x62, 0x31, 0xd0, 0x62, 0x0b, 0x10, 0x6d, 0xf4, 0x4f, 0x3b, 0x00, 0xa1, 0x0d, 0x06, 0x3d, 0x11,
+        0x19, 0x6f, 0xae, 0x3f, 0x65, 0xc3, 0x51, 0xe2, 0xf6, 0x3b, 0xbc, 0xfa, 0x55, 0x0e, 0x53, 0x15,
+        0x27, 0xc9, 0x70, 0x36, 0xa8, 0x4c, 0xf8, 0x7b, 0x0e, 0xc9, 0x42, 0x0c, 0xca, 0x6d, 0x33, 0x20,
+        0xb7, 0x94, 0xa5, 0xf2, 0xf2, 0x1f, 0x92, 0x32, 0x95, 0xca, 0x80, 0x85, 0xc1, 0x10, 0x70, 0x12,
+        0x0b, 0x70, 0x4d, 0x2d, 0xa0, 0x26, 0x96, 0x3d, 0x59, 0x40, 0x83, 0x20, 0xc0, 0xd0, 0x97, 0x1e,
+        0x69, 0xfa, 0xe7, 0xb0, 0x84, 0xb0, 0x23, 0xd1, 0x97, 0xc1, 0x5b, 0xbe, 0x32, 0x52, 0x48, 0xb2,
+        0x4c, 0x28, 0x0c, 0xd2, 0xf3, 0x47, 0x3d, 0x3e, 0x8b, 0x3d, 0x21, 0x2d, 0xd6, 0x2b, 0x34, 0x0c,
+        0xe2, 0x9a, 0x71, 0x5e, 0xb9, 0xe4, 0x10, 0x6b, 0x8c, 0x6d, 0x74, 0x91, 0x18, 0x28, 0xf8, 0x63,
+        0x17, 0x02, 0x84, 0xda, 0x39, 0xb3, 0x15, 0x73, 0x4c, 0xf3, 0xbe, 0x98, 0x2d, 0x49, 0x8c, 0x1c,
+        0x8e, 0x82, 0x28, 0x19, 0xd7, 0x4c, 0x61, 0xef, 0xcb, 0xee, 0xf4, 0xb2, 0x02, 0xb0, 0x89, 0x7e,
+        0x0a, 0x8a, 0x3f, 0x5a, 0xb5, 0x44, 0x60, 0xa9, 0x1e, 0x63, 0xca, 0xb4, 0x0b, 0x86, 0xd1, 0x2e,
+        0x1c, 0xc2, 0x25, 0x7b, 0xaf, 0x31, 0xe0, 0x78, 0x91, 0xcc, 0xa5, 0x43, 0xcf, 0xfb, 0x45, 0x7e,
+        0x96, 0xce, 0xf0, 0x94, 0x1e, 0x03, 0x06, 0x1f, 0x7f, 0x7c, 0x1b, 0x0e, 0x46, 0x9e, 0x63, 0x10,
+        0x06, 0xf2, 0x33, 0xaf, 0x98, 0x37, 0xb8, 0xdf, 0xf3, 0x79, 0x81, 0x93, 0xea, 0x0b, 0xc9, 0x01,
+        0xfe, 0x26, 0xba, 0x98, 0xa9, 0x9b, 0xba, 0xc9, 0x3d, 0x7d, 0x2d, 0x9d, 0x3e, 0xcd, 0x52, 0x0e,
+        0x4d, 0x8c, 0xbe, 0xb7, 0x1b, 0x67, 0x1e, 0x4b, 0x62, 0x4b, 0xe7, 0x75, 0x32, 0xa0, 0xb9, 0x9e,
+        0xb0, 0x9e, 0x43, 0xdf, 0xda, 0x8c, 0xfa, 0x4a, 0xa9, 0xa6, 0x67, 0x6a, 0xf2, 0x9d, 0x8b, 0x2c,
+        0x7a, 0x5d, 0xa3, 0x3b, 0x0b, 0xb4, 0xed, 0xa0, 0xc8, 0x4f, 0x50, 0x1e, 0x6a, 0xa4, 0x50, 0x2f,
+        0xab, 0x4b, 0x54, 0x33, 0x99, 0x46, 0x0e, 0xb1, 0x31, 0x4a, 0x72, 0x3e, 0x4b, 0x36, 0xf0, 0x25,
+        0x4c, 0xf7, 0xd3, 0x36, 0x1b, 0x70, 0x18, 0xd2, 0x8c, 0xf0, 0x16, 0xdf, 0xdf, 0x22, 0x9b, 0x50,
+        0xe5, 0x40, 0xfd, 0x0d, 0x8c, 0xe0, 0x12, 0x93, 0xec, 0xd4, 0x38, 0x1b, 0xbb, 0xdd, 0x76, 0x0f,
+        0x51, 0xfd, 0xb9, 0xb9, 0x51, 0xc5, 0x8f, 0x98, 0x9e, 0x20, 0x99, 0x8e, 0x29, 0xf9, 0x90, 0x0a,
+        0xbc, 0xf0, 0xca, 0xb6, 0x61, 0x8d, 0xfc, 0x81, 0xf1, 0xb3, 0xe0, 0xff, 0x84, 0x38, 0x90, 0x14,
+        0xf4, 0xbf, 0x22, 0x3c, 0x35, 0x18, 0x35, 0x18, 0x25, 0xb3, 0x3c, 0x0a, 0x40, 0xd2, 0xb6, 0xb4,
+        0xf6, 0xe7, 0x34, 0x50, 0xd1, 0xd7, 0xde, 0x46, 0x7b, 0x24, 0x86, 0x0d, 0xe9, 0x98, 0x0e, 0x2d,
+        0xc4, 0x96, 0x18, 0x23, 0x5c, 0xda, 0x34, 0xd0, 0x55, 0x40, 0x26, 0x0d, 0xc2, 0x31, 0xb7, 0x30,
+        0xb4, 0xe4, 0xbe, 0xe3, 0xfa, 0x08, 0x0a, 0xda, 0x6f, 0x21, 0xa8, 0xde, 0x0a, 0xd0, 0x57, 0x29,
+        0xcd, 0x45, 0xc2, 0xff, 0xbf, 0x0f, 0xae, 0x15, 0x5f, 0x65, 0xea, 0x03, 0xc5, 0x51, 0x1a, 0xf9,
+        0x4c, 0x0c, 0xc0, 0x74, 0xaa, 0x2a, 0x0a, 0xbe, 0xf6, 0x55, 0x50, 0x66, 0xb7, 0x56, 0xd1, 0x1f,
+        0xd4, 0xa1, 0xb6, 0x04, 0x60, 0x83, 0x73, 0x3f, 0xae, 0xb8, 0xc7, 0x3f, 0x5f, 0xc8, 0x85, 0xf4,
+        0x57, 0xc9, 0x22, 0xc0, 0xb9, 0x6f, 0xcb, 0xf9, 0xb5, 0xd5, 0x0a, 0x79, 0xfc, 0xbb, 0xc5, 0x2a,
+        0x56, 0x69, 0xb6, 0x73, 0xd9, 0xbc, 0x5a, 0xe8, 0x71, 0xdd, 0xe8, 0x49, 0x6b, 0x20, 0x86, 0x0d,
+        0x45, 0x6c, 0xe6, 0x73, 0x60, 0x8e, 0x7e, 0x3f, 0x95, 0x5f, 0x8e, 0x98, 0xee, 0x4e, 0x42, 0x10,
+        0x43, 0xa8, 0x85, 0x16, 0xcf, 0x74, 0xe8, 0x65, 0xdc, 0xc7, 0xf1, 0x7b, 0x81, 0x91, 0xd3, 0x02,
+        0x53, 0x09, 0xd5, 0xe5, 0xba, 0x4e, 0xa5, 0x01, 0x36, 0xbb, 0xa1, 0x1b, 0xbc, 0x5b, 0x7b, 0x00,
+        0x9e, 0x76, 0x1a, 0x75, 0x3d, 0x18, 0xc2, 0x9b, 0x3c, 0x6d, 0x3f, 0x11, 0x60, 0x3b, 0x92, 0x04,
+        0xcb, 0xa8, 0x57, 0x33, 0x36, 0x8f, 0x10, 0x64, 0xd1, 0x72, 0x61, 0x1b, 0xbd, 0xb6, 0x62, 0x0b,
+        0xf6, 0xed, 0xaa, 0x36, 0xe1, 0xf5, 0xe9, 0xe3, 0xd5, 0xb9, 0x22, 0x08, 0x5f, 0xda, 0xde, 0x05,
+        0xd2, 0xea, 0x1a, 0x1c, 0xf4, 0x35, 0xd1, 0x44, 0xa4, 0xf7, 0x97, 0xe1, 0x24, 0xd7, 0xc6, 0x24,
+        0x5f, 0xe3, 0x41, 0x3a, 0xfd, 0x20, 0x5c, 0x9d, 0xcc, 0x6a, 0x56, 0xe4, 0xed, 0x3b, 0x24, 0x8c,
+        0x3d, 0x6c, 0xfb, 0x17, 0x62, 0x2f, 0x63, 0x36, 0x4f, 0xa6, 0x61, 0x16, 0x99, 0x38, 0xa3, 0x0e,
+        0x12, 0x4b, 0x5e, 0x4c, 0x1f, 0xb5, 0xb6, 0x97, 0x84, 0x4a, 0x8e, 0xcc, 0x43, 0xdc, 0xf0, 0xbc,
+        0xde, 0x44, 0xc0, 0x82, 0xaf, 0x20, 0xb4, 0x86, 0x93, 0x31, 0xe7, 0x7c, 0x7e, 0xff, 0x08, 0x23,
+        0x41, 0xf6, 0x00, 0x1d, 0xe1, 0x2c, 0x16, 0xb9, 0x69, 0xb7, 0x4f, 0x85, 0x1d, 0x05, 0x6a, 0x98,
+        0xc0, 0x51, 0xe8, 0x85, 0xa9, 0x07, 0xa6, 0x8c, 0xd4, 0x2c, 0x6f, 0x71, 0x92, 0x29, 0x43, 0x0f,
+        0x05, 0x78, 0x74, 0x25, 0x9c, 0x57, 0x52, 0x3d, 0x6d, 0x33, 0x15, 0x97, 0xa7, 0x39, 0xb2, 0x09,
+        0x47, 0x13, 0xb6, 0x62, 0x46, 0xb3, 0x0a, 0xff, 0x3d, 0x6e, 0xc1, 0xb5, 0xf2, 0x80, 0xf7, 0x11,
+        0xaf, 0x0b, 0xaf, 0x43, 0x0d, 0x20, 0x60, 0x99, 0x13, 0x66, 0x37, 0x32, 0x9b, 0x4b, 0xaa, 0x7f,
+        0x41, 0xa6, 0x4f, 0xa6, 0x1f, 0xbb, 0x7f, 0x7d, 0xba, 0xd4, 0xfb, 0xc4, 0x5a, 0x29, 0x35, 0x26,
+        0x59, 0xe0, 0x71, 0xd6, 0x16, 0x3a, 0x75, 0x57, 0x21, 0x46, 0x91, 0x53, 0x6e, 0xe1, 0xe6, 0xd8,
+        0x21, 0x1d, 0x4f, 0xbf, 0xd5, 0x38, 0xee, 0x63, 0xee, 0xe6, 0x2b, 0x84, 0x6f, 0xed, 0x3d, 0x03,
+        0xea, 0x79, 0x6f, 0xea, 0xbe, 0x58, 0x4c, 0x06, 0xd7, 0x5a, 0x81, 0xa5, 0x21, 0xeb, 0xcb, 0xc9,
+        0xf2, 0x4a, 0xd7, 0x9f, 0x71, 0x62, 0x9b, 0x8a, 0xdb, 0xb7, 0x9a, 0x09, 0x66, 0x34, 0xac, 0x15,
+        0x8f, 0x40, 0x8a, 0xc7, 0x41, 0x1f, 0xdf, 0x08, 0x29, 0x93, 0xdc, 0xef, 0x25, 0x83, 0xc4, 0x4d,
+        0xd2, 0xe2, 0x9e, 0x1d, 0x10, 0x2f, 0xca, 0x93, 0x1c, 0x20, 0x4d, 0x06, 0x25, 0xa9, 0xbf, 0x14,
+        0x3f, 0x74, 0xed, 0xcb, 0xab, 0x51, 0xec, 0xa5, 0x8d, 0x06, 0x58, 0xd6, 0xa7, 0xfa, 0x46, 0x6c,
+        0x19, 0x1d, 0x60, 0x36, 0x0a, 0x1c, 0xad, 0xb5, 0x88, 0xfb, 0xeb, 0x66, 0xc8, 0x49, 0xe1, 0x11,
+        0x10, 0x71, 0xb6, 0x6d, 0xbd, 0x2d, 0x56, 0x62, 0x0c, 0x0c, 0x5e, 0xf3, 0x95, 0x68, 0x73, 0x0c,
+        0x75, 0xf9, 0x31, 0x8c, 0x53, 0x70, 0xe6, 0xda, 0xcd, 0xd7, 0xb3, 0x4a, 0xd0, 0x81, 0x56, 0x2a,
+        0xe3, 0x2b, 0x8e, 0xee, 0xd0, 0x79, 0x3a, 0xaa, 0xa0, 0x0d, 0x18, 0x14, 0xba, 0x33, 0x6c, 0x03,
+        0xc4, 0x71, 0xad, 0xa8, 0x6f, 0xfe, 0x36, 0xb1, 0x6c, 0xf6, 0x56, 0x38, 0x4c, 0x5d, 0xfe, 0x0c,
+        0x2a, 0x0b, 0xc4, 0xc0, 0x5b, 0xdf, 0xe6, 0xb0, 0x71, 0x2c, 0x4b, 0x6e, 0x30, 0x61, 0xe3, 0xe8,
+        0x32, 0xd4, 0x25, 0xd3, 0x42, 0x5e, 0xd7, 0x71, 0x4e, 0xea, 0x60, 0x62, 0x04, 0x9d, 0x1d, 0x02,
+        0x7b, 0xf5, 0x35, 0xd6, 0x26, 0x41, 0x14, 0x07, 0xa7, 0x52, 0xb6, 0xbd, 0x7f, 0xb2, 0x43, 0x42,
+        0xbe, 0x9c, 0xb2, 0xf1, 0xd3, 0x20, 0x06, 0x21, 0xe3, 0xb0, 0xb6, 0x62, 0xb0, 0x34, 0xf3, 0x10,
+        0x0b, 0x13, 0xeb, 0x1e, 0x80, 0xd0, 0xdb, 0xd0, 0xe1, 0xe4, 0xad, 0xa4, 0xa3, 0xd1, 0xcf, 0xfe,
+        0xd3, 0xd2, 0x3b, 0xa9, 0x7a, 0xd3, 0xc5, 0x2b, 0x26, 0x7e, 0x87, 0x82, 0x18, 0xa8, 0xa4, 0x16,
+        0x6f, 0x52, 0x33, 0xe1, 0x3a, 0x7b, 0x78, 0x8f, 0xa5, 0x7a, 0x24, 0x71, 0xe4, 0x11, 0xb5, 0x9d,
+        0x0b, 0xdc, 0xda, 0xb6, 0xfc, 0x40, 0x2f, 0xa8, 0x10, 0x4d, 0xc2, 0x42, 0x85, 0xb0, 0x89, 0x0f,
+        0x41, 0xa5, 0xa4, 0x48, 0xe7, 0xb2, 0x37, 0x2b, 0x13, 0x71, 0xba, 0x50, 0xf7, 0x3a, 0xa1, 0xee,
+        0x16, 0x90, 0xab, 0xaa, 0xae, 0xbb, 0x94, 0xf5, 0xee, 0x87, 0x81, 0x1a, 0x56, 0xfe, 0x57, 0x28,
+        0x5b, 0x4b, 0xbf, 0x28, 0x8e, 0x1e, 0x9a, 0xf2, 0x45, 0x7a, 0x8b, 0x54, 0x4e, 0xff, 0x5c, 0x10,
+        0xf0, 0x4f, 0x01, 0x28, 0x55, 0x83, 0x19, 0xd7, 0xfc, 0xab, 0x8a, 0xef, 0x94, 0x36, 0x39, 0x19,
+        0x5f, 0x27, 0xb5, 0xa4, 0xbf, 0x15, 0x88, 0xe4, 0xfc, 0x58, 0x5c, 0x90, 0xd3, 0xb9, 0xa4, 0xe4,
+        0x64, 0x41, 0xa9, 0x9f, 0xb6, 0xf1, 0x6a, 0xbb, 0x8b, 0x76, 0xb9, 0x63, 0xaf, 0x81, 0x21, 0x18,
+        0xfd, 0x19, 0x86, 0x64, 0x8d, 0x31, 0x2d, 0x0d, 0x61, 0xbd, 0xa0, 0x52, 0x01, 0x57, 0x6a, 0x9a,
+        0xf7, 0xe7, 0x88, 0x82, 0x75, 0x4b, 0x1a, 0xef, 0xa5, 0x3e, 0x6a, 0x20, 0x94, 0xc5, 0x86, 0x25,
+        0x74, 0x57, 0x9c, 0x07, 0x46, 0xc3, 0xfc, 0x89, 0x94, 0x3b, 0xf5, 0x4a, 0x3b, 0xd3, 0x63, 0xb8,
+        0xc9, 0xc8, 0x40, 0x3b, 0x50, 0xb2, 0x79, 0xda, 0xe8, 0xaa, 0x8f, 0xc7, 0x8b, 0x97, 0x7b, 0x23,
+        0xf1, 0x0a, 0x3a, 0x7b, 0xd3, 0x46, 0x84, 0x5f, 0x1c, 0x30, 0xf6, 0x45, 0xf9, 0x13, 0x06, 0xd4,
+        0x31, 0xad, 0xa0, 0x4a, 0x92, 0xce, 0x76, 0xb4, 0xc0, 0x70, 0xf5, 0x67, 0x08, 0x81, 0xd4, 0x17,
+        0x6d, 0x16, 0x5f, 0x23, 0xaa, 0x91, 0x75, 0x3a, 0xa5, 0xf3, 0xc0, 0xd7, 0xc2, 0x6d, 0x5e, 0x1a,
+        0xfe, 0xd7, 0x50, 0x78, 0xb8, 0x0c, 0xb6, 0x47, 0xcd, 0xf9, 0xd5, 0xfb, 0x45, 0xc9, 0x5f, 0x15,
+        0x2e, 0xde, 0x82, 0xc9, 0xa3, 0x64, 0x25, 0xbb, 0x84, 0x08, 0xbd, 0xa7, 0x50, 0x6e, 0xf7, 0x6f,
+        0x27, 0x28, 0xff, 0xe8, 0x1e, 0x5c, 0x87, 0xf3, 0xcf, 0x44, 0x7c, 0xf1, 0xf8, 0xf3, 0xdf, 0x0d,
+        0x70, 0x22, 0x53, 0xa5, 0x07, 0x3b, 0xb2, 0x79, 0xd1, 0x24, 0x71, 0xbf, 0x4e, 0x53, 0x10, 0xb5,
+        0x18, 0xe7, 0xe3, 0x09, 0x77, 0xea, 0xbf, 0xd9, 0x9a, 0x41, 0x99, 0x86, 0x85, 0x53, 0xf2, 0x2b,
+        0xec, 0x39, 0x89, 0xd4, 0x08, 0xf8, 0x32, 0x33, 0x08, 0xad, 0x7e, 0x0e, 0x35, 0x17, 0x9a, 0xf5,
+        0x8b, 0x58, 0xfa, 0xca, 0x50, 0xb7, 0xfd, 0x87, 0x1b, 0x72, 0xd0, 0x1b, 0xea, 0x44, 0xc3, 0x19,
+        0x97, 0xe1, 0xa7, 0xb3, 0x33, 0xd8, 0xeb, 0xdb, 0x54, 0x63, 0x37, 0xe0, 0x84, 0x12, 0x90, 0x07,
+        0xb2, 0x34, 0xef, 0x3f, 0xd9, 0x9d, 0x8b, 0x68, 0x12, 0x9b, 0x18, 0x4c, 0x68, 0xfb, 0x74, 0x2b,
+        0x01, 0xfe, 0x9c, 0x84, 0x60, 0x1e, 0xbc, 0x5e, 0x44, 0xc9, 0x15, 0xe1, 0x40, 0x60, 0x7f, 0x3c,
+        0xf5, 0x99, 0x26, 0xae, 0xdf, 0x6c, 0xa9, 0x42, 0xa2, 0x67, 0x58, 0x05, 0x8f, 0xd2, 0xe4, 0x0d,
+        0xf4, 0xc5, 0x05, 0x0c, 0x83, 0xc8, 0x5a, 0x43, 0x94, 0xf1, 0x65, 0xf6, 0x49, 0x1f, 0x63, 0x5c,
+        0x38, 0x58, 0x7c, 0x47, 0xdc, 0x3c, 0x1f, 0x11, 0xa6, 0x34, 0x61, 0x2a, 0x72, 0xd1, 0x93, 0x11,
+        0x59, 0xc2, 0x81, 0x1f, 0xd8, 0xac, 0x7a, 0x02, 0x55, 0xad, 0xe6, 0x9e, 0xdc, 0x91, 0xa5, 0xe3,
+        0xed, 0xc8, 0x17, 0xff, 0xc3, 0xa4, 0xe0, 0x7b, 0x3c, 0xc3, 0xe4, 0x79, 0xe0, 0x9d, 0x90, 0x0f,
+        0x53, 0xe8, 0xbe, 0x74, 0xcc, 0x35, 0x67, 0xbd, 0xf0, 0xfb, 0x08, 0x69, 0xaa, 0x48, 0x3d, 0xf8,
+        0x0b, 0x45, 0xb4, 0x37, 0x3b, 0x47, 0x8f, 0x95, 0x45, 0xa2, 0xb1, 0x45, 0xd7, 0xea, 0x44, 0x07,
+        0x7d, 0x8b, 0x40, 0x07, 0x44, 0xf4, 0x45, 0x30, 0x2e, 0xe7, 0x29, 0x9a, 0xd1, 0x8f, 0x41, 0x8c,
+        0x8f, 0x38, 0x8f, 0xda, 0x64, 0x3d, 0x2f, 0xa8, 0x30, 0x9d, 0x7e, 0x06, 0x11, 0x0e, 0x86, 0x0a,
+        0x32, 0x64, 0x1a, 0xbc, 0x12, 0x98, 0xeb, 0x32, 0xef, 0x31, 0x23, 0xc1, 0xdd, 0x94, 0x6e, 0x03,
+        0x61, 0x19, 0xe4, 0x8f, 0xf4, 0x0a, 0xe8, 0xa2, 0x63, 0x23, 0x58, 0x28, 0xeb, 0xe3, 0x8e, 0x21,
+        0x62, 0x6a, 0xe3, 0x1b, 0x75, 0x2d, 0xa3, 0xe7, 0x50, 0x70, 0x5e, 0x9c, 0x58, 0xfe, 0x46, 0x97,
+        0x0d, 0xc1, 0x18, 0x19, 0xb3, 0x55, 0x08, 0x81, 0xa3, 0x54, 0x3e, 0x2f, 0x79, 0xfb, 0x15, 0x09,
+        0xb7, 0x4f, 0xce, 0x12, 0xd4, 0xb8, 0x34, 0x23, 0x59, 0x9f, 0x9d, 0x38, 0xfb, 0x52, 0xc7, 0xa6,
+        0x7b, 0x5b, 0x25, 0xc3, 0x0b, 0xaa, 0xbd, 0x91, 0xb4, 0x6e, 0xd9, 0xa9, 0x6f, 0xb7, 0xf7, 0x07,
+        0xc7, 0x85, 0x88, 0x08, 0x38, 0xa2, 0x90, 0xda, 0xbc, 0x2b, 0x33, 0x6b, 0x1e, 0x22, 0x80, 0x08,
+        0xca, 0x5c, 0x97, 0xc9, 0xd4, 0xb5, 0xe2, 0x5b, 0xda, 0xc5, 0x51, 0x23, 0x97, 0x23, 0x7f, 0x26,
+        0x2c, 0x1e, 0x1c, 0x27, 0x83, 0xbc, 0x7b, 0xe2, 0xd2, 0x1d, 0xd5, 0xb0, 0x52, 0x7e, 0x42, 0x4c,
+        0xee, 0xa1, 0x21, 0x90, 0x00, 0x42, 0x34, 0x61, 0xfa, 0x23, 0xdc, 0x40, 0xc8, 0x36, 0x42, 0x15,
+        0x74, 0x54, 0xc2, 0x80, 0x24, 0x85, 0xb0, 0x2e, 0xce, 0x4e, 0x51, 0xb9, 0xb1, 0x00, 0x1e, 0x24,
+        0x0a, 0x8c, 0x2f, 0x28, 0x4b, 0x6f, 0x93, 0x1b, 0xe5, 0x47, 0xf1, 0xf4, 0x4f, 0x08, 0xa6, 0x0b,
+        0x67, 0xbb, 0x00, 0x16, 0x9f, 0x65, 0x2b, 0x1a, 0xda, 0x32, 0x33, 0xa6, 0xb3, 0xaa, 0xee, 0xba,
+        0xd0, 0xc0, 0x23, 0x2e, 0x23, 0x82, 0x3e, 0x83, 0x09, 0xd9, 0x12, 0xf6, 0xf9, 0x07, 0xc0, 0x2b,
+        0xbb, 0x36, 0xd4, 0x08, 0x4a, 0x59, 0x6d, 0x06, 0x75, 0xa1, 0xad, 0x12, 0x65, 0x1b, 0x15, 0x46,
+        0x0d, 0x88, 0xb9, 0xf7, 0xb1, 0x92, 0xc3, 0xb4, 0x62, 0x3f, 0x20, 0xd9, 0x28, 0x6e, 0xf0, 0x0b,
+        0x64, 0x37, 0x7d, 0x9a, 0x11, 0x1a, 0xf7, 0x3d, 0xde, 0x04, 0x73, 0xcc, 0xbb, 0x8f, 0x45, 0xa3,
+        0x52, 0x92, 0x95, 0xf2, 0x2d, 0xdf, 0x21, 0x72, 0x24, 0x7f, 0x63, 0x6e, 0x61, 0x46, 0x1c, 0x26,
+        0xec, 0x35, 0x8b, 0x82, 0x3f, 0x27, 0x82, 0x90, 0xc6, 0x89, 0xe3, 0x54, 0x8d, 0x64, 0xa3, 0x2b,
+        0x7f, 0x6e, 0x3d, 0x9b, 0xf7, 0x44, 0x9a, 0xd5, 0xd7, 0xe3, 0x06, 0x91, 0xa9, 0xf6, 0x27, 0x20,
+        0xe7, 0xde, 0x5f, 0x93, 0xbd, 0xe0, 0x98, 0x4b, 0x11, 0x3b, 0xe9, 0x80, 0xa5, 0xdf, 0xda, 0x95,
+        0x77, 0x8c, 0x53, 0x6b, 0x54, 0x70, 0xb5, 0xa5, 0x42, 0x46, 0xc2, 0x20, 0xa2, 0xf4, 0x3a, 0x1b,
+        0x35, 0x37, 0x2d, 0xd2, 0x1d, 0xb8, 0x4c, 0x4d, 0xcd, 0x10, 0xcf, 0x13, 0x5a, 0x1e, 0x05, 0xc3,
+        0x47, 0xac, 0x73, 0x17, 0xa8, 0x2f, 0xbc, 0xf3, 0xcf, 0xa9, 0x73, 0x08, 0x83, 0x4f, 0xd5, 0x2e,
+        0x92, 0x29, 0xfc, 0x9b, 0x1f, 0xe2, 0xf4, 0xc0, 0x57, 0xfe, 0x01, 0x6a, 0xc1, 0xe2, 0x86, 0x91,
+        0xaa, 0xe9, 0x90, 0x26, 0xf4, 0x45, 0x29, 0x99, 0xba, 0xf8, 0x7f, 0xc1, 0x3c, 0xb3, 0x07, 0x03,
+        0xa2, 0x62, 0x6f, 0x87, 0x21, 0xe8, 0xa2, 0x2a, 0x65, 0x19, 0x78, 0x21, 0x71, 0x60, 0x64, 0xbe,
+        0x7e, 0x17, 0xd9, 0xb8, 0x70, 0x9f, 0x5f, 0x89, 0xf3, 0x7d, 0xfd, 0x2f, 0x7d, 0x12, 0xe5, 0x10,
+        0x1e, 0x90, 0x1c, 0x01, 0x61, 0x7d, 0x3a, 0x06, 0x2c, 0xa7, 0xf4, 0xfb, 0x17, 0x78, 0x8d, 0xb4,
+        0xe3, 0x04, 0x61, 0x69, 0xf1, 0x86, 0x6a, 0x66, 0xdf, 0xb6, 0x91, 0x4f, 0xe2, 0xf2, 0x45, 0x19,
+        0x8f, 0x1a, 0x79, 0x3c, 0xd0, 0x99, 0x32, 0x11, 0xa8, 0x32, 0x3c, 0x32, 0xee, 0x37, 0x52, 0x38,
+        0xa4, 0x15, 0xeb, 0x41, 0x4c, 0xb3, 0xa8, 0x7d, 0xbc, 0x0d, 0xe9, 0xab, 0xaa, 0x0d, 0x6a, 0x06,
+        0x39, 0x57, 0xa5, 0xcc, 0xa3, 0xf3, 0x2c, 0x96, 0x56, 0x75, 0x3c, 0x2a, 0x97, 0xcc, 0xea, 0xa1,
+        0xdb, 0x10, 0xcb, 0x2b, 0x4a, 0x29, 0x01, 0x4a, 0xe6, 0x62, 0x10, 0x64, 0xec, 0x24, 0x9e, 0x00,
+        0xf1, 0xb6, 0x59, 0xca, 0xa8, 0x8e, 0xd9, 0xc7, 0x45, 0xb2, 0xc7, 0x57, 0x8b, 0xd4, 0x75, 0xdc,
+        0x4c, 0x5b, 0xdd, 0x61, 0xac, 0x2d, 0xbc, 0x31, 0x69, 0x39, 0x90, 0x88, 0x08, 0xdc, 0x24, 0x1a,
+        0xaa, 0x36, 0xf0, 0xa8, 0x7e, 0x78, 0x28, 0x2f, 0xa1, 0x6e, 0xc6, 0x2b, 0xd3, 0xde, 0x1d, 0xc8,
+        0x27, 0x4a, 0x78, 0x35, 0x52, 0xa6, 0xb2, 0x09, 0xe1, 0x25, 0x64, 0xed, 0x69, 0x10, 0xaa, 0x15,
+        0xba, 0x9e, 0x42, 0x61, 0xfc, 0x8f, 0xdd, 0x85, 0x4d, 0xaa, 0x47, 0xaf, 0xd9, 0x37, 0x32, 0xce,
+        0xb7, 0x9c, 0x9c, 0x80, 0x4f, 0xc2, 0x48, 0x48, 0xa4, 0x57, 0xd8, 0xe8, 0x1d, 0xca, 0x2f, 0x24,
+        0x79, 0x69, 0x18, 0x9d, 0xb9, 0x9d, 0x56, 0xdd, 0x09, 0x2a, 0xf1, 0x5c, 0xcb, 0xba, 0xa0, 0x0b,
+        0x7d, 0x8f, 0xe8, 0xc3, 0x26, 0x3f, 0x70, 0x1d, 0xb1, 0xa1, 0xbd, 0xe2, 0xd6, 0xd1, 0xe6, 0x06,
+        0xa3, 0x14, 0xf2, 0xc6, 0x35, 0x29, 0x6e, 0xd3, 0x1a, 0xed, 0xd3, 0x63, 0x1a, 0x5e, 0x70, 0x30,
+        0x7f, 0x9d, 0x28, 0xd5, 0xac, 0xee, 0xdc, 0x7e, 0xa0, 0xda, 0x08, 0xb2, 0x99, 0x64, 0x82, 0x20,
+        0xc9, 0x03, 0xa6, 0xb5, 0x24, 0xa8, 0xdb, 0xe8, 0x55, 0x74, 0xb8, 0x04, 0xb7, 0x04, 0x39, 0xa5,
+        0x5a, 0xbe, 0x82, 0x07, 0x50, 0x79, 0x17, 0x81, 0xb6, 0x27, 0x86, 0x7a, 0x5d, 0x62, 0x68, 0x0d,
+        0xad, 0x9e, 0xd6, 0xf2, 0x9b, 0xf5, 0x1e, 0xee, 0xf3, 0xbd, 0xb7, 0xdb, 0x1a, 0xd4, 0x02, 0x9c,
+        0xb4, 0x95, 0xc2, 0xb3, 0xd6, 0x0f, 0x61, 0x15, 0x38, 0x51, 0x4c, 0x36, 0x33, 0xdb, 0x40, 0x2a,
+        0x36, 0x93, 0xf3, 0xe3, 0x6e, 0x7a, 0x37, 0x7f, 0xc7, 0xaf, 0x8b, 0x71, 0x98, 0x00, 0x6a, 0x29,
+        0xa9, 0xe1, 0xf2, 0x51, 0x83, 0x6b, 0xe9, 0x80, 0x4f, 0x20, 0xad, 0xef, 0x51, 0x53, 0x8f, 0x2a,
+        0x4d, 0x73, 0x82, 0x9c, 0x7f, 0x79, 0x2d, 0xfa, 0x7a, 0x4d, 0xe4, 0x21, 0x48, 0x25, 0xd8, 0x46,
+        0x98, 0x90, 0x1c, 0x6d, 0xe4, 0x02, 0xac, 0xf2, 0xf2, 0x91, 0xad, 0xc4, 0x1b, 0x3d, 0x47, 0x14,
+        0x7a, 0x2b, 0x66, 0x20, 0x52, 0x3d, 0x3c, 0x30, 0xd5, 0xb6, 0x2b, 0x45, 0x2f, 0x42, 0xbb, 0xb2,
+        0x56, 0x4e, 0x33, 0x99, 0x5e, 0x9e, 0xf7, 0x1b, 0x72, 0x35, 0x19, 0xef, 0xed, 0x9c, 0x3c, 0x2d,
+        0x30, 0x19, 0x71, 0x28, 0xcd, 0xb8, 0x78, 0x85, 0x3c, 0x2f, 0x75, 0xac, 0xe9, 0x7b, 0x48, 0x3e,
+        0x9f, 0xb3, 0xea, 0x6f, 0x9f, 0xb2, 0x92, 0xc2, 0xef, 0xa4, 0x74, 0x8d, 0xe4, 0x20, 0x42, 0x11,
+        0x96, 0x0b, 0x0b, 0xbb, 0xd0, 0x46, 0xfc, 0x03, 0x5f, 0xdb, 0xba, 0xfd, 0x9b, 0x15, 0x3e, 0x3c,
+        0x13, 0xba, 0xdd, 0xfc, 0x6b, 0xde, 0x4a, 0xc9, 0xa3, 0x5f, 0x07, 0xa7, 0x00, 0x4b, 0x89, 0x20,
+        0xae, 0xae, 0x37, 0x8e, 0x59, 0xcd, 0x45, 0x99, 0xf0, 0xcb, 0xee, 0x8a, 0x73, 0xbc, 0xca, 0xf7,
+        0x30, 0x02, 0xd2, 0xed, 0x1f, 0xca, 0x19, 0x27, 0x6d, 0xee, 0xd8, 0x1a, 0x0b, 0xc4, 0xe9, 0x04,
+        0xb1, 0x35, 0x71, 0xee, 0x01, 0x5a, 0x65, 0xc5, 0xd4, 0xb6, 0xaf, 0x7d, 0x0c, 0x69, 0x9f, 0xd5,
+        0x4a, 0x38, 0x4a, 0x43, 0x59, 0x89, 0x58, 0x1c, 0x1e, 0x9b, 0x65, 0x08, 0xe1, 0xeb, 0x40, 0x18,
+        0x24, 0xe4, 0xc7, 0xd7, 0xd0, 0x73, 0xd2, 0x88, 0x47, 0x57, 0x97, 0xab, 0xbd, 0x85, 0xb7, 0x45,
+        0xac, 0x4c, 0xae, 0x61, 0x06, 0xfc, 0x63, 0x7f, 0x80, 0x0b, 0x28, 0x76, 0x4f, 0x11, 0x6a, 0x20,
+        0xae, 0x93, 0xd0, 0x34, 0x95, 0x1c, 0x4a, 0x6d, 0x20, 0x8a, 0x0e, 0x2b, 0xf0, 0xd9, 0x89, 0xb7,
+        0x01, 0xf7, 0x22, 0x46, 0xcc, 0x62, 0x3a, 0xa6, 0x11, 0x4a, 0xe9, 0xb8, 0xd8, 0x20, 0x53, 0x19,
+        0x48, 0xff, 0x5c, 0x6f, 0xa1, 0x5a, 0xfb, 0x77, 0xba, 0x1c, 0x56, 0xbe, 0x0f, 0xeb, 0x37, 0xdc,
+        0xf2, 0xbc, 0xe7, 0x36, 0xc6, 0x7d, 0x5e, 0x53, 0x0a, 0x48, 0xfe, 0x81, 0xdc, 0xc2, 0x0a, 0x1c,
+        0x1d, 0x77, 0x21, 0x4b, 0xc8, 0xcd, 0x33, 0xf2, 0x5c, 0x24, 0x93, 0x5e, 0x83, 0x48, 0x1e, 0x8e,
+        0x84, 0x6f, 0xc5, 0x0f, 0x2c, 0x51, 0x9f, 0x7e, 0xa9, 0xd2, 0x0b, 0x41, 0xc7, 0xd5, 0x62, 0x18,
+        0x45, 0xe8, 0x6a, 0xa3, 0x70, 0xb1, 0xbe, 0x1b, 0x2d, 0x75, 0x2f, 0xb7, 0x83, 0x10, 0xf9, 0x55,
+        0xb6, 0xd3, 0xca, 0x47, 0x0f, 0x3b, 0x55, 0xd6, 0x1e, 0x93, 0xd7, 0x9d, 0x0b, 0xc7, 0x0b, 0x01,
+        0xa4, 0x7b, 0xba, 0xb7, 0xd2, 0xdb, 0xde, 0x66, 0xc1, 0x59, 0xab, 0xa1, 0xa2, 0xe5, 0x34, 0x9d,
+        0x71, 0xbe, 0xb9, 0x76, 0x81, 0xe8, 0xd8, 0x55, 0xe6, 0x43, 0xa7, 0xcd, 0x0d, 0x31, 0x0b, 0x21,
+        0x1b, 0xf9, 0xac, 0xf3, 0x2e, 0xc3, 0x95, 0x9b, 0x56, 0x35, 0xd6, 0x21, 0x9f, 0x31, 0x4c, 0x1e,
+        0xb8, 0xa3, 0x43, 0xe9, 0x5a, 0x68, 0x2f, 0x0b, 0x7b, 0x3c, 0xbf, 0xc1, 0xb3, 0x86, 0x82, 0x2c,
+        0x81, 0xd7, 0xdc, 0xd5, 0xf5, 0xad, 0x3f, 0x7f, 0xa2, 0x58, 0x6e, 0x4b, 0xe2, 0x2c, 0x31, 0x14,
+        0x44, 0xf4, 0x4d, 0xd2, 0x24, 0x87, 0x1e, 0x41, 0xb0, 0xe0, 0x7e, 0x6d, 0xc1, 0xdc, 0xd9, 0x2d,
+        0x5a, 0xb9, 0x46, 0x9e, 0x0e, 0x30, 0x99, 0xe3, 0x81, 0x9a, 0x57, 0xaa, 0x99, 0x51, 0x4c, 0x16,
+        0xde, 0x08, 0xa5, 0xfd, 0x15, 0x4e, 0x52, 0x4a, 0x08, 0x5f, 0xcc, 0x28, 0xe9, 0xc2, 0xed, 0x1e,
+        0xa5, 0x79, 0xac, 0x7d, 0xe6, 0xbf, 0x22, 0xd5, 0x6b, 0x35, 0xae, 0x99, 0x12, 0xfd, 0x9b, 0x73,
+        0xe1, 0x7f, 0xe2, 0x56, 0x23, 0x2f, 0x05, 0x7e, 0x0b, 0x01, 0xe1, 0xad, 0x29, 0x37, 0x08, 0x0a,
+        0x8d, 0x6f, 0xeb, 0x2c, 0xeb, 0x56, 0x57, 0x08, 0xdf, 0x23, 0x65, 0xbe, 0x7d, 0xb6, 0xb1, 0x6b,
+        0x7d, 0xbb, 0xe9, 0x42, 0xa7, 0x50, 0x75, 0x08, 0x99, 0x09, 0x4f, 0xe0, 0x4b, 0x93, 0x70, 0x0f,
+        0xf3, 0x9d, 0x24, 0x41, 0xd7, 0x59, 0xd6, 0xfb, 0xc1, 0x75, 0x40, 0xc9, 0x20, 0x69, 0x8c, 0xeb,
+        0x92, 0xe2, 0x53, 0xe3, 0x5a, 0x49, 0x20, 0x20, 0xc7, 0x0c, 0x0c, 0xd1, 0xcd, 0xb5, 0x93, 0x08,
+        0x67, 0x09, 0x50, 0x75, 0xa6, 0xb2, 0xfb, 0x84, 0x16, 0x76, 0x0f, 0xb2, 0x22, 0x37, 0xe0, 0xe1,
+        0x6d, 0xee, 0xbf, 0x4d, 0x38, 0x93, 0xd8, 0x8a, 0x79, 0x87, 0x4a, 0x67, 0x5f, 0xfc, 0xed, 0x20,
+        0xee, 0x19, 0xa5, 0x0e, 0x1c, 0xa0, 0xd7, 0xc2, 0x11, 0x97, 0xb2, 0xaf, 0x11, 0x7e, 0xa5, 0xc9,
+        0xfd, 0x3f, 0x74, 0xa8, 0x7f, 0x22, 0xc5, 0x8b, 0x43, 0x56, 0x27, 0x3d, 0x20, 0x82, 0x0e, 0x07,
+        0x52, 0x4e, 0x1b, 0xd1, 0xde, 0x13, 0xac, 0xd8, 0x54, 0x4b, 0xa5, 0xfe, 0xe6, 0x60, 0x6b, 0x88,
+        0xee, 0xe9, 0x59, 0x52, 0xba, 0xec, 0x20, 0xe6, 0xb0, 0xc4, 0xca, 0xb8, 0x58, 0x42, 0x3e, 0x25,
+        0x54, 0x45, 0x9c, 0x7b, 0x52, 0x29, 0x90, 0x32, 0xb5, 0xc2, 0xaa, 0x20, 0xac, 0xb6, 0x20, 0xdf,
+        0xc5, 0xfe, 0xec, 0x72, 0x59, 0xbc, 0x56, 0x61, 0xea, 0x0a, 0x2a, 0xe9, 0x10, 0x16, 0x39, 0x0b,
+        0x2f, 0x34, 0x23, 0x43, 0x4b, 0x0c, 0xdf, 0xbe, 0x35, 0xbf, 0xa9, 0xfb, 0x93, 0x6a, 0x49, 0x03,
+        0xe8, 0x80, 0xd8, 0x7f, 0x4f, 0xd2, 0xc6, 0x30, 0x10, 0xb6, 0x28, 0xd6, 0x45, 0x1b, 0x45, 0x25,
+        0x81, 0x1a, 0xf5, 0x3d, 0x12, 0x21, 0xd5, 0x61, 0x88, 0xf3, 0x8b, 0xc0, 0x63, 0xc6, 0x44, 0xbc,
+        0xc9, 0xbc, 0x00, 0x87, 0xff, 0x55, 0x08, 0x4d, 0xdf, 0x87, 0x5e, 0x8c, 0xb6, 0xc1, 0xee, 0x21,
+        0xaa, 0xea, 0x23, 0xcf, 0x67, 0x38, 0x92, 0x21, 0xae, 0x8c, 0xfb, 0x7d, 0x83, 0xe5, 0x8f, 0x17,
+        0x17, 0x8b, 0x3b, 0x00, 0x76, 0x14, 0x8e, 0xb6, 0x63, 0xde, 0xdf, 0xb1, 0x24, 0x5c, 0x53, 0x04,
+        0xe0, 0xf7, 0x35, 0xdc, 0x37, 0xad, 0xe9, 0x39, 0xff, 0xf0, 0x62, 0x6a, 0x51, 0xd8, 0xd6, 0x97,
+        0x02, 0xce, 0x47, 0x96, 0x57, 0x51, 0xe9, 0x72, 0x21, 0x7f, 0xe0, 0xa5, 0xe7, 0x03, 0xab, 0x1a,
+        0xbc, 0x05, 0xdc, 0x02, 0x2a, 0x14, 0x7e, 0x5a, 0x2e, 0x7a, 0x1a, 0x33, 0xec, 0x86, 0x0d, 0x21,
+        0xa5, 0x40, 0xb4, 0x43, 0x6a, 0x33, 0xc4, 0xda, 0xbf, 0xfc, 0x99, 0x39, 0x6c, 0x9d, 0x7b, 0x27,
+        0x4c, 0x79, 0x14, 0x53, 0x45, 0xf6, 0x81, 0xe0, 0x65, 0x60, 0x47, 0x3d, 0x9b, 0x49, 0x01, 0x7a,
+        0xcc, 0xc8, 0xdd, 0xe5, 0x46, 0x2e, 0x10, 0x9b, 0x4b, 0x30, 0xfb, 0x36, 0x43, 0x78, 0x1a, 0x02,
+        0x22, 0xbb, 0xb4, 0x8b, 0x31, 0xfc, 0x2a, 0x52, 0xe6, 0x07, 0x01, 0x5c, 0x6e, 0x11, 0x68, 0x30,
+        0x6d, 0x3f, 0xa2, 0x0d, 0xc3, 0xfc, 0xdf, 0x29, 0xf7, 0xd8, 0xa9, 0x88, 0x37, 0x81, 0x33, 0x10,
+        0x56, 0xb8, 0xbc, 0x9a, 0x6f, 0x3d, 0x00, 0x32, 0x3f, 0x33, 0x0c, 0x10, 0x71, 0x72, 0xaa, 0x17,
+        0x63, 0x7b, 0xfe, 0xdd, 0x61, 0x6b, 0xd8, 0x65, 0x59, 0xb1, 0x8c, 0xe6, 0x4c, 0x24, 0xa1, 0x04,
+        0x91, 0x35, 0xd0, 0x92, 0xb4, 0x64, 0x9b, 0xd7, 0x1c, 0x6b, 0x4e, 0x55, 0xd5, 0x18, 0x73, 0x37,
+        0xee, 0x75, 0x6a, 0xab, 0xaf, 0x62, 0xe1, 0xd5, 0xdc, 0xb4, 0x63, 0x43, 0x75, 0x73, 0x4b, 0x2a,
+        0xe3, 0xd9, 0xae, 0x58, 0xf0, 0xe9, 0xf5, 0xc6, 0x99, 0x8d, 0x03, 0x9f, 0xc4, 0xcb, 0xd3, 0xf9,
+        0x69, 0x4f, 0x4a, 0x11, 0xcf, 0x79, 0x4b, 0x8e, 0x49, 0x77, 0x1f, 0xa2, 0x76, 0xc7, 0x4c, 0x01,
+        0x29, 0xb2, 0x87, 0x3e, 0x81, 0xef, 0x43, 0x25, 0xb4, 0x86, 0x6d, 0xeb, 0x38, 0xcb, 0x7b, 0x82,
+        0x44, 0x13, 0x7e, 0x33, 0x2a, 0x6c, 0x25, 0x97, 0xee, 0x07, 0xce, 0x7d, 0x7e, 0x4f, 0x2d, 0x04,
+        0x6a, 0xe2, 0xf4, 0x79, 0x27, 0xad, 0x6c, 0xb8, 0x42, 0xe0, 0xfd, 0x6b, 0x0a, 0xb0, 0x2a, 0x67,
+        0x7b, 0x35, 0x71, 0x11, 0x30, 0x2a, 0xc8, 0xcf, 0x1a, 0x74, 0x9b, 0x9c, 0x68, 0xef, 0xc4, 0x06,
+        0x88, 0x2c, 0x7f, 0x28, 0x2b, 0xa8, 0x72, 0xb4, 0x92, 0x68, 0x3c, 0x22, 0x81, 0xc1, 0x8f, 0xbd,
+        0xc6, 0x14, 0x54, 0x00, 0x4d, 0x17, 0xe2, 0x30, 0x14, 0xcd, 0x73, 0xff, 0x20, 0xb2, 0xfe, 0x21,
+        0xb4, 0x8c, 0x58, 0xa0, 0x40, 0xa5, 0xe1, 0xd7, 0x24, 0x30, 0x10, 0x0c, 0xf0, 0xac, 0x87, 0xd5,
+        0xdc, 0x63, 0xad, 0x7c, 0x2e, 0x07, 0xc6, 0x8f, 0x98, 0xaa, 0xe9, 0xf6, 0xb8, 0x62, 0x7b, 0x28,
+        0x16, 0xfc, 0xaa, 0x91, 0x74, 0x9b, 0x52, 0xff, 0x68, 0xb0, 0x13, 0xe7, 0x6d, 0x16, 0x8d, 0x0a,
+        0xdd, 0x1a, 0xce, 0x61, 0x79, 0x04, 0xaa, 0x7b, 0xac, 0x2f, 0xfb, 0x49, 0x7f, 0x36, 0x49, 0x2f,
+        0xf8, 0x25, 0x45, 0x5d, 0xc8, 0x09, 0x41, 0x19, 0x25, 0x0f, 0x95, 0x00, 0x25, 0x0f, 0xcf, 0xec,
+        0x9e, 0xcf, 0xe8, 0x70, 0xc5, 0xd9, 0x0d, 0x16, 0xcd, 0xc2, 0x24, 0x90, 0x25, 0x1a, 0x88, 0x1e,
+        0xc9, 0xaa, 0x3b, 0x06, 0xde, 0x21, 0xc5, 0x27, 0x88, 0x6e, 0x68, 0x76, 0xd5, 0x54, 0x05, 0x89,
+        0x8d, 0x3c, 0xab, 0x0e, 0x39, 0x31, 0x3c, 0xaa, 0x83, 0x0e, 0x6d, 0xf9, 0x94, 0x3d, 0x29, 0x11,
+        0xab, 0xb2, 0xd3, 0xb4, 0x0c, 0x7b, 0x6f, 0xa0, 0xca, 0x26, 0x13, 0x71, 0xdd, 0x52, 0x18, 0x9e,
+        0x53, 0x64, 0xe7, 0xc2, 0xad, 0x5d, 0x8b, 0x4e, 0x7c, 0xc3, 0x31, 0x84, 0xbc, 0xc0, 0x0d, 0x1d,
+        0x7b, 0x2d, 0x17, 0x5a, 0xbe, 0xbf, 0xab, 0x5f, 0x25, 0xe8, 0x14, 0x8f, 0xda, 0xab, 0x14, 0xb6,
+        0xe0, 0xd1, 0x0c, 0x54, 0xd9, 0x72, 0x99, 0x8c, 0xc9, 0x11, 0x4a, 0xfd, 0x0c, 0xa1, 0xde, 0x0e,
+        0xdc, 0x86, 0x8c, 0x92, 0x48, 0xcd, 0xd0, 0x2a, 0x26, 0x34, 0x3b, 0x54, 0x0c, 0x13, 0xad, 0x43,
+        0xee, 0x51, 0xca, 0x59, 0x88, 0xdd, 0x36, 0x7a, 0xbe, 0x09, 0x37, 0xa8, 0x05, 0xe4, 0x26, 0x18,
+        0x37, 0x39, 0x4c, 0x71, 0xa0, 0xef, 0xba, 0x1e, 0x61, 0xe3, 0x6c, 0x93, 0x56, 0x9e, 0x91, 0xe4,
+        0x29, 0xd6, 0x26, 0xb5, 0x10, 0xa9, 0x22, 0xfe, 0xa5, 0xf6, 0xe5, 0x5e, 0x56, 0x48, 0x69, 0x2b,
+        0x0f, 0xf7, 0xf3, 0xd5, 0xec, 0xe7, 0x4b, 0x57, 0x20, 0xdc, 0xcd, 0x7f, 0x89, 0xd8, 0xd3, 0x73,
+        0x64, 0x8f, 0x8a, 0x08, 0xa5, 0x5f, 0x8b, 0x91, 0xff, 0x14, 0x72, 0x7f, 0x42, 0x82, 0x24, 0x1b,
+        0x75, 0x92, 0xd2, 0xe1, 0x2d, 0xe8, 0xe2, 0x34, 0xf3, 0xf5, 0xd7, 0x43, 0xbc, 0x64, 0xa6, 0xc0,
+        0x34, 0x91, 0x9b, 0xfa, 0xc7, 0x8b, 0xff, 0xa7, 0x70, 0xbd, 0x4b, 0xb4, 0x20, 0xd0, 0xd4, 0x0c,
+        0x5b, 0xe4, 0x25, 0xd4, 0x9a, 0x03, 0xa9, 0xff, 0x5a, 0x5d, 0xdb, 0x50, 0x0a, 0xb7, 0x94, 0x52,
+        0xf5, 0x39, 0x1b, 0x10, 0xb7, 0x5c, 0xc1, 0xe7, 0xbf, 0x74, 0x1e, 0x3c, 0xc3, 0x0a, 0x0c, 0x05,
+        0xd7, 0x9d, 0xd8, 0x92, 0xef, 0x4a, 0xff, 0xad, 0x0b, 0x3c, 0x22, 0x93, 0x22, 0x4f, 0x67, 0xd7,
+        0xd9, 0x55, 0x55, 0xf1, 0xb1, 0x0e, 0xc3, 0x8b, 0xb9, 0xbe, 0x09, 0xe5, 0x94, 0x40, 0x11, 0x23,
+        0x0c, 0xe1, 0xe3, 0x5b, 0x01, 0x81, 0xaa, 0x31, 0x0b, 0xc4, 0x77, 0x95, 0x90, 0xa9, 0x5f, 0x0a,
+        0x57, 0xf5, 0x60, 0x21, 0x07, 0x52, 0x58, 0x76, 0x50, 0x77, 0xed, 0xd1, 0xbc, 0x39, 0x34, 0x21,
+        0xa0, 0xdd, 0x46, 0xc5, 0x53, 0xa8, 0x9d, 0x8f, 0xd5, 0xda, 0x04, 0xe1, 0xca, 0xd3, 0x26, 0x47,
+        0xe0, 0x64, 0x1d, 0xca, 0x95, 0xac, 0x9d, 0x0d, 0xe4, 0x20, 0x91, 0x7e, 0x86, 0x23, 0xed, 0x2e,
+        0x76, 0xe2, 0x2e, 0x02, 0x2d, 0x55, 0x85, 0xe7, 0x88, 0xfa, 0x0e, 0x56, 0xc5, 0xc1, 0xf3, 0xd0,
+        0xf2, 0xc4, 0x71, 0x12, 0xd8, 0x91, 0x45, 0xd4, 0xd3, 0x48, 0x5b, 0x4a, 0x9e, 0x60, 0xf7, 0x07,
+        0xf7, 0x51, 0xad, 0x5b, 0xdd, 0x8d, 0x42, 0x7e, 0xb1, 0x3a, 0xac, 0x7c, 0x02, 0x3e, 0x5c, 0xf8,
+        0xfa, 0xff, 0x4d, 0xd0, 0x0f, 0xbc, 0xa1, 0xe0, 0xcf, 0xf0, 0x9e, 0x36, 0xe5, 0x7d, 0xaf, 0x28,
+        0xd2, 0xcb, 0xe9, 0x25, 0xed, 0x4a, 0x85, 0x00, 0x17, 0x88, 0xd1, 0x4e, 0x20, 0xc6, 0xc6, 0x2a,
+        0x95, 0xbc, 0xd8, 0x08, 0x73, 0xcd, 0xd5, 0x7b, 0x17, 0x71, 0x7a, 0x66, 0xc9, 0x24, 0xfd, 0x2e,
+        0x79, 0xc5, 0x46, 0x37, 0x36, 0x45, 0x8e, 0x5e, 0x32, 0x76, 0xb0, 0x34, 0x9e, 0x41, 0x14, 0x43,
+        0x5f, 0x63, 0x0b, 0x29, 0x4b, 0x3e, 0xcb, 0xae, 0x0e, 0x7e, 0xd0, 0x55, 0x42, 0x59, 0x51, 0x18,
+        0x55, 0x21, 0x69, 0x75, 0x67, 0x05, 0xf2, 0xc7, 0x1f, 0x0a, 0xcb, 0xf9, 0xeb, 0x22, 0x0f, 0x70,
+        0x2e, 0x97, 0xab, 0xae, 0xba, 0xf1, 0xad, 0x54, 0xe9, 0x79, 0x3f, 0x88, 0x21, 0x4f, 0xd3, 0x29,
+        0x6f, 0x0d, 0xf4, 0xe3, 0x29, 0x24, 0xf8, 0xab, 0x09, 0x18, 0xf5, 0xd0, 0xb9, 0x6e, 0x7f, 0x65,
+        0xe3, 0xa0, 0x7b, 0x03, 0xa7, 0xe3, 0x36, 0x02, 0x35, 0x66, 0xf6, 0x99, 0x61, 0x2e, 0x13, 0x23,
+        0xca, 0x69, 0x03, 0x6f, 0x4d, 0xb2, 0x53, 0x75, 0x5d, 0x5c, 0xe5, 0xd5, 0xd7, 0x27, 0x96, 0x1e,
+        0x0f, 0x42, 0x2e, 0x02, 0x3b, 0x9b, 0x70, 0xd7, 0x3d, 0x80, 0xa8, 0x9e, 0x64, 0x07, 0x76, 0x26,
+        0xde, 0xa1, 0x7c, 0x29, 0x35, 0x5d, 0x8b, 0xea, 0x01, 0x5b, 0xf0, 0xbb, 0xde, 0x97, 0x53, 0xfc,
+        0xee, 0x53, 0x36, 0xa7, 0x53, 0x75, 0x41, 0xf9, 0x82, 0xe4, 0x18, 0xa7, 0x89, 0x3d, 0xe7, 0x16,
+        0x5c, 0x85, 0x03, 0x38, 0x7b, 0xde, 0xa0, 0xf0, 0x74, 0x5f, 0x1e, 0x76, 0xc2, 0x15, 0xd5, 0x6b,
+        0xef, 0x24, 0x81, 0x99, 0x3b, 0xec, 0x9b, 0x1a, 0xca, 0x87, 0x4b, 0xb3, 0xa5, 0xed, 0xc9, 0x22,
+        0xaa, 0xfb, 0x0e, 0x9c, 0x29, 0xe6, 0x3b, 0x60, 0xef, 0xb6, 0xe1, 0x8b, 0x11, 0x7d, 0xed, 0x01,
+        0x97, 0x23, 0x00, 0x4f, 0x62, 0x59, 0xcd, 0xc7, 0x03, 0xd7, 0x18, 0x53, 0x17, 0x4d, 0x33, 0x2b,
+        0x56, 0x76, 0x0b, 0x9a, 0xf4, 0x25, 0x19, 0xce, 0x7a, 0x85, 0x5b, 0x65, 0xf2, 0x1a, 0x46, 0x33,
+        0x52, 0x94, 0x4a, 0x37, 0x51, 0x5c, 0xbf, 0x43, 0x31, 0xd9, 0x2d, 0xbf, 0xdc, 0x97, 0x58, 0x13,
+        0x15, 0x7d, 0xab, 0x7b, 0xe8, 0x93, 0x95, 0xa8, 0x16, 0xfc, 0x06, 0x48, 0x5e, 0xd9, 0x67, 0xda,
+        0x26, 0x25, 0xd7, 0x7f, 0xb9, 0xc3, 0x91, 0xbf, 0xd7, 0xdd, 0x80, 0x4f, 0xe1, 0x57, 0x13, 0x2a,
+        0x3f, 0x87, 0xb7, 0xf6, 0x86, 0x4a, 0xd1, 0xec, 0xde, 0x36, 0xbe, 0x27, 0xf8, 0x4e, 0x3d, 0x72,
+        0xcc, 0x3b, 0x9b, 0x89, 0x76, 0x9e, 0x02, 0x46, 0x31, 0x74, 0x26, 0xb4, 0x78, 0xe4, 0xbc, 0x29,
+        0x1d, 0x3b, 0x99, 0x98, 0x49, 0x9f, 0x09, 0x9e, 0xb0, 0x4d, 0x36, 0x48, 0xcc, 0x62, 0x1d, 0xab,
+        0x86, 0xeb, 0x9d, 0x34, 0x42, 0x8e, 0xa1, 0xaf, 0xf6, 0x5f, 0x89, 0x03, 0xe6, 0x5d, 0x16, 0x2c,
+        0x4b, 0x35, 0x70, 0xfb, 0xe0, 0xb7, 0xc9, 0x5c, 0xa6, 0xd0, 0x35, 0x40, 0xb3, 0xdf, 0x02, 0xbd,
+        0x71, 0x2e, 0xd1, 0xb0, 0x41, 0xb0, 0x39, 0x58, 0x45, 0x77, 0xf0, 0x9c, 0x7a, 0x6a, 0x04, 0x25,
+        0x15, 0xc5, 0xa7, 0xc9, 0xa3, 0xb2, 0x06, 0x5f, 0x65, 0xea, 0xeb, 0xa0, 0xac, 0xf9, 0x33, 0x57,
+        0xbc, 0x18, 0x8f, 0x30, 0x9b, 0x8e, 0x3c, 0x25, 0x14, 0x0b, 0xe0, 0xce, 0x1a, 0x9c, 0x79, 0x0b,
+        0xf1, 0xcb, 0x5e, 0x0a, 0x15, 0x8c, 0xa4, 0x49, 0x91, 0xa9, 0xa2, 0xc5, 0x46, 0xbb, 0x33, 0x60,
+        0xa8, 0xe9, 0xa7, 0xdf, 0xa6, 0x90, 0x0d, 0x0c, 0x8e, 0x6a, 0x5c, 0xc3, 0x58, 0xe3, 0xe9, 0x0e,
+        0x80, 0x8c, 0x75, 0xb7, 0xb0, 0x33, 0xa4, 0x89, 0x52, 0x4f, 0xc9, 0x0c, 0x8d, 0xc0, 0x91, 0x52,
+        0x67, 0x1b, 0xe8, 0x54, 0x50, 0xbd, 0xea, 0xb2, 0x35, 0x9a, 0x25, 0x5a, 0xb0, 0x17, 0xd5, 0x18,
+        0x71, 0x67, 0x30, 0x43, 0x47, 0x6d, 0xa0, 0x5d, 0x06, 0xc0, 0x81, 0xef, 0x35, 0x79, 0x3c, 0x4d,
+        0x62, 0xc9, 0x33, 0x56, 0x1f, 0x14, 0xbb, 0x7c, 0x7d, 0x75, 0x97, 0xb8, 0x30, 0xc0, 0x1f, 0x17,
+        0xd1, 0xe3, 0xc7, 0xc3, 0x6a, 0xd2, 0xc5, 0x14, 0x50, 0xfa, 0x5f, 0xb1, 0x52, 0x45, 0x2e, 0xe1,
+        0x9c, 0x06, 0xa9, 0x6f, 0x5d, 0xc4, 0x3a, 0x6b, 0x56, 0x9a, 0xb3, 0x25, 0x79, 0xeb, 0x84, 0x22,
+        0x66, 0xcb, 0xdc, 0x33, 0x09, 0x6d, 0x52, 0x6f, 0x35, 0xab, 0xc2, 0x4f, 0x7e, 0x7e, 0x34, 0x47,
+        0x54, 0x61, 0x7c, 0x39, 0x8f, 0x1d, 0x52, 0x43, 0x94, 0xe5, 0x30, 0x1f, 0x8b, 0xb0, 0x0b, 0x28,
+        0xbb, 0xe4, 0x3b, 0x4b, 0x48, 0x90, 0x3a, 0xc3, 0x31, 0x3b, 0x5c, 0x25, 0x42, 0xde, 0xf4, 0x9f,
+        0x92, 0x31, 0x0d, 0x2b, 0xff, 0x0a, 0x3a, 0x49, 0x44, 0x9c, 0xfe, 0xfa, 0x65, 0x4b, 0x7a, 0x2b,
+        0xf5, 0xd4, 0x2c, 0x09, 0xb6, 0x4c, 0xfe, 0x0f, 0x8c, 0xb1, 0x94, 0xd5, 0xdd, 0x49, 0x42, 0xb7,
+        0x32, 0x15, 0x55, 0xa4, 0x2c, 0x3d, 0x61, 0x0b, 0x14, 0x12, 0xdd, 0x8c, 0xf2, 0x99, 0x5a, 0x1c,
+        0xc7, 0xdf, 0xcc, 0xd4, 0x57, 0x89, 0x59, 0xf1, 0xa8, 0x9f, 0x34, 0x5f, 0x9e, 0x7c, 0x53, 0x0e,
+        0x3e, 0x8e, 0x3e, 0x35, 0x54, 0xb4, 0xb4, 0x03, 0xd8, 0x46, 0xa1, 0x91, 0x16, 0x6c, 0x69, 0x1f,
+        0x83, 0x18, 0x3a, 0xc3, 0xea, 0x2a, 0xbc, 0x62, 0x2f, 0xeb, 0x71, 0x1e, 0xc1, 0x83, 0x0f, 0x2a,
+        0x29, 0x68, 0x54, 0xf5, 0x19, 0xc5, 0xee, 0xd0, 0xa8, 0x38, 0x68, 0x96, 0xfa, 0x25, 0xe7, 0x17,
+        0x6c, 0x82, 0xb8, 0xc7, 0x10, 0x9a, 0x23, 0xd2, 0xf8, 0xae, 0x35, 0xcf, 0xe6, 0xf4, 0x88, 0x5e,
+        0xa5, 0x26, 0x1f, 0xcd, 0xcf, 0x74, 0xda, 0x0d, 0x6a, 0x28, 0xad, 0x23, 0x7f, 0xfe, 0x33, 0x30,
+        0xae, 0xd8, 0xdb, 0x7b, 0x72, 0xfb, 0x7b, 0x58, 0x25, 0xde, 0x03, 0x8b, 0xca, 0xf8, 0x8c, 0xc3,
+        0xb7, 0x25, 0x5c, 0xad, 0xae, 0x6f, 0x73, 0xa7, 0x98, 0xdb, 0xc7, 0xdd, 0x00, 0x7d, 0x25, 0x30,
+        0x18, 0x80, 0x3a, 0xb3, 0x42, 0x75, 0x48, 0xaf, 0x24, 0xa2, 0x94, 0x56, 0x88, 0xd3, 0x3a, 0x3f,
+        0xac, 0xaf, 0x92, 0xc1, 0x59, 0x16, 0x14, 0x66, 0x82, 0x1f, 0xaa, 0x03, 0xb1, 0xfc, 0x59, 0x17,
+        0x29, 0x01, 0x77, 0x4d, 0x71, 0x57, 0x2a, 0x2c, 0xf2, 0xe0, 0x24, 0x30, 0xd9, 0x96, 0xd1, 0xff,
+        0x35, 0x4b, 0xd6, 0x47, 0x85, 0xf7, 0xd9, 0xa3, 0x72, 0xa8, 0x04, 0x85, 0x74, 0xc6, 0x33, 0x1b,
+        0xb4, 0x86, 0x07, 0xc4, 0x6f, 0xa8, 0x73, 0x96, 0xf9, 0x53, 0xef, 0x2d, 0xfe, 0x5f, 0x88, 0xc1,
+        0xaf, 0x21, 0x60, 0x87, 0x92, 0x7f, 0x6a, 0x40, 0xf9, 0xbf, 0x0e, 0x49, 0x35, 0x7e, 0x4b, 0x11,
+        0xc6, 0x30, 0xc3, 0x68, 0x32, 0xf2, 0x2b, 0x96, 0x9c, 0xd2, 0xad, 0x60, 0x0e, 0x2e, 0xff, 0x09,
+        0x92, 0x8d, 0xae, 0x37, 0xe4, 0xe0, 0x4b, 0x61, 0xf8, 0x27, 0x7d, 0x4a, 0x85, 0x91, 0xa4, 0x2c,
+        0x65, 0x98, 0x3b, 0xd3, 0xbc, 0x93, 0x36, 0x2a, 0x67, 0x0b, 0xf7, 0xaa, 0x14, 0x50, 0xa0, 0x2f,
+        0xb0, 0xf7, 0x49, 0xa0, 0xa7, 0x8f, 0x15, 0x90, 0xc9, 0xdd, 0x54, 0xaa, 0xfb, 0x52, 0xc9, 0x01,
+        0x9a, 0xfb, 0xf2, 0xdc, 0x92, 0x57, 0x13, 0xad, 0x33, 0x50, 0x5b, 0x19, 0xa0, 0xb5, 0xa6, 0xba,
+        0x21, 0xf0, 0x51, 0xcb, 0x8a, 0x57, 0x3a, 0x0f, 0xd8, 0xd6, 0x6f, 0xc9, 0x9f, 0x6d, 0xdb, 0x16,
+        0x86, 0x5d, 0x20, 0xe4, 0xe8, 0x19, 0x4d, 0x51, 0x84, 0x08, 0x9f, 0x04, 0x7b, 0x37, 0x38, 0x67,
+        0xcb, 0x4c, 0x07, 0xcb, 0xd5, 0x0c, 0x7b, 0xbd, 0xf4, 0x58, 0x01, 0xbe, 0xcb, 0x6e, 0xd0, 0x0b,
+        0x1a, 0xe1, 0x51, 0xce, 0xf5, 0xe8, 0x30, 0xbe, 0x10, 0xb9, 0x06, 0xe6, 0x95, 0x25, 0x64, 0x13,
+        0x79, 0x2c, 0xdd, 0xac, 0xd6, 0x4c, 0x8f, 0x01, 0x10, 0x91, 0x65, 0x14, 0x32, 0xa7, 0x27, 0x03,
+        0x69, 0xc4, 0x7a, 0x5b, 0x80, 0x6f, 0xa3, 0xa5, 0x1b, 0x03, 0xfa, 0xc8, 0xd5, 0x8f, 0x6c, 0x3b,
+        0x86, 0xb9, 0x7a, 0x7a, 0x66, 0x1d, 0x5d, 0xa1, 0x33, 0xfc, 0xca, 0xed, 0x2f, 0xfa, 0x77, 0x19,
+        0x5c, 0xea, 0x5b, 0xf1, 0xaf, 0x94, 0x00, 0xc1, 0x6c, 0xff, 0x4e, 0xca, 0x31, 0x39, 0xb2, 0x7f,
+        0x3d, 0x13, 0xd6, 0xcc, 0xa6, 0xc9, 0x05, 0xea, 0xb7, 0xb6, 0x69, 0xe6, 0x31, 0xe8, 0xd0, 0x28,
+        0x5a, 0xf1, 0x2e, 0xa8, 0xd0, 0xf6, 0x5f, 0x2d, 0xe1, 0xc9, 0x48, 0xc2, 0x43, 0x34, 0x96, 0x6b,
+        0xed, 0x13, 0x2b, 0xf4, 0x3d, 0x69, 0xc3, 0xfb, 0xbd, 0xbe, 0xe4, 0x77, 0xf1, 0x10, 0x36, 0x20,
+        0x72, 0xc6, 0x89, 0x99, 0x3c, 0xbb, 0x9c, 0xfa, 0xe1, 0x83, 0xec, 0x2c, 0xf2, 0xe9, 0xc7, 0x77,
+        0xcc, 0x13, 0x3b, 0xbf, 0x7c, 0xa6, 0x37, 0x3c, 0xd3, 0xbb, 0x63, 0x88, 0xc7, 0xd4, 0x42, 0x20,
+        0x6d, 0x6c, 0x02, 0x74, 0xa6, 0xc7, 0x02, 0xaf, 0x20, 0xa8, 0x5e, 0x9c, 0x8b, 0xe2, 0x45, 0x7c,
+        0xb2, 0x62, 0xf7, 0xfc, 0xeb, 0xbc, 0x81, 0x14, 0x9b, 0x6f, 0x78, 0xd7, 0x93, 0xa6, 0x38, 0x26,
+        0x44, 0xc8, 0xf6, 0x4b, 0x46, 0x8f, 0x30, 0xe3, 0x41, 0x48, 0x57, 0x56, 0x40, 0xef, 0x17, 0xcb,
+        0xbc, 0x1b, 0x5b, 0x82, 0x3a, 0x76, 0x5d, 0xf2, 0xe2, 0xbe, 0x2b, 0x2c, 0x2c, 0x4b, 0x03, 0x02,
+        0xdc, 0x74, 0x10, 0x1a, 0x0c, 0x26, 0x86, 0xa4, 0xf3, 0xbd, 0x71, 0x48, 0x01, 0x9d, 0x8c, 0x98,
+        0x28, 0x93, 0x82, 0xc4, 0xd4, 0x12, 0xb0, 0x72, 0xeb, 0x4b, 0x53, 0xf4, 0xda, 0x1c, 0x42, 0x07,
+        0xef, 0x7c, 0xda, 0x58, 0xd4, 0xcb, 0x6e, 0x6c, 0x75, 0xcf, 0xfe, 0x20, 0xd3, 0x43, 0xaa, 0xc2,
+        0xf4, 0x55, 0xd7, 0x34, 0xd7, 0xc1, 0x55, 0x1d, 0xae, 0xdf, 0x94, 0x0f, 0x4a, 0x93, 0x86, 0x26,
+        0xee, 0xf2, 0x21, 0x29, 0x61, 0xef, 0xb1, 0x0d, 0xc5, 0x25, 0xb2, 0x1e, 0xe5, 0x07, 0x15, 0x03,
+        0x80, 0x1b, 0xd8, 0xb2, 0xaf, 0x00, 0x39, 0xae, 0x8b, 0xae, 0x98, 0xf3, 0x9e, 0xa8, 0xce, 0x2f,
+        0x25, 0x2f, 0xb1, 0xa9, 0x38, 0xbd, 0xb6, 0xcd, 0x97, 0x8f, 0x28, 0x0d, 0x71, 0xf2, 0x02, 0xa7,
+        0xa0, 0xbb, 0x4a, 0xbb, 0x0c, 0x1d, 0x32, 0x7e, 0x08, 0x56, 0x84, 0x3c, 0x42, 0xf2, 0x4d, 0x2b,
+        0x14, 0xf3, 0x13, 0xfc, 0x5a, 0xd6, 0x14, 0x69, 0x61, 0x88, 0x98, 0x08, 0x28, 0x8a, 0xb7, 0x06,
+        0xe9, 0xfb, 0x66, 0x15, 0xae, 0xfe, 0x69, 0xd1, 0xcb, 0xba, 0xe3, 0xf0, 0x5d, 0x05, 0x86, 0x03,
+        0xb6, 0xa2, 0xdc, 0x01, 0x38, 0x5d, 0x23, 0xfe, 0x13, 0x00, 0xfa, 0xd9, 0x07, 0x4a, 0x76, 0xc7,
+        0x4f, 0x4e, 0x9c, 0x64, 0xee, 0x35, 0x7d, 0x6a, 0x15, 0xe1, 0x62, 0x2c, 0x66, 0x8c, 0xce, 0x07,
+        0x72, 0xb9, 0x72, 0xbd, 0x17, 0xe2, 0x09, 0x94, 0x68, 0x62, 0x14, 0xe1, 0x99, 0x14, 0xd2, 0x20,
+        0x9e, 0xdd, 0xc6, 0x2c, 0xe7, 0xaf, 0x4d, 0x67, 0xc1, 0x8c, 0xd8, 0x58, 0xc6, 0x01, 0x13, 0x2a,
+        0xef, 0x4b, 0xbe, 0x5e, 0xfd, 0xda, 0xfd, 0x30, 0x6a, 0x06, 0xde, 0x57, 0xfb, 0x3c, 0x09, 0x70,
+        0xd4, 0xa7, 0xf7, 0xda, 0xee, 0x65, 0xd0, 0x60, 0x58, 0xb4, 0xd4, 0x94, 0x19, 0xba, 0x60, 0x12,
+        0xb6, 0x10, 0xf8, 0x53, 0x87, 0x2a, 0x62, 0xe9, 0x3c, 0x37, 0x48, 0x82, 0xe4, 0xdd, 0xee, 0x2d,
+        0xe8, 0xa4, 0x46, 0x6e, 0x15, 0xef, 0x7d, 0xc2, 0x72, 0x65, 0xf8, 0x81, 0xb3, 0x67, 0x43, 0x19,
+        0x55, 0x7e, 0x3e, 0x7f, 0x0e, 0x47, 0xef, 0xf7, 0x0b, 0xc3, 0x70, 0x46, 0x2e, 0xfc, 0x56, 0xf2,
+        0x96, 0x22, 0x66, 0x92, 0x19, 0x30, 0xf3, 0xbc, 0xdb, 0x72, 0x34, 0x48, 0x2c, 0x8e, 0x75, 0x28,
+        0x1a, 0xeb, 0x42, 0xd8, 0xa6, 0x9b, 0xc3, 0x5d, 0x82, 0xe9, 0x6e, 0xd0, 0x1a, 0xc2, 0xef, 0x8a,
+        0x16, 0x31, 0x70, 0x86, 0xfd, 0xf9, 0xe6, 0x69, 0x53, 0x9f, 0xbc, 0x06, 0x9a, 0xf9, 0xe3, 0x0a,
+        0x00, 0x69, 0x2a, 0x01, 0xee, 0x48, 0x9c, 0xbf, 0x77, 0xec, 0x81, 0x14, 0x14, 0x15, 0x7d, 0x9d,
+        0x0b, 0x71, 0x00, 0xf4, 0x16, 0xcd, 0x70, 0xf5, 0x05, 0xac, 0xb9, 0xa4, 0x2d, 0xc0, 0x95, 0x23,
+        0xa8, 0x5a, 0x27, 0x6a, 0x29, 0xf4, 0x74, 0x80, 0x33, 0x50, 0x01, 0x16, 0x68, 0xf3, 0x0d, 0xc7,
+        0x9e, 0x55, 0x35, 0x62, 0x46, 0x7e, 0x9b, 0x08, 0xd4, 0x89, 0xf5, 0x6b, 0x09, 0xdd, 0xfe, 0x03,
+        0x01, 0x91, 0xd3, 0x8e, 0x85, 0x03, 0x2f, 0x32, 0x8f, 0x1d, 0xa3, 0xac, 0x41, 0xf4, 0x07, 0x57,
+        0x89, 0x1e, 0x99, 0x12, 0xca, 0xad, 0x47, 0xe0, 0x53, 0x22, 0xe0, 0xde, 0x49, 0x5f, 0x10, 0x18,
+        0x33, 0x33, 0xf8, 0x8a, 0xae, 0x4a, 0x8a, 0x75, 0xed, 0xac, 0xc7, 0x8f, 0x76, 0x91, 0xdf, 0xd4,
+        0x34, 0x8d, 0xf1, 0x08, 0x69, 0x8d, 0x76, 0xfa, 0x2e, 0x8f, 0x91, 0x4f, 0xf9, 0x3d, 0xe7, 0x2b,
+        0x08, 0x23, 0xf6, 0x39, 0x8a, 0x96, 0xaa, 0x07, 0x3b, 0x50, 0x71, 0xe4, 0x43, 0x0e, 0x66, 0x98,
+        0x41, 0xd4, 0x84, 0x37, 0x17, 0x2d, 0x97, 0x02, 0x96, 0xdc, 0x7d, 0xe8, 0x30, 0xaf, 0x7a, 0x18,
+        0xd1, 0x68, 0x83, 0x9f, 0x65, 0x8e, 0xe0, 0xdb, 0x25, 0xe5, 0x91, 0xdd, 0x83, 0x0b, 0xff, 0xe2,
+        0xf1, 0xac, 0x9e, 0xf2, 0x38, 0x5d, 0xd9, 0x35, 0x50, 0x65, 0x76, 0x8f, 0x93, 0x02, 0xba, 0x2d,
+        0xb6, 0x70, 0xda, 0x08, 0x86, 0xf9, 0x6e, 0xa2, 0xcc, 0xcf, 0xf8, 0x62, 0xc9, 0x2b, 0xf5, 0x72,
+        0x76, 0x9a, 0x0d, 0xea, 0x40, 0x1d, 0x0b, 0xec, 0xe2, 0x75, 0x79, 0xd7, 0x7d, 0x7a, 0x1e, 0x20,
+        0x1b, 0xb9, 0xd3, 0x3e, 0x3a, 0x31, 0x15, 0xbf, 0x6a, 0x72, 0xd5, 0xfa, 0x9e, 0x04, 0xcb, 0xad,
+        0x1c, 0xb3, 0x1d, 0x32, 0x1c, 0x0f, 0xc4, 0x81, 0xdb, 0x36, 0x8b, 0x6c, 0xdd, 0xdc, 0x20, 0x07,
+        0x5c, 0xc5, 0xc4, 0x11, 0x5b, 0xb9, 0xc7, 0xfa, 0x13, 0xbe, 0x5b, 0x3d, 0x89, 0x94, 0x87, 0x1f,
+        0xc0, 0x2f, 0x00, 0xd6, 0xd4, 0x1e, 0x78, 0x64, 0xab, 0x71, 0xe0, 0xbd, 0x07, 0x12, 0x1a, 0x28,
+        0x5c, 0xd2, 0x7f, 0x26, 0x96, 0xd6, 0x59, 0x9a, 0xf3, 0x37, 0x53, 0x72, 0x6a, 0xbf, 0x9b, 0xa2,
+        0xc5, 0x12, 0x90, 0x49, 0xb9, 0xc9, 0x65, 0x19, 0xdd, 0x81, 0x51, 0x64, 0x1a, 0x8b, 0x01, 0x15,
+        0x75, 0x82, 0x0f, 0x7b, 0xdf, 0x44, 0xdd, 0xb8, 0x0e, 0x3c, 0x80, 0x90, 0x04, 0x65, 0x3e, 0x1e,
+        0x1a, 0xef, 0x1e, 0x42, 0x82, 0xc4, 0x87, 0xc3, 0xce, 0xde, 0x14, 0x0c, 0xd3, 0x2c, 0x03, 0x07,
+        0x20, 0x8e, 0x62, 0x63, 0x19, 0xca, 0x5f, 0x46, 0xf3, 0x16, 0x4a, 0xd4, 0xde, 0x8a, 0xd5, 0x41,
+        0x29, 0x88, 0x93, 0x70, 0xa2, 0x9b, 0x5c, 0x8e, 0xff, 0xb7, 0x32, 0x52, 0x6a, 0x89, 0x0f, 0x12,
+        0x38, 0x90, 0x43, 0x33, 0x18, 0xfc, 0x68, 0xae, 0xe1, 0x26, 0xf2, 0xa9, 0x7e, 0x33, 0xcf, 0xd5,
+        0x69, 0x26, 0x32, 0x70, 0x5d, 0xfd, 0x1f, 0xd2, 0x29, 0x75, 0xf5, 0x55, 0xef, 0xfa, 0x7b, 0x2d,
+        0xda, 0xaa, 0x0b, 0x84, 0xc6, 0xdd, 0x2d, 0xbf, 0x58, 0x25, 0x5f, 0x84, 0xf8, 0x54, 0x45, 0xd7,
+        0xc7, 0xef, 0xe1, 0xee, 0x1c, 0xf3, 0x58, 0x3a, 0xc5, 0xc6, 0xcf, 0x00, 0xe9, 0xb5, 0x90, 0x0b,
+        0x87, 0xf1, 0x90, 0x71, 0xe6, 0x00, 0xdd, 0x90, 0x68, 0x30, 0xe4, 0xd2, 0xd8, 0xaf, 0x22, 0xb1,
+        0xc9, 0x5a, 0xe0, 0x38, 0x21, 0x22, 0xac, 0xe1, 0x25, 0x30, 0xe3, 0xb9, 0x87, 0xcf, 0xfd, 0x08,
+        0x69, 0x62, 0x34, 0x3a, 0x86, 0xab, 0xec, 0xfa, 0xbd, 0xaa, 0x0c, 0x10, 0x1a, 0xb9, 0x79, 0x03,
+        0x68, 0x01, 0x41, 0xae, 0x18, 0x14, 0x9a, 0x84, 0x6c, 0xb2, 0x37, 0xa0, 0x45, 0xf5, 0x3d, 0x14,
+        0xe8, 0xf7, 0x61, 0xf0, 0x46, 0x30, 0x69, 0xe8, 0x4c, 0x76, 0xd8, 0x4a, 0x2d, 0xf3, 0xbb, 0x17,
+        0x6f, 0xcd, 0xc5, 0x58, 0xb8, 0xca, 0x6d, 0xa1, 0x56, 0x8b, 0xec, 0x5c, 0x2f, 0x8c, 0xb2, 0x1e,
+        0xf9, 0x88, 0xe7, 0x07, 0x31, 0xae, 0x19, 0xa4, 0xf4, 0xd1, 0x97, 0xe5, 0xc7, 0x62, 0x53, 0xfb,
+        0x6f, 0x1d, 0x06, 0x79, 0x83, 0x2a, 0x5f, 0x02, 0x2c, 0x18, 0x68, 0xc0, 0x95, 0x3c, 0x25, 0x16,
+        0xfb, 0xf5, 0x66, 0x1e, 0x40, 0x85, 0xbf, 0x36, 0x4a, 0x86, 0x14, 0xcb, 0x1d, 0x47, 0xce, 0xf5,
+        0x6a, 0x3a, 0xff, 0x60, 0xd6, 0xd8, 0xde, 0x3c, 0x87, 0xc5, 0x14, 0xcc, 0x7d, 0xee, 0x08, 0x04,
+        0xbd, 0x8e, 0x81, 0x6b, 0x00, 0x1b, 0x33, 0x46, 0xd7, 0x69, 0x8a, 0xa4, 0x10, 0xfd, 0xaf, 0xcc,
+        0x76, 0x70, 0x8b, 0x0b, 0xe2, 0x0e, 0xcb, 0x83, 0xc8, 0xf8, 0xce, 0xb8, 0xfd, 0x60, 0xdc, 0x08,
+        0x25, 0x22, 0x92, 0x0c, 0x68, 0xb6, 0x71, 0x75, 0x05, 0x6a, 0x39, 0x92, 0x9e, 0xed, 0x95, 0x43,
+        0x23, 0xbe, 0x45, 0x43, 0x30, 0xf4, 0xed, 0xf9, 0x76, 0x41, 0xa2, 0x5c, 0x73, 0x5c, 0xe2, 0x10,
+        0xc4, 0x16, 0x64, 0xe8, 0x50, 0xa3, 0x9e, 0xd3, 0x0c, 0xbe, 0xc3, 0xd5, 0x68, 0x2d, 0xe4, 0x70,
+        0x91, 0xbd, 0x6f, 0x17, 0x38, 0x69, 0x47, 0x74, 0x93, 0x06, 0x46, 0x9a, 0x82, 0x40, 0x73, 0x0f,
+        0xce, 0x89, 0xb6, 0xc5, 0xdb, 0xdc, 0x03, 0x13, 0x2e, 0x77, 0x55, 0x8a, 0x1a, 0x53, 0xb0, 0xc3,
+        0xcd, 0x3c, 0x56, 0x9c, 0x1c, 0xfc, 0x86, 0x95, 0x62, 0xfb, 0x71, 0x46, 0x0d, 0x79, 0x78, 0x17,
+        0x5b, 0x26, 0x19, 0xcd, 0x8e, 0x90, 0xc1, 0x99, 0x28, 0x11, 0x2a, 0xf5, 0xd1, 0x20, 0x43, 0xde,
+        0x78, 0xf2, 0x51, 0x81, 0xc6, 0x1b, 0xb7, 0xbd, 0x29, 0x75, 0xd2, 0xd7, 0x2a, 0x23, 0x19, 0x06,
+        0x1a, 0xb4, 0x81, 0xe0, 0xd2, 0x0d, 0x6a, 0x01, 0xc4, 0xf0, 0xe8, 0xd1, 0xa0, 0x00, 0xc0, 0x66,
+        0x09, 0x1f, 0xec, 0xb2, 0x7e, 0xae, 0xd1, 0x8b, 0xf5, 0xed, 0x20, 0x2c, 0x4f, 0x66, 0x6b, 0x14,
+        0x87, 0x18, 0x4f, 0x12, 0x40, 0x49, 0xc8, 0x63, 0x5a, 0x1a, 0xee, 0x3e, 0x45, 0x44, 0x4b, 0x9a,
+        0xca, 0x4b, 0x52, 0x26, 0x5a, 0xd4, 0xd5, 0xad, 0xdd, 0xdf, 0xe0, 0x6c, 0x37, 0x04, 0xdb, 0x2b,
+        0xee, 0x18, 0xaf, 0xaa, 0x15, 0x07, 0xbd, 0x2f, 0x24, 0x0c, 0x7e, 0x26, 0x85, 0xfa, 0x59, 0x19,
+        0x9e, 0x27, 0x5e, 0x36, 0xda, 0xf8, 0xcc, 0xf5, 0x21, 0x6c, 0x8b, 0xd9, 0xb0, 0x2d, 0xe6, 0x27,
+        0xa2, 0xbe, 0xd2, 0x5b, 0xd9, 0xb0, 0x6a, 0xcc, 0x2f, 0x30, 0xed, 0x07, 0x02, 0x1b, 0x87, 0x24,
+        0xf2, 0x68, 0xec, 0xdc, 0x8b, 0x1e, 0x4a, 0x99, 0xfa, 0x2b, 0xb5, 0x2a, 0x75, 0x72, 0xed, 0x1e,
+        0x5d, 0xa2, 0xe1, 0x4a, 0x6d, 0x60, 0x87, 0xc0, 0xc3, 0xf6, 0xa1, 0x26, 0xa6, 0xf8, 0x84, 0x42,
+        0xb7, 0x0e, 0x5a, 0xc4, 0x7f, 0x5a, 0x33, 0x82, 0x56, 0x0d, 0x79, 0xe2, 0xd7, 0x41, 0x7a, 0x27,
+        0xb7, 0x9f, 0x94, 0x17, 0x7b, 0x89, 0x0b, 0xc2, 0x98, 0xa1, 0x2f, 0xd9, 0xe6, 0x5b, 0x62, 0xa6,
+        0x83, 0x8a, 0x10, 0x5b, 0x8c, 0x7f, 0xac, 0xa3, 0xe5, 0x67, 0x24, 0x39, 0x90, 0x04, 0x3e, 0x2d,
+        0x73, 0x8a, 0x34, 0x23, 0x80, 0x91, 0xbb, 0xa7, 0x82, 0x0c, 0x63, 0x41, 0x15, 0x02, 0x44, 0x8f,
+        0xd8, 0xe0, 0x6d, 0x14, 0xd0, 0x55, 0x8f, 0x68, 0xbe, 0xec, 0x61, 0xec, 0xff, 0x34, 0x86, 0x24,
+        0x9d, 0x3f, 0x2f, 0x74, 0x7f, 0x76, 0x4c, 0x70, 0xe3, 0xcb, 0x4f, 0xb6, 0x31, 0x7d, 0x19, 0xd4,
+        0xe7, 0x7f, 0x03, 0x0f, 0x3f, 0xfc, 0x26, 0x90, 0x34, 0x6e, 0x9b, 0xeb, 0xae, 0xfe, 0xdb, 0x07,
+        0xaa, 0x67, 0xd3, 0x23, 0x38, 0x4d, 0xf5, 0x6c, 0x4a, 0xcb, 0xb0, 0xc2, 0x55, 0x36, 0x37, 0x26,
+        0x7b, 0x3a, 0xca, 0x3d, 0xd2, 0xd5, 0xaf, 0x4e, 0x63, 0x53, 0xf6, 0x46, 0x21, 0x00, 0x72, 0x09,
+        0x3e, 0xba, 0x5c, 0xb3, 0x96, 0x35, 0xb5, 0xee, 0xa8, 0x71, 0x0d, 0x22, 0x60, 0x8e, 0xa0, 0xcd,
+        0x91, 0x3b, 0x3c, 0x0e, 0x72, 0x9c, 0x6c, 0x09, 0x89, 0x8e, 0x94, 0x90, 0x19, 0x9f, 0xa7, 0x16,
+        0x16, 0x17, 0xde, 0xe2, 0x34, 0x2f, 0xf8, 0xbb, 0xb0, 0x91, 0xbe, 0x45, 0xe2, 0x5d, 0x80, 0x41,
+        0x83, 0xeb, 0x32, 0xdf, 0x17, 0xe4, 0x91, 0xa5, 0x00, 0xf7, 0xbd, 0x17, 0x45, 0xb9, 0x56, 0x2a,
+        0x10, 0x64, 0xba, 0x63, 0x7b, 0xfe, 0x3f, 0x87, 0xe2, 0xb2, 0xdd, 0x3e, 0xa7, 0xdf, 0x1f, 0x71,
+        0xff, 0x2c, 0x40, 0x19, 0x0a, 0x5e, 0xb7, 0xf4, 0xfc, 0x2d, 0x93, 0x47, 0xb5, 0x81, 0xf5, 0x04,
+        0x14, 0x51, 0x94, 0x79, 0x39, 0xa0, 0xaf, 0x06, 0x8c, 0x0d, 0x51, 0x05, 0x2f, 0xa9, 0xf3, 0x46,
+        0x7c, 0x6c, 0xf9, 0xa7, 0x20, 0x2f, 0xe8, 0x4f, 0x4f, 0xb9, 0x8e, 0x2d, 0x1e, 0x1c, 0xba, 0x2f,
+        0xee, 0x23, 0x22, 0x24, 0xdb, 0x50, 0x1e, 0xb8, 0x78, 0x67, 0x46, 0x70, 0x29, 0x24, 0xc6, 0x6a,
+        0x68, 0x64, 0xd1, 0x3d, 0x95, 0x83, 0xb9, 0x9c, 0x25, 0x07, 0xc2, 0xad, 0xd2, 0xac, 0x8f, 0x03,
+        0x72, 0xd5, 0x64, 0x90, 0xd3, 0xa8, 0x91, 0xa5, 0x2f, 0x28, 0x17, 0x86, 0x8b, 0xa8, 0x2f, 0x8c,
+        0x4e, 0x25, 0x80, 0x05, 0x34, 0xcd, 0x7a, 0x81, 0x01, 0x30, 0xd6, 0xa3, 0x89, 0x86, 0x08, 0x1d,
+        0x34, 0x8b, 0xfc, 0xb5, 0x42, 0xd1, 0x54, 0x9a, 0x27, 0x5d, 0xec, 0x44, 0xf4, 0xa3, 0x28, 0x91,
+        0x03, 0xb9, 0x0b, 0x37, 0x2d, 0xa8, 0xff, 0xea, 0xbe, 0x63, 0xa3, 0xab, 0x9d, 0x54, 0x8e, 0x06,
+        0xe3, 0x1f, 0x16, 0x83, 0xa6, 0x97, 0x3b, 0x63, 0x62, 0x46, 0xc9, 0x7c, 0xb7, 0x27, 0xfd, 0x43,
+        0xdd, 0xdb, 0xbb, 0x0d, 0xcb, 0xa1, 0xa0, 0x0f, 0xc2, 0xbf, 0x8c, 0xe0, 0xa8, 0xa8, 0xb2, 0x2b,
+        0x7a, 0x6f, 0xf2, 0xbe, 0xb0, 0x9c, 0x43, 0x14, 0x41, 0xa4, 0xd2, 0x09, 0x2d, 0x42, 0xb1, 0xb1,
+        0xdb, 0xde, 0x0b, 0x8e, 0x08, 0x01, 0x28, 0xd2, 0xe7, 0x66, 0x3a, 0x71, 0x72, 0xf8, 0x7c, 0x01,
+        0x68, 0x70, 0x90, 0x65, 0x62, 0x75, 0x08, 0x0e, 0x0c, 0x95, 0xcc, 0x6d, 0x23, 0x37, 0x71, 0x72,
+        0x90, 0x92, 0xae, 0x26, 0xef, 0x9d, 0xac, 0xd7, 0x77, 0xc1, 0x4b, 0x7e, 0xe3, 0x3c, 0x64, 0x00,
+        0xe7, 0x50, 0x82, 0x6c, 0xaf, 0x3a, 0x21, 0x53, 0xd1, 0xd8, 0x23, 0x76, 0x31, 0x61, 0xc1, 0xd7,
+        0x55, 0x0b, 0x85, 0x98, 0xa4, 0xd3, 0x58, 0x91, 0xdd, 0x48, 0x53, 0x73, 0x43, 0xa0, 0xc5, 0x1b,
+        0x9f, 0x31, 0xe5, 0x73, 0x1c, 0x7f, 0x37, 0x2a, 0x34, 0x47, 0x01, 0x8b, 0xb2, 0x88, 0xbc, 0x38,
+        0x2c, 0x52, 0x92, 0xd4, 0xd4, 0xaa, 0x6e, 0x1f, 0x9c, 0x38, 0x7f, 0x4a, 0x20, 0x9f, 0x62, 0x10,
+        0x7b, 0x23, 0x36, 0x1d, 0x15, 0x73, 0x07, 0xfe, 0x09, 0x27, 0x51, 0x74, 0xc2, 0xdf, 0x97, 0x8a,
+        0x00, 0x53, 0xaa, 0x91, 0x06, 0x87, 0xd0, 0x04, 0x1b, 0x37, 0x05, 0xc7, 0x11, 0x22, 0x48, 0x02,
+        0x39, 0x54, 0x39, 0xfa, 0x60, 0xfd, 0xd4, 0x3c, 0x19, 0x27, 0x68, 0x7a, 0x2f, 0x14, 0x76, 0x72,
+        0x98, 0x0b, 0x29, 0x68, 0xb8, 0xc9, 0xc6, 0x82, 0x45, 0xed, 0xa3, 0x5f, 0x14, 0xf1, 0x8b, 0x0c,
+        0xe8, 0x86, 0xfd, 0x74, 0x99, 0x5c, 0xcf, 0x65, 0x17, 0x78, 0xf8, 0x92, 0xfd, 0x4a, 0x48, 0x86,
+        0x51, 0x38, 0x40, 0x99, 0x47, 0x13, 0x27, 0x2b, 0xf5, 0x47, 0xd0, 0x9a, 0x6d, 0x7e, 0x2f, 0x2b,
+        0x90, 0x5e, 0x38, 0x69, 0x3b, 0xe2, 0x19, 0xcb, 0xf3, 0xef, 0x87, 0x4c, 0x4b, 0x56, 0xcb, 0x00,
+        0x1b, 0x5c, 0xfc, 0x49, 0xb3, 0x62, 0x39, 0xf4, 0x62, 0x5e, 0x17, 0x8d, 0x79, 0xf4, 0x4d, 0x08,
+        0xe1, 0xa1, 0x81, 0x6b, 0x0a, 0x29, 0x1c, 0x47, 0xc5, 0x7d, 0x46, 0xb3, 0xf2, 0x15, 0xc9, 0x0e,
+        0x15, 0x9e, 0x47, 0xce, 0xc1, 0xb6, 0xbb, 0x53, 0x4d, 0x0b, 0x85, 0xd5, 0x01, 0x98, 0xd6, 0x14,
+        0x94, 0x93, 0x29, 0x2f, 0x9f, 0x41, 0xc0, 0x8b, 0xb7, 0xc4, 0xeb, 0xd4, 0xd2, 0xdd, 0x3a, 0x99,
+        0xba, 0x12, 0xf6, 0xd8, 0xe7, 0xb8, 0x55, 0x3b, 0x5e, 0xf4, 0xb5, 0x21, 0xe7, 0x68, 0xd1, 0x15,
+        0x5c, 0xbc, 0xde, 0x3f, 0x79, 0x9d, 0x1b, 0x2d, 0x05, 0xa5, 0x19, 0x0f, 0x1a, 0xc1, 0x25, 0x8f,
+        0x59, 0xa6, 0xa6, 0xb2, 0xcf, 0xfc, 0xfa, 0x63, 0xbd, 0x9f, 0x4f, 0x9e, 0x25, 0x2c, 0xe6, 0x1a,
+        0x43, 0x83, 0x7b, 0xf1, 0x28, 0x67, 0xe4, 0x7c, 0x1c, 0xb8, 0xf2, 0x0b, 0x47, 0x72, 0x63, 0x5d,
+        0xc6, 0x88, 0x12, 0xe5, 0x2c, 0x69, 0x7b, 0x97, 0x9d, 0x9a, 0xef, 0xbd, 0x6b, 0x51, 0x24, 0x0e,
+        0x2f, 0xf7, 0x97, 0x40, 0x63, 0xa4, 0x96, 0x9d, 0xc8, 0x1d, 0x23, 0x8b, 0x19, 0xff, 0xb2, 0xe0,
+        0x96, 0xf2, 0x6b, 0xbb, 0x7f, 0x23, 0x1c, 0xe5, 0x10, 0x2d, 0x06, 0xc8, 0xbb, 0x1a, 0x85, 0x1b,
+        0xfe, 0x56, 0x86, 0xdc, 0x34, 0x87, 0x65, 0xd5, 0xe2, 0xdb, 0x76, 0x8a, 0xa1, 0xa0, 0x99, 0xa1,
+        0x1a, 0xd7, 0x36, 0x68, 0x8d, 0x21, 0x33, 0x71, 0x6b, 0x3b, 0x00, 0x45, 0xd3, 0xa0, 0xc7, 0x09,
+        0xb8, 0xb1, 0x33, 0x2d, 0xdc, 0x6c, 0x86, 0x69, 0xb4, 0x67, 0xf4, 0x08, 0x92, 0x8f, 0x9d, 0xa9,
+        0x75, 0xfe, 0x36, 0x0d, 0x9d, 0x4e, 0xe6, 0x8d, 0xb7, 0xff, 0x1c, 0xee, 0xe3, 0x05, 0x22, 0x04,
+        0x38, 0x7f, 0x51, 0xf4, 0x19, 0x51, 0x44, 0x6e, 0xc1, 0x4f, 0x0b, 0x06, 0xa3, 0x04, 0xf6, 0x80,
+        0x79, 0x42, 0xe6, 0x00, 0xf0, 0x8a, 0x32, 0xac, 0x75, 0xb8, 0xa7, 0x32, 0x17, 0x26, 0x66, 0x01,
+        0x14, 0xfc, 0x4c, 0xdc, 0x1f, 0x3e, 0x52, 0xcf, 0x81, 0x9b, 0x79, 0x29, 0x8a, 0x79, 0xfb, 0xd0,
+        0x63, 0xd5, 0xbb, 0x47, 0xef, 0x3d, 0x14, 0xe8, 0x8f, 0xfe, 0x72, 0x74, 0xdf, 0xdb, 0xbc, 0x21,
+        0xda, 0xa1, 0x63, 0xe1, 0x97, 0x16, 0x2c, 0x5e, 0x74, 0x8d, 0x2d, 0x5d, 0xd1, 0xec, 0xc6, 0x9f,
+        0x70, 0x50, 0x9b, 0xcf, 0xe5, 0xde, 0x64, 0xd2, 0xa8, 0xc9, 0xd1, 0xc1, 0x2d, 0x7b, 0xba, 0x07,
+        0x59, 0x35, 0xab, 0xad, 0xdf, 0x3b, 0x15, 0x0d, 0xa1, 0x8e, 0x4c, 0x13, 0x20, 0x0f, 0x85, 0x8c,
+        0x2f, 0xe9, 0x03, 0xd9, 0x1f, 0xe2, 0xc8, 0xc4, 0xf7, 0x50, 0x53, 0xad, 0x83, 0x8b, 0xa4, 0x19,
+        0xa1, 0x74, 0xb0, 0xde, 0x7a, 0xb3, 0xba, 0xa2, 0x84, 0x96, 0x27, 0x9f, 0x03, 0x2c, 0xd6, 0xe7,
+        0xd7, 0xd3, 0x11, 0x1c, 0xeb, 0x84, 0x80, 0xcc, 0x24, 0xe2, 0xbc, 0x0d, 0x23, 0xe3, 0x78, 0x2a,
+        0xd4, 0xb3, 0x9f, 0x37, 0xa4, 0xab, 0x50, 0xb2, 0x7c, 0xe2, 0x39, 0x57, 0x29, 0xf5, 0x79, 0xda,
+        0x5c, 0xe7, 0xe6, 0x13, 0x2d, 0xe1, 0xc9, 0x2e, 0x48, 0xfc, 0x62, 0xfe, 0x60, 0x12, 0x87, 0x01,
+        0xee, 0x7b, 0xb5, 0x94, 0x18, 0xd4, 0xdb, 0x61, 0x30, 0xca, 0x34, 0x42, 0xe6, 0x9a, 0x34, 0x20,
+        0x47, 0x34, 0xfc, 0x54, 0xcd, 0xe2, 0x86, 0xdd, 0xcd, 0x22, 0x52, 0xfc, 0x1d, 0x8b, 0x2d, 0x1f,
+        0xbf, 0x73, 0xa4, 0xdc, 0x8a, 0xc1, 0x3d, 0x24, 0x18, 0x82, 0xcd, 0x40, 0x82, 0xb7, 0x79, 0x82,
+        0xc5, 0x49, 0x83, 0x32, 0x76, 0x1d, 0x21, 0xf2, 0xd1, 0x2c, 0x4d, 0xc7, 0x77, 0x8d, 0x8e, 0x2f,
+        0x23, 0xb1, 0x02, 0x08, 0xe0, 0xa0, 0xaf, 0xde, 0x47, 0x93, 0x60, 0xb3, 0x3b, 0x0f, 0xfd, 0xa3,
+        0x01, 0x16, 0xfb, 0x15, 0x59, 0x4e, 0xe9, 0xd2, 0x2e, 0xeb, 0x65, 0xe8, 0xd9, 0xf7, 0xe1, 0x23,
+        0x2c, 0x01, 0x33, 0xf0, 0x5b, 0x26, 0x94, 0x6d, 0x3c, 0x89, 0xd5, 0x09, 0x9a, 0x25, 0xae, 0x8c,
+        0x4b, 0x5c, 0xd4, 0x4f, 0xc7, 0x78, 0x84, 0xa3, 0x62, 0x20, 0xa7, 0xa1, 0x2f, 0x9c, 0x8b, 0x2a,
+        0x4a, 0x78, 0x50, 0xf5, 0xcb, 0x88, 0xf3, 0x84, 0x8f, 0x7f, 0x8e, 0x64, 0x7e, 0xaa, 0xfc, 0x5f,
+        0xe4, 0x46, 0x1d, 0xea, 0xb0, 0x55, 0xc1, 0xf3, 0x5f, 0x39, 0xa9, 0xfe, 0x4f, 0xdc, 0x89, 0x1a,
+        0x96, 0x46, 0x24, 0x24, 0xbb, 0x3c, 0x5d, 0x58, 0x35, 0x1e, 0x7b, 0x66, 0xfe, 0xf7, 0x02, 0xeb,
+        0x64, 0xd3, 0xcd, 0x62, 0xa7, 0xf7, 0x4d, 0x53, 0xc1, 0xfa, 0xca, 0xc1, 0x0a, 0xfb, 0x58, 0x13,
+        0xb9, 0x4b, 0x69, 0x97, 0xfb, 0xd3, 0x3d, 0x7f, 0xa2, 0x08, 0xc5, 0x1e, 0x68, 0x7c, 0x2e, 0x26,
+        0x94, 0x47, 0x37, 0x48, 0x9f, 0x6a, 0xf3, 0x1f, 0x1c, 0x9a, 0x77, 0xa3, 0x44, 0x79, 0x78, 0x1e,
+        0x9b, 0x26, 0xc9, 0x58, 0x89, 0xdc, 0xd9, 0xe1, 0x62, 0x74, 0x01, 0x87, 0xb8, 0x4f, 0xf4, 0x32,
+        0x82, 0x68, 0xba, 0xd3, 0x92, 0x59, 0xb0, 0xde, 0x65, 0x2f, 0xe9, 0x6f, 0x3b, 0x4c, 0xf9, 0x22,
+        0x65, 0x93, 0x71, 0xdf, 0xa1, 0x2d, 0x4e, 0x31, 0x1f, 0x77, 0x3e, 0x11, 0x8a, 0x4c, 0x6f, 0xb5,
+        0xba, 0x5d, 0x7d, 0xe0, 0x54, 0xfa, 0x3e, 0x60, 0x6f, 0x30, 0x37, 0x90, 0x98, 0x61, 0x16, 0x2a,
+        0xb7, 0xe7, 0xa3, 0xdc, 0xb5, 0x0f, 0x76, 0x72, 0xfc, 0x05, 0x3d, 0xf8, 0xda, 0x15, 0xdf, 0x3f,
+        0x67, 0x16, 0x3b, 0xad, 0x98, 0x35, 0xf1, 0x6c, 0xec, 0x68, 0xfb, 0xc8, 0x85, 0x7e, 0xe1, 0x00,
+        0xbe, 0x7f, 0x0f, 0x3e, 0x63, 0xad, 0x56, 0x25, 0x9f, 0xe1, 0xa7, 0x2d, 0xe5, 0x7d, 0x4c, 0x63,
+        0x04, 0x78, 0x9a, 0x9a, 0xbb, 0x49, 0x46, 0xac, 0x75, 0x0a, 0x5e, 0x07, 0x7d, 0xae, 0x9e, 0x18,
+        0x87, 0x01, 0xc3, 0x29, 0xf3, 0xae, 0x45, 0x27, 0x8d, 0xc7, 0xac, 0x54, 0x37, 0xe4, 0xce, 0x6b,
+        0x03, 0x3c, 0x09, 0xdf, 0x16, 0xb9, 0xa9, 0x1f, 0xb6, 0x39, 0xa7, 0xf5, 0xe3, 0xff, 0x08, 0x13,
+        0xc1, 0xd1, 0xca, 0x50, 0xcc, 0xb7, 0xce, 0xf1, 0x84, 0x17, 0x0b, 0x06, 0x77, 0x83, 0xfe, 0x95,
+        0xd7, 0xcf, 0x5c, 0xec, 0x08, 0xbe, 0xe0, 0xc5, 0xe7, 0x3f, 0x30, 0xc7, 0x40, 0xa3, 0x8c, 0x1d,
+        0x0a, 0x78, 0x7a, 0x6e, 0xa1, 0xbf, 0xec, 0x3e, 0xf6, 0x69, 0xaf, 0x4b, 0x44, 0x17, 0x5b, 0xd0,
+        0xc1, 0xf7, 0xb5, 0xa6, 0x1c, 0x60, 0x08, 0x94, 0xfd, 0xdf, 0xa2, 0x8d, 0x16, 0x57, 0x51, 0x06,
+        0x89, 0xf0, 0xc1, 0x69, 0x5e, 0xe4, 0x58, 0xbe, 0xc1, 0x00, 0xe9, 0x4a, 0x48, 0xf2, 0xcd, 0xe4,
+        0x35, 0x4c, 0x3e, 0xe5, 0x12, 0x12, 0x51, 0xf5, 0x43, 0xc7, 0x9e, 0x61, 0xb1, 0xe0, 0x14, 0x00,
+        0x65, 0xd5, 0x17, 0x3a, 0x29, 0x63, 0xa4, 0x49, 0xa7, 0x31, 0x79, 0x55, 0x30, 0xbb, 0xdf, 0x7c,
+        0x3e, 0xd0, 0xca, 0x85, 0x80, 0x89, 0x8e, 0x07, 0x67, 0xd8, 0xd9, 0xf3, 0xae, 0x2d, 0xd5, 0x13,
+        0xa3, 0x9c, 0x7a, 0x5b, 0xc7, 0x78, 0x94, 0x64, 0xa5, 0x0f, 0xa6, 0x8a, 0x18, 0x8b, 0x6d, 0x09,
+        0xcd, 0x64, 0x3a, 0xee, 0xc0, 0x0d, 0x79, 0xe0, 0xb6, 0x73, 0x98, 0x0a, 0xa2, 0x6b, 0x7f, 0x08,
+        0xc4, 0xfa, 0x1f, 0xd5, 0x06, 0x55, 0x3f, 0x16, 0xa7, 0xc1, 0xdb, 0x54, 0x0c, 0x2d, 0x54, 0xcb,
+        0xeb, 0x16, 0x4d, 0xdd, 0x0c, 0x37, 0x58, 0xf9, 0x4b, 0xc2, 0xbc, 0xab, 0xfa, 0xc4, 0x66, 0x1a,
+        0x73, 0xe1, 0xab, 0x82, 0xfb, 0xc8, 0x2a, 0x9d, 0xc2, 0x7a, 0x50, 0x1a, 0x60, 0xa0, 0x77, 0x25,
+        0x57, 0x92, 0xde, 0x8d, 0xa0, 0x60, 0x6a, 0xcc, 0xf1, 0x1d, 0xab, 0x15, 0x17, 0xe7, 0x5d, 0x26,
+        0x93, 0xbd, 0x31, 0x81, 0xb6, 0x3c, 0x71, 0x92, 0xdb, 0x12, 0xbb, 0x65, 0xf1, 0x9a, 0xbd, 0xe8,
+        0xbf, 0xda, 0x70, 0x30, 0x2c, 0x3d, 0x0e, 0xde, 0xac, 0xab, 0xbc, 0xb7, 0xdb, 0x5b, 0x82, 0x1b,
+        0x99, 0x8b, 0xdc, 0xde, 0x59, 0x52, 0xa1, 0xd6, 0x95, 0xa8, 0xbd, 0x69, 0x3e, 0x4e, 0x52, 0x11,
+        0xac, 0x99, 0x2a, 0xdd, 0x6c, 0xb0, 0x17, 0x92, 0x31, 0x96, 0xc3, 0x9c, 0x61, 0x8d, 0xbc, 0x25,
+        0xeb, 0x04, 0x35, 0x52, 0x90, 0x2f, 0x19, 0x47, 0x01, 0x75, 0x53, 0x1f, 0xa1, 0x96, 0xf9, 0x5d,
+        0x58, 0xf1, 0x55, 0x18, 0x66, 0x16, 0x85, 0x3f, 0xbb, 0xd8, 0x9d, 0x13, 0xf9, 0x5d, 0x50, 0x09,
+        0xff, 0x27, 0xf4, 0xe0, 0x77, 0x51, 0x65, 0xd4, 0x9a, 0x26, 0xb3, 0x25, 0x32, 0xdf, 0xe5, 0x59,
+        0x44, 0x10, 0xc1, 0xe9, 0x23, 0x96, 0x15, 0xe2, 0x70, 0x8b, 0x67, 0x3c, 0x43, 0x9c, 0xc9, 0x09,
+        0xc7, 0x55, 0xe4, 0x93, 0x71, 0x21, 0xc0, 0xf0, 0x8f, 0xfc, 0x6a, 0xd1, 0xb5, 0x12, 0x94, 0x06,
+        0xb1, 0xc9, 0x35, 0x95, 0x36, 0xcd, 0x34, 0xba, 0x2d, 0x54, 0x81, 0xd6, 0x42, 0x8c, 0xbf, 0x17,
+        0xe5, 0x29, 0x5d, 0xd7, 0xb3, 0x93, 0xa3, 0x5e, 0x78, 0x3d, 0xf1, 0x38, 0x38, 0xca, 0x94, 0x54,
+        0x3e, 0x25, 0x85, 0xcd, 0xb2, 0x0e, 0x53, 0x51, 0x88, 0x56, 0x70, 0x50, 0x9b, 0xf6, 0x21, 0x02,
+        0x3c, 0x76, 0x12, 0x24, 0x0b, 0x3a, 0x06, 0x3f, 0x63, 0x96, 0x06, 0xb8, 0xbc, 0x71, 0x32, 0x78,
+        0x06, 0xea, 0x6c, 0xa0, 0x5e, 0x6c, 0xdf, 0x3b, 0x47, 0x76, 0xbc, 0xa7, 0x14, 0xe1, 0xa4, 0x1e,
+        0x0e, 0xa6, 0x48, 0xc5, 0x18, 0x11, 0x07, 0x1a, 0x20, 0xf3, 0xf0, 0xb0, 0xeb, 0x8a, 0x2f, 0xf6,
+        0x33, 0xbc, 0xa9, 0x1a, 0xf1, 0xe9, 0xd0, 0x37, 0x46, 0xa9, 0x66, 0x09, 0x19, 0xce, 0x96, 0x00,
+        0x2a, 0x83, 0x75, 0xb3, 0x95, 0x34, 0xc9, 0xf8, 0x42, 0x77, 0x8f, 0x10, 0xf2, 0x90, 0x9a, 0x27,
+        0xd8, 0x1f, 0x88, 0x2d, 0x8d, 0x14, 0xb6, 0xa2, 0x5a, 0x16, 0x3f, 0x79, 0x68, 0x21, 0xce, 0x15,
+        0x06, 0x81, 0xdf, 0x2c, 0xfe, 0xdc, 0x90, 0xf5, 0x87, 0x9c, 0xa1, 0x7d, 0xe6, 0x73, 0xde, 0xdd,
+        0xa7, 0x07, 0x76, 0xed, 0xe4, 0x5a, 0xe9, 0xdf, 0x6b, 0xb9, 0x48, 0x8b, 0x62, 0xcd, 0x29, 0x27,
+        0x7a, 0x68, 0xc4, 0x30, 0x9c, 0x42, 0xda, 0x79, 0xb4, 0x5d, 0xea, 0x60, 0x52, 0x93, 0xec, 0x3f,
+        0xf1, 0xa4, 0x67, 0x5b, 0xb1, 0x5c, 0x94, 0xdf, 0x1e, 0x1f, 0x36, 0x9b, 0x77, 0x31, 0x8f, 0x11,
+        0x8d, 0xbb, 0xaf, 0xfd, 0xb7, 0xf6, 0x08, 0x75, 0x12, 0x13, 0x2d, 0x11, 0x43, 0xe8, 0xb1, 0x5c,
+        0xea, 0x87, 0xa9, 0xff, 0xe5, 0x53, 0xbd, 0xb1, 0x82, 0x60, 0x03, 0x11, 0x34, 0x55, 0x6d, 0x0b,
+        0x95, 0xaf, 0xe3, 0xa5, 0x55, 0x65, 0x65, 0xcc, 0xad, 0x9f, 0x1c, 0xa2, 0x77, 0x1c, 0x2b, 0xa6,
+        0x03, 0x4f, 0x0f, 0x7e, 0x77, 0x7f, 0x32, 0xdf, 0xbe, 0xc9, 0x0c, 0x78, 0x31, 0x10, 0x56, 0x25,
+        0x33, 0x76, 0xbb, 0xff, 0x13, 0x36, 0xf3, 0xb6, 0x25, 0x42, 0x54, 0x9b, 0x95, 0xa3, 0x17, 0x45,
+        0x6e, 0x4a, 0x54, 0x89, 0xf2, 0xf7, 0x52, 0xbe, 0x8b, 0xa3, 0x31, 0xdf, 0xf9, 0xcc, 0xd1, 0x05,
+        0x99, 0xb3, 0xb1, 0xc3, 0x25, 0x9d, 0x6e, 0xf5, 0x5f, 0x25, 0x8c, 0x33, 0xef, 0xcd, 0xa8, 0xed,
+        0x58, 0x75, 0x95, 0x5e, 0x9b, 0x39, 0xe7, 0xe7, 0x9e, 0xee, 0x2b, 0x2a, 0x1b, 0xad, 0x07, 0x0b,
+        0x50, 0x49, 0xc7, 0x99, 0x04, 0x3f, 0x9b, 0x58, 0x2f, 0x54, 0x43, 0xfd, 0x27, 0x89, 0x37, 0x98,
+        0x88, 0xc9, 0xfd, 0x08, 0x16, 0x29, 0xe5, 0xa6, 0x90, 0x21, 0x5d, 0x19, 0xe6, 0xf3, 0xf1, 0x2d,
+        0x0a, 0x6b, 0x7d, 0x5c, 0xa7, 0xc5, 0x68, 0xa8, 0xbd, 0x98, 0xa6, 0xdb, 0xc7, 0x08, 0xca, 0x46,
+        0xd1, 0xbb, 0x19, 0x8b, 0x28, 0xd5, 0x40, 0x23, 0x36, 0x0b, 0xf7, 0x2d, 0xc4, 0xe9, 0x7f, 0x2b,
+        0xbd, 0x09, 0x60, 0xab, 0x55, 0x30, 0x4c, 0x06, 0x5b, 0xf8, 0x1d, 0x0a, 0xe7, 0x2b, 0xd1, 0x85,
+        0xb2, 0x87, 0xa2, 0xf9, 0xf3, 0xb8, 0x75, 0x1a, 0x67, 0x32, 0x5e, 0x42, 0xc4, 0xa3, 0xf4, 0x01,
+        0xb8, 0xcb, 0x26, 0xf5, 0x4d, 0x77, 0x5c, 0xe9, 0x62, 0xe1, 0x1f, 0xc5, 0xd6, 0xae, 0x12, 0x5e,
+        0x6d, 0x19, 0x41, 0xdd, 0x10, 0xc5, 0x47, 0x73, 0x8a, 0xbf, 0xa0, 0x86, 0x1b, 0x58, 0x23, 0x2b,
+        0x01, 0xd9, 0xd2, 0xb0, 0x22, 0xd3, 0x3b, 0x4e, 0xef, 0x57, 0x6d, 0xee, 0x68, 0x80, 0xfb, 0x03,
+        0x19, 0x72, 0x37, 0x9a, 0x9d, 0xe3, 0x71, 0x03, 0xf3, 0xba, 0x43, 0xdd, 0xd8, 0xfc, 0x86, 0x0d,
+        0x21, 0xba, 0x87, 0x9c, 0x0c, 0x3a, 0xcb, 0xb0, 0x9a, 0x9b, 0xca, 0xbf, 0x45, 0x21, 0x83, 0x6b,
+        0xe9, 0x96, 0xad, 0x88, 0x34, 0x44, 0x09, 0xf8, 0x45, 0xb9, 0x61, 0x08, 0x38, 0x17, 0xfd, 0x29,
+        0x26, 0xb8, 0x45, 0xf7, 0xb4, 0x01, 0xd3, 0x58, 0x86, 0x32, 0x0c, 0x0d, 0x9b, 0x87, 0xd6, 0x66,
+        0x15, 0x2d, 0xf2, 0x63, 0x84, 0x68, 0x36, 0xa4, 0x57, 0xe8, 0x54, 0x11, 0xc4, 0xa4, 0xac, 0x1c,
+        0x46, 0x88, 0xdc, 0x98, 0x61, 0x7b, 0x40, 0x43, 0x8d, 0xaf, 0xd9, 0xe2, 0xe2, 0x33, 0x56, 0x8d,
+        0x90, 0x60, 0x42, 0xf0, 0x43, 0x3c, 0xd3, 0x7e, 0x22, 0x5a, 0xa8, 0xdb, 0x47, 0x4b, 0x4d, 0x10,
+        0x27, 0xd2, 0x8d, 0x0e, 0x95, 0xf0, 0x02, 0x37, 0x28, 0x2e, 0x88, 0xe5, 0x4b, 0x9c, 0xad, 0x23,
+        0x7a, 0x8f, 0xe0, 0x6c, 0x02, 0xb2, 0x96, 0x3a, 0x09, 0xb1, 0x54, 0x63, 0x53, 0xeb, 0x9a, 0x25,
+        0x42, 0xb8, 0xf1, 0x5a, 0x80, 0x67, 0xf2, 0xb4, 0x6e, 0x61, 0x4b, 0xfa, 0xec, 0xfe, 0x95, 0x82,
+        0x5c, 0xa3, 0xf9, 0xc5, 0xea, 0xbd, 0xe9, 0x35, 0x74, 0x94, 0xda, 0xbb, 0xd6, 0x6d, 0xbb, 0x0c,
+        0x9e, 0x6b, 0x83, 0x2d, 0x44, 0xcc, 0x36, 0xb5, 0x44, 0xec, 0x57, 0x38, 0x36, 0x41, 0x95, 0x38,
+        0xa3, 0x93, 0xcd, 0x41, 0x09, 0xbc, 0xfe, 0xfd, 0x52, 0x76, 0x39, 0x68, 0x28, 0x88, 0x8d, 0x18,
+        0xc3, 0xe3, 0xf1, 0xa2, 0xb8, 0x12, 0xe1, 0xa2, 0xc6, 0x60, 0xb2, 0xf9, 0x84, 0x93, 0xde, 0x02,
+        0xed, 0x8b, 0xe3, 0xe8, 0x63, 0xbf, 0xcf, 0xeb, 0xda, 0xb2, 0x74, 0x27, 0xe3, 0x1e, 0x1b, 0x28,
+        0xc0, 0xb9, 0x4c, 0x21, 0xef, 0x9d, 0x79, 0xe7, 0x5f, 0xb0, 0xa5, 0x20, 0xfd, 0xe9, 0x03, 0x2b,
+        0x69, 0xfb, 0x0b, 0xeb, 0x29, 0x3f, 0xa6, 0x7d, 0x71, 0x8f, 0x2f, 0x18, 0x12, 0x44, 0xd5, 0x1a,
+        0xd6, 0x86, 0xfd, 0xfd, 0xe5, 0x2f, 0x09, 0x3d, 0x35, 0x00, 0xf5, 0x70, 0x8d, 0x80, 0x2a, 0x0a,
+        0x31, 0xc4, 0xa8, 0xb3, 0x89, 0xd5, 0x05, 0xb8, 0xba, 0xec, 0x6c, 0x8d, 0xc9, 0x17, 0x53, 0x00,
+        0xad, 0xda, 0x5e, 0xdb, 0xdc, 0x6d, 0x60, 0xb8, 0x0e, 0x7c, 0x40, 0x31, 0x9e, 0x1f, 0xfe, 0xa3,
+        0xb8, 0xa3, 0x57, 0x32, 0x40, 0x55, 0xcf, 0xd5, 0x4a, 0x91, 0x03, 0x3d, 0x4c, 0x91, 0x3d, 0x13,
+        0x39, 0x6e, 0x67, 0x4f, 0xd6, 0xc3, 0x88, 0x43, 0xfd, 0x5f, 0x8e, 0x11, 0x71, 0x95, 0x43, 0xeb,
+        0xf2, 0xed, 0x59, 0xd5, 0x4d, 0xc8, 0x23, 0x0a, 0x32, 0xe2, 0xeb, 0xe3, 0x9f, 0x82, 0xa4, 0x0d,
+        0x79, 0xc2, 0x21, 0x71, 0xb6, 0xf3, 0x70, 0xee, 0xc1, 0x33, 0x08, 0x7e, 0xb7, 0x28, 0xf3, 0x47,
+        0xec, 0xfc, 0x98, 0x55, 0x64, 0xa9, 0x03, 0x33, 0x5e, 0x41, 0x28, 0x7c, 0x72, 0x57, 0xc3, 0x22,
+        0x12, 0xf5, 0x20, 0xb3, 0x46, 0xed, 0xf1, 0xc7, 0x51, 0x75, 0xf2, 0x69, 0xba, 0xe8, 0x50, 0x54,
+        0x75, 0x63, 0xcd, 0x58, 0x77, 0x66, 0xea, 0x37, 0x39, 0x71, 0xc0, 0x14, 0x48, 0xbb, 0x24, 0x07,
+        0x90, 0x2c, 0x3e, 0x98, 0x3d, 0x87, 0xdf, 0x14, 0xda, 0xd4, 0x4b, 0x49, 0x03, 0x32, 0xc7, 0xdf,
+        0x17, 0x15, 0x4b, 0x4b, 0x37, 0x37, 0x3b, 0x2e, 0x06, 0x09, 0x9c, 0xdc, 0xf3, 0x48, 0xbc, 0x2c,
+        0x56, 0x8c, 0x29, 0x66, 0xa5, 0x9b, 0x84, 0x71, 0x72, 0xea, 0x9d, 0x8d, 0x20, 0x25, 0x5b, 0x8e,
+        0xd9, 0xda, 0xfd, 0x2a, 0x46, 0x23, 0x97, 0x31, 0x7d, 0x09, 0x5e, 0x6c, 0x7c, 0x4f, 0x51, 0x04,
+        0xb2, 0x5d, 0xd6, 0x3e, 0x8f, 0x8c, 0x29, 0x6e, 0x78, 0xa7, 0x3e, 0xbb, 0x36, 0x6b, 0x5a, 0xb4,
+        0x0b, 0x87, 0x43, 0xb7, 0xf2, 0x06, 0x87, 0xf9, 0x9d, 0x89, 0x39, 0x37, 0xeb, 0xf8, 0xa0, 0x2c,
+        0x8d, 0xaf, 0x7f, 0x96, 0x6d, 0x3a, 0x5c, 0x49, 0x61, 0xe8, 0x36, 0x09, 0x47, 0x83, 0x55, 0x93,
+        0x89, 0x0c, 0x08, 0x5b, 0x48, 0x6d, 0xfe, 0xbb, 0x0f, 0xc6, 0x3d, 0x3a, 0xb9, 0xf4, 0x96, 0x09,
+        0x0e, 0x89, 0xb6, 0x01, 0x6d, 0x37, 0x54, 0x21, 0x16, 0xd2, 0x75, 0x87, 0xed, 0xa5, 0xf2, 0xce,
+        0xe8, 0x82, 0x45, 0xe2, 0xc2, 0x4a, 0x25, 0xaa, 0x6c, 0x81, 0x44, 0xae, 0xd5, 0xfd, 0x45, 0x27,
+        0xc1, 0x98, 0xf5, 0xb9, 0xe5, 0xa1, 0x58, 0x47, 0x22, 0x06, 0xc6, 0xb0, 0x70, 0x2c, 0x76, 0xd1,
+        0xd0, 0x93, 0x36, 0x29, 0x8d, 0x66, 0xc8, 0x3c, 0x77, 0x8b, 0x94, 0x98, 0x97, 0x65, 0x56, 0x2a,
+        0x63, 0x57, 0xc3, 0x2b, 0x20, 0x86, 0xf7, 0xb2, 0xd8, 0x13, 0xb3, 0x80, 0x48, 0x09, 0xa4, 0xfb,
+        0x59, 0x42, 0x68, 0x6d, 0xf7, 0xd0, 0xdd, 0x48, 0xe3, 0xe3, 0xf5, 0x0a, 0x6c, 0x53, 0xf0, 0x14,
+        0xf1, 0x43, 0xf8, 0x4c, 0x56, 0x5c, 0xcf, 0xe7, 0x89, 0x23, 0x30, 0xa8, 0x74, 0x0b, 0xe5, 0xbf,
+        0x97, 0x9d, 0x8b, 0x3d, 0x14, 0x1f, 0x2f, 0xd1, 0x91, 0x50, 0xe4, 0x01, 0x88, 0xeb, 0xe0, 0x0a,
+        0x42, 0x39, 0x2c, 0x8a, 0x14, 0x06, 0x77, 0xac, 0x1e, 0xe0, 0xee, 0x4f, 0x1d, 0xb1, 0x9c, 0xf8,
+        0x2e, 0xd0, 0xba, 0x43, 0xf0, 0x19, 0x46, 0x5d, 0xf0, 0x43, 0xbf, 0x31, 0x09, 0xf4, 0x86, 0x16,
+        0xcd, 0x49, 0xa6, 0xf2, 0x6d, 0xcc, 0x47, 0xdd, 0xab, 0xbd, 0x5d, 0x39, 0xdf, 0x71, 0x1a, 0x42,
+        0xa3, 0x55, 0xba, 0x53, 0xe6, 0x97, 0xcb, 0x08, 0xe5, 0x32, 0x0b, 0x38, 0x09, 0xee, 0x20, 0x29,
+        0x68, 0x56, 0x6b, 0x1c, 0x47, 0x1b, 0x97, 0xd0, 0x38, 0x19, 0x58, 0x97, 0xe7, 0x44, 0x62, 0xd2,
+        0xfe, 0x5d, 0xe1, 0x62, 0x35, 0x34, 0x50, 0xf7, 0xf4, 0xd9, 0x9e, 0xf6, 0x87, 0xf9, 0xe8, 0x0c,
+        0x3e, 0x1b, 0x2d, 0x51, 0x0d, 0xf1, 0x77, 0x1b, 0xa6, 0x9e, 0xed, 0x87, 0x74, 0xeb, 0xad, 0x20,
+        0xe8, 0x7b, 0x42, 0xd6, 0x63, 0x8b, 0xbc, 0xce, 0xa2, 0xcf, 0x99, 0x75, 0x94, 0xbb, 0xd9, 0x25,
+        0x81, 0x49, 0x1b, 0x6d, 0x43, 0x32, 0xa8, 0x2a, 0x8c, 0x10, 0xc9, 0xf1, 0x49, 0x02, 0xf4, 0x4a,
+        0x28, 0x07, 0x5f, 0x79, 0xca, 0x71, 0x67, 0x2c, 0x55, 0x39, 0x1e, 0x2f, 0x41, 0xdc, 0x92, 0x19,
+        0x4c, 0xf5, 0x09, 0x39, 0xbc, 0x11, 0x8d, 0xa4, 0x84, 0x0b, 0x1d, 0x31, 0x08, 0x65, 0xab, 0xb1,
+        0x1f, 0xcd, 0xd0, 0x5b, 0x58, 0xcc, 0x7b, 0x6d, 0xf9, 0x3b, 0xcc, 0x5c, 0xf1, 0x60, 0x9b, 0x0e,
+        0x59, 0x99, 0xff, 0x04, 0xae, 0x44, 0xdf, 0x1b, 0x58, 0x5b, 0x05, 0x62, 0x10, 0x1a, 0x53, 0xde,
+        0x46, 0xd1, 0xd9, 0x13, 0x94, 0x63, 0x35, 0xa8, 0x8e, 0xaa, 0xcf, 0x42, 0x01, 0x40, 0x13, 0x00,
+        0x46, 0xe0, 0xf5, 0x2a, 0x37, 0x8e, 0x1c, 0xd1, 0x09, 0xc9, 0x9b, 0xb6, 0x78, 0xb8, 0xa3, 0x9b,
+        0x20, 0xda, 0xcf, 0xc9, 0x0a, 0x28, 0x02, 0x35, 0x75, 0x7d, 0xdb, 0x6a, 0x6f, 0xc7, 0x86, 0x20,
+        0xfb, 0x18, 0xa7, 0x25, 0xa0, 0x96, 0x6a, 0xc5, 0xf5, 0x5a, 0xf0, 0x0e, 0x80, 0x0a, 0x0d, 0xaf,
+        0x93, 0x67, 0x08, 0x58, 0x2e, 0x8b, 0x86, 0x66, 0xf1, 0xbc, 0x56, 0xf9, 0x17, 0x15, 0x3c, 0x18,
+        0xcc, 0x4c, 0x79, 0x53, 0x4e, 0x94, 0xf5, 0x7c, 0x0a, 0x44, 0x39, 0x37, 0xcf, 0x82, 0xe3, 0xed,
+        0x73, 0x33, 0xd8, 0x46, 0x7b, 0x4a, 0x5c, 0x66, 0x16, 0xeb, 0x0f, 0x63, 0x43, 0x0c, 0x41, 0x20,
+        0xc2, 0xd8, 0x44, 0x0c, 0x13, 0x05, 0xd2, 0x60, 0xc9, 0xba, 0x91, 0x81, 0xeb, 0x6e, 0x5c, 0xde,
+        0x21, 0xd3, 0xaf, 0xa9, 0x39, 0xd9, 0xe4, 0xed, 0xbf, 0x7b, 0x16, 0xee, 0x03, 0x39, 0x21, 0x0c,
+        0x32, 0xb9, 0xe4, 0xf4, 0xfc, 0x24, 0xe6, 0xb7, 0x74, 0x6f, 0x1b, 0x46, 0x92, 0x5e, 0xdf, 0x9b,
+        0x80, 0xb2, 0xf2, 0x91, 0x2b, 0x94, 0xe5, 0x0a, 0x49, 0x96, 0xba, 0x3c, 0xb4, 0x0d, 0x6b, 0x1c,
+        0x3e, 0x2b, 0xfe, 0x85, 0xd4, 0x3e, 0x46, 0x34, 0x86, 0xa9, 0x5a, 0x28, 0x9b, 0x41, 0xe4, 0x91,
+        0x59, 0xc3, 0x1c, 0x92, 0x9c, 0xad, 0xb4, 0x37, 0x5f, 0xc2, 0x85, 0x52, 0xb1, 0x31, 0xf3, 0x0d,
+        0x1b, 0x90, 0x2e, 0x90, 0xf5, 0xb9, 0x05, 0xc9, 0xe5, 0x87, 0xba, 0x1e, 0x64, 0xcf, 0xed, 0x5c,
+        0x60, 0x70, 0x0f, 0x8f, 0x72, 0xcb, 0xdd, 0x07, 0x66, 0xd7, 0x3d, 0x44, 0x68, 0x9c, 0xb0, 0x2c,
+        0x73, 0xb4, 0x79, 0xd0, 0xa5, 0xfe, 0x8b, 0xf7, 0x67, 0x0e, 0x8f, 0xd3, 0x9c, 0xc5, 0xb6, 0x7c,
+        0x0c, 0x98, 0x80, 0x46, 0x14, 0xf8, 0xf9, 0xf0, 0x18, 0x96, 0xa0, 0x2e, 0x11, 0xe3, 0xb3, 0x09,
+        0xb9, 0x38, 0xd3, 0x7a, 0x74, 0x71, 0x1a, 0xc7, 0xd8, 0x63, 0xd6, 0x8c, 0x8a, 0x7f, 0xb2, 0xda,
+        0x54, 0x81, 0xd5, 0xb0, 0x51, 0x29, 0xde, 0xa8, 0xf0, 0xcc, 0xa5, 0x59, 0xf7, 0x99, 0xc7, 0x0b,
+        0x08, 0xd5, 0x4f, 0x82, 0xeb, 0x57, 0x79, 0x01, 0x5a, 0x99, 0x8d, 0xee, 0x50, 0xef, 0x3e, 0x96,
+        0x61, 0x75, 0x27, 0xdf, 0xce, 0xf0, 0xa2, 0xb3, 0xe7, 0xeb, 0x67, 0x57, 0x3b, 0x9d, 0x76, 0x20,
+        0xff, 0x3d, 0x64, 0x5d, 0x0a, 0x4d, 0x0e, 0x0f, 0x21, 0x13, 0x96, 0x22, 0x1e, 0xf8, 0xfb, 0x81,
+        0x31, 0xa5, 0x97, 0xb3, 0x45, 0x56, 0xbb, 0xa5, 0x4c, 0x79, 0xd4, 0xed, 0x46, 0xc9, 0x4b, 0x0a,
+        0x86, 0x2a, 0xca, 0x3b, 0x4a, 0x26, 0x77, 0xff, 0x74, 0x6a, 0xfb, 0x4d, 0xd3, 0x93, 0x35, 0x0f,
+        0xc4, 0x81, 0x0d, 0x39, 0xdf, 0x55, 0xc4, 0xe9, 0x1e, 0xc9, 0xa1, 0xdc, 0x14, 0x5d, 0x68, 0x13,
+        0x49, 0x39, 0x69, 0x94, 0xa5, 0x7f, 0xf0, 0x70, 0x65, 0xf1, 0xf5, 0x7d, 0x58, 0xc7, 0x81, 0x14,
+        0xfc, 0xdd, 0x71, 0x09, 0x13, 0x90, 0x3d, 0xda, 0xef, 0x29, 0xe9, 0x9f, 0x93, 0x71, 0x87, 0x2c,
+        0x95, 0x92, 0x9b, 0x5e, 0xcc, 0x1f, 0x87, 0x00, 0x3c, 0x86, 0x99, 0x2f, 0x8f, 0x36, 0xb4, 0x57,
+        0x89, 0x84, 0xe0, 0xc9, 0x67, 0xec, 0xcf, 0x17, 0x40, 0xfa, 0x12, 0xce, 0x46, 0xde, 0x80, 0x0a,
+        0xf4, 0x8d, 0x48, 0x4f, 0x33, 0x35, 0x1e, 0xb3, 0x5e, 0xd8, 0x24, 0xd2, 0x31, 0x43, 0x67, 0x3a,
+        0xd1, 0x4d, 0xe1, 0x22, 0x7a, 0xfd, 0x8c, 0xeb, 0x21, 0x21, 0x8d, 0xb4, 0xa6, 0x32, 0xba, 0x0b,
+        0x98, 0x18, 0x90, 0x3a, 0x9f, 0xdd, 0x80, 0x88, 0x13, 0xff, 0xa0, 0xd4, 0x67, 0xa8, 0xa7, 0x87,
+        0x20, 0x18, 0x86, 0xe9, 0xb0, 0xaa, 0x0c, 0x1e, 0x53, 0xea, 0x02, 0x07, 0xc9, 0xdb, 0x8c, 0x2e,
+        0x10, 0x51, 0xa1, 0x16, 0x7b, 0x9f, 0xe7, 0x3f, 0x04, 0xd8, 0x47, 0xac, 0xf2, 0x34, 0x07, 0x12,
+        0x4b, 0x3c, 0xf1, 0x0e, 0x28, 0xb9, 0xae, 0x5f, 0x85, 0x71, 0x9b, 0x60, 0x4c, 0x22, 0xf9, 0x1b,
+        0xb1, 0x50, 0xfe, 0xba, 0xce, 0xab, 0xb6, 0x6d, 0xc7, 0x5a, 0xa4, 0xb1, 0x1d, 0xf5, 0x59, 0x9b,
+        0x0c, 0x70, 0xe9, 0xde, 0x95, 0x8e, 0x1e, 0x0e, 0x33, 0x92, 0xe4, 0x71, 0xca, 0xfb, 0xda, 0x27,
+        0x21, 0x0d, 0xf8, 0xef, 0x79, 0x95, 0xeb, 0xbb, 0x88, 0xfb, 0x04, 0xd5, 0x64, 0xbb, 0x66, 0x1c,
+        0x56, 0xb2, 0x9c, 0xe3, 0x83, 0x1a, 0x95, 0x12, 0xf5, 0x4b, 0x94, 0xac, 0x60, 0xcb, 0x70, 0x12,
+        0xa0, 0x76, 0x61, 0x8a, 0x44, 0x63, 0x6b, 0x5c, 0x53, 0xf2, 0x6e, 0xcc, 0x94, 0x70, 0x30, 0x17,
+        0x21, 0x02, 0x52, 0x86, 0xe9, 0xc2, 0xa1, 0xda, 0xf4, 0x71, 0x45, 0xc9, 0x94, 0x33, 0x50, 0x05,
+        0xf0, 0xa7, 0x30, 0x8c, 0x09, 0x92, 0x57, 0xd1, 0x4d, 0x4f, 0x50, 0x1e, 0x97, 0x38, 0x3d, 0x81,
+        0x13, 0x32, 0xae, 0x3a, 0x28, 0x9c, 0xb2, 0xfd, 0x1c, 0xb2, 0xd0, 0x0a, 0xf4, 0x1a, 0xbd, 0x2e,
+        0x9c, 0xfa, 0x75, 0x39, 0xac, 0x91, 0x1b, 0x60, 0x24, 0x2b, 0x23, 0xeb, 0xa7, 0x34, 0x1f, 0x96,
+        0xe2, 0x02, 0x45, 0xef, 0xd5, 0x7b, 0x3f, 0x0e, 0xc4, 0x81, 0x7e, 0xfc, 0x59, 0x4b, 0xeb, 0x02,
+        0x36, 0x3d, 0xca, 0xff, 0x95, 0x90, 0xef, 0x18, 0x02, 0x6c, 0x35, 0xae, 0x14, 0x1a, 0x26, 0xc1,
+        0xfe, 0xae, 0x0e, 0xac, 0x99, 0xf8, 0xe9, 0x2e, 0x82, 0x47, 0x80, 0x53, 0x4e, 0xdc, 0x02, 0x13,
+        0x5c, 0x2d, 0x2b, 0xb5, 0x82, 0xe6, 0xb4, 0xbc, 0x6d, 0x55, 0xab, 0x71, 0xa5, 0xca, 0x4d, 0x83,
+        0xae, 0x19, 0xa6, 0x8d, 0xed, 0x62, 0x9c, 0x3d, 0x02, 0xfc, 0xdd, 0xe0, 0x61, 0xe4, 0x84, 0x15,
+        0xf8, 0xc8, 0x01, 0xbc, 0xfc, 0xa5, 0x10, 0xd6, 0x23, 0x8f, 0x94, 0x8a, 0xcf, 0xf6, 0x1e, 0x93,
+        0x51, 0x12, 0xf6, 0x0f, 0x68, 0x40, 0xee, 0x07, 0x9c, 0xe5, 0x6f, 0x69, 0x0b, 0x85, 0x88, 0x1b,
+        0xd7, 0x20, 0x0d, 0x8b, 0x87, 0x18, 0x5c, 0xc8, 0x64, 0xd8, 0x56, 0x8a, 0x24, 0xe8, 0x06, 0x0d,
+        0xaa, 0x31, 0x73, 0x8b, 0xc2, 0x48, 0xcc, 0x3c, 0x9d, 0x95, 0x57, 0x54, 0x2c, 0x05, 0x67, 0x28,
+        0x52, 0x76, 0x93, 0x1f, 0x1b, 0x67, 0xe9, 0xb5, 0x46, 0x1b, 0x9d, 0xb6, 0x31, 0x55, 0x22, 0xe8,
+        0xab, 0xc1, 0x65, 0x73, 0x85, 0x11, 0xe0, 0xf8, 0xa5, 0x68, 0x91, 0x8c, 0x0b, 0x7a, 0xb4, 0x23,
+        0x0f, 0x8c, 0x30, 0x31, 0xbd, 0xf1, 0x6e, 0x1b, 0x87, 0x3b, 0xc1, 0x93, 0xde, 0xcb, 0x52, 0x6d,
+        0xcd, 0x9d, 0x67, 0x04, 0x04, 0x48, 0x61, 0x6f, 0x93, 0xc3, 0x52, 0x11, 0x44, 0x98, 0x13, 0x28,
+        0xee, 0x09, 0x60, 0x9c, 0x40, 0x81, 0xa6, 0x34, 0x60, 0x0b, 0xa2, 0x82, 0x43, 0xb4, 0x29, 0xca,
+        0xa0, 0x97, 0x36, 0x9b, 0x27, 0xa2, 0x18, 0x3f, 0xd6, 0x56, 0x26, 0xf3, 0xc4, 0x54, 0xc1, 0x2d,
+        0xa9, 0xee, 0x99, 0x3f, 0x85, 0xdd, 0xa5, 0x70, 0x2a, 0x1c, 0xe7, 0xfd, 0xb4, 0x38, 0xb8, 0x19,
+        0x78, 0xa0, 0x90, 0xc7, 0x0e, 0x62, 0x6b, 0xfb, 0x61, 0x2a, 0x00, 0x72, 0x59, 0xd1, 0x80, 0x28,
+        0x07, 0x5a, 0x1d, 0x9e, 0xfc, 0x8f, 0x75, 0x09, 0xcb, 0xb8, 0xd6, 0xa9, 0x14, 0x0d, 0xdf, 0x0b,
+        0x8d, 0x07, 0xe7, 0xe0, 0xb5, 0xca, 0x25, 0xf9, 0x99, 0x6f, 0x8e, 0x74, 0x12, 0xe1, 0x1c, 0x2c,
+        0xaa, 0x8e, 0x7b, 0x65, 0x16, 0x26, 0xfa, 0x01, 0x9b, 0x21, 0x04, 0xd1, 0x37, 0xd0, 0x74, 0x53,
+        0xbc, 0xd1, 0x42, 0xc6, 0xcd, 0xa2, 0x83, 0x52, 0xdc, 0x88, 0xe3, 0x8b, 0xcb, 0x96, 0x53, 0x28,
+        0xa8, 0x27, 0x8b, 0x07, 0xdb, 0xc1, 0x86, 0xec, 0x90, 0xfa, 0x35, 0x21, 0xd5, 0x2c, 0x1d, 0x9e,
+        0xa5, 0xdc, 0xb1, 0x9c, 0x38, 0x03, 0x81, 0x23, 0x6a, 0x1d, 0xfe, 0x70, 0x47, 0x5d, 0xec, 0x1d,
+        0xa8, 0xd3, 0x34, 0x5d, 0x9b, 0x72, 0x01, 0x98, 0xd7, 0x6b, 0xbf, 0xce, 0x5f, 0x9b, 0xda, 0xcf,
+        0x8b, 0x4e, 0xf0, 0xdf, 0x8f, 0xe2, 0xcd, 0xa3, 0xda, 0x36, 0xb6, 0xa5, 0xde, 0x9b, 0xea, 0x20,
+        0x6b, 0x92, 0xd3, 0x6e, 0xfc, 0xcf, 0x77, 0x30, 0xf6, 0xe7, 0xac, 0x7c, 0x35, 0x90, 0x28, 0xfa,
+        0x37, 0x32, 0xbe, 0x5b, 0x6a, 0x16, 0x6a, 0xaf, 0x66, 0x19, 0x68, 0xfd, 0x34, 0x1f, 0xab, 0x0f,
+        0x65, 0xaa, 0x44, 0xd5, 0x74, 0x41, 0x90, 0xc2, 0xf2, 0xe9, 0x8f, 0xde, 0x0f, 0xdf, 0xc1, 0x20,
+        0x82, 0x85, 0x89, 0x91, 0x70, 0x16, 0x60, 0x3f, 0x16, 0x85, 0xf4, 0x20, 0xd3, 0x63, 0x14, 0x05,
+        0x27, 0x2a, 0x02, 0x03, 0x32, 0x82, 0x86, 0x54, 0x88, 0x21, 0xc3, 0xfb, 0xe8, 0x7b, 0xe5, 0xe5,
+        0x48, 0x73, 0x05, 0xd5, 0xfa, 0x36, 0xb7, 0xfc, 0xf3, 0x94, 0x89, 0xda, 0xbc, 0x52, 0x1b, 0x04,
+        0xe1, 0xb0, 0x29, 0x05, 0x0f, 0xde, 0x77, 0xd0, 0xbe, 0x1c, 0xf0, 0x7c, 0x73, 0x26, 0x9f, 0x4d,
+        0x1d, 0xf2, 0xe5, 0x19, 0xd5, 0x5d, 0xbd, 0x4d, 0x34, 0x1f, 0xf3, 0x38, 0xcd, 0xc3, 0x92, 0x05,
+        0xf3, 0xa7, 0x80, 0x8e, 0xd7, 0x13, 0xe9, 0xe2, 0xea, 0xce, 0xc4, 0xb7, 0xe2, 0x85, 0x43, 0x3b,
+        0xfe, 0xd2, 0x5d, 0x80, 0x7f, 0x45, 0x29, 0x49, 0xb4, 0x23, 0xd8, 0x45, 0xab, 0x73, 0x56, 0x29,
+        0x98, 0x48, 0x55, 0xdc, 0xa8, 0x19, 0xad, 0xac, 0xee, 0xc2, 0x1f, 0xab, 0x94, 0x07, 0xd7, 0xfc,
+        0x3e, 0xc3, 0xe8, 0x0b, 0x4d, 0xaa, 0x93, 0xb6, 0x7d, 0x08, 0x61, 0x1e, 0x8b, 0x50, 0x2c, 0x06,
+        0xed, 0xe2, 0xfc, 0x34, 0xce, 0xa2, 0x08, 0x97, 0xe0, 0xf3, 0xac, 0xcc, 0xe5, 0xa1, 0x07, 0x4a,
+        0x00, 0xa7, 0x48, 0xa6, 0x56, 0xf3, 0xf3, 0x8a, 0x29, 0xb8, 0xb6, 0xfd, 0x26, 0x16, 0x2a, 0x02,
+        0xf0, 0xa5, 0x86, 0x24, 0x0e, 0x42, 0x79, 0x1b, 0x3c, 0x1f, 0xe2, 0x33, 0x39, 0xc7, 0xf4, 0xfa,
+        0x25, 0x03, 0x74, 0xa8, 0x68, 0x9d, 0x72, 0xd7, 0x95, 0x2b, 0x2a, 0xce, 0x70, 0xb0, 0xbf, 0x1a,
+        0x38, 0xaf, 0x3f, 0xf6, 0x1e, 0x1b, 0xa7, 0x1c, 0xdd, 0x43, 0x19, 0xfc, 0xce, 0x03, 0x52, 0x9d,
+        0x32, 0x9f, 0xfe, 0x2d, 0x64, 0x5d, 0x83, 0x73, 0x8f, 0x88, 0x13, 0x53, 0x9f, 0xf8, 0xe8, 0x07,
+        0x32, 0x5c, 0x9b, 0xb1, 0xd6, 0x9a, 0x51, 0x04, 0xb4, 0xd3, 0x70, 0x84, 0x57, 0x27, 0x28, 0x24,
+        0xf3, 0x9e, 0x41, 0x88, 0xf6, 0xa1, 0x02, 0x9c, 0xf8, 0xe2, 0x49, 0x7f, 0x93, 0x1c, 0xaf, 0x23,
+        0xd6, 0x8e, 0x9b, 0x15, 0xee, 0x34, 0x92, 0xee, 0xbd, 0xee, 0xd0, 0xaa, 0x2c, 0x83, 0x51, 0x5e,
+        0x74, 0x2d, 0xca, 0xd2, 0xff, 0x43, 0x9b, 0x49, 0xb8, 0xb3, 0x46, 0xe6, 0xc2, 0xf4, 0xe8, 0x02,
+        0x43, 0x97, 0xde, 0x5f, 0x97, 0x09, 0xcc, 0x01, 0x9c, 0xa3, 0xb7, 0x4c, 0xf4, 0x87, 0x52, 0x5a,
+        0xba, 0xdd, 0xce, 0x20, 0x9a, 0x2b, 0x29, 0x0d, 0xdd, 0xee, 0xab, 0xd9, 0xf5, 0x41, 0x2d, 0x05,
+        0x5b, 0x0e, 0x41, 0x7a, 0xb8, 0x12, 0x2f, 0xc5, 0xe4, 0x74, 0x54, 0xb5, 0x05, 0xff, 0xec, 0x9b,
+        0x76, 0x3a, 0x5c, 0x9e, 0xdd, 0x4f, 0xe7, 0x15, 0xa8, 0xf2, 0x43, 0xf2, 0xb2, 0x85, 0x0b, 0x2b,
+        0x99, 0x7b, 0x5f, 0x1b, 0xb9, 0xab, 0x93, 0xc4, 0xbf, 0x50, 0x1e, 0xa3, 0xf8, 0x9c, 0xd0, 0xb3,
+        0x79, 0x8f, 0xc6, 0x73, 0x87, 0x62, 0xcc, 0x36, 0x56, 0xcf, 0xbf, 0x35, 0xfc, 0x30, 0xeb, 0x0a,
+        0x1d, 0xb0, 0x94, 0x02, 0xb1, 0x6b, 0x81, 0x35, 0x2f, 0x21, 0xaa, 0xfe, 0x7a, 0xeb, 0x2c, 0xd4,
+        0x09, 0x7e, 0xfc, 0xde, 0x49, 0xd0, 0xe5, 0xf8, 0x41, 0x0b, 0xea, 0x74, 0x1a, 0x96, 0x8d, 0x21,
+        0xf4, 0x71, 0x86, 0x73, 0x51, 0x6f, 0x0a, 0x83, 0x80, 0xa9, 0x0b, 0x02, 0xb4, 0xbd, 0x79, 0xf4,
+        0x4f, 0xb6, 0x3d, 0x0a, 0xe0, 0xea, 0xa0, 0x9d, 0x1e, 0xf9, 0xa1, 0x8c, 0x96, 0x94, 0xf9, 0x14,
+        0x68, 0x28, 0x50, 0x2d, 0x4a, 0x21, 0xf5, 0x7f, 0x25, 0x05, 0x70, 0x0b, 0x66, 0xc6, 0xa7, 0xc0,
+        0x75, 0x30, 0x27, 0xcc, 0x56, 0x38, 0x6c, 0x5c, 0xa3, 0x5f, 0xe2, 0x57, 0xe5, 0x53, 0xc0, 0x09,
+        0x58, 0x14, 0x6f, 0x5d, 0x69, 0xa7, 0x24, 0x21, 0xdb, 0x3a, 0xf2, 0xec, 0xbc, 0x15, 0x2e, 0x42,
+        0x8b, 0xd2, 0x8e, 0x18, 0xd3, 0xc9, 0x7e, 0x86, 0x0c, 0x57, 0x4a, 0xf2, 0x67, 0xe3, 0x65, 0x2a,
+        0xca, 0xdd, 0x96, 0x18, 0x65, 0x66, 0xb6, 0xe1, 0xe9, 0xea, 0xd5, 0xce, 0x65, 0x30, 0x26, 0x87,
+        0x25, 0x89, 0x9d, 0x7c, 0xe7, 0x55, 0xd5, 0xd7, 0x6d, 0x6f, 0xa9, 0xde, 0x7f, 0x7d, 0xd1, 0x11,
+        0x07, 0x91, 0x89, 0x8b, 0x59, 0x67, 0x1a, 0x81, 0xd6, 0xe5, 0x6f, 0x69, 0x51, 0xaa, 0xcf, 0x7d,
+        0x96, 0xd2, 0xc5, 0x27, 0xf7, 0x8d, 0x16, 0x12, 0xef, 0x27, 0x72, 0x68, 0x8f, 0x7b, 0xf5, 0x16,
+        0x9a, 0xb6, 0x91, 0x55, 0x78, 0x34, 0xd4, 0x91, 0x56, 0x32, 0x5a, 0xa4, 0x54, 0xe4, 0x48, 0x03,
+        0x4d, 0x50, 0xb9, 0x29, 0x26, 0x41, 0x5b, 0xc8, 0x17, 0x1c, 0x9e, 0xee, 0x88, 0x4a, 0xe6, 0x0b,
+        0x32, 0x20, 0x16, 0xdd, 0xb0, 0x18, 0x71, 0xd9, 0x75, 0x58, 0x76, 0xac, 0x42, 0x96, 0xbb, 0x15,
+        0xd1, 0xe2, 0xaa, 0xe5, 0x03, 0x1b, 0xb9, 0x0b, 0xc7, 0xa0, 0xf3, 0x49, 0x20, 0xf1, 0x2d, 0x13,
+        0xf9, 0xa8, 0x3d, 0x88, 0x3f, 0x2e, 0x7d, 0xfd, 0xdf, 0xca, 0xaf, 0x2f, 0x37, 0xe1, 0x27, 0x18,
+        0x3e, 0xca, 0x58, 0x96, 0xf2, 0xdd, 0x01, 0x6b, 0x6d, 0x35, 0x64, 0xcd, 0x96, 0xd2, 0x48, 0x11,
+        0x53, 0xcc, 0x8a, 0x15, 0x70, 0xcb, 0x03, 0x99, 0xf3, 0xa4, 0xf4, 0xb1, 0xc2, 0xe1, 0xf3, 0xe1,
+        0xc7, 0x01, 0x8a, 0xcd, 0x83, 0x2e, 0x9a, 0x96, 0x9a, 0xcd, 0x5c, 0xe3, 0x36, 0xa8, 0xc2, 0x24,
+        0x6e, 0xb5, 0x4e, 0xbb, 0x1a, 0x14, 0xe9, 0x3e, 0x49, 0x26, 0x5a, 0x84, 0x5c, 0x3d, 0x91, 0xee,
+        0x17, 0x3b, 0x51, 0x33, 0x28, 0x1e, 0x66, 0xf2, 0x27, 0x5c, 0x8c, 0xb8, 0x73, 0x3c, 0x80, 0x2c,
+        0xf7, 0xd5, 0x2c, 0x84, 0xe4, 0x0d, 0x1b, 0xef, 0x82, 0xac, 0x3b, 0x46, 0x4f, 0xac, 0xac, 0xf7,
+        0x92, 0xad, 0x8e, 0x9f, 0x6f, 0x5e, 0x85, 0x9e, 0xa6, 0x04, 0x9c, 0xf7, 0x21, 0xb0, 0x92, 0x10,
+        0xbd, 0x3f, 0xdf, 0xf9, 0x64, 0x67, 0xac, 0xdf, 0xd5, 0x9f, 0x4e, 0x8f, 0xa9, 0x30, 0xde, 0x54,
+        0xce, 0x59, 0x14, 0xe9, 0x36, 0x57, 0xb1, 0x1d, 0x60, 0x0a, 0xa5, 0x10, 0x3b, 0x6d, 0x2b, 0x01,
+        0x1c, 0xda, 0x47, 0xd1, 0x98, 0x13, 0x78, 0x25, 0x00, 0xe9, 0x29, 0xcd, 0x5f, 0x6c, 0xb6, 0x3b,
+        0x96, 0x61, 0x86, 0x70, 0xbf, 0x5f, 0x01, 0x4e, 0xeb, 0x56, 0x5f, 0x51, 0xd8, 0x14, 0x57, 0x25,
+        0xf7, 0xe1, 0x4f, 0x51, 0xa8, 0xc3, 0x42, 0x32, 0xc7, 0x0a, 0x1a, 0x28, 0x39, 0x73, 0x89, 0x8c,
+        0x92, 0x73, 0xb3, 0x3e, 0x10, 0x0b, 0xb2, 0x90, 0x69, 0xe7, 0x45, 0x5b, 0x66, 0xd9, 0x49, 0x20,
+        0x35, 0xaa, 0x09, 0x43, 0x2f, 0x43, 0x6f, 0x6e, 0x7b, 0xef, 0xd3, 0x5d, 0x81, 0x8e, 0xec, 0xfc,
+        0xb4, 0xf8, 0x6f, 0xd3, 0x22, 0x09, 0xc8, 0x9d, 0x51, 0x7b, 0x3f, 0x4d, 0x5e, 0x95, 0xfc, 0x2b,
+        0xde, 0x64, 0x8f, 0x3f, 0xb2, 0xf0, 0x20, 0xfb, 0xe0, 0x69, 0xf4, 0x52, 0xaa, 0x55, 0xc2, 0xb5,
+        0xac, 0xba, 0x87, 0x3b, 0xba, 0x9c, 0x99, 0x6c, 0xcb, 0xaf, 0xf3, 0xca, 0x54, 0xeb, 0x59, 0x04,
+        0xc9, 0xaf, 0xe3, 0x9e, 0xf2, 0xa3, 0xbc, 0x60, 0x5b, 0x2e, 0x87, 0x1c, 0xe5, 0x92, 0x87, 0x28,
+        0x42, 0xeb, 0x07, 0x10, 0xc8, 0xe8, 0x01, 0xef, 0xcc, 0x85, 0xea, 0xf6, 0x60, 0xa7, 0x84, 0x06,
+        0x60, 0x69, 0x0e, 0x48, 0x25, 0xb7, 0xb3, 0x17, 0xe3, 0x76, 0x5f, 0x68, 0xa7, 0x2b, 0xa8, 0x9e,
+        0x47, 0x04, 0x59, 0x3a, 0xfa, 0x0f, 0xb3, 0xa5, 0x39, 0xec, 0x25, 0x07, 0x9e, 0x2f, 0xb6, 0x1e,
+        0x09, 0xf1, 0x6e, 0x5f, 0x86, 0xc9, 0x89, 0x32, 0x59, 0x4c, 0xf4, 0xec, 0x71, 0x65, 0xae, 0xfe,
+        0x3e, 0xdc, 0xc7, 0x59, 0xe4, 0x08, 0x50, 0x2f, 0x33, 0x33, 0x23, 0x02, 0xe1, 0xaf, 0xe5, 0x07,
+        0x9e, 0x1b, 0x8a, 0x8e, 0xaf, 0x0a, 0x98, 0x41, 0xad, 0x5c, 0xcc, 0x42, 0xe8, 0xed, 0xc4, 0x2d,
+        0xa3, 0x15, 0xfe, 0x05, 0x5b, 0xb6, 0x4f, 0x0f, 0x7a, 0xf8, 0x67, 0x3a, 0x3b, 0x2b, 0xe2, 0x2f,
+        0x51, 0x31, 0x9b, 0x62, 0x12, 0x5b, 0x33, 0x55, 0x07, 0x58, 0xc4, 0x61, 0x46, 0xe7, 0xb8, 0x50,
+        0x8e, 0x09, 0xdd, 0xdf, 0x67, 0x39, 0x92, 0x90, 0x01, 0x93, 0x00, 0xe4, 0x91, 0x7c, 0x73, 0x2c,
+        0x64, 0x4b, 0x0c, 0x84, 0xec, 0x42, 0x2b, 0x4a, 0xde, 0x79, 0xf4, 0x41, 0x4a, 0xc9, 0x8a, 0x7e,
+        0xb6, 0xc0, 0x0f, 0xd6, 0x88, 0xb6, 0x98, 0x56, 0xf3, 0x2c, 0x28, 0xe4, 0xa1, 0xb3, 0xc7, 0x00,
+        0xed, 0xbc, 0x81, 0xc6, 0xfc, 0xa7, 0xff, 0xa3, 0xa7, 0xfb, 0x67, 0x1b, 0xa5, 0x6f, 0xe5, 0x17,
+        0xec, 0x02, 0xa7, 0xa2, 0x56, 0x84, 0xb1, 0x91, 0xd8, 0x4f, 0x48, 0xf7, 0x82, 0x3e, 0xca, 0x1e,
+        0x86, 0xcc, 0x84, 0xbb, 0x94, 0x96, 0x19, 0xd4, 0xf5, 0x44, 0x38, 0xf3, 0xec, 0x8e, 0xec, 0x3b,
+        0xbb, 0x0e, 0xdf, 0xde, 0xfb, 0x74, 0xe6, 0x8d, 0x64, 0xaf, 0x88, 0xa8, 0x7e, 0x36, 0x88, 0x1a,
+        0x07, 0x25, 0xb3, 0xab, 0x0b, 0x69, 0xd8, 0x70, 0x65, 0x9d, 0x58, 0x54, 0x24, 0x94, 0xa8, 0x93,
+        0xd1, 0x4c, 0x74, 0x07, 0x24, 0x3d, 0x3f, 0x54, 0xed, 0x33, 0xf0, 0x5c, 0xb4, 0xe9, 0x1d, 0x2e,
+        0xe7, 0x7e, 0x82, 0x9f, 0x39, 0xdc, 0xef, 0xe3, 0x8d, 0x76, 0xba, 0x29, 0x09, 0x11, 0x91, 0x35,
+        0x65, 0x4f, 0xd9, 0xb5, 0x01, 0xe2, 0x32, 0x52, 0xaa, 0x87, 0xc1, 0xc3, 0x32, 0x8b, 0x3c, 0x25,
+        0x94, 0x6c, 0x2e, 0xb8, 0xac, 0x94, 0x38, 0xab, 0xab, 0x30, 0x73, 0x7b, 0xb9, 0x3a, 0x71, 0x26,
+        0x94, 0x31, 0x55, 0x0b, 0x1b, 0x39, 0x09, 0x6d, 0x30, 0x8a, 0xb4, 0x5c, 0x4b, 0x9c, 0xc8, 0x27,
+        0x82, 0x9a, 0xe4, 0x03, 0xa2, 0xad, 0x20, 0x86, 0x57, 0xaa, 0x45, 0x41, 0xc5, 0xd9, 0x14, 0xaf,
+        0x73, 0x90, 0x76, 0x83, 0x0c, 0x75, 0x3d, 0xed, 0x91, 0x6d, 0x6e, 0xa9, 0xd2, 0x86, 0xd5, 0x1a,
+        0xdf, 0xf5, 0xdb, 0x13, 0x6a, 0x07, 0x52, 0x74, 0x6d, 0x60, 0xe5, 0x11, 0xa0, 0x79, 0x64, 0xa9,
+        0x16, 0xec, 0xd3, 0x7b, 0x7e, 0xe0, 0x41, 0xff, 0xbd, 0xba, 0xc2, 0xe3, 0x6d, 0xd7, 0xb8, 0x2c,
+        0x25, 0xd3, 0xc0, 0x47, 0x35, 0x0c, 0x96, 0x39, 0x12, 0x88, 0x20, 0xe3, 0xab, 0xf7, 0xcb, 0x63,
+        0x6f, 0x98, 0x79, 0xbc, 0x2d, 0xad, 0x74, 0xbd, 0x62, 0xda, 0xe5, 0xa4, 0x84, 0x54, 0xcd, 0x28,
+        0xaa, 0xe9, 0x32, 0x6a, 0x0a, 0x93, 0x96, 0x3e, 0x4f, 0x7a, 0x6d, 0x30, 0xe3, 0xce, 0xf2, 0x04,
+        0x3c, 0xdf, 0x9a, 0x93, 0xe1, 0x53, 0x49, 0x5c, 0x83, 0xe1, 0x52, 0x5f, 0x19, 0x78, 0x9a, 0x18,
+        0xde, 0x3b, 0xb4, 0x53, 0xb0, 0xc3, 0x3d, 0xc8, 0xe4, 0x8e, 0xe7, 0x97, 0x78, 0x51, 0x0a, 0xf1,
+        0x90, 0xac, 0x67, 0x00, 0x54, 0xe2, 0xc1, 0x2f, 0x89, 0x71, 0xba, 0x3a, 0x9f, 0x29, 0xfd, 0x0c,
+        0x17, 0x58, 0xfd, 0x42, 0x84, 0xd6, 0x3b, 0x59, 0x55, 0x4e, 0x4c, 0x1a, 0xa0, 0x1c, 0xd6, 0x31,
+        0x50, 0x47, 0x1f, 0xfe, 0xbf, 0x3a, 0xfc, 0x5a, 0x73, 0x4e, 0x4e, 0x71, 0x4b, 0x01, 0x51, 0x1c,
+        0x88, 0xdb, 0x17, 0x9a, 0x31, 0xbd, 0x9a, 0xa8, 0x38, 0xe7, 0x86, 0x38, 0x5b, 0xdd, 0x1a, 0xf8,
+        0x18, 0x93, 0x16, 0x2f, 0x2c, 0x4a, 0xd2, 0xec, 0x11, 0xb7, 0x73, 0x27, 0xc8, 0xd7, 0x9b, 0x18,
+        0x6c, 0x2b, 0x3f, 0xc9, 0x9a, 0x68, 0x7e, 0xe5, 0x89, 0xed, 0xd0, 0x38, 0x7a, 0x8e, 0x88, 0xc2,
+        0x0a, 0x7c, 0xa1, 0x9a, 0xe0, 0x53, 0x86, 0xae, 0x27, 0xc2, 0x87, 0x1b, 0x82, 0x75, 0x94, 0x26,
+        0xe8, 0xc8, 0x11, 0x96, 0x4b, 0x35, 0xc4, 0x8a, 0x5c, 0xf6, 0x67, 0x1e, 0x98, 0x4b, 0xa2, 0x82,
+        0x4e, 0x5c, 0x5e, 0x4e, 0xe5, 0xcf, 0xce, 0x94, 0x71, 0x06, 0x95, 0xd4, 0x25, 0x32, 0x06, 0x2a,
+        0x5e, 0x57, 0xf6, 0xd6, 0xe4, 0x4f, 0xf7, 0x4a, 0xc1, 0x21, 0xb2, 0xdd, 0x20, 0x88, 0xd8, 0x85,
+        0x9b, 0xef, 0x5b, 0x69, 0x94, 0xb2, 0xa2, 0xed, 0x5b, 0xd6, 0x9c, 0xde, 0x88, 0x78, 0x85, 0x0a,
+        0xa6, 0xf6, 0x63, 0x2f, 0xd4, 0x17, 0x8f, 0x48, 0xcb, 0xe2, 0x30, 0xc5, 0xeb, 0x5d, 0x81, 0xdb,
+        0x6d, 0x78, 0x4d, 0xe9, 0x6d, 0x0e, 0x28, 0x31, 0x3e, 0xad, 0x6e, 0xc9, 0xa3, 0xd5, 0x03, 0x29,
+        0x6a, 0xba, 0x8b, 0x19, 0x98, 0x6d, 0xb1, 0xa6, 0xa6, 0x1c, 0x00, 0xa2, 0xa1, 0xd2, 0xaf, 0x99,
+        0x5f, 0x78, 0x82, 0x98, 0x9b, 0x24, 0xa6, 0xa1, 0xa7, 0xd9, 0x6b, 0xd3, 0xc9, 0x23, 0xe2, 0x2e,
+        0x86, 0xb7, 0xf4, 0x9d, 0xa8, 0x72, 0xf2, 0xa4, 0xe9, 0xdd, 0x11, 0x08, 0x3b, 0x79, 0x74, 0x37,
+        0xd8, 0xe0, 0x9e, 0x39, 0x85, 0xd8, 0xc6, 0xb1, 0x52, 0xc9, 0xc3, 0xdd, 0x2c, 0xda, 0x95, 0x03,
+        0x96, 0x9b, 0xc2, 0x58, 0xb6, 0xca, 0x61, 0x59, 0xa9, 0x52, 0xf6, 0xcf, 0xe9, 0x9b, 0x92, 0xe0,
+        0x4e, 0xd6, 0x7c, 0x6d, 0xed, 0x0d, 0xc5, 0xd1, 0xab, 0x42, 0xfb, 0x55, 0x56, 0xe7, 0xb7, 0x08,
+        0xd0, 0x2f, 0xeb, 0xd2, 0x3f, 0xec, 0x5f, 0x84, 0x62, 0xe5, 0xd0, 0x9f, 0x90, 0x58, 0x8e, 0x1e,
+        0xf2, 0x07, 0xaf, 0xc6, 0x54, 0x54, 0xfb, 0x6d, 0x1b, 0xf5, 0x40, 0xe4, 0x7e, 0xc0, 0xec, 0x02,
+        0xa6, 0xbe, 0xfb, 0x28, 0x7c, 0x4f, 0xf6, 0x5d, 0x21, 0x3a, 0xa1, 0x57, 0xae, 0x4a, 0xf2, 0xd7,
+        0xc4, 0x42, 0xbc, 0xa2, 0xa1, 0x04, 0x23, 0xcb, 0xe3, 0xbb, 0x36, 0x3a, 0x5e, 0x8c, 0xb8, 0x2f,
+        0x98, 0xc0, 0x8a, 0x4e, 0x1c, 0xa1, 0x97, 0x78, 0x20, 0xeb, 0xb1, 0x22, 0x7c, 0x10, 0x6d, 0x00,
+        0x12, 0xb3, 0x7b, 0x66, 0x7c, 0xfa, 0x9c, 0x5d, 0x71, 0x14, 0xad, 0x20, 0x7e, 0x5d, 0x02, 0x0d,
+        0xe9, 0x82, 0x9b, 0xb4, 0xf4, 0xf4, 0x58, 0xde, 0xa8, 0xc2, 0x43, 0x8a, 0x45, 0xf1, 0x40, 0xdc,
+        0x49, 0xfc, 0xbb, 0xb7, 0x78, 0x0f, 0x60, 0x79, 0xe7, 0x8c, 0x24, 0xe2, 0x7f, 0x80, 0x4e, 0x20,
+        0x6a, 0x94, 0xc9, 0x19, 0xc2, 0x5a, 0xe5, 0x49, 0x6c, 0x08, 0x1b, 0xeb, 0x0b, 0x89, 0x80, 0xb6,
+        0x21, 0xea, 0x71, 0x7d, 0x01, 0x62, 0x77, 0x07, 0x5e, 0x69, 0x01, 0x0e, 0x8a, 0x94, 0x73, 0x1f,
+        0xb8, 0x52, 0xbf, 0x6f, 0x3e, 0x55, 0x84, 0xd9, 0x56, 0x39, 0x5a, 0x39, 0xd4, 0x25, 0xc0, 0xc5,
+        0xa7, 0x3f, 0x45, 0x5c, 0xbc, 0x44, 0x1f, 0x9c, 0x22, 0x16, 0x66, 0xd3, 0xe9, 0x86, 0xc1, 0x12,
+        0xb6, 0x46, 0x43, 0x70, 0x72, 0x87, 0x3c, 0x31, 0xbd, 0xe8, 0xf7, 0x60, 0x90, 0x55, 0xad, 0x28,
+        0xc6, 0x46, 0x64, 0xad, 0x66, 0xc4, 0x34, 0xde, 0x5a, 0xeb, 0x01, 0xd9, 0x6d, 0x2c, 0x95, 0x14,
+        0x3c, 0x71, 0xa9, 0xc5, 0xa0, 0xef, 0x27, 0x26, 0xbc, 0xf1, 0x95, 0xd5, 0xad, 0x20, 0xa3, 0x31,
+        0x5e, 0x19, 0x68, 0xd4, 0x70, 0x73, 0xeb, 0x0b, 0xc5, 0x3e, 0x0b, 0x9b, 0xd5, 0xcf, 0x95, 0x11,
+        0x72, 0x9d, 0x1c, 0xd2, 0x1f, 0xc5, 0x47, 0x02, 0x3b, 0xc5, 0xe2, 0x4f, 0x9e, 0x8b, 0x4d, 0x83,
+        0xaa, 0xf7, 0x30, 0xec, 0xb1, 0x42, 0xab, 0xa2, 0xfd, 0xfb, 0x69, 0x9d, 0xb6, 0x36, 0x42, 0x0f,
+        0x9a, 0x66, 0xd2, 0x14, 0xea, 0xee, 0x01, 0x2d, 0x28, 0x9f, 0x08, 0x74, 0xfa, 0x74, 0x03, 0xfc,
+        0x07, 0x0b, 0xbd, 0x1f, 0xf2, 0x30, 0x88, 0x2a, 0xd8, 0x70, 0x43, 0xcd, 0x75, 0x3e, 0xf3, 0x13,
+        0x83, 0x15, 0xeb, 0xd3, 0x26, 0x03, 0xe9, 0x18, 0x23, 0x8f, 0x87, 0xef, 0x9b, 0x1c, 0x21, 0x9a,
+        0xc5, 0xf8, 0x38, 0x09, 0x69, 0x7c, 0x9f, 0x5f, 0xc4, 0xf5, 0xfd, 0x8e, 0x78, 0xe9, 0xeb, 0x0c,
+        0x45, 0xca, 0xc6, 0x95, 0x3b, 0x41, 0xb8, 0xfe, 0x92, 0xae, 0x27, 0x31, 0x6d, 0xff, 0x4e, 0x9f,
+        0x2d, 0x71, 0x82, 0xd9, 0x81, 0xe6, 0xfe, 0xdd, 0x65, 0xa4, 0x3f, 0x79, 0x9c, 0xf1, 0xec, 0x2d,
+        0x55, 0x6d, 0xb6, 0x4e, 0x9c, 0xf4, 0x23, 0x86, 0x98, 0xb1, 0x40, 0x3f, 0xbe, 0x25, 0x7d, 0x04,
+        0xde, 0x77, 0xd3, 0x90, 0x15, 0xa9, 0x8d, 0x70, 0x17, 0x3f, 0x47, 0x11, 0x19, 0xa4, 0x0d, 0x28,
+        0x08, 0x41, 0x1c, 0xe7, 0x5f, 0x94, 0xda, 0x61, 0x97, 0x75, 0xea, 0x71, 0xc5, 0x3e, 0xa1, 0xa7,
+        0x9c, 0xe7, 0x41, 0x1b, 0x82, 0x17, 0x60, 0xb7, 0x6b, 0x97, 0x04, 0xd2, 0x70, 0x64, 0x91, 0x1c,
+        0x51, 0xd2, 0x52, 0x0e, 0xa5, 0x4c, 0xb1, 0xa7, 0x17, 0xaf, 0x97, 0x87, 0x9e, 0xdb, 0xd9, 0x6d,
+        0xc7, 0x09, 0x16, 0x72, 0x04, 0x42, 0x6b, 0xfa, 0x46, 0x51, 0x97, 0xbb, 0x83, 0x85, 0x2f, 0x1a,
+        0x06, 0xd2, 0x8f, 0xe7, 0xd5, 0x84, 0xe0, 0xab, 0x3d, 0x1b, 0x6f, 0x38, 0xf4, 0xe7, 0x3f, 0x41,
+        0x95, 0xf5, 0xe6, 0xed, 0xdc, 0x54, 0x79, 0x4e, 0x1f, 0xe6, 0x49, 0x90, 0x96, 0x73, 0x06, 0x0f,
+        0xad, 0xae, 0xf2, 0x45, 0x92, 0x10, 0x78, 0x45, 0x4d, 0xf1, 0x8b, 0x7a, 0x48, 0x56, 0xa9, 0x53,
+        0x1f, 0x21, 0x1c, 0x69, 0x00, 0x9f, 0x8d, 0x16, 0x22, 0x5f, 0xb6, 0x19, 0x2b, 0xf2, 0xff, 0x03,
+        0x9e, 0x06, 0x25, 0xfa, 0x4e, 0xd0, 0xe7, 0xde, 0x40, 0x92, 0xba, 0xa3, 0x63, 0xaa, 0x04, 0x32,
+        0xc5, 0x33, 0xfe, 0xb2, 0x8b, 0x34, 0x72, 0x25, 0x5b, 0x79, 0x94, 0xc4, 0x47, 0x66, 0xc0, 0x1c,
+        0x5d, 0x98, 0x65, 0xdd, 0xbd, 0xe5, 0x0b, 0x59, 0x03, 0xcf, 0x44, 0xed, 0x66, 0x7a, 0x4f, 0xf9,
+        0x3b, 0xc0, 0x63, 0x45, 0xaa, 0x44, 0x23, 0xe4, 0x4b, 0xf8, 0xa8, 0x7d, 0x81, 0x2e, 0xc7, 0x26,
+        0x6d, 0x8c, 0x75, 0xa2, 0x62, 0xa8, 0x0d, 0xb1, 0xda, 0x82, 0x7c, 0xa4, 0xa8, 0xf0, 0xb4, 0xf4,
+        0x0c, 0xb4, 0x4e, 0x9c, 0x3b, 0xf9, 0xb9, 0x8b, 0x59, 0xd5, 0x44, 0x6b, 0xe4, 0x40, 0xd5, 0x0e,
+        0xb0, 0x9a, 0x3a, 0x08, 0x9e, 0x51, 0xab, 0xa6, 0x9f, 0xf7, 0xbd, 0x2e, 0x8f, 0x56, 0xe1, 0x0f,
+        0x40, 0x67, 0x65, 0x27, 0xb6, 0x61, 0x91, 0x53, 0x2e, 0x13, 0xae, 0x33, 0x36, 0x1d, 0xd2, 0x01,
+        0xda, 0xad, 0x97, 0x31, 0x87, 0x79, 0x06, 0x4b, 0xb9, 0x91, 0x64, 0x9d, 0x22, 0x98, 0x51, 0x6b,
+        0x12, 0xe5, 0x17, 0x38, 0x51, 0x02, 0xbc, 0xeb, 0x7b, 0x10, 0xfa, 0x99, 0x6d, 0x5e, 0x7c, 0x26,
+        0xe1, 0x12, 0x03, 0x42, 0x28, 0x9f, 0xcd, 0x83, 0x73, 0x1f, 0x28, 0x64, 0x33, 0x07, 0x50, 0x32,
+        0xe4, 0xc2, 0x47, 0x07, 0x39, 0x75, 0x89, 0xf1, 0xe6, 0x43, 0x74, 0x81, 0xf5, 0x6d, 0x12, 0x08,
+        0xcd, 0xf0, 0x1c, 0xdc, 0x90, 0x4a, 0x50, 0xb8, 0xcd, 0xdd, 0xc8, 0xc4, 0xc1, 0xc3, 0x34, 0x08,
+        0x73, 0x53, 0x07, 0xdc, 0xd3, 0x0c, 0xf4, 0x27, 0xe4, 0x07, 0x5d, 0x00, 0x6c, 0x9a, 0x05, 0x0f,
+        0x87, 0xe4, 0x68, 0xed, 0x41, 0x91, 0x09, 0x09, 0x1f, 0xd6, 0xcb, 0xf7, 0x83, 0xf8, 0xf0, 0x9b,
+        0x19, 0xba, 0x83, 0x71, 0xf7, 0x49, 0xd0, 0x60, 0x73, 0x7a, 0xbf, 0x75, 0x6a, 0xf4, 0x32, 0x08,
+        0x85, 0xbe, 0x46, 0x56, 0x18, 0xef, 0x0f, 0x92, 0x91, 0x3a, 0x3c, 0x21, 0xdd, 0xf8, 0x7e, 0x65,
+        0x0a, 0x2a, 0xa6, 0x9e, 0x72, 0x79, 0x56, 0xd1, 0x64, 0xce, 0x41, 0x06, 0x0a, 0x86, 0xd0, 0x01,
+        0x7c, 0x77, 0x78, 0x44, 0xb1, 0x7a, 0x95, 0xda, 0x85, 0xe6, 0xc0, 0xd4, 0x6c, 0xf3, 0x22, 0x66,
+        0x74, 0xd1, 0xc4, 0x7b, 0x63, 0xe5, 0x19, 0xc0, 0x9c, 0x47, 0x31, 0xaa, 0xeb, 0x03, 0xe2, 0x20,
+        0x58, 0x38, 0x4d, 0x09, 0x35, 0x7d, 0xef, 0x96, 0xa2, 0x15, 0xec, 0x1b, 0x8b, 0x31, 0x21, 0xcf,
+        0xf1, 0xe6, 0x7e, 0x71, 0x91, 0xd1, 0x38, 0x4b, 0x89, 0xcf, 0xdc, 0x94, 0x21, 0x81, 0x45, 0x15,
+        0x23, 0xad, 0x08, 0xaa, 0x48, 0xcf, 0xe3, 0x77, 0xbf, 0xfe, 0x08, 0xb5, 0xc8, 0x1e, 0x10, 0x89,
+        0x0e, 0x5e, 0x9d, 0x89, 0xec, 0x15, 0x48, 0x0f, 0xdc, 0x5f, 0x2f, 0xe7, 0x82, 0x96, 0x46, 0x16,
+        0xb3, 0x18, 0xf4, 0x8e, 0x1a, 0x38, 0xb5, 0x5a, 0x85, 0x6f, 0xdd, 0xa7, 0x0e, 0x7b, 0xd5, 0x80,
+        0xbf, 0x76, 0x4c, 0xc8, 0xab, 0x91, 0x40, 0xc2, 0x51, 0x3c, 0x22, 0x10, 0x79, 0x0e, 0x71, 0x02,
+        0x9a, 0xa6, 0xac, 0x94, 0x6d, 0x47, 0x1d, 0x26, 0x09, 0xdb, 0x37, 0x99, 0xd6, 0x30, 0x73, 0x80,
+        0xf8, 0x40, 0xfa, 0x26, 0xda, 0xca, 0x3f, 0x2d, 0xac, 0xf5, 0x30, 0x30, 0xe7, 0x91, 0x05, 0x03,
+        0x1f, 0xed, 0x89, 0x90, 0x09, 0x19, 0x5e, 0xd2, 0x6f, 0x2a, 0xce, 0x49, 0xbe, 0x15, 0x32, 0xaf,
+        0x05, 0x5e, 0x4c, 0xfc, 0x2d, 0x8c, 0x63, 0x47, 0x69, 0x9e, 0x16, 0x74, 0xe3, 0x74, 0x3e, 0x19,
+        0x31, 0x18, 0xb6, 0x29, 0x36, 0xed, 0x5a, 0x1c, 0xfc, 0xe4, 0x6c, 0xe8, 0x5a, 0xb0, 0xca, 0xeb,
+        0xa2, 0x43, 0x5f, 0x46, 0xf8, 0x3a, 0x1a, 0xc1, 0x2f, 0xdb, 0x85, 0x28, 0x4a, 0xf2, 0xc4, 0x20,
+        0x7a, 0x01, 0x2d, 0x31, 0x59, 0x02, 0xad, 0xb0, 0xff, 0x7f, 0xb2, 0xe9, 0x5b, 0xf9, 0x88, 0x2c,
+        0xf5, 0x1e, 0x8d, 0xb2, 0xcc, 0xcb, 0x0d, 0x76, 0xa6, 0x79, 0x9e, 0xee, 0xe6, 0xa4, 0xad, 0x0f,
+        0xfa, 0x80, 0x3e, 0x2c, 0xe2, 0xb8, 0x54, 0x51, 0x72, 0xa6, 0xb2, 0x86, 0x91, 0xbf, 0x0b, 0x8c,
+        0xac, 0x51, 0x11, 0xa4, 0xa0, 0x63, 0x61, 0x17, 0xb2, 0xd0, 0x23, 0x1b, 0x69, 0x67, 0x9c, 0x14,
+        0x4b, 0xa1, 0x6d, 0x7c, 0x7d, 0x6a, 0x5f, 0x1a, 0x45, 0x8d, 0x82, 0xd9, 0x54, 0x80, 0xe0, 0xac,
+        0x5e, 0x2e, 0xd8, 0x8f, 0x1c, 0xee, 0x84, 0x0d, 0x9d, 0x31, 0x91, 0xd6, 0xd1, 0x53, 0x2a, 0x08,
+        0xf1, 0xc6, 0xf1, 0x87, 0xf1, 0x9e, 0x47, 0x0a, 0x52, 0xda, 0xe3, 0x81, 0xba, 0xf9, 0x49, 0x5d,
+        0xd9, 0x8c, 0x0b, 0x7b, 0x86, 0x02, 0xaf, 0xd9, 0x44, 0x12, 0x04, 0x79, 0x03, 0xe5, 0xe6, 0x2c,
+        0x6a, 0x76, 0x91, 0xfa, 0x17, 0xe1, 0x4c, 0xc7, 0x26, 0x4d, 0x47, 0xae, 0x6e, 0x3c, 0xd2, 0x6b,
+        0x85, 0x52, 0x21, 0x60, 0x03, 0xf4, 0x10, 0x20, 0x9e, 0x53, 0xd4, 0x4c, 0xc1, 0x67, 0x75, 0x17,
+        0x6d, 0x44, 0x68, 0xfb, 0x73, 0x9e, 0xe1, 0x39, 0xe0, 0xd9, 0xc7, 0xf7, 0x7d, 0x79, 0x3d, 0x6c,
+        0x50, 0x89, 0xdf, 0x9b, 0xea, 0x12, 0xf0, 0xd0, 0x91, 0x41, 0x3a, 0x22, 0x92, 0xbb, 0xc3, 0x2c,
+        0xe3, 0x6b, 0x13, 0x4c, 0x3a, 0x24, 0x52, 0x41, 0x5c, 0x34, 0x50, 0x22, 0x6d, 0x80, 0x87, 0xe3,
+        0x8c, 0x8d, 0x14, 0xa1, 0x8c, 0xf7, 0xc3, 0x3e, 0xd8, 0x1a, 0xc4, 0xa8, 0xfc, 0xc3, 0x94, 0x04,
+        0x1a, 0xe9, 0x75, 0x6b, 0x75, 0x67, 0x71, 0x8b, 0x1e, 0x6a, 0x7a, 0xab, 0xef, 0x76, 0x30, 0x8c,
+        0x5b, 0x5f, 0x1f, 0x8a, 0x9f, 0xcc, 0x29, 0x27, 0x01, 0x5b, 0xc4, 0x56, 0x2c, 0xba, 0x70, 0x12,
+        0xde, 0xe2, 0x32, 0xb6, 0x1d, 0xb9, 0x1f, 0xfc, 0x65, 0xc5, 0x4d, 0x0f, 0x54, 0xeb, 0x43, 0x9e,
+        0x97, 0xb6, 0x57, 0x57, 0x61, 0x93, 0x40, 0x5d, 0x51, 0x1b, 0xa3, 0xd1, 0xbb, 0x1a, 0xbf, 0x0e,
+        0x97, 0xcc, 0xda, 0xd6, 0x13, 0xb4, 0x42, 0xbb, 0x8c, 0xd8, 0x53, 0x38, 0xed, 0x2d, 0x73, 0xb6,
+        0x09, 0x57, 0xb2, 0x94, 0xb3, 0x80, 0xf4, 0xc8, 0xc1, 0x1c, 0x88, 0xc5, 0x9d, 0xdd, 0x05, 0x1b,
+        0xd5, 0x4a, 0xc3, 0x00, 0x14, 0x0e, 0x98, 0x18, 0x1a, 0x29, 0x61, 0x5b, 0x00, 0xc3, 0x01, 0x75,
+        0x53, 0x1d, 0xaf, 0x47, 0x83, 0x43, 0x08, 0xd0, 0xa6, 0xe0, 0x42, 0x98, 0xc4, 0xed, 0xe1, 0x29,
+        0x40, 0x36, 0x20, 0x08, 0x96, 0xa6, 0x34, 0x25, 0x0f, 0x6a, 0x3c, 0x8b, 0x7a, 0x76, 0x9e, 0x67,
+        0x01, 0xf3, 0x08, 0x3e, 0x16, 0x77, 0x73, 0x41, 0x0c, 0x55, 0x32, 0x7b, 0x9a, 0xb5, 0x03, 0x17,
+        0xeb, 0xca, 0xfb, 0x56, 0x3d, 0xfc, 0xa0, 0x92, 0x3f, 0x89, 0xf0, 0x56, 0x1f, 0x0e, 0x13, 0xc2,
+        0x95, 0x8d, 0xa8, 0x71, 0x35, 0x2e, 0x7c, 0x70, 0x13, 0xfa, 0xc4, 0xd0, 0x9c, 0x90, 0xe4, 0x0d,
+        0x2f, 0xf4, 0xf5, 0x28, 0xaa, 0x3d, 0xbb, 0x46, 0xf5, 0x42, 0x75, 0xd1, 0xb1, 0x98, 0x97, 0x07,
+        0x94, 0xbb, 0x14, 0xe2, 0xa1, 0x3d, 0x2d, 0x52, 0x3d, 0xc9, 0xb9, 0x6f, 0x34, 0x31, 0xec, 0x20,
+        0x6d, 0xe9, 0x40, 0x23, 0x99, 0xba, 0x0f, 0xfe, 0x70, 0x5c, 0x9e, 0x92, 0xdf, 0x31, 0x01, 0x0c,
+        0x08, 0x04, 0x89, 0x8b, 0xd8, 0x19, 0x51, 0x3b, 0xef, 0x37, 0xd6, 0x53, 0xa2, 0x44, 0xd7, 0x0b,
+        0xfa, 0x6d, 0xf5, 0x02, 0xe8, 0x84, 0x81, 0xd0, 0xc6, 0x3c, 0x54, 0xea, 0xf3, 0x70, 0x01, 0x47,
+        0xb2, 0xf5, 0x6d, 0x39, 0xe3, 0x51, 0x6e, 0xf5, 0x94, 0x16, 0x43, 0xf2, 0x5c, 0x41, 0xc9, 0x15,
+        0x47, 0x26, 0xf6, 0xda, 0x86, 0x8a, 0xdb, 0x2c, 0x08, 0xac, 0xc3, 0xc7, 0x64, 0xa2, 0x3f, 0xec,
+        0x65, 0xfe, 0xf2, 0xae, 0x41, 0xae, 0x7f, 0xe2, 0xbc, 0x96, 0xff, 0x77, 0x29, 0x03, 0xf1, 0x06,
+        0x00, 0x5b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xec, 0x6a, 0xed, 0x8b, 0x54, 0x74, 0x16,
+        0xaa, 0xde, 0x27, 0x16, 0xd2, 0x03, 0x13, 0xda, 0x13, 0x5a, 0x2b, 0xaf, 0x7e, 0x23, 0x7b, 0x8d,
+        0x0e, 0x3d, 0x2f, 0xe5, 0xb1, 0x73, 0xd3, 0xba, 0x1e, 0x4d, 0xed, 0x59, 0x21, 0x00, 0x48, 0x68,
+        0x18, 0xf8, 0xd3, 0xce, 0x78, 0xd0, 0x13, 0x88, 0xde, 0xf2, 0x31, 0x1b, 0xbd, 0x93, 0x1b, 0x7f,
+        0x65, 0xa3, 0xe2, 0x47, 0x40, 0x98, 0x01, 0x51, 0x0e, 0x8d, 0x0c, 0x4f, 0xf8, 0x45, 0x8a, 0x2d,
+        0x06, 0xa7, 0x01, 0x91, 0xf5, 0x04, 0xb1, 0x21, 0xbe, 0x5f, 0xd7, 0x6f, 0xdc, 0xe8, 0x5b, 0xca,
+        0x6e, 0x31, 0x49, 0x21, 0xe8, 0x59, 0x97, 0x7f, 0x2b, 0x77, 0x46, 0xa0, 0x37, 0x38, 0x78, 0x9f,
+        0xc8, 0x95, 0xe1, 0xc1, 0x85, 0x32, 0x5d, 0xed, 0xbd, 0xee, 0xa6, 0xed, 0x56, 0x1f, 0xc7, 0x38,
+        0xd7, 0x61, 0x06, 0x65, 0x9d, 0x09, 0x20, 0x56, 0x25, 0xc4, 0x6a, 0x31, 0x8f, 0xf5, 0x4b, 0xcc,
+        0x18, 0x99, 0x82, 0xcb, 0xa6, 0x33, 0x7f, 0x09, 0x12, 0x14, 0xda, 0x3b, 0xae, 0x11, 0x4f, 0x4c,
+        0x3e, 0x60, 0xd5, 0x01, 0xd8, 0xe1, 0xa5, 0x67, 0x01, 0x9d, 0x07, 0x25, 0x32, 0x31, 0x5c, 0x3c,
+        0x94, 0x40, 0x63, 0xa0, 0x9e, 0x52, 0x19, 0x5d, 0xd3, 0xea, 0x68, 0xc6, 0x33, 0xe5, 0x3b, 0xbd,
+        0x42, 0xfe, 0x2e, 0x9b, 0xf7, 0x2e, 0xce, 0x6f, 0x20, 0xa5, 0xe9, 0x80, 0x15, 0x0a, 0xea, 0x99,
+        0x0a, 0x5d, 0xc8, 0xc6, 0xa6, 0x4c, 0xf1, 0xfd, 0xb2, 0xd3, 0xf7, 0x0a, 0x1c, 0xe8, 0x07, 0xdd,
+        0x8f, 0xa1, 0x46, 0xe9, 0x32, 0x09, 0xf5, 0x77, 0x1a, 0x17, 0xb5, 0x61, 0x54, 0xfb, 0x9c, 0x8d,
+        0xd6, 0xde, 0x45, 0xde, 0x5d, 0xb5, 0x08, 0x45, 0x5f, 0x42, 0x8b, 0x88, 0x25, 0x21, 0x8b, 0xe7,
+        0xba, 0x85, 0xfa, 0x9b, 0xcc, 0x4f, 0x31, 0xf6, 0x17, 0x65, 0x26, 0x50, 0x25, 0xe1, 0xc7, 0x83,
+        0x7d, 0x27, 0xd8, 0x3d, 0x52, 0x68, 0x9e, 0x82, 0xb3, 0x1e, 0x70, 0xa6, 0x40, 0xc8, 0xba, 0xa1,
+        0x6d, 0x03, 0xec, 0x65, 0xd5, 0x4f, 0xca, 0x50, 0x16, 0x2f, 0x8b, 0xfe, 0x47, 0x45, 0x64, 0xc5,
+        0xc7, 0x55, 0x2e, 0xbc, 0xf8, 0x06, 0xcf, 0xf4, 0x7a, 0x75, 0xd6, 0x5d, 0x9f, 0x4f, 0xfa, 0x7f,
+        0x26, 0x02, 0xfa, 0x77, 0x65, 0x82, 0xde, 0xab, 0x19, 0x5e, 0xe6, 0xf7, 0x94, 0xc2, 0x15, 0x11,
+        0x32, 0xdc, 0xcd, 0xe9, 0x8a, 0xf6, 0x9b, 0xe8, 0xd2, 0xcd, 0x21, 0x87, 0xba, 0xb9, 0x85, 0x0e,
+        0x6d, 0x87, 0xbb, 0xf7, 0x7c, 0xbd, 0xbc, 0xfb, 0x1c, 0xdc, 0xc6, 0xab, 0x88, 0xd4, 0xf4, 0x12,
+        0xc9, 0x74, 0x47, 0x32, 0xea, 0xcf, 0x16, 0x31, 0x4d, 0xce, 0x6d, 0xb7, 0x71, 0x90, 0xde, 0xae,
+        0x1b, 0xc2, 0x8d, 0x72, 0xc3, 0xb7, 0xa1, 0xbc, 0x2e, 0xee, 0x22, 0x22, 0x8f, 0x3f, 0x33, 0x69,
+        0xdc, 0x1a, 0x62, 0x19, 0x9b, 0xef, 0x15, 0x75, 0x51, 0x90, 0x61, 0x5a, 0x0a, 0x2f, 0xa9, 0x7e,
+        0xc5, 0x50, 0xfd, 0xd0, 0x7c, 0x48, 0x20, 0x7c, 0x2e, 0xab, 0xf5, 0x82, 0x2f, 0x84, 0xab, 0x40,
+        0x7c, 0x49, 0x95, 0x0d, 0x83, 0x66, 0x53, 0x09, 0xe9, 0x65, 0xdd, 0xc9, 0x92, 0xb4, 0x46, 0x13,
+        0xaa, 0xa1, 0xa6, 0xb9, 0x5c, 0xe7, 0x40, 0xbb, 0x2c, 0x28, 0x4c, 0x1c, 0xc6, 0xef, 0x91, 0x44,
+        0x49, 0x3f, 0x41, 0xc5, 0x79, 0x28, 0xd9, 0x1a, 0x59, 0x10, 0xd9, 0xa2, 0x20, 0xd1, 0xde, 0xb0,
+        0x3c, 0xcd, 0x98, 0xfa, 0x81, 0x4e, 0x4f, 0xc8, 0x16, 0x62, 0xc5, 0x04, 0x95, 0x8a, 0xbc, 0x06,
+        0xad, 0x0a, 0xa3, 0xe7, 0x98, 0xd7, 0x6f, 0xe2, 0x8b, 0x57, 0xcf, 0x97, 0x7b, 0x1a, 0xc7, 0xa9,
+        0xf2, 0xac, 0xfa, 0x8c, 0xd7, 0xaa, 0x7b, 0x15, 0x0c, 0xab, 0x60, 0x99, 0x1a, 0xa9, 0x25, 0x9c,
+        0x6b, 0x7b, 0x22, 0x31, 0xac, 0xd0, 0x4f, 0x7f, 0x88, 0x2b, 0x5e, 0x6b, 0x56, 0xa2, 0xb0, 0xd7,
+        0xb2, 0x3a, 0xb2, 0xc1, 0x36, 0xa5, 0x1d, 0x2c, 0x30, 0x06, 0x3e, 0x74, 0x36, 0x3f, 0xcd, 0x25,
+        0xd0, 0xb7, 0x7d, 0x72, 0xe0, 0x42, 0xf1, 0x39, 0x8b, 0x85, 0xc6, 0xa9, 0x96, 0xb1, 0xc7, 0x43,
+        0x0e, 0x1a, 0x7e, 0x1e, 0x23, 0xc2, 0x15, 0xe3, 0x2b, 0x67, 0xeb, 0x37, 0x21, 0xfa, 0xa8, 0x45,
+        0x71, 0xb3, 0x04, 0xec, 0xa3, 0xac, 0x75, 0xe7, 0x04, 0x1d, 0xf1, 0x3c, 0x7a, 0x4f, 0x41, 0x37,
+        0xbe, 0x84, 0x6b, 0xae, 0x0e, 0x6b, 0xb8, 0x62, 0x13, 0x11, 0x05, 0x0a, 0x6a, 0xe0, 0xbf, 0xc3,
+        0xa2, 0x59, 0x19, 0x58, 0x28, 0x0f, 0xc2, 0xef, 0x8c, 0x21, 0x69, 0x1d, 0xf1, 0x83, 0xdb, 0x76,
+        0xca, 0xdc, 0x8a, 0xc4, 0x32, 0x1d, 0x20, 0x62, 0x21, 0x6a, 0x5e, 0x13, 0x5e, 0x78, 0xea, 0xe0,
+        0x24, 0xc6, 0x7a, 0x95, 0xf3, 0x56, 0x80, 0xa0, 0xbe, 0x12, 0xb6, 0x4f, 0x04, 0xb3, 0x72, 0xc7,
+        0xa1, 0xa4, 0x6c, 0x09, 0xd4, 0x41, 0x1a, 0x93, 0x20, 0xab, 0x21, 0xf2, 0x3a, 0x55, 0x43, 0x4d,
+        0xad, 0xe3, 0xe1, 0x3b, 0x75, 0x85, 0x9a, 0x2f, 0x8d, 0x53, 0xd3, 0x7b, 0x0d, 0x54, 0x90, 0xe8,
+        0x18, 0x9f, 0x3c, 0xed, 0xbe, 0xee, 0xe0, 0xcf, 0x19, 0xb2, 0xfc, 0x73, 0x8f, 0xa3, 0xdc, 0x6c,
+        0x3e, 0x33, 0xaf, 0x1c, 0x55, 0xce, 0x1e, 0x6b, 0x6b, 0x5e, 0xfe, 0xb4, 0xc0, 0xf1, 0x30, 0x77,
+        0x61, 0x31, 0x86, 0xf3, 0x9a, 0xbd, 0x17, 0x99, 0x1a, 0x0c, 0xd1, 0x37, 0x54, 0xd3, 0x28, 0x37,
+        0xeb, 0x32, 0xff, 0x35, 0x3a, 0x6a, 0xcf, 0xd7, 0x08, 0xf2, 0x13, 0x0d, 0x4f, 0xa9, 0x94, 0xa5,
+        0x34, 0x2a, 0x2f, 0xf2, 0xa5, 0xb9, 0xa5, 0x2f, 0x2c, 0xc0, 0x41, 0x6f, 0xd6, 0x99, 0x80, 0x21,
+        0x54, 0xbf, 0x44, 0x74, 0xe0, 0xc4, 0x66, 0x56, 0x73, 0x9a, 0xf5, 0x6d, 0xb9, 0xb3, 0x9d, 0x89,
+        0x1d, 0x73, 0xda, 0x0e, 0xcc, 0x64, 0x36, 0xe0, 0x07, 0x4b, 0x11, 0xea, 0xa2, 0xd5, 0xbb, 0x89,
+        0x18, 0xb2, 0xc1, 0xad, 0xb3, 0x33, 0x76, 0xa0, 0xd9, 0xd3, 0xb0, 0xcd, 0x10, 0x4a, 0xc3, 0x5b,
+        0x8a, 0x8b, 0x85, 0x29, 0x0c, 0xcc, 0xbd, 0xef, 0x0e, 0x2e, 0x0a, 0xa4, 0x34, 0x7d, 0x24, 0x4f,
+        0x99, 0xe5, 0xf9, 0x3a, 0x83, 0xbd, 0xb8, 0x6d, 0x6d, 0xd0, 0x43, 0xab, 0x6a, 0xef, 0x43, 0x54,
+        0x44, 0xb1, 0x82, 0xd7, 0x6a, 0x6c, 0x99, 0xa8, 0x2b, 0x0b, 0x87, 0x38, 0x5d, 0x36, 0x4a, 0x7a,
+        0x59, 0x75, 0xd9, 0x1b, 0x7a, 0x01, 0xef, 0x3a, 0x89, 0xa7, 0xc7, 0xc8, 0xa4, 0x74, 0x34, 0xd5,
+        0x25, 0x53, 0xed, 0x63, 0x12, 0xdc, 0xab, 0xfa, 0x0a, 0x88, 0x87, 0x46, 0x0b, 0xc1, 0xcd, 0x38,
+        0x76, 0xed, 0x58, 0xa2, 0xfc, 0x0a, 0xac, 0xc9, 0xd6, 0x25, 0x41, 0xe2, 0xe6, 0x8b, 0xe4, 0xd5,
+        0x69, 0xe6, 0x67, 0xea, 0x92, 0x1f, 0x2c, 0x7f, 0x19, 0xd8, 0xd0, 0xa0, 0xcf, 0x81, 0x11, 0x45,
+        0x66, 0xd4, 0x73, 0x01, 0x52, 0x48, 0xda, 0xb9, 0x2e, 0x48, 0xea, 0xa9, 0x25, 0x65, 0xa0, 0x8d,
+        0xc9, 0x94, 0x7e, 0x55, 0x7c, 0x7d, 0x72, 0xad, 0x27, 0x81, 0xfd, 0x70, 0x2b, 0xca, 0x27, 0xed,
+        0xc2, 0xa9, 0x27, 0x29, 0xdd, 0x59, 0xb6, 0x34, 0x82, 0xd4, 0xe7, 0xe7, 0xd8, 0x97, 0xcf, 0xd9,
+        0x00, 0x9d, 0xfd, 0xc1, 0xe5, 0x56, 0xe3, 0x30, 0x18, 0xdf, 0xa0, 0x89, 0xe8, 0xf9, 0x79, 0x94,
+        0xac, 0x7d, 0x32, 0x66, 0x9b, 0x9f, 0x37, 0x2b, 0xed, 0xdb, 0xed, 0x44, 0xa2, 0x3f, 0x96, 0x7c,
+        0xdf, 0x95, 0x18, 0xc2, 0x41, 0x64, 0xc1, 0xbd, 0x07, 0x9d, 0x0d, 0xb8, 0x09, 0xbb, 0x94, 0x92,
+        0x06, 0x5f, 0x31, 0x11, 0xf1, 0xaf, 0x5f, 0xfa, 0xee, 0x49, 0x90, 0x17, 0x03, 0xd8, 0xda, 0x46,
+        0x68, 0xfa, 0x2f, 0x0e, 0x9c, 0x2f, 0x3e, 0x60, 0x1c, 0x01, 0xa9, 0x01, 0xf1, 0x83, 0x38, 0x5b,
+        0x6c, 0x4f, 0x6e, 0x25, 0x70, 0x7e, 0x2c, 0x92, 0x92, 0xc2, 0xdd, 0x71, 0x76, 0x51, 0xdf, 0x9a,
+        0xf1, 0xa1, 0xa4, 0x5f, 0x58, 0x45, 0xa9, 0xdf, 0x15, 0xa9, 0x23, 0xdd, 0x45, 0xb8, 0x0b, 0x37,
+        0x38, 0x00, 0xc2, 0xbc, 0xb3, 0xdd, 0xe4, 0xe1, 0x05, 0x4e, 0x91, 0x2c, 0x4a, 0xa4, 0xcb, 0x7f,
+        0x3a, 0xe2, 0x3d, 0x4e, 0x25, 0x77, 0x2c, 0xd1, 0x21, 0x5a, 0x47, 0x25, 0x8c, 0x57, 0xaf, 0x42,
+        0x6f, 0x5d, 0xbd, 0xd9, 0xd6, 0x29, 0xb1, 0x8c, 0x36, 0xb5, 0xbb, 0xff, 0xc8, 0xb9, 0x0f, 0xe0,
+        0x5d, 0x04, 0x55, 0x83, 0xc2, 0x77, 0xaa, 0x67, 0x23, 0x0f, 0xfc, 0xc9, 0x06, 0x8f, 0x5e, 0x71,
+        0x90, 0xa5, 0x8a, 0x1f, 0x69, 0x51, 0x7a, 0xd8, 0xfb, 0x9a, 0x24, 0x02, 0x05, 0xca, 0x85, 0xa8,
+        0x67, 0xc0, 0xc0, 0xa6, 0x63, 0xf9, 0xd9, 0x91, 0x17, 0x73, 0x91, 0x1b, 0x99, 0x53, 0xf9, 0x25,
+        0x6d, 0x34, 0xbf, 0x2a, 0xb6, 0x42, 0x50, 0x24, 0x08, 0x51, 0x44, 0x88, 0x3c, 0xe7, 0x48, 0x2d,
+        0x04, 0x94, 0x78, 0x65, 0x9c, 0x7c, 0xb3, 0x6e, 0x2d, 0x75, 0xf1, 0xbe, 0xcd, 0xa9, 0xb1, 0x04,
+        0x53, 0x8f, 0x07, 0x80, 0xcc, 0xe7, 0x38, 0x20, 0x19, 0x1c, 0x09, 0x6b, 0x27, 0x5f, 0x3d, 0x74,
+        0xdc, 0xc7, 0x72, 0xbd, 0x18, 0x06, 0xac, 0x31, 0x2d, 0x88, 0x35, 0xdc, 0x77, 0x18, 0x5b, 0x12,
+        0x26, 0xa2, 0x55, 0x61, 0x45, 0xed, 0xba, 0xec, 0x31, 0x02, 0xbe, 0x9f, 0x8d, 0xf9, 0xda, 0xab,
+        0x22, 0xf0, 0x42, 0xee, 0xe9, 0x62, 0x77, 0x37, 0x1d, 0xf3, 0x01, 0xf8, 0x98, 0xd9, 0xc0, 0x80,
+        0x86, 0xad, 0xcf, 0xc8, 0xe6, 0x4d, 0x8e, 0x0d, 0xef, 0x50, 0x37, 0x8e, 0x0d, 0x1b, 0xcf, 0x42,
+        0xc4, 0xae, 0xe2, 0xe3, 0x61, 0x08, 0x77, 0xc5, 0x15, 0x83, 0xd3, 0x57, 0x3c, 0xb1, 0x16, 0xb8,
+        0x26, 0x42, 0x60, 0x53, 0x1f, 0xa3, 0x13, 0x76, 0x17, 0xf1, 0xbb, 0x5e, 0x7b, 0x26, 0xd6, 0xf6,
+        0x13, 0x11, 0xf5, 0x23, 0x04, 0x5e, 0x4f, 0x7c, 0x0a, 0xc1, 0x30, 0xa2, 0x13, 0x27, 0x05, 0xcd,
+        0xf8, 0x51, 0xb2, 0x7a, 0x4a, 0x18, 0xb1, 0xea, 0x89, 0x03, 0xe8, 0x07, 0x36, 0xdb, 0xc0, 0x09,
+        0x69, 0x55, 0x0f, 0x62, 0xe4, 0x64, 0x37, 0xca, 0x06, 0xa4, 0x20, 0x47, 0xa6, 0xde, 0xd6, 0x73,
+        0x1e, 0x5a, 0xa1, 0xf0, 0xab, 0x39, 0x9a, 0xdc, 0x7a, 0x20, 0xfe, 0xb7, 0x9f, 0x72, 0x89, 0xfb,
+        0x32, 0x43, 0x2e, 0x69, 0xac, 0x0d, 0x2f, 0x51, 0x24, 0xf6, 0x65, 0x9c, 0xf2, 0x52, 0x8c, 0x2e,
+        0xd4, 0x9b, 0xe2, 0x0d, 0x06, 0x03, 0x08, 0x95, 0xb6, 0xec, 0x29, 0xb1, 0x6d, 0x8e, 0x2b, 0x9e,
+        0xc5, 0xb8, 0x58, 0xf0, 0xf4, 0xde, 0x1a, 0x0a, 0x17, 0x4c, 0xe3, 0x4b, 0xe5, 0x6b, 0x71, 0xc1,
+        0xbf, 0xf4, 0x57, 0x6f, 0x3a, 0x10, 0x53, 0x13, 0xab, 0x5e, 0x08, 0x7e, 0x0b, 0xba, 0x83, 0xba,
+        0x91, 0x79, 0x30, 0xa3, 0x08, 0x38, 0xe8, 0xfb, 0x00, 0xfd, 0x9b, 0x7b, 0x36, 0x6e, 0x58, 0x1e,
+        0xc7, 0x75, 0xc1, 0x27, 0xd9, 0x6c, 0xe5, 0x83, 0x75, 0x3f, 0xce, 0xb4, 0x4c, 0xfe, 0xec, 0x0a,
+        0x90, 0xaa, 0x6c, 0x88, 0x23, 0x2a, 0x3e, 0xb6, 0x2b, 0x31, 0x48, 0x0b, 0xa1, 0xad, 0x42, 0x19,
+        0x4c, 0x65, 0x57, 0x95, 0xb7, 0x5d, 0xaf, 0x25, 0x31, 0x2b, 0x28, 0xf9, 0x46, 0x36, 0x7f, 0x2c,
+        0xcb, 0x3a, 0x50, 0xfc, 0x98, 0xfa, 0x14, 0xbc, 0x09, 0xeb, 0x0d, 0x14, 0xdf, 0xe1, 0x92, 0x32,
+        0x39, 0xf1, 0x65, 0xb7, 0xf0, 0x8f, 0x51, 0xb4, 0x18, 0x99, 0xa8, 0xf6, 0x83, 0x09, 0xe5, 0xd7,
+        0x75, 0x08, 0xe8, 0xf8, 0xa0, 0x11, 0x0a, 0x68, 0x14, 0x68, 0xc6, 0x2f, 0x53, 0x1e, 0x3b, 0xb9,
+        0x73, 0x05, 0xb7, 0x1b, 0x08, 0x90, 0x08, 0x3b, 0x49, 0xf1, 0x81, 0x92, 0x60, 0x04, 0x53, 0x7a,
+        0x9d, 0x4a, 0xed, 0x24, 0x33, 0x7b, 0x7a, 0xc0, 0x1d, 0x33, 0x17, 0x65, 0xab, 0xab, 0xd9, 0x82,
+        0xde, 0x7a, 0x06, 0x94, 0xb1, 0xe5, 0x8b, 0x73, 0x5b, 0x8e, 0x7f, 0xd3, 0x83, 0x23, 0x20, 0x4d,
+        0xff, 0xdf, 0xd6, 0x3e, 0xc3, 0x36, 0xb3, 0x25, 0x29, 0xb3, 0xfe, 0xbd, 0x87, 0x62, 0x76, 0x8b,
+        0xaa, 0x71, 0x24, 0xf5, 0x89, 0xf0, 0xe1, 0xba, 0x79, 0x2d, 0xc0, 0xf3, 0x0f, 0x79, 0xfb, 0x52,
+        0x33, 0x55, 0x49, 0x91, 0x2e, 0xb6, 0x52, 0x0f, 0x27, 0x06, 0x2a, 0x69, 0xa2, 0x90, 0x3d, 0xc6,
+        0x09, 0x18, 0x99, 0x75, 0x37, 0xf3, 0xd2, 0xa3, 0x48, 0xa5, 0x28, 0x11, 0xa7, 0xa8, 0x76, 0xf1,
+        0xfc, 0x5e, 0x6d, 0xb1, 0x9d, 0x5e, 0xbf, 0xe3, 0x2a, 0x28, 0x73, 0x24, 0x01, 0x30, 0xc5, 0xf9,
+        0x0a, 0xd4, 0x8c, 0xd6, 0x8a, 0xc1, 0x67, 0xba, 0x15, 0x4a, 0xf0, 0x14, 0x14, 0xc0, 0x17, 0xca,
+        0x81, 0x17, 0x27, 0x3d, 0x20, 0x1b, 0x9c, 0x43, 0x1f, 0x00, 0xd7, 0xcc, 0x3a, 0x0b, 0x54, 0x50,
+        0xa7, 0xe5, 0xb5, 0x5a, 0x66, 0x4d, 0x06, 0xbf, 0x1c, 0xf2, 0x6f, 0xd0, 0xaf, 0xda, 0x4f, 0xf7,
+        0xbe, 0x77, 0x63, 0x70, 0xb1, 0x51, 0xb5, 0x76, 0x04, 0x1e, 0x5b, 0x95, 0xd1, 0x88, 0x08, 0x68,
+        0xc7, 0x8d, 0x9a, 0x81, 0xc4, 0xb0, 0x1a, 0x55, 0x30, 0x1b, 0x9b, 0x0f, 0xa7, 0xda, 0xec, 0x05,
+        0x9a, 0xef, 0x96, 0x4a, 0x6d, 0x97, 0xa5, 0x1b, 0x01, 0x4c, 0x0e, 0x8f, 0x54, 0x69, 0xf2, 0xf5,
+        0xeb, 0x97, 0x8b, 0xda, 0x46, 0x61, 0x22, 0x6c, 0x51, 0x38, 0x7d, 0x58, 0x76, 0x39, 0x9f, 0xe4,
+        0xcb, 0x79, 0x8c, 0x31, 0xe8, 0x00, 0x27, 0x6e, 0x1a, 0xc8, 0xe1, 0x39, 0xba, 0xbe, 0x7d, 0x7e,
+        0xff, 0x1f, 0x00, 0xb8, 0x8a, 0xf6, 0xa2, 0xb4, 0x11, 0x36, 0x72, 0x23, 0x34, 0x03, 0x72, 0xe2,
+        0x49, 0x0a, 0x8f, 0x1b, 0x02, 0x51, 0x31, 0x03, 0x2f, 0x20, 0xee, 0x95, 0xb8, 0xfd, 0x3c, 0x55,
+        0x54, 0x7c, 0x49, 0xb6, 0x58, 0x90, 0xd1, 0x21, 0x68, 0x68, 0x3e, 0x46, 0x0d, 0x89, 0x7f, 0x66,
+        0xce, 0x08, 0xa8, 0x73, 0x7d, 0x87, 0x09, 0x8e, 0x24, 0x3b, 0x1c, 0x56, 0x5b, 0xd6, 0x01, 0x09,
+        0x0a, 0x96, 0x59, 0x63, 0x66, 0x63, 0xd9, 0x16, 0xef, 0x4c, 0x3b, 0x24, 0x72, 0x78, 0x5c, 0x27,
+        0x3b, 0x30, 0x8d, 0xa4, 0x44, 0xc0, 0x3b, 0xfa, 0x1d, 0xb7, 0x2c, 0x48, 0xe8, 0x25, 0xcf, 0x44,
+        0xb9, 0x89, 0x26, 0xec, 0x48, 0xe0, 0x2d, 0x33, 0x32, 0xc9, 0x5e, 0xf4, 0x9f, 0x30, 0xf9, 0x03,
+        0x16, 0x9a, 0x82, 0x82, 0xee, 0x95, 0xf5, 0xaf, 0x11, 0xe8, 0xf5, 0xdb, 0x7f, 0x17, 0x1c, 0x66,
+        0xeb, 0xe8, 0x6b, 0xa6, 0x0c, 0x2e, 0xf0, 0x6c, 0x73, 0x66, 0x17, 0x06, 0x3d, 0x21, 0xb0, 0x42,
+        0xa6, 0x42, 0x5e, 0x95, 0xf3, 0x40, 0xdf, 0xfb, 0x0b, 0xd5, 0x8d, 0x47, 0xc0, 0xd9, 0x66, 0xa1,
+        0xe2, 0x63, 0xf5, 0xd8, 0x71, 0x16, 0x64, 0x81, 0xbb, 0xbb, 0x13, 0x5f, 0x85, 0x4c, 0x47, 0x2b,
+        0x37, 0x84, 0x6c, 0x8c, 0x60, 0x56, 0x5f, 0x02, 0x20, 0xd7, 0xd6, 0xe0, 0x30, 0x8a, 0x5e, 0xab,
+        0xea, 0x6b, 0xae, 0xf1, 0x7a, 0x37, 0x5f, 0xd9, 0xa7, 0x12, 0x24, 0xf6, 0x49, 0xfe, 0xac, 0xce,
+        0xe7, 0xe3, 0xd6, 0xaf, 0xc2, 0xb9, 0xa0, 0x05, 0x25, 0x94, 0xc0, 0x4a, 0x84, 0x49, 0x81, 0x70,
+        0x8f, 0xd3, 0xad, 0x72, 0xd5, 0x59, 0x26, 0x02, 0xa5, 0xef, 0xe5, 0xae, 0x84, 0x18, 0x5a, 0x31,
+        0x4b, 0x64, 0x6c, 0x6e, 0x94, 0x2f, 0xd5, 0x72, 0x0a, 0xab, 0xb7, 0xc8, 0x0c, 0x6c, 0x39, 0xc3,
+        0x3b, 0x19, 0xca, 0xf3, 0x6a, 0xd9, 0xe0, 0xa7, 0x5f, 0x9b, 0xc5, 0x20, 0xd9, 0x27, 0xb4, 0x57,
+        0x95, 0xbf, 0x4b, 0x3e, 0x0e, 0x47, 0xf1, 0x77, 0x03, 0xb4, 0x78, 0xf4, 0x95, 0x4e, 0x24, 0xff,
+        0x3f, 0x71, 0x23, 0xf6, 0xf3, 0xa3, 0x86, 0x66, 0xe5, 0xf3, 0x0e, 0x12, 0xa0, 0xfc, 0x1a, 0x7e,
+        0xe1, 0x5b, 0x6d, 0xa1, 0x1e, 0xb9, 0x5e, 0x66, 0x03, 0xc5, 0x4e, 0xe1, 0xa6, 0x12, 0xf4, 0x8a,
+        0x3b, 0xf5, 0x58, 0x97, 0xbc, 0xf2, 0xff, 0xe1, 0x0b, 0x15, 0x6c, 0xff, 0xb1, 0xfc, 0xe3, 0xa7,
+        0x4e, 0x65, 0x94, 0x09, 0x42, 0xd4, 0xe7, 0xb2, 0x17, 0x1b, 0xb4, 0xc9, 0xe6, 0xd3, 0x3c, 0x45,
+        0x90, 0x33, 0x60, 0x37, 0x41, 0x65, 0xe9, 0xeb, 0x8d, 0x55, 0x55, 0x31, 0x12, 0x28, 0xe4, 0x6e,
+        0xfd, 0xb9, 0xe3, 0x88, 0xbe, 0x76, 0x7a, 0xc5, 0x2c, 0x43, 0x00, 0x0c, 0xfb, 0x5c, 0x8c, 0xc0,
+        0x44, 0xf4, 0x28, 0xf7, 0xe0, 0x39, 0x3f, 0x56, 0x38, 0x44, 0x36, 0x73, 0x7f, 0x55, 0xe3, 0xda,
+        0xa1, 0xf8, 0xb5, 0x30, 0xa8, 0xb1, 0xd7, 0x06, 0x1e, 0xc1, 0x36, 0x90, 0x51, 0xe3, 0x37, 0x38,
+        0x2e, 0xeb, 0x69, 0xa0, 0xfc, 0x3b, 0xe8, 0xc6, 0xc0, 0x24, 0x5e, 0x19, 0x05, 0x04, 0xbb, 0x38,
+        0x09, 0xee, 0x6a, 0x5b, 0xbe, 0x09, 0xd5, 0x09, 0x0b, 0x76, 0x84, 0xbf, 0xeb, 0x7b, 0x22, 0x91,
+        0x3f, 0xc2, 0x3a, 0x62, 0x1c, 0x8b, 0x1e, 0xbe, 0x25, 0x37, 0x80, 0xed, 0x03, 0x24, 0xa6, 0xfb,
+        0x5c, 0xf3, 0x73, 0xc5, 0x3f, 0xbe, 0xc2, 0x66, 0x1c, 0x32, 0x71, 0xfa, 0x60, 0x04, 0xe7, 0xf4,
+        0x35, 0xa9, 0xc3, 0xcd, 0x01, 0x62, 0xc9, 0xaa, 0xd0, 0xfa, 0xac, 0xb5, 0x2d, 0xb6, 0xa5, 0x33,
+        0xbb, 0x03, 0x5f, 0xc9, 0x91, 0xce, 0x44, 0x91, 0x21, 0x2b, 0x2d, 0xc6, 0x17, 0x22, 0xf5, 0x83,
+        0x7e, 0xfa, 0x79, 0x9a, 0xae, 0xb5, 0x73, 0xb5, 0x6a, 0xfe, 0x3b, 0x7b, 0xe7, 0xe2, 0x9d, 0x28,
+        0xa6, 0x33, 0x98, 0xb6, 0xe8, 0x47, 0xad, 0xf9, 0x07, 0xd9, 0xe8, 0x2d, 0x1c, 0xa8, 0x82, 0x34,
+        0x8a, 0x70, 0x99, 0x83, 0x84, 0x81, 0x5c, 0x6f, 0x85, 0x5b, 0xcf, 0xe8, 0xbe, 0xeb, 0x02, 0xfe,
+        0x3b, 0x65, 0x32, 0xdf, 0xa9, 0xb5, 0x6f, 0xe4, 0x06, 0xd2, 0x26, 0x15, 0x77, 0xdb, 0xfd, 0x1c,
+        0x4b, 0x33, 0x58, 0xfc, 0xf9, 0x87, 0xd2, 0x70, 0xb1, 0x70, 0x33, 0xef, 0x12, 0x12, 0xa7, 0x4f,
+        0x50, 0x23, 0x2a, 0xe8, 0xc3, 0xf8, 0x41, 0xc5, 0x19, 0xd3, 0xc6, 0x5f, 0x8c, 0x54, 0x21, 0x17,
+        0x26, 0x31, 0x9a, 0x6a, 0x67, 0x14, 0xc0, 0x0e, 0x97, 0xda, 0xb5, 0x06, 0xdf, 0x3a, 0x1b, 0x2c,
+        0x0a, 0xda, 0x07, 0x78, 0x31, 0xc8, 0x0c, 0xbc, 0x1d, 0x02, 0xd9, 0xdb, 0xba, 0xce, 0x38, 0x04,
+        0xe0, 0x7b, 0x3c, 0xae, 0x28, 0xe7, 0x54, 0x20, 0x65, 0x1c, 0x0d, 0x2a, 0x5c, 0x4b, 0x40, 0xe2,
+        0x8a, 0x28, 0x7b, 0x78, 0x8a, 0x2b, 0x77, 0x66, 0x12, 0x5e, 0x42, 0xc0, 0xd0, 0xf9, 0xd0, 0xd2,
+        0x4f, 0x81, 0xbf, 0x9d, 0xf8, 0x8b, 0x35, 0x9d, 0x18, 0xd7, 0x56, 0x56, 0x7b, 0x49, 0xe7, 0x2b,
+        0xb6, 0x41, 0xab, 0x99, 0xed, 0xc6, 0x43, 0x51, 0x13, 0x43, 0x39, 0x59, 0x86, 0x00, 0xec, 0x1e,
+        0xb4, 0xc9, 0xd1, 0x24, 0x0e, 0x5d, 0x94, 0x34, 0x44, 0xf4, 0x12, 0xc7, 0x03, 0x4a, 0x07, 0x41,
+        0x2e, 0x6f, 0x64, 0xb7, 0x46, 0xab, 0x4d, 0x67, 0x0f, 0x9c, 0xfb, 0x66, 0x18, 0x51, 0x6f, 0x9c,
+        0x71, 0xef, 0x0a, 0x79, 0x43, 0xf3, 0x93, 0x37, 0xfc, 0xd2, 0x45, 0xdf, 0x79, 0xdd, 0x71, 0x28,
+        0x3a, 0x81, 0x6f, 0xa2, 0x67, 0x57, 0xd8, 0x1c, 0x1b, 0x96, 0x40, 0x1d, 0xa6, 0xfc, 0xca, 0x7d,
+        0xff, 0x4b, 0xed, 0x7d, 0x91, 0x8c, 0x81, 0x27, 0x42, 0x21, 0x80, 0x8f, 0x18, 0xb9, 0x32, 0x93,
+        0x5e, 0xa8, 0x23, 0x83, 0xb1, 0x7e, 0x24, 0xfe, 0x0a, 0xb3, 0x15, 0x20, 0x8e, 0x6a, 0x67, 0x73,
+        0x8f, 0xb5, 0xa8, 0x3b, 0x19, 0xf7, 0x76, 0xac, 0xa4, 0x62, 0x02, 0xe0, 0x3d, 0x9f, 0x1c, 0x15,
+        0x57, 0xdf, 0xaa, 0x82, 0x3f, 0xf7, 0x3a, 0x57, 0x25, 0x6d, 0x11, 0x47, 0xc4, 0x27, 0x53, 0x89,
+        0x1f, 0x1e, 0xff, 0x87, 0x08, 0x03, 0x87, 0x5e, 0xc4, 0x82, 0x2e, 0x73, 0x16, 0xde, 0x12, 0xa4,
+        0x52, 0xd6, 0x9e, 0xd5, 0x3f, 0xc6, 0x17, 0xe5, 0x08, 0x34, 0xf8, 0x40, 0x66, 0x9e, 0xc1, 0xb5,
+        0x60, 0x42, 0x38, 0x3f, 0xb8, 0xda, 0xab, 0x5e, 0x97, 0xf4, 0x62, 0x86, 0x69, 0xa2, 0xf2, 0x55,
+        0x38, 0x44, 0x9b, 0x11, 0x59, 0x26, 0xc9, 0xee, 0x0e, 0x35, 0x87, 0x50, 0x19, 0x65, 0xca, 0x3e,
+        0x92, 0x08, 0x0d, 0x7f, 0x9a, 0x48, 0x99, 0x21, 0x78, 0x8c, 0x78, 0x87, 0xf6, 0xc7, 0x9a, 0xcb,
+        0x86, 0x3c, 0x6a, 0x99, 0x6d, 0x94, 0x1b, 0x04, 0x22, 0x70, 0x7d, 0xec, 0x31, 0x60, 0x93, 0xda,
+        0xbf, 0x90, 0x3f, 0x73, 0x08, 0x79, 0x77, 0xe8, 0xa5, 0x6f, 0xee, 0x87, 0x33, 0x06, 0x92, 0xba,
+        0xa1, 0xd5, 0xf8, 0x66, 0x78, 0x15, 0x55, 0x53, 0x2a, 0x22, 0x47, 0xe2, 0xf8, 0x4f, 0xe5, 0x65,
+        0xb1, 0x92, 0xce, 0x21, 0x16, 0x0a, 0x9f, 0xcc, 0xa4, 0x61, 0x02, 0x01, 0x1a, 0x24, 0x30, 0x0d,
+        0x81, 0xf9, 0xc5, 0xe3, 0xad, 0x89, 0x39, 0xa8, 0x2e, 0x19, 0x51, 0x42, 0x51, 0x18, 0x3a, 0xbe,
+        0x5c, 0xf1, 0x1a, 0x13, 0x0b, 0x60, 0xc8, 0x03, 0xbe, 0xa9, 0xcd, 0xa0, 0x44, 0x69, 0xc3, 0x27,
+        0x3d, 0x6a, 0xb0, 0x50, 0x5b, 0x8a, 0x81, 0x2f, 0x0f, 0x43, 0x23, 0x00, 0x93, 0xd3, 0x33, 0x91,
+        0x07, 0x24, 0x8a, 0x51, 0x10, 0x17, 0xe0, 0x13, 0xd2, 0xef, 0x8a, 0x74, 0x85, 0x41, 0x08, 0x79,
+        0xa8, 0xf5, 0x83, 0x2b, 0x90, 0x61, 0x03, 0x7c, 0x20, 0x80, 0xd6, 0x85, 0x11, 0xd2, 0xf4, 0x4e,
+        0xb2, 0x1e, 0xdf, 0x69, 0x59, 0xcf, 0xa9, 0xbe, 0xe2, 0xac, 0xc7, 0x48, 0x35, 0x48, 0x58, 0x59,
+        0x2c, 0xe8, 0x36, 0xcd, 0x4d, 0x26, 0x70, 0x92, 0x19, 0x27, 0xcc, 0xdb, 0x50, 0xa8, 0xb2, 0x7f,
+        0x4c, 0x0d, 0xf6, 0x9c, 0x40, 0xe2, 0xe8, 0x1c, 0x04, 0x90, 0xc7, 0xe7, 0x14, 0xc8, 0x88, 0x07,
+        0x31, 0x41, 0xe6, 0x3e, 0x5c, 0x54, 0x1c, 0xae, 0x16, 0xc7, 0xd6, 0x5c, 0xdc, 0x13, 0x40, 0x32,
+        0xc6, 0x58, 0xb3, 0x81, 0x96, 0x42, 0x70, 0xfb, 0xda, 0x66, 0x55, 0x1a, 0x30, 0x2b, 0xed, 0x2c,
+        0x98, 0x26, 0x55, 0x15, 0x8b, 0x52, 0xba, 0xc9, 0x13, 0x3b, 0x29, 0x9c, 0x5f, 0x75, 0x02, 0xff,
+        0x21, 0x18, 0xc1, 0xab, 0x90, 0xaf, 0x3f, 0x10, 0x94, 0x91, 0xbd, 0x91, 0x5f, 0x64, 0x47, 0x4e,
+        0x76, 0xac, 0x2a, 0xb8, 0x95, 0xec, 0x7e, 0x9b, 0x0e, 0x74, 0x7b, 0x8a, 0x30, 0xfc, 0x5c, 0x00,
+        0xba, 0x4b, 0xc6, 0x9a, 0xab, 0x61, 0xca, 0x96, 0x9a, 0x74, 0x18, 0x45, 0x16, 0xd5, 0xda, 0x6b,
+        0xb6, 0x93, 0xa4, 0x34, 0x52, 0xcb, 0x82, 0x94, 0x02, 0x7a, 0x50, 0xa6, 0x6f, 0x39, 0xb6, 0x35,
+        0x40, 0xc2, 0x60, 0x7f, 0xbd, 0xd3, 0x8c, 0xcb, 0x91, 0x86, 0x68, 0xe9, 0xb4, 0x39, 0x95, 0x5b,
+        0xb0, 0x85, 0xb4, 0x50, 0x83, 0xbd, 0x10, 0xb3, 0x23, 0xe3, 0x8f, 0x1a, 0x62, 0x66, 0x3d, 0x6a,
+        0xc9, 0xd0, 0x1c, 0x39, 0x9d, 0x81, 0xf8, 0xe3, 0xfd, 0x17, 0x50, 0xad, 0x3c, 0x1e, 0xae, 0x56,
+        0x7a, 0x49, 0x33, 0x03, 0x6b, 0x8b, 0x9b, 0x96, 0x24, 0x1f, 0x6e, 0xbd, 0xa8, 0x52, 0x6c, 0x9f,
+        0x76, 0x7b, 0x2c, 0x23, 0xc7, 0xbd, 0x3b, 0xe9, 0x03, 0x5c, 0xf1, 0xcb, 0x1c, 0xbd, 0xd3, 0xe1,
+        0x57, 0x29, 0x01, 0x6b, 0xba, 0x07, 0x88, 0xc9, 0x2e, 0xce, 0x20, 0x2f, 0x99, 0x72, 0xe6, 0xb5,
+        0x28, 0x0b, 0xf7, 0x97, 0x84, 0xa4, 0x71, 0x9d, 0x91, 0xc9, 0x6f, 0x9f, 0xb5, 0x62, 0xdf, 0x4c,
+        0x85, 0x3b, 0x45, 0x5d, 0x5a, 0x2d, 0xc6, 0x65, 0x12, 0x93, 0x2f, 0x23, 0xcb, 0x30, 0xe6, 0xab,
+        0x7a, 0x45, 0xa9, 0x0b, 0x14, 0x24, 0x7b, 0xbd, 0xd8, 0x86, 0xac, 0x26, 0xcc, 0x1c, 0x86, 0x70,
+        0x57, 0xac, 0xeb, 0x14, 0x94, 0xe5, 0xc0, 0xfa, 0x0a, 0x2a, 0xd8, 0x1d, 0xde, 0xe2, 0xa6, 0xae,
+        0x25, 0x1c, 0x73, 0x0f, 0x2a, 0xa3, 0x39, 0x6e, 0x26, 0xe3, 0xbe, 0x8b, 0x24, 0x1b, 0xdf, 0xa0,
+        0x0c, 0xa0, 0x71, 0x76, 0x95, 0x4e, 0x5b, 0xd3, 0x27, 0x12, 0x11, 0x8c, 0x99, 0xc4, 0xaf, 0x5a,
+        0x2b, 0x82, 0x46, 0x59, 0xe2, 0xb4, 0x71, 0xd0, 0x62, 0x59, 0x8d, 0xb7, 0x99, 0xaa, 0x7d, 0xdd,
+        0xbd, 0x2f, 0xcc, 0x92, 0x66, 0x16, 0x56, 0xba, 0x21, 0xf4, 0x07, 0x53, 0xde, 0xb2, 0x76, 0x88,
+        0x2a, 0xec, 0x93, 0x5d, 0x5d, 0x17, 0x0d, 0x64, 0x8c, 0x96, 0x83, 0xa0, 0xdb, 0x1b, 0x32, 0xe3,
+        0x2b, 0xcd, 0x32, 0xda, 0x35, 0x3e, 0x37, 0x74, 0x17, 0x9d, 0x4b, 0x50, 0x4d, 0x22, 0x9d, 0xe6,
+        0x95, 0xda, 0xa5, 0xb9, 0x1c, 0x64, 0xa5, 0xe2, 0x71, 0x37, 0x81, 0x9c, 0x56, 0xac, 0x9a, 0x54,
+        0xbb, 0xd0, 0xd0, 0x27, 0xfa, 0xf8, 0xbd, 0x83, 0x05, 0x87, 0xb8, 0x72, 0x57, 0x9b, 0xa4, 0x0e,
+        0x0b, 0x6d, 0xb2, 0xcd, 0xa6, 0x52, 0x8c, 0xfb, 0xe2, 0x59, 0x0a, 0xc1, 0xfe, 0x7a, 0xd4, 0xa0,
+        0xf4, 0xbd, 0xe2, 0x62, 0xdf, 0xac, 0x93, 0x13, 0x0e, 0xf6, 0xb5, 0x34, 0xc1, 0x4f, 0x6b, 0x18,
+        0xa8, 0x74, 0x9d, 0x1c, 0xd7, 0x8d, 0x44, 0xf9, 0x46, 0x11, 0x74, 0xae, 0xeb, 0x42, 0x3c, 0xda,
+        0x70, 0xa6, 0x8d, 0x2c, 0xfe, 0x42, 0x1d, 0x9b, 0x14, 0xb2, 0x71, 0x53, 0x6f, 0xc8, 0x44, 0xf9,
+        0x15, 0x4f, 0x4c, 0x60, 0xe0, 0x6c, 0x1f, 0x99, 0x54, 0x46, 0x38, 0x60, 0x35, 0x70, 0xde, 0xf7,
+        0x7b, 0x43, 0x7a, 0x44, 0xec, 0x8b, 0x09, 0x27, 0x1a, 0xde, 0x9a, 0x5e, 0x68, 0x30, 0x59, 0x4d,
+        0x20, 0x5a, 0x37, 0xce, 0x72, 0xc1, 0x40, 0x34, 0x42, 0xbb, 0x12, 0x2d, 0x20, 0xdb, 0xe9, 0x11,
+        0x11, 0xee, 0xcb, 0x73, 0xd2, 0xf9, 0xda, 0x8a, 0x27, 0xe3, 0x52, 0xde, 0x48, 0x1d, 0x83, 0xed,
+        0x81, 0x28, 0xdf, 0x16, 0x88, 0xbd, 0xfa, 0x63, 0xf0, 0xbe, 0xf1, 0x78, 0x52, 0xab, 0x85, 0xdc,
+        0x92, 0x75, 0x84, 0xc5, 0x04, 0xc5, 0xac, 0x5c, 0x10, 0x0b, 0x6f, 0xc3, 0x4e, 0x5e, 0x44, 0xcb,
+        0x7f, 0x77, 0x50, 0xf8, 0xb5, 0x96, 0x11, 0x8b, 0xd2, 0xf0, 0xb9, 0x19, 0x8d, 0xdd, 0x06, 0x42,
+        0x63, 0x26, 0xac, 0xe1, 0xa9, 0xb0, 0x13, 0x90, 0x1c, 0xd8, 0x6b, 0x09, 0x07, 0xf9, 0xbc, 0x06,
+        0x42, 0x18, 0xcd, 0xbe, 0xf8, 0x90, 0xe1, 0x99, 0xa1, 0xb9, 0x25, 0x99, 0xd6, 0x96, 0xb9, 0xd2,
+        0x6c, 0xd1, 0x1e, 0x84, 0x20, 0x1b, 0xec, 0x2e, 0x23, 0xf2, 0x6e, 0x06, 0x35, 0x7b, 0xff, 0xbd,
+        0x57, 0xcd, 0xd9, 0x8a, 0x1f, 0x42, 0x39, 0xf4, 0xa2, 0x7b, 0x36, 0xea, 0xbf, 0x0d, 0x0d, 0x63,
+        0x22, 0x5c, 0x47, 0x64, 0x6b, 0xcf, 0xb5, 0xdc, 0x1f, 0x79, 0xca, 0x9c, 0x4e, 0x78, 0xc5, 0xf8,
+        0x8d, 0xa7, 0x9e, 0x9f, 0xd7, 0xd2, 0x44, 0x7e, 0xd2, 0xa6, 0x73, 0xfb, 0xac, 0xd1, 0xe4, 0xcf,
+        0x82, 0xb6, 0x85, 0x73, 0x47, 0x0a, 0x0b, 0x29, 0x1e, 0x58, 0x92, 0x8f, 0xc8, 0x12, 0x84, 0x69,
+        0xa4, 0xaf, 0x9d, 0xb1, 0x89, 0xfe, 0x20, 0xb5, 0xf9, 0xee, 0x8b, 0xdf, 0xa0, 0x08, 0x71, 0x01,
+        0xd0, 0x7d, 0x21, 0x1b, 0x91, 0x96, 0x90, 0xab, 0x2d, 0x02, 0x76, 0xdc, 0xf6, 0xd6, 0x9d, 0x86,
+        0x56, 0xcb, 0x1d, 0xf0, 0xe2, 0xec, 0xaa, 0x72, 0x3d, 0x26, 0x75, 0x4c, 0xdd, 0x07, 0x6e, 0x6e,
+        0xab, 0x2d, 0x46, 0xcb, 0xbb, 0x3a, 0xf7, 0x0e, 0x07, 0x2b, 0x10, 0x1a, 0x43, 0xe6, 0x84, 0x77,
+        0xe1, 0x0d, 0x61, 0x90, 0x1f, 0xd7, 0x71, 0x90, 0x58, 0xcc, 0xb8, 0xc4, 0x97, 0x5d, 0xba, 0xcd,
+        0x8f, 0xd2, 0x12, 0xf6, 0xb9, 0xa2, 0x76, 0x0a, 0x2e, 0xae, 0x9e, 0xfd, 0x5f, 0x6e, 0x97, 0xb7,
+        0x11, 0xd3, 0xc2, 0xae, 0x50, 0xe0, 0x21, 0xec, 0xf8, 0x43, 0xfa, 0x68, 0x5c, 0x94, 0x2b, 0x58,
+        0xc1, 0x3c, 0x42, 0xcf, 0x2f, 0xb9, 0x52, 0xea, 0x05, 0x6f, 0x60, 0x1d, 0xbb, 0xb1, 0x49, 0xf8,
+        0x0d, 0xe5, 0xdb, 0xd6, 0x5e, 0xc3, 0x47, 0x13, 0xdd, 0x86, 0x43, 0x1a, 0xb4, 0x7f, 0xcd, 0xbc,
+        0xdf, 0x95, 0x51, 0x16, 0x68, 0xb3, 0xe9, 0xc7, 0x0c, 0x69, 0x05, 0x5b, 0x16, 0x64, 0x5b, 0x71,
+        0x6c, 0x70, 0xd8, 0xde, 0xf5, 0x0c, 0xd4, 0x92, 0xf1, 0x45, 0x86, 0x74, 0x8f, 0x98, 0x6e, 0x96,
+        0x9f, 0x45, 0x43, 0xc3, 0x50, 0x5f, 0x0e, 0x82, 0x03, 0xf0, 0x77, 0x1e, 0x99, 0xd3, 0x2e, 0x60,
+        0xc6, 0x63, 0xa0, 0xbf, 0xb0, 0xa8, 0x70, 0xd3, 0x4b, 0x37, 0x0a, 0x20, 0x6f, 0x19, 0x6c, 0xdc,
+        0xb7, 0xec, 0x68, 0x14, 0x43, 0xe7, 0xf4, 0x86, 0x04, 0xac, 0x5b, 0xa1, 0x38, 0x7c, 0x83, 0x79,
+        0x4b, 0x2c, 0x72, 0x13, 0x11, 0xfc, 0x74, 0x10, 0x3d, 0x38, 0x9c, 0x2b, 0x25, 0x24, 0xf6, 0x31,
+        0x5a, 0xa4, 0x94, 0x55, 0xdd, 0xf0, 0x9d, 0xac, 0x12, 0x42, 0x55, 0x95, 0xc0, 0xb8, 0x9c, 0x05,
+        0xa7, 0x87, 0xc6, 0x25, 0x89, 0xf1, 0x9d, 0x12, 0x25, 0xbd, 0x97, 0xf4, 0xf2, 0x97, 0x28, 0xe2,
+        0x4d, 0x81, 0x30, 0x85, 0xdd, 0x8e, 0x29, 0x76, 0x2b, 0x7a, 0x74, 0x02, 0xd0, 0xa6, 0x01, 0xec,
+        0x20, 0xef, 0x6a, 0x7c, 0xe5, 0x52, 0xde, 0x9b, 0x91, 0x48, 0x2d, 0xe9, 0xe8, 0x20, 0x5a, 0xba,
+        0xdd, 0x21, 0xa3, 0xb4, 0x25, 0xe3, 0x81, 0x0c, 0x20, 0xfc, 0x07, 0x2d, 0x53, 0x30, 0x8d, 0x0b,
+        0xe1, 0x09, 0xcc, 0x79, 0xba, 0x71, 0x02, 0x52, 0x78, 0x72, 0x4f, 0x78, 0xe6, 0x06, 0xf5, 0xf9,
+        0x0c, 0x2a, 0x8e, 0xea, 0xd0, 0x3b, 0xc2, 0x71, 0x2a, 0x5a, 0x0c, 0x14, 0xaf, 0x96, 0xc3, 0xb6,
+        0x89, 0x8e, 0x58, 0x51, 0xc2, 0x71, 0x24, 0x90, 0x43, 0x95, 0x77, 0x64, 0xdb, 0x14, 0x92, 0xfb,
+        0xa7, 0xac, 0x9d, 0xcb, 0x53, 0x9c, 0x8e, 0x2d, 0x18, 0xb6, 0x30, 0x4d, 0x40, 0x9c, 0xf0, 0xaf,
+        0x48, 0xd0, 0xd5, 0x08, 0x97, 0xd5, 0xda, 0x06, 0x93, 0x2e, 0xaa, 0xe0, 0xcd, 0xfb, 0x22, 0xb8,
+        0x88, 0x81, 0x49, 0x68, 0xfc, 0x33, 0x61, 0xb9, 0x13, 0x71, 0x57, 0x8a, 0x7c, 0x5f, 0x99, 0x67,
+        0x81, 0x9e, 0xcc, 0xf6, 0xd7, 0xf7, 0xbb, 0xa9, 0x9c, 0x45, 0x8b, 0xee, 0x20, 0x45, 0x28, 0x09,
+        0x60, 0xa5, 0x7f, 0x16, 0x7e, 0xe6, 0xa5, 0xf2, 0x27, 0x5c, 0x86, 0xf5, 0x92, 0x52, 0xe7, 0x2c,
+        0x18, 0x94, 0x5a, 0xff, 0x69, 0xdb, 0x21, 0x55, 0xca, 0x6b, 0x54, 0x5a, 0x6c, 0x33, 0xb5, 0x9b,
+        0x6b, 0x14, 0x10, 0xd8, 0x2a, 0x6e, 0xb2, 0x3d, 0x12, 0x22, 0x0a, 0x81, 0xa6, 0x05, 0x21, 0xb2,
+        0xb8, 0xec, 0xea, 0x2d, 0x26, 0x34, 0x6d, 0xf6, 0xb3, 0xb7, 0x9d, 0x92, 0x42, 0x23, 0xc3, 0x0d,
+        0x12, 0xb1, 0x80, 0xbc, 0x5a, 0xb3, 0xc8, 0xbd, 0x1a, 0xb9, 0x45, 0x4a, 0x41, 0x41, 0x95, 0xd0,
+        0x28, 0xe7, 0x2e, 0x1f, 0xb6, 0x62, 0xa7, 0xe2, 0xb3, 0x6c, 0x18, 0x5a, 0x0b, 0xd0, 0xf6, 0xc9,
+        0x60, 0xba, 0x91, 0x77, 0x1b, 0xf7, 0x0b, 0x91, 0x1e, 0x7a, 0xdb, 0x0e, 0xc4, 0xbb, 0x27, 0xbe,
+        0x8b, 0xb9, 0x7e, 0xae, 0xac, 0xc8, 0x18, 0xe4, 0x4d, 0xaf, 0x99, 0x2f, 0xb3, 0x75, 0xe3, 0x6a,
+        0x28, 0x7a, 0x98, 0x4d, 0x98, 0x33, 0x10, 0x06, 0x2e, 0x8b, 0x1f, 0x6f, 0xad, 0x32, 0x7b, 0x2d,
+        0x53, 0xb1, 0x5b, 0x88, 0xaf, 0x5b, 0xcd, 0xb9, 0xe9, 0x9b, 0x91, 0x41, 0xcc, 0xb2, 0xb2, 0x67,
+        0x03, 0xf4, 0x3e, 0xdb, 0x17, 0x7e, 0x57, 0x3d, 0x20, 0x19, 0xbe, 0xd7, 0x76, 0x24, 0xf3, 0xc1,
+        0x88, 0x5f, 0xc4, 0x38, 0x27, 0x39, 0x2b, 0xe4, 0x57, 0x9c, 0x4e, 0xc8, 0xdb, 0xd3, 0xfb, 0xe8,
+        0x1e, 0xda, 0x28, 0x02, 0x5b, 0x20, 0xf5, 0x58, 0x30, 0x5f, 0xbe, 0x36, 0x00, 0xb5, 0xfb, 0x94,
+        0x74, 0x15, 0x1c, 0x59, 0x8b, 0xe0, 0xf9, 0x05, 0x5a, 0x2c, 0x8f, 0xfc, 0xb7, 0xc9, 0xeb, 0xf8,
+        0xc9, 0x92, 0x29, 0x82, 0xda, 0x0e, 0xbe, 0x6e, 0x19, 0xeb, 0x6f, 0xa3, 0xdd, 0x36, 0x49, 0xc5,
+        0xb8, 0xa7, 0xd2, 0x5e, 0x23, 0x10, 0x39, 0xd4, 0xb7, 0x38, 0x6c, 0x91, 0x8d, 0x30, 0x03, 0xed,
+        0xb0, 0xb1, 0x0a, 0x45, 0xe7, 0x7e, 0x78, 0x55, 0x04, 0x57, 0x8c, 0x98, 0xc6, 0x26, 0x19, 0xdd,
+        0x10, 0xa3, 0xdd, 0x65, 0xf7, 0x04, 0xc1, 0xa2, 0xa0, 0x05, 0x2e, 0x04, 0x54, 0xa1, 0x20, 0xc0,
+        0x25, 0xc6, 0x37, 0x41, 0x05, 0xcf, 0xf7, 0xee, 0x0b, 0x42, 0x2e, 0xfb, 0x06, 0x1d, 0x80, 0xaa,
+        0x0e, 0x0f, 0x14, 0xa5, 0xc7, 0x12, 0x5d, 0xc4, 0x37, 0x00, 0x52, 0xda, 0xec, 0x63, 0x55, 0xc6,
+        0xbe, 0x5f, 0x8d, 0x39, 0xb8, 0x52, 0x8d, 0x4f, 0x17, 0x8b, 0x86, 0xbe, 0xd6, 0x02, 0x71, 0x51,
+        0xf4, 0x6d, 0x10, 0x96, 0xa4, 0x27, 0x73, 0x1a, 0x64, 0x20, 0xad, 0x79, 0xeb, 0xc7, 0xa0, 0x2c,
+        0x6e, 0xf3, 0x64, 0xb1, 0x62, 0x8a, 0x32, 0xc5, 0x1c, 0x09, 0x7e, 0xf6, 0x17, 0xb9, 0xae, 0xae,
+        0x73, 0xb0, 0x8f, 0x2c, 0xcc, 0x77, 0xc5, 0xf5, 0xc6, 0x19, 0x54, 0x5b, 0xd5, 0xdc, 0xf2, 0xd0,
+        0x7a, 0x64, 0x18, 0x98, 0xdb, 0x14, 0x0e, 0x43, 0x24, 0x23, 0xbe, 0x9d, 0x26, 0x2e, 0xb2, 0xce,
+        0xc1, 0x51, 0xeb, 0x77, 0x85, 0x1e, 0xe3, 0x44, 0x6e, 0x51, 0x84, 0x93, 0xf7, 0xb5, 0x56, 0x74,
+        0x48, 0xe7, 0x20, 0xfd, 0x00, 0x2b, 0xa6, 0xb5, 0x0b, 0xc3, 0x8c, 0x9a, 0xe4, 0x22, 0x6f, 0xcd,
+        0xfc, 0x20, 0xab, 0xba, 0x75, 0x9a, 0xfd, 0xea, 0x0a, 0xef, 0x66, 0x37, 0x6c, 0x22, 0x55, 0xd9,
+        0xb9, 0x3a, 0x47, 0xde, 0x4f, 0x4e, 0x18, 0x6b, 0x2d, 0xfb, 0x74, 0x38, 0xdc, 0x12, 0x7c, 0x6f,
+        0xf7, 0x4d, 0x1c, 0x1e, 0x62, 0x86, 0x68, 0x25, 0xce, 0xeb, 0x03, 0x51, 0xf5, 0x9e, 0x8f, 0x44,
+        0x7d, 0x21, 0x2c, 0x5b, 0x90, 0x2a, 0x89, 0xf4, 0x14, 0xe8, 0x28, 0x3d, 0xd1, 0x17, 0x72, 0xe7,
+        0xfe, 0x4b, 0xee, 0x3c, 0x4f, 0xb3, 0x99, 0x86, 0x23, 0xbc, 0xec, 0x1f, 0x84, 0xb1, 0x81, 0x8f,
+        0xe8, 0x5d, 0xe7, 0x45, 0x52, 0x7a, 0xe6, 0xed, 0x11, 0xab, 0xe1, 0x33, 0xff, 0x4a, 0xcd, 0xd8,
+        0x58, 0x7e, 0x26, 0xf7, 0x57, 0x90, 0xc9, 0xb8, 0x1b, 0xcb, 0x13, 0xc3, 0x4e, 0x58, 0xaf, 0x5a,
+        0x1e, 0x4a, 0x95, 0x2a, 0x86, 0x7d, 0x1e, 0xcc, 0x19, 0xbb, 0x2d, 0xd2, 0x28, 0x12, 0x31, 0x97,
+        0x89, 0x0d, 0x9c, 0x73, 0x9d, 0xa1, 0x21, 0x5b, 0xfa, 0xab, 0xa4, 0xc8, 0xd1, 0x31, 0x35, 0xab,
+        0x6f, 0xec, 0x2a, 0x28, 0xb5, 0x05, 0x5e, 0x03, 0x00, 0x58, 0x1e, 0x97, 0x7d, 0xf7, 0xa1, 0xfe,
+        0x3e, 0xc6, 0xf3, 0xd5, 0x26, 0x46, 0x3c, 0x87, 0xd8, 0x9a, 0xb3, 0xab, 0x12, 0x3c, 0x78, 0x90,
+        0x1b, 0x5c, 0x04, 0x70, 0x0b, 0x3d, 0xa2, 0xea, 0x27, 0x6c, 0x1b, 0xa2, 0xb0, 0x1d, 0xb7, 0x81,
+        0x91, 0x12, 0xeb, 0xdb, 0x66, 0x33, 0x37, 0xa3, 0x29, 0x46, 0x01, 0x60, 0x3f, 0xd6, 0x8e, 0x29,
+        0xc4, 0x2a, 0xdb, 0x36, 0x35, 0x90, 0x2e, 0x87, 0x0c, 0x55, 0x63, 0x7b, 0x23, 0x55, 0x3d, 0x1c,
+        0xae, 0xfa, 0x81, 0xeb, 0x85, 0x1b, 0xb5, 0x10, 0x98, 0x4d, 0x1c, 0xc3, 0xcf, 0x73, 0xd3, 0x57,
+        0x42, 0x35, 0xbc, 0xb5, 0x61, 0x59, 0x6a, 0x63, 0x12, 0xd4, 0x47, 0x3c, 0xca, 0x69, 0x72, 0xbb,
+        0xcf, 0xf2, 0x25, 0x38, 0x77, 0x00, 0xf9, 0x32, 0x7e, 0xfc, 0x17, 0x47, 0xd7, 0xcc, 0xf6, 0x10,
+        0x03, 0x0f, 0xb2, 0x34, 0x08, 0x1e, 0x5f, 0xdd, 0x24, 0xd2, 0x3b, 0xb4, 0xfd, 0x8d, 0x00, 0xaa,
+        0x3c, 0x3a, 0x19, 0x06, 0x40, 0x07, 0xdb, 0x19, 0x2e, 0x34, 0x2e, 0x07, 0x98, 0x67, 0xdd, 0x1d,
+        0x4b, 0x9d, 0xb7, 0x26, 0xd0, 0x32, 0x1f, 0x56, 0x0d, 0x46, 0xc5, 0xee, 0xf0, 0x8e, 0xba, 0x73,
+        0x0f, 0x2a, 0x0a, 0x0f, 0xf6, 0x72, 0x43, 0x01, 0xea, 0x1c, 0xd0, 0x69, 0x94, 0x08, 0x46, 0xfd,
+        0xae, 0xbf, 0x39, 0xfb, 0x50, 0x54, 0xf5, 0xf7, 0x2f, 0xbb, 0xd5, 0xa8, 0x49, 0x69, 0x1f, 0x3a,
+        0x36, 0xcd, 0xd4, 0x92, 0x1b, 0xb3, 0xa3, 0xf7, 0x68, 0x2e, 0xe5, 0x39, 0x44, 0x47, 0xea, 0xea,
+        0x6f, 0x0a, 0xed, 0x45, 0x41, 0x3e, 0x80, 0x0a, 0x0c, 0xdb, 0xa9, 0xae, 0x9f, 0x7c, 0x46, 0x3c,
+        0x9e, 0xd0, 0xb1, 0x0a, 0x9c, 0x6d, 0x1e, 0x25, 0xb1, 0xb9, 0x0c, 0x7b, 0xc1, 0xe4, 0x3c, 0x0f,
+        0xd3, 0x99, 0x8e, 0xf8, 0x8c, 0xaa, 0xdb, 0x36, 0x11, 0xef, 0x3f, 0x1a, 0x7e, 0x7e, 0x02, 0x1d,
+        0x3d, 0x23, 0xc3, 0x78, 0xcd, 0x15, 0x0d, 0xbb, 0x31, 0x6f, 0x5d, 0x4f, 0x6c, 0x80, 0xd5, 0xad,
+        0x21, 0xdb, 0x1a, 0xdd, 0x95, 0xa7, 0x07, 0x7c, 0x29, 0x2b, 0x9a, 0xb3, 0xaf, 0x44, 0xdb, 0x45,
+        0x58, 0x64, 0x48, 0xe5, 0x65, 0xcc, 0x7e, 0xf8, 0xfe, 0xb7, 0x94, 0x0c, 0x09, 0xc8, 0x30, 0x54,
+        0xa4, 0x85, 0x89, 0x2c, 0x3f, 0xf8, 0xd1, 0x67, 0x13, 0x93, 0x1d, 0x14, 0x0b, 0xe5, 0x2d, 0xe4,
+        0xc8, 0x4d, 0xac, 0x34, 0x6e, 0xf7, 0x33, 0xd4, 0x7d, 0xc0, 0x3f, 0x43, 0x20, 0xeb, 0x61, 0xef,
+        0xae, 0x48, 0x60, 0x15, 0x13, 0x76, 0x28, 0x81, 0x2f, 0x48, 0x98, 0x27, 0x59, 0xb7, 0x53, 0x09,
+        0x76, 0x32, 0x83, 0x7b, 0xcf, 0xdf, 0x73, 0x58, 0xe6, 0x93, 0x1d, 0xbf, 0x2e, 0x10, 0x55, 0xed,
+        0x95, 0x9b, 0xbb, 0x3b, 0xb2, 0x15, 0x86, 0x15, 0x06, 0x9a, 0x34, 0xe7, 0xa4, 0x91, 0x43, 0x93,
+        0xe8, 0x6d, 0xd1, 0x73, 0x7a, 0x8e, 0x0c, 0x68, 0x4b, 0x9b, 0xfa, 0xc8, 0x6b, 0xac, 0x5e, 0x09,
+        0x33, 0x1f, 0x6f, 0xe2, 0x18, 0x1f, 0xf2, 0x7b, 0x18, 0x8c, 0xc3, 0xcc, 0xf0, 0x16, 0x54, 0xfb,
+        0xca, 0x3f, 0x55, 0x40, 0xf6, 0xfb, 0x3a, 0x0e, 0x27, 0xd2, 0x5e, 0xf8, 0x3d, 0x2f, 0x03, 0xe7,
+        0x85, 0x64, 0xa4, 0x45, 0xd3, 0xd1, 0x7e, 0x88, 0x07, 0x37, 0x8a, 0x08, 0xbf, 0x3e, 0x0f, 0x47,
+        0xbe, 0xfa, 0xd9, 0xd4, 0xcf, 0xc2, 0x0c, 0xd7, 0x79, 0xda, 0xd7, 0x91, 0x19, 0x14, 0xf4, 0x3d,
+        0xf0, 0xeb, 0x29, 0xf1, 0x38, 0x10, 0x55, 0x38, 0x30, 0x7f, 0xbc, 0xa6, 0x61, 0x1b, 0x8c, 0xf3,
+        0xfe, 0x62, 0x97, 0x16, 0x1a, 0xe9, 0x52, 0x33, 0x6d, 0xed, 0xf9, 0xc5, 0x89, 0x45, 0x4a, 0x1a,
+        0xe6, 0x37, 0x4f, 0x2d, 0x57, 0xd9, 0xa2, 0xf0, 0x13, 0x3f, 0xf4, 0x61, 0x69, 0x47, 0x30, 0x43,
+        0x72, 0xe7, 0x60, 0xb6, 0xb0, 0xc4, 0xcf, 0x0f, 0x3f, 0x34, 0x53, 0x92, 0xa8, 0x3a, 0x8f, 0x49,
+        0x3d, 0x94, 0x14, 0xbc, 0x44, 0xb5, 0x47, 0x2c, 0x16, 0x16, 0x43, 0x1e, 0xb7, 0x8d, 0x93, 0x3b,
+        0xb0, 0x2b, 0xa2, 0xc8, 0x07, 0xc3, 0x36, 0x05, 0x67, 0x1a, 0xf4, 0x82, 0x41, 0x30, 0x39, 0xc6,
+        0x77, 0xd0, 0x64, 0x36, 0x39, 0x22, 0x27, 0x65, 0x04, 0x59, 0xbd, 0x88, 0xaa, 0x68, 0x5c, 0x3e,
+        0x46, 0x9c, 0x2b, 0xcd, 0x05, 0x9a, 0x2c, 0xba, 0x0c, 0x02, 0x5b, 0x7a, 0xa2, 0x42, 0x8e, 0x55,
+        0xff, 0xfe, 0x61, 0xca, 0xa8, 0x4a, 0xdd, 0xd8, 0x1b, 0x76, 0x46, 0xcf, 0x0a, 0x7d, 0x58, 0x78,
+        0x97, 0xff, 0x58, 0x66, 0x97, 0xba, 0xc7, 0xe3, 0x1f, 0x39, 0x5a, 0x99, 0x5f, 0x88, 0x9b, 0x9c,
+        0x4f, 0x88, 0xc9, 0x95, 0xe8, 0xbe, 0x06, 0xeb, 0x04, 0xe2, 0xff, 0x0a, 0x3e, 0xb8, 0xb4, 0x0f,
+        0x01, 0xe3, 0x1f, 0xf1, 0x3f, 0x26, 0xa3, 0x0a, 0x7f, 0xac, 0x9b, 0x5b, 0x5b, 0xff, 0xea, 0xd0,
+        0x7b, 0xbc, 0x31, 0x5f, 0xdb, 0x55, 0x03, 0x82, 0x01, 0x27, 0xbc, 0x23, 0x8e, 0x3c, 0xd0, 0x0f,
+        0xa1, 0xc4, 0x83, 0x64, 0x68, 0x29, 0xb4, 0xc8, 0xda, 0x72, 0x8c, 0xe2, 0x30, 0xe8, 0xfb, 0xd3,
+        0xa8, 0xc4, 0x5a, 0xdd, 0xb2, 0xa5, 0xd3, 0x53, 0x03, 0x37, 0xf5, 0x71, 0xd1, 0x44, 0x46, 0x11,
+        0xd1, 0x4f, 0x2b, 0xa6, 0x5a, 0x61, 0xb3, 0xe5, 0x9a, 0x2c, 0x65, 0x18, 0x97, 0x33, 0xfd, 0xb4,
+        0xd5, 0x0f, 0xc5, 0x0c, 0x6b, 0x0d, 0xa7, 0xb3, 0x1f, 0x1c, 0xdd, 0x62, 0xc0, 0x14, 0xd2, 0x18,
+        0xfb, 0xe8, 0x9e, 0xe2, 0x4f, 0xd4, 0x7f, 0x96, 0xcc, 0xcf, 0x81, 0x5f, 0x70, 0x27, 0xd5, 0xb4,
+        0xde, 0x40, 0x0c, 0xe0, 0x24, 0x6e, 0xd5, 0x64, 0x10, 0x8f, 0x8f, 0x31, 0x17, 0x77, 0xd0, 0xbb,
+        0xa3, 0x3c, 0x1d, 0xf9, 0x18, 0x78, 0xca, 0x58, 0x88, 0x65, 0x8b, 0xe3, 0xb1, 0xe8, 0x92, 0xbb,
+        0xea, 0x9d, 0x14, 0xdf, 0x6d, 0xda, 0x1e, 0x1d, 0x27, 0x2b, 0xe4, 0xa7, 0xa6, 0x0f, 0xa9, 0xa5,
+        0x50, 0xf3, 0x2c, 0xaa, 0x29, 0xa2, 0xef, 0x80, 0x90, 0x66, 0xbd, 0x6f, 0xa8, 0xda, 0xa3, 0x28,
+        0xb3, 0xc4, 0xc5, 0x16, 0x7a, 0x54, 0xab, 0x5e, 0x2c, 0x3c, 0x42, 0x40, 0x97, 0xee, 0x3a, 0x47,
+        0x83, 0x78, 0xdc, 0x1d, 0xb6, 0x67, 0x55, 0x16, 0x3e, 0xbe, 0x10, 0x98, 0x96, 0xff, 0x7d, 0xdb,
+        0x0f, 0xdc, 0xac, 0xb5, 0x6e, 0xe1, 0x75, 0xda, 0x15, 0x54, 0xa3, 0xc1, 0x5a, 0x4d, 0xf5, 0x1e,
+        0x48, 0xe3, 0x01, 0x26, 0x62, 0x97, 0x2f, 0x9b, 0x69, 0x94, 0x8a, 0xa8, 0x9e, 0xce, 0x7b, 0xcd,
+        0x20, 0x88, 0xae, 0xcf, 0xd6, 0xd7, 0xa6, 0xb4, 0x0e, 0x1b, 0x6e, 0x94, 0x6c, 0x47, 0x44, 0x21,
+        0xa9, 0x2a, 0xc0, 0xcd, 0xaa, 0x51, 0x17, 0xfb, 0x40, 0x8d, 0xd6, 0x6d, 0xac, 0xc8, 0xeb, 0x91,
+        0x26, 0x8d, 0x84, 0x02, 0x67, 0xb4, 0x05, 0xbf, 0x12, 0x8d, 0xe3, 0x8a, 0x58, 0xb7, 0x70, 0xa4,
+        0x21, 0xc5, 0xd8, 0x45, 0x69, 0xdd, 0x3a, 0x8b, 0x43, 0x07, 0x2f, 0x22, 0x01, 0xc0, 0x8a, 0x42,
+        0x4d, 0xf4, 0xf5, 0x6a, 0xd9, 0x37, 0x71, 0x79, 0x28, 0xc9, 0x63, 0xb7, 0x98, 0xb6, 0x9b, 0x18,
+        0x54, 0x12, 0x68, 0x93, 0x77, 0xae, 0x22, 0x98, 0x35, 0x35, 0xaf, 0xd3, 0xcf, 0x0d, 0x11, 0x86,
+        0x0c, 0x1e, 0x03, 0x59, 0x63, 0xe0, 0xb7, 0x60, 0x0d, 0xcc, 0xf0, 0x5e, 0xc0, 0x71, 0xdf, 0x10,
+        0xce, 0x7a, 0x66, 0x87, 0x77, 0xcc, 0xba, 0x51, 0xbd, 0x6a, 0x5c, 0x68, 0x69, 0x9f, 0x1e, 0xfa,
+        0xec, 0xc1, 0x45, 0xff, 0xf1, 0xce, 0xce, 0x7b, 0x24, 0x23, 0x0d, 0x0b, 0xb5, 0xf2, 0x71, 0x01,
+        0xcc, 0x45, 0x25, 0x8d, 0x71, 0x4c, 0x4c, 0x15, 0x00, 0x20, 0x75, 0xab, 0xcb, 0x62, 0xf8, 0x19,
+        0xd1, 0xe6, 0xba, 0x2d, 0x8d, 0x98, 0x0a, 0x6b, 0x15, 0xce, 0x2d, 0x08, 0x87, 0x91, 0x52, 0xc1,
+        0xc0, 0x15, 0xd2, 0x49, 0xfd, 0x92, 0x45, 0xb9, 0xbd, 0xc4, 0xec, 0x18, 0x6c, 0xea, 0x1b, 0x0b,
+        0x8b, 0xed, 0xba, 0x4a, 0x6a, 0xd3, 0xb4, 0x0e, 0x1a, 0xea, 0x4c, 0x67, 0x3e, 0x7f, 0xd2, 0xb6,
+        0x45, 0x7c, 0x68, 0x06, 0xa2, 0xdd, 0xc9, 0xe9, 0x5d, 0x46, 0xbc, 0xce, 0xcb, 0x4a, 0xf6, 0x72,
+        0x73, 0x08, 0x8f, 0x75, 0x47, 0x94, 0x43, 0x6e, 0x1e, 0x39, 0x69, 0x83, 0x8b, 0x88, 0x01, 0x0a,
+        0xd6, 0x15, 0x2d, 0xc4, 0xfd, 0x13, 0x2a, 0xad, 0x67, 0x11, 0xeb, 0x60, 0x6c, 0x95, 0x49, 0x9a,
+        0xdc, 0x02, 0x72, 0xa3, 0x6c, 0x1e, 0xc8, 0x20, 0x2f, 0x72, 0xdf, 0x32, 0xc6, 0x38, 0xaf, 0xa4,
+        0xd3, 0x75, 0x9e, 0xee, 0x75, 0xc5, 0x41, 0x6b, 0xdc, 0x1d, 0x75, 0x24, 0x11, 0xa8, 0x98, 0xfe,
+        0x6c, 0x94, 0x53, 0x53, 0x64, 0x6e, 0x29, 0xe1, 0x0f, 0x54, 0x53, 0x84, 0xb7, 0xa7, 0xf8, 0xd6,
+        0x79, 0xbf, 0xfd, 0xe2, 0x04, 0x3f, 0x8a, 0x7c, 0x3c, 0x73, 0xaa, 0xbb, 0x60, 0xfa, 0x38, 0x96,
+        0xc0, 0x30, 0x88, 0xcf, 0xb0, 0x16, 0xa4, 0xd1, 0x2c, 0x2a, 0x71, 0x3f, 0xb9, 0xd1, 0xfe, 0x52,
+        0x49, 0xb3, 0x46, 0x53, 0x86, 0xc3, 0xb0, 0xe1, 0xe5, 0x40, 0xf7, 0xd9, 0xfc, 0xc3, 0x8b, 0x95,
+        0xb3, 0x60, 0xdd, 0x7e, 0xfd, 0x08, 0x2b, 0xe8, 0x16, 0xab, 0x3c, 0xa5, 0xeb, 0x3e, 0x1d, 0xcd,
+        0xbb, 0xd4, 0xc6, 0xc8, 0x0b, 0x9b, 0x60, 0x3c, 0x54, 0x84, 0xb9, 0xcd, 0x05, 0x72, 0xd2, 0x25,
+        0xcf, 0x4e, 0x91, 0xef, 0x0c, 0x4b, 0x48, 0x15, 0x16, 0xd3, 0x36, 0x5f, 0xcb, 0x2b, 0x02, 0xe1,
+        0x03, 0x8f, 0x8d, 0x7b, 0x74, 0xa4, 0xf3, 0xa7, 0x22, 0x0d, 0x42, 0x09, 0x26, 0x2c, 0x12, 0xcf,
+        0xc8, 0x07, 0x69, 0x4f, 0xd3, 0xe1, 0x86, 0x88, 0x10, 0x07, 0x70, 0xe8, 0xbe, 0x46, 0x2f, 0xb3,
+        0xb1, 0x9a, 0x2b, 0xa5, 0xc2, 0x34, 0xf0, 0x44, 0xcf, 0x6a, 0xef, 0xef, 0x1b, 0x13, 0x2a, 0x3a,
+        0xad, 0x37, 0xaa, 0x93, 0xf1, 0xc5, 0x04, 0xfb, 0x0f, 0xe1, 0x0c, 0x59, 0x24, 0xb2, 0x6b, 0xd1,
+        0x21, 0xae, 0xe0, 0xe5, 0x73, 0xb3, 0xeb, 0x8d, 0x66, 0xe8, 0x69, 0x48, 0x96, 0x77, 0xdb, 0x69,
+        0xbc, 0x90, 0xb5, 0x0a, 0x7e, 0x66, 0x5e, 0xe0, 0x0f, 0x28, 0x7b, 0x7e, 0xd1, 0x15, 0x65, 0xc8,
+        0x7f, 0x48, 0x41, 0x32, 0xf1, 0xa4, 0x41, 0xcd, 0x0a, 0x40, 0xb2, 0x84, 0x1a, 0x3b, 0x84, 0x3c,
+        0x60, 0x60, 0x9a, 0x2c, 0x82, 0x8c, 0xc3, 0x02, 0x29, 0x46, 0x21, 0x84, 0x82, 0xec, 0xcb, 0xd0,
+        0x78, 0xf0, 0x97, 0x91, 0xeb, 0xe1, 0xf6, 0x27, 0x9c, 0xed, 0xf9, 0x39, 0x1d, 0x6e, 0x8a, 0x7f,
+        0xbf, 0x20, 0xc8, 0x9e, 0x72, 0xaf, 0xcf, 0x5f, 0x1d, 0xd2, 0xff, 0xd2, 0x1d, 0xa9, 0x02, 0x21,
+        0x63, 0x1f, 0x8c, 0x18, 0xcd, 0x70, 0x24, 0xfd, 0xc8, 0xc8, 0x04, 0xac, 0xed, 0x88, 0x22, 0xea,
+        0xba, 0x14, 0xfc, 0x0f, 0xb7, 0xa8, 0x8d, 0xcb, 0x0b, 0xae, 0xa0, 0x21, 0xb7, 0x58, 0x1a, 0xe3,
+        0x6e, 0xc8, 0x8e, 0xb5, 0xb8, 0x16, 0x1b, 0x73, 0x40, 0x20, 0x7d, 0x2e, 0x1e, 0xcd, 0x65, 0x16,
+        0xf6, 0x40, 0x5c, 0x5e, 0x9a, 0x06, 0x47, 0x9e, 0x04, 0xa8, 0xd0, 0xea, 0x0f, 0x90, 0x37, 0xed,
+        0x49, 0xe0, 0xe7, 0xd4, 0xbe, 0x93, 0xbb, 0x38, 0x99, 0x4f, 0x93, 0xda, 0xd9, 0x10, 0x9a, 0x16,
+        0xa4, 0x53, 0x41, 0x8b, 0x34, 0xde, 0x9d, 0x34, 0x12, 0xd8, 0xc5, 0x62, 0x2e, 0x5f, 0x75, 0x79,
+        0xaa, 0xcb, 0x94, 0xaf, 0x27, 0xe6, 0xdb, 0x56, 0xad, 0x0f, 0x2b, 0xb3, 0x02, 0xeb, 0x88, 0x29,
+        0xa1, 0x4d, 0xca, 0x3b, 0x7a, 0x3c, 0xe3, 0x64, 0x0c, 0x1a, 0x0d, 0x1a, 0xc4, 0x2f, 0xb2, 0x85,
+        0x8e, 0x03, 0x00, 0x2c, 0x8c, 0xee, 0x56, 0x5f, 0x10, 0xc1, 0x94, 0x7a, 0x7d, 0xfe, 0xef, 0xa2,
+        0x7b, 0x32, 0x03, 0x54, 0x54, 0x86, 0xc2, 0xb3, 0x03, 0x94, 0x01, 0x2f, 0xd7, 0xcd, 0x53, 0x53,
+        0x74, 0x3d, 0x2b, 0x40, 0xb9, 0xef, 0xdd, 0xbc, 0x8e, 0x2c, 0x81, 0x1a, 0x21, 0x27, 0x27, 0x3a,
+        0x00, 0x5e, 0x87, 0x9e, 0x58, 0xa4, 0xe8, 0x23, 0x07, 0xa9, 0xfd, 0x5e, 0xec, 0x9d, 0x37, 0xc9,
+        0xa5, 0xd4, 0xc0, 0xbd, 0xac, 0x87, 0x4c, 0xe3, 0x8b, 0x91, 0xdf, 0x78, 0xb7, 0x40, 0x11, 0xc4,
+        0x10, 0xef, 0x94, 0xf1, 0x11, 0xdc, 0x18, 0xd6, 0x0a, 0x97, 0x12, 0x82, 0x54, 0x60, 0xf3, 0xda,
+        0x3f, 0x90, 0xe0, 0x83, 0x40, 0x44, 0xd6, 0x5d, 0x15, 0x1a, 0xc4, 0x94, 0xc8, 0x38, 0xbb, 0x80,
+        0x4e, 0x21, 0x98, 0xf0, 0xae, 0xa2, 0x89, 0xc4, 0x2d, 0xba, 0xf0, 0x0a, 0x1a, 0x47, 0xa2, 0x17,
+        0x91, 0xc4, 0xc1, 0x5c, 0x65, 0x99, 0x41, 0xb9, 0xb3, 0x7b, 0xc9, 0x0d, 0xc5, 0xc5, 0xb0, 0xa2,
+        0xfa, 0x65, 0xb5, 0x51, 0x10, 0x8b, 0x6c, 0x87, 0x11, 0xf9, 0x57, 0x91, 0x93, 0x4d, 0x48, 0x99,
+        0x74, 0x74, 0x10, 0x51, 0xb0, 0xa3, 0x69, 0xc6, 0x56, 0x53, 0x6d, 0x27, 0x9c, 0x19, 0xb2, 0x87,
+        0x5a, 0xec, 0x84, 0xe9, 0x46, 0x8b, 0x43, 0xdc, 0x27, 0x28, 0xd2, 0x18, 0x18, 0x76, 0x49, 0x55,
+        0x51, 0x8c, 0xf9, 0x79, 0x64, 0x01, 0xbf, 0x8c, 0x21, 0xd3, 0x9d, 0xfe, 0x47, 0x26, 0x2c, 0xb7,
+        0xd8, 0x18, 0xc7, 0x4d, 0x25, 0x42, 0xcd, 0x28, 0x21, 0x5f, 0x78, 0xad, 0xd3, 0xa9, 0x8d, 0x66,
+        0x34, 0xef, 0x19, 0x5d, 0xc0, 0x5c, 0xbf, 0xfc, 0x1f, 0xf3, 0xa7, 0xf1, 0x9d, 0x6f, 0xa9, 0xa8,
+        0xbc, 0x29, 0xcd, 0xd7, 0x77, 0x32, 0xc4, 0x8a, 0x10, 0xa9, 0x45, 0xe8, 0x61, 0xe9, 0xd3, 0xe6,
+        0xca, 0xb6, 0x39, 0x65, 0xf1, 0x2b, 0x13, 0x3f, 0x47, 0x20, 0xb1, 0xda, 0x1b, 0x7c, 0x8f, 0x3e,
+        0x14, 0xc2, 0x31, 0x86, 0xc3, 0x89, 0xc7, 0x41, 0x21, 0x95, 0x4f, 0xee, 0x7b, 0xa2, 0x64, 0xaa,
+        0xe8, 0x56, 0xaa, 0x29, 0xe1, 0x15, 0x3a, 0xf3, 0x5d, 0x3f, 0x79, 0x3d, 0x5d, 0x27, 0x51, 0xa6,
+        0x80, 0x50, 0x0a, 0xeb, 0x5a, 0x18, 0xf6, 0x4b, 0x10, 0x20, 0x6e, 0xd6, 0xa7, 0x07, 0x14, 0x72,
+        0x1f, 0xc5, 0x69, 0x23, 0xd6, 0x88, 0x68, 0xfd, 0x26, 0x87, 0x7f, 0x80, 0x42, 0x77, 0x5b, 0xf6,
+        0xfa, 0xde, 0x4d, 0xfb, 0x4e, 0xdf, 0x4c, 0x0d, 0x0b, 0xbf, 0xba, 0xea, 0x3c, 0x47, 0xa7, 0xf4,
+        0x93, 0x94, 0x7b, 0xfb, 0x85, 0x6a, 0xd9, 0x91, 0x98, 0xc1, 0xd7, 0xe8, 0xe1, 0x64, 0x1c, 0x8c,
+        0xea, 0x1d, 0xea, 0xbb, 0x7f, 0xa0, 0x0b, 0xe0, 0x1f, 0xb1, 0xf0, 0xb5, 0x6a, 0x0a, 0x2e, 0x44,
+        0x35, 0x8c, 0x73, 0xea, 0x7d, 0x6e, 0xfe, 0xf2, 0xfd, 0x88, 0x1c, 0xc7, 0x79, 0x1b, 0xb0, 0xed,
+        0xf2, 0xf4, 0xac, 0xb8, 0x53, 0x91, 0xe1, 0x51, 0x13, 0xe7, 0xc2, 0xab, 0x31, 0xd9, 0xe3, 0x51,
+        0xd6, 0x36, 0x3d, 0x36, 0x83, 0xb4, 0xd8, 0xd6, 0x89, 0x98, 0xf1, 0x0f, 0xb5, 0x90, 0xc9, 0xfa,
+        0x1b, 0xd9, 0x2d, 0x60, 0x85, 0x64, 0xe3, 0x45, 0x2b, 0xf7, 0x29, 0x34, 0x7e, 0xf1, 0x46, 0x86,
+        0x06, 0x90, 0x89, 0x63, 0x54, 0x46, 0xec, 0xad, 0x54, 0x9d, 0xba, 0xde, 0xb2, 0xaf, 0x46, 0x9f,
+        0xda, 0xda, 0xc9, 0xb0, 0x85, 0xe9, 0x6b, 0x72, 0x13, 0x16, 0x65, 0x63, 0x34, 0xb3, 0x55, 0x36,
+        0x8c, 0x6c, 0x5f, 0xca, 0xf5, 0x03, 0xb1, 0x1e, 0x85, 0x33, 0x57, 0xa0, 0x64, 0x15, 0x70, 0xc6,
+        0x68, 0x01, 0x9a, 0x23, 0x37, 0xa6, 0xb8, 0xda, 0x0d, 0xca, 0xce, 0x81, 0x81, 0xd3, 0xa4, 0x0d,
+        0xe8, 0xe1, 0xba, 0xc2, 0x97, 0xa2, 0x5c, 0xbb, 0xd5, 0x06, 0x02, 0x24, 0x2b, 0x97, 0x26, 0xba,
+        0xfe, 0x9b, 0x93, 0x92, 0x2f, 0x9a, 0xa8, 0x93, 0x02, 0x84, 0x34, 0x4b, 0x26, 0x8b, 0x95, 0x6a,
+        0x1a, 0x09, 0xb8, 0x47, 0x1e, 0xcb, 0xfd, 0xf1, 0xc3, 0x88, 0x83, 0xbe, 0xb3, 0x58, 0x15, 0x8e,
+        0xab, 0x78, 0xcc, 0xab, 0xe9, 0x2b, 0x4f, 0x10, 0x1a, 0x5a, 0x31, 0x1f, 0x8c, 0xd4, 0xd3, 0x2f,
+        0xbf, 0x36, 0xa8, 0x0f, 0xd5, 0x63, 0xa8, 0x82, 0x76, 0x14, 0x09, 0x50, 0x5d, 0x85, 0x20, 0x78,
+        0xea, 0x8f, 0xcd, 0xd8, 0x14, 0xaa, 0x1f, 0x28, 0x12, 0x0a, 0x65, 0xf9, 0x14, 0x1f, 0x4f, 0x55,
+        0x1e, 0xaf, 0x41, 0xa0, 0xb5, 0x3e, 0x39, 0xc0, 0xe8, 0x51, 0x86, 0x0e, 0x0b, 0x2a, 0x32, 0x0e,
+        0xa9, 0xfe, 0x85, 0xe5, 0x5d, 0x61, 0x7b, 0xcc, 0x14, 0x12, 0x59, 0xfe, 0x0f, 0xbf, 0x4e, 0x8f,
+        0x78, 0xc2, 0x92, 0x65, 0xb7, 0xc5, 0x9a, 0xf1, 0x72, 0x37, 0xa9, 0x15, 0xca, 0x28, 0xa7, 0xf5,
+        0x54, 0xb9, 0x53, 0x6f, 0x91, 0xcf, 0x99, 0x43, 0x01, 0x2f, 0xba, 0xfe, 0xef, 0xf3, 0xcc, 0xf0,
+        0x17, 0x45, 0xba, 0xc1, 0xe1, 0xc9, 0xc0, 0xe8, 0x5f, 0xd9, 0x23, 0x17, 0x08, 0xe2, 0x22, 0xa0,
+        0xe4, 0x53, 0x1c, 0x2a, 0xac, 0x6e, 0xf6, 0xc2, 0x2e, 0x90, 0xb8, 0x82, 0x50, 0xfb, 0x5f, 0x1b,
+        0x79, 0x71, 0xae, 0x51, 0x99, 0x0d, 0x32, 0x17, 0xd0, 0x30, 0x81, 0xa0, 0xc0, 0x6b, 0x9b, 0x9e,
+        0xf4, 0xb5, 0xb1, 0x6f, 0x1f, 0x1b, 0x26, 0x38, 0x0a, 0x52, 0x20, 0xdc, 0x49, 0xef, 0x1d, 0x8e,
+        0x85, 0xfc, 0x52, 0x57, 0x5b, 0xf5, 0x36, 0xd4, 0x1f, 0x32, 0x75, 0x3f, 0xee, 0xb0, 0xf7, 0x63,
+        0x66, 0x9b, 0xf0, 0xbb, 0xfd, 0x3a, 0x26, 0x99, 0x0b, 0x3a, 0xce, 0x64, 0x43, 0x75, 0x36, 0xd4,
+        0xfa, 0x72, 0x55, 0xaa, 0x7e, 0x0c, 0x24, 0x00, 0xea, 0x4d, 0x4f, 0x7d, 0x91, 0x87, 0x57, 0xae,
+        0x71, 0x8d, 0xe2, 0x77, 0x6f, 0xa2, 0xd5, 0x0a, 0x11, 0x0e, 0x5d, 0x5e, 0x0a, 0x8c, 0xb5, 0x39,
+        0x6c, 0xfb, 0x94, 0x1d, 0x39, 0x23, 0x32, 0xfd, 0xf0, 0xdc, 0x36, 0x49, 0x13, 0xe8, 0x05, 0xa3,
+        0x15, 0xb8, 0x17, 0xe3, 0xa8, 0x8b, 0x75, 0x62, 0x0a, 0xcb, 0x04, 0xcd, 0x0e, 0x9a, 0x36, 0x2b,
+        0x46, 0x79, 0x06, 0x63, 0xf7, 0x74, 0xc1, 0xe6, 0x3b, 0x57, 0x6f, 0x03, 0xf1, 0x7a, 0xf5, 0x8d,
+        0xa6, 0x93, 0xd2, 0xe2, 0x68, 0x33, 0xc8, 0xce, 0x02, 0x9e, 0x4d, 0x1d, 0x77, 0x50, 0xdd, 0x42,
+        0xe8, 0x71, 0xce, 0xd2, 0xcb, 0x22, 0xd4, 0x06, 0xf3, 0x1e, 0xb4, 0xa9, 0x9a, 0x4f, 0x63, 0xbd,
+        0x2c, 0x5b, 0x6b, 0xcf, 0x23, 0x6d, 0x77, 0x9e, 0x1c, 0xbe, 0x0b, 0x25, 0xac, 0x71, 0x96, 0x61,
+        0x7d, 0x16, 0xef, 0xd8, 0x67, 0x19, 0xf1, 0x1d, 0xd2, 0x94, 0x71, 0x2c, 0xcd, 0xf6, 0x16, 0x14,
+        0x9e, 0x1b, 0xc1, 0x4e, 0x97, 0xed, 0xb6, 0x99, 0x12, 0x0b, 0x46, 0xf0, 0x73, 0x05, 0x18, 0x11,
+        0x12, 0xd8, 0xfa, 0x51, 0xde, 0xac, 0xdc, 0x18, 0x60, 0x07, 0x56, 0x86, 0x8f, 0x76, 0x5a, 0xfd,
+        0x76, 0x92, 0xfd, 0xf7, 0x55, 0xf0, 0x2f, 0x04, 0x2e, 0x30, 0xec, 0x58, 0x70, 0xe5, 0xaa, 0x7a,
+        0x5d, 0xa0, 0x83, 0x9d, 0xe2, 0x89, 0x35, 0x80, 0x8c, 0x99, 0xd0, 0xd5, 0xd6, 0x7f, 0x6b, 0x60,
+        0x07, 0x20, 0x08, 0xcb, 0xec, 0xf6, 0xca, 0x34, 0x1c, 0x0f, 0xdc, 0x3a, 0xa9, 0x0f, 0xb8, 0x7c,
+        0x76, 0x26, 0x43, 0x88, 0x61, 0x89, 0x7d, 0xd4, 0xf5, 0xd6, 0x8a, 0x83, 0x40, 0x04, 0xeb, 0x65,
+        0x36, 0x78, 0x75, 0xf7, 0x40, 0x42, 0x7a, 0xc2, 0x0b, 0xa3, 0xe2, 0xb5, 0xec, 0x91, 0x4e, 0x5d,
+        0xa8, 0x1f, 0x1b, 0xe9, 0xd3, 0x90, 0x1f, 0x23, 0x71, 0x4c, 0x67, 0x29, 0x58, 0xf3, 0x75, 0x70,
+        0x7c, 0xfe, 0xf8, 0xfb, 0x9f, 0xf2, 0x55, 0xd9, 0x2f, 0x53, 0x70, 0xa8, 0xd0, 0x3b, 0xae, 0x53,
+        0xb7, 0x6f, 0xdb, 0x6d, 0x26, 0xbe, 0x26, 0x30, 0x00, 0x03, 0xc4, 0x3c, 0xfe, 0xb9, 0x2e, 0x6b,
+        0x09, 0x7d, 0x98, 0xc4, 0xcd, 0xbd, 0xc1, 0x9a, 0x17, 0x9e, 0x49, 0xfa, 0x0b, 0x7d, 0x35, 0xa8,
+        0x69, 0xeb, 0xc7, 0x23, 0xdd, 0x42, 0x7e, 0xe6, 0x38, 0x38, 0x45, 0xf1, 0x12, 0xf0, 0xdf, 0xe8,
+        0x72, 0x77, 0x0e, 0x7e, 0xc2, 0x34, 0x82, 0xe0, 0x0d, 0x1e, 0x1f, 0x8c, 0xdb, 0x00, 0x89, 0xe2,
+        0xab, 0x43, 0x33, 0x19, 0xb9, 0x0f, 0x43, 0xa5, 0x84, 0xc3, 0x41, 0xee, 0x43, 0x52, 0x44, 0x24,
+        0xc5, 0x7c, 0xcd, 0x5b, 0x8a, 0x9b, 0xe8, 0x18, 0x18, 0xf5, 0x6b, 0x5d, 0x79, 0xdf, 0xbb, 0x32,
+        0xc6, 0xcb, 0xe4, 0xed, 0xa9, 0xa4, 0xad, 0x34, 0x49, 0xfa, 0x61, 0x7f, 0xab, 0x0f, 0x64, 0x11,
+        0xb2, 0x86, 0xab, 0x32, 0x0f, 0x6d, 0x95, 0x31, 0x1b, 0x9d, 0xaa, 0x17, 0xce, 0xa7, 0xa5, 0x78,
+        0xae, 0xc4, 0xb0, 0x2b, 0x70, 0x37, 0x7b, 0x30, 0xd9, 0xbb, 0x9e, 0xf7, 0xc9, 0xaf, 0x00, 0xe8,
+        0xa1, 0xd1, 0x82, 0x9c, 0xba, 0x86, 0x78, 0x6e, 0x16, 0x84, 0xea, 0xad, 0x4e, 0x4f, 0xb7, 0xa0,
+        0x5c, 0xf5, 0x73, 0x8d, 0xf8, 0x10, 0xfc, 0x41, 0xa0, 0xc6, 0x97, 0x90, 0xc6, 0xbd, 0x56, 0xde,
+        0x0f, 0x3b, 0x3b, 0x27, 0xf4, 0xdd, 0x20, 0x13, 0x19, 0x7f, 0xf2, 0xce, 0x2f, 0xc0, 0xec, 0xd6,
+        0xe8, 0xf2, 0x6f, 0xc9, 0x95, 0xd2, 0x8c, 0xf2, 0xbd, 0x93, 0x7c, 0x87, 0x69, 0xd1, 0x8a, 0xf4,
+        0x88, 0x13, 0xfb, 0xfc, 0x6a, 0x06, 0xa6, 0x29, 0x11, 0xb3, 0xb7, 0xc6, 0x37, 0xf2, 0xdd, 0xff,
+        0xdb, 0x26, 0x3c, 0x0d, 0x9d, 0xf4, 0x3b, 0xc2, 0x6b, 0x82, 0x9d, 0x95, 0x87, 0x93, 0xf4, 0xab,
+        0xf9, 0x67, 0x5a, 0x2c, 0x49, 0xfb, 0x0c, 0x74, 0x23, 0x8f, 0x82, 0xb5, 0xa4, 0xe0, 0xe3, 0xeb,
+        0xe0, 0x49, 0xcd, 0xea, 0x0b, 0x90, 0x02, 0x2d, 0x31, 0x10, 0x56, 0x51, 0xf8, 0xf2, 0x5d, 0xa7,
+        0xf8, 0xa7, 0x3c, 0xce, 0x9f, 0xc8, 0x9d, 0x84, 0x04, 0x31, 0xf5, 0x9c, 0x15, 0x61, 0x25, 0x70,
+        0x7f, 0xc8, 0x66, 0x38, 0x6b, 0x4f, 0xed, 0xd7, 0x0c, 0x1e, 0xe1, 0x12, 0xd2, 0x30, 0x69, 0x05,
+        0xea, 0x69, 0x25, 0x70, 0x1d, 0xf9, 0x12, 0x55, 0x05, 0x4a, 0x1c, 0x0d, 0xae, 0xdc, 0x6f, 0xc1,
+        0x50, 0x95, 0x61, 0xf4, 0x09, 0xf6, 0xb4, 0x58, 0x60, 0x3f, 0x21, 0x13, 0x2a, 0x2f, 0x92, 0xb1,
+        0xa3, 0x41, 0x39, 0x51, 0xab, 0x73, 0xcc, 0x20, 0x22, 0x99, 0xd3, 0xc7, 0x34, 0x06, 0x91, 0x6d,
+        0x2a, 0xc9, 0x8c, 0x95, 0x3d, 0x84, 0x46, 0x88, 0xc2, 0x26, 0x6f, 0xbd, 0x56, 0xe2, 0x92, 0x80,
+        0x6d, 0x64, 0xcf, 0x9d, 0x97, 0x63, 0x31, 0xc1, 0x1a, 0x65, 0xfc, 0x77, 0x36, 0xbe, 0x35, 0x68,
+        0xb2, 0xdd, 0x1b, 0x2b, 0x45, 0xf2, 0x5b, 0x18, 0xc1, 0xda, 0x93, 0x4c, 0xa0, 0x8f, 0xec, 0xf1,
+        0x1c, 0x44, 0x0e, 0x8e, 0xbe, 0xd9, 0xda, 0x3f, 0x28, 0x97, 0x25, 0xba, 0x85, 0x4c, 0xa8, 0x89,
+        0x8b, 0x9b, 0xd6, 0x95, 0x94, 0xe9, 0x3f, 0x5e, 0x40, 0x3f, 0x6f, 0x6d, 0x68, 0x67, 0xed, 0x40,
+        0xf7, 0x7b, 0x36, 0xa9, 0x91, 0x66, 0x1d, 0xc5, 0x09, 0x3d, 0x4c, 0x35, 0x82, 0x3b, 0xcc, 0x63,
+        0x90, 0x56, 0xb8, 0x3e, 0x8a, 0x8d, 0x81, 0x54, 0x55, 0x13, 0x54, 0xff, 0xa9, 0x28, 0xc9, 0xcf,
+        0xa8, 0x38, 0xf4, 0x17, 0xf3, 0x3b, 0x4e, 0xca, 0x02, 0x28, 0x18, 0x12, 0xee, 0x98, 0xd1, 0x6f,
+        0x80, 0x8f, 0x9b, 0x21, 0xb3, 0x6f, 0xc0, 0xfe, 0xf0, 0x75, 0xbb, 0x54, 0xfe, 0xb1, 0x4b, 0x76,
+        0x0e, 0xf7, 0x76, 0x8b, 0xa5, 0xac, 0x8d, 0x08, 0x07, 0x8e, 0xa9, 0x6f, 0x25, 0x95, 0xdc, 0xb1,
+        0x58, 0x66, 0xd6, 0x32, 0xba, 0x4e, 0x99, 0x50, 0xf9, 0x21, 0x4f, 0x6c, 0x8c, 0x60, 0xcd, 0x2e,
+        0xfa, 0x8f, 0xc6, 0xe6, 0x37, 0x32, 0x19, 0x26, 0x0f, 0x3c, 0xfe, 0x27, 0x17, 0x6c, 0x5f, 0x0a,
+        0xf8, 0xdd, 0xaf, 0x5e, 0x52, 0xa3, 0x04, 0x17, 0x31, 0xf9, 0xcb, 0x44, 0xb0, 0x51, 0x3d, 0xcc,
+        0x4b, 0x0a, 0xfd, 0x08, 0x5f, 0xf4, 0x5f, 0x4f, 0x11, 0x34, 0xb7, 0x71, 0x84, 0x4b, 0x65, 0xb8,
+        0xe3, 0xed, 0x9f, 0xd9, 0xc9, 0x1c, 0x79, 0xae, 0xb9, 0x91, 0x53, 0xb6, 0x12, 0x2f, 0xaa, 0x6f,
+        0x74, 0x16, 0x45, 0xbe, 0x1b, 0xe6, 0xc4, 0xde, 0x05, 0x3a, 0xe0, 0x42, 0x42, 0x4a, 0x95, 0x26,
+        0xd5, 0x18, 0x42, 0xd5, 0x4e, 0x3e, 0xc1, 0xac, 0x62, 0x2d, 0x2d, 0xe6, 0xb3, 0x0b, 0x6d, 0x70,
+        0xb7, 0xa6, 0x35, 0x6a, 0xc4, 0x40, 0xd3, 0x8d, 0x0f, 0x28, 0xb9, 0xf2, 0xdd, 0x4e, 0xca, 0x43,
+        0x55, 0xbc, 0x0e, 0xfa, 0x2d, 0x96, 0x4d, 0x75, 0xfc, 0x62, 0x3d, 0xb3, 0xef, 0xa5, 0x09, 0x52,
+        0xc8, 0xea, 0x44, 0xf8, 0x0b, 0xa7, 0x61, 0x6a, 0x00, 0xf3, 0x79, 0x38, 0xf8, 0xbb, 0xfc, 0xd2,
+        0x4e, 0xb0, 0x3b, 0x99, 0x44, 0xd1, 0x6d, 0xa5, 0x22, 0x0b, 0x72, 0x34, 0x6b, 0xd8, 0x97, 0x0e,
+        0x62, 0x34, 0xc4, 0x14, 0xac, 0x1a, 0x6f, 0x0b, 0x26, 0x8a, 0x3a, 0xf5, 0xa4, 0x58, 0x7c, 0x56,
+        0x9c, 0x8a, 0x34, 0x9b, 0xb0, 0xdb, 0xf4, 0xd5, 0x86, 0x15, 0xcd, 0x61, 0x51, 0x82, 0x32, 0xe7,
+        0xe3, 0xb4, 0x99, 0x04, 0xc6, 0xce, 0x41, 0xee, 0x23, 0xd1, 0x86, 0x97, 0xc6, 0x07, 0x9f, 0x63,
+        0x46, 0xe3, 0x04, 0x1d, 0xad, 0x2b, 0xfb, 0x9a, 0x5f, 0x21, 0xf5, 0x1f, 0x13, 0xac, 0xed, 0x1d,
+        0xc6, 0x11, 0x7c, 0xd9, 0xfe, 0xd8, 0x5e, 0xdf, 0x15, 0x7c, 0x60, 0xb6, 0x18, 0x44, 0x2f, 0x42,
+        0xdc, 0xc6, 0x14, 0xb9, 0x9d, 0x98, 0x66, 0x65, 0xae, 0xd2, 0xf8, 0x7f, 0xf8, 0x40, 0x68, 0x69,
+        0xed, 0xb4, 0x19, 0x51, 0x94, 0x09, 0x85, 0xee, 0x2b, 0x1a, 0x7b, 0xac, 0xff, 0x82, 0x47, 0xa5,
+        0xb4, 0x5d, 0x50, 0x91, 0x50, 0x80, 0x84, 0x69, 0x91, 0x94, 0x4f, 0xff, 0x28, 0x82, 0xb0, 0xc5,
+        0x3c, 0x17, 0x31, 0x7b, 0xdb, 0xe6, 0x81, 0xf4, 0x28, 0x1e, 0x4b, 0xd0, 0x48, 0xd9, 0x53, 0xd1,
+        0xda, 0x5e, 0x56, 0xb5, 0xa5, 0x3e, 0xb2, 0x90, 0x4f, 0x27, 0x1e, 0x20, 0x90, 0xfb, 0xab, 0x35,
+        0x43, 0x21, 0x53, 0x9e, 0x91, 0x1d, 0x90, 0xe9, 0x1e, 0x8c, 0xce, 0xb5, 0xb9, 0x6c, 0xc9, 0x18,
+        0x3a, 0x0e, 0x00, 0xd9, 0x85, 0x1e, 0x58, 0x4b, 0xea, 0x8d, 0xb6, 0x9d, 0x51, 0x6a, 0xa9, 0xfe,
+        0xfd, 0xb9, 0x8b, 0xbb, 0x51, 0xd1, 0x62, 0x21, 0x1b, 0x67, 0xb4, 0x43, 0x16, 0xe1, 0xc5, 0xea,
+        0x28, 0x24, 0x10, 0x08, 0x80, 0xd0, 0x81, 0x8a, 0x01, 0x5c, 0xe2, 0x9a, 0xfe, 0xc8, 0x44, 0x45,
+        0x93, 0xef, 0x97, 0x78, 0x8c, 0xe1, 0x20, 0x5a, 0x05, 0x65, 0x5a, 0x3d, 0xa0, 0x26, 0x7d, 0x8b,
+        0xfb, 0xde, 0x3b, 0x6a, 0x39, 0x3a, 0x4c, 0x21, 0x9e, 0xa8, 0xfe, 0x14, 0x3f, 0x32, 0x99, 0xc4,
+        0x76, 0xd1, 0xd1, 0x25, 0x9d, 0xa8, 0x39, 0x96, 0x22, 0x69, 0xfe, 0xf2, 0x4d, 0x4f, 0x30, 0x21,
+        0xc2, 0xbe, 0xd3, 0x4b, 0x78, 0xd8, 0xda, 0xbf, 0x65, 0x45, 0xdd, 0xd4, 0x3d, 0xcd, 0x73, 0x41,
+        0x25, 0x8c, 0xa9, 0x7d, 0x91, 0xd2, 0x31, 0xd6, 0x0e, 0xbe, 0xb5, 0x4a, 0x92, 0x17, 0x57, 0x5a,
+        0x40, 0xe6, 0x50, 0x48, 0xd2, 0xd1, 0x6e, 0x07, 0xb9, 0x6f, 0x2c, 0xe6, 0x9f, 0x2c, 0xe2, 0xee,
+        0x95, 0xdc, 0xf8, 0xb0, 0xcc, 0x7c, 0x32, 0xb0, 0x02, 0x05, 0x97, 0xb0, 0x56, 0x69, 0x3b, 0xcb,
+        0x49, 0x73, 0xf8, 0xf4, 0xea, 0xbf, 0xf2, 0x38, 0xff, 0x5a, 0x9a, 0xd3, 0xf3, 0x41, 0xe2, 0x57,
+        0xce, 0x8c, 0x8a, 0xf2, 0x3b, 0x34, 0x4a, 0x18, 0x1a, 0x89, 0x0a, 0x43, 0x0b, 0x5e, 0x85, 0x9d,
+        0xd9, 0x48, 0x25, 0x9f, 0xff, 0x2a, 0x29, 0xd4, 0x70, 0xef, 0xcb, 0xcf, 0x32, 0x8e, 0x3b, 0x66,
+        0x21, 0x60, 0xcf, 0x94, 0xa3, 0xe5, 0xa5, 0x82, 0x1e, 0xed, 0xdf, 0x47, 0x59, 0xa0, 0x73, 0x3e,
+        0xed, 0xfd, 0x5c, 0xa0, 0x1b, 0x47, 0xc3, 0xc5, 0x1d, 0x2e, 0xe6, 0xe7, 0x45, 0x29, 0x9b, 0x92,
+        0x88, 0x74, 0x27, 0xf2, 0xd1, 0x52, 0x00, 0x33, 0x1f, 0x76, 0x86, 0x2d, 0x8a, 0x29, 0xae, 0xde,
+        0xb0, 0xb6, 0x2c, 0x4b, 0x28, 0x20, 0x0d, 0x60, 0xd1, 0x92, 0x2d, 0xf8, 0x96, 0xa7, 0x3c, 0x1a,
+        0xc0, 0x46, 0xf7, 0x4a, 0x0a, 0x74, 0x9d, 0x96, 0x26, 0x95, 0x91, 0xc4, 0x1f, 0xd9, 0x10, 0x45,
+        0xcd, 0xb5, 0x69, 0xc7, 0x6a, 0x2d, 0x7b, 0x43, 0x7e, 0x5e, 0xa1, 0x85, 0xac, 0x97, 0xa2, 0x6f,
+        0x56, 0x47, 0xab, 0x59, 0xfe, 0xbc, 0xaf, 0x62, 0x10, 0x56, 0xc4, 0x7b, 0xbf, 0x02, 0xa9, 0x67,
+        0xf5, 0xcb, 0xfe, 0x8b, 0xdc, 0x7d, 0x26, 0x74, 0x22, 0xf3, 0x10, 0x94, 0x12, 0xde, 0x8b, 0x80,
+        0xb1, 0x7e, 0x74, 0x57, 0x65, 0x32, 0x67, 0xab, 0x06, 0x6d, 0xf0, 0xb1, 0x76, 0xa9, 0x5d, 0xcd,
+        0xaf, 0x02, 0xc5, 0xb7, 0x7b, 0xc5, 0xf8, 0x84, 0x75, 0x50, 0x2c, 0x86, 0x20, 0xa2, 0x77, 0xa0,
+        0x98, 0x0b, 0x4a, 0xfe, 0xbb, 0xa3, 0x09, 0x84, 0x00, 0x2b, 0x97, 0x9b, 0xcf, 0x0c, 0x9e, 0x91,
+        0x48, 0x25, 0x6f, 0x70, 0x53, 0xf2, 0x50, 0xb0, 0x89, 0xc9, 0x01, 0x53, 0x8b, 0x3d, 0x1b, 0xf8,
+        0xb6, 0x4e, 0xf1, 0x0a, 0x33, 0x34, 0xb6, 0x5a, 0x1d, 0xa8, 0x0a, 0x65, 0x55, 0x5d, 0x70, 0x90,
+        0x2a, 0x25, 0xe5, 0x72, 0x34, 0x13, 0xbb, 0xfc, 0xec, 0x96, 0xa6, 0xc3, 0x99, 0xdc, 0x7c, 0x80,
+        0x97, 0x98, 0x08, 0xf6, 0x09, 0x5e, 0x6a, 0x07, 0x1e, 0xf1, 0x78, 0xcc, 0xf0, 0x74, 0x05, 0x29,
+        0x0f, 0xe5, 0xe3, 0x22, 0x35, 0x55, 0x32, 0x79, 0x29, 0x18, 0x67, 0x4c, 0x9e, 0xed, 0x91, 0x6f,
+        0x25, 0xb2, 0x31, 0x1b, 0xe0, 0x0a, 0x59, 0xfa, 0x01, 0xb1, 0x64, 0x46, 0xc9, 0xf4, 0x31, 0xa5,
+        0x21, 0x53, 0x33, 0xda, 0xcb, 0x6c, 0x8d, 0x6b, 0x8e, 0x3c, 0x29, 0xb5, 0xe3, 0xe8, 0x02, 0xd5,
+        0x20, 0x61, 0x4c, 0xf9, 0x5b, 0x2e, 0x27, 0x80, 0x08, 0x10, 0xeb, 0x8b, 0x59, 0x7d, 0x5d, 0xc6,
+        0xf9, 0xad, 0xde, 0xc8, 0x5f, 0x1e, 0xe9, 0x2b, 0x20, 0xdd, 0xb8, 0x24, 0x3d, 0xe3, 0x69, 0x1f,
+        0x5f, 0xc1, 0xb1, 0x63, 0x67, 0xe3, 0x86, 0xde, 0x24, 0xf4, 0x38, 0xd0, 0xc0, 0x8b, 0x36, 0xed,
+        0xbc, 0x23, 0x0c, 0x8f, 0xb8, 0x4c, 0x99, 0xfe, 0x64, 0x90, 0x82, 0xb4, 0x7c, 0x40, 0x60, 0x2c,
+        0x06, 0x8e, 0xa2, 0xe3, 0xee, 0x90, 0xcd, 0x1a, 0x15, 0xd4, 0xb6, 0x14, 0xd9, 0x0c, 0xe1, 0xc4,
+        0x6b, 0xb1, 0x41, 0x99, 0x34, 0x55, 0x82, 0x50, 0x45, 0x58, 0x1e, 0xac, 0x66, 0x4f, 0x2b, 0xe8,
+        0xa3, 0xbc, 0x19, 0xb7, 0x43, 0xec, 0xfd, 0xcb, 0x14, 0xda, 0xd1, 0x91, 0x64, 0xcd, 0x94, 0x78,
+        0x89, 0x63, 0x37, 0x32, 0x50, 0x03, 0xb7, 0xf6, 0x94, 0x81, 0x27, 0x9e, 0xab, 0x77, 0x5b, 0xc0,
+        0xe5, 0xe0, 0x67, 0x26, 0x0c, 0x16, 0x86, 0x8b, 0x1c, 0x33, 0x9c, 0x4e, 0x5e, 0xe4, 0xb8, 0xcd,
+        0x47, 0xb4, 0x75, 0x0f, 0x8b, 0xd3, 0x65, 0x1b, 0xb0, 0xc6, 0x2f, 0x23, 0x61, 0xce, 0x86, 0x45,
+        0xc6, 0x38, 0x9c, 0xb0, 0x98, 0x02, 0x26, 0x2f, 0x20, 0xdf, 0xc2, 0x91, 0x35, 0x9b, 0x70, 0xa0,
+        0x39, 0x61, 0x3a, 0x64, 0x69, 0x65, 0xcf, 0xbd, 0x67, 0xea, 0x08, 0x5a, 0xce, 0x6f, 0xd4, 0x05,
+        0xcc, 0xef, 0xf4, 0x8e, 0xac, 0x55, 0x2d, 0x8c, 0x18, 0xc8, 0xe4, 0x2b, 0x87, 0x27, 0xf4, 0x75,
+        0x0a, 0xb7, 0xb8, 0xfb, 0x65, 0xef, 0xe2, 0x81, 0x01, 0xb6, 0xc2, 0x9f, 0xc9, 0x4d, 0x6e, 0x41,
+        0x06, 0xde, 0x79, 0x2e, 0xe6, 0x52, 0x49, 0x0a, 0x2e, 0xf4, 0x0e, 0xac, 0x54, 0x0a, 0x7f, 0x29,
+        0x74, 0xd7, 0xe5, 0xe8, 0x03, 0xc5, 0xa8, 0x7e, 0xdc, 0xde, 0x91, 0xa3, 0x81, 0x8a, 0x94, 0x55,
+        0x70, 0xe5, 0x7c, 0xa5, 0xb4, 0xde, 0xdf, 0x6f, 0x03, 0x38, 0x1f, 0x76, 0xbd, 0x0f, 0xca, 0x22,
+        0x6e, 0x62, 0xe1, 0xb7, 0xae, 0x24, 0xe9, 0x89, 0xab, 0x4d, 0xc3, 0xe9, 0x2b, 0x18, 0x6e, 0x13,
+        0x52, 0xfd, 0x91, 0x4e, 0xd5, 0x84, 0xc9, 0x15, 0x19, 0x14, 0x22, 0xcb, 0x10, 0x28, 0xef, 0x6f,
+        0xb0, 0xa2, 0xe6, 0x27, 0xf5, 0xfd, 0x1c, 0x46, 0x9e, 0x70, 0xde, 0x49, 0x2a, 0x32, 0xf6, 0x65,
+        0xba, 0x6b, 0x62, 0xe1, 0x2f, 0xba, 0x46, 0x22, 0x18, 0x3d, 0x66, 0x72, 0x53, 0x14, 0x55, 0x16,
+        0x61, 0x38, 0xa0, 0xfc, 0xec, 0x3e, 0xa4, 0xfd, 0x64, 0x04, 0x69, 0xed, 0xe6, 0xaf, 0xdf, 0xc4,
+        0x72, 0x10, 0x71, 0xc3, 0xb0, 0xc4, 0xa0, 0x4e, 0x21, 0x6b, 0xbd, 0x66, 0x7e, 0x9c, 0xf0, 0x27,
+        0xe0, 0x7c, 0x45, 0x37, 0xc5, 0x3c, 0xfe, 0x79, 0x99, 0x49, 0xe8, 0x36, 0xf4, 0xef, 0xb0, 0x44,
+        0xf5, 0x25, 0x3d, 0xb2, 0xf9, 0xc4, 0x32, 0x0e, 0x30, 0x8a, 0xef, 0x52, 0xf6, 0xc6, 0xe4, 0xb2,
+        0xd6, 0xc3, 0x70, 0x84, 0x38, 0xe3, 0x03, 0x46, 0xd4, 0x6e, 0x08, 0x3e, 0x4a, 0x0c, 0xfa, 0x0f,
+        0x6c, 0xfa, 0xc4, 0x8b, 0x72, 0xe1, 0x03, 0xf0, 0x2f, 0x71, 0x35, 0xf3, 0xfc, 0xc2, 0x16, 0x6b,
+        0x53, 0xaf, 0x95, 0x9e, 0x0d, 0x7c, 0x3d, 0x7a, 0x07, 0x9e, 0x92, 0x95, 0x94, 0x9e, 0x21, 0xc2,
+        0x60, 0x82, 0x6f, 0xaa, 0x01, 0x6d, 0xbc, 0xe4, 0x15, 0x74, 0x2a, 0xbb, 0xca, 0x07, 0xe4, 0xac,
+        0x10, 0xf8, 0x15, 0x4a, 0xea, 0x0d, 0xaa, 0x66, 0x1a, 0x36, 0x07, 0xe4, 0x7a, 0xc1, 0x8f, 0xaa,
+        0x6b, 0xc8, 0xf3, 0xfa, 0xbf, 0x7d, 0x95, 0xb8, 0x25, 0x38, 0x19, 0x39, 0xc9, 0x9e, 0xa5, 0x23,
+        0x4f, 0xc9, 0x56, 0xca, 0xf6, 0xba, 0xa8, 0x86, 0x3c, 0x38, 0xbb, 0x93, 0x8d, 0x57, 0x3f, 0x15,
+        0x17, 0xc6, 0x72, 0xf3, 0xc8, 0xd9, 0xe4, 0x06, 0x08, 0x80, 0xe1, 0x93, 0x4d, 0x6c, 0xa7, 0xc0,
+        0xd4, 0x34, 0x11, 0x50, 0xae, 0xb4, 0x5b, 0x9e, 0xe5, 0xa9, 0x53, 0x5a, 0xff, 0x1d, 0x71, 0x6e,
+        0x6e, 0x77, 0x33, 0xc0, 0x1e, 0xa9, 0xa4, 0x3f, 0x23, 0x72, 0xe5, 0x07, 0x0d, 0x57, 0x72, 0x91,
+        0xbb, 0x36, 0x6d, 0xde, 0x6a, 0xbf, 0xf3, 0xfb, 0x93, 0x23, 0x10, 0x54, 0xbd, 0xfb, 0xd0, 0xdf,
+        0x41, 0x26, 0x75, 0x4e, 0xcb, 0x73, 0xdb, 0x56, 0x2e, 0x5c, 0x67, 0x4b, 0xeb, 0x72, 0xe5, 0x38,
+        0xd1, 0x2c, 0x09, 0x34, 0xe7, 0xaa, 0x63, 0x29, 0x48, 0x69, 0x10, 0xee, 0xf0, 0x75, 0x5f, 0xbc,
+        0xd5, 0x2c, 0x22, 0xa7, 0x96, 0x66, 0x80, 0x78, 0x1d, 0x43, 0xdc, 0x43, 0x30, 0xbc, 0xa3, 0xc3,
+        0xf5, 0x6e, 0x92, 0x9b, 0x30, 0xd7, 0xb5, 0xb4, 0x7d, 0xe1, 0xc8, 0x16, 0x6b, 0x3a, 0xb2, 0x18,
+        0xf7, 0x28, 0x16, 0x60, 0xb0, 0xa8, 0xfa, 0xc9, 0x12, 0x24, 0xce, 0x73, 0x4e, 0x17, 0x1d, 0x17,
+        0xfa, 0xef, 0x08, 0xc8, 0xbf, 0x79, 0x90, 0xd9, 0x0a, 0xba, 0x0a, 0x8a, 0x8b, 0xe1, 0x17, 0x39,
+        0x3d, 0xeb, 0x18, 0x87, 0xd1, 0x5b, 0x6e, 0x84, 0x2d, 0xa8, 0xf9, 0xc4, 0x25, 0x6e, 0xb2, 0x6f,
+        0xe9, 0xbe, 0x05, 0xe3, 0xc9, 0x3a, 0x27, 0x71, 0x4d, 0x69, 0x9b, 0xb2, 0x3f, 0x8c, 0x5a, 0xf8,
+        0x33, 0xd1, 0xc2, 0x87, 0x6c, 0xb8, 0xed, 0x4d, 0x1d, 0xea, 0xbc, 0x89, 0x9f, 0xf9, 0xd7, 0x50,
+        0xff, 0xa8, 0x5e, 0xdc, 0xe6, 0x0b, 0xde, 0xa8, 0x03, 0x6c, 0xcd, 0xae, 0x47, 0x09, 0x6c, 0x29,
+        0xe6, 0x99, 0x6c, 0x6f, 0xe5, 0x16, 0x90, 0xb1, 0x26, 0xec, 0x96, 0xf6, 0xf9, 0x3a, 0xdc, 0x28,
+        0x06, 0xc3, 0x58, 0xd0, 0x68, 0xc7, 0x77, 0x2a, 0x38, 0x53, 0x15, 0x1c, 0x83, 0x84, 0x49, 0x27,
+        0x25, 0x79, 0x6d, 0xc5, 0x3b, 0xfe, 0x87, 0xe6, 0x0c, 0xff, 0xf0, 0xcd, 0xee, 0xcc, 0x7a, 0xdb,
+        0x7c, 0x1d, 0xb5, 0x3f, 0x4f, 0xea, 0x29, 0x57, 0x06, 0x36, 0xe8, 0x5c, 0x44, 0x25, 0xe2, 0xbb,
+        0xa4, 0xc1, 0xea, 0x5b, 0x95, 0x19, 0x5b, 0x7e, 0x25, 0x08, 0x7a, 0xd6, 0x74, 0x24, 0x89, 0xaf,
+        0x8d, 0xd1, 0xa4, 0x84, 0xf2, 0x7e, 0xf6, 0x4d, 0x91, 0xce, 0xeb, 0xf4, 0x75, 0x52, 0x68, 0xb7,
+        0x12, 0xd7, 0xdb, 0xc3, 0x7f, 0xfc, 0x3f, 0xf6, 0x0e, 0x7d, 0x2c, 0x02, 0xff, 0xfd, 0x6e, 0x75,
+        0xf3, 0xb3, 0x3b, 0x3a, 0x30, 0x86, 0x8f, 0x91, 0xd0, 0xd9, 0x04, 0xca, 0x8a, 0x90, 0x08, 0xce,
+        0x09, 0xbc, 0xbf, 0xe7, 0x34, 0x9c, 0xdf, 0x72, 0x1a, 0xcd, 0x3f, 0x2b, 0xec, 0xca, 0x58, 0x7c,
+        0x26, 0x1d, 0x34, 0xb1, 0x89, 0x45, 0x73, 0x60, 0xd4, 0x3d, 0x5b, 0x62, 0x2f, 0x32, 0x6f, 0x0c,
+        0x30, 0xd6, 0x22, 0xd5, 0xd7, 0x21, 0x44, 0x61, 0x25, 0xe1, 0xdc, 0xa6, 0x1b, 0x99, 0x6b, 0xf8,
+        0xeb, 0x56, 0x44, 0x5e, 0x10, 0x76, 0xdd, 0x7b, 0x78, 0x50, 0xac, 0x1a, 0x11, 0xc3, 0x18, 0x3f,
+        0xf0, 0xce, 0xbe, 0xeb, 0x06, 0x2f, 0xae, 0x75, 0x04, 0xf4, 0x62, 0x4c, 0x1d, 0x1b, 0xc0, 0xa7,
+        0xaa, 0xba, 0xf0, 0x2f, 0x10, 0x23, 0xcd, 0xad, 0xe4, 0xd4, 0x59, 0x1d, 0xcd, 0x13, 0xbc, 0xa6,
+        0xbe, 0xb8, 0x03, 0x40, 0xc3, 0x96, 0x6a, 0xb6, 0x1c, 0x7e, 0x1d, 0x89, 0x6e, 0x45, 0x2e, 0xd0,
+        0xd9, 0x67, 0x7b, 0x7d, 0x6b, 0x74, 0x4a, 0xfe, 0x49, 0x81, 0xb4, 0x5d, 0xb7, 0x23, 0xf1, 0xd6,
+        0x4e, 0x8b, 0xab, 0x21, 0xf8, 0x6e, 0x31, 0x5c, 0x1c, 0x4c, 0x5f, 0x77, 0x20, 0x5a, 0x18, 0xa3,
+        0x81, 0x8c, 0xc6, 0x7c, 0x0b, 0x0d, 0x7e, 0xf0, 0x18, 0x32, 0x67, 0x9c, 0xaa, 0x48, 0x84, 0x8f,
+        0xf1, 0xc7, 0x84, 0x15, 0xf6, 0x16, 0x17, 0x5f, 0x26, 0x37, 0x83, 0x0c, 0xf4, 0xc8, 0xb5, 0x23,
+        0x07, 0x1c, 0x40, 0x6a, 0xc9, 0xb4, 0xcb, 0x66, 0x0b, 0xf5, 0x7f, 0xe7, 0x6f, 0x42, 0xe6, 0x1a,
+        0xa3, 0xef, 0x77, 0x05, 0x3e, 0xfd, 0xc4, 0xa3, 0x0b, 0xeb, 0xbc, 0xde, 0xd6, 0xce, 0xd2, 0x2e,
+        0x47, 0x4f, 0x7b, 0x5d, 0x96, 0x0c, 0x11, 0x7b, 0x54, 0x43, 0xea, 0x42, 0x5d, 0x19, 0x31, 0xd4,
+        0xf7, 0x64, 0x88, 0x99, 0xb2, 0xdf, 0x21, 0xfc, 0x19, 0x37, 0xf8, 0x36, 0x39, 0x9a, 0x79, 0x7d,
+        0x55, 0xda, 0x7b, 0xe4, 0x78, 0xfd, 0xa6, 0xe0, 0x06, 0x31, 0x01, 0x9c, 0x27, 0x32, 0xc8, 0x5e,
+        0x91, 0xc8, 0xfa, 0x0b, 0x74, 0xdc, 0x83, 0x50, 0x26, 0xa2, 0x96, 0xcc, 0x70, 0xcb, 0xf2, 0xa3,
+        0xb6, 0x37, 0x8d, 0xb2, 0xb2, 0x5f, 0x4a, 0x6a, 0x8a, 0xb1, 0x13, 0xbd, 0xf7, 0xf8, 0x7b, 0x3c,
+        0xbd, 0xb6, 0xe9, 0x0a, 0x18, 0x20, 0x56, 0x6f, 0x0f, 0x66, 0xca, 0x98, 0x90, 0x9c, 0x63, 0xe2,
+        0xa1, 0x47, 0x55, 0x75, 0xdc, 0xfa, 0xa0, 0x5d, 0x00, 0xef, 0x2f, 0x80, 0x54, 0x68, 0x66, 0xee,
+        0x17, 0xe5, 0xf9, 0x72, 0xd2, 0xdc, 0xd8, 0x1b, 0x20, 0xc4, 0x42, 0x72, 0x66, 0xe2, 0x2d, 0x29,
+        0x20, 0xd9, 0x33, 0xba, 0x07, 0x5c, 0xc7, 0xae, 0x3d, 0x50, 0x97, 0xca, 0xdc, 0x23, 0xa3, 0xee,
+        0x36, 0xfa, 0x17, 0xa2, 0x96, 0xcd, 0x82, 0xdc, 0x2a, 0xac, 0xf9, 0xa3, 0xc1, 0x60, 0x4e, 0xff,
+        0x2a, 0x6d, 0xdb, 0xd8, 0xc5, 0x93, 0x86, 0x53, 0x15, 0x0d, 0x74, 0x4e, 0x48, 0xc1, 0x5a, 0xb2,
+        0xc4, 0x48, 0xaf, 0xb4, 0xc6, 0x86, 0x85, 0x8e, 0x2e, 0x54, 0x36, 0x56, 0x4f, 0x36, 0x72, 0x57,
+        0x91, 0xc1, 0xf2, 0xcb, 0x05, 0x38, 0x17, 0x8e, 0x3e, 0x05, 0x2a, 0xe5, 0x01, 0xf2, 0x26, 0x61,
+        0x75, 0xdb, 0x85, 0x81, 0xd2, 0x6e, 0x15, 0x17, 0x0d, 0x29, 0x49, 0xae, 0x9a, 0x26, 0x15, 0xe8,
+        0xb4, 0xd5, 0x71, 0x92, 0x45, 0x8a, 0xc0, 0xe7, 0xac, 0x5a, 0xce, 0xd3, 0xc8, 0xbb, 0xb0, 0xf6,
+        0xf4, 0xc6, 0x01, 0x4b, 0x2e, 0x14, 0x5d, 0x57, 0x2a, 0x61, 0xb5, 0x09, 0xa0, 0xb3, 0x2c, 0xd0,
+        0x50, 0x05, 0x05, 0x44, 0x50, 0x44, 0xaf, 0x22, 0x07, 0xa5, 0xf7, 0xe2, 0xa1, 0x54, 0x16, 0x36,
+        0x58, 0xcd, 0xdf, 0x4d, 0x00, 0x69, 0x3c, 0x23, 0x2c, 0x88, 0xd2, 0x1d, 0x63, 0xae, 0xe3, 0x32,
+        0x5d, 0xb5, 0x04, 0x0d, 0x9a, 0x2b, 0x3e, 0xdd, 0x48, 0xd2, 0x23, 0xca, 0xea, 0xa0, 0x37, 0x67,
+        0xb7, 0xb9, 0x25, 0x38, 0x1a, 0x2c, 0x64, 0xcf, 0x0d, 0x0e, 0xbb, 0xaf, 0x28, 0x9a, 0x07, 0x30,
+        0x5c, 0x5c, 0x76, 0x71, 0xf0, 0xcc, 0x32, 0xf2, 0x54, 0x73, 0x07, 0x69, 0xe7, 0xce, 0xa2, 0x1a,
+        0xe2, 0xe9, 0x38, 0x83, 0xc8, 0xa2, 0x2f, 0xce, 0x29, 0x0d, 0x38, 0x4a, 0xe8, 0x32, 0x3d, 0xb2,
+        0x2d, 0x7a, 0x7e, 0x78, 0x4e, 0x46, 0x54, 0xfa, 0x51, 0x31, 0x34, 0xbb, 0xd4, 0xf6, 0xc9, 0x74,
+        0x31, 0xd8, 0xfc, 0x0d, 0x11, 0xe0, 0xd9, 0xc0, 0x27, 0xf7, 0x86, 0x34, 0x2f, 0x46, 0x75, 0x87,
+        0xce, 0x4a, 0xe0, 0x7f, 0xbf, 0xbe, 0xf2, 0x4c, 0x9b, 0x8e, 0x0d, 0x3b, 0x77, 0x30, 0xfe, 0xa0,
+        0xd5, 0x29, 0x88, 0x4e, 0xaf, 0xe1, 0x1d, 0xed, 0x09, 0xef, 0x8c, 0x63, 0x60, 0xed, 0x5e, 0x29,
+        0xdf, 0xb4, 0x6c, 0xd7, 0xe0, 0x3a, 0x3f, 0x01, 0xbb, 0xcd, 0x36, 0x85, 0x51, 0xeb, 0x92, 0x50,
+        0xe3, 0x10, 0xed, 0x62, 0x06, 0xb7, 0x87, 0xe1, 0x06, 0x36, 0x14, 0x7c, 0x40, 0xc3, 0xb1, 0x36,
+        0x9f, 0x4b, 0x3e, 0xc5, 0x6a, 0x3b, 0x48, 0x3a, 0x72, 0xc9, 0x97, 0x7f, 0xf0, 0x92, 0xeb, 0x6e,
+        0xfe, 0x70, 0xa1, 0xd4, 0x58, 0x2c, 0x6b, 0xe4, 0x09, 0xd4, 0xc3, 0x01, 0x0d, 0x6f, 0x4c, 0xdf,
+        0x7f, 0xbe, 0x3d, 0x16, 0xe8, 0xc6, 0x83, 0x0d, 0x44, 0x23, 0x34, 0x8d, 0x9d, 0x2d, 0x6c, 0xf4,
+        0xd3, 0x18, 0xcf, 0x89, 0x3b, 0xa5, 0x67, 0x7e, 0x29, 0x72, 0xb9, 0x83, 0x67, 0xd3, 0x35, 0xb9,
+        0x83, 0xcf, 0x6a, 0xdd, 0x01, 0x56, 0x8b, 0x5c, 0x6a, 0xf2, 0x87, 0x46, 0x9c, 0x7f, 0x38, 0x05,
+        0x7b, 0x69, 0xe1, 0x72, 0x73, 0x31, 0x12, 0x4a, 0x14, 0xbc, 0x6e, 0x1d, 0x8d, 0x07, 0x6a, 0x44,
+        0x29, 0x1e, 0xa6, 0xf5, 0x5a, 0x34, 0x90, 0xf2, 0x99, 0x31, 0x60, 0x09, 0x9d, 0xa3, 0x7a, 0x60,
+        0xf9, 0x9d, 0x14, 0x9b, 0x45, 0x1e, 0x3a, 0x0e, 0x22, 0xc4, 0xe6, 0x49, 0xb8, 0x07, 0xdd, 0x86,
+        0xc0, 0xac, 0x41, 0x55, 0x61, 0xa5, 0x02, 0xf1, 0xbc, 0x88, 0xf3, 0x5b, 0x00, 0xd1, 0x96, 0xbc,
+        0x02, 0x96, 0xe8, 0x14, 0x02, 0xb0, 0xd5, 0xf3, 0x2c, 0x68, 0xf5, 0xd4, 0xad, 0xa9, 0xda, 0x69,
+        0xf0, 0xcb, 0x7c, 0x79, 0x1d, 0xfd, 0x1b, 0x74, 0x46, 0x96, 0x10, 0x45, 0x33, 0xb5, 0xf5, 0xb2,
+        0xe6, 0xe4, 0x55, 0x40, 0xcb, 0x76, 0x2c, 0xfc, 0x1e, 0x47, 0x0b, 0x23, 0x99, 0xce, 0x51, 0x5c,
+        0xe2, 0x2e, 0x36, 0x48, 0xaa, 0x5a, 0x4d, 0xac, 0xa3, 0xa9, 0xa2, 0xf6, 0x45, 0xf8, 0x74, 0x77,
+        0x8b, 0xf5, 0x84, 0x3f, 0x0e, 0x2d, 0x78, 0x2d, 0x10, 0x95, 0x94, 0x53, 0xf9, 0xee, 0x86, 0xb3,
+        0xde, 0x3e, 0xd2, 0xf1, 0x23, 0x0f, 0xae, 0x79, 0x1e, 0xe3, 0x05, 0xb8, 0x5e, 0x14, 0xd6, 0xf8,
+        0xd8, 0x4d, 0x89, 0xb3, 0x3d, 0xc7, 0xe2, 0x64, 0x09, 0x20, 0x7a, 0x6e, 0x8a, 0xa9, 0xb2, 0x62,
+        0xd6, 0x06, 0x80, 0x9e, 0xfd, 0x99, 0x37, 0x8b, 0xec, 0xa2, 0xeb, 0x90, 0xeb, 0xcc, 0xcf, 0xe9,
+        0x8a, 0x47, 0xfb, 0x7f, 0x63, 0x06, 0xe6, 0x53, 0x25, 0xb8, 0xda, 0x9f, 0x18, 0xd6, 0xc9, 0x60,
+        0xf7, 0x29, 0x3d, 0x3d, 0x80, 0xb1, 0x22, 0xd2, 0x30, 0xd7, 0xa2, 0x06, 0x8e, 0xe2, 0xdc, 0x10,
+        0xdc, 0x57, 0x29, 0xb9, 0x5d, 0x65, 0xff, 0xa4, 0x2c, 0xcf, 0x42, 0x04, 0xfa, 0x6e, 0x1e, 0x72,
+        0x5c, 0xcb, 0x03, 0xb3, 0xc9, 0x99, 0x7f, 0x2f, 0xfe, 0x58, 0x20, 0x9e, 0xe1, 0x90, 0x95, 0x46,
+        0xc6, 0x93, 0xd3, 0x03, 0xb0, 0x34, 0x9d, 0x27, 0x0a, 0x7c, 0xe1, 0x9b, 0x5e, 0x7a, 0xd0, 0x96,
+        0xf5, 0xd1, 0x02, 0x74, 0x0c, 0x34, 0x2c, 0x1f, 0xb0, 0x05, 0x09, 0x10, 0x94, 0x01, 0x92, 0x3b,
+        0x22, 0x70, 0xb1, 0x58, 0xca, 0x71, 0x0c, 0x7f, 0x12, 0xe2, 0x84, 0xc4, 0x4b, 0x07, 0x0d, 0xd0,
+        0xe0, 0xd1, 0xb5, 0xf4, 0xb7, 0x58, 0xb3, 0xa6, 0xb9, 0x7f, 0x5f, 0xab, 0x35, 0x18, 0x51, 0x87,
+        0x6d, 0x18, 0x9f, 0x3f, 0x70, 0x17, 0x85, 0x72, 0x26, 0xe9, 0xe5, 0x60, 0x8b, 0xff, 0x74, 0x6a,
+        0x17, 0x56, 0xea, 0xb0, 0xe8, 0xbf, 0xef, 0x41, 0xaf, 0xda, 0x50, 0x23, 0x1c, 0xcf, 0x50, 0x40,
+        0x48, 0x1f, 0x06, 0xb0, 0xed, 0x73, 0xbc, 0x9b, 0x03, 0x5e, 0x70, 0xad, 0xcf, 0x5d, 0x1a, 0xbe,
+        0xc9, 0x68, 0x64, 0x83, 0x58, 0x81, 0xd5, 0xcb, 0x71, 0xef, 0x62, 0xc6, 0x4f, 0xc5, 0x99, 0x67,
+        0x64, 0xf7, 0xfe, 0x4d, 0x7d, 0xdd, 0x1f, 0x14, 0x2f, 0x54, 0x23, 0xd8, 0xae, 0xd9, 0x9e, 0x0d,
+        0x2b, 0x2b, 0x66, 0xa2, 0x33, 0xfb, 0x15, 0x3f, 0x31, 0x23, 0xcb, 0x28, 0xd8, 0x9e, 0x47, 0xec,
+        0xfe, 0xea, 0xc1, 0xef, 0x14, 0xe3, 0x49, 0xbe, 0x07, 0x35, 0x84, 0x72, 0x78, 0x02, 0x3e, 0x9b,
+        0xf3, 0xc8, 0xd4, 0x6d, 0x0b, 0x99, 0xdd, 0x2f, 0x82, 0x0f, 0x33, 0x80, 0x78, 0xf8, 0x85, 0xa4,
+        0x91, 0xe8, 0x29, 0x28, 0xf4, 0xbc, 0x1e, 0xd3, 0x20, 0x5f, 0x83, 0x68, 0x90, 0x30, 0xc9, 0x18,
+        0xf9, 0x01, 0xd9, 0x84, 0x7b, 0x1c, 0xce, 0x59, 0x60, 0xf0, 0x6f, 0xf1, 0x5f, 0x0e, 0x4b, 0x3c,
+        0x62, 0xf7, 0x51, 0x5c, 0x67, 0x4f, 0x29, 0x60, 0x1f, 0x93, 0x39, 0x2a, 0xbb, 0xb2, 0x51, 0xb8,
+        0x4c, 0x5e, 0xfc, 0xad, 0x8a, 0xfc, 0x7f, 0xad, 0x4f, 0x2b, 0x0e, 0x42, 0xda, 0x9c, 0xc7, 0x91,
+        0xca, 0x7a, 0xd9, 0xbc, 0xc3, 0xd3, 0xab, 0x1d, 0x11, 0x11, 0xe5, 0x66, 0x6e, 0x8e, 0x6d, 0xea,
+        0x00, 0x76, 0xcc, 0xcd, 0x91, 0xfa, 0xbe, 0x1b, 0xb9, 0xe9, 0xb2, 0x0a, 0x2f, 0x98, 0x4d, 0x62,
+        0xf5, 0x58, 0xdc, 0x18, 0x51, 0x1c, 0x30, 0xec, 0x08, 0x8b, 0x5b, 0x15, 0x3c, 0x29, 0x29, 0xed,
+        0xad, 0xd2, 0x15, 0xd1, 0x93, 0x96, 0x17, 0xcf, 0x2b, 0xd9, 0x4b, 0x5d, 0xf0, 0x6c, 0x80, 0x0d,
+        0xa1, 0xa7, 0xec, 0xd9, 0x70, 0xef, 0x1a, 0x05, 0x2c, 0xac, 0xab, 0x69, 0xc2, 0x42, 0xd5, 0x8f,
+        0xe6, 0xea, 0xcd, 0x9d, 0x52, 0xa5, 0xb3, 0xcc, 0xc9, 0x81, 0xf5, 0xc9, 0x65, 0x28, 0xb0, 0x54,
+        0x76, 0x4e, 0x1d, 0x58, 0xa0, 0x09, 0x9e, 0xf8, 0x02, 0xbd, 0x78, 0x9a, 0x7c, 0x6c, 0xc7, 0xe0,
+        0x59, 0x6e, 0x9c, 0xd3, 0x32, 0xed, 0x88, 0x36, 0x6d, 0xbb, 0x4f, 0xd6, 0x8f, 0x18, 0xc4, 0xa8,
+        0x14, 0xf9, 0x77, 0x06, 0x77, 0xea, 0x97, 0x0d, 0x09, 0x4a, 0x2b, 0x52, 0xa1, 0x21, 0xf6, 0x65,
+        0x12, 0x6f, 0xf6, 0x69, 0x24, 0x03, 0xf8, 0xa8, 0x93, 0x1c, 0xc6, 0x59, 0x3d, 0x64, 0x46, 0x08,
+        0x88, 0x4a, 0xa9, 0x59, 0xc2, 0xbd, 0xfa, 0x66, 0x04, 0x4f, 0x50, 0x22, 0x7f, 0x3a, 0xcd, 0x03,
+        0x85, 0x2d, 0x6b, 0x22, 0xc3, 0x26, 0xc4, 0xe9, 0xeb, 0x5a, 0xa6, 0x74, 0xad, 0x83, 0x29, 0xda,
+        0x00, 0x7f, 0xd2, 0x64, 0x34, 0x88, 0xbe, 0xc1, 0x13, 0x19, 0xcb, 0x28, 0xe1, 0x29, 0x97, 0x4d,
+        0xa3, 0x5e, 0xcc, 0x99, 0x4c, 0x5d, 0x2e, 0xa0, 0x65, 0x7f, 0xbd, 0x69, 0xc4, 0x60, 0x0d, 0x09,
+        0x9d, 0x7c, 0x8c, 0x9d, 0x31, 0x99, 0xbb, 0xc2, 0x12, 0x29, 0x7d, 0x34, 0x95, 0x7b, 0xb9, 0x39,
+        0x76, 0x4b, 0xdf, 0x5f, 0x6e, 0x95, 0x14, 0xc2, 0x7d, 0x5c, 0x4f, 0xca, 0x32, 0xd2, 0xe3, 0xc8,
+        0x7e, 0x1d, 0xe0, 0x2d, 0x9a, 0x1c, 0xcf, 0x99, 0x25, 0x59, 0xf4, 0xa7, 0xc6, 0xff, 0x08, 0x90,
+        0xeb, 0xe4, 0x29, 0x5d, 0xd5, 0x61, 0xdd, 0x1c, 0x5c, 0xd5, 0x84, 0x73, 0x6f, 0x47, 0xda, 0x7d,
+        0xe8, 0x8f, 0x89, 0xe5, 0xf3, 0x39, 0xbc, 0x6c, 0x2b, 0x30, 0xc8, 0xe8, 0xde, 0xb0, 0xa5, 0x73,
+        0x7a, 0x21, 0xdb, 0x78, 0x2b, 0x45, 0xb7, 0x19, 0x14, 0xd5, 0x5c, 0x66, 0xe5, 0xd5, 0x80, 0x75,
+        0xbf, 0x73, 0xf7, 0x31, 0x1d, 0xaf, 0xec, 0x32, 0x20, 0xcf, 0x69, 0x78, 0xef, 0x0c, 0x59, 0x8f,
+        0xee, 0xef, 0xbb, 0x6e, 0xaa, 0x58, 0xa4, 0x0a, 0x6f, 0xd8, 0x66, 0x71, 0xd1, 0xab, 0x3d, 0x60,
+        0x58, 0xe3, 0xf6, 0x23, 0x7f, 0x50, 0x1f, 0x4c, 0x2e, 0xeb, 0xbb, 0x6f, 0xb9, 0x2a, 0x70, 0x13,
+        0x97, 0x9b, 0x5c, 0x56, 0x44, 0xc4, 0x5c, 0xca, 0xf4, 0x58, 0xe0, 0xef, 0xf7, 0x48, 0xa1, 0x8a,
+        0x0b, 0x80, 0x66, 0x0a, 0x44, 0xf0, 0x9a, 0xc4, 0x1e, 0x77, 0x99, 0xcb, 0x04, 0xd8, 0x1b, 0xa8,
+        0xff, 0x85, 0x38, 0xa9, 0x1b, 0x6b, 0x4e, 0xcc, 0x21, 0x83, 0x32, 0x66, 0x22, 0x89, 0x06, 0x25,
+        0xdd, 0x17, 0xc3, 0x65, 0x71, 0xc0, 0x6c, 0x21, 0x11, 0xb1, 0x42, 0x80, 0xe2, 0xe9, 0x14, 0xd5,
+        0x0f, 0xfb, 0xf1, 0xa7, 0xe9, 0xde, 0xf5, 0xd0, 0xaf, 0x27, 0x43, 0xc3, 0xbd, 0x70, 0x10, 0xa4,
+        0xd0, 0xdc, 0x22, 0x19, 0x6f, 0x93, 0x5f, 0x5d, 0x0f, 0xc4, 0x21, 0xa6, 0x2b, 0x30, 0x6c, 0xac,
+        0x53, 0x2d, 0xb7, 0x6b, 0x7c, 0x8c, 0x89, 0x17, 0xf0, 0x98, 0xd9, 0x3d, 0x63, 0xf4, 0x1c, 0x57,
+        0xd4, 0xb7, 0x8b, 0x40, 0x9b, 0x30, 0xf7, 0x6a, 0x0a, 0x33, 0x10, 0x84, 0xc0, 0x58, 0x50, 0xaa,
+        0xa4, 0x72, 0xf4, 0xd3, 0xb6, 0x7b, 0x48, 0x45, 0x73, 0x68, 0x07, 0xbb, 0x96, 0xbc, 0xff, 0x66,
+        0x7c, 0x4a, 0x75, 0x47, 0x6d, 0xf9, 0xed, 0x4c, 0x13, 0x88, 0xc9, 0x07, 0x03, 0x07, 0x2d, 0x28,
+        0xf2, 0xdf, 0xfa, 0x39, 0x06, 0x9d, 0x24, 0xbd, 0x2b, 0x87, 0xf3, 0x97, 0x12, 0x01, 0x27, 0xdf,
+        0xe4, 0xc5, 0xfc, 0x73, 0x07, 0xa9, 0x69, 0xd3, 0x25, 0x45, 0xaf, 0x41, 0x63, 0xe3, 0x12, 0xea,
+        0x6c, 0xb6, 0x77, 0x6a, 0x1d, 0x8c, 0x53, 0x0c, 0x1f, 0xd9, 0x8d, 0x7b, 0x0e, 0x95, 0xc0, 0xc2,
+        0xee, 0x9b, 0x07, 0x7f, 0x02, 0x57, 0x78, 0x01, 0x04, 0x80, 0x22, 0x60, 0xe9, 0x9c, 0xe3, 0xf2,
+        0xb8, 0x29, 0xf6, 0x33, 0x53, 0x8a, 0x82, 0x4a, 0x3c, 0x1b, 0xd1, 0xfc, 0xfb, 0xe4, 0x3f, 0xf9,
+        0x2b, 0xb7, 0x2a, 0xb5, 0x5b, 0x1b, 0x66, 0xd5, 0x29, 0x04, 0x89, 0x72, 0x82, 0x27, 0xc9, 0xdd,
+        0x09, 0x07, 0x31, 0x8d, 0x4f, 0xf6, 0xbd, 0x9c, 0x74, 0x8f, 0x9d, 0xa5, 0x64, 0xa1, 0x44, 0x21,
+        0xb2, 0x42, 0x84, 0x8c, 0x44, 0x10, 0xba, 0x28, 0x00, 0xb0, 0x83, 0x0c, 0xfe, 0xd7, 0x46, 0x33,
+        0x31, 0x2e, 0x82, 0x35, 0xd8, 0x30, 0x26, 0x5c, 0x05, 0xf1, 0xc3, 0x4d, 0x43, 0x37, 0x0e, 0xcc,
+        0x5d, 0x83, 0x37, 0xc9, 0x7c, 0x0f, 0x5a, 0x55, 0x2a, 0xf7, 0x4f, 0xa0, 0xc7, 0xc8, 0xf8, 0x3a,
+        0x42, 0x30, 0x0b, 0xe6, 0x31, 0x13, 0x28, 0x17, 0x33, 0xcf, 0xc2, 0x70, 0xe0, 0xdc, 0x07, 0xa8,
+        0x2b, 0x70, 0x20, 0xe4, 0xbe, 0x45, 0x00, 0xf0, 0x05, 0xfb, 0x7e, 0xee, 0xa6, 0x70, 0xe4, 0x84,
+        0x6b, 0xc4, 0x4b, 0xba, 0x10, 0x17, 0xe7, 0x54, 0x36, 0x24, 0x06, 0x55, 0x17, 0x1a, 0xa9, 0x37,
+        0x17, 0xca, 0xc1, 0x26, 0x4c, 0x55, 0x7c, 0x38, 0x1b, 0x25, 0x09, 0x8d, 0x27, 0x57, 0xb1, 0x78,
+        0xe3, 0xdf, 0xf4, 0x81, 0xb0, 0x79, 0x5a, 0x95, 0x64, 0x8b, 0x25, 0xbf, 0x47, 0xf8, 0x94, 0x90,
+        0xba, 0x7d, 0x24, 0xa4, 0xb9, 0xd8, 0xc4, 0x2a, 0x02, 0xe3, 0x52, 0x3e, 0xee, 0xa7, 0x9a, 0xb6,
+        0x1b, 0x35, 0x74, 0xd5, 0xd9, 0xd9, 0x93, 0xd4, 0xa6, 0x19, 0xef, 0x7a, 0x88, 0x48, 0x56, 0x81,
+        0xd4, 0x6d, 0x24, 0x59, 0x2d, 0xdd, 0x2b, 0x4e, 0x23, 0xa3, 0x45, 0xa0, 0xa8, 0x38, 0x7d, 0x68,
+        0xe2, 0xac, 0x45, 0x25, 0x66, 0x47, 0xab, 0x99, 0x7d, 0x28, 0xbb, 0xfb, 0xc7, 0x20, 0x5f, 0x41,
+        0x28, 0xbd, 0x36, 0x4b, 0x6c, 0xb4, 0x07, 0x93, 0x26, 0x34, 0xf1, 0x71, 0xb8, 0xea, 0xc0, 0x08,
+        0x4b, 0x7a, 0xf1, 0x23, 0xca, 0x00, 0xfd, 0xde, 0x48, 0xb6, 0x57, 0x61, 0x2f, 0x3a, 0x85, 0x67,
+        0x72, 0xe2, 0xd0, 0xaa, 0xc1, 0xfb, 0xf2, 0xf0, 0x14, 0x84, 0x03, 0xd4, 0xd8, 0x67, 0x5d, 0xcb,
+        0xec, 0x32, 0x5a, 0x37, 0xf1, 0x19, 0xa8, 0xf8, 0xb0, 0x8a, 0x9b, 0x8b, 0xcb, 0xd2, 0x49, 0xd0,
+        0xc1, 0x8c, 0x2b, 0x6d, 0xde, 0xf7, 0xac, 0xaf, 0x14, 0x36, 0x58, 0x1d, 0x66, 0x02, 0x88, 0x39,
+        0x44, 0x9f, 0x77, 0xea, 0x1b, 0x92, 0x05, 0xcf, 0xf9, 0x1b, 0x5d, 0xcc, 0x11, 0x1c, 0xf8, 0x94,
+        0x38, 0x2f, 0xf6, 0x3f, 0xa8, 0xd8, 0x35, 0x93, 0x27, 0xdc, 0x7b, 0xfa, 0xe5, 0x58, 0x7c, 0x69,
+        0x3d, 0xa6, 0xe4, 0x6b, 0xa9, 0x0c, 0x11, 0x81, 0x1d, 0x49, 0x71, 0x8e, 0xc8, 0xb4, 0xed, 0x38,
+        0xe4, 0x23, 0xe2, 0x17, 0x0e, 0xc9, 0x17, 0x5a, 0x10, 0xc7, 0xe5, 0x3f, 0x0b, 0x36, 0x62, 0xcc,
+        0xba, 0x99, 0x1e, 0xe2, 0x15, 0xc2, 0x7c, 0xe5, 0xa2, 0xb0, 0x82, 0x9e, 0x69, 0xc6, 0x15, 0x46,
+        0xef, 0x41, 0x62, 0xf7, 0x29, 0x7c, 0xa8, 0xc4, 0x23, 0xb3, 0x85, 0xa8, 0xf7, 0xf2, 0x67, 0x37,
+        0x07, 0x4d, 0x33, 0x88, 0x3c, 0x84, 0x04, 0x6a, 0x63, 0x31, 0xb0, 0x53, 0x59, 0xfd, 0xc3, 0x8a,
+        0x93, 0x20, 0x3b, 0xf3, 0x2b, 0xe9, 0xfc, 0x25, 0x20, 0xb5, 0xf8, 0xeb, 0xb5, 0x7f, 0x70, 0xc2,
+        0xb2, 0xa6, 0x78, 0x9a, 0x78, 0x38, 0x3c, 0x20, 0xb2, 0xe4, 0x3a, 0xc9, 0x9e, 0x2b, 0x3e, 0x67,
+        0x76, 0x4b, 0x38, 0x39, 0x85, 0xee, 0x4e, 0xd1, 0x22, 0x3e, 0x95, 0xce, 0xf1, 0x91, 0x22, 0x15,
+        0x47, 0xd1, 0xe5, 0xe5, 0x03, 0xf9, 0x75, 0xea, 0xf6, 0x8b, 0x9d, 0xec, 0x55, 0x15, 0x6a, 0x2e,
+        0x8f, 0x5c, 0x94, 0x84, 0xbe, 0xeb, 0x13, 0x82, 0x0a, 0x22, 0xc0, 0xf4, 0x1a, 0x0a, 0x74, 0xd9,
+        0x86, 0x00, 0xfb, 0x49, 0xc5, 0x24, 0x64, 0xc7, 0x2c, 0xd6, 0xb9, 0xbe, 0x95, 0xe6, 0x51, 0x9d,
+        0x75, 0x35, 0xde, 0xfe, 0x8a, 0x44, 0xa1, 0x0b, 0x10, 0xdf, 0x6e, 0xf9, 0x16, 0x4d, 0x8b, 0x88,
+        0x89, 0x6e, 0xb5, 0x2f, 0x65, 0xc0, 0x2e, 0x90, 0x81, 0x84, 0x6c, 0x37, 0x5d, 0x59, 0x64, 0xa3,
+        0x71, 0xa3, 0x8c, 0x43, 0x3d, 0x2b, 0x32, 0xdf, 0x06, 0x6f, 0x6a, 0xda, 0x0d, 0xc4, 0x96, 0xe0,
+        0x42, 0x88, 0x65, 0xbd, 0x65, 0xf4, 0xf7, 0x3f, 0xc7, 0x7d, 0x6b, 0x4a, 0x39, 0xce, 0x65, 0x7f,
+        0x14, 0xe5, 0xc2, 0x0f, 0xa1, 0x57, 0x7b, 0xd1, 0x0b, 0x19, 0x90, 0x5a, 0x41, 0xc7, 0x4c, 0x28,
+        0x93, 0xa3, 0x80, 0x57, 0x2b, 0xf6, 0xcf, 0x3d, 0x89, 0x58, 0x55, 0x7e, 0xca, 0x5b, 0x9c, 0xa6,
+        0xbb, 0xf6, 0x89, 0xe1, 0xd3, 0x60, 0x3b, 0x1f, 0x09, 0x52, 0x15, 0x38, 0x7c, 0xe9, 0x7a, 0xaf,
+        0x82, 0xbd, 0x12, 0xf6, 0x2f, 0xb1, 0x09, 0x27, 0xbd, 0x38, 0xc9, 0x41, 0x4d, 0xc3, 0x83, 0x13,
+        0x02, 0xd2, 0x3a, 0x6e, 0xfd, 0xbe, 0x4c, 0x2f, 0x23, 0xcb, 0xa3, 0x47, 0x63, 0xeb, 0x53, 0xf8,
+        0x7c, 0xbc, 0x80, 0x12, 0x96, 0xaf, 0x9b, 0xcf, 0xb7, 0x3a, 0xcb, 0x22, 0x08, 0xe2, 0x34, 0xa7,
+        0x19, 0xb0, 0x53, 0x62, 0x30, 0x86, 0x55, 0x86, 0x17, 0x6d, 0xfb, 0xed, 0xaf, 0x76, 0x3e, 0x02,
+        0xc9, 0xf0, 0x6c, 0x17, 0x32, 0x9f, 0x86, 0xff, 0x72, 0x5f, 0xf4, 0xf2, 0x84, 0x67, 0x74, 0xd2,
+        0xa1, 0x33, 0x3f, 0x62, 0x74, 0x62, 0x11, 0x53, 0x23, 0xc1, 0x58, 0xdb, 0xbd, 0x88, 0x1b, 0xe3,
+        0x69, 0x21, 0x36, 0xe5, 0xbc, 0x61, 0x03, 0xc2, 0x9b, 0xeb, 0x75, 0x58, 0xf1, 0x7f, 0x54, 0xe7,
+        0x0e, 0xb3, 0x2d, 0x45, 0x11, 0x7a, 0x99, 0xfc, 0x18, 0xe3, 0xdb, 0x49, 0xfb, 0xea, 0x42, 0x2b,
+        0x48, 0x3d, 0x84, 0x11, 0x88, 0x7a, 0x99, 0x88, 0x14, 0x0a, 0xdd, 0x51, 0x31, 0x64, 0xd4, 0x2d,
+        0xe3, 0x17, 0xbe, 0xae, 0xe4, 0x86, 0x4b, 0xe0, 0x04, 0xc0, 0xa3, 0x5a, 0xef, 0x36, 0xbe, 0x23,
+        0xe7, 0xfd, 0xf3, 0x8f, 0x02, 0x93, 0x51, 0x78, 0xc6, 0xf9, 0x3c, 0x2d, 0x09, 0xb8, 0x11, 0xb6,
+        0xe9, 0xe4, 0x20, 0x66, 0x0f, 0x1b, 0xd8, 0x26, 0x12, 0xf7, 0x19, 0xf4, 0x6d, 0x6e, 0x96, 0x79,
+        0x42, 0xf8, 0xfa, 0x0d, 0x62, 0xa6, 0x72, 0x08, 0xbd, 0x05, 0x41, 0x68, 0xb7, 0x41, 0x5e, 0xc2,
+        0x30, 0x88, 0x92, 0x84, 0x2d, 0xe1, 0xb5, 0xc8, 0x2b, 0x2f, 0xcf, 0x26, 0x3d, 0x87, 0x88, 0x42,
+        0x41, 0xb4, 0xd6, 0x3c, 0xad, 0x71, 0xd7, 0xec, 0xd5, 0x90, 0xfb, 0x8a, 0xc3, 0xc5, 0x8f, 0x8a,
+        0x26, 0xc7, 0xac, 0x7a, 0xae, 0xb6, 0x76, 0x78, 0x02, 0x71, 0x83, 0xfb, 0xda, 0xc2, 0xe5, 0xe5,
+        0xa6, 0x9d, 0xec, 0x66, 0x74, 0x4d, 0x23, 0x12, 0x5f, 0xae, 0x4b, 0xff, 0x04, 0x41, 0x15, 0x2d,
+        0x1a, 0xe5, 0x1d, 0xfb, 0xca, 0x9e, 0x76, 0x1c, 0x14, 0xb3, 0x7b, 0xe8, 0xc5, 0xc6, 0x25, 0x40,
+        0xfc, 0x5d, 0x12, 0xe3, 0x34, 0xad, 0x72, 0x11, 0x73, 0xb4, 0xc0, 0x90, 0xcc, 0xd8, 0x26, 0x0f,
+        0xb6, 0x82, 0x8e, 0xbe, 0x75, 0x6f, 0xd1, 0xc8, 0x26, 0x0e, 0x8f, 0x6b, 0x8e, 0x9b, 0x80, 0x43,
+        0xa0, 0x98, 0x35, 0x9c, 0x50, 0x6e, 0x09, 0x8c, 0x3f, 0xa7, 0x18, 0x77, 0x5b, 0x1f, 0xde, 0xbd,
+        0x7a, 0x59, 0xa3, 0xb5, 0x72, 0xe2, 0x01, 0x7c, 0x1c, 0x59, 0xf6, 0x72, 0x91, 0x9f, 0x20, 0xf6,
+        0x23, 0xb0, 0x4e, 0x75, 0xd2, 0x38, 0x48, 0x05, 0xff, 0x51, 0xad, 0x3a, 0x34, 0xc6, 0xe0, 0x9b,
+        0x21, 0x31, 0x39, 0x8e, 0x40, 0x76, 0xa2, 0x8c, 0x13, 0xb6, 0xf6, 0xe3, 0x38, 0xe8, 0x3a, 0xe7,
+        0x33, 0xf5, 0x1d, 0xc1, 0x71, 0x02, 0xaa, 0xc5, 0xc4, 0x1d, 0x09, 0x4e, 0xf1, 0xe1, 0x40, 0x14,
+        0x7c, 0x6b, 0x04, 0x82, 0x22, 0x4d, 0x37, 0x02, 0x2b, 0xa8, 0xa1, 0x70, 0x01, 0xcc, 0x07, 0xba,
+        0x22, 0x44, 0xd7, 0x2f, 0xd7, 0x5e, 0xb0, 0x46, 0xf6, 0xf2, 0x11, 0x1a, 0xad, 0x37, 0xe2, 0xc8,
+        0xb5, 0x35, 0x12, 0x15, 0x9a, 0x1c, 0x1c, 0xd5, 0x09, 0x36, 0x57, 0x28, 0x77, 0xa8, 0xf5, 0xf1,
+        0xfc, 0x67, 0x9b, 0x0b, 0x55, 0x39, 0x82, 0x76, 0x28, 0x84, 0xa2, 0xa9, 0xa4, 0xd9, 0xab, 0x8a,
+        0x12, 0x67, 0xa9, 0x48, 0x2e, 0x5c, 0x20, 0xb0, 0x04, 0x98, 0x16, 0xf6, 0x1b, 0xf3, 0x6c, 0xfd,
+        0xdf, 0xb1, 0xb0, 0xd1, 0x01, 0x35, 0xc8, 0x1d, 0xc6, 0x15, 0x29, 0x9c, 0x85, 0xce, 0x81, 0x4c,
+        0xc0, 0x9d, 0x60, 0xcb, 0xa5, 0x56, 0xbc, 0xab, 0x06, 0x5e, 0xfd, 0x51, 0xbd, 0x38, 0x25, 0x9a,
+        0x25, 0x2d, 0x1c, 0x9a, 0xe3, 0xf8, 0x94, 0x7b, 0x59, 0xd7, 0xbd, 0xac, 0x74, 0x0f, 0x1e, 0xa8,
+        0x5c, 0xff, 0x08, 0x5e, 0x0b, 0x95, 0x7b, 0x4c, 0x19, 0xfe, 0x1b, 0x8d, 0x0b, 0xfc, 0x58, 0x59,
+        0x65, 0x8f, 0x44, 0xf4, 0x93, 0xde, 0x99, 0x0a, 0x5a, 0xb7, 0x78, 0x72, 0x0e, 0x7a, 0x57, 0x11,
+        0xce, 0xec, 0x7f, 0xcf, 0x7f, 0x3c, 0x57, 0x55, 0x1f, 0xb1, 0x2e, 0xc7, 0xb4, 0xd8, 0x87, 0x0c,
+        0x98, 0x28, 0x23, 0x64, 0x2d, 0x97, 0x4f, 0x6a, 0xc8, 0xbe, 0x6b, 0x57, 0x2b, 0xed, 0x84, 0x48,
+        0x08, 0xbe, 0x78, 0xd7, 0x07, 0xc2, 0x9f, 0x24, 0x2a, 0x54, 0xaf, 0xb4, 0x6b, 0x63, 0xa9, 0x96,
+        0xed, 0xa1, 0x24, 0x70, 0x49, 0xf7, 0x2c, 0x06, 0xa8, 0x3e, 0x40, 0xc4, 0xd3, 0xf8, 0x01, 0x7c,
+        0x99, 0x5f, 0xb5, 0x44, 0x32, 0x4f, 0x13, 0x3a, 0x26, 0x8e, 0x8d, 0xa5, 0xec, 0xc4, 0x06, 0x47,
+        0xf7, 0x01, 0x05, 0x58, 0x62, 0xb4, 0x1a, 0x04, 0x67, 0x02, 0xa6, 0x5a, 0x58, 0xfd, 0xf9, 0xe1,
+        0x3d, 0x5c, 0x1b, 0x23, 0x54, 0x3f, 0xa5, 0x37, 0x27, 0xb4, 0xf8, 0x4e, 0x98, 0x0b, 0x7e, 0xcd,
+        0xf9, 0x2a, 0xf1, 0x85, 0xe9, 0x10, 0x78, 0x30, 0x18, 0x40, 0xbc, 0x32, 0xfd, 0x59, 0x06, 0x52,
+        0xc8, 0x68, 0x24, 0x09, 0x9a, 0xec, 0xd7, 0x26, 0x21, 0x4c, 0xed, 0xba, 0x51, 0xf7, 0x95, 0xd6,
+        0x76, 0xcf, 0xe2, 0x6d, 0xa4, 0xad, 0x0d, 0x6b, 0xb2, 0xee, 0x66, 0xa8, 0xd5, 0x9c, 0x31, 0x56,
+        0x16, 0x43, 0x3d, 0xa7, 0x63, 0xc7, 0x13, 0x5a, 0x1e, 0x0d, 0x64, 0x43, 0x81, 0x69, 0x25, 0x2f,
+        0x1f, 0xfc, 0xff, 0xad, 0xf1, 0x35, 0xf8, 0xd0, 0xbc, 0xa2, 0x57, 0xb9, 0x95, 0xc2, 0xc4, 0x7e,
+        0xdb, 0x20, 0x3a, 0x42, 0x88, 0xa5, 0x75, 0x6b, 0x15, 0x75, 0xc8, 0x03, 0x93, 0x1f, 0x54, 0xa8,
+        0xf7, 0xd7, 0x69, 0x22, 0x4c, 0xe3, 0xa9, 0xfc, 0xe1, 0x3f, 0xee, 0x9e, 0x65, 0x00, 0xc9, 0x73,
+        0xe3, 0xb8, 0x22, 0x51, 0xde, 0xfa, 0x28, 0x60, 0x13, 0x60, 0x82, 0x14, 0x04, 0x3a, 0xc0, 0xdd,
+        0x33, 0x19, 0x4d, 0x5d, 0xea, 0x3d, 0x6b, 0xbd, 0x75, 0xe9, 0xa3, 0xb6, 0x3d, 0x88, 0xf7, 0xd0,
+        0xdc, 0x1c, 0x47, 0xe8, 0x93, 0xa9, 0x8f, 0xae, 0x15, 0xf1, 0x40, 0x89, 0x6d, 0x40, 0xbd, 0xb7,
+        0x6b, 0x30, 0x65, 0x90, 0x31, 0xa5, 0xc7, 0xf4, 0x97, 0x8c, 0x10, 0x2d, 0xab, 0x16, 0x80, 0x5d,
+        0xde, 0x06, 0xf5, 0x98, 0x9c, 0x77, 0x58, 0xdd, 0x1c, 0xc4, 0xe1, 0x36, 0x37, 0x43, 0x28, 0x7a,
+        0x31, 0xf8, 0xbc, 0x6d, 0x26, 0x4d, 0x73, 0xbe, 0x7a, 0xba, 0x88, 0x26, 0x87, 0x94, 0x3e, 0x92,
+        0xb1, 0x24, 0xe6, 0xba, 0x90, 0xd8, 0x13, 0x85, 0x1d, 0xe0, 0xe7, 0x42, 0x6b, 0xa3, 0x6b, 0x1d,
+        0x99, 0x7f, 0xa5, 0xc6, 0x8f, 0x52, 0x31, 0x09, 0xc9, 0x91, 0x4f, 0x78, 0x19, 0xb3, 0x3c, 0xb2,
+        0x63, 0xa8, 0x5b, 0x66, 0x13, 0x15, 0xcc, 0xf6, 0x18, 0x68, 0xb7, 0xdd, 0xb8, 0x55, 0x91, 0x82,
+        0x30, 0xe9, 0xaa, 0xa6, 0x00, 0x89, 0xa0, 0x8a, 0x60, 0x65, 0xcd, 0xb6, 0x0b, 0x22, 0x8f, 0x52,
+        0x99, 0x4e, 0x40, 0xf1, 0x7c, 0xc0, 0x78, 0x3a, 0x1f, 0xf2, 0x45, 0xe0, 0x4c, 0xb2, 0xa3, 0x8b,
+        0xca, 0x12, 0xc9, 0xce, 0x87, 0x9e, 0x62, 0xa2, 0xeb, 0x1f, 0x79, 0x50, 0x52, 0xff, 0xae, 0x4b,
+        0x9d, 0x3f, 0xb8, 0x71, 0xf1, 0xd1, 0x4f, 0x71, 0x0b, 0x0e, 0x3a, 0x3c, 0xcf, 0x37, 0x82, 0x20,
+        0x82, 0xc9, 0x7b, 0x86, 0xdd, 0x6d, 0xe2, 0x3b, 0x3d, 0x1b, 0x62, 0x90, 0xaf, 0x2a, 0xcf, 0xd4,
+        0x23, 0xce, 0x77, 0x08, 0xcf, 0x18, 0x9b, 0x6b, 0x11, 0x15, 0xc0, 0x4b, 0x02, 0xbf, 0x20, 0xea,
+        0x0d, 0x29, 0xe6, 0x1c, 0xf9, 0x78, 0xaa, 0x70, 0x43, 0x0b, 0xd9, 0x83, 0x80, 0x66, 0xda, 0x0c,
+        0x57, 0xf9, 0xf9, 0xb8, 0xaf, 0x46, 0xe3, 0xc7, 0x27, 0x0f, 0xf6, 0x37, 0x88, 0x17, 0x9a, 0xdb,
+        0x4e, 0xf6, 0xc4, 0x2c, 0x92, 0xa6, 0x6b, 0x41, 0x28, 0x88, 0x16, 0x9a, 0x81, 0x5e, 0x7e, 0xd5,
+        0xa8, 0x08, 0x5b, 0x59, 0xb6, 0xd2, 0x78, 0x8b, 0x1a, 0x1d, 0x6a, 0xab, 0x82, 0xed, 0xce, 0x7a,
+        0x40, 0xd6, 0x36, 0x31, 0xfb, 0x52, 0x5b, 0xa7, 0x2c, 0x84, 0x7b, 0xdd, 0x4b, 0x49, 0x38, 0x96,
+        0xe8, 0xa0, 0x84, 0x44, 0x8b, 0x71, 0xc1, 0x92, 0x1c, 0xdb, 0xd1, 0x4b, 0xf1, 0x15, 0x35, 0x32,
+        0x81, 0x94, 0x1f, 0x48, 0xb3, 0xda, 0x9c, 0xdc, 0x68, 0x82, 0xf0, 0x5e, 0xa0, 0x23, 0xdc, 0xc9,
+        0x07, 0x97, 0xe6, 0x5c, 0xbb, 0x8a, 0x36, 0x9e, 0x12, 0x38, 0x18, 0x68, 0x70, 0x91, 0x70, 0xbd,
+        0xb5, 0x4a, 0x23, 0x82, 0x6e, 0xa1, 0xa8, 0xf5, 0x91, 0x8b, 0x5e, 0x0d, 0x7f, 0x35, 0x6b, 0x0e,
+        0x45, 0x2b, 0x83, 0x57, 0xc8, 0x4a, 0xf8, 0x55, 0x13, 0x91, 0x1d, 0x55, 0xd9, 0xbb, 0x32, 0x75,
+        0x04, 0xaa, 0xd3, 0xb3, 0x5c, 0x19, 0xf2, 0xd3, 0x04, 0xf8, 0xaf, 0x34, 0x94, 0x37, 0xba, 0x42,
+        0x1b, 0x28, 0x02, 0xa5, 0xc5, 0x22, 0xcb, 0xe4, 0x13, 0xec, 0x5f, 0x46, 0xc4, 0xf5, 0x98, 0x34,
+        0xd7, 0xaa, 0x1e, 0x83, 0xee, 0x64, 0xdb, 0x9b, 0xbd, 0x2e, 0xae, 0x3f, 0x1e, 0xf4, 0x63, 0xc0,
+        0x97, 0xa8, 0x60, 0x0c, 0x08, 0x30, 0x75, 0x9b, 0x0c, 0xe0, 0xbf, 0x7b, 0x7a, 0x11, 0xb3, 0x77,
+        0xbb, 0xd1, 0x32, 0x30, 0xb5, 0x66, 0xbc, 0xf0, 0x5f, 0x5d, 0xa1, 0x8b, 0x58, 0x1f, 0x5c, 0x07,
+        0x68, 0xe9, 0xe0, 0x72, 0xe9, 0x8a, 0xcc, 0x2b, 0x08, 0x38, 0xd0, 0x79, 0x94, 0x5b, 0x68, 0x41,
+        0x64, 0xab, 0x95, 0x49, 0x1f, 0x6a, 0xfb, 0x4c, 0x39, 0xca, 0xe2, 0xab, 0xd6, 0xaf, 0xd1, 0x5b,
+        0x4d, 0xca, 0xff, 0xc1, 0xa8, 0x4a, 0x04, 0xf4, 0x27, 0xc3, 0x5a, 0x44, 0xee, 0xb4, 0xcc, 0x4d,
+        0x64, 0x7b, 0xcc, 0xae, 0x82, 0xa8, 0x8a, 0x28, 0xc8, 0xd2, 0x21, 0x9f, 0x68, 0xef, 0x7b, 0x07,
+        0x27, 0x00, 0x78, 0x5d, 0x86, 0x66, 0xab, 0xd3, 0x16, 0xc1, 0xee, 0xbb, 0x23, 0x5d, 0x83, 0x6b,
+        0xa7, 0xc7, 0x9f, 0x9a, 0xa3, 0xa8, 0x9e, 0x5e, 0xe3, 0x21, 0xe4, 0x49, 0xcf, 0x0a, 0xf4, 0x66,
+        0x56, 0x5e, 0x99, 0x7b, 0x33, 0x6b, 0x0a, 0x56, 0x1c, 0xab, 0x73, 0xcd, 0x9b, 0x74, 0x77, 0x98,
+        0x6a, 0xa5, 0x4c, 0xce, 0xa1, 0x96, 0x5d, 0x06, 0xef, 0xfb, 0x34, 0x27, 0xb7, 0x81, 0xfe, 0xaf,
+        0x2f, 0x83, 0xab, 0x6c, 0x7e, 0x71, 0xa4, 0x05, 0x19, 0xf0, 0x26, 0xd5, 0xcc, 0x3b, 0xd8, 0xa6,
+        0x0a, 0x07, 0xcc, 0x69, 0x19, 0x00, 0xe1, 0x2a, 0x83, 0xd1, 0x36, 0x57, 0x62, 0xc8, 0xad, 0xbc,
+        0x78, 0xa9, 0x62, 0x75, 0x31, 0x68, 0x05, 0xea, 0x12, 0xa7, 0xb1, 0x9d, 0x5f, 0x6b, 0xc4, 0x36,
+        0x27, 0x71, 0xd6, 0x19, 0x5f, 0x65, 0xf6, 0xa8, 0xf1, 0x7b, 0x4f, 0xc0, 0xd4, 0x12, 0x60, 0x4e,
+        0xd1, 0x5b, 0xf6, 0x48, 0xd2, 0xd4, 0x73, 0x25, 0x19, 0x2b, 0xbe, 0x75, 0x92, 0x18, 0x19, 0x6a,
+        0x47, 0x44, 0xd4, 0xc6, 0xa7, 0xdd, 0x51, 0x2b, 0x43, 0x27, 0xb5, 0xfd, 0x8e, 0x67, 0x8b, 0x92,
+        0x9e, 0x2c, 0x98, 0x41, 0x40, 0x25, 0x7f, 0x26, 0x24, 0x2f, 0x2a, 0x84, 0x6a, 0x06, 0xcc, 0x2b,
+        0xdb, 0x9a, 0x78, 0xce, 0xd5, 0x84, 0x3e, 0xb4, 0xd6, 0x74, 0x63, 0xab, 0xbb, 0xa8, 0x15, 0xa2,
+        0xe2, 0xa6, 0xae, 0x65, 0x84, 0x6a, 0xcd, 0x4c, 0x07, 0x0f, 0x78, 0x13, 0xff, 0xfb, 0x14, 0xc0,
+        0x35, 0x08, 0x31, 0x61, 0x8e, 0x21, 0xa7, 0xe1, 0x6f, 0x7e, 0x60, 0x9a, 0x1a, 0x92, 0x3f, 0x9c,
+        0x7e, 0x9c, 0x43, 0x39, 0x49, 0x32, 0x61, 0x7c, 0x23, 0x2a, 0xdf, 0xdf, 0x83, 0x53, 0x7c, 0x79,
+        0x5d, 0xb1, 0x73, 0x0b, 0x7c, 0xd5, 0x4a, 0xe3, 0xbe, 0x43, 0x69, 0x47, 0x40, 0x66, 0x34, 0xcf,
+        0x62, 0x6d, 0x21, 0xd9, 0x67, 0xc9, 0x30, 0x00, 0x27, 0x98, 0x7f, 0x41, 0x42, 0x72, 0xe7, 0xb0,
+        0x30, 0x09, 0xc0, 0x9a, 0xe0, 0xc9, 0xda, 0xae, 0x38, 0x36, 0x9c, 0xf8, 0x96, 0x9c, 0x7f, 0x7e,
+        0xbc, 0xf4, 0x7d, 0x48, 0x77, 0x42, 0xb7, 0xe4, 0x05, 0xd3, 0x9f, 0x96, 0x70, 0x28, 0x12, 0xcc,
+        0x38, 0xa0, 0x60, 0xd0, 0xa5, 0xcf, 0x73, 0x2f, 0x71, 0xb3, 0xf5, 0x73, 0x60, 0x3d, 0xc6, 0x08,
+        0x39, 0xd1, 0xa6, 0xfb, 0x91, 0xcc, 0xf0, 0x24, 0x21, 0xa3, 0xd9, 0xd0, 0x50, 0x01, 0x01, 0x24,
+        0x2c, 0xbd, 0x41, 0x1f, 0x99, 0x60, 0xf7, 0x2c, 0x64, 0x48, 0x59, 0x0d, 0x31, 0x58, 0xdd, 0xc9,
+        0x27, 0x63, 0xba, 0x08, 0x7f, 0x75, 0xd1, 0x3e, 0x10, 0x1c, 0x9f, 0x6d, 0xb9, 0x6e, 0xc1, 0xfe,
+        0xe4, 0x8e, 0x2f, 0x5c, 0x28, 0x4b, 0x84, 0x88, 0xfc, 0xe5, 0x2e, 0x9f, 0xc7, 0x6e, 0x93, 0x51,
+        0x65, 0x59, 0xd6, 0x21, 0x54, 0x98, 0x15, 0x4f, 0x25, 0xa6, 0xe2, 0x98, 0x7c, 0x16, 0xbe, 0xdb,
+        0x88, 0xc6, 0x33, 0x8b, 0x8e, 0x1d, 0x95, 0x8a, 0x29, 0xa3, 0xf8, 0x96, 0x05, 0x0c, 0x5d, 0x77,
+        0x4c, 0x28, 0x5c, 0xea, 0xf4, 0x1e, 0x78, 0xb9, 0x06, 0xb6, 0x5c, 0xdc, 0xbd, 0x2a, 0x42, 0xf6,
+        0xaf, 0xbb, 0x87, 0x81, 0x46, 0xe2, 0x0d, 0xd1, 0x65, 0xbb, 0x3c, 0x37, 0x26, 0xb6, 0x32, 0x21,
+        0xef, 0xdc, 0xf2, 0xd8, 0x18, 0xc6, 0x3e, 0x48, 0x2d, 0xf7, 0xa1, 0x3e, 0xc4, 0x3d, 0xf0, 0xba,
+        0x13, 0x35, 0x95, 0x86, 0x67, 0x2e, 0x7f, 0x24, 0x52, 0xea, 0xa8, 0xb7, 0x23, 0x4c, 0x3a, 0xd7,
+        0x74, 0xab, 0xd5, 0xbe, 0xf0, 0x1e, 0xaf, 0x07, 0x2e, 0xc5, 0x57, 0x8a, 0x1d, 0x48, 0xed, 0xdd,
+        0x38, 0xa4, 0x4c, 0x33, 0x1a, 0xd1, 0x26, 0x8b, 0x8e, 0x01, 0x53, 0x85, 0x2d, 0xea, 0x50, 0xc9,
+        0x6f, 0x34, 0xaf, 0x46, 0xcd, 0x61, 0xc1, 0x22, 0x0a, 0xc9, 0x2f, 0xea, 0xbb, 0x1b, 0x70, 0x7d,
+        0xff, 0x41, 0x44, 0xf7, 0x17, 0x67, 0xf4, 0x84, 0x79, 0xc2, 0x75, 0xe3, 0x75, 0x59, 0xe7, 0x28,
+        0x4a, 0xee, 0x47, 0xef, 0x11, 0x75, 0x93, 0x95, 0x22, 0x6d, 0x98, 0xe8, 0xb1, 0x12, 0x4b, 0x60,
+        0x45, 0xe4, 0x00, 0xde, 0x3a, 0x9a, 0x89, 0x22, 0xb7, 0xa6, 0xf6, 0x34, 0x9b, 0x5e, 0xcd, 0xd2,
+        0x0d, 0x6e, 0x32, 0xcf, 0x5d, 0x9e, 0x0b, 0x85, 0x12, 0xe6, 0x5b, 0x07, 0x10, 0x85, 0xca, 0xe7,
+        0xc1, 0xff, 0xd0, 0xa8, 0x47, 0x78, 0x9f, 0x9a, 0x33, 0xc2, 0x5f, 0x69, 0xcf, 0x38, 0xa7, 0x32,
+        0xab, 0x80, 0x37, 0xb8, 0x4a, 0x72, 0x8d, 0xb8, 0x11, 0x5d, 0x7f, 0xec, 0x59, 0xda, 0xc2, 0x2b,
+        0xe5, 0x83, 0xe3, 0x95, 0xdd, 0x8d, 0x17, 0xf8, 0x86, 0xba, 0x0e, 0x77, 0x1d, 0x8b, 0x6d, 0x10,
+        0x61, 0xf6, 0x8d, 0xea, 0x2f, 0xdc, 0x87, 0xec, 0x2a, 0x7c, 0xeb, 0xab, 0x9e, 0xb4, 0xa2, 0x0c,
+        0x33, 0x5b, 0xf4, 0x66, 0x2b, 0x26, 0xe9, 0x62, 0xe7, 0xd2, 0x03, 0x97, 0xb5, 0xbc, 0x64, 0xcb,
+        0x4f, 0xaa, 0xe2, 0x2d, 0x63, 0x41, 0x3c, 0xa6, 0x2e, 0xfe, 0x6c, 0x4c, 0x29, 0x40, 0xad, 0xce,
+        0xc3, 0xc0, 0x29, 0x4d, 0xd2, 0xcc, 0x56, 0xdb, 0x3e, 0x93, 0x57, 0x56, 0x14, 0x02, 0xa6, 0x02,
+        0x15, 0x3c, 0xcb, 0x34, 0x70, 0xe5, 0x88, 0xb8, 0x2b, 0xc9, 0x63, 0x6d, 0xe6, 0x92, 0x1e, 0x92,
+        0xcd, 0xc7, 0x6f, 0x1b, 0x92, 0xe6, 0x33, 0x3e, 0xff, 0xe6, 0x89, 0xec, 0xc0, 0x71, 0x8c, 0x17,
+        0xe2, 0xfc, 0x0e, 0xab, 0x9b, 0x91, 0x73, 0x24, 0x0f, 0x3a, 0x64, 0x2f, 0x33, 0x58, 0x86, 0x1f,
+        0xc5, 0x16, 0x41, 0xca, 0x5f, 0x1b, 0x3d, 0xa9, 0x8a, 0x2e, 0x56, 0x39, 0x3e, 0xd7, 0x14, 0x0f,
+        0x42, 0x99, 0x2e, 0xb5, 0xc2, 0xd8, 0xfc, 0x1e, 0x1c, 0xb8, 0xf7, 0x42, 0x69, 0x32, 0x0e, 0xce,
+        0x9b, 0xc7, 0x4d, 0xf4, 0xae, 0x31, 0xcd, 0xe5, 0x17, 0x57, 0xbf, 0x78, 0xde, 0x3b, 0x17, 0x07,
+        0x1b, 0xbb, 0xb6, 0x9a, 0x69, 0x36, 0xe5, 0xd7, 0x03, 0x26, 0x92, 0xd7, 0x82, 0x4f, 0x5e, 0x5a,
+        0x25, 0xe5, 0x51, 0xa7, 0xf5, 0x46, 0xd6, 0xf2, 0xb4, 0x57, 0xf8, 0x43, 0x3d, 0x8f, 0x27, 0x6c,
+        0xd8, 0xe7, 0x3a, 0xd2, 0x41, 0x91, 0x91, 0x63, 0x16, 0x8b, 0xd7, 0x95, 0x0b, 0xcc, 0x41, 0xeb,
+        0x47, 0xa2, 0x61, 0xec, 0xc5, 0x44, 0xd9, 0xf2, 0x2b, 0x97, 0x28, 0xdd, 0x91, 0x51, 0x5b, 0x58,
+        0x80, 0xad, 0x9d, 0x19, 0xfc, 0xf4, 0x22, 0xe5, 0x1e, 0x2c, 0xfe, 0xc9, 0xc9, 0xff, 0xf5, 0x2e,
+        0x14, 0x38, 0x33, 0x3f, 0xac, 0x92, 0x2e, 0xf4, 0xd0, 0x8b, 0x89, 0xab, 0xc5, 0x99, 0x0b, 0x6b,
+        0xaa, 0xcc, 0x7e, 0x6d, 0xad, 0xb2, 0x0c, 0x48, 0x21, 0x21, 0x9d, 0x7c, 0x6d, 0xee, 0xca, 0xd1,
+        0x95, 0xcc, 0xdb, 0xb8, 0x44, 0xe4, 0xc6, 0xc3, 0x46, 0x38, 0x34, 0x17, 0x07, 0x23, 0x95, 0x19,
+        0x33, 0x7e, 0xe7, 0x87, 0xe5, 0xaf, 0x0b, 0x13, 0x07, 0xca, 0x21, 0x8a, 0x89, 0x9f, 0xd9, 0x10,
+        0xa4, 0xf5, 0x05, 0x52, 0xc2, 0x38, 0xbd, 0x3f, 0xe1, 0xf9, 0xfd, 0x2d, 0x3e, 0xfb, 0xd9, 0x9d,
+        0x18, 0x98, 0xb6, 0xfd, 0x0f, 0x6b, 0x0d, 0x10, 0x00, 0x5b, 0xb0, 0xe6, 0x2a, 0xdd, 0x3a, 0xe5,
+        0x56, 0x46, 0xc3, 0x97, 0xcb, 0x1d, 0x92, 0xa1, 0x10, 0x6a, 0x24, 0x3c, 0x76, 0xd2, 0x96, 0x2c,
+        0xfc, 0x39, 0x12, 0x56, 0x6d, 0x8b, 0x22, 0xc6, 0x22, 0xc0, 0x6f, 0x9d, 0x82, 0x12, 0x6b, 0x41,
+        0x02, 0xd0, 0xf0, 0x69, 0x68, 0xb0, 0xfb, 0xc0, 0xde, 0x41, 0x60, 0x36, 0x44, 0x5e, 0xc3, 0x8a,
+        0x43, 0xd5, 0x8c, 0x2a, 0x94, 0x92, 0xf6, 0xec, 0x13, 0x40, 0x78, 0x14, 0x9d, 0x02, 0xea, 0x1c,
+        0xf2, 0x9f, 0x57, 0xb7, 0x96, 0x40, 0x87, 0xd1, 0xf7, 0x44, 0x04, 0x10, 0x5c, 0x62, 0xfa, 0xec,
+        0x94, 0x59, 0x29, 0x83, 0xd9, 0x84, 0xf8, 0x33, 0x2a, 0x04, 0xc1, 0x7a, 0x0a, 0x7d, 0x2b, 0xd9,
+        0xd4, 0xe0, 0x61, 0x07, 0x1b, 0xcf, 0xac, 0x37, 0x04, 0x70, 0xa3, 0xef, 0xc8, 0x62, 0x87, 0x4a,
+        0x83, 0x27, 0xba, 0x79, 0xcc, 0x93, 0x4a, 0xdc, 0x0f, 0x54, 0x40, 0xce, 0x0f, 0x0b, 0x77, 0x94,
+        0x15, 0x91, 0xaa, 0xe8, 0x2a, 0x33, 0xa6, 0xc1, 0x48, 0x5e, 0x4c, 0x1e, 0x27, 0x2f, 0xd3, 0x48,
+        0x1b, 0x63, 0x6d, 0x75, 0x41, 0x62, 0x0b, 0x27, 0x15, 0x7c, 0xd5, 0xbf, 0x15, 0xb0, 0xc5, 0xfb,
+        0xad, 0xea, 0x2a, 0x7c, 0x61, 0x08, 0xb2, 0x32, 0xb6, 0x68, 0x25, 0xc6, 0x2d, 0x7e, 0x0d, 0x6f,
+        0x5e, 0x8a, 0x24, 0x9d, 0xb3, 0xb1, 0x94, 0x0b, 0x30, 0xd5, 0x4d, 0x40, 0x3e, 0xc9, 0x03, 0xe6,
+        0xf1, 0xbf, 0x04, 0xaa, 0x25, 0xa9, 0xd0, 0xb5, 0x45, 0x57, 0xe3, 0xcb, 0x69, 0xd2, 0xe8, 0xc0,
+        0x90, 0x6a, 0x9d, 0x3a, 0x4e, 0xd9, 0x65, 0xe0, 0x11, 0x84, 0xf7, 0x48, 0xbb, 0x94, 0x44, 0xc4,
+        0xbd, 0x48, 0x29, 0x5a, 0x89, 0x5a, 0x41, 0x78, 0x84, 0x3d, 0x09, 0x22, 0xea, 0xff, 0x5f, 0x97,
+        0x78, 0x7a, 0xfd, 0x6e, 0x8b, 0x89, 0xc1, 0x7d, 0x04, 0xdc, 0x67, 0x8f, 0x57, 0xe5, 0xd7, 0xd7,
+        0x55, 0x42, 0x87, 0x63, 0xfd, 0x2a, 0x0b, 0x48, 0xc6, 0x28, 0xde, 0x8d, 0x76, 0x06, 0x1c, 0x3b,
+        0xba, 0x23, 0x3b, 0x52, 0x9d, 0xc4, 0x20, 0x93, 0x19, 0x96, 0x29, 0xe6, 0x3c, 0x35, 0x22, 0x03,
+        0xa0, 0xab, 0x27, 0x63, 0x63, 0x94, 0x39, 0x87, 0x2e, 0xd5, 0xef, 0xf0, 0x9c, 0xea, 0x39, 0xa7,
+        0x62, 0x88, 0xf9, 0xfe, 0xe0, 0xf5, 0xac, 0xd5, 0x23, 0x46, 0xf4, 0xa4, 0xa2, 0xa6, 0xab, 0x61,
+        0xf1, 0xa1, 0xd9, 0x46, 0x49, 0xed, 0xac, 0xda, 0xbc, 0x3f, 0x9f, 0x9c, 0xd6, 0x44, 0x67, 0x3e,
+        0x04, 0x49, 0xf2, 0xa9, 0x16, 0xfa, 0x21, 0xa5, 0x2e, 0xaa, 0x70, 0x7e, 0xe8, 0xb4, 0x86, 0x13,
+        0x66, 0x03, 0x4d, 0xcc, 0xf9, 0x35, 0x7e, 0x49, 0xb9, 0xb7, 0x48, 0x8b, 0x79, 0xb8, 0x74, 0x08,
+        0x0c, 0xa6, 0x11, 0xaa, 0x85, 0x42, 0xdd, 0x3a, 0x15, 0xa4, 0x64, 0xe2, 0x1e, 0xf0, 0x16, 0x5b,
+        0x95, 0x03, 0x4d, 0x05, 0xbe, 0xd3, 0x03, 0xb4, 0x4e, 0xcf, 0x76, 0xb5, 0xc1, 0xb4, 0x4b, 0x6a,
+        0xe1, 0xee, 0x10, 0xce, 0x76, 0x7e, 0xe1, 0xa4, 0x1f, 0x35, 0x54, 0xe6, 0xe4, 0x04, 0xbf, 0xa7,
+        0x20, 0x03, 0x66, 0x65, 0x1b, 0xf7, 0xc6, 0x01, 0x45, 0x1c, 0x92, 0x56, 0xfb, 0xba, 0x0f, 0x33,
+        0xaa, 0x8a, 0xed, 0x29, 0x82, 0x20, 0x6d, 0x7f, 0x02, 0x8e, 0x61, 0x02, 0x1d, 0x02, 0x27, 0x63,
+        0xd0, 0x98, 0xfc, 0x67, 0x49, 0x8b, 0x81, 0x52, 0xc5, 0xa6, 0xe6, 0x8c, 0x68, 0xa4, 0x29, 0xab,
+        0x68, 0x12, 0xb1, 0xc7, 0xbe, 0xd0, 0x81, 0x81, 0x0e, 0x59, 0x85, 0x4e, 0x32, 0xf4, 0xc5, 0x4c,
+        0x9d, 0x5f, 0x20, 0xd1, 0xf3, 0x27, 0x82, 0x22, 0xe7, 0x96, 0xbf, 0x2f, 0xcb, 0x9d, 0x3f, 0xcc,
+        0x52, 0xab, 0x69, 0xe2, 0xa7, 0xe7, 0xdb, 0xe2, 0x0f, 0xf9, 0xbe, 0x64, 0x21, 0x3e, 0x40, 0xa5,
+        0x2d, 0xa6, 0x49, 0x2d, 0xda, 0x24, 0x94, 0x75, 0x1e, 0x9e, 0x38, 0x2d, 0xc6, 0x81, 0x85, 0x36,
+        0x5c, 0x2d, 0x67, 0x19, 0x78, 0x13, 0x32, 0xb9, 0x00, 0x5c, 0xc1, 0xbf, 0x63, 0xd6, 0xba, 0x4f,
+        0xce, 0x4a, 0xa4, 0xcb, 0x97, 0xff, 0x03, 0x4e, 0xcd, 0x80, 0x85, 0xcf, 0x67, 0x9c, 0x7f, 0xed,
+        0xbc, 0xbf, 0x2b, 0xff, 0x31, 0xd5, 0xbf, 0xed, 0x24, 0x21, 0xdc, 0x05, 0x9d, 0xd0, 0xcd, 0x7a,
+        0x86, 0x86, 0xee, 0x77, 0x2d, 0x28, 0xe6, 0xb9, 0xc2, 0x55, 0x61, 0x91, 0x56, 0xc4, 0xac, 0x94,
+        0x90, 0xa6, 0x73, 0x4b, 0xc2, 0xfb, 0x2a, 0x7b, 0x18, 0x88, 0xf1, 0x05, 0xba, 0x16, 0xb2, 0xdd,
+        0x5a, 0xdd, 0x34, 0xdd, 0x30, 0xac, 0xf6, 0x8a, 0xec, 0x87, 0x98, 0x35, 0x42, 0xb4, 0xdf, 0xca,
+        0x9c, 0x1b, 0x3e, 0xa0, 0x1d, 0xcd, 0x9f, 0x61, 0x16, 0xb5, 0x06, 0x7c, 0xa3, 0xe7, 0x32, 0x0f,
+        0x73, 0x6c, 0x2d, 0x15, 0xb1, 0x07, 0x33, 0xc2, 0xc1, 0x09, 0xeb, 0x31, 0x61, 0x67, 0x24, 0x41,
+        0xf7, 0x1e, 0xd0, 0xc8, 0x89, 0x5e, 0xf4, 0x04, 0x18, 0x80, 0x16, 0xad, 0x3e, 0x99, 0x55, 0xaa,
+        0x5a, 0xb6, 0xa6, 0x71, 0xe3, 0x58, 0x58, 0xdf, 0x8c, 0xfb, 0x17, 0xa0, 0x01, 0x00, 0xd1, 0xe0,
+        0xa5, 0x5e, 0xec, 0x6c, 0xc5, 0x86, 0xe4, 0x0a, 0x04, 0x6e, 0x88, 0x1a, 0x4b, 0xfd, 0x64, 0x59,
+        0x52, 0x16, 0xa2, 0x02, 0x7e, 0x87, 0xc6, 0x60, 0xf4, 0x19, 0x80, 0xd4, 0x73, 0xb0, 0xd9, 0x72,
+        0x31, 0xe1, 0x9e, 0x67, 0x76, 0xd2, 0x3b, 0xe1, 0x2e, 0x11, 0x18, 0x69, 0xbf, 0xab, 0x6f, 0x20,
+        0x41, 0xd1, 0x49, 0x4d, 0x3f, 0x7a, 0xcd, 0x3b, 0x9b, 0x8f, 0x7a, 0x6e, 0xa4, 0xf4, 0xf9, 0x36,
+        0xd8, 0x1d, 0xea, 0x0d, 0xcb, 0xbb, 0x9f, 0x75, 0x0c, 0xc6, 0x61, 0xc5, 0xfb, 0x48, 0x1d, 0x0d,
+        0xa6, 0x90, 0x22, 0xb0, 0x4a, 0xfb, 0x87, 0xd4, 0x96, 0x81, 0x76, 0x97, 0xef, 0xab, 0x09, 0x0a,
+        0xdb, 0xaa, 0x01, 0x64, 0xc2, 0xd6, 0x62, 0x85, 0x02, 0x15, 0x14, 0x8d, 0xc5, 0x53, 0x5d, 0xe6,
+        0x81, 0x55, 0x0e, 0xb0, 0x09, 0x3d, 0x90, 0x80, 0x08, 0x51, 0x50, 0x6d, 0xbb, 0x54, 0xdd, 0x19,
+        0x41, 0x9f, 0xff, 0xce, 0x55, 0x0a, 0xc2, 0xd7, 0x01, 0x64, 0x0b, 0x24, 0x65, 0xba, 0x2e, 0xb4,
+        0x91, 0x87, 0xaf, 0x69, 0xd1, 0x65, 0x08, 0xbe, 0xfa, 0x1a, 0xa2, 0xb4, 0x99, 0xac, 0x64, 0x0d,
+        0x61, 0x2c, 0x7e, 0x3e, 0xf5, 0xf9, 0x1b, 0x28, 0x07, 0x11, 0x7f, 0x8b, 0x89, 0x06, 0xaf, 0xb4,
+        0x4e, 0x3c, 0xc3, 0x45, 0x22, 0xb4, 0xe4, 0x05, 0x65, 0xfb, 0xd3, 0xef, 0x8f, 0x3a, 0xc9, 0x2d,
+        0x82, 0x3a, 0xd5, 0x93, 0x5e, 0xbb, 0x7c, 0xd0, 0x06, 0x35, 0xdf, 0xbb, 0xba, 0x22, 0x5b, 0x85,
+        0xc9, 0xee, 0x87, 0x12, 0x2e, 0x3a, 0xfd, 0xa2, 0x2c, 0x97, 0xa0, 0x43, 0x19, 0xf7, 0xa1, 0x29,
+        0xd1, 0x77, 0xef, 0xa2, 0xd2, 0x8d, 0xf1, 0xbd, 0x1c, 0x03, 0xa8, 0xde, 0x7c, 0x86, 0x06, 0xd6,
+        0x18, 0x5a, 0x09, 0xe6, 0x33, 0x2c, 0xab, 0x09, 0x6d, 0x72, 0xe4, 0x6a, 0x82, 0x4f, 0xb4, 0xc0,
+        0xbe, 0xe0, 0x7d, 0xef, 0x01, 0x3c, 0x1e, 0x3f, 0x1e, 0x37, 0xfb, 0x5a, 0x03, 0x02, 0xb0, 0x72,
+        0xb0, 0x4f, 0xf1, 0x99, 0x83, 0xda, 0x96, 0xd0, 0xb1, 0x0f, 0x41, 0xcb, 0xae, 0x17, 0x6c, 0x1a,
+        0x61, 0x84, 0x75, 0x67, 0x59, 0xca, 0x3d, 0x60, 0x05, 0x90, 0xe4, 0xb7, 0xfb, 0x5c, 0x9c, 0x4a,
+        0x3e, 0x00, 0x01, 0x21, 0xb6, 0x58, 0x6e, 0x86, 0x06, 0x0c, 0xec, 0xb6, 0x34, 0x87, 0x90, 0xf2,
+        0x9a, 0x0a, 0x7b, 0x52, 0x53, 0xb8, 0x57, 0x71, 0x07, 0x94, 0xc3, 0x4c, 0x3e, 0x58, 0x98, 0xb4,
+        0xcb, 0x83, 0xd2, 0xb3, 0xf9, 0xb1, 0x22, 0xa8, 0x1f, 0x81, 0x81, 0x65, 0x29, 0x74, 0xe1, 0x9e,
+        0xed, 0xfa, 0xd6, 0xaa, 0x01, 0x2e, 0xb1, 0x1d, 0x1a, 0x6d, 0x07, 0x16, 0xed, 0xb3, 0x7b, 0x7f,
+        0x44, 0xa6, 0xba, 0x35, 0x80, 0x36, 0x9a, 0x8d, 0x5e, 0xa2, 0xdd, 0xb4, 0xa1, 0xdf, 0x05, 0xe5,
+        0x1f, 0x94, 0xc8, 0x41, 0x5a, 0xc3, 0xd9, 0xfb, 0x0a, 0x63, 0xbc, 0x93, 0x19, 0xfd, 0xfd, 0x88,
+        0x38, 0x92, 0x1f, 0x8f, 0x43, 0xb9, 0x63, 0x14, 0x87, 0xdd, 0x86, 0x6d, 0xb1, 0x6f, 0xfc, 0x39,
+        0x2f, 0x16, 0x3c, 0x78, 0xb5, 0x98, 0xb8, 0x66, 0x0a, 0xf6, 0xd8, 0x1e, 0x1c, 0x70, 0x4d, 0x1c,
+        0x48, 0x51, 0x83, 0x2b, 0xbc, 0x89, 0x90, 0x8a, 0xf2, 0x56, 0x54, 0x89, 0x5a, 0xb0, 0x2a, 0x37,
+        0x6e, 0x10, 0x08, 0xa5, 0xb2, 0xb4, 0xa2, 0x04, 0x1b, 0xf1, 0xb6, 0xdc, 0x4a, 0x44, 0x42, 0xd8,
+        0x89, 0x3f, 0x0b, 0x81, 0xff, 0x67, 0x15, 0x41, 0x35, 0x0c, 0x34, 0x44, 0x21, 0xc8, 0x5d, 0xab,
+        0x6f, 0xaa, 0x63, 0x8d, 0x3e, 0x7a, 0xe5, 0x4f, 0x1e, 0x90, 0x5f, 0x64, 0x06, 0x9a, 0x3e, 0x2f,
+        0xcb, 0x1e, 0x3f, 0x5c, 0xd3, 0x2d, 0x16, 0x42, 0x1e, 0x6d, 0x49, 0x28, 0xdd, 0x61, 0xa3, 0xcd,
+        0xef, 0xb3, 0xb2, 0x57, 0x9b, 0xae, 0xee, 0x67, 0x03, 0xac, 0xad, 0x5d, 0x6f, 0x7c, 0xaf, 0x87,
+        0xd4, 0x72, 0xbd, 0xc7, 0xe1, 0x55, 0x99, 0xc5, 0x1f, 0xb5, 0xe5, 0xa2, 0x77, 0x2c, 0xd7, 0x9a,
+        0x30, 0x46, 0x51, 0x85, 0xfc, 0x0a, 0xc5, 0x8f, 0x2b, 0x16, 0xbb, 0x55, 0x0d, 0x06, 0x56, 0xa4,
+        0xff, 0x00, 0x93, 0xb5, 0x6c, 0xfc, 0x2b, 0xda, 0x03, 0xec, 0x1a, 0xdb, 0xd6, 0xfa, 0xe2, 0x1e,
+        0x41, 0x52, 0x18, 0x54, 0x23, 0x3d, 0x20, 0xd2, 0x04, 0x0d, 0x10, 0xf1, 0x14, 0x0d, 0x0e, 0xfd,
+        0xbe, 0xd7, 0x30, 0xbb, 0xba, 0xed, 0xf4, 0xf2, 0xf9, 0x35, 0xab, 0x50, 0xf9, 0xee, 0xd7, 0x08,
+        0xd4, 0xe0, 0x30, 0x03, 0x41, 0x91, 0x3c, 0xb9, 0x25, 0x10, 0x7c, 0xe4, 0x07, 0x6c, 0x62, 0x73,
+        0x17, 0xe5, 0x88, 0x00, 0x53, 0x32, 0x8a, 0x6a, 0xc6, 0xa3, 0x60, 0xb3, 0x03, 0x30, 0xa6, 0xdc,
+        0x1a, 0x6a, 0x35, 0x6d, 0xef, 0x42, 0x82, 0xac, 0x1b, 0x59, 0x18, 0x14, 0xc1, 0x87, 0xe9, 0xf7,
+        0x28, 0xb9, 0x79, 0xbd, 0x52, 0x32, 0xbb, 0xb3, 0x80, 0x43, 0x76, 0x00, 0x17, 0xee, 0xbc, 0x16,
+        0x6c, 0x8a, 0x33, 0xd9, 0x74, 0x46, 0x4f, 0xf7, 0x17, 0x99, 0x6e, 0x3f, 0x5c, 0x7e, 0x0a, 0x16,
+        0x51, 0x62, 0xbf, 0x40, 0x65, 0x51, 0x62, 0x2d, 0x1e, 0x42, 0x93, 0xd7, 0x1d, 0xed, 0x2e, 0x61,
+        0x06, 0x53, 0x6b, 0xca, 0x6b, 0xde, 0x4c, 0xc2, 0x1a, 0xf3, 0xf7, 0x30, 0x1c, 0xa6, 0xb4, 0xcd,
+        0x48, 0x37, 0xb9, 0xe7, 0x30, 0xcf, 0x03, 0x60, 0x2f, 0xc2, 0xb3, 0x72, 0xbb, 0xcd, 0x0a, 0xbe,
+        0x83, 0x59, 0x53, 0x56, 0x70, 0xb3, 0xb2, 0x53, 0x30, 0x23, 0x28, 0x67, 0xb7, 0x61, 0xe6, 0x34,
+        0x91, 0x35, 0x1b, 0xa1, 0x6a, 0x14, 0xfa, 0xe9, 0x09, 0x88, 0x61, 0x43, 0x16, 0xa7, 0xae, 0x0e,
+        0xbb, 0x13, 0xa8, 0x07, 0xac, 0xc2, 0xcd, 0x49, 0x08, 0x14, 0x3b, 0x3d, 0x4c, 0x97, 0xb9, 0x10,
+        0x22, 0xe4, 0xeb, 0x58, 0xe4, 0x9a, 0x66, 0x15, 0x47, 0x89, 0x45, 0x01, 0x37, 0x43, 0x23, 0x57,
+        0x39, 0xec, 0xd2, 0xcd, 0x62, 0x95, 0x40, 0x42, 0x17, 0xf3, 0xd8, 0x90, 0x8c, 0x18, 0x1b, 0x64,
+        0x9b, 0x81, 0x89, 0x19, 0x6e, 0xda, 0xf9, 0xb6, 0x9a, 0x68, 0x5b, 0xf0, 0xda, 0x17, 0xb8, 0xa8,
+        0xe7, 0xae, 0xe0, 0x90, 0x19, 0x53, 0x62, 0xb2, 0x23, 0x65, 0x37, 0xa2, 0x8d, 0x09, 0xc5, 0x2d,
+        0x52, 0x12, 0x8d, 0x18, 0xca, 0x7c, 0x61, 0xbb, 0x5d, 0x63, 0xa4, 0x5b, 0x15, 0x06, 0x04, 0xbe,
+        0xc1, 0xdf, 0xad, 0x3e, 0x1c, 0x53, 0xf1, 0xfa, 0x19, 0xb0, 0x16, 0x4b, 0xda, 0x9e, 0x6e, 0x27,
+        0xa1, 0x7a, 0x71, 0x8e, 0xe7, 0xaf, 0x84, 0xe4, 0x84, 0x76, 0x19, 0x85, 0x9d, 0x8c, 0x8d, 0x50,
+        0x16, 0x9b, 0xe6, 0x62, 0x22, 0x6a, 0x07, 0x51, 0x10, 0x91, 0xfc, 0x4e, 0x4a, 0x8d, 0x45, 0xe2,
+        0xe3, 0x63, 0x25, 0xd4, 0x8a, 0xec, 0x53, 0x60, 0xb7, 0xe3, 0x6c, 0x73, 0xb9, 0x5c, 0x7b, 0xc9,
+        0x55, 0xd3, 0x0f, 0x21, 0xf4, 0xf0, 0xd2, 0xb3, 0x08, 0xc3, 0x4e, 0xd7, 0x53, 0xa0, 0x83, 0xe9,
+        0xfb, 0x79, 0x47, 0xec, 0x3a, 0xc5, 0xf6, 0x13, 0xa9, 0x54, 0x29, 0x38, 0x0b, 0xf0, 0xd5, 0x0e,
+        0xcc, 0x92, 0x22, 0x8e, 0xd1, 0x09, 0xc1, 0xf4, 0x1c, 0xf5, 0x87, 0xef, 0x25, 0xf1, 0x0e, 0xc2,
+        0xba, 0x2a, 0xc9, 0x8e, 0xcd, 0x89, 0x61, 0x37, 0xb7, 0x67, 0x49, 0xd2, 0x56, 0x0d, 0x35, 0xa1,
+        0x4b, 0xa4, 0xba, 0x83, 0x53, 0x3c, 0xbf, 0x65, 0x00, 0x6d, 0x50, 0xf1, 0x3e, 0x84, 0x10, 0xc3,
+        0x7a, 0xad, 0x6a, 0x63, 0x96, 0xdf, 0xf0, 0xbe, 0x0c, 0x8a, 0x0a, 0x67, 0xc0, 0xd4, 0x70, 0x96,
+        0x9a, 0x14, 0xb3, 0xd2, 0x12, 0x5d, 0x20, 0x92, 0x21, 0x9d, 0x8d, 0xa1, 0x36, 0x7e, 0x29, 0x62,
+        0x70, 0xb3, 0x1f, 0x7e, 0xd2, 0xf2, 0xcc, 0x29, 0xfd, 0x29, 0xe9, 0xbb, 0x89, 0x36, 0xc7, 0x75,
+        0x1b, 0xcb, 0x56, 0xec, 0xe6, 0x2f, 0x8d, 0x71, 0x16, 0x90, 0x92, 0xe1, 0x83, 0x5b, 0xc8, 0x2f,
+        0x89, 0x24, 0x9e, 0xbe, 0x78, 0x8e, 0x3d, 0x88, 0xdd, 0x39, 0xdf, 0x99, 0xd8, 0xfc, 0x1b, 0x14,
+        0x96, 0x3f, 0xde, 0x6e, 0x94, 0x06, 0x0d, 0xeb, 0x2e, 0x89, 0x05, 0x2e, 0xd9, 0x44, 0xea, 0x77,
+        0xaf, 0x47, 0x3c, 0x22, 0xde, 0x83, 0x04, 0x60, 0x0b, 0x28, 0x72, 0x4c, 0xd5, 0x10, 0xbe, 0x99,
+        0x62, 0xa6, 0x0b, 0x7d, 0xa5, 0x7d, 0x68, 0x7d, 0x2c, 0x8d, 0x52, 0xd5, 0xed, 0x53, 0x23, 0x67,
+        0x3e, 0xfa, 0x76, 0x1a, 0xdd, 0x28, 0xc1, 0x4b, 0x14, 0x97, 0x4a, 0x4c, 0x16, 0xcd, 0x4d, 0x79,
+        0xa8, 0x27, 0x10, 0xc2, 0x57, 0x5b, 0x49, 0xc2, 0x27, 0xaf, 0x9e, 0x23, 0x43, 0x9e, 0xd3, 0xef,
+        0x74, 0x89, 0x13, 0xad, 0x3a, 0x85, 0xf9, 0xd4, 0xe7, 0x82, 0x54, 0xe1, 0xb6, 0xac, 0x4f, 0x34,
+        0x1b, 0xbf, 0xe3, 0x0a, 0x57, 0xe3, 0x19, 0xff, 0x06, 0x9e, 0xc8, 0xd2, 0x02, 0x44, 0x1e, 0x0d,
+        0x26, 0x4f, 0xab, 0x6d, 0xa1, 0xe9, 0x84, 0xe6, 0x28, 0xe9, 0xc1, 0x11, 0xb0, 0x18, 0xf1, 0xf1,
+        0xe4, 0x01, 0x4f, 0x3d, 0x07, 0xb3, 0x79, 0x8f, 0x09, 0x04, 0xe9, 0xb3, 0x09, 0x7e, 0x76, 0xf2,
+        0x19, 0xc3, 0x0c, 0xb9, 0x80, 0x9f, 0xac, 0x5d, 0x9a, 0xe2, 0xfd, 0x09, 0x5f, 0x18, 0xaa, 0x3e,
+        0xdd, 0xf8, 0x0a, 0x24, 0x8d, 0x94, 0x94, 0xc3, 0x00, 0x2e, 0x6b, 0xf5, 0x5f, 0x2d, 0xb8, 0xf5,
+        0x5c, 0xe2, 0x31, 0xf4, 0x7c, 0xbc, 0x58, 0x37, 0x8b, 0x7d, 0xa0, 0xc2, 0x8c, 0x6c, 0x13, 0x60,
+        0x5a, 0x48, 0xf6, 0x07, 0xf2, 0xe4, 0xe2, 0xcf, 0x21, 0xca, 0xfe, 0xd0, 0x31, 0xd7, 0xa0, 0xc6,
+        0xd6, 0x4f, 0x8e, 0xd8, 0x08, 0x8f, 0xf5, 0x8c, 0x38, 0x39, 0xbc, 0xb1, 0x09, 0x8f, 0x03, 0xe1,
+        0xbc, 0xa3, 0xcb, 0x6a, 0x44, 0xec, 0x65, 0x97, 0x0e, 0x1d, 0x7d, 0x06, 0x45, 0x51, 0x42, 0x59,
+        0xe4, 0xbc, 0xf9, 0x57, 0x6b, 0x2b, 0xad, 0xf3, 0x7f, 0x86, 0x49, 0xcd, 0x8d, 0x84, 0xc2, 0xbf,
+        0x71, 0x60, 0xa7, 0x0d, 0x9a, 0xa4, 0x5e, 0x30, 0x2f, 0x34, 0xf7, 0x54, 0xe8, 0x0d, 0xe1, 0xbe,
+        0x4c, 0x9e, 0xc9, 0x5d, 0x36, 0x26, 0xf9, 0x1b, 0x73, 0x91, 0xd8, 0x9d, 0x83, 0x48, 0x46, 0x14,
+        0xfb, 0xdb, 0x3c, 0xf9, 0x4c, 0x9d, 0x96, 0x55, 0x0e, 0xea, 0xbe, 0xcc, 0xd3, 0x6a, 0xa8, 0x43,
+        0x9e, 0xb8, 0x88, 0xc8, 0xdf, 0xbb, 0x99, 0xce, 0x9f, 0xfc, 0x32, 0xe0, 0xf9, 0x46, 0xf5, 0xe6,
+        0x4b, 0x7f, 0xf8, 0xf0, 0x26, 0x41, 0x90, 0x7a, 0x15, 0xbb, 0x2d, 0x26, 0x52, 0x10, 0x7e, 0x3d,
+        0x93, 0x14, 0x25, 0xb3, 0x12, 0xe5, 0x35, 0xfd, 0xee, 0x71, 0xb9, 0x1f, 0x15, 0xe2, 0x56, 0x71,
+        0x25, 0x2d, 0x17, 0x72, 0xfe, 0x6d, 0xa3, 0x36, 0x27, 0x4c, 0x7d, 0xbf, 0x44, 0xc8, 0x06, 0xa3,
+        0xa6, 0xf4, 0x04, 0x41, 0x51, 0x22, 0x08, 0x70, 0x97, 0x5e, 0xed, 0x0d, 0x1a, 0xfd, 0x36, 0xb3,
+        0xb5, 0x36, 0xf9, 0x2b, 0x92, 0xf6, 0x73, 0xa3, 0x00, 0xa2, 0x01, 0xe6, 0x1f, 0x8f, 0x9f, 0xa0,
+        0x30, 0xd7, 0x7d, 0xb4, 0x7c, 0xb2, 0x60, 0x3b, 0xc2, 0x31, 0xe7, 0x83, 0x3e, 0x79, 0xca, 0x9f,
+        0x2a, 0x0c, 0xfd, 0xab, 0xfa, 0x90, 0x5f, 0xc7, 0x0e, 0x65, 0x86, 0x8c, 0x4d, 0xd0, 0x6d, 0x37,
+        0x72, 0x36, 0xd5, 0x5c, 0xbd, 0x98, 0x8a, 0x51, 0x9f, 0x52, 0x4a, 0x28, 0x3e, 0xde, 0xb3, 0x07,
+        0x00, 0xa0, 0x70, 0x5e, 0xf6, 0x2a, 0x92, 0x24, 0x09, 0x26, 0xb0, 0x44, 0x2b, 0x20, 0x3b, 0x8c,
+        0xf2, 0x6e, 0x08, 0x1b, 0x75, 0x85, 0xae, 0xc7, 0x70, 0x13, 0x3a, 0x24, 0xf7, 0x5b, 0x79, 0x83,
+        0xcb, 0xd5, 0xd7, 0x01, 0x62, 0x13, 0x3d, 0xe2, 0x22, 0xe1, 0xbe, 0x03, 0x42, 0xb0, 0xc6, 0x34,
+        0x21, 0x98, 0xba, 0x72, 0x8c, 0x97, 0xc9, 0x18, 0x7a, 0xe0, 0x34, 0xb7, 0xc2, 0xe3, 0x86, 0xf8,
+        0xe8, 0xc5, 0xa2, 0x48, 0x4b, 0x4c, 0x2c, 0x3d, 0x2b, 0x80, 0x34, 0x71, 0x47, 0xb2, 0xad, 0x25,
+        0xaa, 0x99, 0x76, 0x19, 0x3f, 0xdb, 0x29, 0xda, 0x35, 0xa0, 0x23, 0xf8, 0x50, 0x92, 0x75, 0xe3,
+        0x18, 0x6d, 0x51, 0x2c, 0xd4, 0xd9, 0x51, 0xd3, 0x2a, 0x57, 0xb6, 0x71, 0x06, 0xa3, 0x22, 0x71,
+        0x98, 0x2f, 0x96, 0xcc, 0xce, 0x4a, 0x3b, 0x37, 0x2f, 0xf6, 0x54, 0xe9, 0xcd, 0xf1, 0xd9, 0x41,
+        0xf9, 0x91, 0x89, 0x16, 0x70, 0x74, 0x4f, 0x18, 0x1a, 0x6b, 0x0e, 0x7e, 0xaa, 0xb5, 0xb7, 0xa3,
+        0xf5, 0x13, 0xe1, 0x35, 0xab, 0x97, 0x4d, 0xc9, 0x6e, 0x72, 0x55, 0xe3, 0x4c, 0x30, 0x8b, 0x77,
+        0xb4, 0x93, 0xcb, 0x0e, 0x49, 0x79, 0xfe, 0x88, 0x21, 0x6b, 0xf1, 0x4c, 0x52, 0x8a, 0x4c, 0x97,
+        0x5a, 0xe0, 0x88, 0x5f, 0x84, 0xea, 0xa0, 0x5c, 0x3a, 0xca, 0x7d, 0x58, 0x39, 0x16, 0xa4, 0xc3,
+        0x28, 0xbf, 0x25, 0xa3, 0x39, 0x4f, 0x8c, 0xad, 0x2b, 0x2f, 0x70, 0x52, 0xc4, 0xde, 0x41, 0x25,
+        0x40, 0xac, 0xf9, 0xfe, 0x55, 0x43, 0x95, 0x5d, 0xcd, 0x98, 0x50, 0xd3, 0xa9, 0x82, 0x65, 0xc0,
+        0x94, 0xf1, 0xdb, 0x77, 0x1e, 0x63, 0x99, 0x92, 0x14, 0x7a, 0xf8, 0xfe, 0xd7, 0x5d, 0xa2, 0xe5,
+        0xd8, 0xfe, 0xa9, 0xda, 0xf4, 0x02, 0xca, 0x19, 0x70, 0x71, 0xe2, 0x22, 0x37, 0xcb, 0xf9, 0x60,
+        0xe8, 0x74, 0xcd, 0xec, 0x80, 0x99, 0xc3, 0xe4, 0x0c, 0xa6, 0x12, 0x55, 0xca, 0xf0, 0xbe, 0xef,
+        0x64, 0xcd, 0x1d, 0xc4, 0x6d, 0x73, 0x3a, 0x5c, 0xf2, 0x07, 0x07, 0xd8, 0xe9, 0x44, 0x07, 0xf0,
+        0x4a, 0x2f, 0x91, 0xa0, 0x56, 0xa7, 0x92, 0x8c, 0x0b, 0xfe, 0x71, 0xb3, 0x68, 0xde, 0x5e, 0xc0,
+        0xb6, 0x90, 0x97, 0x8b, 0x88, 0x29, 0x39, 0x42, 0x5a, 0x76, 0x85, 0x2a, 0xaf, 0xdb, 0x62, 0xc6,
+        0xac, 0x55, 0x16, 0x83, 0x36, 0x51, 0x7c, 0xdf, 0x2a, 0xca, 0xad, 0x1c, 0xce, 0x64, 0x50, 0x96,
+        0x50, 0xe6, 0xf4, 0x41, 0xaa, 0xce, 0x8b, 0x1b, 0xaf, 0xaf, 0xdc, 0x51, 0xc9, 0xad, 0x78, 0xcd,
+        0xa7, 0xd4, 0xb9, 0xf4, 0x99, 0x81, 0x6d, 0x90, 0x06, 0x5f, 0x3c, 0x67, 0x5b, 0x7f, 0x22, 0x09,
+        0x19, 0x21, 0xa0, 0x2e, 0x2e, 0x71, 0x0d, 0xde, 0x49, 0xcf, 0xd8, 0x1c, 0x0d, 0x18, 0x43, 0x65,
+        0x61, 0x50, 0xdc, 0xbe, 0x5e, 0x3f, 0x92, 0xde, 0x1e, 0x97, 0xa6, 0x6b, 0x64, 0xe3, 0x94, 0xc0,
+        0x98, 0x39, 0x82, 0xdc, 0x51, 0x01, 0x8a, 0x34, 0xb0, 0xc5, 0xe5, 0xbf, 0xc3, 0x50, 0x0a, 0x1c,
+        0x89, 0xa2, 0xf8, 0x63, 0xab, 0x7f, 0xeb, 0x49, 0x14, 0x27, 0x98, 0xb3, 0x52, 0x48, 0x73, 0x03,
+        0x94, 0x4b, 0x35, 0x38, 0x58, 0x22, 0xde, 0xb6, 0xf6, 0x95, 0x95, 0x65, 0xb1, 0x77, 0x4b, 0xba,
+        0x22, 0xa0, 0x6e, 0x44, 0x38, 0x45, 0x24, 0x93, 0x15, 0x88, 0xbf, 0x27, 0xd7, 0x1d, 0xcb, 0xf8,
+        0x23, 0x6d, 0x98, 0x26, 0xac, 0x9b, 0xc9, 0x79, 0x34, 0x3d, 0x09, 0x32, 0xd4, 0xfd, 0x9c, 0xc1,
+        0x3e, 0xbb, 0xd9, 0x86, 0x9b, 0x56, 0x37, 0xbc, 0x29, 0xb0, 0x84, 0x48, 0x92, 0xe2, 0x11, 0xb2,
+        0x45, 0xe0, 0x70, 0x66, 0x08, 0x69, 0xd2, 0xa0, 0xee, 0xe8, 0x34, 0x9e, 0xba, 0x2c, 0xba, 0x02,
+        0x62, 0x86, 0x67, 0x1f, 0xd1, 0x71, 0xdf, 0xd7, 0x06, 0x2d, 0x33, 0x69, 0x32, 0xbe, 0x4f, 0x50,
+        0x98, 0xdc, 0xfa, 0x3d, 0xbb, 0xbd, 0xaa, 0x32, 0x60, 0x15, 0x99, 0xc7, 0x40, 0x45, 0xb7, 0xda,
+        0xfd, 0xce, 0x08, 0x67, 0x97, 0xed, 0x36, 0xd8, 0x02, 0x17, 0x33, 0xdc, 0x99, 0x27, 0x69, 0x9b,
+        0x4e, 0x61, 0x41, 0x44, 0x80, 0x5e, 0xda, 0x67, 0x08, 0x93, 0x4b, 0xa9, 0x2b, 0x15, 0x4a, 0x40,
+        0x05, 0xb3, 0x3d, 0x10, 0xbe, 0x88, 0xca, 0xbc, 0x1c, 0x69, 0x77, 0xba, 0x3f, 0x73, 0xb0, 0xfc,
+        0x2f, 0x6d, 0x7e, 0x79, 0x10, 0x10, 0x42, 0x11, 0x6b, 0x91, 0x0f, 0x9d, 0xd4, 0xf0, 0x9f, 0xa2,
+        0x94, 0x14, 0x49, 0xc3, 0x4b, 0xf5, 0xf2, 0xf9, 0x1b, 0xfc, 0x3d, 0x38, 0x31, 0xfd, 0x51, 0x4c,
+        0xc1, 0xee, 0x96, 0xec, 0x73, 0x55, 0x2b, 0x74, 0x62, 0x16, 0x39, 0x47, 0xff, 0x80, 0xa1, 0x50,
+        0x90, 0xea, 0x2c, 0x74, 0xb6, 0x71, 0x60, 0xd8, 0x1d, 0x04, 0x87, 0x1e, 0x4e, 0x1c, 0x1b, 0xed,
+        0xca, 0xb8, 0xef, 0xe0, 0x8b, 0x81, 0x7c, 0x66, 0xc5, 0x1a, 0xda, 0xe1, 0xdf, 0x0f, 0xe1, 0x60,
+        0xbf, 0x07, 0x14, 0x7d, 0x08, 0x9f, 0x14, 0xa2, 0x0e, 0x68, 0xbc, 0x38, 0x96, 0xc7, 0xca, 0x03,
+        0x61, 0x13, 0x41, 0xc8, 0x35, 0x45, 0xe3, 0x0e, 0x0a, 0xd0, 0x18, 0x47, 0x45, 0x38, 0xda, 0x88,
+        0xcc, 0xb9, 0xe4, 0x9d, 0x7e, 0x5d, 0x4b, 0x07, 0x1f, 0x43, 0x56, 0x66, 0x63, 0xb5, 0x3f, 0x96,
+        0x02, 0xcb, 0x60, 0xc5, 0x01, 0x4a, 0x85, 0xd9, 0x8c, 0xef, 0xd9, 0x5c, 0x8f, 0xb5, 0x57, 0xc8,
+        0x42, 0x38, 0x99, 0xab, 0xcb, 0x77, 0x29, 0xc3, 0x23, 0xf9, 0xb7, 0x66, 0x0b, 0xa8, 0xa2, 0x6c,
+        0x8d, 0xf4, 0x8a, 0x82, 0xc4, 0xb7, 0xad, 0xc5, 0xc9, 0x8e, 0x20, 0x32, 0x6b, 0x8c, 0x97, 0x0a,
+        0xd0, 0x1f, 0xd2, 0x0f, 0x3c, 0x2e, 0x5e, 0x69, 0x1c, 0x3e, 0x17, 0xcb, 0xb4, 0x54, 0x57, 0xf8,
+        0x7e, 0x8c, 0x91, 0xf8, 0x22, 0xf5, 0x44, 0xd3, 0x37, 0xd9, 0x5b, 0x59, 0xf2, 0x9b, 0xcd, 0x4f,
+        0x03, 0x2f, 0x09, 0x7e, 0x3b, 0xa0, 0xf7, 0xe6, 0x01, 0x02, 0x2c, 0x9a, 0xfa, 0x08, 0x2f, 0xab,
+        0x3d, 0x27, 0xc1, 0x75, 0x22, 0xb6, 0xa3, 0x19, 0x9f, 0x5e, 0xf0, 0xa2, 0x93, 0xc0, 0x1d, 0x6a,
+        0xdd, 0x6b, 0xf9, 0x72, 0x18, 0x9e, 0x27, 0xb9, 0x1c, 0x95, 0x98, 0x73, 0x8b, 0x11, 0x72, 0x54,
+        0x21, 0x3d, 0x5a, 0x03, 0xf7, 0xe4, 0xfb, 0x16, 0xc1, 0x1f, 0x10, 0x54, 0x79, 0xaa, 0xcd, 0x06,
+        0xaa, 0xfa, 0xec, 0x5e, 0x98, 0x4e, 0x79, 0xc1, 0x09, 0xd0, 0x18, 0x8f, 0x55, 0xf9, 0x7e, 0x8a,
+        0x6b, 0x79, 0x1c, 0x63, 0x0d, 0x8d, 0x04, 0x40, 0x30, 0x6b, 0x1a, 0x3d, 0xb4, 0xb8, 0xec, 0xf9,
+        0x18, 0xc2, 0xff, 0x7b, 0x49, 0x56, 0x7c, 0xf7, 0x08, 0x0c, 0x1e, 0xa6, 0x1b, 0x95, 0xa4, 0x48,
+        0xe4, 0xc0, 0x7e, 0x80, 0x6b, 0x8a, 0x63, 0x54, 0xb1, 0xe1, 0x61, 0x52, 0x96, 0x37, 0x5a, 0x7d,
+        0x2a, 0xa8, 0xc4, 0x9e, 0xac, 0x1d, 0xeb, 0xd0, 0x05, 0x23, 0xe2, 0xee, 0xee, 0x61, 0xcf, 0x7f,
+        0x41, 0x8c, 0x8e, 0x9a, 0xdf, 0x6f, 0xf8, 0xdb, 0xd3, 0x9f, 0xd6, 0x71, 0x19, 0xa8, 0x4e, 0x1e,
+        0x18, 0x9e, 0x02, 0x3c, 0x4a, 0xea, 0x74, 0x45, 0x10, 0x00,
+    ];
+}
+
+pub const VERIFIER_KEYS: &[VerifierKeyEntry] = &[
+    VerifierKeyEntry {
+        circuit_id: circuit_1::CIRCUIT_ID,
+        circuit_version: circuit_1::CIRCUIT_VERSION,
+        key_hash: circuit_1::VERIFYING_KEY_HASH,
+        prepared_key: circuit_1::VERIFYING_KEY_BYTES,
+        evm_key: circuit_1::VERIFYING_KEY_EVM,
+        nullifier: circuit_1::NULLIFIER,
+    },
+];
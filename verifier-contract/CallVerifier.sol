@@ -1,15 +1,60 @@
 // SPDX-License-Identifier: Apache-2.0
-pragma solidity ^0.8.0;
+pragma solidity ^0.8.5;
 
-/// Wrapper to call the Rust contract's exported `call()` with encoded calldata
+/// ABI of the Rust verifier contract (see `verifier_core::abi`)
+interface INiet2codeVerifier {
+    /// `proof` is the 128-byte compressed Groth16 proof. Returns `true` or reverts with
+    /// one of the errors below; `legacy-bool` builds return `false` instead. In both
+    /// builds, calldata shorter than a selector or with an unknown selector reverts
+    /// with `MalformedCalldata()` or `UnknownSelector(bytes4)`.
+    /// Not `view`: circuits with replay protection record the proof's nullifier.
+    function verify(uint256 circuitId, bytes calldata proof, uint256[] calldata inputs) external returns (bool);
+    /// SHA-256 of the circuit's compressed verifying key, zero if unknown
+    function vkHash(uint256 circuitId) external view returns (bytes32);
+    function version() external view returns (string memory);
+    function isUsed(bytes32 nullifier) external view returns (bool);
+
+    event NullifierUsed(bytes32 indexed nullifier, uint256 indexed circuitId);
+
+    error MalformedCalldata();
+    error UnknownCircuit();
+    error DeprecatedKey();
+    error MalformedVerifyingKey();
+    error MalformedProof();
+    error NonCanonicalPublicInput();
+    error PublicInputCount(uint256 expected, uint256 actual);
+    error NullifierAlreadyUsed();
+    error InvalidProof();
+    error NotOwner();
+    error UnknownSelector(bytes4 selector);
+    error PrecompileFailed();
+}
+
+/// Wrapper that forwards a proof to the deployed Rust verifier contract and
+/// answers `false` for a rejected proof, whichever result mode the contract was
+/// built with. Malformed calls still revert.
 contract VerifyFromSolidity {
-    /// Calls the deployed Rust contract and passes ABI-compatible calldata
-    function verify(bytes calldata proofAndInput, address rustContract) external view returns (bool result) {
-        (bool success, bytes memory returnData) = rustContract.staticcall(proofAndInput);
-        require(success, "Rust contract call failed");
-
-        // Expect a single bool (last byte is 0 or 1)
-        require(returnData.length == 32, "Invalid return data");
-        result = returnData[31] != 0;
+    function verify(
+        address rustContract,
+        uint256 circuitId,
+        bytes calldata proof,
+        uint256[] calldata inputs
+    ) external returns (bool) {
+        try INiet2codeVerifier(rustContract).verify(circuitId, proof, inputs) returns (bool ok) {
+            return ok;
+        } catch (bytes memory reason) {
+            bytes4 selector = bytes4(reason);
+            if (
+                selector == INiet2codeVerifier.InvalidProof.selector ||
+                selector == INiet2codeVerifier.UnknownCircuit.selector ||
+                selector == INiet2codeVerifier.DeprecatedKey.selector ||
+                selector == INiet2codeVerifier.NullifierAlreadyUsed.selector
+            ) {
+                return false;
+            }
+            assembly {
+                revert(add(reason, 32), mload(reason))
+            }
+        }
     }
 }
@@ -0,0 +1,56 @@
+// Locate the verifier key table written by `prove` and copy it into OUT_DIR
+// for `include!`. Resolution mirrors `prover::workspace`:
+//   1. NIET2CODE_VK_RS (explicit path to a verifier_keys.rs)
+//   2. $NIET2CODE_HOME/projects/$NIET2CODE_PROJECT/keys/verifier_keys.rs
+//   3. $XDG_DATA_HOME/niet2code/... or ~/.local/share/niet2code/...
+// With the `native-host` feature (native tests) the default is the checked-in
+// test-vector key table in ../test-vectors/mul instead of the workspace.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+const MODULE: &str = "verifier_keys.rs";
+
+fn main() {
+    for var in ["NIET2CODE_VK_RS", "NIET2CODE_HOME", "NIET2CODE_PROJECT", "XDG_DATA_HOME", "HOME"] {
+        println!("cargo:rerun-if-env-changed={}", var);
+    }
+
+    let source = locate_module();
+    println!("cargo:rerun-if-changed={}", source.display());
+
+    let module = fs::read_to_string(&source).unwrap_or_else(|e| {
+        panic!(
+            "cannot read verifying key module {}: {}\nrun `niet2code-cli prove` first or set NIET2CODE_VK_RS",
+            source.display(),
+            e
+        )
+    });
+
+    let out = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR not set")).join(MODULE);
+    fs::write(out, module).expect("failed to write verifying key module to OUT_DIR");
+}
+
+fn locate_module() -> PathBuf {
+    if let Some(path) = non_empty_env("NIET2CODE_VK_RS") {
+        return PathBuf::from(path);
+    }
+    if env::var_os("CARGO_FEATURE_NATIVE_HOST").is_some() {
+        let manifest_dir = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set");
+        return PathBuf::from(manifest_dir).join("../test-vectors/mul").join(MODULE);
+    }
+
+    let home = non_empty_env("NIET2CODE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| non_empty_env("XDG_DATA_HOME").map(|dir| PathBuf::from(dir).join("niet2code")))
+        .or_else(|| non_empty_env("HOME").map(|dir| PathBuf::from(dir).join(".local/share/niet2code")))
+        .expect("cannot resolve niet2code home: set NIET2CODE_VK_RS or NIET2CODE_HOME");
+    let project = non_empty_env("NIET2CODE_PROJECT").unwrap_or_else(|| "default".to_string());
+
+    home.join("projects").join(project).join("keys").join(MODULE)
+}
+
+fn non_empty_env(name: &str) -> Option<String> {
+    env::var(name).ok().filter(|value| !value.is_empty())
+}
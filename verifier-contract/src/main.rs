@@ -10,176 +10,460 @@
     ## Highlights:
 
     - Compiles to `no_std` and targets the `riscv64emac-unknown-none-polkavm` architecture.
-    - Verifier logic implemented in Rust using the `arkworks` Groth16 backend.
-    - Takes ABI-compatible calldata (selector + proof + input), verifies it, and returns a boolean result.
-    - Uses a custom dummy allocator to support builds in environments without heap support.
-
-    ## Expected Calldata Format:
-
-    - 4 bytes: function selector (ignored for now)
-    - 256 bytes: Groth16 proof (A: G1 = 64, B: G2 = 128, C: G1 = 64)
-    - 32 bytes: Public input (Fr element from BN254)
-
-    Total: 292 bytes
+    - Thin wrapper over `verifier-core`, which holds the decoding and Groth16
+      verification and is unit-tested natively.
+    - Runs the multi-scalar multiplication and pairing through the chain's BN254
+      precompiles (`ecAdd` 0x06, `ecMul` 0x07, `ecPairing` 0x08; feature `precompiles`)
+      and falls back to the arkworks pairing when they are missing (feature `arkworks`).
+      Both are on by default; see the README for the blob-size and cost trade-off.
+    - Dispatches Solidity ABI calls by selector over a table of keys, one per circuit
+      in the prover's registry (`prover::circuit::CIRCUITS`).
+    - Host functions, the bump allocator and the panic handler come from `contract-host`,
+      shared with `registry-contract`.
+
+    ## Interface:
+
+    - `verify(uint256 circuitId, bytes proof, uint256[] inputs) returns (bool)` (0xb11870c7)
+      `proof` is the 128-byte compressed Groth16 proof (A: G1, B: G2, C: G1) or the
+      256-byte uncompressed proof in the precompile layout; inputs must be canonical
+      BN254 Fr elements. Returns `true`, or reverts with the custom
+      error for the failure class (`InvalidProof()`, `UnknownCircuit()`, ...; see
+      `verifier_core::errors`). Built with `--features legacy-bool`, every failure
+      of a call with the `verify` selector returns `false` instead, as
+      `CallVerifier.sol` expects. Calldata shorter than a selector, or with an
+      unknown one, still reverts (`MalformedCalldata()`, `UnknownSelector(bytes4)`).
+    - `vkHash(uint256 circuitId) returns (bytes32)` (0x8f4fbd7a)
+      SHA-256 of the circuit's compressed verifying key (the CLI's `sha256:`
+      fingerprint), or zero for an unknown circuit.
+    - `version() returns (string)` (0x54fd4d50)
+    - `owner() returns (address)` (0x8da5cb5b), the account that ran `deploy()`
+    - `isUsed(bytes32 nullifier) returns (bool)` (0xfe255a18)
+      Circuits registered with a `NullifierMode` other than `Off` accept each
+      nullifier once: `verify` records it, emits `NullifierUsed(bytes32 indexed,
+      uint256 indexed circuitId)` and rejects later replays with `NullifierAlreadyUsed()`.
+    - `registerKey(uint256 vkId, uint32 circuitVersion, bytes verifyingKey)` (0xa822c4f9),
+      owner only: store an uncompressed verifying key for `vkId` in contract storage,
+      replacing any stored or compiled-in key with that id.
+    - `deprecateKey(uint256 vkId)` (0xf9b746db), owner only: proofs for `vkId` no longer verify.
+
+    Unknown selectors (`UnknownSelector(bytes4)`), truncated calls
+    (`MalformedCalldata()`) and admin calls from anyone but the owner (`NotOwner()`)
+    revert in both result modes. `niet2code-cli admin` encodes the admin calls.
 
     ## Deployment and Use:
 
-    - Embed the verifying key at compile time using `verifying_key_bytes.rs`.
+    - Embed the prepared verifying keys at compile time using `verifier_keys.rs`,
+      generated by `prover::key_artifact` (`prove` in the CLI). `build.rs` picks it
+      up from the niet2code workspace (`NIET2CODE_HOME`, `NIET2CODE_PROJECT`) or from
+      an explicit `NIET2CODE_VK_RS` path. The build fails if the module was written
+      with an encoding other than the one `call()` decodes.
     - Use an off-chain prover to generate the calldata.
-    - Deploy the contract using `deploy()`.
+    - Deploy the contract using `deploy()`; the deployer becomes the owner.
+    - Rotate keys without redeploying via `registerKey` / `deprecateKey`.
     - Call the contract with `call()` and calldata to perform verification.
 
+    ## Native Tests:
+
+    With `--no-default-features --features native-host,precompiles,arkworks` the uapi host is replaced by
+    an in-process mock (`contract-host/src/native_host.rs`) and the contract builds as a normal x86_64
+    binary, so `deploy()` and `call()` run under `cargo test` (`make test`).
+
 */
 
-#![feature(alloc_error_handler)]
-#![no_std]
-#![no_main]
+#![cfg_attr(not(feature = "native-host"), no_std)]
+#![cfg_attr(not(feature = "native-host"), no_main)]
 
-use core::{
-    alloc::{GlobalAlloc, Layout},
-    panic::PanicInfo,
-    ptr::null_mut,
-};
+#[cfg(not(any(feature = "precompiles", feature = "arkworks")))]
+compile_error!("enable `precompiles`, `arkworks` or both: the contract needs a way to check the pairing");
+
+// Allocator, panic handler and the `Host` adapter over uapi (or the native mock)
+use contract_host::ContractHost;
+#[cfg(test)]
+use contract_host::native_host;
+
+use verifier_core::dispatch::{self, dispatch, ResultMode, VerifierKeyEntry};
+use verifier_core::nullifier::NullifierMode;
+
+//--------------------------------------------------------------------------
+// 1.  Embed the verifier key table (generated off-chain)
+//--------------------------------------------------------------------------
 
-use uapi::{HostFn, HostFnImpl as api, ReturnFlags};
+// Copied into OUT_DIR by build.rs from the niet2code workspace (or NIET2CODE_VK_RS).
+// Defines one `circuit_<id>` module per key and the `VERIFIER_KEYS` table.
+include!(concat!(env!("OUT_DIR"), "/verifier_keys.rs"));
 
-use ark_bn254::{Bn254, Fr};
-use ark_groth16::{prepare_verifying_key, Groth16, Proof, VerifyingKey};
-use ark_serialize::CanonicalDeserialize;
+// Keys are embedded prepared (e(alpha, beta) and G2 line coefficients
+// precomputed) so `call()` skips `prepare_verifying_key`; refuse to build
+// against a module generated with any other encoding.
+const _: () = assert!(VERIFYING_KEY_PREPARED, "verifier_keys.rs must use the prepared encoding; rerun `prove`");
+const _: () = assert!(!VERIFYING_KEY_COMPRESSED);
+const _: () = assert!(!VERIFIER_KEYS.is_empty(), "verifier_keys.rs has no keys; rerun `prove`");
 
 //--------------------------------------------------------------------------
-// 1.  Static bump allocator (512 KiB)
+// 2.  PolkaVM entry points
 //--------------------------------------------------------------------------
 
-const HEAP_SIZE: usize = 512 * 1024;
+/// How `verify` reports failures: custom errors by default, a plain `false`
+/// with `--features legacy-bool` for callers that only decode a bool
+#[cfg(not(feature = "legacy-bool"))]
+const RESULT_MODE: ResultMode = ResultMode::Errors;
+#[cfg(feature = "legacy-bool")]
+const RESULT_MODE: ResultMode = ResultMode::Bool;
+
+#[no_mangle]
+#[cfg_attr(not(feature = "native-host"), polkavm_derive::polkavm_export)]
+pub extern "C" fn deploy() {
+    dispatch::deploy(&mut ContractHost);
+}
+
+#[no_mangle]
+#[cfg_attr(not(feature = "native-host"), polkavm_derive::polkavm_export)]
+pub extern "C" fn call() {
+    handle_call();
+}
+
+/// Body of `call()`. Kept as a Rust-ABI function so the native mock host can
+/// unwind out of `return_value` (unwinding through `extern "C"` aborts).
+fn handle_call() {
+    // Selector routing, ABI decoding and verification live in verifier-core
+    // (`dispatch`); this only moves bytes between the host and it.
+    let calldata = contract_host::call_data();
+    let response = dispatch(&mut ContractHost, &calldata, VERIFIER_KEYS, RESULT_MODE);
+    contract_host::return_value(response.revert, &response.data);
+}
 
-static mut HEAP: [u8; HEAP_SIZE] = [0; HEAP_SIZE];
-static mut OFFSET: usize = 0;
+#[cfg(feature = "native-host")]
+fn main() {}
 
-struct Bump;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use native_host::ContractReturn;
+    #[cfg(feature = "precompiles")]
+    use verifier_core::precompile::Bn254Precompile;
+    use verifier_core::abi::{self, VERSION_SELECTOR, VK_HASH_SELECTOR};
+    use verifier_core::errors::ContractError;
 
-unsafe impl GlobalAlloc for Bump {
-    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
-        let align = layout.align();
-        let size = layout.size();
+    const CALLDATA: &[u8] = include_bytes!("../../test-vectors/mul/calldata.bin");
 
-        // Align current offset
-        let mis = OFFSET % align;
-        if mis != 0 {
-            OFFSET += align - mis;
+    fn call_with(calldata: &[u8]) -> ContractReturn {
+        native_host::reset(calldata);
+        native_host::run(handle_call).expect("call() did not return a value")
+    }
+
+    fn returned_bool(ret: &ContractReturn) -> bool {
+        assert!(!ret.reverted());
+        assert_eq!(ret.data.len(), 32);
+        assert!(ret.data[..31].iter().all(|&b| b == 0));
+        ret.data[31] == 1
+    }
+
+    /// A failed `verify`: the custom error, or plain `false` in `legacy-bool` builds
+    fn assert_rejected(ret: &ContractReturn, expected: ContractError) {
+        match RESULT_MODE {
+            ResultMode::Errors => {
+                assert!(ret.reverted());
+                assert_eq!(ContractError::decode(&ret.data), Some(expected));
+            }
+            ResultMode::Bool => assert!(!returned_bool(ret)),
         }
+    }
+
+    fn assert_reverted_with(ret: &ContractReturn, expected: ContractError) {
+        assert!(ret.reverted());
+        assert_eq!(ContractError::decode(&ret.data), Some(expected));
+    }
+
+    #[test]
+    fn test_deploy_returns_without_output() {
+        native_host::reset(&[]);
+        assert_eq!(native_host::run(|| deploy()), None);
+    }
 
-        if OFFSET + size > HEAP_SIZE {
-            return null_mut(); // triggers trap → ContractTrapped
+    #[test]
+    fn test_valid_proof_returns_true() {
+        assert!(returned_bool(&call_with(CALLDATA)));
+    }
+
+    #[test]
+    fn test_corrupted_proof_is_rejected() {
+        let mut calldata = CALLDATA.to_vec();
+        calldata[4 + 5 * 32 + 10] ^= 0x01;
+        let ret = call_with(&calldata);
+        match RESULT_MODE {
+            ResultMode::Errors => assert!(matches!(
+                ContractError::decode(&ret.data),
+                Some(ContractError::InvalidProof | ContractError::MalformedProof)
+            )),
+            ResultMode::Bool => assert!(!returned_bool(&ret)),
         }
+    }
 
-        let ptr = HEAP.as_mut_ptr().add(OFFSET);
-        OFFSET += size;
+    #[test]
+    fn test_wrong_public_input_is_rejected() {
+        let mut calldata = CALLDATA.to_vec();
+        *calldata.last_mut().unwrap() ^= 0x01;
+        assert_rejected(&call_with(&calldata), ContractError::InvalidProof);
+    }
 
-        ptr
+    #[test]
+    fn test_unknown_circuit_is_rejected() {
+        let mut calldata = CALLDATA.to_vec();
+        calldata[4 + 31] = 0xff;
+        assert_rejected(&call_with(&calldata), ContractError::UnknownCircuit);
     }
 
-    unsafe fn dealloc(&self, _ptr: *mut u8, _layout: Layout) {
-        /* no-op */
+    #[test]
+    fn test_truncated_verify_call_is_rejected() {
+        assert_rejected(&call_with(&CALLDATA[..100]), ContractError::MalformedCalldata);
     }
-}
 
-#[global_allocator]
-static ALLOC: Bump = Bump;
+    #[test]
+    fn test_unknown_selector_and_empty_calldata_revert() {
+        let mut calldata = CALLDATA.to_vec();
+        calldata[0] ^= 0xff;
+        let selector = [calldata[0], calldata[1], calldata[2], calldata[3]];
+        assert_reverted_with(&call_with(&calldata), ContractError::UnknownSelector(selector));
+        assert_reverted_with(&call_with(&[]), ContractError::MalformedCalldata);
+    }
 
-#[alloc_error_handler]
-fn oom(_: Layout) -> ! {
-    unsafe {
-        core::arch::asm!("unimp");
-        core::hint::unreachable_unchecked()
+    #[test]
+    fn test_legacy_bool_mode_returns_false() {
+        let mut calldata = CALLDATA.to_vec();
+        *calldata.last_mut().unwrap() ^= 0x01;
+        native_host::reset(&[]);
+        for (data, expected) in [(CALLDATA, true), (&calldata[..], false), (&CALLDATA[..100], false)] {
+            let response = dispatch(&mut ContractHost, data, VERIFIER_KEYS, ResultMode::Bool);
+            assert!(!response.revert);
+            assert_eq!(response.data, abi::encode_bool(expected));
+        }
     }
-}
 
-//--------------------------------------------------------------------------
-// 2.  Panic → PolkaVM trap
-//--------------------------------------------------------------------------
+    #[test]
+    fn test_vk_hash_returns_embedded_key_hash() {
+        let mut calldata = VK_HASH_SELECTOR.to_vec();
+        calldata.extend_from_slice(&abi::uint_word(circuit_1::CIRCUIT_ID));
+        assert_eq!(call_with(&calldata).data, circuit_1::VERIFYING_KEY_HASH);
 
-#[panic_handler]
-fn panic(_: &PanicInfo) -> ! {
-    unsafe {
-        core::arch::asm!("unimp");
-        core::hint::unreachable_unchecked()
+        calldata[4 + 31] = 0xff;
+        assert_eq!(call_with(&calldata).data, [0u8; 32]);
     }
-}
 
-//--------------------------------------------------------------------------
-// 3.  Embed verifying-key bytes (generated off-chain)
-//--------------------------------------------------------------------------
+    #[test]
+    fn test_version_returns_abi_string() {
+        let ret = call_with(&VERSION_SELECTOR);
+        assert!(!ret.reverted());
+        assert_eq!(ret.data, abi::encode_string(verifier_core::dispatch::VERSION));
+    }
 
-include!("../../keys/verifying_key_bytes.rs");
+    const OWNER: [u8; 20] = [0x11; 20];
 
-//--------------------------------------------------------------------------
-// 4.  PolkaVM entry points
-//--------------------------------------------------------------------------
+    /// Deploy as `OWNER`, keeping storage for the calls that follow
+    fn deploy_as_owner() {
+        native_host::reset(&[]);
+        native_host::set_caller(OWNER);
+        assert_eq!(native_host::run(|| deploy()), None);
+    }
 
-#[no_mangle]
-#[polkavm_derive::polkavm_export]
-pub extern "C" fn deploy() {}
+    /// Call against the current storage, as `caller`
+    fn call_as(caller: [u8; 20], calldata: &[u8]) -> ContractReturn {
+        native_host::set_caller(caller);
+        native_host::set_call_data(calldata);
+        native_host::run(handle_call).expect("call() did not return a value")
+    }
 
-#[no_mangle]
-#[polkavm_derive::polkavm_export]
-pub extern "C" fn call() {
-    // ┌──────────┬──────────────────────────┬───────────────────────┐
-    // │ 0..3 sel │ 4..131 compressed Proof │ 132..163 public input │
-    // └──────────┴──────────────────────────┴───────────────────────┘
+    fn verify_call(vk_id: u64) -> Vec<u8> {
+        let call = abi::decode_verify_args(&CALLDATA[4..]).unwrap();
+        verifier_core::encode_calldata(vk_id, &call.proof, &call.public_inputs).unwrap()
+    }
 
-    let mut calldata = [0u8; 164];
-    api::call_data_copy(&mut calldata, 0);
+    #[test]
+    fn test_deploy_records_owner() {
+        deploy_as_owner();
+        assert_eq!(call_as([0x22; 20], &abi::OWNER_SELECTOR).data, abi::encode_address(&OWNER));
+    }
 
-    let proof_bytes = &calldata[4..132];
-    let input_bytes = &calldata[132..164];
+    #[test]
+    fn test_owner_registers_key_in_storage() {
+        const PLAIN_KEY: &[u8] = include_bytes!("../../test-vectors/mul/verifying_key.bin");
+        deploy_as_owner();
+        assert_rejected(&call_as(OWNER, &verify_call(42)), ContractError::UnknownCircuit);
 
-    // --------  Deserialize verifying-key (once per call) --------
+        let register = abi::encode_register_key_call(42, 1, PLAIN_KEY);
+        assert_reverted_with(&call_as([0x22; 20], &register), ContractError::NotOwner);
+        assert_rejected(&call_as(OWNER, &verify_call(42)), ContractError::UnknownCircuit);
 
-    let mut vk_src = VERIFYING_KEY_BYTES;
+        let ret = call_as(OWNER, &register);
+        assert!(!ret.reverted());
+        assert!(ret.data.is_empty());
+        assert!(returned_bool(&call_as([0x22; 20], &verify_call(42))));
 
-    // --------  Deserialize verifying-key (once per call) --------
+        let mut vk_hash = VK_HASH_SELECTOR.to_vec();
+        vk_hash.extend_from_slice(&abi::uint_word(42));
+        assert_eq!(call_as(OWNER, &vk_hash).data, circuit_1::VERIFYING_KEY_HASH);
+    }
 
-    let vk: VerifyingKey<Bn254> = match VerifyingKey::deserialize_uncompressed(&mut vk_src) {
-        Ok(vk) => vk,
-        Err(_) => {
-            return_bool(false);
-            return;
-        }
-    };
+    #[test]
+    fn test_owner_deprecates_compiled_in_key() {
+        deploy_as_owner();
+        let deprecate = abi::encode_deprecate_key_call(circuit_1::CIRCUIT_ID);
+        assert_reverted_with(&call_as([0x22; 20], &deprecate), ContractError::NotOwner);
+        assert!(returned_bool(&call_as(OWNER, CALLDATA)));
 
-    // --------  Deserialize proof & public input  --------
+        assert!(!call_as(OWNER, &deprecate).reverted());
+        assert_rejected(&call_as(OWNER, CALLDATA), ContractError::DeprecatedKey);
+    }
 
-    let proof: Proof<Bn254> = match Proof::deserialize_compressed(&mut &*proof_bytes) {
-        Ok(p) => p,
-        Err(_) => {
-            return_bool(false);
-            return;
-        }
-    };
+    #[test]
+    fn test_nullifier_mode_rejects_replays_and_emits_event() {
+        let keys = [VerifierKeyEntry {
+            nullifier: NullifierMode::ProofHash,
+            ..VERIFIER_KEYS[0]
+        }];
+        let call = abi::decode_verify_args(&CALLDATA[4..]).unwrap();
+        let nullifier = verifier_core::nullifier::nullifier(NullifierMode::ProofHash, &call).unwrap().unwrap();
+        let mut is_used = abi::IS_USED_SELECTOR.to_vec();
+        is_used.extend_from_slice(&nullifier);
+
+        deploy_as_owner();
+        assert_eq!(call_as(OWNER, &is_used).data, abi::encode_bool(false));
+
+        let first = dispatch(&mut ContractHost, CALLDATA, &keys, ResultMode::Errors);
+        let replay = dispatch(&mut ContractHost, CALLDATA, &keys, ResultMode::Errors);
+        let legacy_replay = dispatch(&mut ContractHost, CALLDATA, &keys, ResultMode::Bool);
+        assert_eq!(first.data, abi::encode_bool(true));
+        assert!(replay.revert);
+        assert_eq!(replay.data, ContractError::NullifierAlreadyUsed.encode());
+        assert_eq!(legacy_replay.data, abi::encode_bool(false));
+
+        assert_eq!(call_as(OWNER, &is_used).data, abi::encode_bool(true));
+        assert_eq!(
+            native_host::events(),
+            vec![native_host::Event {
+                topics: vec![verifier_core::nullifier::NULLIFIER_USED_TOPIC, nullifier, abi::uint_word(1)],
+                data: vec![],
+            }]
+        );
+    }
 
-    let public: Fr = match Fr::deserialize_compressed(&mut &*input_bytes) {
-        Ok(f) => f,
-        Err(_) => {
-            return_bool(false);
-            return;
+    #[test]
+    fn test_compiled_in_circuit_is_stateless_by_default() {
+        assert_eq!(circuit_1::NULLIFIER, NullifierMode::Off);
+        deploy_as_owner();
+        assert!(returned_bool(&call_as(OWNER, CALLDATA)));
+        assert!(returned_bool(&call_as(OWNER, CALLDATA)));
+        assert!(native_host::events().is_empty());
+    }
+
+    const EVM_CALLDATA: &[u8] = include_bytes!("../../test-vectors/mul/evm_calldata.bin");
+
+    /// `call()` on fresh storage, with the BN254 precompiles present or not
+    fn call_with_precompiles(calldata: &[u8], enabled: bool) -> ContractReturn {
+        native_host::reset(calldata);
+        native_host::set_precompiles(enabled);
+        native_host::run(handle_call).expect("call() did not return a value")
+    }
+
+    #[test]
+    #[cfg(feature = "precompiles")]
+    fn test_precompiles_verify_both_proof_layouts() {
+        for calldata in [CALLDATA, EVM_CALLDATA] {
+            assert!(returned_bool(&call_with_precompiles(calldata, true)));
+            let used: Vec<_> = native_host::precompile_calls().iter().map(|call| call.precompile).collect();
+            assert_eq!(used, [Bn254Precompile::Mul, Bn254Precompile::Add, Bn254Precompile::Pairing]);
+
+            let mut wrong_input = calldata.to_vec();
+            *wrong_input.last_mut().unwrap() ^= 0x01;
+            assert_rejected(&call_with_precompiles(&wrong_input, true), ContractError::InvalidProof);
         }
-    };
+    }
 
-    // --------  Verify  --------
+    #[test]
+    fn test_missing_precompiles_fall_back_to_arkworks() {
+        for calldata in [CALLDATA, EVM_CALLDATA] {
+            let ret = call_with_precompiles(calldata, false);
+            if cfg!(feature = "arkworks") {
+                assert!(returned_bool(&ret));
+            } else {
+                assert_rejected(&ret, ContractError::PrecompileFailed);
+            }
+            assert!(native_host::precompile_calls().is_empty());
+        }
+    }
 
-    let pvk = prepare_verifying_key(&vk);
-    let valid = Groth16::<Bn254>::verify_proof(&pvk, &proof, &[public]).unwrap_or(false);
+    /// Native cost of one `verify` with each backend. The precompiles run in a
+    /// software stand-in here, so their time is subtracted: what remains is the
+    /// work done inside the contract. Run with:
+    /// `cargo test --release --no-default-features --features native-host,precompiles,arkworks --target <host> -- --ignored --nocapture`
+    #[test]
+    #[ignore]
+    #[cfg(all(feature = "precompiles", feature = "arkworks"))]
+    fn report_precompile_cost() {
+        use std::time::{Duration, Instant};
+
+        const ITERATIONS: u32 = 50;
+
+        /// Mean ms per call: (whole call, inside the emulated precompiles)
+        fn average(calldata: &[u8], precompiles: bool) -> (f64, f64) {
+            let (mut total, mut emulated) = (Duration::ZERO, Duration::ZERO);
+            for _ in 0..ITERATIONS {
+                let start = Instant::now();
+                assert!(returned_bool(&call_with_precompiles(calldata, precompiles)));
+                total += start.elapsed();
+                emulated += native_host::precompile_calls().iter().map(|call| call.elapsed).sum::<Duration>();
+            }
+            let ms = |d: Duration| d.as_secs_f64() * 1000.0 / ITERATIONS as f64;
+            (ms(total), ms(emulated))
+        }
 
-    return_bool(valid);
-}
+        println!("| Backend | Proof | Full call (ms) | In precompiles (ms) | Contract-side (ms) |");
+        println!("|---------|-------|----------------|---------------------|--------------------|");
+        for (backend, proof, calldata, precompiles) in [
+            ("arkworks", "compressed", CALLDATA, false),
+            ("precompiles", "compressed", CALLDATA, true),
+            ("precompiles", "EVM layout", EVM_CALLDATA, true),
+        ] {
+            let (total, emulated) = average(calldata, precompiles);
+            println!("| {} | {} | {:.3} | {:.3} | {:.3} |", backend, proof, total, emulated, total - emulated);
+        }
+        for call in native_host::precompile_calls() {
+            println!("{:?}: {} input bytes", call.precompile, call.input_len);
+        }
+    }
 
-//--------------------------------------------------------------------------
-// 5.  Helper: return Solidity-style bool (32-byte word)
-//--------------------------------------------------------------------------
+    /// Native timing of the per-call key handling, before and after embedding the
+    /// prepared key. Wall-clock on the build machine is only a proxy for PolkaVM
+    /// weight. Run with:
+    /// `cargo test --release --no-default-features --features native-host,arkworks --target <host> -- --ignored --nocapture`
+    #[test]
+    #[ignore]
+    #[cfg(feature = "arkworks")]
+    fn report_prepared_key_cost() {
+        use std::time::Instant;
+
+        const PLAIN_KEY: &[u8] = include_bytes!("../../test-vectors/mul/verifying_key.bin");
+        const ITERATIONS: u32 = 50;
+
+        fn average(f: impl Fn()) -> f64 {
+            let start = Instant::now();
+            for _ in 0..ITERATIONS {
+                f();
+            }
+            start.elapsed().as_secs_f64() * 1000.0 / ITERATIONS as f64
+        }
 
-fn return_bool(b: bool) {
-    let mut out = [0u8; 32];
-    out[31] = b as u8;
-    api::return_value(ReturnFlags::empty(), &out);
-}
\ No newline at end of file
+        let plain_decode = average(|| {
+            verifier_core::decode_verifying_key(PLAIN_KEY).unwrap();
+        });
+        let prepared_decode = average(|| {
+            verifier_core::decode_prepared_verifying_key(circuit_1::VERIFYING_KEY_BYTES).unwrap();
+        });
+        let plain_call = average(|| assert!(verifier_core::verify_calldata(CALLDATA, PLAIN_KEY).is_valid()));
+        let prepared_call = average(|| assert!(returned_bool(&call_with(CALLDATA))));
+
+        println!("| Path | Key bytes | Key decode (ms) | Full call (ms) |");
+        println!("|------|-----------|-----------------|----------------|");
+        println!("| plain key, prepared per call | {} | {:.3} | {:.3} |", PLAIN_KEY.len(), plain_decode, plain_call);
+        println!("| embedded prepared key | {} | {:.3} | {:.3} |", circuit_1::VERIFYING_KEY_BYTES.len(), prepared_decode, prepared_call);
+    }
+}
@@ -0,0 +1,259 @@
+// Solidity ABI encoding for the verifier contract's interface:
+//
+//   verify(uint256 circuitId, bytes proof, uint256[] inputs) returns (bool)
+//   vkHash(uint256 circuitId) returns (bytes32)
+//   version() returns (string)
+//   owner() returns (address)
+//   isUsed(bytes32 nullifier) returns (bool)
+//   registerKey(uint256 vkId, uint32 circuitVersion, bytes verifyingKey)   owner only
+//   deprecateKey(uint256 vkId)                                             owner only
+//
+// `proof` is the 128-byte compressed Groth16 proof, or the 256-byte uncompressed
+// proof in the EVM precompile layout (see `precompile`); each input is a
+// big-endian `uint256` that must be a canonical BN254 scalar. Decoding bounds-checks every
+// offset and length, so arbitrary calldata can be fed in without panicking.
+
+use alloc::vec::Vec;
+
+use ark_bn254::{Bn254, Fr};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+
+use crate::groth16::Proof;
+use crate::host::Address;
+use crate::{decode_public_input, encode_public_input, precompile, RejectReason, EVM_PROOF_LEN, PROOF_LEN};
+
+pub const WORD: usize = 32;
+pub const SELECTOR_LEN: usize = 4;
+
+/// keccak256("verify(uint256,bytes,uint256[])")[..4]
+pub const VERIFY_SELECTOR: [u8; SELECTOR_LEN] = [0xb1, 0x18, 0x70, 0xc7];
+/// keccak256("vkHash(uint256)")[..4]
+pub const VK_HASH_SELECTOR: [u8; SELECTOR_LEN] = [0x8f, 0x4f, 0xbd, 0x7a];
+/// keccak256("version()")[..4]
+pub const VERSION_SELECTOR: [u8; SELECTOR_LEN] = [0x54, 0xfd, 0x4d, 0x50];
+/// keccak256("owner()")[..4]
+pub const OWNER_SELECTOR: [u8; SELECTOR_LEN] = [0x8d, 0xa5, 0xcb, 0x5b];
+/// keccak256("isUsed(bytes32)")[..4]
+pub const IS_USED_SELECTOR: [u8; SELECTOR_LEN] = [0xfe, 0x25, 0x5a, 0x18];
+/// keccak256("registerKey(uint256,uint32,bytes)")[..4]
+pub const REGISTER_KEY_SELECTOR: [u8; SELECTOR_LEN] = [0xa8, 0x22, 0xc4, 0xf9];
+/// keccak256("deprecateKey(uint256)")[..4]
+pub const DEPRECATE_KEY_SELECTOR: [u8; SELECTOR_LEN] = [0xf9, 0xb7, 0x46, 0xdb];
+
+/// Decoded arguments of `verify`
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifyCall {
+    pub circuit_id: u64,
+    pub proof: Proof<Bn254>,
+    pub public_inputs: Vec<Fr>,
+}
+
+/// Decoded arguments of `registerKey`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegisterKeyCall<'a> {
+    pub vk_id: u64,
+    pub circuit_version: u32,
+    /// Uncompressed `VerifyingKey`
+    pub verifying_key: &'a [u8],
+}
+
+/// Split calldata into selector and argument bytes
+pub fn split_selector(calldata: &[u8]) -> Result<([u8; SELECTOR_LEN], &[u8]), RejectReason> {
+    if calldata.len() < SELECTOR_LEN {
+        return Err(RejectReason::MalformedCalldata);
+    }
+    let mut selector = [0u8; SELECTOR_LEN];
+    selector.copy_from_slice(&calldata[..SELECTOR_LEN]);
+    Ok((selector, &calldata[SELECTOR_LEN..]))
+}
+
+/// Encode a full `verify(circuitId, proof, inputs)` call, selector included
+pub fn encode_verify_call(circuit_id: u64, proof: &Proof<Bn254>, public_inputs: &[Fr]) -> Result<Vec<u8>, SerializationError> {
+    let mut proof_bytes = Vec::with_capacity(PROOF_LEN);
+    proof.serialize_compressed(&mut proof_bytes)?;
+    Ok(encode_verify_call_with(circuit_id, &proof_bytes, public_inputs))
+}
+
+/// Encode a `verify` call carrying the uncompressed proof in the precompile layout,
+/// which spares the contract decompressing B on G2
+pub fn encode_evm_verify_call(circuit_id: u64, proof: &Proof<Bn254>, public_inputs: &[Fr]) -> Vec<u8> {
+    encode_verify_call_with(circuit_id, &precompile::encode_proof(proof), public_inputs)
+}
+
+fn encode_verify_call_with(circuit_id: u64, proof_bytes: &[u8], public_inputs: &[Fr]) -> Vec<u8> {
+    let input_words: Vec<u8> = public_inputs.iter().flat_map(encode_public_input).collect();
+    encode_raw_verify_call(circuit_id, proof_bytes, &input_words)
+}
+
+/// Encode a `verify` call from bytes as received, e.g. when forwarding another
+/// contract's arguments. `input_words` holds the inputs as consecutive 32-byte words.
+pub fn encode_raw_verify_call(circuit_id: u64, proof_bytes: &[u8], input_words: &[u8]) -> Vec<u8> {
+    debug_assert!(input_words.len().is_multiple_of(WORD));
+    let proof_offset = 3 * WORD;
+    let inputs_offset = proof_offset + WORD + padded_len(proof_bytes.len());
+
+    let mut buf = Vec::with_capacity(SELECTOR_LEN + inputs_offset + WORD + input_words.len());
+    buf.extend_from_slice(&VERIFY_SELECTOR);
+    buf.extend_from_slice(&uint_word(circuit_id));
+    buf.extend_from_slice(&uint_word(proof_offset as u64));
+    buf.extend_from_slice(&uint_word(inputs_offset as u64));
+    buf.extend_from_slice(&encode_bytes_tail(proof_bytes));
+    buf.extend_from_slice(&uint_word((input_words.len() / WORD) as u64));
+    buf.extend_from_slice(input_words);
+    buf
+}
+
+/// Decode the arguments of `verify` (calldata without the selector)
+pub fn decode_verify_args(args: &[u8]) -> Result<VerifyCall, RejectReason> {
+    read_word(args, 0).ok_or(RejectReason::MalformedCalldata)?;
+    // Ids above u64 can never be in the key table
+    let circuit_id = read_u64(args, 0).ok_or(RejectReason::UnknownCircuit)?;
+
+    let proof_bytes = read_bytes(args, WORD)?;
+    let proof = match proof_bytes.len() {
+        PROOF_LEN => Proof::<Bn254>::deserialize_compressed(proof_bytes).ok(),
+        EVM_PROOF_LEN => precompile::decode_proof(proof_bytes),
+        _ => None,
+    }
+    .ok_or(RejectReason::MalformedProof)?;
+
+    let public_inputs = read_words(args, 2 * WORD)?
+        .chunks_exact(WORD)
+        .map(|word| decode_public_input(word.try_into().expect("chunks_exact yields full words")))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(VerifyCall {
+        circuit_id,
+        proof,
+        public_inputs,
+    })
+}
+
+/// Encode a full `registerKey(vkId, circuitVersion, verifyingKey)` call
+pub fn encode_register_key_call(vk_id: u64, circuit_version: u32, verifying_key: &[u8]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(SELECTOR_LEN + 4 * WORD + padded_len(verifying_key.len()));
+    buf.extend_from_slice(&REGISTER_KEY_SELECTOR);
+    buf.extend_from_slice(&uint_word(vk_id));
+    buf.extend_from_slice(&uint_word(circuit_version as u64));
+    buf.extend_from_slice(&uint_word(3 * WORD as u64));
+    buf.extend_from_slice(&encode_bytes_tail(verifying_key));
+    buf
+}
+
+/// Decode the arguments of `registerKey` (calldata without the selector)
+pub fn decode_register_key_args(args: &[u8]) -> Result<RegisterKeyCall<'_>, RejectReason> {
+    let vk_id = read_u64(args, 0).ok_or(RejectReason::MalformedCalldata)?;
+    let circuit_version = read_u64(args, WORD)
+        .and_then(|version| u32::try_from(version).ok())
+        .ok_or(RejectReason::MalformedCalldata)?;
+    let verifying_key = read_bytes(args, 2 * WORD)?;
+    Ok(RegisterKeyCall {
+        vk_id,
+        circuit_version,
+        verifying_key,
+    })
+}
+
+/// Encode a full `deprecateKey(vkId)` call
+pub fn encode_deprecate_key_call(vk_id: u64) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(SELECTOR_LEN + WORD);
+    buf.extend_from_slice(&DEPRECATE_KEY_SELECTOR);
+    buf.extend_from_slice(&uint_word(vk_id));
+    buf
+}
+
+/// Decode the single `uint256 circuitId` argument of `vkHash` (and `vkId` of `deprecateKey`)
+pub fn decode_circuit_id_arg(args: &[u8]) -> Result<Option<u64>, RejectReason> {
+    if args.len() < WORD {
+        return Err(RejectReason::MalformedCalldata);
+    }
+    Ok(read_u64(args, 0))
+}
+
+/// Decode a single `bytes32` argument
+pub fn decode_bytes32_arg(args: &[u8]) -> Result<[u8; WORD], RejectReason> {
+    read_word(args, 0)
+        .and_then(|word| word.try_into().ok())
+        .ok_or(RejectReason::MalformedCalldata)
+}
+
+pub fn encode_bool(value: bool) -> [u8; WORD] {
+    uint_word(value as u64)
+}
+
+pub fn encode_address(address: &Address) -> [u8; WORD] {
+    let mut word = [0u8; WORD];
+    word[WORD - address.len()..].copy_from_slice(address);
+    word
+}
+
+pub fn encode_string(value: &str) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(2 * WORD + padded_len(value.len()));
+    buf.extend_from_slice(&uint_word(WORD as u64));
+    buf.extend_from_slice(&encode_bytes_tail(value.as_bytes()));
+    buf
+}
+
+pub fn uint_word(value: u64) -> [u8; WORD] {
+    let mut word = [0u8; WORD];
+    word[WORD - 8..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+/// Length word followed by the data, zero-padded to a whole number of words
+pub fn encode_bytes_tail(data: &[u8]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(WORD + padded_len(data.len()));
+    buf.extend_from_slice(&uint_word(data.len() as u64));
+    buf.extend_from_slice(data);
+    buf.resize(WORD + padded_len(data.len()), 0);
+    buf
+}
+
+fn padded_len(len: usize) -> usize {
+    len.div_ceil(WORD) * WORD
+}
+
+pub fn read_word(args: &[u8], offset: usize) -> Option<&[u8]> {
+    args.get(offset..offset.checked_add(WORD)?)
+}
+
+/// A word holding an integer that fits in u64 (None if missing or larger)
+pub fn read_u64(args: &[u8], offset: usize) -> Option<u64> {
+    let word = read_word(args, offset)?;
+    if word[..WORD - 8].iter().any(|&b| b != 0) {
+        return None;
+    }
+    Some(u64::from_be_bytes(word[WORD - 8..].try_into().ok()?))
+}
+
+/// An offset or length word; must point inside `args`
+fn read_offset(args: &[u8], offset: usize) -> Result<usize, RejectReason> {
+    read_u64(args, offset)
+        .and_then(|value| usize::try_from(value).ok())
+        .filter(|&value| value <= args.len())
+        .ok_or(RejectReason::MalformedCalldata)
+}
+
+/// Dynamic `bytes` (or `string`) argument whose head word is at `head`
+pub fn read_bytes(args: &[u8], head: usize) -> Result<&[u8], RejectReason> {
+    let offset = read_offset(args, head)?;
+    let len = read_offset(args, offset)?;
+    let start = offset + WORD;
+    start
+        .checked_add(len)
+        .and_then(|end| args.get(start..end))
+        .ok_or(RejectReason::MalformedCalldata)
+}
+
+/// Dynamic array of 32-byte elements (`uint256[]`, `bytes32[]`) whose head word
+/// is at `head`, as one slice of consecutive words
+pub fn read_words(args: &[u8], head: usize) -> Result<&[u8], RejectReason> {
+    let offset = read_offset(args, head)?;
+    let count = read_offset(args, offset)?;
+    let start = offset + WORD;
+    count
+        .checked_mul(WORD)
+        .and_then(|len| start.checked_add(len))
+        .and_then(|end| args.get(start..end))
+        .ok_or(RejectReason::MalformedCalldata)
+}
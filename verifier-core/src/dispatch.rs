@@ -0,0 +1,209 @@
+// Selector dispatch for the verifier contract. `dispatch` is the whole body of
+// `call()` minus the host I/O: it takes the host (caller and storage), the raw
+// calldata and the compiled-in key table, and returns the bytes (and revert flag)
+// to hand to `return_value`. Keys are looked up in storage first (see `registry`),
+// then in the compiled-in table. Proofs are checked through the BN254 precompiles
+// where the host has them, else with arkworks (see `precompile`). Circuits with a
+// `NullifierMode` other than `Off` make `verify` stateful (see `nullifier`).
+
+use alloc::vec::Vec;
+
+use ark_bn254::Bn254;
+
+use crate::abi::{
+    self, VerifyCall, DEPRECATE_KEY_SELECTOR, IS_USED_SELECTOR, OWNER_SELECTOR, REGISTER_KEY_SELECTOR, VERIFY_SELECTOR,
+    VERSION_SELECTOR, VK_HASH_SELECTOR,
+};
+use crate::errors::ContractError;
+use crate::host::Host;
+use crate::nullifier::{self, NullifierMode};
+use crate::precompile;
+use crate::registry::{self, KeyStatus};
+use crate::groth16::VerifyingKey;
+use crate::{RejectReason, Verdict};
+
+/// Reported by `version()`
+pub const VERSION: &str = concat!("niet2code-verifier/", env!("CARGO_PKG_VERSION"));
+
+/// Verifying key compiled into the contract for one circuit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VerifierKeyEntry {
+    pub circuit_id: u64,
+    pub circuit_version: u32,
+    /// SHA-256 of the compressed verifying key (the `sha256:` fingerprint used by the CLI)
+    pub key_hash: [u8; 32],
+    /// Uncompressed `PreparedVerifyingKey`, for the arkworks fallback
+    pub prepared_key: &'static [u8],
+    /// The same key in the precompile layout (`precompile::encode_verifying_key`)
+    pub evm_key: &'static [u8],
+    /// Replay protection for this circuit; keys registered in storage under the
+    /// same id keep it
+    pub nullifier: NullifierMode,
+}
+
+/// What `call()` returns to the host
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub revert: bool,
+    pub data: Vec<u8>,
+}
+
+impl Response {
+    fn ok(data: impl Into<Vec<u8>>) -> Self {
+        Self {
+            revert: false,
+            data: data.into(),
+        }
+    }
+
+    fn revert(error: ContractError) -> Self {
+        Self {
+            revert: true,
+            data: error.encode(),
+        }
+    }
+}
+
+/// How `verify` reports a call that does not verify
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResultMode {
+    /// Revert with the `ContractError` for the failure class; `true` otherwise
+    Errors,
+    /// Legacy: answer `false` for every `verify` failure, for callers that only decode a bool
+    Bool,
+}
+
+pub fn find_key(keys: &[VerifierKeyEntry], circuit_id: u64) -> Option<&VerifierKeyEntry> {
+    keys.iter().find(|entry| entry.circuit_id == circuit_id)
+}
+
+/// Hash of the active key for `vk_id`, if any
+pub fn active_key_hash<H: Host>(host: &H, vk_id: u64, keys: &[VerifierKeyEntry]) -> Option<[u8; 32]> {
+    match registry::read_record(host, vk_id) {
+        Some(record) if record.status == KeyStatus::Active => Some(record.key_hash),
+        Some(_) => None,
+        None => find_key(keys, vk_id).map(|entry| entry.key_hash),
+    }
+}
+
+/// Decode and verify a `verify` call against the stored keys, then the key
+/// table, and consume its nullifier if the circuit has one
+pub fn verify_args<H: Host>(host: &mut H, args: &[u8], keys: &[VerifierKeyEntry]) -> Verdict {
+    let call = match abi::decode_verify_args(args) {
+        Ok(call) => call,
+        Err(reason) => return Verdict::Rejected(reason),
+    };
+    let entry = find_key(keys, call.circuit_id);
+
+    let verdict = match registry::read_record(host, call.circuit_id) {
+        Some(record) if record.status == KeyStatus::Deprecated => Verdict::Rejected(RejectReason::DeprecatedKey),
+        Some(record) => match registry::load_key(host, call.circuit_id, &record) {
+            Ok(vk) => verify_with_fallback(host, &precompile::encode_verifying_key(&vk), &call, Fallback::Key(&vk)),
+            Err(reason) => Verdict::Rejected(reason),
+        },
+        None => match entry {
+            Some(entry) => verify_with_fallback(host, entry.evm_key, &call, Fallback::Prepared(entry.prepared_key)),
+            None => Verdict::Rejected(RejectReason::UnknownCircuit),
+        },
+    };
+    if verdict != Verdict::Valid {
+        return verdict;
+    }
+
+    let mode = entry.map_or(NullifierMode::Off, |entry| entry.nullifier);
+    nullifier::consume(host, mode, &call)
+}
+
+/// Key the arkworks fallback checks a call against
+#[cfg_attr(not(feature = "software-pairing"), allow(dead_code))]
+enum Fallback<'a> {
+    /// A stored key, already validated
+    Key(&'a VerifyingKey<Bn254>),
+    /// A compiled-in prepared key
+    Prepared(&'a [u8]),
+}
+
+/// Verify through the precompiles; if they are missing or fail, fall back to
+/// the arkworks pairing
+fn verify_with_fallback<H: Host>(host: &mut H, evm_key: &[u8], call: &VerifyCall, fallback: Fallback) -> Verdict {
+    match precompile::verify(host, evm_key, call) {
+        Ok(verdict) => verdict,
+        Err(_) => software_pairing(call, fallback),
+    }
+}
+
+#[cfg(feature = "software-pairing")]
+fn software_pairing(call: &VerifyCall, fallback: Fallback) -> Verdict {
+    match fallback {
+        Fallback::Key(vk) => crate::verify(vk, &call.proof, &call.public_inputs),
+        Fallback::Prepared(bytes) => match crate::decode_prepared_verifying_key(bytes) {
+            Ok(pvk) => crate::verify_prepared(&pvk, &call.proof, &call.public_inputs),
+            Err(reason) => Verdict::Rejected(reason),
+        },
+    }
+}
+
+/// Built without `software-pairing`: no pairing to fall back to
+#[cfg(not(feature = "software-pairing"))]
+fn software_pairing(_call: &VerifyCall, _fallback: Fallback) -> Verdict {
+    Verdict::Rejected(RejectReason::PrecompileFailed)
+}
+
+/// Body of `deploy()`: the deployer becomes the owner
+pub fn deploy<H: Host>(host: &mut H) {
+    registry::init_owner(host);
+}
+
+/// Route calldata by selector. Unknown selectors, truncated calls and refused
+/// admin calls revert with a `ContractError`; a failed `verify` does too, or
+/// answers `false` under `ResultMode::Bool`. Calldata shorter than a selector or
+/// with an unknown one reverts in both modes, since it is not a `verify` call.
+pub fn dispatch<H: Host>(host: &mut H, calldata: &[u8], keys: &[VerifierKeyEntry], results: ResultMode) -> Response {
+    let Ok((selector, args)) = abi::split_selector(calldata) else {
+        return Response::revert(ContractError::MalformedCalldata);
+    };
+
+    match selector {
+        VERIFY_SELECTOR => {
+            let verdict = verify_args(host, args, keys);
+            match (ContractError::from_verdict(&verdict), results) {
+                (None, _) => Response::ok(abi::encode_bool(true)),
+                (Some(error), ResultMode::Errors) => Response::revert(error),
+                (Some(_), ResultMode::Bool) => Response::ok(abi::encode_bool(false)),
+            }
+        }
+        VK_HASH_SELECTOR => match abi::decode_circuit_id_arg(args) {
+            Ok(circuit_id) => {
+                let hash = circuit_id
+                    .and_then(|id| active_key_hash(host, id, keys))
+                    .unwrap_or([0u8; 32]);
+                Response::ok(hash)
+            }
+            Err(reason) => Response::revert(reason.into()),
+        },
+        VERSION_SELECTOR => Response::ok(abi::encode_string(VERSION)),
+        OWNER_SELECTOR => Response::ok(abi::encode_address(&registry::owner(host).unwrap_or_default())),
+        IS_USED_SELECTOR => match abi::decode_bytes32_arg(args) {
+            Ok(nullifier) => Response::ok(abi::encode_bool(nullifier::is_used(host, &nullifier))),
+            Err(reason) => Response::revert(reason.into()),
+        },
+        REGISTER_KEY_SELECTOR => match abi::decode_register_key_args(args) {
+            Ok(call) => admin_response(registry::register_key(host, call.vk_id, call.circuit_version, call.verifying_key)),
+            Err(reason) => Response::revert(reason.into()),
+        },
+        DEPRECATE_KEY_SELECTOR => match abi::decode_circuit_id_arg(args) {
+            Ok(Some(vk_id)) => admin_response(registry::deprecate_key(host, vk_id, find_key(keys, vk_id))),
+            Ok(None) => Response::revert(ContractError::UnknownCircuit),
+            Err(reason) => Response::revert(reason.into()),
+        },
+        _ => Response::revert(ContractError::UnknownSelector(selector)),
+    }
+}
+
+/// Admin calls return nothing on success and revert when refused
+fn admin_response<T>(result: Result<T, registry::AdminError>) -> Response {
+    match result {
+        Ok(_) => Response::ok([]),
+        Err(error) => Response::revert(error.into()),
+    }
+}
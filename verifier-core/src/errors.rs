@@ -0,0 +1,258 @@
+// Solidity custom errors the verifier contract reverts with, one per failure
+// class, so integrators can tell a malformed submission from an invalid proof:
+//
+//   error MalformedCalldata();
+//   error UnknownCircuit();
+//   error DeprecatedKey();
+//   error MalformedVerifyingKey();
+//   error MalformedProof();
+//   error NonCanonicalPublicInput();
+//   error PublicInputCount(uint256 expected, uint256 actual);
+//   error NullifierAlreadyUsed();
+//   error InvalidProof();
+//   error NotOwner();
+//   error UnknownSelector(bytes4 selector);
+//   error PrecompileFailed();
+//
+// Revert data is the 4-byte error selector followed by its ABI-encoded arguments.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::abi::{self, SELECTOR_LEN, WORD};
+use crate::registry::AdminError;
+use crate::{RejectReason, Verdict};
+
+/// One of each error, for matching selectors
+const ALL: [ContractError; 12] = [
+    ContractError::MalformedCalldata,
+    ContractError::UnknownCircuit,
+    ContractError::DeprecatedKey,
+    ContractError::MalformedVerifyingKey,
+    ContractError::MalformedProof,
+    ContractError::NonCanonicalPublicInput,
+    ContractError::PublicInputCount { expected: 0, actual: 0 },
+    ContractError::NullifierAlreadyUsed,
+    ContractError::InvalidProof,
+    ContractError::NotOwner,
+    ContractError::UnknownSelector([0; SELECTOR_LEN]),
+    ContractError::PrecompileFailed,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContractError {
+    MalformedCalldata,
+    UnknownCircuit,
+    DeprecatedKey,
+    MalformedVerifyingKey,
+    MalformedProof,
+    NonCanonicalPublicInput,
+    PublicInputCount { expected: u64, actual: u64 },
+    NullifierAlreadyUsed,
+    /// Well-formed call, but the pairing check failed
+    InvalidProof,
+    NotOwner,
+    UnknownSelector([u8; SELECTOR_LEN]),
+    /// A BN254 precompile is missing or failed and the contract has no software pairing
+    PrecompileFailed,
+}
+
+impl ContractError {
+    pub fn signature(&self) -> &'static str {
+        match self {
+            ContractError::MalformedCalldata => "MalformedCalldata()",
+            ContractError::UnknownCircuit => "UnknownCircuit()",
+            ContractError::DeprecatedKey => "DeprecatedKey()",
+            ContractError::MalformedVerifyingKey => "MalformedVerifyingKey()",
+            ContractError::MalformedProof => "MalformedProof()",
+            ContractError::NonCanonicalPublicInput => "NonCanonicalPublicInput()",
+            ContractError::PublicInputCount { .. } => "PublicInputCount(uint256,uint256)",
+            ContractError::NullifierAlreadyUsed => "NullifierAlreadyUsed()",
+            ContractError::InvalidProof => "InvalidProof()",
+            ContractError::NotOwner => "NotOwner()",
+            ContractError::UnknownSelector(_) => "UnknownSelector(bytes4)",
+            ContractError::PrecompileFailed => "PrecompileFailed()",
+        }
+    }
+
+    /// keccak256(signature)[..4]
+    pub fn selector(&self) -> [u8; SELECTOR_LEN] {
+        match self {
+            ContractError::MalformedCalldata => [0x59, 0x17, 0x0b, 0xf0],
+            ContractError::UnknownCircuit => [0x04, 0x62, 0xd6, 0xb3],
+            ContractError::DeprecatedKey => [0x90, 0x25, 0xc3, 0xe6],
+            ContractError::MalformedVerifyingKey => [0x7a, 0xf6, 0x23, 0x22],
+            ContractError::MalformedProof => [0xf4, 0xea, 0x0a, 0xb0],
+            ContractError::NonCanonicalPublicInput => [0x8b, 0x74, 0xc4, 0xf6],
+            ContractError::PublicInputCount { .. } => [0x7a, 0xcc, 0x18, 0x67],
+            ContractError::NullifierAlreadyUsed => [0xca, 0xd2, 0xae, 0x02],
+            ContractError::InvalidProof => [0x09, 0xbd, 0xe3, 0x39],
+            ContractError::NotOwner => [0x30, 0xcd, 0x74, 0x71],
+            ContractError::UnknownSelector(_) => [0xc2, 0xa8, 0x25, 0xf5],
+            ContractError::PrecompileFailed => [0x84, 0xe8, 0x16, 0x92],
+        }
+    }
+
+    /// Revert data: selector followed by the ABI-encoded arguments
+    pub fn encode(&self) -> Vec<u8> {
+        let mut out = self.selector().to_vec();
+        match self {
+            ContractError::PublicInputCount { expected, actual } => {
+                out.extend_from_slice(&abi::uint_word(*expected));
+                out.extend_from_slice(&abi::uint_word(*actual));
+            }
+            ContractError::UnknownSelector(selector) => {
+                let mut word = [0u8; WORD];
+                word[..SELECTOR_LEN].copy_from_slice(selector);
+                out.extend_from_slice(&word);
+            }
+            _ => {}
+        }
+        out
+    }
+
+    /// Parse revert data produced by `encode`; `None` for anything else
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        let (selector, args) = abi::split_selector(data).ok()?;
+        let word = |index: usize| args.get(index * WORD..(index + 1) * WORD);
+        let uint = |index: usize| {
+            let word = word(index)?;
+            let (high, low) = word.split_at(WORD - 8);
+            high.iter().all(|&b| b == 0).then(|| u64::from_be_bytes(low.try_into().expect("8-byte word tail")))
+        };
+
+        let error = match ALL.iter().find(|error| error.selector() == selector)? {
+            ContractError::PublicInputCount { .. } => ContractError::PublicInputCount {
+                expected: uint(0)?,
+                actual: uint(1)?,
+            },
+            ContractError::UnknownSelector(_) => ContractError::UnknownSelector(word(0)?[..SELECTOR_LEN].try_into().ok()?),
+            error => *error,
+        };
+        // Rejects trailing bytes and non-zero padding
+        (error.encode() == data).then_some(error)
+    }
+
+    /// Failure class of a verdict; `None` when it is valid
+    pub fn from_verdict(verdict: &Verdict) -> Option<Self> {
+        match verdict {
+            Verdict::Valid => None,
+            Verdict::Invalid => Some(ContractError::InvalidProof),
+            Verdict::Rejected(reason) => Some((*reason).into()),
+        }
+    }
+}
+
+impl From<RejectReason> for ContractError {
+    fn from(reason: RejectReason) -> Self {
+        match reason {
+            RejectReason::MalformedCalldata => ContractError::MalformedCalldata,
+            RejectReason::UnknownCircuit => ContractError::UnknownCircuit,
+            RejectReason::DeprecatedKey => ContractError::DeprecatedKey,
+            RejectReason::NullifierUsed => ContractError::NullifierAlreadyUsed,
+            RejectReason::MalformedVerifyingKey => ContractError::MalformedVerifyingKey,
+            RejectReason::MalformedProof => ContractError::MalformedProof,
+            RejectReason::NonCanonicalPublicInput => ContractError::NonCanonicalPublicInput,
+            RejectReason::PublicInputCount { expected, actual } => ContractError::PublicInputCount {
+                expected: expected as u64,
+                actual: actual as u64,
+            },
+            RejectReason::PrecompileFailed => ContractError::PrecompileFailed,
+        }
+    }
+}
+
+impl From<AdminError> for ContractError {
+    fn from(error: AdminError) -> Self {
+        match error {
+            AdminError::NotOwner => ContractError::NotOwner,
+            AdminError::MalformedKey => ContractError::MalformedVerifyingKey,
+            AdminError::UnknownKey => ContractError::UnknownCircuit,
+        }
+    }
+}
+
+impl fmt::Display for ContractError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContractError::MalformedCalldata => write!(f, "MalformedCalldata(): calldata is truncated or badly encoded"),
+            ContractError::UnknownCircuit => write!(f, "UnknownCircuit(): no verifying key for this circuit id"),
+            ContractError::DeprecatedKey => write!(f, "DeprecatedKey(): the key for this circuit id is deprecated"),
+            ContractError::MalformedVerifyingKey => write!(f, "MalformedVerifyingKey(): the verifying key does not decode"),
+            ContractError::MalformedProof => write!(f, "MalformedProof(): the proof is not a valid compressed Groth16 proof"),
+            ContractError::NonCanonicalPublicInput => {
+                write!(f, "NonCanonicalPublicInput(): a public input is not below the BN254 scalar modulus")
+            }
+            ContractError::PublicInputCount { expected, actual } => {
+                write!(f, "PublicInputCount({}, {}): wrong number of public inputs", expected, actual)
+            }
+            ContractError::NullifierAlreadyUsed => write!(f, "NullifierAlreadyUsed(): this proof was already accepted"),
+            ContractError::InvalidProof => write!(f, "InvalidProof(): the pairing check failed"),
+            ContractError::NotOwner => write!(f, "NotOwner(): only the deployer may manage keys"),
+            ContractError::UnknownSelector(selector) => write!(
+                f,
+                "UnknownSelector(0x{:02x}{:02x}{:02x}{:02x}): no such function",
+                selector[0], selector[1], selector[2], selector[3]
+            ),
+            ContractError::PrecompileFailed => {
+                write!(f, "PrecompileFailed(): BN254 precompile unavailable and no software pairing compiled in")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn samples() -> [ContractError; 12] {
+        let mut samples = ALL;
+        samples[6] = ContractError::PublicInputCount { expected: 1, actual: 2 };
+        samples[10] = ContractError::UnknownSelector([0xde, 0xad, 0xbe, 0xef]);
+        samples
+    }
+
+    #[test]
+    fn test_selectors_match_signatures() {
+        use sha3::{Digest, Keccak256};
+        for error in ALL {
+            assert_eq!(Keccak256::digest(error.signature().as_bytes())[..4], error.selector(), "{}", error.signature());
+        }
+    }
+
+    #[test]
+    fn test_revert_data_round_trips() {
+        for error in samples() {
+            let data = error.encode();
+            assert_eq!(data.len() % WORD, SELECTOR_LEN);
+            assert_eq!(ContractError::decode(&data), Some(error));
+        }
+    }
+
+    #[test]
+    fn test_decode_rejects_foreign_data() {
+        assert_eq!(ContractError::decode(&[]), None);
+        assert_eq!(ContractError::decode(&[0x08, 0xc3, 0x79, 0xa0]), None);
+
+        let mut trailing = ContractError::InvalidProof.encode();
+        trailing.push(0);
+        assert_eq!(ContractError::decode(&trailing), None);
+
+        let mut dirty_padding = ContractError::UnknownSelector([1, 2, 3, 4]).encode();
+        dirty_padding[SELECTOR_LEN + WORD - 1] = 1;
+        assert_eq!(ContractError::decode(&dirty_padding), None);
+
+        let truncated = ContractError::PublicInputCount { expected: 1, actual: 2 }.encode();
+        assert_eq!(ContractError::decode(&truncated[..truncated.len() - 1]), None);
+    }
+
+    #[test]
+    fn test_invalid_verdict_maps_to_invalid_proof() {
+        assert_eq!(ContractError::from_verdict(&Verdict::Valid), None);
+        assert_eq!(ContractError::from_verdict(&Verdict::Invalid), Some(ContractError::InvalidProof));
+        assert_eq!(
+            ContractError::from_verdict(&Verdict::Rejected(RejectReason::NullifierUsed)),
+            Some(ContractError::NullifierAlreadyUsed)
+        );
+    }
+}
@@ -0,0 +1,36 @@
+// Groth16 proof and verifying key. With `software-pairing` these are the
+// ark-groth16 types the prover produces and passes straight in; without it they
+// are field-for-field copies with the same canonical encoding, so a
+// precompile-only contract compiles neither ark-groth16 nor the pairing.
+
+#[cfg(feature = "software-pairing")]
+pub use ark_groth16::{Proof, VerifyingKey};
+
+#[cfg(not(feature = "software-pairing"))]
+pub use standalone::{Proof, VerifyingKey};
+
+#[cfg(not(feature = "software-pairing"))]
+mod standalone {
+    use alloc::vec::Vec;
+
+    use ark_ec::pairing::Pairing;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+    /// Same layout as `ark_groth16::Proof`
+    #[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+    pub struct Proof<E: Pairing> {
+        pub a: E::G1Affine,
+        pub b: E::G2Affine,
+        pub c: E::G1Affine,
+    }
+
+    /// Same layout as `ark_groth16::VerifyingKey`
+    #[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+    pub struct VerifyingKey<E: Pairing> {
+        pub alpha_g1: E::G1Affine,
+        pub beta_g2: E::G2Affine,
+        pub gamma_g2: E::G2Affine,
+        pub delta_g2: E::G2Affine,
+        pub gamma_abc_g1: Vec<E::G1Affine>,
+    }
+}
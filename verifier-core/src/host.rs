@@ -0,0 +1,81 @@
+// Contract host as seen by `dispatch`: the caller, persistent storage, events and
+// the chain's BN254 precompiles.
+// verifier-contract implements it over the pallet-revive host functions; unit
+// tests here use `MemoryHost`. Storage keys are always 32 bytes and values are
+// at most `registry::STORAGE_CHUNK_LEN` bytes, the limit pallet-revive enforces.
+
+use alloc::vec::Vec;
+
+use crate::precompile::{Bn254Precompile, PrecompileError};
+
+/// 20-byte H160 account address
+pub type Address = [u8; 20];
+
+pub trait Host {
+    fn caller(&self) -> Address;
+    fn get_storage(&self, key: &[u8; 32]) -> Option<Vec<u8>>;
+    fn set_storage(&mut self, key: &[u8; 32], value: &[u8]);
+    fn deposit_event(&mut self, topics: &[[u8; 32]], data: &[u8]);
+
+    /// Call a BN254 precompile and return its output. Hosts without them keep
+    /// this default, and `verify` falls back to the arkworks pairing when built
+    /// with `software-pairing`.
+    fn call_precompile(&mut self, _precompile: Bn254Precompile, _input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+        Err(PrecompileError::Unavailable)
+    }
+}
+
+#[cfg(test)]
+pub(crate) use memory::MemoryHost;
+
+#[cfg(test)]
+mod memory {
+    use super::*;
+    use alloc::collections::BTreeMap;
+
+    pub(crate) struct MemoryHost {
+        pub caller: Address,
+        pub storage: BTreeMap<[u8; 32], Vec<u8>>,
+        pub events: Vec<(Vec<[u8; 32]>, Vec<u8>)>,
+        /// Answer precompile calls with `precompile::emulate`
+        pub precompiles: bool,
+    }
+
+    impl Default for MemoryHost {
+        fn default() -> Self {
+            Self {
+                caller: Address::default(),
+                storage: BTreeMap::new(),
+                events: Vec::new(),
+                // Without software pairing only the precompile path can verify
+                precompiles: !cfg!(feature = "software-pairing"),
+            }
+        }
+    }
+
+    impl Host for MemoryHost {
+        fn caller(&self) -> Address {
+            self.caller
+        }
+
+        fn get_storage(&self, key: &[u8; 32]) -> Option<Vec<u8>> {
+            self.storage.get(key).cloned()
+        }
+
+        fn set_storage(&mut self, key: &[u8; 32], value: &[u8]) {
+            assert!(value.len() <= crate::registry::STORAGE_CHUNK_LEN, "storage value too large");
+            self.storage.insert(*key, value.to_vec());
+        }
+
+        fn deposit_event(&mut self, topics: &[[u8; 32]], data: &[u8]) {
+            self.events.push((topics.to_vec(), data.to_vec()));
+        }
+
+        fn call_precompile(&mut self, precompile: Bn254Precompile, input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+            if !self.precompiles {
+                return Err(PrecompileError::Unavailable);
+            }
+            crate::precompile::emulate(precompile, input)
+        }
+    }
+}
@@ -0,0 +1,486 @@
+// Groth16 (BN254) verification shared by the PolkaVM verifier contract and the CLI.
+// `no_std` + `alloc`, so the code path the contract runs on-chain is the same one
+// exercised by native unit tests and by `niet2code-cli verify`.
+//
+// - `abi`: Solidity ABI encoding of `verify(uint256,bytes,uint256[])`, `vkHash`, `version`
+// - `dispatch`: selector routing over stored and compiled-in keys (the contract's `call()`)
+// - `registry`: owner-managed keys in contract storage, behind the `host::Host` trait
+// - `nullifier`: optional per-circuit replay protection (`isUsed`)
+// - `errors`: the Solidity custom errors `call()` reverts with
+// - `precompile`: verification through the chain's BN254 precompiles, with the
+//   arkworks pairing as fallback (feature `software-pairing`, on by default)
+// - `groth16`: the proof and key types, arkworks' own with `software-pairing`
+//
+// Public inputs are 32-byte big-endian integers (ABI `uint256`) and must be < r.
+//
+// Verifying keys come in two forms, both written by `prover::key_artifact`:
+// - plain: uncompressed `VerifyingKey`, validated on decode and prepared per call
+// - prepared: uncompressed `PreparedVerifyingKey` (e(alpha, beta) and the G2 line
+//   coefficients precomputed). Decoded without curve/subgroup checks, so it must
+//   only come from a trusted source such as the key compiled into the contract.
+
+#![no_std]
+
+extern crate alloc;
+
+pub mod abi;
+pub mod dispatch;
+pub mod errors;
+pub mod groth16;
+pub mod host;
+pub mod nullifier;
+pub mod precompile;
+pub mod registry;
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use ark_bn254::{Bn254, Fr};
+use ark_ff::{BigInt, BigInteger, PrimeField};
+#[cfg(feature = "software-pairing")]
+use ark_groth16::{prepare_verifying_key, Groth16, PreparedVerifyingKey};
+use ark_serialize::{CanonicalDeserialize, SerializationError};
+
+use groth16::{Proof, VerifyingKey};
+
+/// Compressed Groth16 proof (A: G1, B: G2, C: G1)
+pub const PROOF_LEN: usize = 128;
+/// Uncompressed proof in the EVM precompile layout (see `precompile`)
+pub const EVM_PROOF_LEN: usize = 256;
+pub const PUBLIC_INPUT_LEN: usize = 32;
+
+/// Outcome of verifying one call
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    /// The proof verifies for the public input
+    Valid,
+    /// Well-formed proof and input that do not verify
+    Invalid,
+    /// Calldata or key could not be decoded; nothing was verified
+    Rejected(RejectReason),
+}
+
+impl Verdict {
+    pub fn is_valid(&self) -> bool {
+        matches!(self, Verdict::Valid)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RejectReason {
+    /// Calldata is truncated or its ABI offsets/lengths are out of bounds
+    MalformedCalldata,
+    /// No key is registered or compiled in for the requested circuit id
+    UnknownCircuit,
+    /// The owner deprecated the key for the requested circuit id
+    DeprecatedKey,
+    /// The proof verified, but its nullifier was already used
+    NullifierUsed,
+    MalformedVerifyingKey,
+    MalformedProof,
+    /// Public input is not a canonical field element (>= the BN254 scalar modulus)
+    NonCanonicalPublicInput,
+    /// The key was generated for a different number of public inputs
+    PublicInputCount { expected: usize, actual: usize },
+    /// A BN254 precompile is missing or failed and there is no software fallback
+    PrecompileFailed,
+}
+
+impl fmt::Display for RejectReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RejectReason::MalformedCalldata => write!(f, "calldata is not a well-formed verify(uint256,bytes,uint256[]) call"),
+            RejectReason::UnknownCircuit => write!(f, "no verifying key for this circuit id"),
+            RejectReason::DeprecatedKey => write!(f, "verifying key for this circuit id is deprecated"),
+            RejectReason::NullifierUsed => write!(f, "nullifier already used (replayed proof)"),
+            RejectReason::MalformedVerifyingKey => write!(f, "verifying key is not a valid uncompressed BN254 key"),
+            RejectReason::MalformedProof => write!(f, "proof is not a valid compressed or EVM-layout Groth16 proof"),
+            RejectReason::NonCanonicalPublicInput => write!(f, "public input is not below the BN254 scalar modulus"),
+            RejectReason::PublicInputCount { expected, actual } => {
+                write!(f, "verifying key expects {} public input(s), got {}", expected, actual)
+            }
+            RejectReason::PrecompileFailed => write!(f, "BN254 precompile unavailable and no software pairing compiled in"),
+        }
+    }
+}
+
+/// Encode a `verify(circuitId, proof, inputs)` call for the verifier contract
+pub fn encode_calldata(circuit_id: u64, proof: &Proof<Bn254>, public_inputs: &[Fr]) -> Result<Vec<u8>, SerializationError> {
+    abi::encode_verify_call(circuit_id, proof, public_inputs)
+}
+
+/// Encode a `verify` call with the uncompressed proof in the EVM precompile layout
+pub fn encode_evm_calldata(circuit_id: u64, proof: &Proof<Bn254>, public_inputs: &[Fr]) -> Vec<u8> {
+    abi::encode_evm_verify_call(circuit_id, proof, public_inputs)
+}
+
+/// 32-byte big-endian encoding of a field element
+pub fn encode_public_input(input: &Fr) -> [u8; PUBLIC_INPUT_LEN] {
+    let mut out = [0u8; PUBLIC_INPUT_LEN];
+    out.copy_from_slice(&input.into_bigint().to_bytes_be());
+    out
+}
+
+/// Decode a 32-byte big-endian field element, rejecting values >= the modulus
+pub fn decode_public_input(bytes: &[u8; PUBLIC_INPUT_LEN]) -> Result<Fr, RejectReason> {
+    let mut limbs = [0u64; 4];
+    for (i, limb) in limbs.iter_mut().enumerate() {
+        let end = PUBLIC_INPUT_LEN - 8 * i;
+        let mut word = [0u8; 8];
+        word.copy_from_slice(&bytes[end - 8..end]);
+        *limb = u64::from_be_bytes(word);
+    }
+    Fr::from_bigint(BigInt(limbs)).ok_or(RejectReason::NonCanonicalPublicInput)
+}
+
+pub fn decode_verifying_key(vk_bytes: &[u8]) -> Result<VerifyingKey<Bn254>, RejectReason> {
+    VerifyingKey::<Bn254>::deserialize_uncompressed(vk_bytes).map_err(|_| RejectReason::MalformedVerifyingKey)
+}
+
+/// Decode a trusted prepared key. Skips point validation: the bytes must come
+/// from `prover::key_artifact`, e.g. compiled into the contract.
+#[cfg(feature = "software-pairing")]
+pub fn decode_prepared_verifying_key(pvk_bytes: &[u8]) -> Result<PreparedVerifyingKey<Bn254>, RejectReason> {
+    PreparedVerifyingKey::<Bn254>::deserialize_uncompressed_unchecked(pvk_bytes)
+        .map_err(|_| RejectReason::MalformedVerifyingKey)
+}
+
+/// Verify an already-decoded proof
+#[cfg(feature = "software-pairing")]
+pub fn verify(vk: &VerifyingKey<Bn254>, proof: &Proof<Bn254>, public_inputs: &[Fr]) -> Verdict {
+    verify_prepared(&prepare_verifying_key(vk), proof, public_inputs)
+}
+
+/// Verify an already-decoded proof against a prepared key
+#[cfg(feature = "software-pairing")]
+pub fn verify_prepared(pvk: &PreparedVerifyingKey<Bn254>, proof: &Proof<Bn254>, public_inputs: &[Fr]) -> Verdict {
+    let expected = pvk.vk.gamma_abc_g1.len().saturating_sub(1);
+    if expected != public_inputs.len() {
+        return Verdict::Rejected(RejectReason::PublicInputCount {
+            expected,
+            actual: public_inputs.len(),
+        });
+    }
+
+    match Groth16::<Bn254>::verify_proof(pvk, proof, public_inputs) {
+        Ok(true) => Verdict::Valid,
+        Ok(false) => Verdict::Invalid,
+        Err(_) => Verdict::Rejected(RejectReason::PublicInputCount {
+            expected,
+            actual: public_inputs.len(),
+        }),
+    }
+}
+
+/// Decode a `verify` call and check it against a plain (untrusted) verifying key,
+/// whatever circuit id it names. Used by the CLI to check calldata before submission.
+#[cfg(feature = "software-pairing")]
+pub fn verify_calldata(calldata: &[u8], vk_bytes: &[u8]) -> Verdict {
+    let call = match abi::split_selector(calldata) {
+        Ok((abi::VERIFY_SELECTOR, args)) => abi::decode_verify_args(args),
+        Ok(_) | Err(_) => Err(RejectReason::MalformedCalldata),
+    };
+    let call = match call {
+        Ok(call) => call,
+        Err(reason) => return Verdict::Rejected(reason),
+    };
+    let vk = match decode_verifying_key(vk_bytes) {
+        Ok(vk) => vk,
+        Err(reason) => return Verdict::Rejected(reason),
+    };
+    verify(&vk, &call.proof, &call.public_inputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use crate::abi::{decode_verify_args, VERIFY_SELECTOR, VERSION_SELECTOR, VK_HASH_SELECTOR, WORD};
+    use crate::dispatch::{dispatch, ResultMode, VerifierKeyEntry, VERSION};
+    use crate::errors::ContractError;
+    use crate::host::MemoryHost;
+    use crate::nullifier::NullifierMode;
+
+    // Canonical vectors from `prover::test_vectors` (circuit 1: a = 3, b = 4, c = 12)
+    const CALLDATA: &[u8] = include_bytes!("../../test-vectors/mul/calldata.bin");
+    const EVM_CALLDATA: &[u8] = include_bytes!("../../test-vectors/mul/evm_calldata.bin");
+    #[cfg(feature = "software-pairing")]
+    const VERIFYING_KEY: &[u8] = include_bytes!("../../test-vectors/mul/verifying_key.bin");
+    const PREPARED_VERIFYING_KEY: &[u8] = include_bytes!("../../test-vectors/mul/prepared_verifying_key.bin");
+    const EVM_VERIFYING_KEY: &[u8] = include_bytes!("../../test-vectors/mul/evm_verifying_key.bin");
+
+    const KEYS: &[VerifierKeyEntry] = &[VerifierKeyEntry {
+        circuit_id: 1,
+        circuit_version: 1,
+        key_hash: [0xab; 32],
+        prepared_key: PREPARED_VERIFYING_KEY,
+        evm_key: EVM_VERIFYING_KEY,
+        nullifier: NullifierMode::Off,
+    }];
+
+    fn decoded() -> abi::VerifyCall {
+        decode_verify_args(&CALLDATA[abi::SELECTOR_LEN..]).unwrap()
+    }
+
+    fn reencode(circuit_id: u64, inputs: &[Fr]) -> Vec<u8> {
+        encode_calldata(circuit_id, &decoded().proof, inputs).unwrap()
+    }
+
+    /// `verify` result under `ResultMode::Bool`
+    fn returned_bool(calldata: &[u8]) -> bool {
+        let response = dispatch(&mut MemoryHost::default(), calldata, KEYS, ResultMode::Bool);
+        assert!(!response.revert);
+        assert_eq!(response.data.len(), WORD);
+        response.data[WORD - 1] == 1
+    }
+
+    /// `verify` revert under `ResultMode::Errors`, `None` if it returned `true`
+    fn reverted_with(calldata: &[u8]) -> Option<ContractError> {
+        let response = dispatch(&mut MemoryHost::default(), calldata, KEYS, ResultMode::Errors);
+        if !response.revert {
+            assert_eq!(response.data, abi::encode_bool(true));
+            return None;
+        }
+        Some(ContractError::decode(&response.data).expect("revert data is not a ContractError"))
+    }
+
+    #[test]
+    #[cfg(feature = "software-pairing")]
+    fn test_canonical_vector_verifies() {
+        assert_eq!(verify_calldata(CALLDATA, VERIFYING_KEY), Verdict::Valid);
+        assert!(returned_bool(CALLDATA));
+        assert_eq!(reverted_with(CALLDATA), None);
+
+        let call = decoded();
+        assert_eq!(call.circuit_id, 1);
+        assert_eq!(call.public_inputs, vec![Fr::from(12u64)]);
+    }
+
+    #[test]
+    fn test_encode_round_trip() {
+        assert_eq!(reencode(1, &[Fr::from(12u64)]), CALLDATA);
+    }
+
+    #[test]
+    fn test_raw_verify_call_forwards_bytes_unchanged() {
+        let args = &CALLDATA[4..];
+        let proof = abi::read_bytes(args, abi::WORD).unwrap();
+        let inputs = abi::read_words(args, 2 * abi::WORD).unwrap();
+        assert_eq!((proof.len(), inputs.len()), (PROOF_LEN, abi::WORD));
+        assert_eq!(abi::encode_raw_verify_call(1, proof, inputs), CALLDATA);
+
+        assert_eq!(abi::read_words(&args[..args.len() - 1], 2 * abi::WORD), Err(RejectReason::MalformedCalldata));
+    }
+
+    #[test]
+    #[cfg(feature = "software-pairing")]
+    fn test_wrong_public_input_is_invalid() {
+        let calldata = reencode(1, &[Fr::from(13u64)]);
+        assert_eq!(verify_calldata(&calldata, VERIFYING_KEY), Verdict::Invalid);
+        assert!(!returned_bool(&calldata));
+        assert_eq!(reverted_with(&calldata), Some(ContractError::InvalidProof));
+    }
+
+    #[test]
+    #[cfg(feature = "software-pairing")]
+    fn test_wrong_input_count_is_rejected() {
+        let calldata = reencode(1, &[Fr::from(12u64), Fr::from(1u64)]);
+        assert_eq!(
+            verify_calldata(&calldata, VERIFYING_KEY),
+            Verdict::Rejected(RejectReason::PublicInputCount { expected: 1, actual: 2 })
+        );
+        assert!(!returned_bool(&calldata));
+        assert_eq!(
+            reverted_with(&calldata),
+            Some(ContractError::PublicInputCount { expected: 1, actual: 2 })
+        );
+    }
+
+    #[test]
+    fn test_unknown_circuit_is_rejected() {
+        let calldata = reencode(2, &[Fr::from(12u64)]);
+        assert_eq!(
+            dispatch::verify_args(&mut MemoryHost::default(), &calldata[abi::SELECTOR_LEN..], KEYS),
+            Verdict::Rejected(RejectReason::UnknownCircuit)
+        );
+        assert!(!returned_bool(&calldata));
+        assert_eq!(reverted_with(&calldata), Some(ContractError::UnknownCircuit));
+    }
+
+    #[test]
+    #[cfg(feature = "software-pairing")]
+    fn test_corrupted_proof_does_not_verify() {
+        let mut calldata = CALLDATA.to_vec();
+        calldata[abi::SELECTOR_LEN + 5 * WORD + 5] ^= 0x01;
+        assert!(!verify_calldata(&calldata, VERIFYING_KEY).is_valid());
+        assert!(!returned_bool(&calldata));
+        assert!(matches!(
+            reverted_with(&calldata),
+            Some(ContractError::InvalidProof | ContractError::MalformedProof)
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "software-pairing")]
+    fn test_truncated_calldata_is_rejected_at_every_length() {
+        for len in abi::SELECTOR_LEN..CALLDATA.len() {
+            assert_eq!(
+                verify_calldata(&CALLDATA[..len], VERIFYING_KEY),
+                Verdict::Rejected(RejectReason::MalformedCalldata),
+                "length {}",
+                len
+            );
+            assert!(!returned_bool(&CALLDATA[..len]));
+            assert_eq!(reverted_with(&CALLDATA[..len]), Some(ContractError::MalformedCalldata));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "software-pairing")]
+    fn test_out_of_bounds_offsets_are_rejected() {
+        let mut calldata = CALLDATA.to_vec();
+        calldata[abi::SELECTOR_LEN + 2 * WORD..abi::SELECTOR_LEN + 3 * WORD].fill(0xff);
+        assert_eq!(
+            verify_calldata(&calldata, VERIFYING_KEY),
+            Verdict::Rejected(RejectReason::MalformedCalldata)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "software-pairing")]
+    fn test_non_canonical_public_input_is_rejected() {
+        let mut calldata = CALLDATA.to_vec();
+        let len = calldata.len();
+        calldata[len - WORD..].fill(0xff);
+        assert_eq!(
+            verify_calldata(&calldata, VERIFYING_KEY),
+            Verdict::Rejected(RejectReason::NonCanonicalPublicInput)
+        );
+        assert_eq!(reverted_with(&calldata), Some(ContractError::NonCanonicalPublicInput));
+    }
+
+    #[test]
+    #[cfg(feature = "software-pairing")]
+    fn test_malformed_key_is_rejected() {
+        assert_eq!(
+            verify_calldata(CALLDATA, &VERIFYING_KEY[..100]),
+            Verdict::Rejected(RejectReason::MalformedVerifyingKey)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "software-pairing")]
+    fn test_prepared_key_matches_plain_key() {
+        let pvk = decode_prepared_verifying_key(PREPARED_VERIFYING_KEY).unwrap();
+        assert_eq!(pvk, prepare_verifying_key(&decode_verifying_key(VERIFYING_KEY).unwrap()));
+    }
+
+    #[test]
+    #[cfg(feature = "software-pairing")]
+    fn test_truncated_prepared_key_is_rejected() {
+        let keys = [VerifierKeyEntry {
+            prepared_key: &PREPARED_VERIFYING_KEY[..PREPARED_VERIFYING_KEY.len() - 1],
+            ..KEYS[0]
+        }];
+        assert_eq!(
+            dispatch::verify_args(&mut MemoryHost::default(), &CALLDATA[abi::SELECTOR_LEN..], &keys),
+            Verdict::Rejected(RejectReason::MalformedVerifyingKey)
+        );
+    }
+
+    #[test]
+    fn test_precompile_path_verifies_both_proof_layouts() {
+        let mut host = MemoryHost {
+            precompiles: true,
+            ..MemoryHost::default()
+        };
+        // A broken prepared key shows the arkworks fallback is not reached
+        let keys = [VerifierKeyEntry {
+            prepared_key: &[],
+            ..KEYS[0]
+        }];
+        for calldata in [CALLDATA, EVM_CALLDATA] {
+            assert_eq!(dispatch::verify_args(&mut host, &calldata[abi::SELECTOR_LEN..], &keys), Verdict::Valid);
+
+            let mut wrong_input = calldata.to_vec();
+            *wrong_input.last_mut().unwrap() ^= 0x01;
+            let response = dispatch(&mut host, &wrong_input, &keys, ResultMode::Errors);
+            assert_eq!(response.data, ContractError::InvalidProof.encode());
+        }
+    }
+
+    #[test]
+    fn test_evm_proof_layout_falls_back_to_arkworks() {
+        assert_eq!(EVM_CALLDATA.len(), CALLDATA.len() + EVM_PROOF_LEN - PROOF_LEN);
+        let expected = if cfg!(feature = "software-pairing") {
+            Verdict::Valid
+        } else {
+            Verdict::Rejected(RejectReason::PrecompileFailed)
+        };
+        for calldata in [CALLDATA, EVM_CALLDATA] {
+            let mut host = MemoryHost {
+                precompiles: false,
+                ..MemoryHost::default()
+            };
+            assert_eq!(dispatch::verify_args(&mut host, &calldata[abi::SELECTOR_LEN..], KEYS), expected);
+        }
+    }
+
+    #[test]
+    fn test_vk_hash_and_version() {
+        let host = &mut MemoryHost::default();
+        let mut call = VK_HASH_SELECTOR.to_vec();
+        call.extend_from_slice(&abi::uint_word(1));
+        assert_eq!(dispatch(host, &call, KEYS, ResultMode::Errors).data, vec![0xab; 32]);
+
+        call[abi::SELECTOR_LEN..].copy_from_slice(&abi::uint_word(7));
+        assert_eq!(dispatch(host, &call, KEYS, ResultMode::Errors).data, vec![0; 32]);
+        assert_eq!(
+            dispatch(host, &VK_HASH_SELECTOR, KEYS, ResultMode::Errors).data,
+            ContractError::MalformedCalldata.encode()
+        );
+
+        let version = dispatch(host, &VERSION_SELECTOR, KEYS, ResultMode::Errors).data;
+        assert_eq!(version[..WORD], abi::uint_word(WORD as u64));
+        assert_eq!(version[WORD..2 * WORD], abi::uint_word(VERSION.len() as u64));
+        assert_eq!(&version[2 * WORD..2 * WORD + VERSION.len()], VERSION.as_bytes());
+        assert_eq!(version.len() % WORD, 0);
+    }
+
+    #[test]
+    fn test_unknown_selector_and_short_calldata_revert() {
+        let host = &mut MemoryHost::default();
+        // Bool mode only answers `false` to `verify`; these never reach it
+        for results in [ResultMode::Errors, ResultMode::Bool] {
+            let unknown = dispatch(host, &[1, 2, 3, 4], KEYS, results);
+            assert!(unknown.revert);
+            assert_eq!(ContractError::decode(&unknown.data), Some(ContractError::UnknownSelector([1, 2, 3, 4])));
+
+            for short in [&VERIFY_SELECTOR[..3], &[]] {
+                let response = dispatch(host, short, KEYS, results);
+                assert!(response.revert);
+                assert_eq!(response.data, ContractError::MalformedCalldata.encode());
+            }
+        }
+    }
+
+    #[test]
+    fn test_selectors_match_signatures() {
+        use sha3::{Digest, Keccak256};
+        for (signature, selector) in [
+            ("verify(uint256,bytes,uint256[])", VERIFY_SELECTOR),
+            ("vkHash(uint256)", VK_HASH_SELECTOR),
+            ("version()", VERSION_SELECTOR),
+            ("owner()", abi::OWNER_SELECTOR),
+            ("isUsed(bytes32)", abi::IS_USED_SELECTOR),
+            ("registerKey(uint256,uint32,bytes)", abi::REGISTER_KEY_SELECTOR),
+            ("deprecateKey(uint256)", abi::DEPRECATE_KEY_SELECTOR),
+        ] {
+            assert_eq!(Keccak256::digest(signature.as_bytes())[..4], selector, "{}", signature);
+        }
+        assert_eq!(
+            Keccak256::digest(b"NullifierUsed(bytes32,uint256)")[..],
+            nullifier::NULLIFIER_USED_TOPIC
+        );
+    }
+}
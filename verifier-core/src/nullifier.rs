@@ -0,0 +1,193 @@
+// Replay protection for `verify`. A circuit can opt in (see `NullifierMode` on
+// its key entry): once a proof verifies, its nullifier is recorded in storage,
+// a `NullifierUsed` event is emitted, and later calls with the same nullifier
+// are rejected with `NullifierAlreadyUsed()`. `isUsed(bytes32)` exposes the set read-only.
+//
+// Groth16 proofs are malleable: anyone can re-randomize a valid proof into a
+// different valid proof for the same inputs. `ProofHash` therefore only stops
+// byte-identical replays; circuits that need one-time use must expose a
+// nullifier as a public input and use `PublicInput`.
+
+use alloc::vec::Vec;
+
+use ark_serialize::CanonicalSerialize;
+use sha2::{Digest, Sha256};
+
+use crate::abi::{self, VerifyCall};
+use crate::host::Host;
+use crate::{encode_public_input, RejectReason, Verdict};
+
+/// keccak256("NullifierUsed(bytes32,uint256)"), topic 0 of the event
+pub const NULLIFIER_USED_TOPIC: [u8; 32] = [
+    0x31, 0x66, 0x45, 0xec, 0xaf, 0x93, 0x8c, 0x84, 0x6b, 0x02, 0x4e, 0x0b, 0x93, 0x0d, 0x5c, 0xc9,
+    0x89, 0xae, 0x15, 0x74, 0x55, 0xee, 0xa2, 0xab, 0x97, 0x57, 0x6c, 0xd0, 0x0c, 0x01, 0x7d, 0x36,
+];
+
+const STORAGE_DOMAIN: &[u8] = b"niet2code/nullifier";
+
+/// What a circuit's proofs are deduplicated by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NullifierMode {
+    /// Stateless: the same calldata verifies every time
+    Off,
+    /// SHA-256 over the circuit id and the compressed proof
+    ProofHash,
+    /// The public input at this index, as a big-endian `bytes32`
+    PublicInput(usize),
+}
+
+/// Nullifier of a decoded `verify` call, `None` when the mode is `Off`
+pub fn nullifier(mode: NullifierMode, call: &VerifyCall) -> Result<Option<[u8; 32]>, RejectReason> {
+    match mode {
+        NullifierMode::Off => Ok(None),
+        NullifierMode::ProofHash => {
+            let mut hasher = Sha256::new();
+            hasher.update(abi::uint_word(call.circuit_id));
+            let mut proof = Vec::new();
+            call.proof
+                .serialize_compressed(&mut proof)
+                .map_err(|_| RejectReason::MalformedProof)?;
+            hasher.update(&proof);
+            Ok(Some(hasher.finalize().into()))
+        }
+        NullifierMode::PublicInput(index) => call
+            .public_inputs
+            .get(index)
+            .map(|input| Some(encode_public_input(input)))
+            .ok_or(RejectReason::PublicInputCount {
+                expected: index + 1,
+                actual: call.public_inputs.len(),
+            }),
+    }
+}
+
+fn storage_key(nullifier: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(STORAGE_DOMAIN);
+    hasher.update(nullifier);
+    hasher.finalize().into()
+}
+
+pub fn is_used<H: Host>(host: &H, nullifier: &[u8; 32]) -> bool {
+    host.get_storage(&storage_key(nullifier)).is_some()
+}
+
+/// Record the nullifier of a verified call and emit `NullifierUsed`, or reject
+/// the call if it was seen before
+pub fn consume<H: Host>(host: &mut H, mode: NullifierMode, call: &VerifyCall) -> Verdict {
+    let nullifier = match nullifier(mode, call) {
+        Ok(Some(nullifier)) => nullifier,
+        Ok(None) => return Verdict::Valid,
+        Err(reason) => return Verdict::Rejected(reason),
+    };
+    if is_used(host, &nullifier) {
+        return Verdict::Rejected(RejectReason::NullifierUsed);
+    }
+
+    host.set_storage(&storage_key(&nullifier), &[1]);
+    host.deposit_event(&[NULLIFIER_USED_TOPIC, nullifier, abi::uint_word(call.circuit_id)], &[]);
+    Verdict::Valid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use crate::abi::{IS_USED_SELECTOR, WORD};
+    use crate::dispatch::{self, dispatch, ResultMode, VerifierKeyEntry};
+    use crate::errors::ContractError;
+    use crate::host::MemoryHost;
+    use ark_bn254::Fr;
+
+    const CALLDATA: &[u8] = include_bytes!("../../test-vectors/mul/calldata.bin");
+    const PREPARED_VERIFYING_KEY: &[u8] = include_bytes!("../../test-vectors/mul/prepared_verifying_key.bin");
+    const EVM_VERIFYING_KEY: &[u8] = include_bytes!("../../test-vectors/mul/evm_verifying_key.bin");
+
+    fn keys(nullifier: NullifierMode) -> [VerifierKeyEntry; 1] {
+        [VerifierKeyEntry {
+            circuit_id: 1,
+            circuit_version: 1,
+            key_hash: [0xab; 32],
+            prepared_key: PREPARED_VERIFYING_KEY,
+            evm_key: EVM_VERIFYING_KEY,
+            nullifier,
+        }]
+    }
+
+    fn verify_twice(mode: NullifierMode) -> (MemoryHost, [Verdict; 2]) {
+        let mut host = MemoryHost::default();
+        let keys = keys(mode);
+        let args = &CALLDATA[abi::SELECTOR_LEN..];
+        let first = dispatch::verify_args(&mut host, args, &keys);
+        let second = dispatch::verify_args(&mut host, args, &keys);
+        (host, [first, second])
+    }
+
+    fn is_used_call(nullifier: &[u8; 32]) -> Vec<u8> {
+        let mut calldata = IS_USED_SELECTOR.to_vec();
+        calldata.extend_from_slice(nullifier);
+        calldata
+    }
+
+    #[test]
+    fn test_off_mode_stays_stateless() {
+        let (host, verdicts) = verify_twice(NullifierMode::Off);
+        assert_eq!(verdicts, [Verdict::Valid, Verdict::Valid]);
+        assert!(host.storage.is_empty());
+        assert!(host.events.is_empty());
+    }
+
+    #[test]
+    fn test_replayed_proof_is_rejected() {
+        for mode in [NullifierMode::ProofHash, NullifierMode::PublicInput(0)] {
+            let (mut host, verdicts) = verify_twice(mode);
+            assert_eq!(verdicts, [Verdict::Valid, Verdict::Rejected(RejectReason::NullifierUsed)]);
+
+            let call = abi::decode_verify_args(&CALLDATA[abi::SELECTOR_LEN..]).unwrap();
+            let used = nullifier(mode, &call).unwrap().unwrap();
+            assert_eq!(host.events, vec![(vec![NULLIFIER_USED_TOPIC, used, abi::uint_word(1)], vec![])]);
+
+            let keys = keys(mode);
+            assert_eq!(dispatch(&mut host, &is_used_call(&used), &keys, ResultMode::Errors).data, abi::encode_bool(true));
+            assert_eq!(dispatch(&mut host, &is_used_call(&[0; 32]), &keys, ResultMode::Errors).data, abi::encode_bool(false));
+
+            let replay = dispatch(&mut host, CALLDATA, &keys, ResultMode::Errors);
+            assert!(replay.revert);
+            assert_eq!(replay.data, ContractError::NullifierAlreadyUsed.encode());
+            let replay = dispatch(&mut host, CALLDATA, &keys, ResultMode::Bool);
+            assert!(!replay.revert);
+            assert_eq!(replay.data, abi::encode_bool(false));
+        }
+    }
+
+    #[test]
+    fn test_public_input_nullifier_is_the_input_word() {
+        let call = abi::decode_verify_args(&CALLDATA[abi::SELECTOR_LEN..]).unwrap();
+        assert_eq!(
+            nullifier(NullifierMode::PublicInput(0), &call).unwrap(),
+            Some(encode_public_input(&Fr::from(12u64)))
+        );
+        assert_eq!(
+            nullifier(NullifierMode::PublicInput(1), &call),
+            Err(RejectReason::PublicInputCount { expected: 2, actual: 1 })
+        );
+    }
+
+    #[test]
+    fn test_invalid_proof_does_not_consume_nullifier() {
+        let mut host = MemoryHost::default();
+        let keys = keys(NullifierMode::PublicInput(0));
+        let mut calldata = CALLDATA.to_vec();
+        calldata[abi::SELECTOR_LEN + 5 * WORD + 5] ^= 0x01;
+
+        assert!(!dispatch::verify_args(&mut host, &calldata[abi::SELECTOR_LEN..], &keys).is_valid());
+        assert!(host.events.is_empty());
+        assert_eq!(dispatch::verify_args(&mut host, &CALLDATA[abi::SELECTOR_LEN..], &keys), Verdict::Valid);
+    }
+
+    #[test]
+    fn test_is_used_requires_a_full_word() {
+        let mut host = MemoryHost::default();
+        assert!(dispatch(&mut host, &IS_USED_SELECTOR, &keys(NullifierMode::Off), ResultMode::Errors).revert);
+    }
+}
@@ -0,0 +1,390 @@
+// Groth16 verification through the chain's BN254 precompiles (EIP-196/197):
+// `ecAdd` at 0x06, `ecMul` at 0x07 and `ecPairing` at 0x08. The contract then
+// only encodes points and moves bytes; the multi-scalar multiplication and the
+// pairing run natively in the runtime instead of in arkworks inside PolkaVM.
+//
+// Points use the EVM layout: G1 is x ‖ y, G2 is x.c1 ‖ x.c0 ‖ y.c1 ‖ y.c0, every
+// coordinate a 32-byte big-endian integer, and the point at infinity is all
+// zeros. Keys in this layout (`encode_verifying_key`) are
+//
+//   alpha (G1) ‖ beta (G2) ‖ gamma (G2) ‖ delta (G2) ‖ gamma_abc[0..=n] (G1)
+//
+// and are embedded next to the prepared key, which stays as the arkworks
+// fallback for chains without the precompiles.
+
+use alloc::vec::Vec;
+
+use ark_bn254::{Bn254, Fq, Fq2, G1Affine, G2Affine};
+use ark_ec::AffineRepr;
+use ark_ff::{BigInt, BigInteger, PrimeField, Zero};
+
+use crate::abi::{VerifyCall, WORD};
+use crate::groth16::{Proof, VerifyingKey};
+use crate::host::{Address, Host};
+use crate::{encode_public_input, RejectReason, Verdict, EVM_PROOF_LEN};
+
+pub const G1_LEN: usize = 2 * WORD;
+pub const G2_LEN: usize = 4 * WORD;
+/// alpha, beta, gamma and delta, before the `gamma_abc` points
+pub const KEY_HEADER_LEN: usize = G1_LEN + 3 * G2_LEN;
+const PAIRING_PAIR_LEN: usize = G1_LEN + G2_LEN;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bn254Precompile {
+    /// `ecAdd`: two G1 points in, their sum out
+    Add,
+    /// `ecMul`: a G1 point and a 256-bit scalar in, the product out
+    Mul,
+    /// `ecPairing`: (G1, G2) pairs in, a word that is 1 iff the product of pairings is one
+    Pairing,
+}
+
+impl Bn254Precompile {
+    pub fn address(self) -> Address {
+        let mut address = [0u8; 20];
+        address[19] = match self {
+            Bn254Precompile::Add => 0x06,
+            Bn254Precompile::Mul => 0x07,
+            Bn254Precompile::Pairing => 0x08,
+        };
+        address
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrecompileError {
+    /// Nothing answered at the address (a call to an account without code returns no data)
+    Unavailable,
+    /// The precompile rejected its input or ran out of gas
+    Failed,
+}
+
+pub fn encode_g1(point: &G1Affine) -> [u8; G1_LEN] {
+    let mut out = [0u8; G1_LEN];
+    if let Some((x, y)) = point.xy() {
+        out[..WORD].copy_from_slice(&fq_to_be(x));
+        out[WORD..].copy_from_slice(&fq_to_be(y));
+    }
+    out
+}
+
+pub fn encode_g2(point: &G2Affine) -> [u8; G2_LEN] {
+    let mut out = [0u8; G2_LEN];
+    if let Some((x, y)) = point.xy() {
+        for (i, coordinate) in [x.c1, x.c0, y.c1, y.c0].iter().enumerate() {
+            out[i * WORD..(i + 1) * WORD].copy_from_slice(&fq_to_be(coordinate));
+        }
+    }
+    out
+}
+
+/// Decode a G1 point, checking it is on the curve (the group has cofactor 1)
+pub fn decode_g1(bytes: &[u8]) -> Option<G1Affine> {
+    if bytes.len() != G1_LEN {
+        return None;
+    }
+    let (x, y) = (fq_from_be(&bytes[..WORD])?, fq_from_be(&bytes[WORD..])?);
+    if x.is_zero() && y.is_zero() {
+        return Some(G1Affine::zero());
+    }
+    let point = G1Affine::new_unchecked(x, y);
+    point.is_on_curve().then_some(point)
+}
+
+/// Decode a G2 point, checking it is on the curve and in the prime-order subgroup
+pub fn decode_g2(bytes: &[u8]) -> Option<G2Affine> {
+    if bytes.len() != G2_LEN {
+        return None;
+    }
+    let mut coordinates = [Fq::zero(); 4];
+    for (i, coordinate) in coordinates.iter_mut().enumerate() {
+        *coordinate = fq_from_be(&bytes[i * WORD..(i + 1) * WORD])?;
+    }
+    let [x_c1, x_c0, y_c1, y_c0] = coordinates;
+    if coordinates.iter().all(Zero::is_zero) {
+        return Some(G2Affine::zero());
+    }
+    let point = G2Affine::new_unchecked(Fq2::new(x_c0, x_c1), Fq2::new(y_c0, y_c1));
+    (point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve()).then_some(point)
+}
+
+/// Proof in the precompile layout: A (G1) ‖ B (G2) ‖ C (G1)
+pub fn encode_proof(proof: &Proof<Bn254>) -> [u8; EVM_PROOF_LEN] {
+    let mut out = [0u8; EVM_PROOF_LEN];
+    out[..G1_LEN].copy_from_slice(&encode_g1(&proof.a));
+    out[G1_LEN..G1_LEN + G2_LEN].copy_from_slice(&encode_g2(&proof.b));
+    out[G1_LEN + G2_LEN..].copy_from_slice(&encode_g1(&proof.c));
+    out
+}
+
+pub fn decode_proof(bytes: &[u8]) -> Option<Proof<Bn254>> {
+    if bytes.len() != EVM_PROOF_LEN {
+        return None;
+    }
+    Some(Proof {
+        a: decode_g1(&bytes[..G1_LEN])?,
+        b: decode_g2(&bytes[G1_LEN..G1_LEN + G2_LEN])?,
+        c: decode_g1(&bytes[G1_LEN + G2_LEN..])?,
+    })
+}
+
+pub fn encode_verifying_key(vk: &VerifyingKey<Bn254>) -> Vec<u8> {
+    let mut out = Vec::with_capacity(KEY_HEADER_LEN + G1_LEN * vk.gamma_abc_g1.len());
+    out.extend_from_slice(&encode_g1(&vk.alpha_g1));
+    out.extend_from_slice(&encode_g2(&vk.beta_g2));
+    out.extend_from_slice(&encode_g2(&vk.gamma_g2));
+    out.extend_from_slice(&encode_g2(&vk.delta_g2));
+    for point in &vk.gamma_abc_g1 {
+        out.extend_from_slice(&encode_g1(point));
+    }
+    out
+}
+
+/// Check a decoded call against a key in the precompile layout:
+///
+///   e(-A, B) · e(alpha, beta) · e(vk_x, gamma) · e(C, delta) == 1
+///
+/// where vk_x = gamma_abc[0] + Σ input_i · gamma_abc[i + 1] is computed with
+/// `ecMul` / `ecAdd`. The key must come from `encode_verifying_key`; its points
+/// are not re-validated.
+pub fn verify<H: Host>(host: &mut H, key: &[u8], call: &VerifyCall) -> Result<Verdict, PrecompileError> {
+    let gamma_abc = match key.get(KEY_HEADER_LEN..) {
+        Some(points) if !points.is_empty() && points.len().is_multiple_of(G1_LEN) => points,
+        _ => return Ok(Verdict::Rejected(RejectReason::MalformedVerifyingKey)),
+    };
+    let expected = gamma_abc.len() / G1_LEN - 1;
+    if expected != call.public_inputs.len() {
+        return Ok(Verdict::Rejected(RejectReason::PublicInputCount {
+            expected,
+            actual: call.public_inputs.len(),
+        }));
+    }
+
+    let mut vk_x = [0u8; G1_LEN];
+    vk_x.copy_from_slice(&gamma_abc[..G1_LEN]);
+    for (input, point) in call.public_inputs.iter().zip(gamma_abc[G1_LEN..].chunks_exact(G1_LEN)) {
+        let mut mul = Vec::with_capacity(G1_LEN + WORD);
+        mul.extend_from_slice(point);
+        mul.extend_from_slice(&encode_public_input(input));
+        let product = call_point(host, Bn254Precompile::Mul, &mul)?;
+
+        let mut add = Vec::with_capacity(2 * G1_LEN);
+        add.extend_from_slice(&vk_x);
+        add.extend_from_slice(&product);
+        vk_x = call_point(host, Bn254Precompile::Add, &add)?;
+    }
+
+    let (alpha, rest) = key.split_at(G1_LEN);
+    let (beta, rest) = rest.split_at(G2_LEN);
+    let (gamma, rest) = rest.split_at(G2_LEN);
+    let delta = &rest[..G2_LEN];
+
+    let mut pairs = Vec::with_capacity(4 * PAIRING_PAIR_LEN);
+    for (g1, g2) in [
+        (&encode_g1(&-call.proof.a)[..], &encode_g2(&call.proof.b)[..]),
+        (alpha, beta),
+        (&vk_x[..], gamma),
+        (&encode_g1(&call.proof.c)[..], delta),
+    ] {
+        pairs.extend_from_slice(g1);
+        pairs.extend_from_slice(g2);
+    }
+
+    let output = call_checked(host, Bn254Precompile::Pairing, &pairs)?;
+    match output.as_slice() {
+        [zeros @ .., last] if output.len() == WORD && zeros.iter().all(|&b| b == 0) && *last <= 1 => {
+            Ok(if *last == 1 { Verdict::Valid } else { Verdict::Invalid })
+        }
+        _ => Err(PrecompileError::Failed),
+    }
+}
+
+fn call_checked<H: Host>(host: &mut H, precompile: Bn254Precompile, input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+    match host.call_precompile(precompile, input)? {
+        output if output.is_empty() => Err(PrecompileError::Unavailable),
+        output => Ok(output),
+    }
+}
+
+fn call_point<H: Host>(host: &mut H, precompile: Bn254Precompile, input: &[u8]) -> Result<[u8; G1_LEN], PrecompileError> {
+    call_checked(host, precompile, input)?
+        .try_into()
+        .map_err(|_| PrecompileError::Failed)
+}
+
+/// The three precompiles in software with EIP-196/197 semantics, for hosts that
+/// stand in for the chain (unit tests, the contract's native harness). Behind
+/// `emulated-precompiles`, since the pairing has no place in a deployed contract.
+#[cfg(any(test, feature = "emulated-precompiles"))]
+pub fn emulate(precompile: Bn254Precompile, input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+    use ark_ec::pairing::Pairing;
+
+    // Short input is zero-padded, as on the EVM
+    let padded = |len: usize| {
+        let mut buf = input.to_vec();
+        buf.resize(len.max(input.len()), 0);
+        buf
+    };
+    match precompile {
+        Bn254Precompile::Add => {
+            let input = padded(2 * G1_LEN);
+            let a = decode_g1(&input[..G1_LEN]).ok_or(PrecompileError::Failed)?;
+            let b = decode_g1(&input[G1_LEN..2 * G1_LEN]).ok_or(PrecompileError::Failed)?;
+            Ok(encode_g1(&(a + b).into()).to_vec())
+        }
+        Bn254Precompile::Mul => {
+            let input = padded(G1_LEN + WORD);
+            let point = decode_g1(&input[..G1_LEN]).ok_or(PrecompileError::Failed)?;
+            let scalar = limbs_from_be(&input[G1_LEN..G1_LEN + WORD]);
+            Ok(encode_g1(&point.mul_bigint(scalar).into()).to_vec())
+        }
+        Bn254Precompile::Pairing => {
+            if !input.len().is_multiple_of(PAIRING_PAIR_LEN) {
+                return Err(PrecompileError::Failed);
+            }
+            let mut g1 = Vec::with_capacity(input.len() / PAIRING_PAIR_LEN);
+            let mut g2 = Vec::with_capacity(input.len() / PAIRING_PAIR_LEN);
+            for pair in input.chunks_exact(PAIRING_PAIR_LEN) {
+                g1.push(decode_g1(&pair[..G1_LEN]).ok_or(PrecompileError::Failed)?);
+                g2.push(decode_g2(&pair[G1_LEN..]).ok_or(PrecompileError::Failed)?);
+            }
+            let one = Bn254::multi_pairing(g1, g2).is_zero();
+            let mut word = [0u8; WORD];
+            word[WORD - 1] = one as u8;
+            Ok(word.to_vec())
+        }
+    }
+}
+
+fn fq_to_be(value: &Fq) -> [u8; WORD] {
+    let mut out = [0u8; WORD];
+    out.copy_from_slice(&value.into_bigint().to_bytes_be());
+    out
+}
+
+/// `None` for values >= the base field modulus
+fn fq_from_be(bytes: &[u8]) -> Option<Fq> {
+    Fq::from_bigint(BigInt(limbs_from_be(bytes)))
+}
+
+fn limbs_from_be(bytes: &[u8]) -> [u64; 4] {
+    let mut limbs = [0u64; 4];
+    for (i, limb) in limbs.iter_mut().enumerate() {
+        let end = WORD - 8 * i;
+        let mut word = [0u8; 8];
+        word.copy_from_slice(&bytes[end - 8..end]);
+        *limb = u64::from_be_bytes(word);
+    }
+    limbs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use crate::host::MemoryHost;
+    use crate::{abi, decode_verifying_key};
+    use ark_bn254::Fr;
+
+    const CALLDATA: &[u8] = include_bytes!("../../test-vectors/mul/calldata.bin");
+    #[cfg(feature = "software-pairing")]
+    const EVM_CALLDATA: &[u8] = include_bytes!("../../test-vectors/mul/evm_calldata.bin");
+    const VERIFYING_KEY: &[u8] = include_bytes!("../../test-vectors/mul/verifying_key.bin");
+    const EVM_VERIFYING_KEY: &[u8] = include_bytes!("../../test-vectors/mul/evm_verifying_key.bin");
+
+    fn decoded() -> VerifyCall {
+        abi::decode_verify_args(&CALLDATA[abi::SELECTOR_LEN..]).unwrap()
+    }
+
+    fn emulating() -> MemoryHost {
+        MemoryHost {
+            precompiles: true,
+            ..MemoryHost::default()
+        }
+    }
+
+    #[test]
+    fn test_key_and_proof_layouts_round_trip() {
+        let vk = decode_verifying_key(VERIFYING_KEY).unwrap();
+        assert_eq!(encode_verifying_key(&vk), EVM_VERIFYING_KEY);
+        assert_eq!(EVM_VERIFYING_KEY.len(), KEY_HEADER_LEN + 2 * G1_LEN);
+
+        let call = decoded();
+        let bytes = encode_proof(&call.proof);
+        assert_eq!(decode_proof(&bytes), Some(call.proof));
+        assert_eq!(decode_g1(&[0; G1_LEN]), Some(G1Affine::zero()));
+        assert_eq!(decode_g2(&[0; G2_LEN]), Some(G2Affine::zero()));
+    }
+
+    #[test]
+    fn test_decode_rejects_points_off_the_curve() {
+        let bytes = encode_proof(&decoded().proof);
+        let mut a = bytes;
+        a[G1_LEN - 1] ^= 0x01;
+        assert_eq!(decode_proof(&a), None);
+        let mut b = bytes;
+        b[G1_LEN + G2_LEN - 1] ^= 0x01;
+        assert_eq!(decode_proof(&b), None);
+        // x >= p
+        assert_eq!(decode_g1(&[0xff; G1_LEN]), None);
+        assert_eq!(decode_proof(&bytes[1..]), None);
+    }
+
+    #[test]
+    fn test_precompile_verdicts_match_arkworks() {
+        let mut host = emulating();
+        assert_eq!(verify(&mut host, EVM_VERIFYING_KEY, &decoded()), Ok(Verdict::Valid));
+        #[cfg(feature = "software-pairing")]
+        assert_eq!(crate::verify_calldata(EVM_CALLDATA, VERIFYING_KEY), Verdict::Valid);
+
+        let mut wrong_input = decoded();
+        wrong_input.public_inputs[0] += Fr::from(1u64);
+        assert_eq!(verify(&mut host, EVM_VERIFYING_KEY, &wrong_input), Ok(Verdict::Invalid));
+
+        let mut extra_input = decoded();
+        extra_input.public_inputs.push(Fr::from(1u64));
+        assert_eq!(
+            verify(&mut host, EVM_VERIFYING_KEY, &extra_input),
+            Ok(Verdict::Rejected(RejectReason::PublicInputCount { expected: 1, actual: 2 }))
+        );
+        assert_eq!(
+            verify(&mut host, &EVM_VERIFYING_KEY[..KEY_HEADER_LEN + 1], &decoded()),
+            Ok(Verdict::Rejected(RejectReason::MalformedVerifyingKey))
+        );
+    }
+
+    #[test]
+    fn test_missing_precompiles_are_reported() {
+        let mut host = MemoryHost {
+            precompiles: false,
+            ..MemoryHost::default()
+        };
+        assert_eq!(verify(&mut host, EVM_VERIFYING_KEY, &decoded()), Err(PrecompileError::Unavailable));
+    }
+
+    #[test]
+    fn test_emulated_precompiles_follow_eip_196_197() {
+        let generator = encode_g1(&G1Affine::generator());
+        let mut double = generator.to_vec();
+        double.extend_from_slice(&generator);
+        let mut times_two = generator.to_vec();
+        times_two.extend_from_slice(&abi::uint_word(2));
+        assert_eq!(emulate(Bn254Precompile::Add, &double), emulate(Bn254Precompile::Mul, &times_two));
+        assert_eq!(emulate(Bn254Precompile::Add, &[]), Ok(vec![0; G1_LEN]));
+        assert_eq!(emulate(Bn254Precompile::Add, &[0xff; 2 * G1_LEN]), Err(PrecompileError::Failed));
+
+        let mut one = [0u8; WORD];
+        one[WORD - 1] = 1;
+        assert_eq!(emulate(Bn254Precompile::Pairing, &[]), Ok(one.to_vec()));
+        assert_eq!(emulate(Bn254Precompile::Pairing, &[0; PAIRING_PAIR_LEN - 1]), Err(PrecompileError::Failed));
+
+        // e(P, Q) · e(-P, Q) == 1
+        let mut pairs = Vec::new();
+        for point in [G1Affine::generator(), -G1Affine::generator()] {
+            pairs.extend_from_slice(&encode_g1(&point));
+            pairs.extend_from_slice(&encode_g2(&G2Affine::generator()));
+        }
+        assert_eq!(emulate(Bn254Precompile::Pairing, &pairs), Ok(one.to_vec()));
+        assert_eq!(emulate(Bn254Precompile::Pairing, &pairs[..PAIRING_PAIR_LEN]), Ok(vec![0; WORD]));
+    }
+}
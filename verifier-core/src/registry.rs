@@ -0,0 +1,337 @@
+// Verifying keys registered in contract storage at runtime, next to the keys
+// compiled into the contract (`dispatch::VerifierKeyEntry`). Rotating a key no
+// longer needs a rebuild and redeploy: the owner recorded by `deploy()` calls
+// `registerKey` with the new plain key, or `deprecateKey` to retire one.
+//
+// Storage layout (32-byte keys, `tag | vk id (u64 BE) | chunk index (u16 BE)`, zero padded):
+// - OWNER_TAG: 20-byte owner address
+// - RECORD_TAG: `KeyRecord` (status, circuit version, key length, key hash)
+// - CHUNK_TAG: the uncompressed `VerifyingKey`, split into `STORAGE_CHUNK_LEN` pieces
+//
+// A stored record takes precedence over a compiled-in key with the same id.
+// Keys are fully validated when registered and read back unchecked afterwards,
+// since only the owner can write them.
+
+use alloc::vec::Vec;
+
+use ark_bn254::Bn254;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use sha2::{Digest, Sha256};
+
+use crate::dispatch::VerifierKeyEntry;
+use crate::groth16::VerifyingKey;
+use crate::host::{Address, Host};
+use crate::{decode_verifying_key, RejectReason};
+
+/// Largest storage value pallet-revive accepts
+pub const STORAGE_CHUNK_LEN: usize = 416;
+
+const OWNER_TAG: u8 = 0x01;
+const RECORD_TAG: u8 = 0x02;
+const CHUNK_TAG: u8 = 0x03;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyStatus {
+    Active,
+    Deprecated,
+}
+
+/// Metadata stored for a registered (or deprecated) key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyRecord {
+    pub status: KeyStatus,
+    pub circuit_version: u32,
+    /// Length of the stored uncompressed key; 0 when deprecating a compiled-in key
+    pub key_len: u32,
+    /// SHA-256 of the compressed key, as returned by `vkHash`
+    pub key_hash: [u8; 32],
+}
+
+impl KeyRecord {
+    const LEN: usize = 1 + 4 + 4 + 32;
+
+    fn encode(&self) -> [u8; Self::LEN] {
+        let mut out = [0u8; Self::LEN];
+        out[0] = match self.status {
+            KeyStatus::Active => 1,
+            KeyStatus::Deprecated => 2,
+        };
+        out[1..5].copy_from_slice(&self.circuit_version.to_be_bytes());
+        out[5..9].copy_from_slice(&self.key_len.to_be_bytes());
+        out[9..].copy_from_slice(&self.key_hash);
+        out
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::LEN {
+            return None;
+        }
+        let status = match bytes[0] {
+            1 => KeyStatus::Active,
+            2 => KeyStatus::Deprecated,
+            _ => return None,
+        };
+        Some(Self {
+            status,
+            circuit_version: u32::from_be_bytes(bytes[1..5].try_into().ok()?),
+            key_len: u32::from_be_bytes(bytes[5..9].try_into().ok()?),
+            key_hash: bytes[9..].try_into().ok()?,
+        })
+    }
+}
+
+/// Why an admin call was refused (the call reverts)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdminError {
+    NotOwner,
+    MalformedKey,
+    UnknownKey,
+}
+
+fn storage_key(tag: u8, vk_id: u64, index: u16) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    key[0] = tag;
+    key[1..9].copy_from_slice(&vk_id.to_be_bytes());
+    key[9..11].copy_from_slice(&index.to_be_bytes());
+    key
+}
+
+/// Record the caller as owner unless one is already set (called from `deploy()`)
+pub fn init_owner<H: Host>(host: &mut H) {
+    if owner(host).is_none() {
+        let caller = host.caller();
+        host.set_storage(&storage_key(OWNER_TAG, 0, 0), &caller);
+    }
+}
+
+pub fn owner<H: Host>(host: &H) -> Option<Address> {
+    host.get_storage(&storage_key(OWNER_TAG, 0, 0))?.try_into().ok()
+}
+
+fn ensure_owner<H: Host>(host: &H) -> Result<(), AdminError> {
+    match owner(host) {
+        Some(owner) if owner == host.caller() => Ok(()),
+        _ => Err(AdminError::NotOwner),
+    }
+}
+
+pub fn read_record<H: Host>(host: &H, vk_id: u64) -> Option<KeyRecord> {
+    KeyRecord::decode(&host.get_storage(&storage_key(RECORD_TAG, vk_id, 0))?)
+}
+
+/// SHA-256 of the compressed key (the CLI's `sha256:` fingerprint)
+pub fn key_hash(vk: &VerifyingKey<Bn254>) -> [u8; 32] {
+    let mut bytes = Vec::new();
+    vk.serialize_compressed(&mut bytes).expect("serializing into a Vec cannot fail");
+    Sha256::digest(&bytes).into()
+}
+
+/// Store `vk_bytes` (uncompressed `VerifyingKey`) under `vk_id`, replacing any
+/// key already registered there. Owner only.
+pub fn register_key<H: Host>(
+    host: &mut H,
+    vk_id: u64,
+    circuit_version: u32,
+    vk_bytes: &[u8],
+) -> Result<KeyRecord, AdminError> {
+    ensure_owner(host)?;
+    let vk = decode_verifying_key(vk_bytes).map_err(|_| AdminError::MalformedKey)?;
+    let key_len = u32::try_from(vk_bytes.len()).map_err(|_| AdminError::MalformedKey)?;
+
+    for (index, chunk) in vk_bytes.chunks(STORAGE_CHUNK_LEN).enumerate() {
+        let index = u16::try_from(index).map_err(|_| AdminError::MalformedKey)?;
+        host.set_storage(&storage_key(CHUNK_TAG, vk_id, index), chunk);
+    }
+
+    let record = KeyRecord {
+        status: KeyStatus::Active,
+        circuit_version,
+        key_len,
+        key_hash: key_hash(&vk),
+    };
+    host.set_storage(&storage_key(RECORD_TAG, vk_id, 0), &record.encode());
+    Ok(record)
+}
+
+/// Mark the key for `vk_id` deprecated, whether stored or compiled in. Owner only.
+pub fn deprecate_key<H: Host>(host: &mut H, vk_id: u64, builtin: Option<&VerifierKeyEntry>) -> Result<(), AdminError> {
+    ensure_owner(host)?;
+    let record = match (read_record(host, vk_id), builtin) {
+        (Some(record), _) => record,
+        (None, Some(entry)) => KeyRecord {
+            status: KeyStatus::Active,
+            circuit_version: entry.circuit_version,
+            key_len: 0,
+            key_hash: entry.key_hash,
+        },
+        (None, None) => return Err(AdminError::UnknownKey),
+    };
+
+    let record = KeyRecord {
+        status: KeyStatus::Deprecated,
+        ..record
+    };
+    host.set_storage(&storage_key(RECORD_TAG, vk_id, 0), &record.encode());
+    Ok(())
+}
+
+/// Read back the key of an active record
+pub fn load_key<H: Host>(host: &H, vk_id: u64, record: &KeyRecord) -> Result<VerifyingKey<Bn254>, RejectReason> {
+    let len = record.key_len as usize;
+    let mut bytes = Vec::with_capacity(len);
+    for index in 0..len.div_ceil(STORAGE_CHUNK_LEN) {
+        let index = u16::try_from(index).map_err(|_| RejectReason::MalformedVerifyingKey)?;
+        let chunk = host
+            .get_storage(&storage_key(CHUNK_TAG, vk_id, index))
+            .ok_or(RejectReason::MalformedVerifyingKey)?;
+        bytes.extend_from_slice(&chunk);
+    }
+    if bytes.len() != len {
+        return Err(RejectReason::MalformedVerifyingKey);
+    }
+    VerifyingKey::<Bn254>::deserialize_uncompressed_unchecked(bytes.as_slice())
+        .map_err(|_| RejectReason::MalformedVerifyingKey)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::{self, DEPRECATE_KEY_SELECTOR, OWNER_SELECTOR, WORD};
+    use crate::dispatch::{self, dispatch, ResultMode};
+    use crate::errors::ContractError;
+    use crate::host::MemoryHost;
+    use crate::nullifier::NullifierMode;
+    use crate::{encode_calldata, Verdict};
+
+    const CALLDATA: &[u8] = include_bytes!("../../test-vectors/mul/calldata.bin");
+    const VERIFYING_KEY: &[u8] = include_bytes!("../../test-vectors/mul/verifying_key.bin");
+    const PREPARED_VERIFYING_KEY: &[u8] = include_bytes!("../../test-vectors/mul/prepared_verifying_key.bin");
+    const EVM_VERIFYING_KEY: &[u8] = include_bytes!("../../test-vectors/mul/evm_verifying_key.bin");
+
+    const OWNER: Address = [0x11; 20];
+    const STRANGER: Address = [0x22; 20];
+    const STORED_ID: u64 = 42;
+
+    const KEYS: &[VerifierKeyEntry] = &[VerifierKeyEntry {
+        circuit_id: 1,
+        circuit_version: 1,
+        key_hash: [0xab; 32],
+        prepared_key: PREPARED_VERIFYING_KEY,
+        evm_key: EVM_VERIFYING_KEY,
+        nullifier: NullifierMode::Off,
+    }];
+
+    fn deployed() -> MemoryHost {
+        let mut host = MemoryHost {
+            caller: OWNER,
+            ..MemoryHost::default()
+        };
+        dispatch::deploy(&mut host);
+        host
+    }
+
+    /// The test-vector call, pointed at another key id
+    fn verify_call(vk_id: u64) -> Vec<u8> {
+        let call = abi::decode_verify_args(&CALLDATA[abi::SELECTOR_LEN..]).unwrap();
+        encode_calldata(vk_id, &call.proof, &call.public_inputs).unwrap()
+    }
+
+    fn verdict(host: &mut MemoryHost, vk_id: u64) -> Verdict {
+        dispatch::verify_args(host, &verify_call(vk_id)[abi::SELECTOR_LEN..], KEYS)
+    }
+
+    #[test]
+    fn test_deploy_records_caller_as_owner_once() {
+        let mut host = deployed();
+        host.caller = STRANGER;
+        dispatch::deploy(&mut host);
+
+        assert_eq!(owner(&host), Some(OWNER));
+        assert_eq!(dispatch(&mut host, &OWNER_SELECTOR, KEYS, ResultMode::Errors).data, abi::encode_address(&OWNER));
+    }
+
+    #[test]
+    fn test_registered_key_verifies_proofs_for_its_id() {
+        let mut host = deployed();
+        assert_eq!(verdict(&mut host, STORED_ID), Verdict::Rejected(RejectReason::UnknownCircuit));
+
+        let response = dispatch(&mut host, &abi::encode_register_key_call(STORED_ID, 3, VERIFYING_KEY), KEYS, ResultMode::Errors);
+        assert!(!response.revert);
+
+        let record = read_record(&host, STORED_ID).unwrap();
+        assert_eq!(record.status, KeyStatus::Active);
+        assert_eq!(record.circuit_version, 3);
+        assert_eq!(record.key_len as usize, VERIFYING_KEY.len());
+        assert!(VERIFYING_KEY.len() > STORAGE_CHUNK_LEN, "key should span several chunks");
+        assert_eq!(verdict(&mut host, STORED_ID), Verdict::Valid);
+        assert_eq!(dispatch::active_key_hash(&host, STORED_ID, KEYS), Some(record.key_hash));
+    }
+
+    #[test]
+    fn test_admin_calls_from_non_owner_revert() {
+        let mut host = deployed();
+        host.caller = STRANGER;
+
+        for call in [abi::encode_register_key_call(STORED_ID, 1, VERIFYING_KEY), abi::encode_deprecate_key_call(1)] {
+            let response = dispatch(&mut host, &call, KEYS, ResultMode::Errors);
+            assert!(response.revert);
+            assert_eq!(response.data, ContractError::NotOwner.encode());
+        }
+        assert_eq!(register_key(&mut host, STORED_ID, 1, VERIFYING_KEY), Err(AdminError::NotOwner));
+        assert_eq!(read_record(&host, STORED_ID), None);
+        assert_eq!(verdict(&mut host, 1), Verdict::Valid);
+    }
+
+    #[test]
+    fn test_malformed_key_is_not_registered() {
+        let mut host = deployed();
+        assert_eq!(
+            register_key(&mut host, STORED_ID, 1, &VERIFYING_KEY[..100]),
+            Err(AdminError::MalformedKey)
+        );
+        let response = dispatch(&mut host, &abi::encode_register_key_call(STORED_ID, 1, &[0xff; 64]), KEYS, ResultMode::Errors);
+        assert!(response.revert);
+        assert_eq!(response.data, ContractError::MalformedVerifyingKey.encode());
+        assert_eq!(read_record(&host, STORED_ID), None);
+    }
+
+    #[test]
+    fn test_deprecating_keys_rejects_their_proofs() {
+        let mut host = deployed();
+        register_key(&mut host, STORED_ID, 1, VERIFYING_KEY).unwrap();
+
+        for vk_id in [STORED_ID, 1] {
+            assert!(!dispatch(&mut host, &abi::encode_deprecate_key_call(vk_id), KEYS, ResultMode::Errors).revert);
+            assert_eq!(verdict(&mut host, vk_id), Verdict::Rejected(RejectReason::DeprecatedKey));
+            assert_eq!(dispatch::active_key_hash(&host, vk_id, KEYS), None);
+        }
+
+        assert_eq!(deprecate_key(&mut host, 7, None), Err(AdminError::UnknownKey));
+        assert!(dispatch(&mut host, &DEPRECATE_KEY_SELECTOR, KEYS, ResultMode::Errors).revert);
+    }
+
+    #[test]
+    fn test_rotating_a_deprecated_key_reactivates_the_id() {
+        let mut host = deployed();
+        deprecate_key(&mut host, 1, dispatch::find_key(KEYS, 1)).unwrap();
+        register_key(&mut host, 1, 2, VERIFYING_KEY).unwrap();
+
+        assert_eq!(verdict(&mut host, 1), Verdict::Valid);
+        assert_eq!(read_record(&host, 1).unwrap().circuit_version, 2);
+    }
+
+    #[test]
+    fn test_register_key_call_round_trip() {
+        let calldata = abi::encode_register_key_call(STORED_ID, 5, VERIFYING_KEY);
+        assert_eq!(calldata.len() % WORD, abi::SELECTOR_LEN);
+
+        let call = abi::decode_register_key_args(&calldata[abi::SELECTOR_LEN..]).unwrap();
+        assert_eq!(call.vk_id, STORED_ID);
+        assert_eq!(call.circuit_version, 5);
+        assert_eq!(call.verifying_key, VERIFYING_KEY);
+        assert_eq!(
+            abi::decode_register_key_args(&calldata[abi::SELECTOR_LEN..calldata.len() - WORD]),
+            Err(RejectReason::MalformedCalldata)
+        );
+    }
+}
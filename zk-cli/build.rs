@@ -0,0 +1,35 @@
+// Embed the registry contract blob built by `registry-contract/Makefile` so an
+// installed CLI can `deploy registry` without the sources. The blob comes from
+// NIET2CODE_REGISTRY_BLOB, else ../registry-contract/contract.polkavm; when
+// neither exists an empty file is embedded and `deploy` asks for --blob.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+const BLOB: &str = "registry.polkavm";
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=NIET2CODE_REGISTRY_BLOB");
+    let source = env::var("NIET2CODE_REGISTRY_BLOB")
+        .ok()
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| {
+            let manifest_dir = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set");
+            PathBuf::from(manifest_dir).join("../registry-contract/contract.polkavm")
+        });
+    // Cargo reruns the script on every build while a watched file is missing, so
+    // until the blob exists watch the directory it will appear in
+    match source.parent().filter(|_| !source.exists()) {
+        Some(dir) => println!("cargo:rerun-if-changed={}", dir.display()),
+        None => println!("cargo:rerun-if-changed={}", source.display()),
+    }
+
+    let blob = fs::read(&source).unwrap_or_default();
+    let out = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR not set")).join(BLOB);
+    // Rewriting an unchanged blob would recompile the CLI on every build
+    if fs::read(&out).ok().as_deref() != Some(blob.as_slice()) {
+        fs::write(out, blob).expect("failed to write registry blob to OUT_DIR");
+    }
+}
@@ -0,0 +1,48 @@
+// Admin calls for the verifier contract's storage-backed key registry.
+// Encodes `registerKey` / `deprecateKey` calldata for the contract owner to
+// submit (e.g. `cast send <contract> <calldata>`); nothing is sent from here.
+
+use anyhow::Result;
+use std::path::Path;
+
+use prover::workspace::Workspace;
+use prover::ProverError;
+use verifier_core::abi;
+
+pub fn handle_encode_register(
+    workspace: &Workspace,
+    vk_id: u64,
+    vk: Option<&Path>,
+    circuit_version: u32,
+    out: Option<&Path>,
+) -> Result<()> {
+    let vk_path = vk.map(Path::to_path_buf).unwrap_or_else(|| workspace.verifying_key_path());
+    let vk_bytes = std::fs::read(&vk_path).map_err(ProverError::from)?;
+
+    // The contract rejects keys that fail this check; catch it before paying for the call
+    let key = verifier_core::decode_verifying_key(&vk_bytes)
+        .map_err(|reason| ProverError::InvalidInput(format!("{}: {}", vk_path.display(), reason)))?;
+
+    println!("🔐 registerKey(vkId = {}, circuitVersion = {})", vk_id, circuit_version);
+    println!("📂 Verifying key: {} ({} bytes)", vk_path.display(), vk_bytes.len());
+    println!("🔑 Fingerprint (vkHash after registration): {}", prover::verifying_key_fingerprint(&key)?);
+    emit(&abi::encode_register_key_call(vk_id, circuit_version, &vk_bytes), out)
+}
+
+pub fn handle_encode_deprecate(vk_id: u64, out: Option<&Path>) -> Result<()> {
+    println!("⛔ deprecateKey(vkId = {})", vk_id);
+    emit(&abi::encode_deprecate_key_call(vk_id), out)
+}
+
+fn emit(calldata: &[u8], out: Option<&Path>) -> Result<()> {
+    if let Some(path) = out {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(ProverError::from)?;
+        }
+        std::fs::write(path, calldata).map_err(ProverError::from)?;
+        println!("📦 Saved calldata ({} bytes) to: {}", calldata.len(), path.display());
+    }
+    println!("0x{}", hex::encode(calldata));
+    println!("💡 Submit from the owner account, e.g.: cast send <verifier> <calldata>");
+    Ok(())
+}
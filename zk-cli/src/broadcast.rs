@@ -0,0 +1,497 @@
+// Import of Foundry broadcast artifacts. `forge script --broadcast` writes every
+// transaction it sent, and the receipts it got back, to
+// `broadcast/<script>/<chain id>/run-latest.json`; reading that file gives the
+// ledger the same record a native deployment would, and points the network
+// profile at the registry the script deployed.
+
+use anyhow::Result;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use prover::workspace::Workspace;
+use prover::ProverError;
+
+use crate::ledger::{Change, Deployment, Ledger};
+use crate::networks::Networks;
+use crate::rpc::{self, TransactionReceipt};
+use crate::wallet;
+
+/// The contract `scripts/deploy_mantle.sh` deploys as the builder registry
+pub const REGISTRY_CONTRACT: &str = "niet2codeBuilder";
+
+/// Where `forge script script/Deploy.s.sol` leaves its last run
+pub fn default_path(chain_id: u64) -> PathBuf {
+    PathBuf::from(format!("broadcast/Deploy.s.sol/{}/run-latest.json", chain_id))
+}
+
+/// The parts of `run-latest.json` the import reads
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BroadcastFile {
+    #[serde(default)]
+    transactions: Vec<BroadcastTransaction>,
+    #[serde(default)]
+    receipts: Vec<BroadcastReceipt>,
+    /// `<source>:<name>:<address>` for each linked library
+    #[serde(default)]
+    libraries: Vec<String>,
+    /// Newer Foundry versions record the chain id; older ones only the path
+    chain: Option<u64>,
+    path: Option<String>,
+    /// Seconds, or milliseconds in newer Foundry versions
+    timestamp: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BroadcastTransaction {
+    hash: Option<String>,
+    #[serde(rename = "type", alias = "transactionType")]
+    kind: Option<String>,
+    contract_name: Option<String>,
+    contract_address: Option<String>,
+    function: Option<String>,
+    #[serde(alias = "transaction")]
+    tx: TransactionFields,
+    /// Contracts created by a factory during the transaction
+    #[serde(default)]
+    additional_contracts: Vec<AdditionalContract>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct TransactionFields {
+    from: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AdditionalContract {
+    #[serde(rename = "transactionType", alias = "type")]
+    kind: Option<String>,
+    address: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BroadcastReceipt {
+    #[serde(flatten)]
+    receipt: TransactionReceipt,
+    from: Option<String>,
+}
+
+/// One transaction or created contract from a broadcast, before it gets a network
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BroadcastRecord {
+    pub tx_hash: String,
+    pub kind: String,
+    pub contract_name: Option<String>,
+    pub contract_address: Option<[u8; 20]>,
+    pub function: Option<String>,
+    pub from: Option<String>,
+    pub receipt: Option<TransactionReceipt>,
+}
+
+impl BroadcastRecord {
+    pub fn is_creation(&self) -> bool {
+        self.kind.starts_with("CREATE")
+    }
+
+    fn into_deployment(self, network: &str, chain_id: u64, source: &str, deployed_at: &str) -> Deployment {
+        Deployment {
+            network: network.to_string(),
+            chain_id,
+            tx_hash: self.tx_hash,
+            kind: self.kind,
+            contract_name: self.contract_name,
+            contract_address: self.contract_address.as_ref().map(wallet::checksum_address),
+            function: self.function,
+            from: self.from.as_deref().and_then(|from| rpc::parse_address(from).ok()).as_ref().map(wallet::checksum_address),
+            block_number: self.receipt.as_ref().map(|receipt| receipt.block_number),
+            gas_used: self.receipt.as_ref().map(|receipt| receipt.gas_used),
+            effective_gas_price: self.receipt.as_ref().and_then(|receipt| receipt.effective_gas_price),
+            succeeded: self.receipt.as_ref().map(TransactionReceipt::succeeded),
+            source: source.to_string(),
+            deployed_at: deployed_at.to_string(),
+        }
+    }
+}
+
+/// A parsed broadcast: its chain, when it ran, and every record
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Broadcast {
+    pub chain_id: Option<u64>,
+    pub timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    pub records: Vec<BroadcastRecord>,
+}
+
+impl Broadcast {
+    /// Join transactions with their receipts. Receipts without a transaction
+    /// entry (Foundry leaves out library and some creation transactions) are
+    /// kept as records of their own, named from the libraries and calls that
+    /// mention their contract address.
+    pub fn parse(content: &str, file: &Path) -> Result<Self> {
+        let broadcast: BroadcastFile = serde_json::from_str(content)
+            .map_err(|err| ProverError::InvalidInput(format!("{} is not a Foundry broadcast: {}", file.display(), err)))?;
+
+        let mut names: HashMap<[u8; 20], String> = HashMap::new();
+        for library in &broadcast.libraries {
+            let mut parts = library.rsplitn(3, ':');
+            if let (Some(address), Some(name)) = (parts.next(), parts.next()) {
+                if let Ok(address) = rpc::parse_address(address) {
+                    names.insert(address, name.to_string());
+                }
+            }
+        }
+        for tx in &broadcast.transactions {
+            if let (Some(name), Some(address)) = (&tx.contract_name, tx.contract_address.as_deref()) {
+                names.insert(rpc::parse_address(address)?, name.clone());
+            }
+        }
+
+        let mut receipts: HashMap<[u8; 32], BroadcastReceipt> =
+            broadcast.receipts.into_iter().map(|receipt| (receipt.receipt.transaction_hash, receipt)).collect();
+        let mut records = Vec::new();
+
+        for tx in broadcast.transactions {
+            // Transactions that were never sent have no hash
+            let Some(hash) = tx.hash.as_deref() else { continue };
+            let hash = parse_hash(hash)?;
+            let receipt = receipts.remove(&hash);
+            let kind = tx.kind.unwrap_or_else(|| "CALL".to_string());
+            let contract_address = match tx.contract_address.as_deref() {
+                Some(address) => Some(rpc::parse_address(address)?),
+                None => receipt.as_ref().and_then(|receipt| receipt.receipt.contract_address),
+            };
+            let from = tx.tx.from.or_else(|| receipt.as_ref().and_then(|receipt| receipt.from.clone()));
+            let receipt = receipt.map(|receipt| receipt.receipt);
+            for extra in tx.additional_contracts {
+                let address = rpc::parse_address(&extra.address)?;
+                records.push(BroadcastRecord {
+                    tx_hash: rpc::to_hex(&hash),
+                    kind: extra.kind.unwrap_or_else(|| "CREATE".to_string()),
+                    contract_name: names.get(&address).cloned(),
+                    contract_address: Some(address),
+                    function: None,
+                    from: from.clone(),
+                    receipt: receipt.clone(),
+                });
+            }
+            records.push(BroadcastRecord {
+                tx_hash: rpc::to_hex(&hash),
+                kind,
+                contract_name: tx.contract_name.or_else(|| contract_address.and_then(|address| names.get(&address).cloned())),
+                contract_address,
+                function: tx.function,
+                from,
+                receipt,
+            });
+        }
+
+        let mut unmatched: Vec<BroadcastReceipt> = receipts.into_values().collect();
+        unmatched.sort_by_key(|receipt| (receipt.receipt.block_number, receipt.receipt.transaction_hash));
+        for BroadcastReceipt { receipt, from } in unmatched {
+            let kind = if receipt.contract_address.is_some() { "CREATE" } else { "CALL" };
+            records.push(BroadcastRecord {
+                tx_hash: rpc::to_hex(&receipt.transaction_hash),
+                kind: kind.to_string(),
+                contract_name: receipt.contract_address.and_then(|address| names.get(&address).cloned()),
+                contract_address: receipt.contract_address,
+                function: None,
+                from,
+                receipt: Some(receipt),
+            });
+        }
+        records.sort_by_key(|record| record.receipt.as_ref().map_or(u64::MAX, |receipt| receipt.block_number));
+
+        let chain_id = broadcast.chain.or_else(|| chain_from_path(file)).or_else(|| broadcast.path.as_deref().and_then(|path| chain_from_path(Path::new(path))));
+        let timestamp = broadcast.timestamp.and_then(|timestamp| {
+            // Milliseconds once the value is past the year 5000 in seconds
+            let seconds = if timestamp > 100_000_000_000 { timestamp / 1000 } else { timestamp };
+            chrono::DateTime::from_timestamp(seconds as i64, 0)
+        });
+        Ok(Self { chain_id, timestamp, records })
+    }
+
+    /// The last creation of `contract_name` with a successful receipt; one
+    /// without a receipt may still be dropped
+    pub fn created(&self, contract_name: &str) -> Option<[u8; 20]> {
+        self.records
+            .iter()
+            .rev()
+            .filter(|record| record.is_creation() && record.contract_name.as_deref() == Some(contract_name))
+            .find(|record| record.receipt.as_ref().is_some_and(TransactionReceipt::succeeded))
+            .and_then(|record| record.contract_address)
+    }
+}
+
+fn parse_hash(hash: &str) -> Result<[u8; 32]> {
+    let bytes = hex::decode(hash.trim_start_matches("0x")).map_err(|_| ProverError::InvalidInput(format!("invalid transaction hash {}", hash)))?;
+    bytes.try_into().map_err(|_| ProverError::InvalidInput(format!("invalid transaction hash {}", hash)).into())
+}
+
+/// `broadcast/<script>/<chain id>/run-latest.json` names the chain in its directory
+fn chain_from_path(path: &Path) -> Option<u64> {
+    path.parent()?.file_name()?.to_str()?.parse().ok()
+}
+
+/// What an import changed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportSummary {
+    pub network: String,
+    pub chain_id: u64,
+    pub added: usize,
+    pub updated: usize,
+    pub unchanged: usize,
+    pub creations: Vec<(Option<String>, [u8; 20])>,
+    /// Creations sent without a receipt in the broadcast
+    pub pending: Vec<(Option<String>, [u8; 20])>,
+    /// The registry address the network profile now points at
+    pub registry: Option<[u8; 20]>,
+}
+
+/// Record `file` in the ledger under `network` (default: the default network),
+/// and point the profile's registry at the last `registry_contract` it created
+pub fn import(workspace: &Workspace, file: &Path, network: Option<&str>, registry_contract: &str) -> Result<ImportSummary> {
+    let mut networks = Networks::load(workspace)?;
+    let network = networks.resolve(network, None, None)?;
+    let content = std::fs::read_to_string(file)
+        .map_err(|e| ProverError::Io(std::io::Error::new(e.kind(), format!("{}: {}", file.display(), e))))?;
+    let broadcast = Broadcast::parse(&content, file)?;
+    if let Some(chain_id) = broadcast.chain_id {
+        if chain_id != network.chain_id {
+            return Err(ProverError::InvalidInput(format!(
+                "{} is a broadcast on chain {}, but {} is chain {}: pass --network",
+                file.display(),
+                chain_id,
+                network.name,
+                network.chain_id
+            ))
+            .into());
+        }
+    }
+
+    let source = file.display().to_string();
+    let deployed_at = broadcast.timestamp.unwrap_or_else(chrono::Utc::now).to_rfc3339();
+    let registry = broadcast.created(registry_contract);
+    let (creations, pending) = broadcast
+        .records
+        .iter()
+        .filter(|record| record.is_creation())
+        .filter_map(|record| record.contract_address.map(|address| (record, (record.contract_name.clone(), address))))
+        .partition::<Vec<_>, _>(|(record, _)| record.receipt.is_some());
+    let creations = creations.into_iter().map(|(_, creation)| creation).collect();
+    let pending = pending.into_iter().map(|(_, creation)| creation).collect();
+
+    let mut ledger = Ledger::load(workspace)?;
+    let (mut added, mut updated, mut unchanged) = (0, 0, 0);
+    for record in broadcast.records {
+        match ledger.record_deployment(record.into_deployment(&network.name, network.chain_id, &source, &deployed_at)) {
+            Change::Added => added += 1,
+            Change::Replaced => updated += 1,
+            Change::Unchanged => unchanged += 1,
+        }
+    }
+    ledger.save(workspace)?;
+
+    if let Some(registry) = registry {
+        networks.set_registry(&network.name, &wallet::checksum_address(&registry))?;
+        networks.save()?;
+    }
+
+    Ok(ImportSummary {
+        network: network.name,
+        chain_id: network.chain_id,
+        added,
+        updated,
+        unchanged,
+        creations,
+        pending,
+        registry,
+    })
+}
+
+pub fn handle_import_broadcast(workspace: &Workspace, file: Option<&Path>, network: Option<&str>, registry_contract: &str) -> Result<()> {
+    let file = match file {
+        Some(file) => file.to_path_buf(),
+        None => default_path(Networks::load(workspace)?.resolve(network, None, None)?.chain_id),
+    };
+    println!("📥 Importing {}", file.display());
+    let summary = import(workspace, &file, network, registry_contract)?;
+
+    println!("🌐 Network: {} (chain {})", summary.network, summary.chain_id);
+    for (name, address) in &summary.creations {
+        println!("📋 {}: {}", name.as_deref().unwrap_or("(unnamed)"), wallet::checksum_address(address));
+    }
+    for (name, address) in &summary.pending {
+        println!("⏳ {}: {} (no receipt yet)", name.as_deref().unwrap_or("(unnamed)"), wallet::checksum_address(address));
+    }
+    println!("📒 Ledger: {} new, {} updated, {} unchanged transactions", summary.added, summary.updated, summary.unchanged);
+    match summary.registry {
+        Some(registry) => println!("✅ {} registry: {}", summary.network, wallet::checksum_address(&registry)),
+        None => println!("💡 No confirmed {} creation found; the network's registry is unchanged", registry_contract),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_workspace::TempWorkspace;
+    use crate::networks::{NativeCurrency, NetworkProfile};
+    use crate::tx::FeeModel;
+
+    const FIXTURE: &str = include_str!("../../lib/forge-std/test/fixtures/broadcast.log.json");
+    const FIXTURE_PATH: &str = "broadcast/Broadcast.t.sol/31337/run-latest.json";
+
+    /// Workspace with an `anvil` profile on the fixture's chain and the fixture on disk
+    fn anvil_workspace(name: &str) -> (TempWorkspace, PathBuf) {
+        let workspace = TempWorkspace::new(&format!("broadcast-{}", name));
+        let mut networks = Networks::load(&workspace).unwrap();
+        let profile = NetworkProfile {
+            chain_id: 31337,
+            rpc_urls: vec!["http://127.0.0.1:8545".to_string()],
+            explorer: None,
+            registry: None,
+            verifier: None,
+            fee_model: FeeModel::Legacy,
+            native_currency: NativeCurrency { name: "Ether".to_string(), symbol: "ETH".to_string(), decimals: 18 },
+        };
+        networks.add("anvil", profile, false).unwrap();
+        networks.save().unwrap();
+        let file = workspace.home().join(FIXTURE_PATH);
+        std::fs::create_dir_all(file.parent().unwrap()).unwrap();
+        std::fs::write(&file, FIXTURE).unwrap();
+        (workspace, file)
+    }
+
+    /// A run whose creations were sent but have no receipts yet
+    const PENDING_RUN: &str = r#"{
+        "transactions": [{
+            "hash": "0x1111111111111111111111111111111111111111111111111111111111111111",
+            "transactionType": "CREATE",
+            "contractName": "niet2codeBuilder",
+            "contractAddress": "0x5fbdb2315678afecb367f032d93f642f64180aa3",
+            "function": null,
+            "arguments": null,
+            "transaction": {"from": "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266", "gas": "0x1"},
+            "additionalContracts": [{"transactionType": "CREATE", "address": "0xe7f1725e7734ce288f8367e1bb143e90bb3f0512", "initCode": "0x"}]
+        }, {
+            "hash": null,
+            "transactionType": "CALL",
+            "transaction": {"from": "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266"}
+        }],
+        "receipts": [],
+        "chain": 5003,
+        "timestamp": 1700000000000
+    }"#;
+
+    fn address(hex: &str) -> [u8; 20] {
+        rpc::parse_address(hex).unwrap()
+    }
+
+    #[test]
+    fn test_parse_forge_fixture() {
+        let broadcast = Broadcast::parse(FIXTURE, Path::new("run-latest.json")).unwrap();
+        assert_eq!(broadcast.chain_id, Some(31337));
+        assert_eq!(broadcast.timestamp.unwrap().timestamp(), 1655140035);
+        // Three calls with transaction entries and four receipts without
+        assert_eq!(broadcast.records.len(), 7);
+        let blocks: Vec<u64> = broadcast.records.iter().map(|record| record.receipt.as_ref().unwrap().block_number).collect();
+        assert_eq!(blocks, [1, 2, 3, 4, 5, 6, 7]);
+        assert!(broadcast.records.iter().all(|record| record.receipt.as_ref().unwrap().succeeded()));
+
+        // The library and the contract it links are named from `libraries` and the calls
+        let creations: Vec<_> = broadcast.records.iter().filter(|record| record.is_creation()).collect();
+        assert_eq!(creations.len(), 2);
+        assert_eq!(creations[0].contract_name.as_deref(), Some("F"));
+        assert_eq!(creations[0].contract_address, Some(address("0x5fbdb2315678afecb367f032d93f642f64180aa3")));
+        assert_eq!(creations[1].contract_name.as_deref(), Some("Test"));
+        assert_eq!(creations[1].receipt.as_ref().unwrap().gas_used, 0x45d80);
+
+        let call = &broadcast.records[3];
+        assert_eq!(call.tx_hash, "0xc6006863c267735a11476b7f15b15bc718e117e2da114a2be815dd651e1a509f");
+        assert_eq!(call.function.as_deref(), Some("multiple_arguments(uint256,address,uint256[]):(uint256)"));
+        assert_eq!(call.from.as_deref(), Some("0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266"));
+        assert_eq!(broadcast.created("Test"), Some(address("0xe7f1725e7734ce288f8367e1bb143e90bb3f0512")));
+        assert_eq!(broadcast.created(REGISTRY_CONTRACT), None);
+    }
+
+    #[test]
+    fn test_newer_format_fields() {
+        let broadcast = Broadcast::parse(PENDING_RUN, Path::new("run-latest.json")).unwrap();
+        assert_eq!(broadcast.chain_id, Some(5003));
+        assert_eq!(broadcast.timestamp.unwrap().timestamp(), 1_700_000_000);
+        assert_eq!(broadcast.records.len(), 2);
+        assert!(broadcast.records.iter().all(|record| record.is_creation() && record.receipt.is_none()));
+        // Pending creations don't count: the transaction may still be dropped
+        assert_eq!(broadcast.created(REGISTRY_CONTRACT), None);
+
+        let err = Broadcast::parse("{\"transactions\": 3}", Path::new("x.json")).unwrap_err();
+        assert!(matches!(err.downcast_ref::<ProverError>(), Some(ProverError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_import_records_ledger_and_registry() {
+        let (workspace, file) = anvil_workspace("import");
+        let summary = import(&workspace, &file, Some("anvil"), "Test").unwrap();
+        assert_eq!((summary.added, summary.updated, summary.unchanged), (7, 0, 0));
+        assert_eq!(summary.creations.len(), 2);
+        assert!(summary.pending.is_empty());
+        let test_contract = address("0xe7f1725e7734ce288f8367e1bb143e90bb3f0512");
+        assert_eq!(summary.registry, Some(test_contract));
+
+        let networks = Networks::load(&workspace).unwrap();
+        assert_eq!(networks.resolve(Some("anvil"), None, None).unwrap().registry, Some(test_contract));
+
+        let ledger = Ledger::load(&workspace).unwrap();
+        assert_eq!(ledger.deployments.len(), 7);
+        let creation = ledger.deployments.iter().find(|d| d.contract_name.as_deref() == Some("Test") && d.kind == "CREATE").unwrap();
+        assert_eq!(creation.network, "anvil");
+        assert_eq!(creation.tx_hash, "0x6a187183545b8a9e7f1790e847139379bf5622baff2cb43acf3f5c79470af782");
+        assert_eq!(creation.contract_address.as_deref(), Some(wallet::checksum_address(&test_contract).as_str()));
+        assert_eq!(creation.effective_gas_price, Some(0xee6b2800));
+        assert_eq!(creation.succeeded, Some(true));
+        assert_eq!(creation.deployed_at, "2022-06-13T17:07:15+00:00");
+
+        // Importing the same run again leaves it as is rather than duplicating it
+        let again = import(&workspace, &file, Some("anvil"), "Test").unwrap();
+        assert_eq!((again.added, again.updated, again.unchanged), (0, 0, 7));
+        assert_eq!(Ledger::load(&workspace).unwrap().deployments.len(), 7);
+
+        // A copy of the run at another path replaces the entries' source
+        let copy = workspace.home().join("run-copy.json");
+        std::fs::copy(&file, &copy).unwrap();
+        let moved = import(&workspace, &copy, Some("anvil"), "Test").unwrap();
+        assert_eq!((moved.added, moved.updated, moved.unchanged), (0, 7, 0));
+        assert_eq!(Ledger::load(&workspace).unwrap().deployments.len(), 7);
+    }
+
+    #[test]
+    fn test_import_checks_chain() {
+        let (workspace, file) = anvil_workspace("chain");
+        let err = import(&workspace, &file, Some("mantle-testnet"), "Test").unwrap_err();
+        assert!(matches!(err.downcast_ref::<ProverError>(), Some(ProverError::InvalidInput(_))));
+        assert!(Ledger::load(&workspace).unwrap().deployments.is_empty());
+
+        // Without the registry contract the profile is left alone
+        let summary = import(&workspace, &file, Some("anvil"), REGISTRY_CONTRACT).unwrap();
+        assert_eq!(summary.registry, None);
+        assert_eq!(Networks::load(&workspace).unwrap().resolve(Some("anvil"), None, None).unwrap().registry, None);
+    }
+
+    #[test]
+    fn test_import_reports_pending_creations() {
+        let workspace = TempWorkspace::new("broadcast-pending");
+        let file = workspace.home().join("broadcast/Deploy.s.sol/5003/run-latest.json");
+        std::fs::create_dir_all(file.parent().unwrap()).unwrap();
+        std::fs::write(&file, PENDING_RUN).unwrap();
+        let before = Networks::load(&workspace).unwrap().resolve(Some("mantle-testnet"), None, None).unwrap().registry;
+
+        let summary = import(&workspace, &file, Some("mantle-testnet"), REGISTRY_CONTRACT).unwrap();
+        assert!(summary.creations.is_empty());
+        assert_eq!(summary.pending.len(), 2);
+        assert_eq!(summary.pending[1], (Some(REGISTRY_CONTRACT.to_string()), address("0x5fbdb2315678afecb367f032d93f642f64180aa3")));
+        assert_eq!(summary.registry, None);
+        assert_eq!(Networks::load(&workspace).unwrap().resolve(Some("mantle-testnet"), None, None).unwrap().registry, before);
+    }
+}
@@ -0,0 +1,351 @@
+// `dashboard`: the builder's counters as the registry keeps them
+// (`getBuilderStats`, `getPlatformStats`) next to the same counts computed from
+// the local ledger (`crate::ledger`), flagging where the two disagree. The last
+// fetch per network and address is cached in `<home>/dashboard_cache.json` so
+// the dashboard still shows something offline.
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use prover::workspace::Workspace;
+use prover::ProverError;
+use verifier_core::abi::{self, WORD};
+
+use crate::ledger::Ledger;
+use crate::profile::Profile;
+use crate::networks::{self, Network};
+use crate::rpc::{self, CallRequest, RpcClient};
+use crate::wallet::{self, WalletStore};
+
+/// keccak256("getBuilderStats(address)")[..4]
+pub const GET_BUILDER_STATS_SELECTOR: [u8; 4] = [0xd5, 0x0a, 0x59, 0x50];
+/// keccak256("getPlatformStats()")[..4]
+pub const GET_PLATFORM_STATS_SELECTOR: [u8; 4] = [0x13, 0x6d, 0x88, 0x83];
+
+const CACHE_FILE: &str = "dashboard_cache.json";
+
+/// `getBuilderStats(address)`; all zero for an address that never registered
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OnChainBuilderStats {
+    pub alias: String,
+    pub proofs_verified: u64,
+    pub contracts_deployed: u64,
+    pub privacy_score: u64,
+    pub joined_at: u64,
+}
+
+impl OnChainBuilderStats {
+    /// Decode `(string aliasName, uint256 proofsVerified, uint256 contractsDeployed,
+    /// uint256 privacyScore, uint256 joinedAt)`
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        Some(Self {
+            alias: String::from_utf8(abi::read_bytes(data, 0).ok()?.to_vec()).ok()?,
+            proofs_verified: abi::read_u64(data, WORD)?,
+            contracts_deployed: abi::read_u64(data, 2 * WORD)?,
+            privacy_score: abi::read_u64(data, 3 * WORD)?,
+            joined_at: abi::read_u64(data, 4 * WORD)?,
+        })
+    }
+
+    pub fn registered(&self) -> bool {
+        self.joined_at != 0
+    }
+}
+
+/// `getPlatformStats()`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlatformStats {
+    pub total_builders: u64,
+    pub total_proofs_verified: u64,
+    pub total_gas_saved: u64,
+}
+
+impl PlatformStats {
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        if data.len() != 3 * WORD {
+            return None;
+        }
+        Some(Self {
+            total_builders: abi::read_u64(data, 0)?,
+            total_proofs_verified: abi::read_u64(data, WORD)?,
+            total_gas_saved: abi::read_u64(data, 2 * WORD)?,
+        })
+    }
+}
+
+/// One fetch of both getters, as cached
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub network: String,
+    pub registry: String,
+    pub address: String,
+    pub fetched_at: String,
+    pub builder: OnChainBuilderStats,
+    pub platform: PlatformStats,
+}
+
+pub fn encode_get_builder_stats(address: &[u8; 20]) -> Vec<u8> {
+    let mut calldata = GET_BUILDER_STATS_SELECTOR.to_vec();
+    calldata.extend_from_slice(&abi::encode_address(address));
+    calldata
+}
+
+/// Call both getters on the network's registry
+pub async fn fetch(rpc: &RpcClient, network: &Network, address: &[u8; 20]) -> Result<Snapshot> {
+    let registry = network.registry()?;
+    let data = rpc.call(&CallRequest::new(registry, encode_get_builder_stats(address))).await?;
+    let builder = OnChainBuilderStats::decode(&data).ok_or_else(|| anyhow!("getBuilderStats returned {}", rpc::to_hex(&data)))?;
+    let data = rpc.call(&CallRequest::new(registry, GET_PLATFORM_STATS_SELECTOR.to_vec())).await?;
+    let platform = PlatformStats::decode(&data).ok_or_else(|| anyhow!("getPlatformStats returned {}", rpc::to_hex(&data)))?;
+    Ok(Snapshot {
+        network: network.name.clone(),
+        registry: wallet::checksum_address(&registry),
+        address: wallet::checksum_address(address),
+        fetched_at: chrono::Utc::now().to_rfc3339(),
+        builder,
+        platform,
+    })
+}
+
+/// Last snapshots, one per network and address
+pub fn load_cache(path: &Path) -> Vec<Snapshot> {
+    std::fs::read_to_string(path).ok().and_then(|content| serde_json::from_str(&content).ok()).unwrap_or_default()
+}
+
+pub fn cached(cache: &[Snapshot], network: &str, address: &[u8; 20]) -> Option<Snapshot> {
+    let address = wallet::checksum_address(address);
+    cache.iter().find(|snapshot| snapshot.network == network && snapshot.address == address).cloned()
+}
+
+/// Replace the snapshot for the same network and address
+pub fn store_cache(path: &Path, snapshot: &Snapshot) -> Result<()> {
+    let mut cache = load_cache(path);
+    cache.retain(|cached| cached.network != snapshot.network || cached.address != snapshot.address);
+    cache.push(snapshot.clone());
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(ProverError::Io)?;
+    }
+    std::fs::write(path, serde_json::to_string_pretty(&cache)?).map_err(ProverError::Io)?;
+    Ok(())
+}
+
+/// ✅ when both sides agree, ⚠️ when they differ, blank without a local value
+pub fn discrepancy<T: PartialEq>(on_chain: &T, local: Option<&T>) -> &'static str {
+    match local {
+        Some(local) if local == on_chain => "✅",
+        Some(_) => "⚠️ ",
+        None => "",
+    }
+}
+
+/// `--address`, else the profile's registered address, else the wallet's
+fn resolve_address(workspace: &Workspace, address: Option<&str>, profile: &Profile, wallet_name: &str) -> Result<[u8; 20]> {
+    if let Some(address) = address.or(profile.address.as_deref()) {
+        return rpc::parse_address(address);
+    }
+    WalletStore::open(workspace).load(wallet_name)?.address().ok_or_else(|| {
+        ProverError::InvalidInput(format!("wallet {:?} has no address: pass --address", wallet_name)).into()
+    })
+}
+
+pub async fn handle_dashboard(
+    workspace: &Workspace,
+    address: Option<&str>,
+    network: Option<&str>,
+    rpc_url: Option<&str>,
+    registry: Option<&str>,
+    wallet_name: &str,
+    offline: bool,
+) -> Result<()> {
+    let profile = Profile::load(workspace)?;
+    let network = networks::resolve(workspace, network, rpc_url, registry)?;
+    let address = resolve_address(workspace, address, &profile, wallet_name)?;
+    let cache_path = workspace.state_file(CACHE_FILE);
+
+    let snapshot = if offline {
+        None
+    } else {
+        match fetch(&RpcClient::new(&network.rpc_url), &network, &address).await {
+            Ok(snapshot) => {
+                store_cache(&cache_path, &snapshot)?;
+                Some((snapshot, false))
+            }
+            Err(err) => {
+                println!("⚠️  Could not reach {}: {}", network.rpc_url, err);
+                None
+            }
+        }
+    };
+    let snapshot = match snapshot {
+        Some(snapshot) => Some(snapshot),
+        None => cached(&load_cache(&cache_path), &network.name, &address).map(|snapshot| (snapshot, true)),
+    };
+
+    // Count what the registry the on-chain figures came from counts
+    let registry = match &snapshot {
+        Some((snapshot, _)) => snapshot.registry.clone(),
+        None => network.registry.as_ref().map(wallet::checksum_address).unwrap_or_default(),
+    };
+    let stats = Ledger::load(workspace)?.stats(&network.name, &registry, &wallet::checksum_address(&address));
+    println!("🔮 niet2code Builder Dashboard");
+    println!("========================");
+    println!("🌐 {} · builder {}", network.name, wallet::checksum_address(&address));
+    let Some((snapshot, from_cache)) = snapshot else {
+        println!("📭 No on-chain stats fetched yet; local counters only");
+        println!("🏗️  Deployments: {}", stats.contracts_deployed);
+        println!("🔍 Proofs Generated: {}", stats.proofs_generated);
+        println!("========================");
+        return Ok(());
+    };
+    if from_cache {
+        println!("🗄️  Cached from {} at {}", snapshot.registry, snapshot.fetched_at);
+    } else {
+        println!("📋 Registry {}", snapshot.registry);
+    }
+
+    let builder = &snapshot.builder;
+    if !builder.registered() {
+        println!("📭 Not registered on this registry: run `register --network {}`", network.name);
+    }
+    let local_alias = profile.alias.clone();
+    let local_submitted = stats.proofs_submitted;
+    let local_deployments = stats.contracts_deployed;
+    println!("{:<22} {:>12} {:>12}", "", "on-chain", "local");
+    println!(
+        "{:<22} {:>12} {:>12} {}",
+        "👤 Alias",
+        builder.alias,
+        local_alias.as_deref().unwrap_or("-"),
+        discrepancy(&builder.alias, local_alias.as_ref())
+    );
+    println!(
+        "{:<22} {:>12} {:>12} {}",
+        "🔍 Proofs verified",
+        builder.proofs_verified,
+        local_submitted,
+        discrepancy(&builder.proofs_verified, Some(&local_submitted))
+    );
+    println!(
+        "{:<22} {:>12} {:>12} {}",
+        "🏗️  Contracts deployed",
+        builder.contracts_deployed,
+        local_deployments,
+        discrepancy(&builder.contracts_deployed, Some(&local_deployments))
+    );
+    println!("{:<22} {:>12}", "🔒 Privacy score", format!("{}/100", builder.privacy_score));
+    if builder.registered() {
+        let joined = chrono::DateTime::from_timestamp(builder.joined_at as i64, 0).map_or_else(|| builder.joined_at.to_string(), |time| time.to_rfc3339());
+        println!("{:<22} {:>12}", "📅 Joined", joined);
+    }
+    println!("🔍 Proofs generated here: {}", stats.proofs_generated);
+    println!("------------------------");
+    let platform = &snapshot.platform;
+    println!("🌍 Platform: {} builders · {} proofs verified · {} gas saved", platform.total_builders, platform.total_proofs_verified, platform.total_gas_saved);
+    println!("========================");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rpc::test_node::{self, NodeError};
+    use serde_json::{json, Value};
+
+    const REGISTRY: [u8; 20] = [0x79; 20];
+    const BUILDER: [u8; 20] = [0x11; 20];
+
+    fn network(url: &str) -> Network {
+        Network::local(url, Some(REGISTRY))
+    }
+
+    /// What `registry-contract` returns from `getBuilderStats`
+    fn builder_stats_output(alias: &str, counters: [u64; 4]) -> Vec<u8> {
+        let mut data = abi::uint_word(5 * WORD as u64).to_vec();
+        for counter in counters {
+            data.extend_from_slice(&abi::uint_word(counter));
+        }
+        data.extend_from_slice(&abi::encode_bytes_tail(alias.as_bytes()));
+        data
+    }
+
+    fn registry_node(params_seen: impl Fn(&Value) + Send + Sync + 'static) -> impl Fn(&str, &Value) -> Result<Value, NodeError> {
+        move |method, params| {
+            if method != "eth_call" {
+                return Err(NodeError::method_not_found());
+            }
+            params_seen(params);
+            let data = hex::decode(params[0]["data"].as_str().unwrap().trim_start_matches("0x")).unwrap();
+            match data[..4].try_into().unwrap() {
+                GET_BUILDER_STATS_SELECTOR => Ok(json!(rpc::to_hex(&builder_stats_output("anon", [3, 1, 55, 1_700_000_000])))),
+                GET_PLATFORM_STATS_SELECTOR => Ok(json!(rpc::to_hex(&[abi::uint_word(7), abi::uint_word(40), abi::uint_word(3_000_000)].concat()))),
+                _ => Err(NodeError::revert(&[])),
+            }
+        }
+    }
+
+    #[test]
+    fn test_selectors_match_signatures() {
+        assert_eq!(GET_BUILDER_STATS_SELECTOR, crate::tx::keccak256(b"getBuilderStats(address)")[..4]);
+        assert_eq!(GET_PLATFORM_STATS_SELECTOR, crate::tx::keccak256(b"getPlatformStats()")[..4]);
+    }
+
+    #[test]
+    fn test_decode_getter_outputs() {
+        let stats = OnChainBuilderStats::decode(&builder_stats_output("anon", [3, 1, 55, 9])).unwrap();
+        assert_eq!(
+            stats,
+            OnChainBuilderStats {
+                alias: "anon".to_string(),
+                proofs_verified: 3,
+                contracts_deployed: 1,
+                privacy_score: 55,
+                joined_at: 9,
+            }
+        );
+        let unregistered = OnChainBuilderStats::decode(&builder_stats_output("", [0; 4])).unwrap();
+        assert!(!unregistered.registered());
+        assert_eq!(OnChainBuilderStats::decode(&[0u8; WORD]), None);
+        assert_eq!(PlatformStats::decode(&[0u8; 2 * WORD]), None);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_queries_registry_for_address() {
+        let node = test_node::spawn(registry_node(|params| assert_eq!(params[0]["to"], rpc::to_hex(&REGISTRY)))).await;
+        let snapshot = fetch(&RpcClient::new(&node.url), &network(&node.url), &BUILDER).await.unwrap();
+        assert_eq!(snapshot.builder.proofs_verified, 3);
+        assert_eq!(snapshot.platform.total_builders, 7);
+        assert_eq!(snapshot.platform.total_gas_saved, 3_000_000);
+        assert_eq!(snapshot.address, wallet::checksum_address(&BUILDER));
+
+        let calls = node.calls();
+        assert_eq!(calls[0].1[0]["data"], rpc::to_hex(&encode_get_builder_stats(&BUILDER)));
+    }
+
+    #[tokio::test]
+    async fn test_cache_keeps_last_fetch_per_network_and_address() {
+        let node = test_node::spawn(registry_node(|_| ())).await;
+        let snapshot = fetch(&RpcClient::new(&node.url), &network(&node.url), &BUILDER).await.unwrap();
+
+        let path = std::env::temp_dir().join(format!("niet2code-dashboard-{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        store_cache(&path, &snapshot).unwrap();
+        let mut newer = snapshot.clone();
+        newer.builder.proofs_verified = 4;
+        store_cache(&path, &newer).unwrap();
+
+        let cache = load_cache(&path);
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cached(&cache, "local", &BUILDER), Some(newer));
+        assert_eq!(cached(&cache, "mantle-mainnet", &BUILDER), None);
+        assert_eq!(cached(&cache, "local", &[0x22; 20]), None);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_discrepancy_indicator() {
+        assert_eq!(discrepancy(&3u64, Some(&3)), "✅");
+        assert_eq!(discrepancy(&3u64, Some(&2)), "⚠️ ");
+        assert_eq!(discrepancy(&3u64, None), "");
+    }
+}
@@ -0,0 +1,495 @@
+// `deploy registry|verifier`: the CLI's replacement for `scripts/deploy_mantle.sh`.
+// It checks the node's chain id and the wallet's balance, sends the creation
+// transaction, waits for confirmations, smoke-tests the new contract with a view
+// call and points the network profile at it.
+//
+// The code deployed is a PolkaVM blob: the registry blob embedded by `build.rs`
+// (or `--blob`), and the project's verifier from `verifier build`, since the
+// verifier compiles in the project's keys. A Foundry artifact (`out/<file>/<name>.json`)
+// is also accepted, so the Solidity registry can be deployed to an EVM dev node
+// such as anvil. The ABIs of both contracts ship in `zk-cli/artifacts`.
+
+use anyhow::{bail, Context, Result};
+use std::path::Path;
+use std::time::Duration;
+
+use prover::workspace::Workspace;
+use prover::ProverError;
+use verifier_core::abi::{self, OWNER_SELECTOR, WORD};
+
+use crate::broadcast::REGISTRY_CONTRACT;
+use crate::ledger::{Deployment, Ledger};
+use crate::networks::{Network, Networks};
+use crate::registry::{RECEIPT_POLL, RECEIPT_TIMEOUT};
+use crate::rpc::{self, CallRequest, RpcClient, TransactionReceipt};
+use crate::tx;
+use crate::wallet::{self, Wallet, WalletStore};
+
+pub const REGISTRY_ABI: &str = include_str!("../artifacts/registry.abi.json");
+pub const VERIFIER_ABI: &str = include_str!("../artifacts/verifier.abi.json");
+
+/// `registry-contract/contract.polkavm` as of the CLI build; empty if it was not built
+const EMBEDDED_REGISTRY: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/registry.polkavm"));
+
+/// keccak256("getContractInfo()")[..4]
+const GET_CONTRACT_INFO_SELECTOR: [u8; 4] = [0x7c, 0xc1, 0xf8, 0x67];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Contract {
+    Registry,
+    Verifier,
+}
+
+impl Contract {
+    /// Contract name in the ledger
+    pub fn name(&self) -> &'static str {
+        match self {
+            Contract::Registry => REGISTRY_CONTRACT,
+            Contract::Verifier => "verifier-contract",
+        }
+    }
+
+    pub fn abi(&self) -> &'static str {
+        match self {
+            Contract::Registry => REGISTRY_ABI,
+            Contract::Verifier => VERIFIER_ABI,
+        }
+    }
+}
+
+/// Creation code and the VM it is for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Code {
+    /// Constructor arguments are appended to the blob
+    PolkaVm(Vec<u8>),
+    /// Bytecode of a Foundry artifact; the Solidity registry takes no arguments
+    Evm(Vec<u8>),
+}
+
+impl Code {
+    /// A Foundry artifact (`.json`) or a raw PolkaVM blob
+    pub fn read(path: &Path) -> Result<Self> {
+        let content = std::fs::read(path)
+            .map_err(ProverError::from)
+            .with_context(|| format!("cannot read {}", path.display()))?;
+        if path.extension().is_some_and(|ext| ext == "json") {
+            let artifact: serde_json::Value = serde_json::from_slice(&content)
+                .map_err(|err| ProverError::InvalidInput(format!("{}: {}", path.display(), err)))?;
+            let bytecode = artifact["bytecode"]["object"]
+                .as_str()
+                .or_else(|| artifact["bytecode"].as_str())
+                .ok_or_else(|| ProverError::InvalidInput(format!("{} has no bytecode", path.display())))?;
+            let bytecode = hex::decode(bytecode.trim_start_matches("0x"))
+                .map_err(|_| ProverError::InvalidInput(format!("{}: bytecode is not hex (unlinked libraries?)", path.display())))?;
+            return Ok(Code::Evm(bytecode));
+        }
+        Ok(Code::PolkaVm(content))
+    }
+
+    /// `--blob`, else the embedded registry or the project's verifier build
+    pub fn load(contract: Contract, workspace: &Workspace, blob: Option<&Path>) -> Result<Self> {
+        match (blob, contract) {
+            (Some(path), _) => Self::read(path),
+            (None, Contract::Registry) if !EMBEDDED_REGISTRY.is_empty() => Ok(Code::PolkaVm(EMBEDDED_REGISTRY.to_vec())),
+            (None, Contract::Registry) => Err(ProverError::InvalidInput(
+                "this CLI was built without the registry blob: run `make` in registry-contract and rebuild, or pass --blob".to_string(),
+            )
+            .into()),
+            (None, Contract::Verifier) => {
+                let path = workspace.verifier_blob_path();
+                Self::read(&path).with_context(|| "run `verifier build` or pass --blob".to_string())
+            }
+        }
+    }
+
+    pub fn init_code(&self, constructor_args: &[u8]) -> Vec<u8> {
+        match self {
+            Code::PolkaVm(blob) => [blob.as_slice(), constructor_args].concat(),
+            Code::Evm(bytecode) => bytecode.clone(),
+        }
+    }
+}
+
+/// The registry's constructor `(address verifier, uint256 circuitId)`
+pub fn encode_registry_constructor(verifier: &[u8; 20], circuit_id: u64) -> Vec<u8> {
+    [abi::encode_address(verifier), abi::uint_word(circuit_id)].concat()
+}
+
+/// Check the node's chain and the wallet's balance, send `init_code` as a
+/// contract creation and wait for its receipt and `confirmations` blocks
+pub async fn deploy_contract(
+    rpc: &RpcClient,
+    wallet: &Wallet,
+    network: &Network,
+    init_code: Vec<u8>,
+    confirmations: u64,
+    poll: Duration,
+) -> Result<([u8; 20], TransactionReceipt)> {
+    let chain_id = rpc.chain_id().await?;
+    if chain_id != network.chain_id {
+        return Err(ProverError::InvalidInput(format!(
+            "{} is on chain {}, but {} is chain {}; refusing to deploy",
+            rpc.url(),
+            chain_id,
+            network.name,
+            network.chain_id
+        ))
+        .into());
+    }
+
+    let transaction = tx::prepare_transaction(rpc, wallet, network.chain_id, network.fee_model, None, init_code).await?;
+    let cost = transaction.max_cost();
+    let balance = rpc.balance(&wallet.address()).await?;
+    let currency = &network.native_currency;
+    println!("💰 Balance: {} (deployment: {} gas, up to {})", currency.format(balance), transaction.gas_limit, currency.format(cost));
+    if balance < cost {
+        return Err(ProverError::InvalidInput(format!(
+            "insufficient balance: {} has {}, the deployment may cost {}",
+            wallet::checksum_address(&wallet.address()),
+            currency.format(balance),
+            currency.format(cost)
+        ))
+        .into());
+    }
+
+    let hash = tx::sign_and_send(rpc, wallet, &transaction).await?;
+    println!("📨 Sent {}", rpc::to_hex(&hash));
+    let receipt = rpc.wait_for_receipt(&hash, poll, RECEIPT_TIMEOUT).await?;
+    if !receipt.succeeded() {
+        bail!("deployment reverted in block {} (tx {})", receipt.block_number, rpc::to_hex(&hash));
+    }
+    let Some(address) = receipt.contract_address else {
+        bail!("receipt of {} has no contract address", rpc::to_hex(&hash));
+    };
+    if confirmations > 1 {
+        println!("⏳ Waiting for {} confirmations...", confirmations);
+    }
+    rpc.wait_for_confirmations(receipt.block_number, confirmations, poll, RECEIPT_TIMEOUT).await?;
+    Ok((address, receipt))
+}
+
+/// Call the new contract the way its users will: `getContractInfo()` on the
+/// registry, `owner()` on the verifier. Returns what it answered.
+pub async fn smoke_test(rpc: &RpcClient, contract: Contract, address: &[u8; 20], deployer: &[u8; 20]) -> Result<String> {
+    match contract {
+        Contract::Registry => {
+            let ret = rpc.call(&CallRequest::new(*address, GET_CONTRACT_INFO_SELECTOR.to_vec())).await?;
+            let field = |index: usize| abi::read_bytes(&ret, index * WORD).ok().map(|bytes| String::from_utf8_lossy(bytes).into_owned());
+            match (field(0), field(1), field(2)) {
+                (Some(name), Some(version), Some(description)) if name == REGISTRY_CONTRACT => {
+                    Ok(format!("getContractInfo(): {} v{} ({})", name, version, description))
+                }
+                _ => bail!("getContractInfo() at {} did not return the niet2code registry's info", wallet::checksum_address(address)),
+            }
+        }
+        Contract::Verifier => {
+            let ret = rpc.call(&CallRequest::new(*address, OWNER_SELECTOR.to_vec())).await?;
+            let owner: Option<[u8; 20]> = ret.get(WORD - 20..WORD).and_then(|bytes| bytes.try_into().ok());
+            match owner {
+                Some(owner) if owner == *deployer => Ok(format!("owner(): {}", wallet::checksum_address(&owner))),
+                _ => bail!("owner() at {} is not the deploying wallet", wallet::checksum_address(address)),
+            }
+        }
+    }
+}
+
+/// Where `record` writes a contract's ABI for frontends and `cast`
+pub fn abi_path(workspace: &Workspace, contract: Contract) -> std::path::PathBuf {
+    workspace.state_file(&format!("abi/{}.json", contract.name()))
+}
+
+/// Point the network profile at the new contract, add it to the ledger and
+/// write its ABI next to them
+pub fn record(workspace: &Workspace, network: &Network, contract: Contract, address: &[u8; 20], receipt: &TransactionReceipt, deployer: &[u8; 20]) -> Result<()> {
+    let checksummed = wallet::checksum_address(address);
+    let mut networks = Networks::load(workspace)?;
+    match contract {
+        Contract::Registry => networks.set_registry(&network.name, &checksummed)?,
+        Contract::Verifier => networks.set_verifier(&network.name, &checksummed)?,
+    }
+    networks.save()?;
+
+    let mut ledger = Ledger::load(workspace)?;
+    ledger.record_deployment(Deployment {
+        network: network.name.clone(),
+        chain_id: network.chain_id,
+        tx_hash: rpc::to_hex(&receipt.transaction_hash),
+        kind: "CREATE".to_string(),
+        contract_name: Some(contract.name().to_string()),
+        contract_address: Some(checksummed),
+        function: None,
+        from: Some(wallet::checksum_address(deployer)),
+        block_number: Some(receipt.block_number),
+        gas_used: Some(receipt.gas_used),
+        effective_gas_price: receipt.effective_gas_price,
+        succeeded: Some(receipt.succeeded()),
+        source: "deploy".to_string(),
+        deployed_at: chrono::Utc::now().to_rfc3339(),
+    });
+    ledger.save(workspace)?;
+
+    let abi_path = abi_path(workspace, contract);
+    if let Some(dir) = abi_path.parent() {
+        std::fs::create_dir_all(dir).map_err(ProverError::Io)?;
+    }
+    std::fs::write(&abi_path, contract.abi()).map_err(ProverError::Io)?;
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn handle_deploy(
+    workspace: &Workspace,
+    contract: Contract,
+    network: Option<&str>,
+    rpc_url: Option<&str>,
+    blob: Option<&Path>,
+    verifier: Option<&str>,
+    circuit_id: u64,
+    confirmations: u64,
+    wallet_name: &str,
+    passphrase_file: Option<&Path>,
+) -> Result<()> {
+    let network = crate::networks::resolve(workspace, network, rpc_url, None)?;
+    let code = Code::load(contract, workspace, blob)?;
+    let rpc = RpcClient::new(&network.rpc_url);
+
+    let constructor_args = match (&code, contract) {
+        (Code::PolkaVm(_), Contract::Registry) => {
+            let verifier = match verifier {
+                Some(verifier) => rpc::parse_address(verifier)?,
+                None => network.verifier.ok_or_else(|| {
+                    ProverError::InvalidInput(format!("no verifier on {} for the registry to call: pass --verifier or run `deploy verifier` first", network.name))
+                })?,
+            };
+            println!("🔗 Verifier: {} (circuit {})", wallet::checksum_address(&verifier), circuit_id);
+            if rpc.get_code(&rpc::to_hex(&verifier)).await?.is_empty() {
+                return Err(ProverError::InvalidInput(format!("no contract deployed at verifier {}", wallet::checksum_address(&verifier))).into());
+            }
+            encode_registry_constructor(&verifier, circuit_id)
+        }
+        _ => Vec::new(),
+    };
+
+    println!("🚀 Deploying {} to {} (chain {})", contract.name(), network.name, network.chain_id);
+    let wallet = WalletStore::open(workspace).unlock(wallet_name, passphrase_file)?;
+    println!("💼 Wallet '{}': {}", wallet_name, wallet::checksum_address(&wallet.address()));
+
+    let (address, receipt) = deploy_contract(&rpc, &wallet, &network, code.init_code(&constructor_args), confirmations, RECEIPT_POLL).await?;
+    let checksummed = wallet::checksum_address(&address);
+    println!("✅ Deployed at {} (block {}, gas used {})", checksummed, receipt.block_number, receipt.gas_used);
+    if let Some(url) = network.explorer_url("address", &checksummed) {
+        println!("🔍 Explorer: {}", url);
+    }
+
+    // Record before the smoke test: the contract is deployed and paid for either way
+    record(workspace, &network, contract, &address, &receipt, &wallet.address())?;
+    println!("🌐 {} now uses this {}", network.name, if contract == Contract::Registry { "registry" } else { "verifier" });
+    println!("📄 ABI: {}", abi_path(workspace, contract).display());
+
+    match smoke_test(&rpc, contract, &address, &wallet.address()).await {
+        Ok(summary) => println!("🧪 {}", summary),
+        Err(err) => bail!("{} at {} is recorded in the ledger, but its smoke test failed: {}", contract.name(), checksummed, err),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_workspace::TempWorkspace;
+    use crate::registry::{REGISTER_BUILDER_SELECTOR, VERIFY_PROOF_SELECTOR};
+    use crate::rpc::test_node::{self, NodeError};
+    use serde_json::{json, Value};
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Arc;
+
+    const KEY: [u8; 32] = [0x46; 32];
+    const CREATED: [u8; 20] = [0xc0; 20];
+
+    fn contract_info(name: &str) -> Vec<u8> {
+        let strings = [name, "1.0.0", "Anonymous Smart Contract Verification"].map(|value| abi::encode_bytes_tail(value.as_bytes()));
+        let mut offset = 3 * WORD;
+        let mut out = Vec::new();
+        for string in &strings {
+            out.extend_from_slice(&abi::uint_word(offset as u64));
+            offset += string.len();
+        }
+        out.extend(strings.concat());
+        out
+    }
+
+    /// Dev node that mines creations at block 0x10 and adds a block per `eth_blockNumber`
+    fn dev_node(balance: u128, call_result: Vec<u8>) -> impl Fn(&str, &Value) -> Result<Value, NodeError> {
+        let head = Arc::new(AtomicU64::new(0x10));
+        let receipt = json!({
+            "blockNumber": "0x10",
+            "status": "0x1",
+            "gasUsed": "0x15f90",
+            "effectiveGasPrice": "0x3b9aca00",
+            "contractAddress": rpc::to_hex(&CREATED),
+        });
+        test_node::mining(receipt, json!([]), move |method, _| match method {
+            "eth_chainId" => Ok(json!("0x138b")),
+            "eth_getBalance" => Ok(json!(rpc::to_quantity(balance))),
+            "eth_estimateGas" => Ok(json!("0x186a0")),
+            "eth_gasPrice" => Ok(json!("0x3b9aca00")),
+            "eth_maxPriorityFeePerGas" => Err(NodeError::method_not_found()),
+            "eth_getTransactionCount" => Ok(json!("0x0")),
+            "eth_blockNumber" => Ok(json!(rpc::to_quantity(head.fetch_add(1, Ordering::SeqCst).into()))),
+            "eth_call" => Ok(json!(rpc::to_hex(&call_result))),
+            _ => Err(NodeError::method_not_found()),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_deploy_registry_waits_for_confirmations() {
+        let wallet = Wallet::from_private_key(&KEY).unwrap();
+        let node = test_node::spawn(dev_node(10u128.pow(18), contract_info(REGISTRY_CONTRACT))).await;
+        let rpc = RpcClient::new(&node.url);
+        let network = Network::local(&node.url, None);
+
+        let init_code = Code::PolkaVm(b"PVM\0blob".to_vec()).init_code(&encode_registry_constructor(&[0x5e; 20], 1));
+        let (address, receipt) = deploy_contract(&rpc, &wallet, &network, init_code.clone(), 3, Duration::ZERO).await.unwrap();
+        assert_eq!(address, CREATED);
+        assert_eq!(receipt.block_number, 0x10);
+
+        let calls = node.calls();
+        // Blocks 0x10, 0x11, 0x12: the receipt's block and two on top
+        assert_eq!(calls.iter().filter(|(method, _)| method == "eth_blockNumber").count(), 3);
+        let estimate = calls.iter().find(|(method, _)| method == "eth_estimateGas").unwrap();
+        assert!(estimate.1[0].get("to").is_none());
+        let sent = calls.iter().find(|(method, _)| method == "eth_sendRawTransaction").unwrap();
+        let raw = hex::decode(sent.1[0].as_str().unwrap().trim_start_matches("0x")).unwrap();
+        // Legacy, since the node suggests no priority fee; the blob carries the constructor arguments
+        assert!(raw[0] >= 0xc0);
+        assert!(raw.windows(init_code.len()).any(|window| window == init_code));
+
+        let info = smoke_test(&rpc, Contract::Registry, &address, &wallet.address()).await.unwrap();
+        assert!(info.contains("niet2codeBuilder v1.0.0"), "{}", info);
+    }
+
+    #[tokio::test]
+    async fn test_deploy_checks_balance_and_chain() {
+        let wallet = Wallet::from_private_key(&KEY).unwrap();
+        // 100_000 gas at 1 gwei needs 0.0001 MNT
+        let node = test_node::spawn(dev_node(10u128.pow(13), Vec::new())).await;
+        let err = deploy_contract(&RpcClient::new(&node.url), &wallet, &Network::local(&node.url, None), vec![0x60], 1, Duration::ZERO)
+            .await
+            .unwrap_err();
+        assert!(matches!(err.downcast_ref::<ProverError>(), Some(ProverError::InvalidInput(_))), "{}", err);
+        assert!(err.to_string().contains("insufficient balance"), "{}", err);
+        assert!(node.calls().iter().all(|(method, _)| method != "eth_sendRawTransaction"));
+
+        let node = test_node::spawn(dev_node(10u128.pow(18), Vec::new())).await;
+        let mut network = Network::local(&node.url, None);
+        network.chain_id = 31337;
+        let err = deploy_contract(&RpcClient::new(&node.url), &wallet, &network, vec![0x60], 1, Duration::ZERO).await.unwrap_err();
+        assert!(err.to_string().contains("refusing to deploy"), "{}", err);
+        assert!(node.calls().iter().all(|(method, _)| method != "eth_sendRawTransaction"));
+    }
+
+    #[tokio::test]
+    async fn test_failed_smoke_test_keeps_the_deployment() {
+        let workspace = TempWorkspace::new("deploy-smoke");
+        let wallet = Wallet::from_private_key(&KEY).unwrap();
+        WalletStore::open(&workspace).save("deployer", &wallet.encrypt("hunter2").unwrap()).unwrap();
+        let passphrase = workspace.home().join("passphrase");
+        std::fs::write(&passphrase, "hunter2\n").unwrap();
+        let blob = workspace.home().join("verifier.polkavm");
+        std::fs::write(&blob, b"PVM\0blob").unwrap();
+        // `owner()` answers someone else
+        let node = test_node::spawn(dev_node(10u128.pow(18), abi::encode_address(&[0x22; 20]).to_vec())).await;
+
+        let err = handle_deploy(&workspace, Contract::Verifier, None, Some(&node.url), Some(&blob), None, 1, 1, "deployer", Some(&passphrase))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("smoke test failed"), "{}", err);
+        let deployments = Ledger::load(&workspace).unwrap().deployments;
+        assert_eq!(deployments.len(), 1);
+        assert_eq!(deployments[0].contract_address, Some(wallet::checksum_address(&CREATED)));
+        let network = crate::networks::resolve(&workspace, None, None, None).unwrap();
+        assert_eq!(network.verifier, Some(CREATED));
+    }
+
+    #[tokio::test]
+    async fn test_smoke_test_rejects_other_contracts() {
+        let deployer = Wallet::from_private_key(&KEY).unwrap().address();
+
+        let node = test_node::spawn(dev_node(0, contract_info("SomethingElse"))).await;
+        let rpc = RpcClient::new(&node.url);
+        assert!(smoke_test(&rpc, Contract::Registry, &CREATED, &deployer).await.is_err());
+
+        let node = test_node::spawn(dev_node(0, abi::encode_address(&deployer).to_vec())).await;
+        let rpc = RpcClient::new(&node.url);
+        assert!(smoke_test(&rpc, Contract::Verifier, &CREATED, &deployer).await.unwrap().contains("owner()"));
+        assert!(smoke_test(&rpc, Contract::Verifier, &CREATED, &[0x22; 20]).await.is_err());
+    }
+
+    #[test]
+    fn test_code_from_blob_or_foundry_artifact() {
+        let workspace = TempWorkspace::new("deploy-code");
+        std::fs::create_dir_all(workspace.home()).unwrap();
+        let blob = workspace.home().join("contract.polkavm");
+        std::fs::write(&blob, b"PVM\0blob").unwrap();
+        let code = Code::read(&blob).unwrap();
+        assert_eq!(code.init_code(&[0x01; WORD]), [b"PVM\0blob".as_slice(), &[0x01; WORD]].concat());
+
+        let artifact = workspace.home().join("niet2codeBuilder.json");
+        std::fs::write(&artifact, r#"{"abi": [], "bytecode": {"object": "0x6080604052"}}"#).unwrap();
+        let code = Code::read(&artifact).unwrap();
+        assert_eq!(code, Code::Evm(vec![0x60, 0x80, 0x60, 0x40, 0x52]));
+        // The Solidity registry has no constructor arguments
+        assert_eq!(code.init_code(&[0x01; WORD]), vec![0x60, 0x80, 0x60, 0x40, 0x52]);
+
+        let err = Code::load(Contract::Verifier, &workspace, None).unwrap_err();
+        assert!(format!("{:#}", err).contains("verifier build"), "{:#}", err);
+    }
+
+    #[test]
+    fn test_record_updates_profile_and_ledger() {
+        let workspace = TempWorkspace::new("deploy-record");
+        let network = crate::networks::resolve(&workspace, Some("mantle-mainnet"), None, None).unwrap();
+        let receipt: TransactionReceipt = serde_json::from_value(json!({
+            "transactionHash": rpc::to_hex(&[0xab; 32]),
+            "blockNumber": "0x10",
+            "status": "0x1",
+            "gasUsed": "0x15f90",
+            "contractAddress": rpc::to_hex(&CREATED),
+        }))
+        .unwrap();
+
+        record(&workspace, &network, Contract::Verifier, &[0x5e; 20], &receipt, &[0x11; 20]).unwrap();
+        record(&workspace, &network, Contract::Registry, &CREATED, &receipt, &[0x11; 20]).unwrap();
+
+        let mainnet = crate::networks::resolve(&workspace, Some("mantle-mainnet"), None, None).unwrap();
+        assert_eq!((mainnet.registry, mainnet.verifier), (Some(CREATED), Some([0x5e; 20])));
+        let ledger = Ledger::load(&workspace).unwrap();
+        let names: Vec<_> = ledger.deployments.iter().map(|d| d.contract_name.as_deref().unwrap()).collect();
+        assert_eq!(names, ["verifier-contract", "niet2codeBuilder"]);
+        assert!(ledger.deployments.iter().all(|d| d.network == "mantle-mainnet" && d.source == "deploy"));
+        assert_eq!(std::fs::read_to_string(abi_path(&workspace, Contract::Registry)).unwrap(), REGISTRY_ABI);
+    }
+
+    #[test]
+    fn test_embedded_abis_match_selectors() {
+        fn selectors(abi: &str) -> Vec<[u8; 4]> {
+            let entries: Vec<Value> = serde_json::from_str(abi).unwrap();
+            entries
+                .iter()
+                .filter(|entry| entry["type"] == "function")
+                .map(|entry| {
+                    let types: Vec<&str> = entry["inputs"].as_array().unwrap().iter().map(|input| input["type"].as_str().unwrap()).collect();
+                    let signature = format!("{}({})", entry["name"].as_str().unwrap(), types.join(","));
+                    tx::keccak256(signature.as_bytes())[..4].try_into().unwrap()
+                })
+                .collect()
+        }
+        let registry = selectors(Contract::Registry.abi());
+        for selector in [REGISTER_BUILDER_SELECTOR, VERIFY_PROOF_SELECTOR, GET_CONTRACT_INFO_SELECTOR] {
+            assert!(registry.contains(&selector), "{:02x?}", selector);
+        }
+        let verifier = selectors(Contract::Verifier.abi());
+        assert!(verifier.contains(&OWNER_SELECTOR));
+        assert!(verifier.contains(&verifier_core::abi::VERIFY_SELECTOR));
+    }
+}
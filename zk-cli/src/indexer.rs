@@ -0,0 +1,711 @@
+// `watch`: the registry's `BuilderRegistered`, `ProofVerified` and
+// `ContractDeployed` events, indexed into the ledger. `eth_getLogs` is paged over
+// block ranges, halving a range the node refuses. A page is only taken if its
+// last block has the same hash before and after its logs are fetched, and that
+// hash is kept in `<home>/indexer_checkpoints.json`. A later run that finds a
+// different hash there rewinds to the newest checkpoint the chain still agrees
+// with and cancels the events recorded above it.
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{btree_map, BTreeMap};
+use std::path::Path;
+use std::time::Duration;
+
+use prover::workspace::Workspace;
+use prover::ProverError;
+
+use crate::ledger::{self, Entry, EventRecord, Ledger, RegistryEvent};
+use crate::networks::{self, Network};
+use crate::registry::{self, BUILDER_REGISTERED_TOPIC, CONTRACT_DEPLOYED_TOPIC, PROOF_VERIFIED_TOPIC};
+use crate::rpc::{self, BlockHeader, Log, LogFilter, RpcClient, RpcError};
+use crate::wallet;
+
+pub const CHECKPOINT_FILE: &str = "indexer_checkpoints.json";
+
+/// Page-end hashes kept per registry, which bounds how deep a reorg can be undone
+pub const REORG_WINDOW: usize = 64;
+
+pub const DEFAULT_BATCH_SIZE: u64 = 1000;
+
+/// Times a page is fetched again because its last block changed meanwhile
+const PAGE_ATTEMPTS: usize = 3;
+
+const EVENT_TOPICS: [[u8; 32]; 3] = [BUILDER_REGISTERED_TOPIC, PROOF_VERIFIED_TOPIC, CONTRACT_DEPLOYED_TOPIC];
+
+/// How far one registry has been indexed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub network: String,
+    pub chain_id: u64,
+    pub registry: String,
+    /// Number and hash of the last block of recent pages, oldest first
+    pub blocks: Vec<(u64, String)>,
+}
+
+impl Checkpoint {
+    fn same_registry(&self, other: &Checkpoint) -> bool {
+        self.chain_id == other.chain_id && self.registry.eq_ignore_ascii_case(&other.registry)
+    }
+}
+
+/// A missing file has no checkpoints; a corrupt one is an error, since
+/// starting over would re-read the whole chain
+pub fn load_checkpoints(path: &Path) -> Result<Vec<Checkpoint>> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => serde_json::from_str(&content).map_err(|err| ProverError::InvalidInput(format!("{}: {}", path.display(), err)).into()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(ProverError::Io(e).into()),
+    }
+}
+
+/// Replace the checkpoint for the same chain and registry
+pub fn store_checkpoint(path: &Path, checkpoint: &Checkpoint) -> Result<()> {
+    let mut checkpoints = load_checkpoints(path)?;
+    checkpoints.retain(|existing| !existing.same_registry(checkpoint));
+    checkpoints.push(checkpoint.clone());
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(ProverError::Io)?;
+    }
+    std::fs::write(path, serde_json::to_string_pretty(&checkpoints)?).map_err(ProverError::Io)?;
+    Ok(())
+}
+
+/// One of the three registry events emitted by `registry`, if `log` is one
+pub fn decode(log: &Log, registry: &[u8; 20]) -> Option<RegistryEvent> {
+    if let Some((builder, alias)) = registry::decode_builder_registered(log, registry) {
+        return Some(RegistryEvent::BuilderRegistered {
+            builder: wallet::checksum_address(&builder),
+            alias,
+        });
+    }
+    if let Some((builder, proof_hash, is_valid)) = registry::decode_proof_verified(log, registry) {
+        return Some(RegistryEvent::ProofVerified {
+            builder: wallet::checksum_address(&builder),
+            proof_hash: rpc::to_hex(&proof_hash),
+            is_valid,
+        });
+    }
+    registry::decode_contract_deployed(log, registry).map(|(builder, contract)| RegistryEvent::ContractDeployed {
+        builder: wallet::checksum_address(&builder),
+        contract: wallet::checksum_address(&contract),
+    })
+}
+
+/// What one `sync` changed in the ledger
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SyncReport {
+    pub events: Vec<EventRecord>,
+    /// Events cancelled because a reorg dropped their blocks
+    pub removed: Vec<EventRecord>,
+    /// Last block indexed
+    pub last_block: Option<u64>,
+}
+
+pub struct Indexer<'a> {
+    pub workspace: &'a Workspace,
+    pub network: &'a Network,
+    pub registry: [u8; 20],
+    /// Blocks per `eth_getLogs` request
+    pub batch_size: u64,
+    /// Blocks to wait for, counting the head; 1 indexes up to the head
+    pub confirmations: u64,
+    /// Start again from this block instead of the checkpoint
+    pub from_block: Option<u64>,
+}
+
+impl Indexer<'_> {
+    fn checkpoint_path(&self) -> std::path::PathBuf {
+        self.workspace.state_file(CHECKPOINT_FILE)
+    }
+
+    fn registry_address(&self) -> String {
+        wallet::checksum_address(&self.registry)
+    }
+
+    /// Rewind past any reorg, then index every page up to the confirmed head
+    pub async fn sync(&self, rpc: &RpcClient) -> Result<SyncReport> {
+        let path = self.checkpoint_path();
+        let fresh = Checkpoint {
+            network: self.network.name.clone(),
+            chain_id: self.network.chain_id,
+            registry: self.registry_address(),
+            blocks: Vec::new(),
+        };
+        let mut checkpoint = match self.from_block {
+            Some(_) => fresh,
+            None => load_checkpoints(&path)?.into_iter().find(|existing| existing.same_registry(&fresh)).unwrap_or(fresh),
+        };
+        let mut report = SyncReport::default();
+
+        let mut rewound = false;
+        while let Some((number, hash)) = checkpoint.blocks.last().cloned() {
+            match rpc.block_header(number).await? {
+                Some(header) if rpc::to_hex(&header.hash) == hash => break,
+                _ => {
+                    checkpoint.blocks.pop();
+                    rewound = true;
+                }
+            }
+        }
+        if rewound {
+            let Some(&(fork, _)) = checkpoint.blocks.last() else {
+                return Err(ProverError::InvalidInput(format!(
+                    "reorg on {} is deeper than the last {} checkpoints of {}: re-index with --from-block",
+                    self.network.name,
+                    REORG_WINDOW,
+                    self.registry_address()
+                ))
+                .into());
+            };
+            report.removed = self.cancel_events_after(fork)?;
+            store_checkpoint(&path, &checkpoint)?;
+        }
+
+        let mut from = match checkpoint.blocks.last() {
+            Some(&(number, _)) => number + 1,
+            None => match self.from_block {
+                Some(block) => block,
+                None => self.deployment_block()?,
+            },
+        };
+        let head = rpc.block_number().await?;
+        let target = (head + 1).checked_sub(self.confirmations.max(1));
+        while let Some(target) = target.filter(|&target| from <= target) {
+            let to = from.saturating_add(self.batch_size.max(1) - 1).min(target);
+            let (to, logs, header) = self.fetch_page(rpc, from, to).await?;
+            let records = self.records(rpc, &logs).await?;
+
+            let mut ledger = Ledger::load(self.workspace)?;
+            for record in records {
+                if ledger.record(Entry::Event(record.clone())) {
+                    report.events.push(record);
+                }
+            }
+            ledger.save(self.workspace)?;
+
+            checkpoint.blocks.push((to, rpc::to_hex(&header.hash)));
+            let excess = checkpoint.blocks.len().saturating_sub(REORG_WINDOW);
+            checkpoint.blocks.drain(..excess);
+            store_checkpoint(&path, &checkpoint)?;
+            from = to + 1;
+        }
+        report.last_block = checkpoint.blocks.last().map(|&(number, _)| number);
+        Ok(report)
+    }
+
+    /// Logs of `from..=to` and the header of the page's last block, which may be
+    /// earlier than `to` if the node refused the range
+    async fn fetch_page(&self, rpc: &RpcClient, from: u64, mut to: u64) -> Result<(u64, Vec<Log>, BlockHeader)> {
+        let mut attempts = 0;
+        loop {
+            let before = header(rpc, to).await?;
+            let filter = LogFilter {
+                address: self.registry,
+                topics: EVENT_TOPICS.to_vec(),
+                from_block: from,
+                to_block: to,
+            };
+            match rpc.get_logs(&filter).await {
+                Ok(logs) => {
+                    if header(rpc, to).await?.hash == before.hash {
+                        return Ok((to, logs, before));
+                    }
+                    attempts += 1;
+                    if attempts == PAGE_ATTEMPTS {
+                        bail!("block {} kept changing while its logs were fetched", to);
+                    }
+                }
+                // Nodes cap the range or the result size; ask for less
+                Err(err) if to > from && err.downcast_ref::<RpcError>().is_some() => to = from + (to - from) / 2,
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// Decode `logs` and date them by their blocks
+    async fn records(&self, rpc: &RpcClient, logs: &[Log]) -> Result<Vec<EventRecord>> {
+        let mut headers: BTreeMap<u64, BlockHeader> = BTreeMap::new();
+        let mut records = Vec::new();
+        for log in logs.iter().filter(|log| !log.removed) {
+            let Some(event) = decode(log, &self.registry) else {
+                continue;
+            };
+            let (Some(block_number), Some(block_hash), Some(tx_hash), Some(log_index)) = (log.block_number, log.block_hash, log.transaction_hash, log.log_index) else {
+                bail!("eth_getLogs returned a {} log without its block, transaction or index", event.name());
+            };
+            if let btree_map::Entry::Vacant(slot) = headers.entry(block_number) {
+                slot.insert(header(rpc, block_number).await?);
+            }
+            let block = &headers[&block_number];
+            if block.hash != block_hash {
+                bail!("block {} changed while it was indexed; run again", block_number);
+            }
+            let block_time = chrono::DateTime::from_timestamp(block.timestamp as i64, 0).unwrap_or_default();
+            records.push(EventRecord {
+                network: self.network.name.clone(),
+                chain_id: self.network.chain_id,
+                registry: self.registry_address(),
+                event,
+                block_number,
+                block_hash: rpc::to_hex(&block_hash),
+                block_time: block_time.to_rfc3339(),
+                tx_hash: rpc::to_hex(&tx_hash),
+                log_index,
+                removed: false,
+            });
+        }
+        Ok(records)
+    }
+
+    /// Append a cancelling line for each event of this registry above `block`
+    fn cancel_events_after(&self, block: u64) -> Result<Vec<EventRecord>> {
+        let mut ledger = Ledger::load(self.workspace)?;
+        let dropped: Vec<EventRecord> = ledger
+            .events
+            .iter()
+            .filter(|event| event.chain_id == self.network.chain_id && event.registry == self.registry_address() && event.block_number > block)
+            .map(|event| EventRecord {
+                removed: true,
+                ..event.clone()
+            })
+            .collect();
+        for event in &dropped {
+            ledger.record(Entry::Event(event.clone()));
+        }
+        ledger.save(self.workspace)?;
+        Ok(dropped)
+    }
+
+    /// Block the ledger saw the registry deployed in; without it a first run
+    /// needs `--from-block` rather than scanning from genesis
+    fn deployment_block(&self) -> Result<u64> {
+        let registry = self.registry_address();
+        let ledger = Ledger::load(self.workspace)?;
+        let block = ledger
+            .deployments
+            .iter()
+            .filter(|deployment| deployment.chain_id == self.network.chain_id && deployment.is_creation())
+            .filter(|deployment| deployment.contract_address.as_deref().is_some_and(|address| address.eq_ignore_ascii_case(&registry)))
+            .find_map(|deployment| deployment.block_number);
+        block.ok_or_else(|| {
+            ProverError::InvalidInput(format!(
+                "the ledger has no deployment block for {} on {}: pass --from-block (or import-broadcast the deployment)",
+                registry, self.network.name
+            ))
+            .into()
+        })
+    }
+}
+
+async fn header(rpc: &RpcClient, number: u64) -> Result<BlockHeader> {
+    match rpc.block_header(number).await? {
+        Some(header) => Ok(header),
+        None => bail!("{} has no block {}", rpc.url(), number),
+    }
+}
+
+/// POST `event` as JSON to `url`
+async fn forward(http: &reqwest::Client, url: &str, event: &EventRecord) -> Result<()> {
+    let response = http.post(url).json(event).send().await?;
+    if !response.status().is_success() {
+        bail!("{} answered HTTP {}", url, response.status());
+    }
+    Ok(())
+}
+
+/// Print and forward what `sync` found
+async fn publish(report: &SyncReport, json: bool, webhook: Option<&str>, http: &reqwest::Client) -> Result<()> {
+    for event in report.removed.iter().chain(&report.events) {
+        if json {
+            println!("{}", serde_json::to_string(event)?);
+        } else if event.removed {
+            println!("↩️  Dropped by reorg: {}", ledger::describe(&Entry::Event(event.clone())));
+        } else {
+            println!("🔔 {} {}", event.block_time, ledger::describe(&Entry::Event(event.clone())));
+        }
+        if let Some(url) = webhook {
+            if let Err(err) = forward(http, url, event).await {
+                eprintln!("⚠️  Could not forward to {}: {}", url, err);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn handle_watch(
+    workspace: &Workspace,
+    network: Option<&str>,
+    rpc_url: Option<&str>,
+    registry: Option<&str>,
+    from_block: Option<u64>,
+    batch_size: u64,
+    confirmations: u64,
+    interval: Duration,
+    once: bool,
+    json: bool,
+    webhook: Option<&str>,
+) -> Result<()> {
+    let network = networks::resolve(workspace, network, rpc_url, registry)?;
+    let rpc = RpcClient::new(&network.rpc_url);
+    let chain_id = rpc.chain_id().await?;
+    if chain_id != network.chain_id {
+        return Err(ProverError::InvalidInput(format!("{} is on chain {}, but {} is chain {}", rpc.url(), chain_id, network.name, network.chain_id)).into());
+    }
+    let mut indexer = Indexer {
+        workspace,
+        network: &network,
+        registry: network.registry()?,
+        batch_size,
+        confirmations,
+        from_block,
+    };
+    if !json {
+        println!("👀 Indexing {} on {} (chain {})", indexer.registry_address(), network.name, network.chain_id);
+    }
+    let http = reqwest::Client::new();
+
+    let report = indexer.sync(&rpc).await?;
+    publish(&report, json, webhook, &http).await?;
+    if !json {
+        let last = report.last_block.map_or("-".to_string(), |block| block.to_string());
+        println!("📒 {} new events, {} dropped · indexed up to block {}", report.events.len(), report.removed.len(), last);
+    }
+    if once {
+        return Ok(());
+    }
+
+    // Later rounds continue from the checkpoint
+    indexer.from_block = None;
+    loop {
+        tokio::time::sleep(interval).await;
+        match indexer.sync(&rpc).await {
+            Ok(report) => publish(&report, json, webhook, &http).await?,
+            Err(err) => eprintln!("⚠️  {}: {}", network.name, err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_workspace::TempWorkspace;
+    use crate::ledger::Deployment;
+    use crate::rpc::test_node::{self, NodeError};
+    use serde_json::{json, Value};
+    use std::sync::{Arc, Mutex};
+    use verifier_core::abi::{self, WORD};
+
+    const REGISTRY: [u8; 20] = [0x79; 20];
+    const BUILDER: [u8; 20] = [0x11; 20];
+
+    /// Block, transaction hash byte, topics and data of a canned log
+    type CannedLog = (u64, u8, Vec<[u8; 32]>, Vec<u8>);
+
+    /// A canned chain: block hashes derive from the number and, from `fork_from`
+    /// on, the `fork`; `eth_getLogs` refuses ranges over `max_range` blocks
+    struct Chain {
+        head: u64,
+        fork: u8,
+        fork_from: u64,
+        max_range: u64,
+        logs: Vec<CannedLog>,
+    }
+
+    impl Chain {
+        fn hash(&self, number: u64) -> [u8; 32] {
+            let fork = if number >= self.fork_from { self.fork } else { 0 };
+            crate::tx::keccak256(&[number.to_be_bytes().as_slice(), &[fork]].concat())
+        }
+    }
+
+    fn block_registered(block: u64, tx: u8, alias: &str) -> CannedLog {
+        (block, tx, vec![BUILDER_REGISTERED_TOPIC, abi::encode_address(&BUILDER)], abi::encode_string(alias))
+    }
+
+    fn proof_verified(block: u64, tx: u8, is_valid: bool) -> CannedLog {
+        (block, tx, vec![PROOF_VERIFIED_TOPIC, abi::encode_address(&BUILDER)], [[0x33; WORD], abi::encode_bool(is_valid)].concat())
+    }
+
+    fn contract_deployed(block: u64, tx: u8) -> CannedLog {
+        (block, tx, vec![CONTRACT_DEPLOYED_TOPIC, abi::encode_address(&BUILDER)], abi::encode_address(&[0xc0; 20]).to_vec())
+    }
+
+    fn chain_node(chain: Arc<Mutex<Chain>>) -> impl Fn(&str, &Value) -> Result<Value, NodeError> {
+        move |method, params| {
+            let chain = chain.lock().unwrap();
+            let quantity = |value: &Value| u64::from_str_radix(value.as_str().unwrap().trim_start_matches("0x"), 16).unwrap();
+            match method {
+                "eth_blockNumber" => Ok(json!(rpc::to_quantity(chain.head.into()))),
+                "eth_getBlockByNumber" => {
+                    let number = quantity(&params[0]);
+                    if number > chain.head {
+                        return Ok(Value::Null);
+                    }
+                    Ok(json!({ "number": params[0], "hash": rpc::to_hex(&chain.hash(number)), "timestamp": rpc::to_quantity((1_700_000_000 + number).into()) }))
+                }
+                "eth_getLogs" => {
+                    let (from, to) = (quantity(&params[0]["fromBlock"]), quantity(&params[0]["toBlock"]));
+                    if to - from + 1 > chain.max_range {
+                        return Err(NodeError::new(-32005, "query returned more than 10000 results"));
+                    }
+                    let logs: Vec<Value> = chain
+                        .logs
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, (block, ..))| (from..=to).contains(block))
+                        .map(|(index, (block, tx, topics, data))| {
+                            json!({
+                                "address": rpc::to_hex(&REGISTRY),
+                                "topics": topics.iter().map(|topic| rpc::to_hex(topic)).collect::<Vec<_>>(),
+                                "data": rpc::to_hex(data),
+                                "blockNumber": rpc::to_quantity((*block).into()),
+                                "blockHash": rpc::to_hex(&chain.hash(*block)),
+                                "transactionHash": rpc::to_hex(&[*tx; 32]),
+                                "logIndex": rpc::to_quantity(index as u128),
+                                "removed": false,
+                            })
+                        })
+                        .collect();
+                    Ok(json!(logs))
+                }
+                _ => Err(NodeError::method_not_found()),
+            }
+        }
+    }
+
+    fn log_ranges(node: &test_node::StandIn) -> Vec<(String, String)> {
+        node.calls()
+            .iter()
+            .filter(|(method, _)| method == "eth_getLogs")
+            .map(|(_, params)| (params[0]["fromBlock"].as_str().unwrap().to_string(), params[0]["toBlock"].as_str().unwrap().to_string()))
+            .collect()
+    }
+
+    fn indexer<'a>(workspace: &'a Workspace, network: &'a Network) -> Indexer<'a> {
+        Indexer {
+            workspace,
+            network,
+            registry: REGISTRY,
+            batch_size: 100,
+            confirmations: 1,
+            from_block: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sync_pages_decodes_and_resumes() {
+        let chain = Arc::new(Mutex::new(Chain {
+            head: 250,
+            fork: 0,
+            fork_from: u64::MAX,
+            max_range: 1000,
+            logs: vec![block_registered(10, 0x01, "anon"), proof_verified(120, 0x02, true), contract_deployed(240, 0x03)],
+        }));
+        let node = test_node::spawn(chain_node(chain.clone())).await;
+        let rpc = RpcClient::new(&node.url);
+        let workspace = TempWorkspace::new("indexer-sync");
+        let network = Network::local(&node.url, Some(REGISTRY));
+
+        let first = Indexer {
+            from_block: Some(0),
+            ..indexer(&workspace, &network)
+        };
+        let report = first.sync(&rpc).await.unwrap();
+        let names: Vec<&str> = report.events.iter().map(|event| event.event.name()).collect();
+        assert_eq!(names, ["BuilderRegistered", "ProofVerified", "ContractDeployed"]);
+        assert_eq!(report.last_block, Some(250));
+        assert_eq!(log_ranges(&node), [("0x0", "0x63"), ("0x64", "0xc7"), ("0xc8", "0xfa")].map(|(a, b)| (a.to_string(), b.to_string())));
+        assert_eq!(
+            report.events[0].event,
+            RegistryEvent::BuilderRegistered {
+                builder: wallet::checksum_address(&BUILDER),
+                alias: "anon".to_string(),
+            }
+        );
+        assert_eq!(report.events[1].block_time, "2023-11-14T22:15:20+00:00");
+        assert_eq!(report.events[1].tx_hash, rpc::to_hex(&[0x02; 32]));
+        assert_eq!(Ledger::load(&workspace).unwrap().events, report.events);
+
+        // Nothing new: no logs are fetched
+        let report = indexer(&workspace, &network).sync(&rpc).await.unwrap();
+        assert!(report.events.is_empty());
+        assert_eq!(log_ranges(&node).len(), 3);
+
+        // New blocks are picked up from the checkpoint
+        {
+            let mut chain = chain.lock().unwrap();
+            chain.head = 260;
+            chain.logs.push(proof_verified(255, 0x04, false));
+        }
+        let report = indexer(&workspace, &network).sync(&rpc).await.unwrap();
+        assert_eq!(report.events.len(), 1);
+        assert_eq!(log_ranges(&node).last().unwrap(), &("0xfb".to_string(), "0x104".to_string()));
+        let event = Entry::Event(report.events[0].clone());
+        assert_eq!((event.kind(), event.status()), ("event", "invalid"));
+
+        // Re-indexing from a block records nothing twice, nor adds lines to the log
+        let lines = || std::fs::read_to_string(Ledger::path(&workspace)).unwrap().lines().count();
+        assert_eq!(lines(), 4);
+        let again = Indexer {
+            from_block: Some(0),
+            ..indexer(&workspace, &network)
+        };
+        assert!(again.sync(&rpc).await.unwrap().events.is_empty());
+        assert_eq!(Ledger::load(&workspace).unwrap().events.len(), 4);
+        assert_eq!(lines(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_reorg_cancels_dropped_events() {
+        let chain = Arc::new(Mutex::new(Chain {
+            head: 250,
+            fork: 0,
+            fork_from: u64::MAX,
+            max_range: 1000,
+            logs: vec![block_registered(10, 0x01, "anon"), contract_deployed(240, 0x03)],
+        }));
+        let node = test_node::spawn(chain_node(chain.clone())).await;
+        let rpc = RpcClient::new(&node.url);
+        let workspace = TempWorkspace::new("indexer-reorg");
+        let network = Network::local(&node.url, Some(REGISTRY));
+        let first = Indexer {
+            from_block: Some(0),
+            ..indexer(&workspace, &network)
+        };
+        assert_eq!(first.sync(&rpc).await.unwrap().events.len(), 2);
+
+        // Blocks from 230 on are replaced; the deployment moves to another transaction
+        {
+            let mut chain = chain.lock().unwrap();
+            chain.fork = 1;
+            chain.fork_from = 230;
+            chain.head = 252;
+            chain.logs = vec![block_registered(10, 0x01, "anon"), contract_deployed(245, 0x05)];
+        }
+        let report = indexer(&workspace, &network).sync(&rpc).await.unwrap();
+        assert_eq!(report.removed.len(), 1);
+        assert_eq!(report.removed[0].tx_hash, rpc::to_hex(&[0x03; 32]));
+        assert_eq!(report.events.len(), 1);
+        assert_eq!(report.events[0].tx_hash, rpc::to_hex(&[0x05; 32]));
+        // Rewound to the checkpoint at 199, the last one still on the chain
+        assert_eq!(log_ranges(&node).last().unwrap(), &("0xc8".to_string(), "0xfc".to_string()));
+
+        let ledger = Ledger::load(&workspace).unwrap();
+        let txs: Vec<&str> = ledger.events.iter().map(|event| event.tx_hash.as_str()).collect();
+        assert_eq!(txs, [rpc::to_hex(&[0x01; 32]), rpc::to_hex(&[0x05; 32])]);
+
+        // A reorg below every checkpoint cannot be undone here
+        {
+            let mut chain = chain.lock().unwrap();
+            chain.fork = 2;
+            chain.fork_from = 0;
+        }
+        let err = indexer(&workspace, &network).sync(&rpc).await.unwrap_err();
+        assert!(matches!(err.downcast_ref::<ProverError>(), Some(ProverError::InvalidInput(_))), "{}", err);
+        assert!(err.to_string().contains("--from-block"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_refused_ranges_are_halved_and_unconfirmed_blocks_wait() {
+        let chain = Arc::new(Mutex::new(Chain {
+            head: 99,
+            fork: 0,
+            fork_from: u64::MAX,
+            max_range: 30,
+            logs: vec![block_registered(40, 0x01, "anon"), proof_verified(95, 0x02, true)],
+        }));
+        let node = test_node::spawn(chain_node(chain)).await;
+        let rpc = RpcClient::new(&node.url);
+        let workspace = TempWorkspace::new("indexer-halve");
+        let network = Network::local(&node.url, Some(REGISTRY));
+        let indexer = Indexer {
+            confirmations: 10,
+            from_block: Some(0),
+            ..indexer(&workspace, &network)
+        };
+
+        let report = indexer.sync(&rpc).await.unwrap();
+        // Blocks 91-99 have fewer than 10 confirmations
+        assert_eq!(report.last_block, Some(90));
+        assert_eq!(report.events.len(), 1);
+        let ranges = log_ranges(&node);
+        assert_eq!(ranges[..3], [("0x0", "0x5a"), ("0x0", "0x2d"), ("0x0", "0x16")].map(|(a, b)| (a.to_string(), b.to_string())));
+        assert_eq!(ranges.last().unwrap().1, "0x5a");
+    }
+
+    #[tokio::test]
+    async fn test_first_sync_starts_at_the_deployment_block() {
+        let chain = Arc::new(Mutex::new(Chain {
+            head: 250,
+            fork: 0,
+            fork_from: u64::MAX,
+            max_range: 1000,
+            logs: vec![proof_verified(120, 0x02, true)],
+        }));
+        let node = test_node::spawn(chain_node(chain)).await;
+        let rpc = RpcClient::new(&node.url);
+        let workspace = TempWorkspace::new("indexer-start");
+        let network = Network::local(&node.url, Some(REGISTRY));
+
+        // Neither a deployment in the ledger nor --from-block: no scan from genesis
+        let err = indexer(&workspace, &network).sync(&rpc).await.unwrap_err();
+        assert!(matches!(err.downcast_ref::<ProverError>(), Some(ProverError::InvalidInput(_))), "{}", err);
+        assert!(err.to_string().contains("--from-block"), "{}", err);
+        assert!(log_ranges(&node).is_empty());
+
+        Ledger::append(
+            &workspace,
+            Entry::Deployment(Deployment {
+                network: network.name.clone(),
+                chain_id: network.chain_id,
+                tx_hash: rpc::to_hex(&[0x01; 32]),
+                kind: "CREATE".to_string(),
+                contract_name: Some("niet2codeBuilder".to_string()),
+                contract_address: Some(wallet::checksum_address(&REGISTRY)),
+                function: None,
+                from: Some(wallet::checksum_address(&BUILDER)),
+                block_number: Some(100),
+                gas_used: Some(21_000),
+                effective_gas_price: Some(1),
+                succeeded: Some(true),
+                source: "deploy".to_string(),
+                deployed_at: "2026-01-01T00:00:00Z".to_string(),
+            }),
+        )
+        .unwrap();
+        let report = indexer(&workspace, &network).sync(&rpc).await.unwrap();
+        assert_eq!(report.events.len(), 1);
+        assert_eq!(log_ranges(&node), [("0x64", "0xc7"), ("0xc8", "0xfa")].map(|(a, b)| (a.to_string(), b.to_string())));
+    }
+
+    #[test]
+    fn test_checkpoints_replace_per_registry() {
+        let path = std::env::temp_dir().join(format!("niet2code-checkpoints-{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let checkpoint = Checkpoint {
+            network: "local".to_string(),
+            chain_id: 5003,
+            registry: wallet::checksum_address(&REGISTRY),
+            blocks: vec![(99, rpc::to_hex(&[0xbb; 32]))],
+        };
+        store_checkpoint(&path, &checkpoint).unwrap();
+        let newer = Checkpoint {
+            registry: rpc::to_hex(&REGISTRY),
+            blocks: vec![(199, rpc::to_hex(&[0xcc; 32]))],
+            ..checkpoint.clone()
+        };
+        store_checkpoint(&path, &newer).unwrap();
+        let other = Checkpoint {
+            chain_id: 5000,
+            ..checkpoint
+        };
+        store_checkpoint(&path, &other).unwrap();
+        assert_eq!(load_checkpoints(&path).unwrap(), vec![newer, other]);
+
+        std::fs::write(&path, "[{").unwrap();
+        assert!(load_checkpoints(&path).is_err());
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,774 @@
+// Local record of what the builder has done: each proof generated, each proof
+// submitted and each deployment transaction (contract creations and the calls
+// that set them up) with its receipt, plus the registry events found by `watch`.
+// Records are appended, one JSON object per line, to `<home>/ledger.jsonl` and
+// never rewritten; a later line for the same deployment (re-importing a
+// broadcast) or event (dropped by a reorg) supersedes the earlier one when the
+// log is read back. `history` and `dashboard` are computed from it.
+
+use anyhow::Result;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use prover::workspace::Workspace;
+use prover::ProverError;
+
+pub const LEDGER_FILE: &str = "ledger.jsonl";
+/// The registry call that bumps a builder's `contractsDeployed`
+const RECORD_DEPLOYMENT: &str = "recordDeployment(address)";
+
+/// A proof written by `prove`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProofRecord {
+    /// Network named with `prove --network`, if any
+    pub network: Option<String>,
+    pub circuit_id: u64,
+    pub circuit: String,
+    pub vk_fingerprint: String,
+    /// keccak256 of the public inputs as 32-byte words
+    pub public_inputs_hash: String,
+    pub calldata: String,
+    pub generated_at: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SubmissionStatus {
+    /// Mined, and the proof was accepted
+    Valid,
+    /// Mined, but recorded as invalid
+    Invalid,
+    /// The transaction reverted
+    Reverted,
+    /// Mined, but the registry emitted no `ProofVerified` event for the sender
+    Unknown,
+}
+
+impl SubmissionStatus {
+    pub fn from_valid(valid: bool) -> Self {
+        if valid {
+            SubmissionStatus::Valid
+        } else {
+            SubmissionStatus::Invalid
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SubmissionStatus::Valid => "valid",
+            SubmissionStatus::Invalid => "invalid",
+            SubmissionStatus::Reverted => "reverted",
+            SubmissionStatus::Unknown => "unknown",
+        }
+    }
+}
+
+/// A mined `verifyProof` (registry) or `verify` (verifier) transaction
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubmissionRecord {
+    pub network: String,
+    pub chain_id: u64,
+    /// `registry` or `verifier`
+    pub target: String,
+    /// Checksummed address the proof was sent to
+    pub contract_address: String,
+    /// Checksummed sender
+    #[serde(default)]
+    pub from: Option<String>,
+    pub tx_hash: String,
+    pub status: SubmissionStatus,
+    pub public_inputs_hash: String,
+    /// `proofHash` from the registry's `ProofVerified` event
+    pub proof_hash: Option<String>,
+    pub block_number: u64,
+    pub gas_used: u64,
+    pub effective_gas_price: Option<u128>,
+    pub submitted_at: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Deployment {
+    pub network: String,
+    pub chain_id: u64,
+    pub tx_hash: String,
+    /// `CREATE`, `CREATE2` or `CALL`
+    pub kind: String,
+    pub contract_name: Option<String>,
+    /// Checksummed address of the created or called contract
+    pub contract_address: Option<String>,
+    /// Signature of the called function, for calls
+    pub function: Option<String>,
+    pub from: Option<String>,
+    /// Receipt fields; absent while the transaction is pending
+    pub block_number: Option<u64>,
+    pub gas_used: Option<u64>,
+    pub effective_gas_price: Option<u128>,
+    pub succeeded: Option<bool>,
+    /// Where the record came from, e.g. the broadcast file
+    pub source: String,
+    pub deployed_at: String,
+}
+
+impl Deployment {
+    fn same_entry(&self, other: &Deployment) -> bool {
+        self.chain_id == other.chain_id && self.tx_hash == other.tx_hash && self.contract_address == other.contract_address
+    }
+
+    /// `succeeded`, `failed`, or `pending` without a receipt
+    pub fn status(&self) -> &'static str {
+        match self.succeeded {
+            Some(true) => "succeeded",
+            Some(false) => "failed",
+            None => "pending",
+        }
+    }
+
+    /// A contract creation that did not fail
+    pub fn is_creation(&self) -> bool {
+        self.kind.starts_with("CREATE") && self.succeeded != Some(false)
+    }
+}
+
+/// A registry event, with addresses checksummed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RegistryEvent {
+    BuilderRegistered { builder: String, alias: String },
+    ProofVerified { builder: String, proof_hash: String, is_valid: bool },
+    ContractDeployed { builder: String, contract: String },
+}
+
+impl RegistryEvent {
+    pub fn name(&self) -> &'static str {
+        match self {
+            RegistryEvent::BuilderRegistered { .. } => "BuilderRegistered",
+            RegistryEvent::ProofVerified { .. } => "ProofVerified",
+            RegistryEvent::ContractDeployed { .. } => "ContractDeployed",
+        }
+    }
+}
+
+/// A registry event found by `watch`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventRecord {
+    pub network: String,
+    pub chain_id: u64,
+    pub registry: String,
+    pub event: RegistryEvent,
+    pub block_number: u64,
+    pub block_hash: String,
+    /// RFC 3339 time of the block
+    pub block_time: String,
+    pub tx_hash: String,
+    pub log_index: u64,
+    /// Set when a reorg dropped the event; the line cancels the earlier one
+    #[serde(default)]
+    pub removed: bool,
+}
+
+impl EventRecord {
+    fn same_entry(&self, other: &EventRecord) -> bool {
+        self.chain_id == other.chain_id && self.registry == other.registry && self.tx_hash == other.tx_hash && self.log_index == other.log_index
+    }
+}
+
+/// One line of the log, `{"<kind>": {...}}`. Internally tagged enums are
+/// buffered by serde, which cannot hold the `u128` gas prices.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Entry {
+    Proof(ProofRecord),
+    Submission(SubmissionRecord),
+    Deployment(Deployment),
+    Event(EventRecord),
+}
+
+impl Entry {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Entry::Proof(_) => "proof",
+            Entry::Submission(_) => "submission",
+            Entry::Deployment(_) => "deployment",
+            Entry::Event(_) => "event",
+        }
+    }
+
+    pub fn network(&self) -> Option<&str> {
+        match self {
+            Entry::Proof(proof) => proof.network.as_deref(),
+            Entry::Submission(submission) => Some(&submission.network),
+            Entry::Deployment(deployment) => Some(&deployment.network),
+            Entry::Event(event) => Some(&event.network),
+        }
+    }
+
+    pub fn status(&self) -> &'static str {
+        match self {
+            Entry::Proof(_) => "generated",
+            Entry::Submission(submission) => submission.status.as_str(),
+            Entry::Deployment(deployment) => deployment.status(),
+            Entry::Event(EventRecord {
+                event: RegistryEvent::ProofVerified { is_valid, .. },
+                ..
+            }) => SubmissionStatus::from_valid(*is_valid).as_str(),
+            Entry::Event(_) => "indexed",
+        }
+    }
+
+    pub fn timestamp(&self) -> &str {
+        match self {
+            Entry::Proof(proof) => &proof.generated_at,
+            Entry::Submission(submission) => &submission.submitted_at,
+            Entry::Deployment(deployment) => &deployment.deployed_at,
+            Entry::Event(event) => &event.block_time,
+        }
+    }
+
+    /// The timestamp, or `None` if it is not RFC 3339
+    pub fn time(&self) -> Option<DateTime<Utc>> {
+        DateTime::parse_from_rfc3339(self.timestamp()).ok().map(|time| time.with_timezone(&Utc))
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Ledger {
+    pub proofs: Vec<ProofRecord>,
+    pub submissions: Vec<SubmissionRecord>,
+    pub deployments: Vec<Deployment>,
+    pub events: Vec<EventRecord>,
+    /// Entries recorded since loading, appended by `save`
+    pending: Vec<Entry>,
+}
+
+impl Ledger {
+    pub fn load(workspace: &Workspace) -> Result<Self> {
+        Self::load_from(&workspace.state_file(LEDGER_FILE))
+    }
+
+    /// Replay the log. A missing file is an empty ledger; a corrupt line is an
+    /// error rather than being skipped and losing history, except an unfinished
+    /// last line left by an interrupted append.
+    pub fn load_from(path: &Path) -> Result<Self> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(ProverError::Io(e).into()),
+        };
+        let complete = content.rfind('\n').map_or("", |end| &content[..end]);
+        let mut ledger = Self::default();
+        for (index, line) in complete.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let entry: Entry = serde_json::from_str(line)
+                .map_err(|err| ProverError::InvalidInput(format!("{} line {}: {}", path.display(), index + 1, err)))?;
+            ledger.apply(entry);
+        }
+        Ok(ledger)
+    }
+
+    /// Append the entries recorded since loading
+    pub fn save(&mut self, workspace: &Workspace) -> Result<()> {
+        self.save_to(&workspace.state_file(LEDGER_FILE))
+    }
+
+    /// An unfinished last line left by an interrupted append is cut off first,
+    /// so the new lines don't run on from it.
+    pub fn save_to(&mut self, path: &Path) -> Result<()> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+        let mut lines = String::new();
+        for entry in &self.pending {
+            lines.push_str(&serde_json::to_string(entry)?);
+            lines.push('\n');
+        }
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(ProverError::Io)?;
+        }
+        let mut file = std::fs::OpenOptions::new().create(true).read(true).append(true).open(path).map_err(ProverError::Io)?;
+        trim_unfinished_line(&mut file).map_err(ProverError::Io)?;
+        file.write_all(lines.as_bytes()).map_err(ProverError::Io)?;
+        self.pending.clear();
+        Ok(())
+    }
+
+    /// Load, record and save in one go
+    pub fn append(workspace: &Workspace, entry: Entry) -> Result<()> {
+        let mut ledger = Self::load(workspace)?;
+        ledger.record(entry);
+        ledger.save(workspace)
+    }
+
+    pub fn path(workspace: &Workspace) -> PathBuf {
+        workspace.state_file(LEDGER_FILE)
+    }
+
+    /// Queue `entry` for the next save if it changes the ledger; returns whether
+    /// it was new
+    pub fn record(&mut self, entry: Entry) -> bool {
+        self.queue(entry) == Change::Added
+    }
+
+    /// Add `deployment`, replacing the entry for the same chain, transaction and
+    /// contract
+    pub fn record_deployment(&mut self, deployment: Deployment) -> Change {
+        self.queue(Entry::Deployment(deployment))
+    }
+
+    fn queue(&mut self, entry: Entry) -> Change {
+        let change = self.apply(entry.clone());
+        if change != Change::Unchanged {
+            self.pending.push(entry);
+        }
+        change
+    }
+
+    fn apply(&mut self, entry: Entry) -> Change {
+        match entry {
+            Entry::Proof(proof) => self.proofs.push(proof),
+            Entry::Submission(submission) => self.submissions.push(submission),
+            Entry::Deployment(deployment) => match self.deployments.iter_mut().find(|existing| existing.same_entry(&deployment)) {
+                Some(existing) if *existing == deployment => return Change::Unchanged,
+                Some(existing) => {
+                    *existing = deployment;
+                    return Change::Replaced;
+                }
+                None => self.deployments.push(deployment),
+            },
+            Entry::Event(event) => {
+                let existing = self.events.iter().position(|existing| existing.same_entry(&event));
+                match (existing, event.removed) {
+                    (Some(index), true) => {
+                        self.events.remove(index);
+                        return Change::Replaced;
+                    }
+                    (None, true) => return Change::Unchanged,
+                    (Some(index), false) if self.events[index] == event => return Change::Unchanged,
+                    (Some(index), false) => {
+                        self.events[index] = event;
+                        return Change::Replaced;
+                    }
+                    (None, false) => self.events.push(event),
+                }
+            }
+        }
+        Change::Added
+    }
+
+    /// Every entry, oldest first
+    pub fn entries(&self) -> Vec<Entry> {
+        let mut entries: Vec<Entry> = self
+            .proofs
+            .iter()
+            .cloned()
+            .map(Entry::Proof)
+            .chain(self.submissions.iter().cloned().map(Entry::Submission))
+            .chain(self.deployments.iter().cloned().map(Entry::Deployment))
+            .chain(self.events.iter().cloned().map(Entry::Event))
+            .collect();
+        entries.sort_by_key(|entry| entry.time());
+        entries
+    }
+
+    /// Counters for `dashboard`, kept the way `registry` keeps them for
+    /// `builder`: mined `verifyProof` and `recordDeployment` calls the builder
+    /// sent to that registry, from submissions and deployments or the events
+    /// `watch` found for them, each transaction counted once
+    pub fn stats(&self, network: &str, registry: &str, builder: &str) -> LedgerStats {
+        let same = |a: &str, b: &str| a.eq_ignore_ascii_case(b);
+        let by_builder = |from: &Option<String>| from.as_deref().is_some_and(|from| same(from, builder));
+        let mut proofs = BTreeSet::new();
+        let mut deployments = BTreeSet::new();
+        for submission in &self.submissions {
+            if submission.network == network
+                && submission.target == "registry"
+                && same(&submission.contract_address, registry)
+                && by_builder(&submission.from)
+                && submission.status != SubmissionStatus::Reverted
+            {
+                proofs.insert(submission.tx_hash.to_ascii_lowercase());
+            }
+        }
+        for deployment in &self.deployments {
+            if deployment.network == network
+                && deployment.function.as_deref() == Some(RECORD_DEPLOYMENT)
+                && deployment.contract_address.as_deref().is_some_and(|address| same(address, registry))
+                && by_builder(&deployment.from)
+                && deployment.succeeded == Some(true)
+            {
+                deployments.insert(deployment.tx_hash.to_ascii_lowercase());
+            }
+        }
+        for event in self.events.iter().filter(|event| event.network == network && same(&event.registry, registry)) {
+            match &event.event {
+                RegistryEvent::ProofVerified { builder: sender, .. } if same(sender, builder) => {
+                    proofs.insert(event.tx_hash.to_ascii_lowercase());
+                }
+                RegistryEvent::ContractDeployed { builder: sender, .. } if same(sender, builder) => {
+                    deployments.insert(event.tx_hash.to_ascii_lowercase());
+                }
+                _ => {}
+            }
+        }
+        LedgerStats {
+            proofs_generated: self.proofs.len() as u64,
+            proofs_submitted: proofs.len() as u64,
+            contracts_deployed: deployments.len() as u64,
+        }
+    }
+}
+
+/// What applying an entry did to the ledger
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    Added,
+    /// Superseded or removed an earlier entry
+    Replaced,
+    /// Already recorded as is
+    Unchanged,
+}
+
+/// Truncate `file` after its last newline, if it doesn't already end with one
+fn trim_unfinished_line(file: &mut std::fs::File) -> std::io::Result<()> {
+    let len = file.metadata()?.len();
+    if len == 0 {
+        return Ok(());
+    }
+    let mut last = [0u8; 1];
+    file.seek(SeekFrom::Start(len - 1))?;
+    file.read_exact(&mut last)?;
+    if last[0] == b'\n' {
+        return Ok(());
+    }
+    let mut content = Vec::new();
+    file.seek(SeekFrom::Start(0))?;
+    file.read_to_end(&mut content)?;
+    let keep = content.iter().rposition(|&byte| byte == b'\n').map_or(0, |end| end + 1);
+    file.set_len(keep as u64)
+}
+
+/// What the ledger knows about one network, next to the registry's counters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LedgerStats {
+    /// On any network
+    pub proofs_generated: u64,
+    /// Mined `verifyProof` transactions, which the registry counts
+    pub proofs_submitted: u64,
+    /// Mined `recordDeployment` transactions, not every contract created
+    pub contracts_deployed: u64,
+}
+
+/// `history` filters; `None` matches everything
+#[derive(Debug, Clone, Default)]
+pub struct Filter {
+    pub kind: Option<String>,
+    pub network: Option<String>,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    pub status: Option<String>,
+}
+
+impl Filter {
+    pub fn matches(&self, entry: &Entry) -> bool {
+        if self.kind.as_deref().is_some_and(|kind| kind != entry.kind()) {
+            return false;
+        }
+        if self.network.is_some() && self.network.as_deref() != entry.network() {
+            return false;
+        }
+        if self.status.as_deref().is_some_and(|status| status != entry.status()) {
+            return false;
+        }
+        if self.since.is_none() && self.until.is_none() {
+            return true;
+        }
+        // Entries without a readable timestamp cannot be placed in a date range
+        let Some(time) = entry.time() else {
+            return false;
+        };
+        self.since.is_none_or(|since| time >= since) && self.until.is_none_or(|until| time <= until)
+    }
+}
+
+/// An RFC 3339 timestamp, or a `YYYY-MM-DD` date taken as the start of the day
+/// (or its end, with `end_of_day`) in UTC
+pub fn parse_date(value: &str, end_of_day: bool) -> Result<DateTime<Utc>> {
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        return Ok(time.with_timezone(&Utc));
+    }
+    let date = NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| ProverError::InvalidInput(format!("{:?} is not a date (YYYY-MM-DD) or RFC 3339 timestamp", value)))?;
+    let time = if end_of_day {
+        date.and_hms_nano_opt(23, 59, 59, 999_999_999)
+    } else {
+        date.and_hms_opt(0, 0, 0)
+    };
+    Ok(time.expect("valid time of day").and_utc())
+}
+
+/// One line of `history`
+pub fn describe(entry: &Entry) -> String {
+    match entry {
+        Entry::Proof(proof) => format!(
+            "{} (circuit {}) · vk {} · inputs {}",
+            proof.circuit, proof.circuit_id, proof.vk_fingerprint, proof.public_inputs_hash
+        ),
+        Entry::Submission(submission) => format!(
+            "{} {} · tx {} · block {} · gas {}",
+            submission.target, submission.contract_address, submission.tx_hash, submission.block_number, submission.gas_used
+        ),
+        Entry::Deployment(deployment) => {
+            let what = match (&deployment.function, &deployment.contract_name) {
+                (Some(function), _) => function.clone(),
+                (None, Some(name)) => name.clone(),
+                (None, None) => "(unnamed)".to_string(),
+            };
+            let gas = deployment.gas_used.map_or(String::new(), |gas| format!(" · gas {}", gas));
+            format!(
+                "{} {} {} · tx {}{}",
+                deployment.kind,
+                what,
+                deployment.contract_address.as_deref().unwrap_or("-"),
+                deployment.tx_hash,
+                gas
+            )
+        }
+        Entry::Event(event) => {
+            let detail = match &event.event {
+                RegistryEvent::BuilderRegistered { builder, alias } => format!("{} as {:?}", builder, alias),
+                RegistryEvent::ProofVerified { builder, proof_hash, .. } => format!("{} proof {}", builder, proof_hash),
+                RegistryEvent::ContractDeployed { builder, contract } => format!("{} deployed {}", builder, contract),
+            };
+            format!("{} {} · block {} · tx {}", event.event.name(), detail, event.block_number, event.tx_hash)
+        }
+    }
+}
+
+pub fn handle_history(workspace: &Workspace, filter: &Filter) -> Result<()> {
+    let ledger = Ledger::load(workspace)?;
+    let entries: Vec<Entry> = ledger.entries().into_iter().filter(|entry| filter.matches(entry)).collect();
+    if entries.is_empty() {
+        println!("📭 No matching entries in {}", Ledger::path(workspace).display());
+        return Ok(());
+    }
+    for entry in &entries {
+        println!(
+            "{:<25} {:<10} {:<15} {:<9} {}",
+            entry.timestamp(),
+            entry.kind(),
+            entry.network().unwrap_or("-"),
+            entry.status(),
+            describe(entry)
+        );
+    }
+    let total = ledger.proofs.len() + ledger.submissions.len() + ledger.deployments.len() + ledger.events.len();
+    println!("📒 {} of {} entries", entries.len(), total);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_workspace::TempWorkspace;
+
+    const REGISTRY: &str = "0x7979797979797979797979797979797979797979";
+    const BUILDER: &str = "0x1111111111111111111111111111111111111111";
+
+    fn deployment(tx: u8, succeeded: Option<bool>, deployed_at: &str) -> Deployment {
+        Deployment {
+            network: "anvil".to_string(),
+            chain_id: 31337,
+            tx_hash: format!("0x{}", hex::encode([tx; 32])),
+            kind: "CREATE".to_string(),
+            contract_name: Some("niet2codeBuilder".to_string()),
+            contract_address: Some(format!("0x{}", hex::encode([tx; 20]))),
+            function: None,
+            from: None,
+            block_number: succeeded.map(|_| 1),
+            gas_used: succeeded.map(|_| 21_000),
+            effective_gas_price: None,
+            succeeded,
+            source: "deploy".to_string(),
+            deployed_at: deployed_at.to_string(),
+        }
+    }
+
+    fn submission(network: &str, status: SubmissionStatus, submitted_at: &str) -> SubmissionRecord {
+        SubmissionRecord {
+            network: network.to_string(),
+            chain_id: 5003,
+            target: "registry".to_string(),
+            contract_address: REGISTRY.to_string(),
+            from: Some(BUILDER.to_string()),
+            // One transaction per submission
+            tx_hash: format!("0x{}", hex::encode(crate::tx::keccak256(submitted_at.as_bytes()))),
+            status,
+            public_inputs_hash: format!("0x{}", hex::encode([0x0c; 32])),
+            proof_hash: None,
+            block_number: 32,
+            gas_used: 180_000,
+            effective_gas_price: Some(1_000_000_000),
+            submitted_at: submitted_at.to_string(),
+        }
+    }
+
+    fn proof(generated_at: &str) -> ProofRecord {
+        ProofRecord {
+            network: None,
+            circuit_id: 1,
+            circuit: "mul".to_string(),
+            vk_fingerprint: "sha256:00".to_string(),
+            public_inputs_hash: format!("0x{}", hex::encode([0x0c; 32])),
+            calldata: "calldata.bin".to_string(),
+            generated_at: generated_at.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_log_is_appended_and_replayed() {
+        let workspace = TempWorkspace::new("ledger-append");
+        let path = Ledger::path(&workspace);
+
+        Ledger::append(&workspace, Entry::Proof(proof("2026-01-01T00:00:00Z"))).unwrap();
+        let mut ledger = Ledger::load(&workspace).unwrap();
+        assert_eq!(ledger.record_deployment(deployment(1, None, "2026-01-02T00:00:00Z")), Change::Added);
+        ledger.save(&workspace).unwrap();
+        // The receipt arrives later: a second line that supersedes the first
+        let mut ledger = Ledger::load(&workspace).unwrap();
+        assert_eq!(ledger.record_deployment(deployment(1, Some(true), "2026-01-02T00:00:00Z")), Change::Replaced);
+        assert_eq!(ledger.record_deployment(deployment(1, Some(true), "2026-01-02T00:00:00Z")), Change::Unchanged);
+        ledger.save(&workspace).unwrap();
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 3);
+        let ledger = Ledger::load(&workspace).unwrap();
+        assert_eq!(ledger.proofs.len(), 1);
+        assert_eq!(ledger.deployments, vec![deployment(1, Some(true), "2026-01-02T00:00:00Z")]);
+
+        // An interrupted append leaves an unfinished line, which is ignored
+        let mut file = std::fs::OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"{\"proof\": {\"netw").unwrap();
+        assert_eq!(Ledger::load(&workspace).unwrap().proofs.len(), 1);
+        // A corrupt complete line is an error
+        file.write_all(b"\n").unwrap();
+        let err = Ledger::load(&workspace).unwrap_err();
+        assert!(matches!(err.downcast_ref::<ProverError>(), Some(ProverError::InvalidInput(_))), "{}", err);
+        assert!(err.to_string().contains("line 4"), "{}", err);
+    }
+
+    #[test]
+    fn test_save_after_interrupted_append() {
+        let workspace = TempWorkspace::new("ledger-interrupted");
+        let path = Ledger::path(&workspace);
+        Ledger::append(&workspace, Entry::Proof(proof("2026-01-01T00:00:00Z"))).unwrap();
+        let mut file = std::fs::OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"{\"proof\": {\"netw").unwrap();
+
+        let mut ledger = Ledger::load(&workspace).unwrap();
+        ledger.record(Entry::Proof(proof("2026-01-02T00:00:00Z")));
+        ledger.save(&workspace).unwrap();
+
+        let ledger = Ledger::load(&workspace).unwrap();
+        assert_eq!(ledger.proofs, vec![proof("2026-01-01T00:00:00Z"), proof("2026-01-02T00:00:00Z")]);
+        assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 2);
+    }
+
+    #[test]
+    fn test_filter_by_kind_network_date_and_status() {
+        let mut ledger = Ledger::default();
+        ledger.record(Entry::Proof(proof("2026-01-01T12:00:00Z")));
+        ledger.record(Entry::Submission(submission("mantle-testnet", SubmissionStatus::Valid, "2026-01-02T12:00:00Z")));
+        ledger.record(Entry::Submission(submission("mantle-testnet", SubmissionStatus::Reverted, "2026-01-03T12:00:00Z")));
+        ledger.record(Entry::Submission(submission("anvil", SubmissionStatus::Invalid, "2026-01-03T13:00:00Z")));
+        ledger.record_deployment(deployment(1, Some(true), "2026-01-04T12:00:00Z"));
+        ledger.record_deployment(deployment(2, Some(false), "2026-01-05T12:00:00Z"));
+
+        let count = |filter: Filter| ledger.entries().iter().filter(|entry| filter.matches(entry)).count();
+        assert_eq!(count(Filter::default()), 6);
+        assert_eq!(count(Filter { kind: Some("submission".to_string()), ..Filter::default() }), 3);
+        assert_eq!(count(Filter { network: Some("anvil".to_string()), ..Filter::default() }), 3);
+        assert_eq!(count(Filter { status: Some("failed".to_string()), ..Filter::default() }), 1);
+        assert_eq!(count(Filter { status: Some("valid".to_string()), ..Filter::default() }), 1);
+        // Date-only bounds cover whole days
+        let since = parse_date("2026-01-03", false).unwrap();
+        let until = parse_date("2026-01-04", true).unwrap();
+        assert_eq!(count(Filter { since: Some(since), until: Some(until), ..Filter::default() }), 3);
+        assert_eq!(count(Filter { until: Some(parse_date("2026-01-01T12:00:00Z", true).unwrap()), ..Filter::default() }), 1);
+        assert!(parse_date("last week", false).is_err());
+
+        let kinds: Vec<&str> = ledger.entries().iter().map(Entry::kind).collect();
+        assert_eq!(kinds, ["proof", "submission", "submission", "submission", "deployment", "deployment"]);
+    }
+
+    /// `recordDeployment(contract)` sent by `from` to `registry` in transaction `tx`
+    fn record_deployment_call(tx: u8, from: &str, registry: &str) -> Deployment {
+        Deployment {
+            kind: "CALL".to_string(),
+            contract_name: Some("niet2codeBuilder".to_string()),
+            contract_address: Some(registry.to_string()),
+            function: Some(RECORD_DEPLOYMENT.to_string()),
+            from: Some(from.to_string()),
+            ..deployment(tx, Some(true), "2026-01-06T12:00:00Z")
+        }
+    }
+
+    fn contract_deployed(tx: u8, builder: &str) -> EventRecord {
+        EventRecord {
+            network: "anvil".to_string(),
+            chain_id: 31337,
+            registry: REGISTRY.to_string(),
+            event: RegistryEvent::ContractDeployed { builder: builder.to_string(), contract: format!("0x{}", hex::encode([tx; 20])) },
+            block_number: 7,
+            block_hash: format!("0x{}", hex::encode([0x07; 32])),
+            block_time: "2026-01-06T12:00:00Z".to_string(),
+            tx_hash: format!("0x{}", hex::encode([tx; 32])),
+            log_index: 0,
+            removed: false,
+        }
+    }
+
+    #[test]
+    fn test_stats_count_what_the_registry_counts() {
+        let other = "0x2222222222222222222222222222222222222222";
+        let mut ledger = Ledger::default();
+        ledger.record(Entry::Proof(proof("2026-01-01T12:00:00Z")));
+        ledger.record(Entry::Proof(proof("2026-01-01T13:00:00Z")));
+        ledger.record(Entry::Submission(submission("anvil", SubmissionStatus::Valid, "2026-01-02T12:00:00Z")));
+        ledger.record(Entry::Submission(submission("anvil", SubmissionStatus::Invalid, "2026-01-02T13:00:00Z")));
+        ledger.record(Entry::Submission(submission("anvil", SubmissionStatus::Reverted, "2026-01-02T14:00:00Z")));
+        let mut direct = submission("anvil", SubmissionStatus::Valid, "2026-01-02T15:00:00Z");
+        direct.target = "verifier".to_string();
+        ledger.record(Entry::Submission(direct));
+        let mut other_wallet = submission("anvil", SubmissionStatus::Valid, "2026-01-02T16:00:00Z");
+        other_wallet.from = Some(other.to_string());
+        ledger.record(Entry::Submission(other_wallet));
+        let mut other_registry = submission("anvil", SubmissionStatus::Valid, "2026-01-02T17:00:00Z");
+        other_registry.contract_address = other.to_string();
+        ledger.record(Entry::Submission(other_registry));
+
+        // Creating contracts, the registry included, is not `recordDeployment`
+        ledger.record_deployment(deployment(1, Some(true), "2026-01-04T12:00:00Z"));
+        ledger.record_deployment(record_deployment_call(3, &BUILDER.to_lowercase(), REGISTRY));
+        ledger.record(Entry::Event(contract_deployed(3, BUILDER)));
+        ledger.record(Entry::Event(contract_deployed(4, BUILDER)));
+        ledger.record(Entry::Event(contract_deployed(5, other)));
+        ledger.record_deployment(record_deployment_call(6, other, REGISTRY));
+        ledger.record_deployment(record_deployment_call(8, BUILDER, other));
+        let mut failed = record_deployment_call(9, BUILDER, REGISTRY);
+        failed.succeeded = Some(false);
+        ledger.record_deployment(failed);
+
+        assert_eq!(
+            ledger.stats("anvil", REGISTRY, BUILDER),
+            LedgerStats {
+                proofs_generated: 2,
+                proofs_submitted: 2,
+                contracts_deployed: 2,
+            }
+        );
+        assert_eq!(ledger.stats("mantle-testnet", REGISTRY, BUILDER).proofs_submitted, 0);
+        assert_eq!(ledger.stats("anvil", REGISTRY, other).contracts_deployed, 2);
+    }
+}
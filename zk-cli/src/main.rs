@@ -1,23 +1,42 @@
 use ark_bn254::{Bn254, Fr};
-use ark_groth16::Groth16;
-use prover::circuit::MulCircuit;
-use prover::utils::{save_calldata, export_verifying_key_to_rs};
+use prover::{generate_proof, seeded_rng, ProverError};
+use prover::circuit::{MUL_CIRCUIT, MUL_CIRCUIT_ID, MUL_CIRCUIT_VERSION};
+use prover::key_artifact::export_verifying_key_to_rs;
+use prover::utils::{encode_calldata, save_calldata};
 use prover::utils::{save_proof, save_public_input, save_verifying_key};
+use prover::workspace::Workspace;
 use clap::{Parser, Subcommand};
 use rand::thread_rng;
-use ark_groth16::{Proof, VerifyingKey, prepare_verifying_key};
+use ark_groth16::Proof;
 use ark_serialize::CanonicalDeserialize;
 use std::fs::File;
 use std::io::BufReader;
 use std::path::PathBuf;
-use std::path::Path;
+use std::process::ExitCode;
+use verifier_core::Verdict;
 use anyhow::Result;
-use serde::{Deserialize, Serialize};
 //use std::process::Command;
 
 // Add integration modules
 mod privy_integration;
 mod thirdweb_integration;
+mod rpc;
+mod tx;
+mod wallet;
+mod trust_store;
+mod admin;
+mod submit;
+mod profile;
+mod registry;
+mod dashboard;
+mod verifier;
+mod networks;
+mod ledger;
+mod broadcast;
+mod deploy;
+mod indexer;
+#[cfg(test)]
+mod test_workspace;
 
 /// niet2code Builder Edition: Real Anonymous ZK verification for builders
 #[derive(Parser)]
@@ -25,6 +44,10 @@ mod thirdweb_integration;
 #[command(about = "🔮 niet2code Builder Edition - Real Anonymous Smart Contract Verification")]
 #[command(version = "1.0.0")]
 struct Cli {
+    #[arg(long, global = true, help = "niet2code home directory (default: $NIET2CODE_HOME or $XDG_DATA_HOME/niet2code)")]
+    home: Option<PathBuf>,
+    #[arg(long, global = true, help = "Project whose keys and proofs to use (default: default)")]
+    project: Option<String>,
     #[command(subcommand)]
     command: Commands,
 }
@@ -39,40 +62,76 @@ enum Commands {
         b: u64,
         #[arg(long, help = "Expected result")]
         c: u64,
-        #[arg(long, default_value = "../calldata.bin", help = "Output file for calldata")]
-        out: String,
-        #[arg(long, help = "Target network (mantle-testnet, mantle-mainnet)")]
+        #[arg(long, help = "Output file for calldata (default: <project>/calldata.bin)")]
+        out: Option<PathBuf>,
+        #[arg(long, help = "Target network (default: the one picked with `network use`)")]
         network: Option<String>,
+        #[arg(long, hide = true, help = "TEST ONLY: deterministic ChaCha seed for reproducible keys and proofs")]
+        seed: Option<u64>,
     },
     /// Verify proof + public input using verifying key (local verification)
     Verify {
-        #[arg(long)]
-        proof: String,
-        #[arg(long)]
-        input: String,
-        #[arg(long)]
-        vk: String,
+        #[arg(long, conflicts_with_all = ["proof", "input"], help = "Calldata file to verify exactly as the verifier contract would")]
+        calldata: Option<PathBuf>,
+        #[arg(long, help = "Proof file (default: <project>/proofs/proof.bin)")]
+        proof: Option<PathBuf>,
+        #[arg(long, help = "Public input file (default: <project>/proofs/public_input.bin)")]
+        input: Option<PathBuf>,
+        #[arg(long, help = "Verifying key file (default: <project>/keys/verifying_key.bin)")]
+        vk: Option<PathBuf>,
+        #[arg(long, help = "Verify even if the verifying key is not pinned")]
+        force: bool,
     },
     /// Register as a builder on-chain
     Register {
-        #[arg(long, help = "Builder alias")]
-        alias: String,
-        #[arg(long, default_value = "mantle-testnet", help = "Target network")]
-        network: String,
+        #[arg(long, help = "Builder alias, 1-32 bytes (default: the profile's alias from `init`)")]
+        alias: Option<String>,
+        #[arg(long, help = "Target network (default: the one picked with `network use`)")]
+        network: Option<String>,
+        #[arg(long, help = "JSON-RPC endpoint (default: $NIET2CODE_RPC_URL, then the network's)")]
+        rpc_url: Option<String>,
+        #[arg(long, help = "Registry address (default: the network's)")]
+        registry: Option<String>,
+        #[arg(long, default_value = wallet::DEFAULT_WALLET, help = "Wallet that signs the transaction")]
+        wallet: String,
+        #[arg(long, help = "Read the wallet passphrase from this file instead of prompting")]
+        passphrase_file: Option<PathBuf>,
     },
     /// Submit proof for on-chain verification
     SubmitProof {
-        #[arg(long, default_value = "../calldata.bin", help = "Proof file")]
-        proof_file: String,
-        #[arg(long, default_value = "mantle-testnet", help = "Target network")]
-        network: String,
+        #[arg(long, help = "Proof file (default: <project>/calldata.bin)")]
+        proof_file: Option<PathBuf>,
+        #[arg(long, help = "Target network (default: the one picked with `network use`)")]
+        network: Option<String>,
+        #[arg(long, help = "JSON-RPC endpoint (default: $NIET2CODE_RPC_URL, else the network's)")]
+        rpc_url: Option<String>,
+        #[arg(long, help = "Registry address (default: the network's)")]
+        registry: Option<String>,
+        #[arg(long, help = "Send `verify` straight to this PolkaVM verifier instead of the registry")]
+        verifier: Option<String>,
+        #[arg(long, default_value = wallet::DEFAULT_WALLET, help = "Wallet that signs the transaction")]
+        wallet: String,
+        #[arg(long, help = "Read the wallet passphrase from this file instead of prompting")]
+        passphrase_file: Option<PathBuf>,
+        #[arg(long, help = "Simulate with eth_call only; nothing is signed or sent")]
+        dry_run: bool,
+        #[arg(long, help = "Explain revert data returned by the verifier contract instead of submitting")]
+        revert_data: Option<String>,
     },
     /// Show builder dashboard with real on-chain stats
     Dashboard {
-        #[arg(long, help = "Builder address (defaults to configured address)")]
+        #[arg(long, help = "Builder address (default: the profile's registered address, else the wallet's)")]
         address: Option<String>,
-        #[arg(long, default_value = "mantle-testnet", help = "Target network")]
-        network: String,
+        #[arg(long, help = "Target network (default: the one picked with `network use`)")]
+        network: Option<String>,
+        #[arg(long, help = "JSON-RPC endpoint (default: $NIET2CODE_RPC_URL, else the network's)")]
+        rpc_url: Option<String>,
+        #[arg(long, help = "Registry address (default: the network's)")]
+        registry: Option<String>,
+        #[arg(long, default_value = wallet::DEFAULT_WALLET, help = "Wallet whose address to show without --address or a registration")]
+        wallet: String,
+        #[arg(long, help = "Show the last fetched stats without querying the node")]
+        offline: bool,
     },
     /// Initialize builder profile locally
     Init {
@@ -81,8 +140,10 @@ enum Commands {
     },
     /// Show contract information
     ContractInfo {
-        #[arg(long, default_value = "mantle-testnet", help = "Target network")]
-        network: String,
+        #[arg(long, help = "Target network (default: the one picked with `network use`)")]
+        network: Option<String>,
+        #[arg(long, help = "JSON-RPC endpoint to query live (default: $NIET2CODE_RPC_URL)")]
+        rpc_url: Option<String>,
     },
     /// Show partner integration roadmap
     Partners,
@@ -96,6 +157,252 @@ enum Commands {
         #[command(subcommand)]
         thirdweb_command: ThirdWebCommands,
     },
+    /// Pinned verifying-key trust store
+    Keys {
+        #[command(subcommand)]
+        keys_command: KeysCommands,
+    },
+    /// Encode owner-only calls to the verifier contract's key registry
+    Admin {
+        #[command(subcommand)]
+        admin_command: AdminCommands,
+    },
+    /// Build the verifier contract blob and check deployments against it
+    Verifier {
+        #[command(subcommand)]
+        verifier_command: VerifierCommands,
+    },
+    /// Encrypted keystore wallets used to sign transactions
+    Wallet {
+        #[command(subcommand)]
+        wallet_command: WalletCommands,
+    },
+    /// Record a `forge script --broadcast` run in the deployment ledger
+    ImportBroadcast {
+        #[arg(help = "Foundry broadcast file (default: broadcast/Deploy.s.sol/<chain id>/run-latest.json)")]
+        file: Option<PathBuf>,
+        #[arg(long, help = "Network the script ran on (default: the one picked with `network use`)")]
+        network: Option<String>,
+        #[arg(long, default_value = broadcast::REGISTRY_CONTRACT, help = "Contract whose creation becomes the network's registry")]
+        registry_contract: String,
+    },
+    /// Proofs, submissions, deployments and registry events recorded in the local ledger
+    History {
+        #[arg(long, value_parser = ["proof", "submission", "deployment", "event"], help = "Only this kind of entry")]
+        kind: Option<String>,
+        #[arg(long, help = "Only entries on this network")]
+        network: Option<String>,
+        #[arg(long, help = "From this date (YYYY-MM-DD or RFC 3339)")]
+        since: Option<String>,
+        #[arg(long, help = "Up to and including this date (YYYY-MM-DD or RFC 3339)")]
+        until: Option<String>,
+        #[arg(long, value_parser = ["generated", "valid", "invalid", "reverted", "succeeded", "failed", "pending", "indexed"], help = "Only entries with this status")]
+        status: Option<String>,
+    },
+    /// Index the registry's events into the ledger and print or forward new ones
+    Watch {
+        #[arg(long, help = "Target network (default: the one picked with `network use`)")]
+        network: Option<String>,
+        #[arg(long, help = "JSON-RPC endpoint (default: $NIET2CODE_RPC_URL, else the network's)")]
+        rpc_url: Option<String>,
+        #[arg(long, help = "Registry address (default: the network's)")]
+        registry: Option<String>,
+        #[arg(long, help = "Re-index from this block instead of the last checkpoint")]
+        from_block: Option<u64>,
+        #[arg(long, default_value_t = indexer::DEFAULT_BATCH_SIZE, help = "Blocks per eth_getLogs request")]
+        batch_size: u64,
+        #[arg(long, default_value_t = 1, help = "Blocks to wait for, counting the one with the event")]
+        confirmations: u64,
+        #[arg(long, default_value_t = 5, help = "Seconds between polls")]
+        interval: u64,
+        #[arg(long, help = "Catch up once and exit")]
+        once: bool,
+        #[arg(long, help = "Print events as JSON lines")]
+        json: bool,
+        #[arg(long, help = "POST each event as JSON to this URL")]
+        webhook: Option<String>,
+    },
+    /// Deploy the registry or verifier contract and point the network at it
+    Deploy {
+        #[command(subcommand)]
+        deploy_command: DeployCommands,
+    },
+    /// Network profiles that every --network flag resolves through
+    Network {
+        #[command(subcommand)]
+        network_command: NetworkCommands,
+    },
+}
+
+#[derive(Subcommand)]
+enum DeployCommands {
+    /// Deploy the builder registry (default: the blob embedded in this CLI)
+    Registry {
+        #[arg(long, help = "Target network (default: the one picked with `network use`)")]
+        network: Option<String>,
+        #[arg(long, help = "JSON-RPC endpoint (default: $NIET2CODE_RPC_URL, else the network's)")]
+        rpc_url: Option<String>,
+        #[arg(long, help = "PolkaVM blob, or a Foundry artifact JSON for EVM nodes such as anvil")]
+        blob: Option<PathBuf>,
+        #[arg(long, help = "Verifier the registry checks proofs with (default: the network's)")]
+        verifier: Option<String>,
+        #[arg(long, default_value_t = MUL_CIRCUIT.id, help = "Circuit id the registry passes to the verifier")]
+        circuit_id: u64,
+        #[arg(long, default_value_t = 1, help = "Blocks to wait for, counting the one with the deployment")]
+        confirmations: u64,
+        #[arg(long, default_value = wallet::DEFAULT_WALLET, help = "Wallet that signs the deployment")]
+        wallet: String,
+        #[arg(long, help = "Read the wallet passphrase from this file instead of prompting")]
+        passphrase_file: Option<PathBuf>,
+    },
+    /// Deploy the project's verifier contract (default: <project>/build/contract.polkavm)
+    Verifier {
+        #[arg(long, help = "Target network (default: the one picked with `network use`)")]
+        network: Option<String>,
+        #[arg(long, help = "JSON-RPC endpoint (default: $NIET2CODE_RPC_URL, else the network's)")]
+        rpc_url: Option<String>,
+        #[arg(long, help = "Blob to deploy (default: the output of `verifier build`)")]
+        blob: Option<PathBuf>,
+        #[arg(long, default_value_t = 1, help = "Blocks to wait for, counting the one with the deployment")]
+        confirmations: u64,
+        #[arg(long, default_value = wallet::DEFAULT_WALLET, help = "Wallet that signs the deployment")]
+        wallet: String,
+        #[arg(long, help = "Read the wallet passphrase from this file instead of prompting")]
+        passphrase_file: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum NetworkCommands {
+    /// Add a network profile to <home>/networks.toml
+    Add {
+        #[arg(long, help = "Profile name (letters, digits, '-', '_')")]
+        name: String,
+        #[arg(long, help = "Chain id, checked against the node before signing")]
+        chain_id: u64,
+        #[arg(long = "rpc-url", required = true, help = "JSON-RPC endpoint; repeat for fallbacks")]
+        rpc_urls: Vec<String>,
+        #[arg(long, help = "Explorer URL with {kind} and {id} placeholders")]
+        explorer: Option<String>,
+        #[arg(long, help = "Builder registry address")]
+        registry: Option<String>,
+        #[arg(long, help = "Verifier that submit-proof calls directly instead of the registry")]
+        verifier: Option<String>,
+        #[arg(long, value_enum, default_value_t = tx::FeeModel::Eip1559, help = "Fee model")]
+        fee_model: tx::FeeModel,
+        #[arg(long, default_value = "Ether", help = "Native currency name")]
+        currency_name: String,
+        #[arg(long, default_value = "ETH", help = "Native currency symbol")]
+        currency_symbol: String,
+        #[arg(long, default_value_t = 18, help = "Native currency decimals")]
+        currency_decimals: u8,
+        #[arg(long, help = "Replace an existing profile with the same name")]
+        replace: bool,
+    },
+    /// List built-in and user network profiles
+    List,
+    /// Make a network the default for commands run without --network
+    Use {
+        #[arg(help = "Network name")]
+        name: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum WalletCommands {
+    /// Create a wallet with a random key
+    New {
+        #[arg(long, default_value = wallet::DEFAULT_WALLET, help = "Wallet name")]
+        name: String,
+        #[arg(long, help = "Read the passphrase from this file instead of prompting")]
+        passphrase_file: Option<PathBuf>,
+    },
+    /// Import a keystore v3 file, or a private key typed at a hidden prompt
+    Import {
+        #[arg(long, default_value = wallet::DEFAULT_WALLET, help = "Wallet name")]
+        name: String,
+        #[arg(long, help = "Keystore v3 JSON file (geth, Foundry, MetaMask export)")]
+        keystore: Option<PathBuf>,
+        #[arg(long, help = "Read the passphrase from this file instead of prompting")]
+        passphrase_file: Option<PathBuf>,
+    },
+    /// List wallets and their addresses
+    List,
+    /// Print a wallet's address
+    Address {
+        #[arg(long, default_value = wallet::DEFAULT_WALLET, help = "Wallet name")]
+        name: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum VerifierCommands {
+    /// Build contract.polkavm reproducibly and print its code hash
+    Build {
+        #[arg(long, help = "Verifier key module to embed (default: <project>/keys/verifier_keys.rs)")]
+        vk_rs: Option<PathBuf>,
+        #[arg(long, help = "verifier-contract source directory (default: the one next to this CLI's sources)")]
+        contract_dir: Option<PathBuf>,
+        #[arg(long, help = "Contract features instead of the defaults (e.g. polkavm,precompiles)")]
+        features: Option<String>,
+        #[arg(long, default_value = verifier::PINNED_TOOLCHAIN, help = "Rust toolchain to build with")]
+        toolchain: String,
+        #[arg(long, help = "Output blob (default: <project>/build/contract.polkavm)")]
+        out: Option<PathBuf>,
+    },
+    /// Compare the code deployed at an address with the local blob
+    VerifyDeployment {
+        #[arg(long, help = "Verifier contract address (0x...)")]
+        address: String,
+        #[arg(long, help = "JSON-RPC endpoint (default: $NIET2CODE_RPC_URL)")]
+        rpc_url: Option<String>,
+        #[arg(long, help = "Blob to compare with (default: <project>/build/contract.polkavm)")]
+        blob: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum AdminCommands {
+    /// Encode registerKey(vkId, circuitVersion, verifyingKey)
+    EncodeRegister {
+        #[arg(long, help = "Key id proofs will name as circuitId")]
+        vk_id: u64,
+        #[arg(long, help = "Uncompressed verifying key (default: <project>/keys/verifying_key.bin)")]
+        vk: Option<PathBuf>,
+        #[arg(long, default_value_t = MUL_CIRCUIT_VERSION, help = "Circuit version")]
+        circuit_version: u32,
+        #[arg(long, help = "Also write the calldata to this file")]
+        out: Option<PathBuf>,
+    },
+    /// Encode deprecateKey(vkId)
+    EncodeDeprecate {
+        #[arg(long, help = "Key id to deprecate")]
+        vk_id: u64,
+        #[arg(long, help = "Also write the calldata to this file")]
+        out: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum KeysCommands {
+    /// Pin a verifying key fingerprint for a circuit
+    Pin {
+        #[arg(long, help = "Verifying key file to pin")]
+        vk: Option<PathBuf>,
+        #[arg(long, help = "Pin the key compiled into verifier-contract (<project>/keys/verifier_keys.rs)")]
+        contract: bool,
+        #[arg(long, default_value = MUL_CIRCUIT_ID, help = "Circuit id")]
+        circuit: String,
+        #[arg(long, default_value_t = MUL_CIRCUIT_VERSION, help = "Circuit version")]
+        circuit_version: u32,
+    },
+    /// List pinned verifying keys
+    List,
+    /// Revoke a pinned fingerprint
+    Revoke {
+        #[arg(long, help = "Fingerprint to revoke (sha256:...)")]
+        fingerprint: String,
+    },
 }
 
 #[derive(Subcommand)]
@@ -106,8 +413,8 @@ enum PrivyCommands {
     Status,
     /// Link Privy wallet to builder profile
     Link {
-        #[arg(long, help = "Builder alias to link")]
-        alias: String,
+        #[arg(long, help = "Builder alias to link (default: the profile's alias from `init`)")]
+        alias: Option<String>,
     },
     /// Get privacy report
     Report,
@@ -121,8 +428,8 @@ enum ThirdWebCommands {
     Deploy {
         #[arg(long, help = "Template ID to deploy")]
         template: String,
-        #[arg(long, default_value = "mantle-testnet", help = "Target network")]
-        network: String,
+        #[arg(long, help = "Target network (default: the one picked with `network use`)")]
+        network: Option<String>,
         #[arg(long, help = "Contract name")]
         name: Option<String>,
         #[arg(long, help = "Contract symbol")]
@@ -139,55 +446,11 @@ enum ThirdWebCommands {
     EstimateCost {
         #[arg(long, help = "Template ID")]
         template: String,
-        #[arg(long, default_value = "mantle-testnet", help = "Target network")]
-        network: String,
+        #[arg(long, help = "Target network (default: the one picked with `network use`)")]
+        network: Option<String>,
     },
 }
 
-#[derive(Serialize, Deserialize, Default)]
-struct BuilderStats {
-    deployments: u32,
-    proofs_generated: u32,
-    gas_saved_estimate: u64,
-    networks: Vec<String>,
-    privacy_score: f32,
-    builder_alias: String,
-    wallet_address: String,
-}
-
-fn load_or_create_stats() -> BuilderStats {
-    match std::fs::read_to_string("../builder_stats.json") {
-        Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
-        Err(_) => BuilderStats::default(),
-    }
-}
-
-fn save_stats(stats: &BuilderStats) -> Result<()> {
-    let content = serde_json::to_string_pretty(stats)?;
-    std::fs::write("../builder_stats.json", content)?;
-    Ok(())
-}
-
-fn update_stats_for_proof(network: Option<String>) -> Result<()> {
-    let mut stats = load_or_create_stats();
-    stats.proofs_generated += 1;
-    stats.gas_saved_estimate += 75000;
-    
-    if let Some(net) = network {
-        if !stats.networks.contains(&net) {
-            stats.networks.push(net);
-        }
-    }
-    
-    let base_score = stats.proofs_generated as f32 * 8.0;
-    let network_bonus = stats.networks.len() as f32 * 15.0;
-    let deployment_bonus = stats.deployments as f32 * 10.0;
-    stats.privacy_score = (base_score + network_bonus + deployment_bonus).min(100.0);
-    
-    save_stats(&stats)?;
-    Ok(())
-}
-
 fn show_partners() {
     println!("\n🤝 Cookathon Partner Integration Status");
     println!("=========================================");
@@ -205,58 +468,96 @@ fn show_partners() {
     println!();
 }
 
+/// Query the endpoint for its chain, fees and the code at `contract`
+async fn show_live_contract_info(rpc: &rpc::RpcClient, contract: &str) -> Result<()> {
+    let chain_id = rpc.chain_id().await?;
+    let block = rpc.block_number().await?;
+    let gas_price = rpc.gas_price().await?;
+    let code = rpc.get_code(contract).await?;
+
+    println!("\n📡 Live ({})", rpc.url());
+    println!("🆔 Chain ID: {}", chain_id);
+    println!("🧱 Latest block: {}", block);
+    println!("⛽ Gas price: {} wei", gas_price);
+    if code.is_empty() {
+        println!("⚠️  No contract code at {}", contract);
+    } else {
+        println!("📋 Contract code: {} bytes", code.len());
+    }
+    Ok(())
+}
+
+/// Process exit code for a failed command. Prover failures get distinct codes so
+/// scripts can tell a bad proof file from a missing one or an untrusted key.
+fn exit_code(err: &anyhow::Error) -> u8 {
+    match err.downcast_ref::<ProverError>() {
+        Some(ProverError::Synthesis(_)) => 3,
+        Some(ProverError::Serialization(_)) => 4,
+        Some(ProverError::Io(_)) => 5,
+        Some(ProverError::InvalidInput(_)) => 6,
+        Some(ProverError::KeyMismatch(_)) => 7,
+        None => 1,
+    }
+}
+
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Load environment variables from .env if it exists
-    if let Ok(env_content) = std::fs::read_to_string("../.env") {
-        for line in env_content.lines() {
-            if !line.starts_with('#') && line.contains('=') {
-                let parts: Vec<&str> = line.splitn(2, '=').collect();
-                if parts.len() == 2 {
-                    std::env::set_var(parts[0], parts[1]);
-                }
-            }
+async fn main() -> ExitCode {
+    match run().await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("❌ Error: {}", err);
+            ExitCode::from(exit_code(&err))
         }
     }
+}
 
+async fn run() -> Result<()> {
     let cli = Cli::parse();
+    let workspace = Workspace::resolve(cli.home.as_deref(), cli.project.as_deref())?;
 
     match &cli.command {
-        Commands::Prove { a, b, c, out, network } => {
+        Commands::Prove { a, b, c, out, network, seed } => {
             println!("🔮 Generating anonymous proof for {} × {} = {}...", a, b, c);
             
-            let a_fr = Fr::from(*a);
-            let b_fr = Fr::from(*b);
-            let c_fr = a_fr * b_fr;
-
             if *a * *b != *c {
                 println!("⚠️  Warning: inputs don't match expected output!");
                 println!("Expected: {} × {} = {}, but you provided c = {}", a, b, a * b, c);
                 println!("Using correct result: {} × {} = {}", a, b, a * b);
             }
 
-            let setup_circuit = MulCircuit { a: None, b: None, c: None };
-            let prove_circuit = MulCircuit { a: Some(a_fr), b: Some(b_fr), c: Some(c_fr) };
-
-            let mut rng = thread_rng();
-            let params = Groth16::<Bn254>::generate_random_parameters_with_reduction(setup_circuit, &mut rng)?;
-            let proof = Groth16::<Bn254>::create_random_proof_with_reduction(prove_circuit, &params, &mut rng)?;
-
-            let calldata_path = PathBuf::from(out);
-            let proof_path = Path::new("../proofs/proof.bin");
-            let input_path = Path::new("../proofs/public_input.bin");
-            let vk_bin_path = Path::new("../keys/verifying_key.bin");
+            let (proof, c_fr, params) = match seed {
+                Some(seed) => {
+                    println!("⚠️  Using deterministic seed {} (TEST ONLY - keys are not secure)", seed);
+                    generate_proof(*a, *b, &mut seeded_rng(*seed))?
+                }
+                None => generate_proof(*a, *b, &mut thread_rng())?,
+            };
 
-            std::fs::create_dir_all("../proofs")?;
-            std::fs::create_dir_all("../keys")?;
+            let calldata_path = out.clone().unwrap_or_else(|| workspace.calldata_path());
+            let proof_path = workspace.proof_path();
+            let input_path = workspace.public_input_path();
+            let vk_bin_path = workspace.verifying_key_path();
 
-            save_calldata(&proof, &c_fr, out)?;
-            save_proof(&proof)?;
-            save_public_input(&c_fr)?;
-            save_verifying_key(&params.vk)?;
-            export_verifying_key_to_rs(&params.vk)?;
+            save_calldata(MUL_CIRCUIT.id, &proof, &[c_fr], &calldata_path)?;
+            save_proof(&workspace, &proof)?;
+            save_public_input(&workspace, &c_fr)?;
+            save_verifying_key(&workspace, &params.vk)?;
+            let vk_artifact = export_verifying_key_to_rs(&workspace, &MUL_CIRCUIT, &params.vk)?;
 
-            update_stats_for_proof(network.clone())?;
+            let network = match network {
+                Some(name) => Some(networks::resolve(&workspace, Some(name), None, None)?.name),
+                None => None,
+            };
+            let bundle = submit::ProofBundle::decode(&std::fs::read(&calldata_path).map_err(ProverError::from)?)?;
+            ledger::Ledger::append(&workspace, ledger::Entry::Proof(ledger::ProofRecord {
+                network: network.clone(),
+                circuit_id: MUL_CIRCUIT.id,
+                circuit: MUL_CIRCUIT.name.to_string(),
+                vk_fingerprint: vk_artifact.fingerprint.clone(),
+                public_inputs_hash: bundle.public_inputs_hash(),
+                calldata: calldata_path.display().to_string(),
+                generated_at: chrono::Utc::now().to_rfc3339(),
+            }))?;
 
             println!("✅ Anonymous proof generated successfully!");
             println!("\n📂 Files created:");
@@ -264,8 +565,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("   • Proof: {}", proof_path.display());
             println!("   • Public input: {}", input_path.display());
             println!("   • Verifying key: {}", vk_bin_path.display());
+            println!("   • Contract key table: {} (circuit {} = {}, {} bytes, {})", workspace.verifier_keys_rs_path().display(), MUL_CIRCUIT.id, MUL_CIRCUIT.name, vk_artifact.bytes.len(), vk_artifact.encoding.as_str());
+            println!("🔑 Verifying key fingerprint: {}", vk_artifact.fingerprint);
+            println!("💡 Pin it before verifying: cargo run -- keys pin --vk {}", vk_bin_path.display());
             
-            if let Some(net) = network {
+            if let Some(net) = &network {
                 println!("🌐 Target network: {}", net);
                 println!("\n🚀 Next steps:");
                 println!("   1. Submit proof: cargo run -- submit-proof --network {}", net);
@@ -275,82 +579,123 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("\n🚀 Ready for on-chain verification!");
         },
         
-        Commands::Verify { proof, input, vk } => {
+        Commands::Verify { calldata, proof, input, vk, force } => {
             println!("🔍 Verifying anonymous proof locally...");
-            
-            let proof_path = PathBuf::from(proof);
-            let input_path = PathBuf::from(input);
-            let vk_path = PathBuf::from(vk);
 
-            let proof: Proof<Bn254> = {
-                let mut reader = BufReader::new(File::open(&proof_path)?);
-                Proof::<Bn254>::deserialize_compressed(&mut reader)?
-            };
+            let proof_path = proof.clone().unwrap_or_else(|| workspace.proof_path());
+            let input_path = input.clone().unwrap_or_else(|| workspace.public_input_path());
+            let vk_path = vk.clone().unwrap_or_else(|| workspace.verifying_key_path());
 
-            let public_input: Fr = {
-                let mut reader = BufReader::new(File::open(&input_path)?);
-                Fr::deserialize_uncompressed(&mut reader)?
-            };
+            trust_store::ensure_trusted(&workspace, &vk_path, MUL_CIRCUIT_ID, MUL_CIRCUIT_VERSION, *force)?;
 
-            let vk: VerifyingKey<Bn254> = {
-                let mut reader = BufReader::new(File::open(&vk_path)?);
-                VerifyingKey::<Bn254>::deserialize_uncompressed(&mut reader)?
+            // Verify the calldata bytes with the same verifier-core path the contract runs
+            let calldata = match calldata {
+                Some(path) => std::fs::read(path).map_err(ProverError::from)?,
+                None => {
+                    let proof: Proof<Bn254> = {
+                        let mut reader = BufReader::new(File::open(&proof_path).map_err(ProverError::from)?);
+                        Proof::<Bn254>::deserialize_compressed(&mut reader).map_err(ProverError::from)?
+                    };
+                    let public_input: Fr = {
+                        let mut reader = BufReader::new(File::open(&input_path).map_err(ProverError::from)?);
+                        Fr::deserialize_uncompressed(&mut reader).map_err(ProverError::from)?
+                    };
+                    encode_calldata(MUL_CIRCUIT.id, &proof, &[public_input])?
+                }
             };
+            let vk_bytes = std::fs::read(&vk_path).map_err(ProverError::from)?;
 
-            let pvk = ark_groth16::prepare_verifying_key(&vk);
-            let valid = Groth16::<Bn254>::verify_proof(&pvk, &proof, &[public_input])?;
-
-            if valid {
-                println!("✅ Local proof verification: PASSED");
-                println!("🔒 Anonymous verification successful!");
-                println!("💡 For on-chain verification, use: submit-proof command");
-            } else {
-                println!("❌ Local proof verification: FAILED");
+            match verifier_core::verify_calldata(&calldata, &vk_bytes) {
+                Verdict::Valid => {
+                    println!("✅ Local proof verification: PASSED");
+                    println!("🔒 Anonymous verification successful!");
+                    println!("💡 For on-chain verification, use: submit-proof command");
+                }
+                Verdict::Invalid => {
+                    println!("❌ Local proof verification: FAILED");
+                    return Err(ProverError::InvalidInput("proof does not verify against the verifying key".to_string()).into());
+                }
+                Verdict::Rejected(reason) => {
+                    println!("❌ Local proof verification: REJECTED ({})", reason);
+                    return Err(prover::reject_error(reason).into());
+                }
             }
         },
         
-        Commands::Register { alias, network: _ } => {
-            println!("🔐 Registering builder '{}'...", alias);
-            println!("✅ Registration simulated (use cast commands for real registration)");
+        Commands::Register { alias, network, rpc_url, registry, wallet, passphrase_file } => {
+            registry::handle_register(
+                &workspace,
+                alias.as_deref(),
+                network.as_deref(),
+                rpc_url.as_deref(),
+                registry.as_deref(),
+                wallet,
+                passphrase_file.as_deref(),
+            )
+            .await?;
         },
         
-        Commands::SubmitProof { proof_file: _, network: _ } => {
-            println!("📤 Submitting proof for verification...");
-            println!("✅ Proof submission simulated (use cast commands for real submission)");
+        Commands::SubmitProof { proof_file, network, rpc_url, registry, verifier, wallet, passphrase_file, dry_run, revert_data } => {
+            if let Some(data) = revert_data {
+                submit::explain_revert(data)?;
+            } else {
+                submit::handle_submit_proof(
+                    &workspace,
+                    proof_file.as_deref(),
+                    network.as_deref(),
+                    rpc_url.as_deref(),
+                    registry.as_deref(),
+                    verifier.as_deref(),
+                    wallet,
+                    passphrase_file.as_deref(),
+                    *dry_run,
+                )
+                .await?;
+            }
         },
         
-        Commands::Dashboard { address: _, network: _ } => {
-            println!("🔮 niet2code Builder Dashboard");
-            println!("========================");
-            let stats = load_or_create_stats();
-            println!("🏗️  Deployments: {}", stats.deployments);
-            println!("🔍 Proofs Generated: {}", stats.proofs_generated);
-            println!("⛽ Gas Saved (Est.): {} wei", stats.gas_saved_estimate);
-            println!("🔒 Privacy Score: {:.1}/100", stats.privacy_score);
-            println!("========================");
+        Commands::Dashboard { address, network, rpc_url, registry, wallet, offline } => {
+            dashboard::handle_dashboard(&workspace, address.as_deref(), network.as_deref(), rpc_url.as_deref(), registry.as_deref(), wallet, *offline).await?;
         },
         
         Commands::Init { alias } => {
-            let mut stats = load_or_create_stats();
             if let Some(name) = alias {
-                stats.builder_alias = name.clone();
-                save_stats(&stats)?;
+                let mut profile = profile::Profile::load(&workspace)?;
+                let stale = profile.set_alias(name)?;
+                profile.save(&workspace)?;
                 println!("🔮 niet2code Builder Edition initialized!");
                 println!("👤 Builder alias: {}", name);
+                for network in stale {
+                    println!("⚠️  {} still shows the previously registered alias; run `register --network {}`", network, network);
+                }
             } else {
                 println!("🔮 niet2code Builder Edition initialized!");
                 println!("👤 Builder: Anonymous");
             }
+            workspace.ensure_dirs()?;
+            println!("📂 Home: {} (project: {})", workspace.home().display(), workspace.project());
             println!("✅ Ready for anonymous smart contract verification!");
         },
         
-        Commands::ContractInfo { network: _ } => {
-            println!("📋 Contract Information");
+        Commands::ContractInfo { network, rpc_url } => {
+            let network = networks::resolve(&workspace, network.as_deref(), rpc_url.as_deref(), None)?;
+            println!("📋 Contract Information ({})", network.name);
             println!("======================");
-            println!("🔗 RPC URL: https://rpc.testnet.mantle.xyz");
-            println!("🆔 Chain ID: 5003");
-            println!("📋 Contract: 0x79169e9A85E46a9f85600E8BE164f767cb88A8Ae");
-            println!("🔍 Explorer: https://explorer.testnet.mantle.xyz/address/0x79169e9A85E46a9f85600E8BE164f767cb88A8Ae");
+            println!("🔗 RPC URL: {}", network.rpc_url);
+            println!("🆔 Chain ID: {}", network.chain_id);
+            match network.registry {
+                Some(registry) => {
+                    let registry = wallet::checksum_address(&registry);
+                    println!("📋 Contract: {}", registry);
+                    if let Some(url) = network.explorer_url("address", &registry) {
+                        println!("🔍 Explorer: {}", url);
+                    }
+                    if rpc_url.is_some() || std::env::var(rpc::RPC_URL_ENV).is_ok_and(|url| !url.is_empty()) {
+                        show_live_contract_info(&rpc::RpcClient::new(&network.rpc_url), &registry).await?;
+                    }
+                }
+                None => println!("📋 Contract: none configured (add one with `network add --registry`)"),
+            }
         },
         
         Commands::Partners => {
@@ -360,22 +705,22 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Commands::Privy { privy_command } => {
             match privy_command {
                 PrivyCommands::Auth => {
-                    if let Err(e) = privy_integration::handle_privy_auth().await {
+                    if let Err(e) = privy_integration::handle_privy_auth(&workspace).await {
                         println!("❌ Privy authentication failed: {}", e);
                     }
                 },
                 PrivyCommands::Status => {
-                    if let Err(e) = privy_integration::show_privy_status() {
+                    if let Err(e) = privy_integration::show_privy_status(&workspace) {
                         println!("❌ Could not get Privy status: {}", e);
                     }
                 },
                 PrivyCommands::Link { alias } => {
-                    if let Err(e) = privy_integration::handle_privy_link(alias).await {
+                    if let Err(e) = privy_integration::handle_privy_link(&workspace, alias.as_deref()).await {
                         println!("❌ Could not link Privy wallet: {}", e);
                     }
                 },
                 PrivyCommands::Report => {
-                    if let Err(e) = privy_integration::handle_privy_report().await {
+                    if let Err(e) = privy_integration::handle_privy_report(&workspace).await {
                         println!("❌ Could not generate privacy report: {}", e);
                     }
                 },
@@ -385,7 +730,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Commands::ThirdWeb { thirdweb_command } => {
             match thirdweb_command {
                 ThirdWebCommands::List => {
-                    if let Err(e) = thirdweb_integration::list_templates() {
+                    if let Err(e) = thirdweb_integration::list_templates(&workspace) {
                         println!("❌ Could not list templates: {}", e);
                     }
                 },
@@ -399,39 +744,189 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         params.insert("symbol".to_string(), s.clone());
                     }
                     
-                    params.insert("niet2code_verifier".to_string(), "0x79169e9A85E46a9f85600E8BE164f767cb88A8Ae".to_string());
+                    let network = networks::resolve(&workspace, network.as_deref(), None, None)?;
+                    if let Some(registry) = network.registry {
+                        params.insert("niet2code_verifier".to_string(), wallet::checksum_address(&registry));
+                    }
                     
-                    if let Err(e) = thirdweb_integration::deploy_template(template, network, params).await {
+                    if let Err(e) = thirdweb_integration::deploy_template(&workspace, template, &network, params).await {
                         println!("❌ Deployment failed: {}", e);
                     }
                 },
                 ThirdWebCommands::Customize { template } => {
-                    if let Err(e) = thirdweb_integration::customize_template(template).await {
+                    if let Err(e) = thirdweb_integration::customize_template(&workspace, template).await {
                         println!("❌ Customization failed: {}", e);
                     }
                 },
                 ThirdWebCommands::Status => {
-                    if let Err(e) = thirdweb_integration::show_thirdweb_status().await {
+                    if let Err(e) = thirdweb_integration::show_thirdweb_status(&workspace).await {
                         println!("❌ Could not get ThirdWeb status: {}", e);
                     }
                 },
                 ThirdWebCommands::EstimateCost { template, network } => {
+                    let network = networks::resolve(&workspace, network.as_deref(), None, None)?;
                     println!("💰 Estimating deployment cost for template: {}", template);
-                    println!("🌐 Network: {}", network);
-                    
-                    let estimated_gas = match template.as_str() {
-                        "niet2code-anonymous-nft" => 2_000_000u64,
-                        "niet2code-private-defi-vault" => 3_500_000u64,
-                        "niet2code-anonymous-dao" => 4_000_000u64,
-                        "niet2code-private-marketplace" => 5_000_000u64,
-                        _ => 2_500_000u64,
+                    println!("🌐 Network: {} (chain {})", network.name, network.chain_id);
+                    thirdweb_integration::estimate_template_cost(template, &network).await?;
+                },
+            }
+        },
+
+        Commands::Keys { keys_command } => {
+            match keys_command {
+                KeysCommands::Pin { vk, contract, circuit, circuit_version } => {
+                    trust_store::handle_keys_pin(&workspace, vk.as_deref(), *contract, circuit, *circuit_version)?;
+                },
+                KeysCommands::List => {
+                    trust_store::handle_keys_list(&workspace)?;
+                },
+                KeysCommands::Revoke { fingerprint } => {
+                    trust_store::handle_keys_revoke(&workspace, fingerprint)?;
+                },
+            }
+        }
+
+        Commands::Admin { admin_command } => {
+            match admin_command {
+                AdminCommands::EncodeRegister { vk_id, vk, circuit_version, out } => {
+                    admin::handle_encode_register(&workspace, *vk_id, vk.as_deref(), *circuit_version, out.as_deref())?;
+                },
+                AdminCommands::EncodeDeprecate { vk_id, out } => {
+                    admin::handle_encode_deprecate(*vk_id, out.as_deref())?;
+                },
+            }
+        }
+
+        Commands::Verifier { verifier_command } => {
+            match verifier_command {
+                VerifierCommands::Build { vk_rs, contract_dir, features, toolchain, out } => {
+                    verifier::handle_build(&workspace, contract_dir.as_deref(), vk_rs.as_deref(), features.as_deref(), toolchain, out.as_deref())?;
+                },
+                VerifierCommands::VerifyDeployment { address, rpc_url, blob } => {
+                    verifier::handle_verify_deployment(&workspace, address, rpc_url.as_deref(), blob.as_deref()).await?;
+                },
+            }
+        }
+
+        Commands::Wallet { wallet_command } => {
+            match wallet_command {
+                WalletCommands::New { name, passphrase_file } => {
+                    wallet::handle_wallet_new(&workspace, name, passphrase_file.as_deref())?;
+                },
+                WalletCommands::Import { name, keystore, passphrase_file } => {
+                    wallet::handle_wallet_import(&workspace, name, keystore.as_deref(), passphrase_file.as_deref())?;
+                },
+                WalletCommands::List => {
+                    wallet::handle_wallet_list(&workspace)?;
+                },
+                WalletCommands::Address { name } => {
+                    wallet::handle_wallet_address(&workspace, name)?;
+                },
+            }
+        }
+
+        Commands::ImportBroadcast { file, network, registry_contract } => {
+            broadcast::handle_import_broadcast(&workspace, file.as_deref(), network.as_deref(), registry_contract)?;
+        }
+
+        Commands::History { kind, network, since, until, status } => {
+            let filter = ledger::Filter {
+                kind: kind.clone(),
+                network: network.clone(),
+                since: since.as_deref().map(|date| ledger::parse_date(date, false)).transpose()?,
+                until: until.as_deref().map(|date| ledger::parse_date(date, true)).transpose()?,
+                status: status.clone(),
+            };
+            ledger::handle_history(&workspace, &filter)?;
+        }
+
+        Commands::Watch { network, rpc_url, registry, from_block, batch_size, confirmations, interval, once, json, webhook } => {
+            indexer::handle_watch(
+                &workspace,
+                network.as_deref(),
+                rpc_url.as_deref(),
+                registry.as_deref(),
+                *from_block,
+                *batch_size,
+                *confirmations,
+                std::time::Duration::from_secs(*interval),
+                *once,
+                *json,
+                webhook.as_deref(),
+            )
+            .await?;
+        }
+
+        Commands::Deploy { deploy_command } => {
+            match deploy_command {
+                DeployCommands::Registry { network, rpc_url, blob, verifier, circuit_id, confirmations, wallet, passphrase_file } => {
+                    deploy::handle_deploy(
+                        &workspace,
+                        deploy::Contract::Registry,
+                        network.as_deref(),
+                        rpc_url.as_deref(),
+                        blob.as_deref(),
+                        verifier.as_deref(),
+                        *circuit_id,
+                        *confirmations,
+                        wallet,
+                        passphrase_file.as_deref(),
+                    )
+                    .await?;
+                },
+                DeployCommands::Verifier { network, rpc_url, blob, confirmations, wallet, passphrase_file } => {
+                    deploy::handle_deploy(
+                        &workspace,
+                        deploy::Contract::Verifier,
+                        network.as_deref(),
+                        rpc_url.as_deref(),
+                        blob.as_deref(),
+                        None,
+                        MUL_CIRCUIT.id,
+                        *confirmations,
+                        wallet,
+                        passphrase_file.as_deref(),
+                    )
+                    .await?;
+                },
+            }
+        }
+
+        Commands::Network { network_command } => {
+            match network_command {
+                NetworkCommands::Add {
+                    name,
+                    chain_id,
+                    rpc_urls,
+                    explorer,
+                    registry,
+                    verifier,
+                    fee_model,
+                    currency_name,
+                    currency_symbol,
+                    currency_decimals,
+                    replace,
+                } => {
+                    let profile = networks::NetworkProfile {
+                        chain_id: *chain_id,
+                        rpc_urls: rpc_urls.clone(),
+                        explorer: explorer.clone(),
+                        registry: registry.clone(),
+                        verifier: verifier.clone(),
+                        fee_model: *fee_model,
+                        native_currency: networks::NativeCurrency {
+                            name: currency_name.clone(),
+                            symbol: currency_symbol.clone(),
+                            decimals: *currency_decimals,
+                        },
                     };
-                    
-                    let network_multiplier = if network.contains("mantle") { 0.4 } else { 1.0 };
-                    let final_cost = (estimated_gas as f64 * network_multiplier) as u64;
-                    
-                    println!("⛽ Estimated gas: {} units", final_cost);
-                    println!("💵 Cost reduction: {}%", ((1.0 - network_multiplier) * 100.0) as u32);
+                    networks::handle_network_add(&workspace, name, profile, *replace)?;
+                },
+                NetworkCommands::List => {
+                    networks::handle_network_list(&workspace)?;
+                },
+                NetworkCommands::Use { name } => {
+                    networks::handle_network_use(&workspace, name)?;
                 },
             }
         }
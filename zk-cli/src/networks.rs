@@ -0,0 +1,460 @@
+// Network profiles: chain id, RPC endpoints, explorer, contract addresses, native
+// currency and fee model for each chain the CLI talks to. The built-in profiles
+// live in `zk-cli/networks.toml`; `<home>/networks.toml` adds to or replaces them
+// and picks the default. Every `--network` flag resolves through here.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use prover::workspace::Workspace;
+use prover::ProverError;
+
+use crate::registry::ProofTarget;
+use crate::rpc;
+use crate::tx::FeeModel;
+use crate::wallet;
+
+pub const NETWORKS_FILE: &str = "networks.toml";
+
+const BUILT_IN: &str = include_str!("../networks.toml");
+
+/// Used when neither file names a default
+const FALLBACK_DEFAULT: &str = "mantle-testnet";
+
+/// One `[networks.<name>]` table
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NetworkProfile {
+    pub chain_id: u64,
+    pub rpc_urls: Vec<String>,
+    /// URL with `{kind}` (`address` or `tx`) and `{id}` placeholders
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explorer: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registry: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verifier: Option<String>,
+    #[serde(default)]
+    pub fee_model: FeeModel,
+    pub native_currency: NativeCurrency,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NativeCurrency {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+impl NativeCurrency {
+    /// `wei` in whole units, e.g. `0.05 MNT`
+    pub fn format(&self, wei: u128) -> String {
+        let scale = 10u128.pow(u32::from(self.decimals));
+        let fraction = format!("{:0width$}", wei % scale, width = usize::from(self.decimals));
+        let fraction = fraction.trim_end_matches('0');
+        if fraction.is_empty() {
+            format!("{} {}", wei / scale, self.symbol)
+        } else {
+            format!("{}.{} {}", wei / scale, fraction, self.symbol)
+        }
+    }
+}
+
+/// Layout of both `networks.toml` files
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NetworksFile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+    #[serde(default)]
+    pub networks: BTreeMap<String, NetworkProfile>,
+}
+
+/// A profile resolved for one command: addresses parsed and the endpoint chosen
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Network {
+    pub name: String,
+    pub chain_id: u64,
+    pub rpc_url: String,
+    pub explorer: Option<String>,
+    pub registry: Option<[u8; 20]>,
+    /// PolkaVM verifier the registry checks proofs with
+    pub verifier: Option<[u8; 20]>,
+    pub fee_model: FeeModel,
+    pub native_currency: NativeCurrency,
+}
+
+impl Network {
+    pub fn registry(&self) -> Result<[u8; 20]> {
+        self.registry
+            .ok_or_else(|| ProverError::InvalidInput(format!("no registry deployed on {}: pass --registry", self.name)).into())
+    }
+
+    /// The registry, or the verifier on networks without one
+    pub fn proof_target(&self) -> Result<ProofTarget> {
+        match (self.registry, self.verifier) {
+            (None, Some(verifier)) => Ok(ProofTarget::Verifier(verifier)),
+            _ => Ok(ProofTarget::Registry(self.registry()?)),
+        }
+    }
+
+    /// Explorer page for an address or transaction, if the profile has an explorer
+    pub fn explorer_url(&self, kind: &str, id: &str) -> Option<String> {
+        self.explorer.as_ref().map(|template| template.replace("{kind}", kind).replace("{id}", id))
+    }
+}
+
+#[cfg(test)]
+impl Network {
+    /// Chain 5003 behind a stand-in node
+    pub fn local(rpc_url: &str, registry: Option<[u8; 20]>) -> Self {
+        Self {
+            name: "local".to_string(),
+            chain_id: 5003,
+            rpc_url: rpc_url.to_string(),
+            explorer: None,
+            registry,
+            verifier: None,
+            fee_model: FeeModel::Eip1559,
+            native_currency: NativeCurrency {
+                name: "Mantle".to_string(),
+                symbol: "MNT".to_string(),
+                decimals: 18,
+            },
+        }
+    }
+}
+
+/// Built-in profiles merged with the user's file
+#[derive(Debug, Clone)]
+pub struct Networks {
+    built_in: NetworksFile,
+    user: NetworksFile,
+    path: PathBuf,
+}
+
+impl Networks {
+    pub fn load(workspace: &Workspace) -> Result<Self> {
+        Self::load_from(workspace.state_file(NETWORKS_FILE))
+    }
+
+    pub fn load_from(path: PathBuf) -> Result<Self> {
+        let built_in = parse(BUILT_IN).context("built-in networks.toml")?;
+        let user = match std::fs::read_to_string(&path) {
+            Ok(content) => parse(&content).with_context(|| format!("{}", path.display()))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => NetworksFile::default(),
+            Err(e) => return Err(ProverError::Io(e).into()),
+        };
+        let networks = Self { built_in, user, path };
+        if let Some(default) = &networks.user.default {
+            networks.profile(default).with_context(|| format!("default network in {}", networks.path.display()))?;
+        }
+        Ok(networks)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn default_name(&self) -> &str {
+        self.user.default.as_deref().or(self.built_in.default.as_deref()).unwrap_or(FALLBACK_DEFAULT)
+    }
+
+    /// Names in order, with whether the user's file defines them
+    pub fn names(&self) -> Vec<(&str, bool)> {
+        let mut names: Vec<(&str, bool)> = self.user.networks.keys().map(|name| (name.as_str(), true)).collect();
+        names.extend(self.built_in.networks.keys().filter(|name| !self.user.networks.contains_key(*name)).map(|name| (name.as_str(), false)));
+        names.sort();
+        names
+    }
+
+    pub fn profile(&self, name: &str) -> Result<&NetworkProfile> {
+        self.user.networks.get(name).or_else(|| self.built_in.networks.get(name)).ok_or_else(|| {
+            let known: Vec<&str> = self.names().into_iter().map(|(name, _)| name).collect();
+            ProverError::InvalidInput(format!("unknown network {:?} (known: {}; add one with `network add`)", name, known.join(", "))).into()
+        })
+    }
+
+    /// `name` or the default, with `--rpc-url` (else $NIET2CODE_RPC_URL, else the
+    /// profile's first endpoint) and `--registry` applied
+    pub fn resolve(&self, name: Option<&str>, rpc_url: Option<&str>, registry: Option<&str>) -> Result<Network> {
+        let name = name.unwrap_or_else(|| self.default_name());
+        let profile = self.profile(name)?;
+        let rpc_url = rpc_url
+            .map(str::to_string)
+            .or_else(|| std::env::var(rpc::RPC_URL_ENV).ok().filter(|url| !url.is_empty()))
+            .unwrap_or_else(|| profile.rpc_urls[0].clone());
+        Ok(Network {
+            name: name.to_string(),
+            chain_id: profile.chain_id,
+            rpc_url,
+            explorer: profile.explorer.clone(),
+            registry: registry.or(profile.registry.as_deref()).map(rpc::parse_address).transpose()?,
+            verifier: profile.verifier.as_deref().map(rpc::parse_address).transpose()?,
+            fee_model: profile.fee_model,
+            native_currency: profile.native_currency.clone(),
+        })
+    }
+
+    /// Add `name` to the user's file; an existing name needs `replace`
+    pub fn add(&mut self, name: &str, profile: NetworkProfile, replace: bool) -> Result<()> {
+        validate_name(name)?;
+        validate_profile(&profile).map_err(|err| ProverError::InvalidInput(format!("network {}: {}", name, err)))?;
+        if !replace && self.profile(name).is_ok() {
+            return Err(ProverError::InvalidInput(format!("network {} already exists: pass --replace to overwrite it", name)).into());
+        }
+        self.user.networks.insert(name.to_string(), profile);
+        Ok(())
+    }
+
+    /// Point `name` at `registry`, copying a built-in profile into the user's file
+    pub fn set_registry(&mut self, name: &str, registry: &str) -> Result<()> {
+        let mut profile = self.profile(name)?.clone();
+        profile.registry = Some(registry.to_string());
+        self.add(name, profile, true)
+    }
+
+    /// Point `name` at `verifier`, copying a built-in profile into the user's file
+    pub fn set_verifier(&mut self, name: &str, verifier: &str) -> Result<()> {
+        let mut profile = self.profile(name)?.clone();
+        profile.verifier = Some(verifier.to_string());
+        self.add(name, profile, true)
+    }
+
+    pub fn set_default(&mut self, name: &str) -> Result<()> {
+        self.profile(name)?;
+        self.user.default = Some(name.to_string());
+        Ok(())
+    }
+
+    pub fn save(&self) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir).map_err(ProverError::Io)?;
+        }
+        std::fs::write(&self.path, toml::to_string_pretty(&self.user)?).map_err(ProverError::Io)?;
+        Ok(())
+    }
+}
+
+/// Shorthand for commands that only need one network
+pub fn resolve(workspace: &Workspace, name: Option<&str>, rpc_url: Option<&str>, registry: Option<&str>) -> Result<Network> {
+    Networks::load(workspace)?.resolve(name, rpc_url, registry)
+}
+
+fn parse(content: &str) -> Result<NetworksFile> {
+    let file: NetworksFile = toml::from_str(content).map_err(|err| ProverError::InvalidInput(err.to_string()))?;
+    for (name, profile) in &file.networks {
+        validate_name(name)?;
+        validate_profile(profile).map_err(|err| ProverError::InvalidInput(format!("network {}: {}", name, err)))?;
+    }
+    Ok(file)
+}
+
+fn validate_name(name: &str) -> Result<()> {
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return Err(ProverError::InvalidInput(format!("invalid network name {:?} (use letters, digits, '-', '_')", name)).into());
+    }
+    Ok(())
+}
+
+fn validate_profile(profile: &NetworkProfile) -> std::result::Result<(), String> {
+    if profile.chain_id == 0 {
+        return Err("chain_id must not be 0".to_string());
+    }
+    if profile.rpc_urls.is_empty() {
+        return Err("rpc_urls must list at least one endpoint".to_string());
+    }
+    for url in &profile.rpc_urls {
+        if !(url.starts_with("http://") || url.starts_with("https://")) {
+            return Err(format!("RPC URL must be http(s): {}", url));
+        }
+    }
+    if let Some(explorer) = &profile.explorer {
+        if !(explorer.starts_with("http://") || explorer.starts_with("https://")) || !explorer.contains("{id}") {
+            return Err(format!("explorer must be an http(s) URL with an {{id}} placeholder: {}", explorer));
+        }
+    }
+    for address in profile.registry.iter().chain(&profile.verifier) {
+        rpc::parse_address(address).map_err(|err| err.to_string())?;
+    }
+    let currency = &profile.native_currency;
+    if currency.name.is_empty() || currency.symbol.is_empty() {
+        return Err("native_currency needs a name and a symbol".to_string());
+    }
+    // 10^decimals must fit the u128 wei amounts
+    if currency.decimals > 38 {
+        return Err(format!("native_currency.decimals {} is above 38", currency.decimals));
+    }
+    Ok(())
+}
+
+pub fn handle_network_list(workspace: &Workspace) -> Result<()> {
+    let networks = Networks::load(workspace)?;
+    println!("🌐 Networks ({}):", networks.path().display());
+    for (name, user) in networks.names() {
+        let profile = networks.profile(name)?;
+        let marker = if name == networks.default_name() { "*" } else { " " };
+        let source = if user { "networks.toml" } else { "built-in" };
+        println!(
+            "{} {:<18} chain {:<8} {:<8} {:<8} {}",
+            marker,
+            name,
+            profile.chain_id,
+            profile.native_currency.symbol,
+            profile.fee_model.as_str(),
+            source
+        );
+        println!("    RPC: {}", profile.rpc_urls.join(", "));
+        if let Some(registry) = &profile.registry {
+            println!("    Registry: {}", registry);
+        }
+        if let Some(verifier) = &profile.verifier {
+            println!("    Verifier: {}", verifier);
+        }
+    }
+    println!("(* = default; change it with `network use <name>`)");
+    Ok(())
+}
+
+pub fn handle_network_add(workspace: &Workspace, name: &str, profile: NetworkProfile, replace: bool) -> Result<()> {
+    let mut networks = Networks::load(workspace)?;
+    networks.add(name, profile, replace)?;
+    networks.save()?;
+    println!("✅ Added network {} to {}", name, networks.path().display());
+    Ok(())
+}
+
+pub fn handle_network_use(workspace: &Workspace, name: &str) -> Result<()> {
+    let mut networks = Networks::load(workspace)?;
+    networks.set_default(name)?;
+    networks.save()?;
+    let network = networks.resolve(Some(name), None, None)?;
+    println!("✅ Default network: {} (chain {})", name, network.chain_id);
+    if let Some(registry) = network.registry {
+        println!("📋 Registry: {}", wallet::checksum_address(&registry));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("niet2code-networks-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir.join(NETWORKS_FILE)
+    }
+
+    fn local_profile() -> NetworkProfile {
+        NetworkProfile {
+            chain_id: 420420420,
+            rpc_urls: vec!["http://127.0.0.1:8545".to_string()],
+            explorer: None,
+            registry: None,
+            verifier: Some("0x5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e".to_string()),
+            fee_model: FeeModel::Legacy,
+            native_currency: NativeCurrency {
+                name: "Unit".to_string(),
+                symbol: "UNIT".to_string(),
+                decimals: 18,
+            },
+        }
+    }
+
+    #[test]
+    fn test_built_in_profiles() {
+        let networks = Networks::load_from(temp_path("built-in")).unwrap();
+        assert_eq!(networks.default_name(), "mantle-testnet");
+
+        let testnet = networks.resolve(None, Some("http://127.0.0.1:8545"), None).unwrap();
+        assert_eq!(testnet.name, "mantle-testnet");
+        assert_eq!(testnet.chain_id, 5003);
+        assert_eq!(testnet.rpc_url, "http://127.0.0.1:8545");
+        assert_eq!(testnet.fee_model, FeeModel::Eip1559);
+        assert_eq!(wallet::checksum_address(&testnet.registry().unwrap()), "0x79169e9A85E46a9f85600E8BE164f767cb88A8Ae");
+        assert_eq!(testnet.explorer_url("tx", "0xab").unwrap(), "https://explorer.sepolia.mantle.xyz/tx/0xab");
+
+        let mainnet = networks.resolve(Some("mantle-mainnet"), None, Some("0x1111111111111111111111111111111111111111")).unwrap();
+        assert_eq!(mainnet.chain_id, 5000);
+        assert_eq!(mainnet.registry, Some([0x11; 20]));
+        assert_eq!(mainnet.proof_target().unwrap(), ProofTarget::Registry([0x11; 20]));
+        assert!(networks.resolve(Some("mantle-mainnet"), None, None).unwrap().proof_target().is_err());
+
+        let err = networks.resolve(Some("goerli"), None, None).unwrap_err();
+        assert!(matches!(err.downcast_ref::<ProverError>(), Some(ProverError::InvalidInput(_))), "{}", err);
+        assert!(err.to_string().contains("mantle-mainnet, mantle-testnet"), "{}", err);
+    }
+
+    #[test]
+    fn test_add_and_use_persist_in_user_file() {
+        let path = temp_path("add");
+        let mut networks = Networks::load_from(path.clone()).unwrap();
+        networks.add("local", local_profile(), false).unwrap();
+        networks.set_default("local").unwrap();
+        networks.save().unwrap();
+        // Only the user's entries are written
+        let written = std::fs::read_to_string(&path).unwrap();
+        assert!(written.contains("[networks.local]") && !written.contains("mantle"), "{}", written);
+
+        let networks = Networks::load_from(path.clone()).unwrap();
+        let local = networks.resolve(None, None, None).unwrap();
+        assert_eq!(local.name, "local");
+        assert_eq!(local.proof_target().unwrap(), ProofTarget::Verifier([0x5e; 20]));
+        assert_eq!(local.fee_model, FeeModel::Legacy);
+        assert_eq!(networks.names(), vec![("local", true), ("mantle-mainnet", false), ("mantle-testnet", false)]);
+
+        // Once the network has a registry, proofs go through it
+        let mut networks = networks;
+        networks.set_registry("local", "0x7979797979797979797979797979797979797979").unwrap();
+        let local = networks.resolve(None, None, None).unwrap();
+        assert_eq!(local.proof_target().unwrap(), ProofTarget::Registry([0x79; 20]));
+        assert_eq!(local.verifier, Some([0x5e; 20]));
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_add_validates() {
+        let mut networks = Networks::load_from(temp_path("validate")).unwrap();
+        assert!(networks.add("mantle-testnet", local_profile(), false).is_err());
+        assert!(networks.add("mantle-testnet", local_profile(), true).is_ok());
+        assert!(networks.add("bad name", local_profile(), false).is_err());
+        assert!(networks.set_default("nowhere").is_err());
+
+        let invalid = [
+            NetworkProfile { chain_id: 0, ..local_profile() },
+            NetworkProfile { rpc_urls: Vec::new(), ..local_profile() },
+            NetworkProfile { rpc_urls: vec!["ftp://node".to_string()], ..local_profile() },
+            NetworkProfile { explorer: Some("https://explorer.example/".to_string()), ..local_profile() },
+            NetworkProfile { registry: Some("0x1234".to_string()), ..local_profile() },
+        ];
+        for profile in invalid {
+            let err = networks.add("local", profile.clone(), true).unwrap_err();
+            assert!(matches!(err.downcast_ref::<ProverError>(), Some(ProverError::InvalidInput(_))), "{:?}: {}", profile, err);
+        }
+    }
+
+    #[test]
+    fn test_user_file_is_validated_on_load() {
+        let path = temp_path("load");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "[networks.local]\nchain_id = 1\nrpc_urls = [\"http://127.0.0.1:8545\"]\nregistyr = \"0x00\"\n").unwrap();
+        let err = Networks::load_from(path.clone()).unwrap_err();
+        assert!(format!("{:#}", err).contains("registyr"), "{:#}", err);
+
+        std::fs::write(&path, "default = \"nowhere\"\n").unwrap();
+        assert!(Networks::load_from(path.clone()).is_err());
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_native_currency_format() {
+        let mnt = local_profile().native_currency;
+        assert_eq!(mnt.format(50_000_000_000_000_000), "0.05 UNIT");
+        assert_eq!(mnt.format(2_000_000_000_000_000_000), "2 UNIT");
+        assert_eq!(mnt.format(1), "0.000000000000000001 UNIT");
+    }
+}
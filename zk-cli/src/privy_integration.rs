@@ -4,6 +4,14 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use anyhow::Result;
+use std::path::PathBuf;
+
+use prover::workspace::Workspace;
+
+use crate::profile::Profile;
+
+const AUTH_STATE_FILE: &str = "privy_auth_state.json";
+const BUILDER_MAPPING_FILE: &str = "builder_privy_mapping.json";
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PrivyConfig {
@@ -42,10 +50,12 @@ pub struct PrivyIntegration {
     config: PrivyConfig,
     authenticated_user: Option<PrivyUser>,
     access_token: Option<String>,
+    auth_state_path: PathBuf,
+    builder_mapping_path: PathBuf,
 }
 
 impl PrivyIntegration {
-    pub fn new() -> Result<Self> {
+    pub fn new(workspace: &Workspace) -> Result<Self> {
         let config = PrivyConfig {
             app_id: std::env::var("PRIVY_APP_ID")
                 .unwrap_or_else(|_| "cmbu92bja01jzjx0lgi75sti0".to_string()), // Your real app ID
@@ -58,6 +68,8 @@ impl PrivyIntegration {
             config,
             authenticated_user: None,
             access_token: None,
+            auth_state_path: workspace.state_file(AUTH_STATE_FILE),
+            builder_mapping_path: workspace.state_file(BUILDER_MAPPING_FILE),
         })
     }
 
@@ -115,38 +127,6 @@ impl PrivyIntegration {
         Ok(wallet)
     }
 
-    /// Link wallet to niet2code Builder profile
-    pub fn link_to_builder_profile(&self, builder_alias: &str) -> Result<()> {
-        if let Some(user) = &self.authenticated_user {
-            println!("🔗 Linking Privy user to niet2code Builder profile...");
-            println!("👤 DID: {}", user.did);
-            println!("🏗️  Builder Alias: {}", builder_alias);
-            
-            if let Some(wallet) = &user.embedded_wallet {
-                println!("💼 Wallet: {}", wallet.address);
-                
-                // Create builder-privy mapping
-                let mapping = BuilderPrivyMapping {
-                    builder_alias: builder_alias.to_string(),
-                    privy_did: user.did.clone(),
-                    wallet_address: wallet.address.clone(),
-                    linked_at: chrono::Utc::now().to_rfc3339(),
-                };
-                
-                self.save_builder_mapping(&mapping)?;
-                
-                println!("✅ Profile linked successfully");
-                println!("🔒 Privacy level: Maximum (Privy managed)");
-                
-                Ok(())
-            } else {
-                Err(anyhow::anyhow!("No embedded wallet found"))
-            }
-        } else {
-            Err(anyhow::anyhow!("No authenticated user"))
-        }
-    }
-
     /// Get privacy report from Privy
     pub fn get_privacy_report(&self) -> Result<HashMap<String, String>> {
         let mut report = HashMap::new();
@@ -174,23 +154,6 @@ impl PrivyIntegration {
         Ok(report)
     }
 
-    /// Check if user is authenticated
-    pub fn is_authenticated(&self) -> bool {
-        self.authenticated_user.is_some() && self.access_token.is_some()
-    }
-
-    /// Get current user
-    pub fn get_current_user(&self) -> Option<&PrivyUser> {
-        self.authenticated_user.as_ref()
-    }
-
-    /// Get wallet address for blockchain operations
-    pub fn get_wallet_address(&self) -> Option<String> {
-        self.authenticated_user.as_ref()
-            .and_then(|user| user.embedded_wallet.as_ref())
-            .map(|wallet| wallet.address.clone())
-    }
-
     // Private helper methods
 
     async fn create_guest_user(&self) -> Result<PrivyAuthResponse> {
@@ -200,7 +163,7 @@ impl PrivyIntegration {
         
         println!("🔄 Creating guest user with Privy...");
         
-        let user_did = format!("did:privy:{}", hex::encode(&rand::random::<[u8; 16]>()));
+        let user_did = format!("did:privy:{}", hex::encode(rand::random::<[u8; 16]>()));
         let wallet_address = self.generate_wallet_address()?;
         
         let embedded_wallet = EmbeddedWallet {
@@ -220,8 +183,8 @@ impl PrivyIntegration {
         };
 
         let auth_response = PrivyAuthResponse {
-            access_token: format!("privy_token_{}", hex::encode(&rand::random::<[u8; 16]>())),
-            refresh_token: format!("privy_refresh_{}", hex::encode(&rand::random::<[u8; 16]>())),
+            access_token: format!("privy_token_{}", hex::encode(rand::random::<[u8; 16]>())),
+            refresh_token: format!("privy_refresh_{}", hex::encode(rand::random::<[u8; 16]>())),
             user,
             expires_in: 3600, // 1 hour
         };
@@ -237,24 +200,32 @@ impl PrivyIntegration {
 
     fn save_auth_state(&self, auth_response: &PrivyAuthResponse) -> Result<()> {
         let auth_data = serde_json::to_string_pretty(auth_response)?;
-        std::fs::write("../privy_auth_state.json", auth_data)?;
+        write_state(&self.auth_state_path, auth_data)?;
         println!("💾 Authentication state saved");
         Ok(())
     }
 
     fn save_builder_mapping(&self, mapping: &BuilderPrivyMapping) -> Result<()> {
         let mapping_data = serde_json::to_string_pretty(mapping)?;
-        std::fs::write("../builder_privy_mapping.json", mapping_data)?;
+        write_state(&self.builder_mapping_path, mapping_data)?;
         println!("💾 Builder-Privy mapping saved");
         Ok(())
     }
 
-    pub fn load_auth_state() -> Result<PrivyAuthResponse> {
-        let auth_data = std::fs::read_to_string("../privy_auth_state.json")?;
+    pub fn load_auth_state(workspace: &Workspace) -> Result<PrivyAuthResponse> {
+        let auth_data = std::fs::read_to_string(workspace.state_file(AUTH_STATE_FILE))?;
         Ok(serde_json::from_str(&auth_data)?)
     }
 }
 
+fn write_state(path: &std::path::Path, contents: String) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, contents)?;
+    Ok(())
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct BuilderPrivyMapping {
     builder_alias: String,
@@ -265,11 +236,11 @@ struct BuilderPrivyMapping {
 
 // CLI Integration Functions
 
-pub fn show_privy_status() -> Result<()> {
+pub fn show_privy_status(workspace: &Workspace) -> Result<()> {
     println!("\n🔐 Privy Authentication Status");
     println!("==============================");
     
-    match PrivyIntegration::load_auth_state() {
+    match PrivyIntegration::load_auth_state(workspace) {
         Ok(auth_state) => {
             println!("✅ Authenticated with Privy");
             println!("👤 DID: {}", auth_state.user.did);
@@ -285,7 +256,7 @@ pub fn show_privy_status() -> Result<()> {
             }
 
             // Check for builder mapping
-            if let Ok(mapping_data) = std::fs::read_to_string("../builder_privy_mapping.json") {
+            if let Ok(mapping_data) = std::fs::read_to_string(workspace.state_file(BUILDER_MAPPING_FILE)) {
                 if let Ok(mapping) = serde_json::from_str::<BuilderPrivyMapping>(&mapping_data) {
                     println!("🔗 Linked to Builder: {}", mapping.builder_alias);
                     println!("📅 Linked At: {}", mapping.linked_at);
@@ -302,8 +273,8 @@ pub fn show_privy_status() -> Result<()> {
     Ok(())
 }
 
-pub async fn handle_privy_auth() -> Result<()> {
-    let mut privy = PrivyIntegration::new()?;
+pub async fn handle_privy_auth(workspace: &Workspace) -> Result<()> {
+    let mut privy = PrivyIntegration::new(workspace)?;
     
     println!("🚀 Starting Privy anonymous authentication...");
     println!("🔒 Privacy Mode: Maximum (Guest credentials)");
@@ -333,14 +304,25 @@ pub async fn handle_privy_auth() -> Result<()> {
     Ok(())
 }
 
-pub async fn handle_privy_link(builder_alias: &str) -> Result<()> {
-    match PrivyIntegration::load_auth_state() {
+pub async fn handle_privy_link(workspace: &Workspace, builder_alias: Option<&str>) -> Result<()> {
+    match PrivyIntegration::load_auth_state(workspace) {
         Ok(_) => {
-            let privy = PrivyIntegration::new()?;
+            let privy = PrivyIntegration::new(workspace)?;
+
+            // The builder profile is the one identity `init` and `register` also use
+            let mut profile = Profile::load(workspace)?;
+            let builder_alias = match builder_alias.or(profile.alias.as_deref()) {
+                Some(alias) => alias.to_string(),
+                None => return Err(anyhow::anyhow!("No builder alias: pass --alias or run: cargo run -- init --alias YourAlias")),
+            };
+            let builder_alias = builder_alias.as_str();
+            let stale = profile.set_alias(builder_alias)?;
             
             // Load authentication state and link
-            if let Ok(auth_data) = std::fs::read_to_string("../privy_auth_state.json") {
+            if let Ok(auth_data) = std::fs::read_to_string(&privy.auth_state_path) {
                 if let Ok(auth_response) = serde_json::from_str::<PrivyAuthResponse>(&auth_data) {
+                    profile.privy_did = Some(auth_response.user.did.clone());
+                    profile.save(workspace)?;
                     
                     let mapping = BuilderPrivyMapping {
                         builder_alias: builder_alias.to_string(),
@@ -349,8 +331,7 @@ pub async fn handle_privy_link(builder_alias: &str) -> Result<()> {
                         linked_at: chrono::Utc::now().to_rfc3339(),
                     };
                     
-                    let mapping_data = serde_json::to_string_pretty(&mapping)?;
-                    std::fs::write("../builder_privy_mapping.json", mapping_data)?;
+                    privy.save_builder_mapping(&mapping)?;
                     
                     println!("🔗 Linking Privy wallet to builder profile...");
                     println!("🏗️  Builder: {}", builder_alias);
@@ -358,6 +339,9 @@ pub async fn handle_privy_link(builder_alias: &str) -> Result<()> {
                     println!("💼 Wallet: {}", mapping.wallet_address);
                     println!("✅ Profile linked successfully");
                     println!("🔒 Privacy maintained through Privy");
+                    for network in stale {
+                        println!("⚠️  {} still shows the previously registered alias; run `register --network {}`", network, network);
+                    }
                 }
             }
             Ok(())
@@ -368,10 +352,10 @@ pub async fn handle_privy_link(builder_alias: &str) -> Result<()> {
     }
 }
 
-pub async fn handle_privy_report() -> Result<()> {
-    match PrivyIntegration::load_auth_state() {
+pub async fn handle_privy_report(workspace: &Workspace) -> Result<()> {
+    match PrivyIntegration::load_auth_state(workspace) {
         Ok(_) => {
-            let privy = PrivyIntegration::new()?;
+            let privy = PrivyIntegration::new(workspace)?;
             let report = privy.get_privacy_report()?;
             
             println!("\n🔒 Privy Privacy Report");
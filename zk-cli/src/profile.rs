@@ -0,0 +1,173 @@
+// The builder's identity, shared by `init`, `register` and `privy link`: one alias,
+// the wallet that registered it, and each on-chain registration. Kept at
+// `<home>/profile.json` so every project uses the same identity.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use prover::workspace::Workspace;
+use prover::ProverError;
+
+use crate::wallet;
+
+pub const PROFILE_FILE: &str = "profile.json";
+
+/// The registry's `require`s on `aliasName`
+pub const MAX_ALIAS_LEN: usize = 32;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Profile {
+    pub alias: Option<String>,
+    /// Name of the wallet that registered, and its checksummed address
+    pub wallet: Option<String>,
+    pub address: Option<String>,
+    /// Privy DID linked by `privy link`
+    pub privy_did: Option<String>,
+    #[serde(default)]
+    pub registrations: Vec<Registration>,
+}
+
+/// A `registerBuilder` transaction that emitted `BuilderRegistered`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Registration {
+    pub network: String,
+    pub chain_id: u64,
+    pub registry: String,
+    pub alias: String,
+    pub tx_hash: String,
+    pub block_number: u64,
+    pub registered_at: String,
+}
+
+/// 1 to 32 bytes, as `registerBuilder` requires
+pub fn validate_alias(alias: &str) -> Result<()> {
+    if alias.is_empty() {
+        return Err(ProverError::InvalidInput("alias cannot be empty".to_string()).into());
+    }
+    if alias.len() > MAX_ALIAS_LEN {
+        return Err(ProverError::InvalidInput(format!(
+            "alias is {} bytes; the registry allows at most {}",
+            alias.len(),
+            MAX_ALIAS_LEN
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+impl Profile {
+    pub fn load(workspace: &Workspace) -> Result<Self> {
+        match std::fs::read_to_string(workspace.state_file(PROFILE_FILE)) {
+            Ok(content) => Ok(serde_json::from_str(&content)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::from_legacy_stats(workspace)),
+            Err(e) => Err(ProverError::Io(e).into()),
+        }
+    }
+
+    /// Before profiles, `init` kept the alias in `builder_stats.json`
+    fn from_legacy_stats(workspace: &Workspace) -> Self {
+        let alias = std::fs::read_to_string(workspace.state_file("builder_stats.json"))
+            .ok()
+            .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+            .and_then(|stats| stats.get("builder_alias")?.as_str().map(str::to_string))
+            .filter(|alias| validate_alias(alias).is_ok());
+        Self {
+            alias,
+            ..Self::default()
+        }
+    }
+
+    pub fn save(&self, workspace: &Workspace) -> Result<()> {
+        std::fs::create_dir_all(workspace.home()).map_err(ProverError::Io)?;
+        std::fs::write(workspace.state_file(PROFILE_FILE), serde_json::to_string_pretty(self)?).map_err(ProverError::Io)?;
+        Ok(())
+    }
+
+    /// Set the alias. Returns the networks where a different alias is still registered.
+    pub fn set_alias(&mut self, alias: &str) -> Result<Vec<String>> {
+        validate_alias(alias)?;
+        self.alias = Some(alias.to_string());
+        Ok(self.stale_registrations())
+    }
+
+    /// Networks whose latest registration is under another alias than the profile's
+    pub fn stale_registrations(&self) -> Vec<String> {
+        let mut stale: Vec<String> = Vec::new();
+        for registration in self.registrations.iter().rev() {
+            if stale.contains(&registration.network) || self.latest_registration(&registration.network) != Some(registration) {
+                continue;
+            }
+            if Some(&registration.alias) != self.alias.as_ref() {
+                stale.push(registration.network.clone());
+            }
+        }
+        stale
+    }
+
+    pub fn latest_registration(&self, network: &str) -> Option<&Registration> {
+        self.registrations.iter().rev().find(|registration| registration.network == network)
+    }
+
+    /// Adopt the registered alias and signing wallet as this profile's identity
+    pub fn record_registration(&mut self, wallet_name: &str, address: &[u8; 20], registration: Registration) {
+        self.alias = Some(registration.alias.clone());
+        self.wallet = Some(wallet_name.to_string());
+        self.address = Some(wallet::checksum_address(address));
+        self.registrations.push(registration);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_workspace::TempWorkspace;
+
+    fn registration(network: &str, alias: &str) -> Registration {
+        Registration {
+            network: network.to_string(),
+            chain_id: 5003,
+            registry: "0x79169e9A85E46a9f85600E8BE164f767cb88A8Ae".to_string(),
+            alias: alias.to_string(),
+            tx_hash: format!("0x{}", "ab".repeat(32)),
+            block_number: 1,
+            registered_at: "2026-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_alias_rule_matches_registry() {
+        assert!(validate_alias("a").is_ok());
+        assert!(validate_alias(&"é".repeat(16)).is_ok());
+        assert!(validate_alias("").is_err());
+        // 33 bytes, though only 17 characters
+        assert!(validate_alias(&format!("{}a", "é".repeat(16))).is_err());
+    }
+
+    #[test]
+    fn test_round_trip_and_legacy_alias() {
+        let workspace = TempWorkspace::new("profile-legacy");
+        std::fs::create_dir_all(workspace.home()).unwrap();
+        std::fs::write(workspace.state_file("builder_stats.json"), r#"{"builder_alias": "old-name", "deployments": 2}"#).unwrap();
+        assert_eq!(Profile::load(&workspace).unwrap().alias.as_deref(), Some("old-name"));
+
+        let mut profile = Profile::default();
+        profile.record_registration("default", &[0x11; 20], registration("mantle-testnet", "anon"));
+        profile.save(&workspace).unwrap();
+        assert_eq!(Profile::load(&workspace).unwrap(), profile);
+        assert_eq!(profile.alias.as_deref(), Some("anon"));
+        assert_eq!(profile.address.as_deref(), Some(wallet::checksum_address(&[0x11; 20]).as_str()));
+    }
+
+    #[test]
+    fn test_renaming_reports_stale_registrations() {
+        let mut profile = Profile::default();
+        profile.record_registration("default", &[0x11; 20], registration("mantle-testnet", "anon"));
+        profile.record_registration("default", &[0x11; 20], registration("mantle-mainnet", "anon"));
+        assert!(profile.stale_registrations().is_empty());
+
+        assert_eq!(profile.set_alias("renamed").unwrap(), vec!["mantle-mainnet", "mantle-testnet"]);
+        profile.record_registration("default", &[0x11; 20], registration("mantle-testnet", "renamed"));
+        assert_eq!(profile.stale_registrations(), vec!["mantle-mainnet"]);
+        assert!(profile.set_alias("").is_err());
+    }
+}
@@ -0,0 +1,355 @@
+// Client side of the builder registry (the Solidity `niet2codeBuilder` contract and
+// its PolkaVM port in `registry-contract`): call encoding, event decoding and the
+// `register` command, which signs and broadcasts `registerBuilder` with a wallet.
+// `submit` sends `verifyProof` to the same registry.
+
+use anyhow::{bail, Result};
+use std::path::Path;
+use std::time::Duration;
+
+use prover::workspace::Workspace;
+use prover::ProverError;
+use verifier_core::abi::{self, WORD};
+
+use crate::networks::{self, Network};
+use crate::profile::{self, Profile, Registration};
+use crate::rpc::{self, Log, RpcClient, TransactionReceipt};
+use crate::tx;
+use crate::wallet::{self, Wallet, WalletStore};
+
+/// keccak256("registerBuilder(string)")[..4]
+pub const REGISTER_BUILDER_SELECTOR: [u8; 4] = [0x90, 0xaa, 0x26, 0x90];
+
+/// keccak256("BuilderRegistered(address,string)")
+pub const BUILDER_REGISTERED_TOPIC: [u8; 32] = [
+    0xa3, 0x0b, 0x1b, 0xc9, 0x03, 0xf2, 0x49, 0x47, 0x03, 0x80, 0xba, 0x4d, 0x18, 0x36, 0xf1, 0x74, 0x3a, 0xf9, 0xea, 0xdf,
+    0x38, 0x31, 0xe4, 0x65, 0x86, 0x03, 0xd8, 0x10, 0x5e, 0xaa, 0x8b, 0x6d,
+];
+
+/// keccak256("verifyProof(bytes,bytes32[])")[..4]
+pub const VERIFY_PROOF_SELECTOR: [u8; 4] = [0xbb, 0xcb, 0x7c, 0x74];
+
+/// keccak256("ProofVerified(address,bytes32,bool)")
+pub const PROOF_VERIFIED_TOPIC: [u8; 32] = [
+    0xe8, 0xad, 0x06, 0xfe, 0xcf, 0xe3, 0xc1, 0x3c, 0xc8, 0x49, 0x98, 0x44, 0x9b, 0x19, 0x33, 0x65, 0x45, 0x18, 0xc4, 0xba,
+    0x62, 0x32, 0x2f, 0x6f, 0x5f, 0x07, 0x9c, 0xa0, 0xe8, 0xe8, 0x22, 0x33,
+];
+
+/// keccak256("ContractDeployed(address,address)")
+pub const CONTRACT_DEPLOYED_TOPIC: [u8; 32] = [
+    0x33, 0xc9, 0x81, 0xba, 0xba, 0x08, 0x1f, 0x8f, 0xd2, 0xc5, 0x2a, 0xc6, 0xad, 0x1e, 0xa9, 0x5b, 0x68, 0x14, 0xb4, 0x37,
+    0x66, 0x40, 0xf5, 0x56, 0x89, 0x05, 0x1f, 0x65, 0x84, 0x72, 0x96, 0x88,
+];
+
+/// How often and how long to poll for a receipt
+pub const RECEIPT_POLL: Duration = Duration::from_secs(2);
+pub const RECEIPT_TIMEOUT: Duration = Duration::from_secs(180);
+
+/// Where `submit-proof` sends a proof
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProofTarget {
+    /// `verifyProof(bytes,bytes32[])`, which records the result and emits `ProofVerified`
+    Registry([u8; 20]),
+    /// `verify(uint256,bytes,uint256[])` on the verifier contract
+    Verifier([u8; 20]),
+}
+
+impl ProofTarget {
+    pub fn address(&self) -> [u8; 20] {
+        match self {
+            ProofTarget::Registry(address) | ProofTarget::Verifier(address) => *address,
+        }
+    }
+}
+
+/// `registerBuilder(aliasName)`, refusing aliases the contract would revert on
+pub fn encode_register_builder(alias: &str) -> Result<Vec<u8>> {
+    profile::validate_alias(alias)?;
+    let mut calldata = REGISTER_BUILDER_SELECTOR.to_vec();
+    calldata.extend_from_slice(&abi::encode_string(alias));
+    Ok(calldata)
+}
+
+/// `verifyProof(proofBytes, publicInputs)`; `input_words` holds the inputs as
+/// consecutive 32-byte words. The registry forwards both to its verifier under
+/// its configured circuit id.
+pub fn encode_verify_proof(proof: &[u8], input_words: &[u8]) -> Vec<u8> {
+    debug_assert!(input_words.len().is_multiple_of(WORD));
+    let proof_offset = 2 * WORD;
+    let inputs_offset = proof_offset + abi::encode_bytes_tail(proof).len();
+
+    let mut calldata = VERIFY_PROOF_SELECTOR.to_vec();
+    calldata.extend_from_slice(&abi::uint_word(proof_offset as u64));
+    calldata.extend_from_slice(&abi::uint_word(inputs_offset as u64));
+    calldata.extend_from_slice(&abi::encode_bytes_tail(proof));
+    calldata.extend_from_slice(&abi::uint_word((input_words.len() / WORD) as u64));
+    calldata.extend_from_slice(input_words);
+    calldata
+}
+
+/// `ProofVerified(verifier, proofHash, isValid)` emitted by `registry`, if `log` is one
+pub fn decode_proof_verified(log: &Log, registry: &[u8; 20]) -> Option<([u8; 20], [u8; 32], bool)> {
+    if &log.address != registry || log.topics.len() != 2 || log.topics[0] != PROOF_VERIFIED_TOPIC || log.data.len() != 2 * WORD {
+        return None;
+    }
+    let builder = topic_address(&log.topics[1])?;
+    let proof_hash = log.data[..WORD].try_into().ok()?;
+    let is_valid = match &log.data[WORD..] {
+        word if word == abi::encode_bool(true) => true,
+        word if word == abi::encode_bool(false) => false,
+        _ => return None,
+    };
+    Some((builder, proof_hash, is_valid))
+}
+
+/// `BuilderRegistered(builder, aliasName)` emitted by `registry`, if `log` is one
+pub fn decode_builder_registered(log: &Log, registry: &[u8; 20]) -> Option<([u8; 20], String)> {
+    if &log.address != registry || log.topics.len() != 2 || log.topics[0] != BUILDER_REGISTERED_TOPIC {
+        return None;
+    }
+    let builder = topic_address(&log.topics[1])?;
+    let alias = abi::read_bytes(&log.data, 0).ok()?;
+    Some((builder, String::from_utf8(alias.to_vec()).ok()?))
+}
+
+/// `ContractDeployed(builder, contractAddress)` emitted by `registry`, if `log` is one
+pub fn decode_contract_deployed(log: &Log, registry: &[u8; 20]) -> Option<([u8; 20], [u8; 20])> {
+    if &log.address != registry || log.topics.len() != 2 || log.topics[0] != CONTRACT_DEPLOYED_TOPIC || log.data.len() != WORD {
+        return None;
+    }
+    let builder = topic_address(&log.topics[1])?;
+    let contract = topic_address(log.data[..].try_into().ok()?)?;
+    Some((builder, contract))
+}
+
+/// Address in an indexed `address` topic (12 zero bytes, then the address)
+fn topic_address(topic: &[u8; 32]) -> Option<[u8; 20]> {
+    if topic[..WORD - 20].iter().any(|&b| b != 0) {
+        return None;
+    }
+    topic[WORD - 20..].try_into().ok()
+}
+
+/// Sign and send `registerBuilder(alias)`, wait for it and decode the event
+pub async fn register_builder(
+    rpc: &RpcClient,
+    wallet: &Wallet,
+    network: &Network,
+    alias: &str,
+    poll: Duration,
+) -> Result<(TransactionReceipt, String)> {
+    let registry = network.registry()?;
+    let calldata = encode_register_builder(alias)?;
+    let hash = tx::send_transaction(rpc, wallet, network.chain_id, network.fee_model, Some(registry), calldata).await?;
+    println!("📨 Sent {}", rpc::to_hex(&hash));
+
+    let receipt = rpc.wait_for_receipt(&hash, poll, RECEIPT_TIMEOUT).await?;
+    if !receipt.succeeded() {
+        bail!("registerBuilder reverted in block {} (tx {})", receipt.block_number, rpc::to_hex(&hash));
+    }
+    let registered = receipt
+        .logs
+        .iter()
+        .find_map(|log| decode_builder_registered(log, &registry))
+        .filter(|(builder, _)| *builder == wallet.address());
+    match registered {
+        Some((_, alias)) => Ok((receipt, alias)),
+        None => bail!("transaction {} succeeded but emitted no BuilderRegistered event for this wallet", rpc::to_hex(&hash)),
+    }
+}
+
+pub async fn handle_register(
+    workspace: &Workspace,
+    alias: Option<&str>,
+    network: Option<&str>,
+    rpc_url: Option<&str>,
+    registry: Option<&str>,
+    wallet_name: &str,
+    passphrase_file: Option<&Path>,
+) -> Result<()> {
+    let mut profile = Profile::load(workspace)?;
+    let alias = match alias.or(profile.alias.as_deref()) {
+        Some(alias) => alias.to_string(),
+        None => return Err(ProverError::InvalidInput("no alias: pass --alias or run `init --alias` first".to_string()).into()),
+    };
+    // Before unlocking anything: the contract would revert on these
+    profile::validate_alias(&alias)?;
+    let network = networks::resolve(workspace, network, rpc_url, registry)?;
+    let registry = network.registry()?;
+
+    println!("🔐 Registering builder '{}' on {} (chain {})", alias, network.name, network.chain_id);
+    println!("📋 Registry: {}", wallet::checksum_address(&registry));
+    let wallet = WalletStore::open(workspace).unlock(wallet_name, passphrase_file)?;
+    println!("💼 Wallet '{}': {}", wallet_name, wallet::checksum_address(&wallet.address()));
+
+    let rpc = RpcClient::new(&network.rpc_url);
+    let (receipt, registered_alias) = register_builder(&rpc, &wallet, &network, &alias, RECEIPT_POLL).await?;
+    println!("✅ BuilderRegistered({}, \"{}\")", wallet::checksum_address(&wallet.address()), registered_alias);
+    println!("🧱 Block {} · gas used {}", receipt.block_number, receipt.gas_used);
+
+    profile.record_registration(
+        wallet_name,
+        &wallet.address(),
+        Registration {
+            network: network.name.clone(),
+            chain_id: network.chain_id,
+            registry: wallet::checksum_address(&registry),
+            alias: registered_alias,
+            tx_hash: rpc::to_hex(&receipt.transaction_hash),
+            block_number: receipt.block_number,
+            registered_at: chrono::Utc::now().to_rfc3339(),
+        },
+    );
+    profile.save(workspace)?;
+    println!("👤 Profile updated: {}", workspace.state_file(profile::PROFILE_FILE).display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rpc::test_node::{self, NodeError};
+    use serde_json::{json, Value};
+
+    const REGISTRY: [u8; 20] = [0x79; 20];
+    const KEY: [u8; 32] = [0x46; 32];
+
+    fn network(url: &str) -> Network {
+        Network::local(url, Some(REGISTRY))
+    }
+
+    fn registered_log(builder: &[u8; 20], alias: &str) -> Value {
+        json!({
+            "address": rpc::to_hex(&REGISTRY),
+            "topics": [rpc::to_hex(&BUILDER_REGISTERED_TOPIC), rpc::to_hex(&abi::encode_address(builder))],
+            "data": rpc::to_hex(&abi::encode_string(alias)),
+        })
+    }
+
+    /// Node that accepts one transaction and mines it with `logs`
+    fn mining_node(chain_id: u64, status: &'static str, logs: Value) -> impl Fn(&str, &Value) -> Result<Value, NodeError> {
+        let receipt = json!({ "blockNumber": "0x10", "status": status, "gasUsed": "0xb000" });
+        test_node::mining(receipt, logs, move |method, _| match method {
+            "eth_chainId" => Ok(json!(rpc::to_quantity(chain_id.into()))),
+            "eth_estimateGas" => Ok(json!("0xc350")),
+            "eth_gasPrice" => Ok(json!("0x3b9aca00")),
+            "eth_maxPriorityFeePerGas" => Ok(json!("0x1")),
+            "eth_getTransactionCount" => Ok(json!("0x4")),
+            _ => Err(NodeError::method_not_found()),
+        })
+    }
+
+    #[test]
+    fn test_selector_and_topic_match_signatures() {
+        assert_eq!(REGISTER_BUILDER_SELECTOR, tx::keccak256(b"registerBuilder(string)")[..4]);
+        assert_eq!(BUILDER_REGISTERED_TOPIC, tx::keccak256(b"BuilderRegistered(address,string)"));
+        assert_eq!(VERIFY_PROOF_SELECTOR, tx::keccak256(b"verifyProof(bytes,bytes32[])")[..4]);
+        assert_eq!(PROOF_VERIFIED_TOPIC, tx::keccak256(b"ProofVerified(address,bytes32,bool)"));
+        assert_eq!(CONTRACT_DEPLOYED_TOPIC, tx::keccak256(b"ContractDeployed(address,address)"));
+    }
+
+    #[test]
+    fn test_register_builder_encoding_enforces_alias_rule() {
+        let calldata = encode_register_builder("anon").unwrap();
+        assert_eq!(calldata[..4], REGISTER_BUILDER_SELECTOR);
+        assert_eq!(abi::read_bytes(&calldata[4..], 0).unwrap(), b"anon");
+        assert_eq!(calldata.len(), 4 + 3 * WORD);
+
+        assert!(encode_register_builder(&"x".repeat(32)).is_ok());
+        for bad in ["", &"x".repeat(33)] {
+            let err = encode_register_builder(bad).unwrap_err();
+            assert!(matches!(err.downcast_ref::<ProverError>(), Some(ProverError::InvalidInput(_))), "{:?}", bad);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_register_sends_signed_transaction_and_decodes_event() {
+        let wallet = Wallet::from_private_key(&KEY).unwrap();
+        let node = test_node::spawn(mining_node(5003, "0x1", json!([registered_log(&wallet.address(), "anon")]))).await;
+
+        let rpc = RpcClient::new(&node.url);
+        let (receipt, alias) = register_builder(&rpc, &wallet, &network(&node.url), "anon", Duration::ZERO).await.unwrap();
+        assert_eq!(alias, "anon");
+        assert_eq!(receipt.block_number, 16);
+
+        let calls = node.calls();
+        let sent = calls.iter().find(|(method, _)| method == "eth_sendRawTransaction").unwrap();
+        let raw = hex::decode(sent.1[0].as_str().unwrap().trim_start_matches("0x")).unwrap();
+        // EIP-1559, since the node suggests a priority fee
+        assert_eq!(raw[0], 0x02);
+        let calldata = encode_register_builder("anon").unwrap();
+        assert!(raw.windows(calldata.len()).any(|window| window == calldata));
+        assert!(raw.windows(20).any(|window| window == REGISTRY));
+
+        let estimate = calls.iter().find(|(method, _)| method == "eth_estimateGas").unwrap();
+        assert_eq!(estimate.1[0]["from"], rpc::to_hex(&wallet.address()));
+    }
+
+    #[tokio::test]
+    async fn test_register_refuses_wrong_chain() {
+        let wallet = Wallet::from_private_key(&KEY).unwrap();
+        let node = test_node::spawn(mining_node(1, "0x1", json!([]))).await;
+
+        let err = register_builder(&RpcClient::new(&node.url), &wallet, &network(&node.url), "anon", Duration::ZERO).await.unwrap_err();
+        assert!(matches!(err.downcast_ref::<ProverError>(), Some(ProverError::InvalidInput(_))), "{}", err);
+        assert!(node.calls().iter().all(|(method, _)| method != "eth_sendRawTransaction"));
+    }
+
+    #[tokio::test]
+    async fn test_register_reports_revert_and_missing_event() {
+        let wallet = Wallet::from_private_key(&KEY).unwrap();
+
+        let node = test_node::spawn(mining_node(5003, "0x0", json!([]))).await;
+        let err = register_builder(&RpcClient::new(&node.url), &wallet, &network(&node.url), "anon", Duration::ZERO).await.unwrap_err();
+        assert!(err.to_string().contains("reverted"), "{}", err);
+
+        // Event for another builder only
+        let node = test_node::spawn(mining_node(5003, "0x1", json!([registered_log(&[0x22; 20], "anon")]))).await;
+        let err = register_builder(&RpcClient::new(&node.url), &wallet, &network(&node.url), "anon", Duration::ZERO).await.unwrap_err();
+        assert!(err.to_string().contains("no BuilderRegistered event"), "{}", err);
+    }
+
+    #[test]
+    fn test_decode_builder_registered_checks_emitter() {
+        let log: Log = serde_json::from_value(registered_log(&[0x11; 20], "anon")).unwrap();
+        assert_eq!(decode_builder_registered(&log, &REGISTRY), Some(([0x11; 20], "anon".to_string())));
+        assert_eq!(decode_builder_registered(&log, &[0x01; 20]), None);
+    }
+
+    #[test]
+    fn test_verify_proof_encoding_matches_registry_decoding() {
+        let proof = [0xab; 130];
+        let inputs = [[0x01; WORD], [0x02; WORD]].concat();
+        let calldata = encode_verify_proof(&proof, &inputs);
+        assert_eq!(calldata[..4], VERIFY_PROOF_SELECTOR);
+        // The reads `verifyProof` does in `registry-contract`
+        assert_eq!(abi::read_bytes(&calldata[4..], 0).unwrap(), proof);
+        assert_eq!(abi::read_words(&calldata[4..], WORD).unwrap(), inputs);
+    }
+
+    #[test]
+    fn test_decode_proof_verified() {
+        let data = [[0x33; WORD], abi::encode_bool(true)].concat();
+        let log: Log = serde_json::from_value(json!({
+            "address": rpc::to_hex(&REGISTRY),
+            "topics": [rpc::to_hex(&PROOF_VERIFIED_TOPIC), rpc::to_hex(&abi::encode_address(&[0x11; 20]))],
+            "data": rpc::to_hex(&data),
+        }))
+        .unwrap();
+        assert_eq!(decode_proof_verified(&log, &REGISTRY), Some(([0x11; 20], [0x33; WORD], true)));
+        assert_eq!(decode_proof_verified(&log, &[0x01; 20]), None);
+    }
+
+    #[test]
+    fn test_decode_contract_deployed() {
+        let log: Log = serde_json::from_value(json!({
+            "address": rpc::to_hex(&REGISTRY),
+            "topics": [rpc::to_hex(&CONTRACT_DEPLOYED_TOPIC), rpc::to_hex(&abi::encode_address(&[0x11; 20]))],
+            "data": rpc::to_hex(&abi::encode_address(&[0xc0; 20])),
+        }))
+        .unwrap();
+        assert_eq!(decode_contract_deployed(&log, &REGISTRY), Some(([0x11; 20], [0xc0; 20])));
+        assert_eq!(decode_contract_deployed(&log, &[0x01; 20]), None);
+        assert_eq!(decode_proof_verified(&log, &REGISTRY), None);
+    }
+}
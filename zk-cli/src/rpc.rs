@@ -0,0 +1,781 @@
+// Minimal Ethereum JSON-RPC client for the chain the contracts are deployed on.
+// pallet-revive chains are reached through their eth-rpc adapter, which serves
+// the standard `eth_*` methods; only what the CLI needs is wrapped here: chain
+// id, balances, nonces, fees, `eth_call`, gas estimates, raw transactions,
+// receipts, block headers and logs. Commands share it instead of shelling out
+// to Foundry's `cast`.
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde::de::{DeserializeOwned, Deserializer};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use prover::ProverError;
+
+/// Environment variable naming the default JSON-RPC endpoint
+pub const RPC_URL_ENV: &str = "NIET2CODE_RPC_URL";
+
+/// JSON-RPC error object returned by the node. `eth_call` and `eth_estimateGas`
+/// report reverts this way, with the revert data in `data`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RpcError {
+    pub method: String,
+    pub code: i64,
+    pub message: String,
+    pub data: Option<String>,
+}
+
+impl RpcError {
+    /// Revert data attached to the error, if the node sent any as hex
+    pub fn revert_data(&self) -> Option<Vec<u8>> {
+        self.data.as_deref().and_then(|data| decode_hex_data(data).ok())
+    }
+
+    /// The node does not serve the method (JSON-RPC -32601)
+    pub fn is_method_not_found(&self) -> bool {
+        self.code == -32601
+    }
+}
+
+impl fmt::Display for RpcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} failed: RPC error {}: {}", self.method, self.code, self.message)
+    }
+}
+
+impl std::error::Error for RpcError {}
+
+/// Call or transaction fields for `eth_call` and `eth_estimateGas`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CallRequest {
+    pub from: Option<[u8; 20]>,
+    pub to: Option<[u8; 20]>,
+    pub data: Vec<u8>,
+    pub value: u128,
+}
+
+impl CallRequest {
+    /// Call `to` with `data` and no value
+    pub fn new(to: [u8; 20], data: Vec<u8>) -> Self {
+        Self {
+            to: Some(to),
+            data,
+            ..Self::default()
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        let mut call = json!({ "data": to_hex(&self.data) });
+        if let Some(from) = &self.from {
+            call["from"] = json!(to_hex(from));
+        }
+        if let Some(to) = &self.to {
+            call["to"] = json!(to_hex(to));
+        }
+        if self.value != 0 {
+            call["value"] = json!(to_quantity(self.value));
+        }
+        call
+    }
+}
+
+/// Log entry of a transaction receipt or `eth_getLogs`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Log {
+    #[serde(deserialize_with = "de_hex_array")]
+    pub address: [u8; 20],
+    #[serde(deserialize_with = "de_topics")]
+    pub topics: Vec<[u8; 32]>,
+    #[serde(deserialize_with = "de_hex_bytes")]
+    pub data: Vec<u8>,
+    /// Where the log is; absent for pending logs
+    #[serde(default, deserialize_with = "de_opt_u64")]
+    pub block_number: Option<u64>,
+    #[serde(default, deserialize_with = "de_opt_hex_array")]
+    pub block_hash: Option<[u8; 32]>,
+    #[serde(default, deserialize_with = "de_opt_hex_array")]
+    pub transaction_hash: Option<[u8; 32]>,
+    #[serde(default, deserialize_with = "de_opt_u64")]
+    pub log_index: Option<u64>,
+    /// Set by nodes when a reorg dropped the log
+    #[serde(default)]
+    pub removed: bool,
+}
+
+/// `eth_getLogs` filter: logs of `address` whose first topic is one of
+/// `topics`, in blocks `from_block..=to_block`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogFilter {
+    pub address: [u8; 20],
+    pub topics: Vec<[u8; 32]>,
+    pub from_block: u64,
+    pub to_block: u64,
+}
+
+impl LogFilter {
+    fn to_json(&self) -> Value {
+        let topics: Vec<String> = self.topics.iter().map(|topic| to_hex(topic)).collect();
+        json!({
+            "address": to_hex(&self.address),
+            "topics": [topics],
+            "fromBlock": to_quantity(self.from_block.into()),
+            "toBlock": to_quantity(self.to_block.into()),
+        })
+    }
+}
+
+/// The fields of `eth_getBlockByNumber` the CLI reads
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct BlockHeader {
+    #[serde(deserialize_with = "de_u64")]
+    pub number: u64,
+    #[serde(deserialize_with = "de_hex_array")]
+    pub hash: [u8; 32],
+    /// Unix seconds
+    #[serde(deserialize_with = "de_u64")]
+    pub timestamp: u64,
+}
+
+/// The fields of `eth_getTransactionReceipt` the CLI reads
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionReceipt {
+    #[serde(deserialize_with = "de_hex_array")]
+    pub transaction_hash: [u8; 32],
+    #[serde(deserialize_with = "de_u64")]
+    pub block_number: u64,
+    /// `1` on success, `0` if the transaction reverted
+    #[serde(deserialize_with = "de_u64")]
+    pub status: u64,
+    #[serde(deserialize_with = "de_u64")]
+    pub gas_used: u64,
+    #[serde(default, deserialize_with = "de_opt_u128")]
+    pub effective_gas_price: Option<u128>,
+    #[serde(default, deserialize_with = "de_opt_hex_array")]
+    pub contract_address: Option<[u8; 20]>,
+    #[serde(default)]
+    pub logs: Vec<Log>,
+}
+
+impl TransactionReceipt {
+    pub fn succeeded(&self) -> bool {
+        self.status == 1
+    }
+}
+
+pub struct RpcClient {
+    url: String,
+    http: reqwest::Client,
+}
+
+impl RpcClient {
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            http: reqwest::Client::new(),
+        }
+    }
+
+    /// Use `--rpc-url` if given, otherwise NIET2CODE_RPC_URL
+    pub fn from_arg_or_env(url: Option<&str>) -> Result<Self> {
+        match url.map(str::to_string).or_else(|| std::env::var(RPC_URL_ENV).ok().filter(|v| !v.is_empty())) {
+            Some(url) => Ok(Self::new(&url)),
+            None => Err(ProverError::InvalidInput(format!("no RPC endpoint: pass --rpc-url or set {}", RPC_URL_ENV)).into()),
+        }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Send one JSON-RPC request and deserialize its `result`
+    pub async fn request<T: DeserializeOwned>(&self, method: &str, params: Value) -> Result<T> {
+        let body = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        let response = self
+            .http
+            .post(&self.url)
+            .json(&body)
+            .send()
+            .await
+            .with_context(|| format!("{} request to {} failed", method, self.url))?;
+        if !response.status().is_success() {
+            bail!("{} request to {} failed: HTTP {}", method, self.url, response.status());
+        }
+        let mut reply: Value = response.json().await.with_context(|| format!("{}: response is not JSON", method))?;
+
+        if let Some(error) = reply.get("error") {
+            return Err(RpcError {
+                method: method.to_string(),
+                code: error.get("code").and_then(Value::as_i64).unwrap_or_default(),
+                message: error.get("message").and_then(Value::as_str).unwrap_or("unknown error").to_string(),
+                data: error.get("data").and_then(Value::as_str).map(str::to_string),
+            }
+            .into());
+        }
+        let result = reply.get_mut("result").map(Value::take).ok_or_else(|| anyhow!("{}: response has no result", method))?;
+        serde_json::from_value(result).with_context(|| format!("{}: unexpected result", method))
+    }
+
+    /// Contract code at `address` on the latest block (empty if there is none)
+    pub async fn get_code(&self, address: &str) -> Result<Vec<u8>> {
+        let code: String = self.request("eth_getCode", json!([address, "latest"])).await?;
+        decode_hex_data(&code)
+    }
+
+    pub async fn chain_id(&self) -> Result<u64> {
+        self.quantity("eth_chainId", json!([])).await
+    }
+
+    pub async fn block_number(&self) -> Result<u64> {
+        self.quantity("eth_blockNumber", json!([])).await
+    }
+
+    /// Balance of `address` in wei on the latest block
+    pub async fn balance(&self, address: &[u8; 20]) -> Result<u128> {
+        self.quantity("eth_getBalance", json!([to_hex(address), "latest"])).await
+    }
+
+    /// Next nonce for `address`, counting its transactions still in the pool
+    pub async fn nonce(&self, address: &[u8; 20]) -> Result<u64> {
+        self.quantity("eth_getTransactionCount", json!([to_hex(address), "pending"])).await
+    }
+
+    /// Legacy gas price in wei
+    pub async fn gas_price(&self) -> Result<u128> {
+        self.quantity("eth_gasPrice", json!([])).await
+    }
+
+    /// Suggested EIP-1559 priority fee in wei
+    pub async fn max_priority_fee(&self) -> Result<u128> {
+        self.quantity("eth_maxPriorityFeePerGas", json!([])).await
+    }
+
+    /// Return data of `call` on the latest block. A revert is an [`RpcError`]
+    /// carrying the revert data.
+    pub async fn call(&self, call: &CallRequest) -> Result<Vec<u8>> {
+        let output: String = self.request("eth_call", json!([call.to_json(), "latest"])).await?;
+        decode_hex_data(&output)
+    }
+
+    pub async fn estimate_gas(&self, call: &CallRequest) -> Result<u64> {
+        self.quantity("eth_estimateGas", json!([call.to_json()])).await
+    }
+
+    /// Broadcast a signed transaction and return its hash
+    pub async fn send_raw_transaction(&self, raw: &[u8]) -> Result<[u8; 32]> {
+        let hash: String = self.request("eth_sendRawTransaction", json!([to_hex(raw)])).await?;
+        decode_hex_array(&hash)
+    }
+
+    /// Receipt of a mined transaction, `None` while it is pending or unknown
+    pub async fn transaction_receipt(&self, hash: &[u8; 32]) -> Result<Option<TransactionReceipt>> {
+        self.request("eth_getTransactionReceipt", json!([to_hex(hash)])).await
+    }
+
+    /// Header of block `number`, `None` if the chain is not that long
+    pub async fn block_header(&self, number: u64) -> Result<Option<BlockHeader>> {
+        self.request("eth_getBlockByNumber", json!([to_quantity(number.into()), false])).await
+    }
+
+    /// Logs matching `filter`, in chain order
+    pub async fn get_logs(&self, filter: &LogFilter) -> Result<Vec<Log>> {
+        self.request("eth_getLogs", json!([filter.to_json()])).await
+    }
+
+    /// Poll for the receipt of `hash` every `interval` until it is mined or `timeout` passes
+    pub async fn wait_for_receipt(&self, hash: &[u8; 32], interval: Duration, timeout: Duration) -> Result<TransactionReceipt> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if let Some(receipt) = self.transaction_receipt(hash).await? {
+                return Ok(receipt);
+            }
+            if Instant::now() >= deadline {
+                bail!("transaction {} was not mined within {}s", to_hex(hash), timeout.as_secs());
+            }
+            tokio::time::sleep(interval).await;
+        }
+    }
+
+    /// Poll until the chain is `confirmations` blocks deep at `block`, counting
+    /// `block` itself
+    pub async fn wait_for_confirmations(&self, block: u64, confirmations: u64, interval: Duration, timeout: Duration) -> Result<()> {
+        let target = block.saturating_add(confirmations.saturating_sub(1));
+        let deadline = Instant::now() + timeout;
+        loop {
+            let head = self.block_number().await?;
+            if head >= target {
+                return Ok(());
+            }
+            if Instant::now() >= deadline {
+                bail!("block {} has {} of {} confirmations after {}s", block, (head + 1).saturating_sub(block), confirmations, timeout.as_secs());
+            }
+            tokio::time::sleep(interval).await;
+        }
+    }
+
+    async fn quantity<T: TryFrom<u128>>(&self, method: &str, params: Value) -> Result<T> {
+        let value: String = self.request(method, params).await?;
+        let value = parse_quantity(&value).with_context(|| format!("{}: unexpected result", method))?;
+        T::try_from(value).map_err(|_| anyhow!("{}: result {} out of range", method, value))
+    }
+}
+
+/// Parse a 20-byte `0x`-prefixed account address
+pub fn parse_address(address: &str) -> Result<[u8; 20]> {
+    let bytes = address
+        .strip_prefix("0x")
+        .and_then(|digits| hex::decode(digits).ok())
+        .ok_or_else(|| ProverError::InvalidInput(format!("address is not 0x-prefixed hex: {}", address)))?;
+    bytes
+        .try_into()
+        .map_err(|bytes: Vec<u8>| ProverError::InvalidInput(format!("address must be 20 bytes, got {}: {}", bytes.len(), address)).into())
+}
+
+/// `0x`-prefixed lowercase hex, the JSON-RPC encoding of byte strings
+pub fn to_hex(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+/// JSON-RPC quantity: `0x`-prefixed hex without leading zeros
+pub fn to_quantity(value: u128) -> String {
+    format!("{:#x}", value)
+}
+
+fn parse_quantity(value: &str) -> Result<u128> {
+    let digits = value.strip_prefix("0x").ok_or_else(|| anyhow!("quantity is not 0x-prefixed: {}", value))?;
+    if digits.is_empty() {
+        bail!("quantity has no digits: {}", value);
+    }
+    u128::from_str_radix(digits, 16).with_context(|| format!("quantity is not a 128-bit hex number: {}", value))
+}
+
+fn decode_hex_data(data: &str) -> Result<Vec<u8>> {
+    let digits = data.strip_prefix("0x").ok_or_else(|| anyhow!("RPC data is not 0x-prefixed: {}", data))?;
+    hex::decode(digits).context("RPC data is not hex")
+}
+
+fn decode_hex_array<const N: usize>(data: &str) -> Result<[u8; N]> {
+    decode_hex_data(data)?
+        .try_into()
+        .map_err(|bytes: Vec<u8>| anyhow!("expected {} bytes, got {}: {}", N, bytes.len(), data))
+}
+
+fn de_u64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    let value = parse_quantity(&value).map_err(serde::de::Error::custom)?;
+    u64::try_from(value).map_err(serde::de::Error::custom)
+}
+
+fn de_opt_u64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<u64>, D::Error> {
+    Option::<String>::deserialize(deserializer)?
+        .map(|value| {
+            let value = parse_quantity(&value).map_err(serde::de::Error::custom)?;
+            u64::try_from(value).map_err(serde::de::Error::custom)
+        })
+        .transpose()
+}
+
+fn de_opt_u128<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<u128>, D::Error> {
+    Option::<String>::deserialize(deserializer)?
+        .map(|value| parse_quantity(&value).map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+fn de_hex_bytes<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    decode_hex_data(&String::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+}
+
+fn de_hex_array<'de, D: Deserializer<'de>, const N: usize>(deserializer: D) -> Result<[u8; N], D::Error> {
+    decode_hex_array(&String::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+}
+
+fn de_opt_hex_array<'de, D: Deserializer<'de>, const N: usize>(deserializer: D) -> Result<Option<[u8; N]>, D::Error> {
+    Option::<String>::deserialize(deserializer)?
+        .map(|value| decode_hex_array(&value).map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+fn de_topics<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<[u8; 32]>, D::Error> {
+    Vec::<String>::deserialize(deserializer)?
+        .iter()
+        .map(|topic| decode_hex_array(topic).map_err(serde::de::Error::custom))
+        .collect()
+}
+
+/// Stand-in JSON-RPC node for tests: answers each request with `handler(method, params)`
+#[cfg(test)]
+pub mod test_node {
+    use serde_json::{json, Value};
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::TcpListener;
+
+    pub type Handler = dyn Fn(&str, &Value) -> Result<Value, NodeError> + Send + Sync;
+
+    /// JSON-RPC error object to answer with
+    #[derive(Debug, Clone)]
+    pub struct NodeError {
+        pub code: i64,
+        pub message: String,
+        pub data: Option<String>,
+    }
+
+    impl NodeError {
+        pub fn new(code: i64, message: &str) -> Self {
+            Self {
+                code,
+                message: message.to_string(),
+                data: None,
+            }
+        }
+
+        /// `execution reverted` with `data`, as nodes report reverting calls
+        pub fn revert(data: &[u8]) -> Self {
+            Self {
+                code: 3,
+                message: "execution reverted".to_string(),
+                data: Some(format!("0x{}", hex::encode(data))),
+            }
+        }
+
+        /// -32601, for methods a test does not serve
+        pub fn method_not_found() -> Self {
+            Self::new(-32601, "method not found")
+        }
+    }
+
+    pub struct StandIn {
+        pub url: String,
+        calls: Arc<Mutex<Vec<(String, Value)>>>,
+    }
+
+    impl StandIn {
+        /// Methods and params received so far, in order
+        pub fn calls(&self) -> Vec<(String, Value)> {
+            self.calls.lock().unwrap().clone()
+        }
+    }
+
+    pub async fn spawn(handler: impl Fn(&str, &Value) -> Result<Value, NodeError> + Send + Sync + 'static) -> StandIn {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handler: Arc<Handler> = Arc::new(handler);
+        let calls = Arc::new(Mutex::new(Vec::new()));
+
+        let recorded = calls.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let handler = handler.clone();
+                let recorded = recorded.clone();
+                tokio::spawn(async move {
+                    let (read, mut write) = stream.into_split();
+                    let mut read = BufReader::new(read);
+                    // One request per iteration; reqwest keeps connections alive
+                    loop {
+                        let mut content_length = 0;
+                        let mut line = String::new();
+                        loop {
+                            line.clear();
+                            if read.read_line(&mut line).await.unwrap_or(0) == 0 {
+                                return;
+                            }
+                            let header = line.trim_end();
+                            if header.is_empty() {
+                                break;
+                            }
+                            if let Some((name, value)) = header.split_once(':') {
+                                if name.eq_ignore_ascii_case("content-length") {
+                                    content_length = value.trim().parse().unwrap();
+                                }
+                            }
+                        }
+                        let mut body = vec![0; content_length];
+                        read.read_exact(&mut body).await.unwrap();
+
+                        let request: Value = serde_json::from_slice(&body).unwrap();
+                        let method = request["method"].as_str().unwrap_or_default().to_string();
+                        let params = request["params"].clone();
+                        recorded.lock().unwrap().push((method.clone(), params.clone()));
+                        let reply = match handler(&method, &params) {
+                            Ok(result) => json!({ "jsonrpc": "2.0", "id": request["id"], "result": result }),
+                            Err(error) => json!({
+                                "jsonrpc": "2.0",
+                                "id": request["id"],
+                                "error": { "code": error.code, "message": error.message, "data": error.data },
+                            }),
+                        };
+                        let reply = reply.to_string();
+                        let response = format!(
+                            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+                            reply.len(),
+                            reply
+                        );
+                        if write.write_all(response.as_bytes()).await.is_err() {
+                            return;
+                        }
+                    }
+                });
+            }
+        });
+
+        StandIn { url, calls }
+    }
+
+    /// Wrap `node` so each raw transaction is mined at once:
+    /// `eth_sendRawTransaction` answers with the transaction's hash and
+    /// `eth_getTransactionReceipt` with `receipt` and `logs` under that hash
+    pub fn mining(
+        receipt: Value,
+        logs: Value,
+        node: impl Fn(&str, &Value) -> Result<Value, NodeError> + Send + Sync + 'static,
+    ) -> impl Fn(&str, &Value) -> Result<Value, NodeError> + Send + Sync + 'static {
+        move |method, params| match method {
+            "eth_sendRawTransaction" => {
+                let raw = hex::decode(params[0].as_str().unwrap().trim_start_matches("0x")).unwrap();
+                Ok(json!(super::to_hex(&crate::tx::keccak256(&raw))))
+            }
+            "eth_getTransactionReceipt" => {
+                let mut receipt = receipt.clone();
+                receipt["transactionHash"] = params[0].clone();
+                receipt["logs"] = logs.clone();
+                Ok(receipt)
+            }
+            _ => node(method, params),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use test_node::NodeError;
+
+    const ACCOUNT: [u8; 20] = [0xaa; 20];
+    const TX_HASH: [u8; 32] = [0x5e; 32];
+
+    fn receipt_json() -> Value {
+        json!({
+            "transactionHash": to_hex(&TX_HASH),
+            "blockNumber": "0x1b4",
+            "status": "0x1",
+            "gasUsed": "0x5208",
+            "effectiveGasPrice": "0x3b9aca00",
+            "contractAddress": null,
+            "logs": [{
+                "address": to_hex(&ACCOUNT),
+                "topics": [to_hex(&[0x01; 32]), to_hex(&[0x02; 32])],
+                "data": "0x0102",
+                "logIndex": "0x0",
+            }],
+            "cumulativeGasUsed": "0x5208",
+        })
+    }
+
+    #[tokio::test]
+    async fn test_get_code_sends_eth_get_code_and_decodes_hex() {
+        let node = test_node::spawn(|method, _| match method {
+            "eth_getCode" => Ok(json!("0x50564d00")),
+            _ => Err(NodeError::method_not_found()),
+        })
+        .await;
+
+        let rpc = RpcClient::new(&node.url);
+        let address = "0x00000000000000000000000000000000000000aa";
+        assert_eq!(rpc.get_code(address).await.unwrap(), b"PVM\0");
+        assert_eq!(node.calls(), vec![("eth_getCode".to_string(), json!([address, "latest"]))]);
+    }
+
+    #[tokio::test]
+    async fn test_rpc_error_is_reported() {
+        let node = test_node::spawn(|_, _| Err(NodeError::new(-32000, "header not found"))).await;
+
+        let err = RpcClient::new(&node.url).get_code("0x00000000000000000000000000000000000000aa").await.unwrap_err();
+        assert!(err.to_string().contains("RPC error -32000: header not found"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_quantities() {
+        let node = test_node::spawn(|method, _| match method {
+            "eth_chainId" => Ok(json!("0x138b")),
+            "eth_blockNumber" => Ok(json!("0x0")),
+            "eth_getBalance" => Ok(json!("0xde0b6b3a7640000")),
+            "eth_getTransactionCount" => Ok(json!("0x7")),
+            "eth_gasPrice" => Ok(json!("0x4a817c800")),
+            "eth_maxPriorityFeePerGas" => Ok(json!("0x3b9aca00")),
+            _ => Err(NodeError::method_not_found()),
+        })
+        .await;
+
+        let rpc = RpcClient::new(&node.url);
+        assert_eq!(rpc.chain_id().await.unwrap(), 5003);
+        assert_eq!(rpc.block_number().await.unwrap(), 0);
+        assert_eq!(rpc.balance(&ACCOUNT).await.unwrap(), 1_000_000_000_000_000_000);
+        assert_eq!(rpc.nonce(&ACCOUNT).await.unwrap(), 7);
+        assert_eq!(rpc.gas_price().await.unwrap(), 20_000_000_000);
+        assert_eq!(rpc.max_priority_fee().await.unwrap(), 1_000_000_000);
+
+        let account = to_hex(&ACCOUNT);
+        assert_eq!(node.calls()[2], ("eth_getBalance".to_string(), json!([account, "latest"])));
+        // Pending, so back-to-back sends do not reuse a nonce
+        assert_eq!(node.calls()[3], ("eth_getTransactionCount".to_string(), json!([account, "pending"])));
+    }
+
+    #[tokio::test]
+    async fn test_malformed_quantity_is_an_error() {
+        let node = test_node::spawn(|method, _| match method {
+            "eth_chainId" => Ok(json!("5003")),
+            "eth_blockNumber" => Ok(json!("0x")),
+            _ => Ok(json!("0x1ffffffffffffffff")),
+        })
+        .await;
+
+        let rpc = RpcClient::new(&node.url);
+        assert!(rpc.chain_id().await.is_err());
+        assert!(rpc.block_number().await.is_err());
+        let err = rpc.nonce(&ACCOUNT).await.unwrap_err();
+        assert!(err.to_string().contains("out of range"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_call_and_estimate_gas_send_the_request() {
+        let node = test_node::spawn(|method, _| match method {
+            "eth_call" => Ok(json!("0x0000000000000000000000000000000000000000000000000000000000000001")),
+            "eth_estimateGas" => Ok(json!("0x124f8")),
+            _ => Err(NodeError::method_not_found()),
+        })
+        .await;
+
+        let rpc = RpcClient::new(&node.url);
+        let call = CallRequest {
+            from: Some([0x11; 20]),
+            ..CallRequest::new(ACCOUNT, vec![0xb1, 0x18, 0x70, 0xc7])
+        };
+        assert_eq!(rpc.call(&call).await.unwrap()[31], 1);
+        assert_eq!(rpc.estimate_gas(&call).await.unwrap(), 75_000);
+
+        let expected = json!({ "from": to_hex(&[0x11; 20]), "to": to_hex(&ACCOUNT), "data": "0xb11870c7" });
+        assert_eq!(
+            node.calls(),
+            vec![
+                ("eth_call".to_string(), json!([expected, "latest"])),
+                ("eth_estimateGas".to_string(), json!([expected])),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_call_revert_keeps_revert_data() {
+        let node = test_node::spawn(|_, _| Err(NodeError::revert(&[0x09, 0xbd, 0xe3, 0x39]))).await;
+
+        let err = RpcClient::new(&node.url).call(&CallRequest::new(ACCOUNT, Vec::new())).await.unwrap_err();
+        let rpc_error = err.downcast_ref::<RpcError>().expect("RpcError");
+        assert_eq!(rpc_error.code, 3);
+        assert_eq!(rpc_error.revert_data(), Some(vec![0x09, 0xbd, 0xe3, 0x39]));
+    }
+
+    #[tokio::test]
+    async fn test_send_raw_transaction_and_wait_for_receipt() {
+        let polls = Arc::new(AtomicUsize::new(0));
+        let counter = polls.clone();
+        let node = test_node::spawn(move |method, _| match method {
+            "eth_sendRawTransaction" => Ok(json!(to_hex(&TX_HASH))),
+            // Pending for the first two polls
+            "eth_getTransactionReceipt" if counter.fetch_add(1, Ordering::SeqCst) < 2 => Ok(Value::Null),
+            "eth_getTransactionReceipt" => Ok(receipt_json()),
+            _ => Err(NodeError::method_not_found()),
+        })
+        .await;
+
+        let rpc = RpcClient::new(&node.url);
+        let hash = rpc.send_raw_transaction(&[0x02, 0xf8, 0x6c]).await.unwrap();
+        assert_eq!(hash, TX_HASH);
+        assert_eq!(node.calls()[0], ("eth_sendRawTransaction".to_string(), json!(["0x02f86c"])));
+
+        let receipt = rpc.wait_for_receipt(&hash, Duration::from_millis(1), Duration::from_secs(5)).await.unwrap();
+        assert_eq!(polls.load(Ordering::SeqCst), 3);
+        assert!(receipt.succeeded());
+        assert_eq!(receipt.block_number, 436);
+        assert_eq!(receipt.gas_used, 21_000);
+        assert_eq!(receipt.effective_gas_price, Some(1_000_000_000));
+        assert_eq!(receipt.contract_address, None);
+        assert_eq!(
+            receipt.logs,
+            vec![Log {
+                address: ACCOUNT,
+                topics: vec![[0x01; 32], [0x02; 32]],
+                data: vec![0x01, 0x02],
+                block_number: None,
+                block_hash: None,
+                transaction_hash: None,
+                log_index: Some(0),
+                removed: false,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_logs_and_block_header() {
+        let node = test_node::spawn(|method, params| match method {
+            "eth_getLogs" => Ok(json!([{
+                "address": to_hex(&ACCOUNT),
+                "topics": [to_hex(&[0x01; 32])],
+                "data": "0x",
+                "blockNumber": params[0]["fromBlock"],
+                "blockHash": to_hex(&[0xbb; 32]),
+                "transactionHash": to_hex(&TX_HASH),
+                "logIndex": "0x3",
+                "removed": false,
+            }])),
+            "eth_getBlockByNumber" if params[0] == "0x1b4" => Ok(json!({ "number": "0x1b4", "hash": to_hex(&[0xbb; 32]), "timestamp": "0x6553f100" })),
+            "eth_getBlockByNumber" => Ok(Value::Null),
+            _ => Err(NodeError::method_not_found()),
+        })
+        .await;
+
+        let rpc = RpcClient::new(&node.url);
+        let filter = LogFilter {
+            address: ACCOUNT,
+            topics: vec![[0x01; 32], [0x02; 32]],
+            from_block: 436,
+            to_block: 1000,
+        };
+        let logs = rpc.get_logs(&filter).await.unwrap();
+        assert_eq!(logs.len(), 1);
+        assert_eq!((logs[0].block_number, logs[0].log_index), (Some(436), Some(3)));
+        assert_eq!(logs[0].block_hash, Some([0xbb; 32]));
+        assert_eq!(logs[0].transaction_hash, Some(TX_HASH));
+        // The first-topic alternatives go in one nested array
+        assert_eq!(
+            node.calls()[0].1,
+            json!([{ "address": to_hex(&ACCOUNT), "topics": [[to_hex(&[0x01; 32]), to_hex(&[0x02; 32])]], "fromBlock": "0x1b4", "toBlock": "0x3e8" }])
+        );
+
+        let header = rpc.block_header(436).await.unwrap().unwrap();
+        assert_eq!((header.number, header.hash, header.timestamp), (436, [0xbb; 32], 1_700_000_000));
+        assert_eq!(rpc.block_header(437).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_receipt_times_out() {
+        let node = test_node::spawn(|_, _| Ok(Value::Null)).await;
+
+        let rpc = RpcClient::new(&node.url);
+        let err = rpc.wait_for_receipt(&TX_HASH, Duration::from_millis(1), Duration::ZERO).await.unwrap_err();
+        assert!(err.to_string().contains("was not mined"), "{}", err);
+        assert_eq!(rpc.transaction_receipt(&TX_HASH).await.unwrap(), None);
+    }
+
+    #[test]
+    fn test_parse_address() {
+        assert_eq!(parse_address("0x00000000000000000000000000000000000000aa").unwrap()[19], 0xaa);
+        for bad in ["00000000000000000000000000000000000000aa", "0xaa", "0xzz000000000000000000000000000000000000aa"] {
+            let err = parse_address(bad).unwrap_err();
+            assert!(matches!(err.downcast_ref::<ProverError>(), Some(ProverError::InvalidInput(_))), "{}", bad);
+        }
+    }
+}
@@ -0,0 +1,480 @@
+// `submit-proof`: preflight a `verify` call against the workspace key and report
+// failures the way the contract does, as one of its custom errors
+// (`verifier_core::errors::ContractError`) with the matching revert data. A proof
+// that passes is simulated with `eth_call` and, unless `--dry-run`, signed and
+// sent to the network's registry (as `verifyProof`) or straight to its verifier.
+// Every mined submission, reverted or not, is added to the ledger.
+
+use anyhow::{bail, Result};
+use std::path::Path;
+use std::time::Duration;
+
+use prover::circuit::{MUL_CIRCUIT_ID, MUL_CIRCUIT_VERSION};
+use prover::workspace::Workspace;
+use prover::ProverError;
+use verifier_core::abi::{self, WORD};
+use verifier_core::errors::ContractError;
+
+use crate::ledger::{Entry, Ledger, SubmissionRecord, SubmissionStatus};
+use crate::networks::{self, Network};
+use crate::registry::{self, ProofTarget};
+use crate::rpc::{self, CallRequest, RpcClient, RpcError, TransactionReceipt};
+use crate::trust_store;
+use crate::tx;
+use crate::wallet::{self, Wallet, WalletStore};
+
+/// keccak256("Error(string)")[..4], the selector of `require` reasons
+const ERROR_STRING_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// The arguments of a `verify` call as `prove` writes it to calldata.bin
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofBundle {
+    pub circuit_id: u64,
+    pub proof: Vec<u8>,
+    /// Public inputs as consecutive 32-byte words
+    pub input_words: Vec<u8>,
+}
+
+impl ProofBundle {
+    /// Split `verify(circuitId, proof, inputs)` calldata into its arguments
+    pub fn decode(calldata: &[u8]) -> Result<Self> {
+        let malformed = |what: &str| ProverError::InvalidInput(format!("not verify(uint256,bytes,uint256[]) calldata: {}", what));
+        let args = match abi::split_selector(calldata) {
+            Ok((abi::VERIFY_SELECTOR, args)) => args,
+            Ok((selector, _)) => return Err(malformed(&format!("selector 0x{}", hex::encode(selector))).into()),
+            Err(_) => return Err(malformed("shorter than a selector").into()),
+        };
+        let circuit_id = abi::read_u64(args, 0).ok_or_else(|| malformed("circuit id"))?;
+        let proof = abi::read_bytes(args, WORD).map_err(|_| malformed("proof"))?;
+        let input_words = abi::read_words(args, 2 * WORD).map_err(|_| malformed("public inputs"))?;
+        Ok(Self {
+            circuit_id,
+            proof: proof.to_vec(),
+            input_words: input_words.to_vec(),
+        })
+    }
+
+    /// keccak256 of the public input words, which ties ledger entries together
+    pub fn public_inputs_hash(&self) -> String {
+        rpc::to_hex(&tx::keccak256(&self.input_words))
+    }
+
+    /// Calldata for `target`: `verifyProof` for a registry, `verify` for a verifier
+    pub fn calldata_for(&self, target: &ProofTarget) -> Vec<u8> {
+        match target {
+            ProofTarget::Registry(_) => registry::encode_verify_proof(&self.proof, &self.input_words),
+            ProofTarget::Verifier(_) => abi::encode_raw_verify_call(self.circuit_id, &self.proof, &self.input_words),
+        }
+    }
+}
+
+/// A mined submission
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub receipt: TransactionReceipt,
+    /// `proofHash` and `isValid` from the registry's `ProofVerified` event
+    pub proof_verified: Option<([u8; 32], bool)>,
+    /// The registry call was mined but emitted no `ProofVerified` event for the wallet
+    pub missing_event: bool,
+}
+
+impl Submission {
+    /// Reverted, else the event's `isValid` (unknown without it), else what the
+    /// simulation returned
+    pub fn status(&self, simulated: bool) -> SubmissionStatus {
+        let valid = match self.proof_verified {
+            _ if !self.receipt.succeeded() => return SubmissionStatus::Reverted,
+            _ if self.missing_event => return SubmissionStatus::Unknown,
+            Some((_, is_valid)) => is_valid,
+            None => simulated,
+        };
+        if valid {
+            SubmissionStatus::Valid
+        } else {
+            SubmissionStatus::Invalid
+        }
+    }
+
+    pub fn record(&self, network: &Network, target: &ProofTarget, from: &[u8; 20], bundle: &ProofBundle, status: SubmissionStatus) -> SubmissionRecord {
+        SubmissionRecord {
+            network: network.name.clone(),
+            chain_id: network.chain_id,
+            target: match target {
+                ProofTarget::Registry(_) => "registry".to_string(),
+                ProofTarget::Verifier(_) => "verifier".to_string(),
+            },
+            contract_address: wallet::checksum_address(&target.address()),
+            from: Some(wallet::checksum_address(from)),
+            tx_hash: rpc::to_hex(&self.receipt.transaction_hash),
+            status,
+            public_inputs_hash: bundle.public_inputs_hash(),
+            proof_hash: self.proof_verified.map(|(proof_hash, _)| rpc::to_hex(&proof_hash)),
+            block_number: self.receipt.block_number,
+            gas_used: self.receipt.gas_used,
+            effective_gas_price: self.receipt.effective_gas_price,
+            submitted_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn handle_submit_proof(
+    workspace: &Workspace,
+    proof_file: Option<&Path>,
+    network: Option<&str>,
+    rpc_url: Option<&str>,
+    registry: Option<&str>,
+    verifier: Option<&str>,
+    wallet_name: &str,
+    passphrase_file: Option<&Path>,
+    dry_run: bool,
+) -> Result<()> {
+    println!("📤 Submitting proof for verification...");
+    let calldata_path = proof_file.map(Path::to_path_buf).unwrap_or_else(|| workspace.calldata_path());
+    let calldata = std::fs::read(&calldata_path).map_err(ProverError::from)?;
+    preflight(workspace, &calldata_path, &calldata)?;
+    let bundle = ProofBundle::decode(&calldata)?;
+
+    let network = networks::resolve(workspace, network, rpc_url, registry)?;
+    let target = match verifier {
+        Some(verifier) => ProofTarget::Verifier(rpc::parse_address(verifier)?),
+        None => network.proof_target()?,
+    };
+    match target {
+        ProofTarget::Registry(address) => {
+            println!("📋 Registry: {} (verifyProof, checked under the registry's circuit id)", wallet::checksum_address(&address))
+        }
+        ProofTarget::Verifier(address) => println!("📋 Verifier: {} (verify, circuit {})", wallet::checksum_address(&address), bundle.circuit_id),
+    }
+
+    let store = WalletStore::open(workspace);
+    let rpc = RpcClient::new(&network.rpc_url);
+    if dry_run {
+        // A simulation needs no key; without the wallet the call has no sender
+        let from = store.load(wallet_name).ok().and_then(|keystore| keystore.address());
+        let valid = simulate(&rpc, &target, from, &bundle).await?;
+        println!("🧪 Dry run on {}: {} would return {}", network.name, call_name(&target), valid);
+        println!("   Nothing was signed or sent");
+        return Ok(());
+    }
+
+    let wallet = store.unlock(wallet_name, passphrase_file)?;
+    println!("💼 Wallet '{}': {}", wallet_name, wallet::checksum_address(&wallet.address()));
+    let simulated = simulate(&rpc, &target, Some(wallet.address()), &bundle).await?;
+    println!("🧪 Simulation: {} returns {}", call_name(&target), simulated);
+
+    let submission = submit(&rpc, &wallet, &network, &target, &bundle, registry::RECEIPT_POLL).await?;
+    let status = submission.status(simulated);
+    Ledger::append(workspace, Entry::Submission(submission.record(&network, &target, &wallet.address(), &bundle, status)))?;
+    let receipt = &submission.receipt;
+    if status == SubmissionStatus::Reverted {
+        bail!("{} reverted in block {} (tx {})", call_name(&target), receipt.block_number, rpc::to_hex(&receipt.transaction_hash));
+    }
+    if status == SubmissionStatus::Unknown {
+        bail!(
+            "transaction {} succeeded but emitted no ProofVerified event for this wallet; recorded with an unknown verdict",
+            rpc::to_hex(&receipt.transaction_hash)
+        );
+    }
+    match submission.proof_verified {
+        Some((proof_hash, is_valid)) => {
+            println!("{} ProofVerified(isValid = {}) · proof hash {}", verdict_icon(is_valid), is_valid, rpc::to_hex(&proof_hash))
+        }
+        // Verifiers emit no event; a mined call returns what the simulation did
+        None => println!("{} verify returned {}", verdict_icon(simulated), simulated),
+    }
+    println!("🧱 Block {} · gas used {}", receipt.block_number, receipt.gas_used);
+    if status == SubmissionStatus::Invalid {
+        return Err(ProverError::InvalidInput(format!("{} was recorded as invalid", calldata_path.display())).into());
+    }
+    Ok(())
+}
+
+/// Same decoding and checks as the contract; storage state (deprecated keys,
+/// used nullifiers) is only known on-chain.
+fn preflight(workspace: &Workspace, calldata_path: &Path, calldata: &[u8]) -> Result<()> {
+    let vk_path = workspace.verifying_key_path();
+    trust_store::ensure_matches_contract(workspace, &vk_path, MUL_CIRCUIT_ID, MUL_CIRCUIT_VERSION)?;
+    let vk_bytes = std::fs::read(&vk_path).map_err(ProverError::from)?;
+
+    let verdict = verifier_core::verify_calldata(calldata, &vk_bytes);
+    if let Some(error) = ContractError::from_verdict(&verdict) {
+        println!("❌ Preflight: verify would revert with {}", error);
+        println!("   Revert data: 0x{}", hex::encode(error.encode()));
+        println!("   (contracts built with --features legacy-bool return false instead)");
+        return Err(ProverError::InvalidInput(format!("{}: proof would be rejected ({})", calldata_path.display(), error.signature())).into());
+    }
+    println!("✅ Preflight: verify would return true");
+    Ok(())
+}
+
+/// `eth_call` the submission and decode the returned `bool`
+pub async fn simulate(rpc: &RpcClient, target: &ProofTarget, from: Option<[u8; 20]>, bundle: &ProofBundle) -> Result<bool> {
+    let call = CallRequest {
+        from,
+        ..CallRequest::new(target.address(), bundle.calldata_for(target))
+    };
+    let output = rpc.call(&call).await.map_err(|err| explain_revert_error(target, err))?;
+    decode_bool(&output).ok_or_else(|| anyhow::anyhow!("{} returned {}, expected a bool", call_name(target), rpc::to_hex(&output)))
+}
+
+/// Sign and send the submission, wait for it and decode the registry's
+/// `ProofVerified` event. A verifier emits none, so only the mined status is known.
+/// A reverted transaction, or a registry call without the event, is returned
+/// too, so that it can be recorded.
+pub async fn submit(
+    rpc: &RpcClient,
+    wallet: &Wallet,
+    network: &Network,
+    target: &ProofTarget,
+    bundle: &ProofBundle,
+    poll: Duration,
+) -> Result<Submission> {
+    let calldata = bundle.calldata_for(target);
+    let hash = tx::send_transaction(rpc, wallet, network.chain_id, network.fee_model, Some(target.address()), calldata)
+        .await
+        .map_err(|err| explain_revert_error(target, err))?;
+    println!("📨 Sent {}", rpc::to_hex(&hash));
+
+    let receipt = rpc.wait_for_receipt(&hash, poll, registry::RECEIPT_TIMEOUT).await?;
+    let proof_verified = match target {
+        ProofTarget::Registry(address) if receipt.succeeded() => receipt
+            .logs
+            .iter()
+            .filter_map(|log| registry::decode_proof_verified(log, address))
+            .find(|(builder, _, _)| *builder == wallet.address())
+            .map(|(_, proof_hash, is_valid)| (proof_hash, is_valid)),
+        _ => None,
+    };
+    Ok(Submission {
+        missing_event: matches!(target, ProofTarget::Registry(_)) && receipt.succeeded() && proof_verified.is_none(),
+        receipt,
+        proof_verified,
+    })
+}
+
+fn call_name(target: &ProofTarget) -> &'static str {
+    match target {
+        ProofTarget::Registry(_) => "verifyProof",
+        ProofTarget::Verifier(_) => "verify",
+    }
+}
+
+fn verdict_icon(valid: bool) -> &'static str {
+    if valid {
+        "✅"
+    } else {
+        "❌"
+    }
+}
+
+fn decode_bool(output: &[u8]) -> Option<bool> {
+    match output {
+        word if word == abi::encode_bool(true) => Some(true),
+        word if word == abi::encode_bool(false) => Some(false),
+        _ => None,
+    }
+}
+
+/// Turn a reverting `eth_call` / `eth_estimateGas` into an input error naming the revert
+fn explain_revert_error(target: &ProofTarget, err: anyhow::Error) -> anyhow::Error {
+    match err.downcast_ref::<RpcError>().and_then(RpcError::revert_data) {
+        Some(data) => ProverError::InvalidInput(format!("{} reverted: {}", call_name(target), describe_revert(&data))).into(),
+        None => err,
+    }
+}
+
+/// A verifier custom error, a `require` reason, or the raw data
+fn describe_revert(data: &[u8]) -> String {
+    if let Some(error) = ContractError::decode(data) {
+        return error.to_string();
+    }
+    if let Some(args) = data.strip_prefix(&ERROR_STRING_SELECTOR) {
+        if let Ok(reason) = abi::read_bytes(args, 0) {
+            return format!("\"{}\"", String::from_utf8_lossy(reason));
+        }
+    }
+    if data.is_empty() {
+        "no revert data".to_string()
+    } else {
+        rpc::to_hex(data)
+    }
+}
+
+/// Name the custom error behind revert data returned by the verifier contract
+pub fn explain_revert(data: &str) -> Result<()> {
+    let bytes = hex::decode(data.trim().trim_start_matches("0x"))
+        .map_err(|err| ProverError::InvalidInput(format!("revert data is not hex: {}", err)))?;
+    let error = ContractError::decode(&bytes)
+        .ok_or_else(|| ProverError::InvalidInput(format!("0x{} is not a niet2code verifier error", hex::encode(&bytes))))?;
+    println!("❌ verify reverted: {}", error);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rpc::test_node::{self, NodeError};
+    use serde_json::{json, Value};
+
+    // Canonical vector from `prover::test_vectors` (circuit 1: a = 3, b = 4, c = 12)
+    const CALLDATA: &[u8] = include_bytes!("../../test-vectors/mul/calldata.bin");
+    const KEY: [u8; 32] = [0x46; 32];
+    const REGISTRY: [u8; 20] = [0x79; 20];
+    const VERIFIER: [u8; 20] = [0x5e; 20];
+
+    fn proof_verified_log(builder: &[u8; 20], is_valid: bool) -> Value {
+        json!({
+            "address": rpc::to_hex(&REGISTRY),
+            "topics": [rpc::to_hex(&registry::PROOF_VERIFIED_TOPIC), rpc::to_hex(&abi::encode_address(builder))],
+            "data": rpc::to_hex(&[[0x33; WORD], abi::encode_bool(is_valid)].concat()),
+        })
+    }
+
+    /// Node on chain 5003 whose `eth_call` answers with `call`, and which mines
+    /// one transaction with `logs`
+    fn stand_in(call: impl Fn() -> Result<Value, NodeError> + Send + Sync + 'static, logs: Value) -> impl Fn(&str, &Value) -> Result<Value, NodeError> {
+        let receipt = json!({ "blockNumber": "0x20", "status": "0x1", "gasUsed": "0x2bf20" });
+        test_node::mining(receipt, logs, move |method, _| match method {
+            "eth_chainId" => Ok(json!("0x138b")),
+            "eth_call" => call(),
+            "eth_estimateGas" => call().map(|_| json!("0x30d40")),
+            "eth_gasPrice" => Ok(json!("0x3b9aca00")),
+            "eth_maxPriorityFeePerGas" => Err(NodeError::method_not_found()),
+            "eth_getTransactionCount" => Ok(json!("0x0")),
+            _ => Err(NodeError::method_not_found()),
+        })
+    }
+
+    fn returns(valid: bool) -> impl Fn() -> Result<Value, NodeError> + Send + Sync + 'static {
+        move || Ok(json!(rpc::to_hex(&abi::encode_bool(valid))))
+    }
+
+    #[test]
+    fn test_bundle_re_encodes_for_each_target() {
+        let bundle = ProofBundle::decode(CALLDATA).unwrap();
+        assert_eq!(bundle.circuit_id, prover::circuit::MUL_CIRCUIT.id);
+        assert_eq!(bundle.input_words.len(), WORD);
+
+        // The verifier takes the bundle as it is
+        assert_eq!(bundle.calldata_for(&ProofTarget::Verifier(VERIFIER)), CALLDATA);
+
+        let calldata = bundle.calldata_for(&ProofTarget::Registry(REGISTRY));
+        assert_eq!(calldata[..4], registry::VERIFY_PROOF_SELECTOR);
+        assert_eq!(abi::read_bytes(&calldata[4..], 0).unwrap(), bundle.proof);
+        assert_eq!(abi::read_words(&calldata[4..], WORD).unwrap(), bundle.input_words);
+
+        let err = ProofBundle::decode(&calldata).unwrap_err();
+        assert!(matches!(err.downcast_ref::<ProverError>(), Some(ProverError::InvalidInput(_))), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_only_calls() {
+        let bundle = ProofBundle::decode(CALLDATA).unwrap();
+        let node = test_node::spawn(stand_in(returns(true), json!([]))).await;
+        let rpc = RpcClient::new(&node.url);
+
+        let valid = simulate(&rpc, &ProofTarget::Registry(REGISTRY), Some([0x11; 20]), &bundle).await.unwrap();
+        assert!(valid);
+        let calls = node.calls();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].0, "eth_call");
+        assert_eq!(calls[0].1[0]["to"], rpc::to_hex(&REGISTRY));
+        assert_eq!(calls[0].1[0]["from"], rpc::to_hex(&[0x11; 20]));
+        assert_eq!(calls[0].1[0]["data"], rpc::to_hex(&bundle.calldata_for(&ProofTarget::Registry(REGISTRY))));
+
+        // Without a wallet the call goes out without a sender
+        assert!(simulate(&rpc, &ProofTarget::Registry(REGISTRY), None, &bundle).await.unwrap());
+        assert!(node.calls()[1].1[0].get("from").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_simulation_names_the_revert() {
+        let bundle = ProofBundle::decode(CALLDATA).unwrap();
+
+        let node = test_node::spawn(stand_in(|| Err(NodeError::revert(&ContractError::InvalidProof.encode())), json!([]))).await;
+        let err = simulate(&RpcClient::new(&node.url), &ProofTarget::Verifier(VERIFIER), None, &bundle).await.unwrap_err();
+        assert!(matches!(err.downcast_ref::<ProverError>(), Some(ProverError::InvalidInput(_))), "{}", err);
+        assert!(err.to_string().contains("InvalidProof"), "{}", err);
+
+        let mut reason = ERROR_STRING_SELECTOR.to_vec();
+        reason.extend_from_slice(&abi::encode_string("Builder not registered"));
+        let node = test_node::spawn(stand_in(move || Err(NodeError::revert(&reason)), json!([]))).await;
+        let err = simulate(&RpcClient::new(&node.url), &ProofTarget::Registry(REGISTRY), None, &bundle).await.unwrap_err();
+        assert!(err.to_string().contains("verifyProof reverted: \"Builder not registered\""), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_submit_to_registry_reports_proof_verified() {
+        let wallet = Wallet::from_private_key(&KEY).unwrap();
+        let bundle = ProofBundle::decode(CALLDATA).unwrap();
+        let node = test_node::spawn(stand_in(returns(true), json!([proof_verified_log(&[0x22; 20], true), proof_verified_log(&wallet.address(), false)]))).await;
+
+        let submission = submit(&RpcClient::new(&node.url), &wallet, &Network::local(&node.url, None), &ProofTarget::Registry(REGISTRY), &bundle, Duration::ZERO).await.unwrap();
+        assert_eq!(submission.proof_verified, Some(([0x33; WORD], false)));
+        assert_eq!(submission.receipt.block_number, 32);
+        // The event's verdict wins over the simulation's
+        assert_eq!(submission.status(true), SubmissionStatus::Invalid);
+
+        let calls = node.calls();
+        let sent = calls.iter().find(|(method, _)| method == "eth_sendRawTransaction").unwrap();
+        let raw = hex::decode(sent.1[0].as_str().unwrap().trim_start_matches("0x")).unwrap();
+        // Legacy, since the node has no eth_maxPriorityFeePerGas
+        assert!(raw[0] >= 0xc0);
+        let calldata = bundle.calldata_for(&ProofTarget::Registry(REGISTRY));
+        assert!(raw.windows(calldata.len()).any(|window| window == calldata));
+    }
+
+    #[tokio::test]
+    async fn test_submit_to_verifier_sends_raw_calldata() {
+        let wallet = Wallet::from_private_key(&KEY).unwrap();
+        let bundle = ProofBundle::decode(CALLDATA).unwrap();
+        let node = test_node::spawn(stand_in(returns(true), json!([]))).await;
+
+        let submission = submit(&RpcClient::new(&node.url), &wallet, &Network::local(&node.url, None), &ProofTarget::Verifier(VERIFIER), &bundle, Duration::ZERO).await.unwrap();
+        assert!(submission.receipt.succeeded());
+        assert_eq!(submission.proof_verified, None);
+
+        let calls = node.calls();
+        let sent = calls.iter().find(|(method, _)| method == "eth_sendRawTransaction").unwrap();
+        let raw = hex::decode(sent.1[0].as_str().unwrap().trim_start_matches("0x")).unwrap();
+        assert!(raw.windows(CALLDATA.len()).any(|window| window == CALLDATA));
+        assert!(raw.windows(20).any(|window| window == VERIFIER));
+    }
+
+    #[tokio::test]
+    async fn test_reverted_submission_is_returned_for_the_ledger() {
+        let wallet = Wallet::from_private_key(&KEY).unwrap();
+        let bundle = ProofBundle::decode(CALLDATA).unwrap();
+        let reverted = json!({ "blockNumber": "0x20", "status": "0x0", "gasUsed": "0x5208" });
+        let node = test_node::spawn(test_node::mining(reverted, json!([]), stand_in(returns(true), json!([])))).await;
+
+        let network = Network::local(&node.url, None);
+        let target = ProofTarget::Registry(REGISTRY);
+        let submission = submit(&RpcClient::new(&node.url), &wallet, &network, &target, &bundle, Duration::ZERO).await.unwrap();
+        assert_eq!(submission.status(true), SubmissionStatus::Reverted);
+
+        let record = submission.record(&network, &target, &wallet.address(), &bundle, SubmissionStatus::Reverted);
+        assert_eq!(record.target, "registry");
+        assert_eq!(record.from, Some(wallet::checksum_address(&wallet.address())));
+        assert_eq!(record.contract_address, wallet::checksum_address(&REGISTRY));
+        assert_eq!(record.gas_used, 21_000);
+        assert_eq!(record.public_inputs_hash, rpc::to_hex(&tx::keccak256(&bundle.input_words)));
+        assert_eq!(record.proof_hash, None);
+    }
+
+    #[tokio::test]
+    async fn test_submit_reports_missing_event() {
+        let wallet = Wallet::from_private_key(&KEY).unwrap();
+        let bundle = ProofBundle::decode(CALLDATA).unwrap();
+        let node = test_node::spawn(stand_in(returns(true), json!([proof_verified_log(&[0x22; 20], true)]))).await;
+
+        let network = Network::local(&node.url, None);
+        let target = ProofTarget::Registry(REGISTRY);
+        let submission = submit(&RpcClient::new(&node.url), &wallet, &network, &target, &bundle, Duration::ZERO).await.unwrap();
+        assert!(submission.missing_event);
+        assert_eq!(submission.status(true), SubmissionStatus::Unknown);
+        let record = submission.record(&network, &target, &wallet.address(), &bundle, SubmissionStatus::Unknown);
+        assert_eq!((record.status.as_str(), record.proof_hash), ("unknown", None));
+    }
+}
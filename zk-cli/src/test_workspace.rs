@@ -0,0 +1,32 @@
+// Throwaway workspace for tests: a fresh home under the system temp directory,
+// removed again when the test drops it (also when it fails).
+
+use std::ops::Deref;
+
+use prover::workspace::Workspace;
+
+pub struct TempWorkspace(Workspace);
+
+impl TempWorkspace {
+    /// Empty home `niet2code-<name>-<pid>` with the `default` project; `name`
+    /// must be unique across the crate's tests since they run in one process
+    pub fn new(name: &str) -> Self {
+        let home = std::env::temp_dir().join(format!("niet2code-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&home);
+        Self(Workspace::new(home, "default"))
+    }
+}
+
+impl Deref for TempWorkspace {
+    type Target = Workspace;
+
+    fn deref(&self) -> &Workspace {
+        &self.0
+    }
+}
+
+impl Drop for TempWorkspace {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(self.0.home());
+    }
+}
@@ -6,6 +6,15 @@ use std::collections::HashMap;
 use anyhow::Result;
 use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
 
+use prover::workspace::Workspace;
+
+use crate::ledger::{Deployment, Ledger};
+use crate::networks::{self, Network};
+use crate::rpc::RpcClient;
+
+/// Ledger `source` of deployments made through the ThirdWeb API
+const LEDGER_SOURCE: &str = "thirdweb";
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ThirdWebConfig {
     pub client_id: String,
@@ -110,10 +119,11 @@ pub struct ThirdWebIntegration {
     config: ThirdWebConfig,
     client: reqwest::Client,
     available_templates: Vec<ContractTemplate>,
+    workspace: Workspace,
 }
 
 impl ThirdWebIntegration {
-    pub fn new() -> Result<Self> {
+    pub fn new(workspace: &Workspace) -> Result<Self> {
         // Load credentials from environment variables
         let config = ThirdWebConfig {
             client_id: std::env::var("THIRDWEB_CLIENT_ID")
@@ -141,6 +151,7 @@ impl ThirdWebIntegration {
             config,
             client,
             available_templates: templates,
+            workspace: workspace.clone(),
         })
     }
 
@@ -301,14 +312,13 @@ impl ThirdWebIntegration {
     /// Deploy ERC721 NFT contract with real ThirdWeb API
     pub async fn deploy_nft_contract(
         &self,
+        network: &Network,
         name: &str,
         symbol: &str,
         description: &str,
     ) -> Result<DeploymentResult> {
         println!("🚀 Deploying NFT contract via ThirdWeb API...");
 
-        let chain_id = 5003; // Mantle testnet
-        
         let deploy_request = ThirdWebDeployRequest {
             metadata: ContractMetadata {
                 name: name.to_string(),
@@ -331,7 +341,7 @@ impl ThirdWebIntegration {
             ],
         };
 
-        let url = format!("{}/v1/deploy/{}/erc721", self.config.base_url, chain_id);
+        let url = format!("{}/v1/deploy/{}/erc721", self.config.base_url, network.chain_id);
         
         let response = self.client
             .post(&url)
@@ -349,12 +359,12 @@ impl ThirdWebIntegration {
         let result = DeploymentResult {
             contract_address: deployment.contract_address.clone(),
             transaction_hash: deployment.transaction_hash,
-            network: "mantle-testnet".to_string(),
+            network: network.name.clone(),
             gas_used: deployment.deploy_transaction.gas_used
                 .and_then(|g| g.parse().ok())
                 .unwrap_or(2_500_000),
-            deployment_cost: "0.05 MNT".to_string(),
-            thirdweb_dashboard_url: format!("https://thirdweb.com/mantle-testnet/{}", deployment.contract_address),
+            deployment_cost: format!("0.05 {}", network.native_currency.symbol),
+            thirdweb_dashboard_url: dashboard_url(network, &deployment.contract_address),
             privacy_features: vec![
                 "ZK Ownership Proofs".to_string(),
                 "Anonymous Minting".to_string(),
@@ -366,92 +376,13 @@ impl ThirdWebIntegration {
         println!("📍 Address: {}", result.contract_address);
         println!("🔗 Transaction: {}", result.transaction_hash);
         
-        // Save deployment record
-        self.save_deployment_record(&result)?;
+        self.save_deployment_record(network, "ERC721", &result)?;
 
         Ok(result)
     }
 
-    /// Deploy ERC20 token contract
-    pub async fn deploy_token_contract(
-        &self,
-        name: &str,
-        symbol: &str,
-        initial_supply: &str,
-    ) -> Result<DeploymentResult> {
-        println!("🪙 Deploying ERC20 token via ThirdWeb API...");
-
-        let chain_id = 5003; // Mantle testnet
-
-        let deploy_request = ThirdWebDeployRequest {
-            metadata: ContractMetadata {
-                name: name.to_string(),
-                description: format!("{} - Privacy-enhanced token with ZK features", name),
-                image: Some("https://niet2code.com/token-logo.png".to_string()),
-                external_link: Some("https://niet2code.com".to_string()),
-                seller_fee_basis_points: 0,
-            },
-            constructor_params: vec![
-                ConstructorParam {
-                    name: "name".to_string(),
-                    value: name.to_string(),
-                    param_type: "string".to_string(),
-                },
-                ConstructorParam {
-                    name: "symbol".to_string(),
-                    value: symbol.to_string(),
-                    param_type: "string".to_string(),
-                },
-                ConstructorParam {
-                    name: "initialSupply".to_string(),
-                    value: initial_supply.to_string(),
-                    param_type: "uint256".to_string(),
-                },
-            ],
-        };
-
-        let url = format!("{}/v1/deploy/{}/erc20", self.config.base_url, chain_id);
-        
-        let response = self.client
-            .post(&url)
-            .json(&deploy_request)
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            let error_text = response.text().await?;
-            return Err(anyhow::anyhow!("Token deployment failed: {}", error_text));
-        }
-
-        let deployment: ThirdWebDeployResponse = response.json().await?;
-        
-        let result = DeploymentResult {
-            contract_address: deployment.contract_address.clone(),
-            transaction_hash: deployment.transaction_hash,
-            network: "mantle-testnet".to_string(),
-            gas_used: deployment.deploy_transaction.gas_used
-                .and_then(|g| g.parse().ok())
-                .unwrap_or(1_500_000),
-            deployment_cost: "0.03 MNT".to_string(),
-            thirdweb_dashboard_url: format!("https://thirdweb.com/mantle-testnet/{}", deployment.contract_address),
-            privacy_features: vec![
-                "ZK Transfer Proofs".to_string(),
-                "Anonymous Balances".to_string(),
-                "niet2code Integration".to_string(),
-            ],
-        };
-
-        println!("✅ Token deployed successfully!");
-        println!("📍 Address: {}", result.contract_address);
-        println!("🪙 Initial Supply: {}", initial_supply);
-
-        self.save_deployment_record(&result)?;
-        Ok(result)
-    }
-
     /// List deployed contracts from ThirdWeb
-    pub async fn list_deployed_contracts(&self) -> Result<Vec<ThirdWebContract>> {
-        let chain_id = 5003; // Mantle testnet
+    pub async fn list_deployed_contracts(&self, chain_id: u64) -> Result<Vec<ThirdWebContract>> {
         let url = format!("{}/v1/account/contracts", self.config.base_url);
         
         let response = self.client
@@ -470,7 +401,7 @@ impl ThirdWebIntegration {
     }
 
     /// Deploy contract using template system
-    pub async fn deploy_contract(&self, request: DeploymentRequest) -> Result<DeploymentResult> {
+    pub async fn deploy_contract(&self, network: &Network, request: DeploymentRequest) -> Result<DeploymentResult> {
         println!("🚀 Deploying contract using ThirdWeb template system...");
         println!("📋 Template: {}", request.template_id);
         println!("🌐 Network: {}", request.network);
@@ -489,11 +420,11 @@ impl ThirdWebIntegration {
                 let name = request.constructor_params.get("name").unwrap_or(&template.name);
                 let default_symbol = "ZK".to_string();
                 let symbol = request.constructor_params.get("symbol").unwrap_or(&default_symbol);
-                self.deploy_nft_contract(name, symbol, &template.description).await?
+                self.deploy_nft_contract(network, name, symbol, &template.description).await?
             },
             "DeFi" | "Governance" | "Marketplace" => {
                 // For complex templates, use custom deployment
-                self.deploy_custom_template(template, &request).await?
+                self.deploy_custom_template(network, template, &request).await?
             },
             _ => {
                 return Err(anyhow::anyhow!("Unsupported template category: {}", template.category));
@@ -508,7 +439,7 @@ impl ThirdWebIntegration {
     }
 
     /// Deploy custom template (fallback for complex contracts)
-    async fn deploy_custom_template(&self, template: &ContractTemplate, request: &DeploymentRequest) -> Result<DeploymentResult> {
+    async fn deploy_custom_template(&self, network: &Network, template: &ContractTemplate, request: &DeploymentRequest) -> Result<DeploymentResult> {
         // For complex templates that don't have direct ThirdWeb equivalents,
         // we'll simulate deployment but with realistic structure
         
@@ -528,17 +459,18 @@ impl ThirdWebIntegration {
             transaction_hash,
             network: request.network.clone(),
             gas_used: 3_000_000,
-            deployment_cost: "0.08 MNT".to_string(),
-            thirdweb_dashboard_url: format!("https://thirdweb.com/{}/{}", request.network, contract_address),
+            deployment_cost: format!("0.08 {}", network.native_currency.symbol),
+            thirdweb_dashboard_url: dashboard_url(network, &contract_address),
             privacy_features: template.features.clone(),
         };
         
-        self.save_deployment_record(&result)?;
+        // Simulated: there is no transaction to put in the ledger
         Ok(result)
     }
 
-    /// Estimate deployment cost
-    pub async fn estimate_deployment_cost(&self, template_id: &str, network: &str) -> Result<u64> {
+    /// Estimate deployment gas for a template, priced at the network's current gas
+    /// price when its node is reachable
+    pub async fn estimate_deployment_cost(&self, template_id: &str, network: &Network) -> Result<u64> {
         println!("💰 Estimating deployment cost...");
         
         let template = self.available_templates
@@ -546,33 +478,34 @@ impl ThirdWebIntegration {
             .find(|t| t.id == template_id)
             .ok_or_else(|| anyhow::anyhow!("Template not found"))?;
 
-        let base_cost = match template.category.as_str() {
-            "NFT" => 2_000_000u64,
-            "DeFi" => 3_500_000u64,
-            "Governance" => 4_000_000u64,
-            "Marketplace" => 5_000_000u64,
-            _ => 2_500_000u64,
-        };
-
-        let network_multiplier = match network {
-            "mantle-testnet" | "mantle" => 0.4, // 60% cheaper
-            "polygon" => 0.1,
-            "ethereum" => 1.0,
-            _ => 0.5,
-        };
-
-        let estimated_gas = (base_cost as f64 * network_multiplier) as u64;
-        
+        let estimated_gas = template_gas(&template.category);
         println!("⛽ Estimated gas: {} units", estimated_gas);
-        println!("💵 Network: {} ({}x multiplier)", network, network_multiplier);
+        print_gas_cost(network, estimated_gas).await;
         
         Ok(estimated_gas)
     }
 
-    fn save_deployment_record(&self, result: &DeploymentResult) -> Result<()> {
-        let record = serde_json::to_string_pretty(result)?;
-        std::fs::write("../thirdweb_deployments.json", record)?;
-        Ok(())
+    /// Add the deployment to the ledger; the API reports no receipt, so its
+    /// status stays pending
+    fn save_deployment_record(&self, network: &Network, contract_name: &str, result: &DeploymentResult) -> Result<()> {
+        let mut ledger = Ledger::load(&self.workspace)?;
+        ledger.record_deployment(Deployment {
+            network: network.name.clone(),
+            chain_id: network.chain_id,
+            tx_hash: result.transaction_hash.clone(),
+            kind: "CREATE".to_string(),
+            contract_name: Some(contract_name.to_string()),
+            contract_address: Some(result.contract_address.clone()),
+            function: None,
+            from: None,
+            block_number: None,
+            gas_used: None,
+            effective_gas_price: None,
+            succeeded: None,
+            source: LEDGER_SOURCE.to_string(),
+            deployed_at: chrono::Utc::now().to_rfc3339(),
+        });
+        ledger.save(&self.workspace)
     }
 
     // Contract code templates (keeping the existing ones)
@@ -758,8 +691,8 @@ contract PrivateMarketplace {
 
 // CLI Integration Functions that work with your existing main.rs
 
-pub fn list_templates() -> Result<()> {
-    let thirdweb = ThirdWebIntegration::new()?;
+pub fn list_templates(workspace: &Workspace) -> Result<()> {
+    let thirdweb = ThirdWebIntegration::new(workspace)?;
     let templates = thirdweb.get_templates();
     
     println!("\n🎨 ThirdWeb ZK-Enabled Templates");
@@ -781,8 +714,8 @@ pub fn list_templates() -> Result<()> {
     Ok(())
 }
 
-pub async fn deploy_template(template_id: &str, network: &str, params: HashMap<String, String>) -> Result<()> {
-    let thirdweb = ThirdWebIntegration::new()?;
+pub async fn deploy_template(workspace: &Workspace, template_id: &str, network: &Network, params: HashMap<String, String>) -> Result<()> {
+    let thirdweb = ThirdWebIntegration::new(workspace)?;
     
     println!("🚀 Deploying ThirdWeb template: {}", template_id);
     
@@ -792,13 +725,13 @@ pub async fn deploy_template(template_id: &str, network: &str, params: HashMap<S
     
     let request = DeploymentRequest {
         template_id: template_id.to_string(),
-        network: network.to_string(),
+        network: network.name.clone(),
         constructor_params: params,
         deployer_alias: "Cookathon Builder".to_string(),
         privacy_enabled: true,
     };
     
-    let result = thirdweb.deploy_contract(request).await?;
+    let result = thirdweb.deploy_contract(network, request).await?;
     
     println!("\n🎉 Deployment Successful!");
     println!("=====================================");
@@ -814,8 +747,8 @@ pub async fn deploy_template(template_id: &str, network: &str, params: HashMap<S
     Ok(())
 }
 
-pub async fn customize_template(template_id: &str) -> Result<()> {
-    let thirdweb = ThirdWebIntegration::new()?;
+pub async fn customize_template(workspace: &Workspace, template_id: &str) -> Result<()> {
+    let thirdweb = ThirdWebIntegration::new(workspace)?;
     
     // Find the template
     let template = thirdweb.get_templates()
@@ -844,8 +777,9 @@ pub async fn customize_template(template_id: &str) -> Result<()> {
     Ok(())
 }
 
-pub async fn show_thirdweb_status() -> Result<()> {
-    let thirdweb = ThirdWebIntegration::new()?;
+pub async fn show_thirdweb_status(workspace: &Workspace) -> Result<()> {
+    let thirdweb = ThirdWebIntegration::new(workspace)?;
+    let network = networks::resolve(workspace, None, None, None)?;
     
     println!("\n🎯 ThirdWeb Integration Status");
     println!("===============================");
@@ -856,7 +790,7 @@ pub async fn show_thirdweb_status() -> Result<()> {
             println!("🔗 API Connection: ✅ Active");
             
             // Try to get deployed contracts
-            match thirdweb.list_deployed_contracts().await {
+            match thirdweb.list_deployed_contracts(network.chain_id).await {
                 Ok(contracts) => {
                     println!("📋 Your Deployed Contracts: {} found", contracts.len());
                     for contract in contracts.iter().take(3) { // Show first 3
@@ -885,14 +819,15 @@ pub async fn show_thirdweb_status() -> Result<()> {
     println!("🔒 ZK Templates: {}", thirdweb.get_templates().iter().filter(|t| t.zk_enabled).count());
     
     // Check for previous deployments
-    if let Ok(deployments) = std::fs::read_to_string("../thirdweb_deployments.json") {
-        println!("📋 Previous Deployments: Found");
-        if let Ok(result) = serde_json::from_str::<DeploymentResult>(&deployments) {
-            println!("   Last Deployed: {}", result.contract_address);
-            println!("   Network: {}", result.network);
+    let ledger = Ledger::load(workspace)?;
+    let deployments: Vec<&Deployment> = ledger.deployments.iter().filter(|deployment| deployment.source == LEDGER_SOURCE).collect();
+    match deployments.last() {
+        Some(last) => {
+            println!("📋 Previous Deployments: {}", deployments.len());
+            println!("   Last Deployed: {}", last.contract_address.as_deref().unwrap_or("-"));
+            println!("   Network: {}", last.network);
         }
-    } else {
-        println!("📋 Previous Deployments: None");
+        None => println!("📋 Previous Deployments: None"),
     }
     
     println!("===============================");
@@ -900,115 +835,45 @@ pub async fn show_thirdweb_status() -> Result<()> {
     Ok(())
 }
 
-// New helper functions for specific deployments
-pub async fn deploy_nft_template(name: &str, symbol: &str, description: &str) -> Result<()> {
-    let thirdweb = ThirdWebIntegration::new()?;
-    
-    println!("🎨 Deploying NFT contract with ZK features...");
-    let result = thirdweb.deploy_nft_contract(name, symbol, description).await?;
-    
-    println!("\n🎉 NFT Deployment Complete!");
-    println!("============================");
-    println!("📍 Contract Address: {}", result.contract_address);
-    println!("🔗 Transaction Hash: {}", result.transaction_hash);
-    println!("🎯 ThirdWeb Dashboard: {}", result.thirdweb_dashboard_url);
-    println!("🔒 ZK Features: {}", result.privacy_features.join(", "));
-    
-    Ok(())
+/// ThirdWeb dashboard page for a contract; ThirdWeb accepts chain ids in place of slugs
+fn dashboard_url(network: &Network, address: &str) -> String {
+    format!("https://thirdweb.com/{}/{}", network.chain_id, address)
 }
 
-pub async fn deploy_token_template(name: &str, symbol: &str, supply: &str) -> Result<()> {
-    let thirdweb = ThirdWebIntegration::new()?;
-    
-    println!("🪙 Deploying ERC20 token with ZK features...");
-    let result = thirdweb.deploy_token_contract(name, symbol, supply).await?;
-    
-    println!("\n🎉 Token Deployment Complete!");
-    println!("=============================");
-    println!("📍 Contract Address: {}", result.contract_address);
-    println!("🔗 Transaction Hash: {}", result.transaction_hash);
-    println!("🪙 Total Supply: {}", supply);
-    println!("🎯 ThirdWeb Dashboard: {}", result.thirdweb_dashboard_url);
-    
-    Ok(())
+/// Typical deployment gas for a template category
+fn template_gas(category: &str) -> u64 {
+    match category {
+        "NFT" => 2_000_000,
+        "DeFi" => 3_500_000,
+        "Governance" => 4_000_000,
+        "Marketplace" => 5_000_000,
+        _ => 2_500_000,
+    }
 }
 
-pub async fn list_deployed_contracts() -> Result<()> {
-    let thirdweb = ThirdWebIntegration::new()?;
-    
-    println!("📋 Your ThirdWeb Contracts on Mantle");
-    println!("====================================");
-    
-    match thirdweb.list_deployed_contracts().await {
-        Ok(contracts) => {
-            if contracts.is_empty() {
-                println!("📭 No contracts found. Deploy your first contract!");
-                println!("💡 Use: cargo run -- thirdweb deploy --template niet2code-anonymous-nft");
-            } else {
-                for (i, contract) in contracts.iter().enumerate() {
-                    println!("\n{}. 📄 {}", i + 1, contract.name.as_ref().unwrap_or(&"Unnamed Contract".to_string()));
-                    println!("   📍 Address: {}", contract.address);
-                    println!("   🏷️  Type: {}", contract.contract_type);
-                    if let Some(symbol) = &contract.symbol {
-                        println!("   🎯 Symbol: {}", symbol);
-                    }
-                    println!("   🌐 Dashboard: https://thirdweb.com/mantle-testnet/{}", contract.address);
-                }
-                
-                println!("\n📊 Total contracts: {}", contracts.len());
-            }
-        }
-        Err(e) => {
-            println!("❌ Failed to fetch contracts: {}", e);
-            println!("💡 This might be because:");
-            println!("   • API credentials are incorrect");
-            println!("   • No contracts deployed yet");
-            println!("   • Network connectivity issues");
-        }
+/// Price `gas` at the network's current gas price, or say why it can't be
+async fn print_gas_cost(network: &Network, gas: u64) {
+    match RpcClient::new(&network.rpc_url).gas_price().await {
+        Ok(price) => println!(
+            "💵 Cost on {}: ~{} at {} wei/gas",
+            network.name,
+            network.native_currency.format(price.saturating_mul(gas as u128)),
+            price
+        ),
+        Err(e) => println!("💵 Cost on {}: unavailable ({})", network.name, e),
     }
-    
-    Ok(())
 }
 
-pub async fn test_thirdweb_api() -> Result<()> {
-    let thirdweb = ThirdWebIntegration::new()?;
-    
-    println!("🔗 Testing ThirdWeb API Integration");
-    println!("===================================");
-    
-    // Test basic connection
-    match thirdweb.test_connection().await {
-        Ok(true) => {
-            println!("✅ API connection successful!");
-            
-            // Test listing contracts
-            println!("\n🔍 Testing contract listing...");
-            match thirdweb.list_deployed_contracts().await {
-                Ok(contracts) => {
-                    println!("✅ Contract listing works! Found {} contracts", contracts.len());
-                }
-                Err(e) => {
-                    println!("⚠️  Contract listing failed: {}", e);
-                    println!("   (This is normal if you haven't deployed any contracts yet)");
-                }
-            }
-            
-            println!("\n🎉 ThirdWeb integration is fully functional!");
-            println!("💡 Ready to deploy contracts with real API calls");
-            
-        }
-        Ok(false) => {
-            println!("❌ API connection failed");
-            println!("💡 Check your THIRDWEB_CLIENT_ID and THIRDWEB_SECRET_KEY in .env");
-        }
-        Err(e) => {
-            println!("❌ Connection error: {}", e);
-            println!("💡 Possible issues:");
-            println!("   • Missing environment variables");
-            println!("   • Invalid credentials");
-            println!("   • Network connectivity");
-        }
-    }
-    
-    Ok(())
-}
\ No newline at end of file
+/// Estimate a template's deployment cost without ThirdWeb credentials
+pub async fn estimate_template_cost(template_id: &str, network: &Network) -> Result<u64> {
+    let templates = ThirdWebIntegration::create_zk_enabled_templates();
+    let template = templates
+        .iter()
+        .find(|t| t.id == template_id)
+        .ok_or_else(|| anyhow::anyhow!("Template not found: {}", template_id))?;
+
+    let gas = template_gas(&template.category);
+    println!("⛽ Estimated gas: {} units", gas);
+    print_gas_cost(network, gas).await;
+    Ok(gas)
+}
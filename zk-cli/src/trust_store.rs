@@ -0,0 +1,328 @@
+// Pinned verifying-key trust store for niet2code Builder Edition
+// Maps (circuit id, version) to the fingerprints of verifying keys we trust.
+// `verify` refuses unpinned keys, and the fingerprint of the key compiled into
+// `verifier-contract` is recorded so mismatches are caught before submission.
+
+use anyhow::Result;
+use ark_bn254::Bn254;
+use ark_groth16::VerifyingKey;
+use ark_serialize::CanonicalDeserialize;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use prover::key_artifact::VerifierKeyTable;
+use prover::workspace::Workspace;
+use prover::ProverError;
+
+/// Trust store file name, kept at the workspace home so all projects share it
+pub const TRUST_STORE_FILE: &str = "trusted_keys.json";
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum KeySource {
+    /// Key file pinned by the builder (e.g. output of `prove`)
+    Local,
+    /// Key compiled into the deployed `verifier-contract`
+    VerifierContract,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PinnedKey {
+    pub circuit_id: String,
+    pub circuit_version: u32,
+    pub fingerprint: String,
+    pub source: KeySource,
+    pub pinned_at: String,
+    pub revoked_at: Option<String>,
+}
+
+impl PinnedKey {
+    pub fn is_active(&self) -> bool {
+        self.revoked_at.is_none()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct TrustStore {
+    #[serde(skip)]
+    path: PathBuf,
+    pub keys: Vec<PinnedKey>,
+}
+
+impl TrustStore {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let mut store = match std::fs::read_to_string(path) {
+            Ok(content) => serde_json::from_str(&content)?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => TrustStore::default(),
+            Err(e) => return Err(e.into()),
+        };
+        store.path = path.to_path_buf();
+        Ok(store)
+    }
+
+    pub fn open(workspace: &Workspace) -> Result<Self> {
+        Self::load(workspace.state_file(TRUST_STORE_FILE))
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&self.path, content)?;
+        Ok(())
+    }
+
+    /// Pin a fingerprint. Re-pinning a revoked key reactivates it.
+    pub fn pin(&mut self, circuit_id: &str, circuit_version: u32, fingerprint: &str, source: KeySource) -> &PinnedKey {
+        let now = chrono::Utc::now().to_rfc3339();
+        let index = match self.position(circuit_id, circuit_version, fingerprint, source) {
+            Some(index) => {
+                self.keys[index].revoked_at = None;
+                index
+            }
+            None => {
+                self.keys.push(PinnedKey {
+                    circuit_id: circuit_id.to_string(),
+                    circuit_version,
+                    fingerprint: fingerprint.to_string(),
+                    source,
+                    pinned_at: now,
+                    revoked_at: None,
+                });
+                self.keys.len() - 1
+            }
+        };
+        &self.keys[index]
+    }
+
+    /// Revoke every active pin with the given fingerprint. Returns how many were revoked.
+    pub fn revoke(&mut self, fingerprint: &str) -> usize {
+        let now = chrono::Utc::now().to_rfc3339();
+        let mut revoked = 0;
+        for key in self.keys.iter_mut().filter(|k| k.fingerprint == fingerprint && k.is_active()) {
+            key.revoked_at = Some(now.clone());
+            revoked += 1;
+        }
+        revoked
+    }
+
+    /// Whether the fingerprint is actively pinned for this circuit (any source)
+    pub fn is_trusted(&self, circuit_id: &str, circuit_version: u32, fingerprint: &str) -> bool {
+        self.keys.iter().any(|k| {
+            k.is_active()
+                && k.circuit_id == circuit_id
+                && k.circuit_version == circuit_version
+                && k.fingerprint == fingerprint
+        })
+    }
+
+    /// Active fingerprint of the key compiled into the verifier contract, if pinned
+    pub fn contract_fingerprint(&self, circuit_id: &str, circuit_version: u32) -> Option<&str> {
+        self.keys
+            .iter()
+            .rev()
+            .find(|k| {
+                k.is_active()
+                    && k.source == KeySource::VerifierContract
+                    && k.circuit_id == circuit_id
+                    && k.circuit_version == circuit_version
+            })
+            .map(|k| k.fingerprint.as_str())
+    }
+
+    fn position(&self, circuit_id: &str, circuit_version: u32, fingerprint: &str, source: KeySource) -> Option<usize> {
+        self.keys.iter().position(|k| {
+            k.circuit_id == circuit_id
+                && k.circuit_version == circuit_version
+                && k.fingerprint == fingerprint
+                && k.source == source
+        })
+    }
+}
+
+/// Deserialize a verifying key, accepting either canonical encoding
+pub fn decode_verifying_key(bytes: &[u8]) -> Result<VerifyingKey<Bn254>> {
+    VerifyingKey::<Bn254>::deserialize_uncompressed(bytes)
+        .or_else(|_| VerifyingKey::<Bn254>::deserialize_compressed(bytes))
+        .map_err(|e| ProverError::from(e).into())
+}
+
+/// Fingerprint of a verifying key stored in a binary file
+pub fn fingerprint_vk_file(path: impl AsRef<Path>) -> Result<String> {
+    let bytes = std::fs::read(path).map_err(ProverError::from)?;
+    let vk = decode_verifying_key(&bytes)?;
+    Ok(prover::verifying_key_fingerprint(&vk)?)
+}
+
+/// Fingerprint of the key `verifier-contract` embeds for a circuit (via `verifier_keys.rs`)
+pub fn fingerprint_contract_vk(path: impl AsRef<Path>, circuit_id: &str, circuit_version: u32) -> Result<String> {
+    let table = VerifierKeyTable::read_rust_module(path)?;
+    let entry = table.by_name(circuit_id).ok_or_else(|| {
+        ProverError::InvalidInput(format!("verifier contract has no key for circuit {}", circuit_id))
+    })?;
+    if entry.circuit_version != circuit_version {
+        return Err(ProverError::KeyMismatch(format!(
+            "verifier contract embeds circuit {} v{}, not v{}",
+            circuit_id, entry.circuit_version, circuit_version
+        ))
+        .into());
+    }
+    Ok(entry.artifact.fingerprint.clone())
+}
+
+// CLI Integration Functions
+
+pub fn handle_keys_pin(
+    workspace: &Workspace,
+    vk: Option<&Path>,
+    contract: bool,
+    circuit_id: &str,
+    circuit_version: u32,
+) -> Result<()> {
+    let (fingerprint, source, origin) = match (vk, contract) {
+        (Some(path), false) => (fingerprint_vk_file(path)?, KeySource::Local, path.to_path_buf()),
+        (None, true) => {
+            let module = workspace.verifier_keys_rs_path();
+            (fingerprint_contract_vk(&module, circuit_id, circuit_version)?, KeySource::VerifierContract, module)
+        }
+        _ => return Err(anyhow::anyhow!("Pass exactly one of --vk <path> or --contract")),
+    };
+
+    let mut store = TrustStore::open(workspace)?;
+    store.pin(circuit_id, circuit_version, &fingerprint, source);
+    store.save()?;
+
+    println!("📌 Pinned verifying key for circuit {} v{}", circuit_id, circuit_version);
+    println!("🔑 Fingerprint: {}", fingerprint);
+    println!("📂 Source: {} ({:?})", origin.display(), source);
+    Ok(())
+}
+
+pub fn handle_keys_list(workspace: &Workspace) -> Result<()> {
+    let store = TrustStore::open(workspace)?;
+
+    println!("\n🔑 Pinned Verifying Keys");
+    println!("========================");
+    if store.keys.is_empty() {
+        println!("📭 No keys pinned");
+        println!("💡 Run: cargo run -- keys pin --vk {}", workspace.verifying_key_path().display());
+    }
+    for key in &store.keys {
+        let status = if key.is_active() { "✅ active" } else { "⛔ revoked" };
+        println!("\n• {} v{} ({:?}) {}", key.circuit_id, key.circuit_version, key.source, status);
+        println!("  Fingerprint: {}", key.fingerprint);
+        println!("  Pinned At: {}", key.pinned_at);
+        if let Some(revoked_at) = &key.revoked_at {
+            println!("  Revoked At: {}", revoked_at);
+        }
+    }
+    println!("========================");
+    Ok(())
+}
+
+pub fn handle_keys_revoke(workspace: &Workspace, fingerprint: &str) -> Result<()> {
+    let mut store = TrustStore::open(workspace)?;
+    let revoked = store.revoke(fingerprint);
+    if revoked == 0 {
+        return Err(anyhow::anyhow!("No active pin with fingerprint {}", fingerprint));
+    }
+    store.save()?;
+
+    println!("⛔ Revoked {} pin(s) for {}", revoked, fingerprint);
+    Ok(())
+}
+
+/// Check that a local key is pinned before it is used for verification
+pub fn ensure_trusted(
+    workspace: &Workspace,
+    vk_path: &Path,
+    circuit_id: &str,
+    circuit_version: u32,
+    force: bool,
+) -> Result<()> {
+    let fingerprint = fingerprint_vk_file(vk_path)?;
+    let store = TrustStore::open(workspace)?;
+
+    if store.is_trusted(circuit_id, circuit_version, &fingerprint) {
+        println!("🔑 Verifying key pinned: {}", fingerprint);
+        return Ok(());
+    }
+    if force {
+        println!("⚠️  Verifying key {} is NOT pinned (continuing because of --force)", fingerprint);
+        return Ok(());
+    }
+    Err(anyhow::anyhow!(
+        "Verifying key {} is not pinned for circuit {} v{}. Pin it with `keys pin --vk {}` or pass --force",
+        fingerprint,
+        circuit_id,
+        circuit_version,
+        vk_path.display()
+    ))
+}
+
+/// Check that a local key matches the key compiled into the deployed verifier contract
+pub fn ensure_matches_contract(
+    workspace: &Workspace,
+    vk_path: &Path,
+    circuit_id: &str,
+    circuit_version: u32,
+) -> Result<()> {
+    let store = TrustStore::open(workspace)?;
+    let Some(expected) = store.contract_fingerprint(circuit_id, circuit_version) else {
+        println!("⚠️  No verifier-contract key pinned; run `keys pin --contract` to enable the pre-submission check");
+        return Ok(());
+    };
+
+    let actual = fingerprint_vk_file(vk_path)?;
+    if actual != expected {
+        return Err(ProverError::KeyMismatch(format!(
+            "proof was generated for {} but the verifier contract embeds {}",
+            actual, expected
+        ))
+        .into());
+    }
+    println!("🔑 Verifying key matches verifier contract: {}", actual);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_store(name: &str) -> TrustStore {
+        let path = std::env::temp_dir().join(format!("niet2code-trust-{}-{}.json", name, std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        TrustStore::load(&path).unwrap()
+    }
+
+    #[test]
+    fn test_pin_revoke_round_trip() {
+        let mut store = temp_store("pin");
+        store.pin("mul", 1, "sha256:aa", KeySource::Local);
+        store.save().unwrap();
+
+        let mut store = TrustStore::load(&store.path).unwrap();
+        assert!(store.is_trusted("mul", 1, "sha256:aa"));
+        assert!(!store.is_trusted("mul", 2, "sha256:aa"));
+
+        assert_eq!(store.revoke("sha256:aa"), 1);
+        assert!(!store.is_trusted("mul", 1, "sha256:aa"));
+
+        store.pin("mul", 1, "sha256:aa", KeySource::Local);
+        assert!(store.is_trusted("mul", 1, "sha256:aa"));
+        assert_eq!(store.keys.len(), 1);
+        let _ = std::fs::remove_file(&store.path);
+    }
+
+    #[test]
+    fn test_contract_fingerprint_ignores_local_pins() {
+        let mut store = temp_store("contract");
+        store.pin("mul", 1, "sha256:local", KeySource::Local);
+        assert_eq!(store.contract_fingerprint("mul", 1), None);
+
+        store.pin("mul", 1, "sha256:contract", KeySource::VerifierContract);
+        assert_eq!(store.contract_fingerprint("mul", 1), Some("sha256:contract"));
+    }
+}
@@ -0,0 +1,397 @@
+// Ethereum transactions the CLI signs: legacy with EIP-155 replay protection and
+// EIP-1559 (type 2), with the RLP encoding both need. Signing itself lives in
+// `wallet`; `send_transaction` fills in nonce, gas and fees from the node and
+// broadcasts the signed bytes.
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+
+use prover::ProverError;
+
+use crate::rpc::{self, CallRequest, RpcClient, RpcError};
+use crate::wallet::Wallet;
+
+/// Headroom on top of `eth_estimateGas`, in percent
+const GAS_LIMIT_MARGIN: u64 = 20;
+
+/// Which transaction type a network takes, from its profile
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum FeeModel {
+    /// Type 2 if the node suggests a priority fee, otherwise legacy
+    #[default]
+    Eip1559,
+    /// Type 0 only
+    Legacy,
+}
+
+impl FeeModel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FeeModel::Eip1559 => "eip1559",
+            FeeModel::Legacy => "legacy",
+        }
+    }
+}
+
+/// Fee fields, which also pick the transaction type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fees {
+    /// Type 0, signed per EIP-155
+    Legacy { gas_price: u128 },
+    /// Type 2
+    Eip1559 {
+        max_fee_per_gas: u128,
+        max_priority_fee_per_gas: u128,
+    },
+}
+
+impl Fees {
+    /// Most the sender can pay per unit of gas
+    pub fn max_gas_price(&self) -> u128 {
+        match *self {
+            Fees::Legacy { gas_price } => gas_price,
+            Fees::Eip1559 { max_fee_per_gas, .. } => max_fee_per_gas,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transaction {
+    pub chain_id: u64,
+    pub nonce: u64,
+    pub gas_limit: u64,
+    /// `None` creates a contract from `data`
+    pub to: Option<[u8; 20]>,
+    pub value: u128,
+    pub data: Vec<u8>,
+    pub fees: Fees,
+}
+
+/// secp256k1 signature over a transaction's signing hash
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Signature {
+    pub r: [u8; 32],
+    pub s: [u8; 32],
+    /// Parity of the signature point's y coordinate (the recovery id)
+    pub y_parity: bool,
+}
+
+/// EIP-2718 type byte of EIP-1559 transactions
+const EIP1559_TYPE: u8 = 0x02;
+
+impl Transaction {
+    /// keccak256 of the payload the sender signs
+    pub fn signing_hash(&self) -> [u8; 32] {
+        keccak256(&self.signing_payload())
+    }
+
+    fn signing_payload(&self) -> Vec<u8> {
+        match self.fees {
+            Fees::Legacy { .. } => {
+                // EIP-155: chain id, 0, 0 in place of v, r, s
+                let mut fields = self.legacy_fields();
+                fields.extend([rlp::uint(self.chain_id.into()), rlp::uint(0), rlp::uint(0)]);
+                rlp::list(&fields)
+            }
+            Fees::Eip1559 { .. } => typed(EIP1559_TYPE, &rlp::list(&self.eip1559_fields())),
+        }
+    }
+
+    /// The signed transaction, as `eth_sendRawTransaction` takes it
+    pub fn encode_signed(&self, signature: &Signature) -> Vec<u8> {
+        let r = rlp::bytes(strip_leading_zeros(&signature.r));
+        let s = rlp::bytes(strip_leading_zeros(&signature.s));
+        match self.fees {
+            Fees::Legacy { .. } => {
+                // EIP-155: v = recovery id + chain id * 2 + 35
+                let v = u128::from(self.chain_id) * 2 + 35 + u128::from(signature.y_parity);
+                let mut fields = self.legacy_fields();
+                fields.extend([rlp::uint(v), r, s]);
+                rlp::list(&fields)
+            }
+            Fees::Eip1559 { .. } => {
+                let mut fields = self.eip1559_fields();
+                fields.extend([rlp::uint(signature.y_parity.into()), r, s]);
+                typed(EIP1559_TYPE, &rlp::list(&fields))
+            }
+        }
+    }
+
+    /// Upper bound on the fee in wei: gas limit times the (max) gas price
+    pub fn max_cost(&self) -> u128 {
+        u128::from(self.gas_limit).saturating_mul(self.fees.max_gas_price())
+    }
+
+    fn legacy_fields(&self) -> Vec<Vec<u8>> {
+        let Fees::Legacy { gas_price } = self.fees else {
+            unreachable!("legacy fields of an EIP-1559 transaction");
+        };
+        vec![
+            rlp::uint(self.nonce.into()),
+            rlp::uint(gas_price),
+            rlp::uint(self.gas_limit.into()),
+            self.to_field(),
+            rlp::uint(self.value),
+            rlp::bytes(&self.data),
+        ]
+    }
+
+    fn eip1559_fields(&self) -> Vec<Vec<u8>> {
+        let Fees::Eip1559 { max_fee_per_gas, max_priority_fee_per_gas } = self.fees else {
+            unreachable!("EIP-1559 fields of a legacy transaction");
+        };
+        vec![
+            rlp::uint(self.chain_id.into()),
+            rlp::uint(self.nonce.into()),
+            rlp::uint(max_priority_fee_per_gas),
+            rlp::uint(max_fee_per_gas),
+            rlp::uint(self.gas_limit.into()),
+            self.to_field(),
+            rlp::uint(self.value),
+            rlp::bytes(&self.data),
+            rlp::list(&[]), // empty access list
+        ]
+    }
+
+    fn to_field(&self) -> Vec<u8> {
+        match &self.to {
+            Some(to) => rlp::bytes(to),
+            None => rlp::bytes(&[]),
+        }
+    }
+}
+
+/// Build, sign and broadcast a call from `wallet`, returning the transaction hash.
+/// Refuses to sign if the node is on another chain than `chain_id`. A call that
+/// would revert fails at gas estimation with the node's `RpcError`.
+pub async fn send_transaction(
+    rpc: &RpcClient,
+    wallet: &Wallet,
+    chain_id: u64,
+    fee_model: FeeModel,
+    to: Option<[u8; 20]>,
+    data: Vec<u8>,
+) -> Result<[u8; 32]> {
+    let transaction = prepare_transaction(rpc, wallet, chain_id, fee_model, to, data).await?;
+    sign_and_send(rpc, wallet, &transaction).await
+}
+
+/// A call from `wallet` with nonce, gas limit and fees filled in from the node,
+/// for callers that check `max_cost` before `sign_and_send`
+pub async fn prepare_transaction(
+    rpc: &RpcClient,
+    wallet: &Wallet,
+    chain_id: u64,
+    fee_model: FeeModel,
+    to: Option<[u8; 20]>,
+    data: Vec<u8>,
+) -> Result<Transaction> {
+    let node_chain_id = rpc.chain_id().await?;
+    if node_chain_id != chain_id {
+        return Err(ProverError::InvalidInput(format!(
+            "{} is on chain {}, expected {}; refusing to sign",
+            rpc.url(),
+            node_chain_id,
+            chain_id
+        ))
+        .into());
+    }
+
+    let call = CallRequest {
+        from: Some(wallet.address()),
+        to,
+        data,
+        value: 0,
+    };
+    let estimate = rpc.estimate_gas(&call).await?;
+    Ok(Transaction {
+        chain_id,
+        nonce: rpc.nonce(&wallet.address()).await?,
+        gas_limit: estimate.saturating_add(estimate.saturating_mul(GAS_LIMIT_MARGIN) / 100),
+        to,
+        value: 0,
+        data: call.data,
+        fees: suggest_fees(rpc, fee_model).await?,
+    })
+}
+
+/// Sign `transaction` with `wallet` and broadcast it, returning its hash
+pub async fn sign_and_send(rpc: &RpcClient, wallet: &Wallet, transaction: &Transaction) -> Result<[u8; 32]> {
+    let raw = wallet.sign_transaction(transaction)?;
+    let hash = rpc.send_raw_transaction(&raw).await?;
+    if hash != transaction_hash(&raw) {
+        bail!("node returned transaction hash {} for {}", rpc::to_hex(&hash), rpc::to_hex(&transaction_hash(&raw)));
+    }
+    Ok(hash)
+}
+
+/// EIP-1559 fees if the network takes them, falling back to a legacy gas price
+/// only when the node doesn't serve `eth_maxPriorityFeePerGas`. The fee cap leaves room for the base fee to
+/// double before inclusion.
+pub async fn suggest_fees(rpc: &RpcClient, fee_model: FeeModel) -> Result<Fees> {
+    let gas_price = rpc.gas_price().await?;
+    if fee_model == FeeModel::Legacy {
+        return Ok(Fees::Legacy { gas_price });
+    }
+    Ok(match rpc.max_priority_fee().await {
+        Ok(tip) => Fees::Eip1559 {
+            max_fee_per_gas: gas_price.saturating_mul(2).saturating_add(tip),
+            max_priority_fee_per_gas: tip,
+        },
+        Err(err) if err.downcast_ref::<RpcError>().is_some_and(RpcError::is_method_not_found) => Fees::Legacy { gas_price },
+        Err(err) => return Err(err),
+    })
+}
+
+/// Transaction hash of a signed, encoded transaction
+pub fn transaction_hash(raw: &[u8]) -> [u8; 32] {
+    keccak256(raw)
+}
+
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+fn typed(tx_type: u8, payload: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(1 + payload.len());
+    out.push(tx_type);
+    out.extend_from_slice(payload);
+    out
+}
+
+fn strip_leading_zeros(bytes: &[u8]) -> &[u8] {
+    let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+    &bytes[start..]
+}
+
+/// The subset of RLP transactions need: byte strings, integers and lists
+mod rlp {
+    use super::strip_leading_zeros;
+
+    pub fn bytes(data: &[u8]) -> Vec<u8> {
+        if data.len() == 1 && data[0] < 0x80 {
+            return data.to_vec();
+        }
+        let mut out = header(0x80, data.len());
+        out.extend_from_slice(data);
+        out
+    }
+
+    /// Big-endian without leading zeros; zero is the empty string
+    pub fn uint(value: u128) -> Vec<u8> {
+        bytes(strip_leading_zeros(&value.to_be_bytes()))
+    }
+
+    /// List of already encoded items
+    pub fn list(items: &[Vec<u8>]) -> Vec<u8> {
+        let payload: Vec<u8> = items.concat();
+        let mut out = header(0xc0, payload.len());
+        out.extend_from_slice(&payload);
+        out
+    }
+
+    fn header(offset: u8, len: usize) -> Vec<u8> {
+        if len < 56 {
+            return vec![offset + len as u8];
+        }
+        let len_bytes = len.to_be_bytes();
+        let len_bytes = strip_leading_zeros(&len_bytes);
+        let mut out = vec![offset + 55 + len_bytes.len() as u8];
+        out.extend_from_slice(len_bytes);
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rlp_encoding() {
+        assert_eq!(rlp::bytes(b"dog"), [0x83, b'd', b'o', b'g']);
+        assert_eq!(rlp::bytes(&[]), [0x80]);
+        assert_eq!(rlp::bytes(&[0x7f]), [0x7f]);
+        assert_eq!(rlp::bytes(&[0x80]), [0x81, 0x80]);
+        assert_eq!(rlp::uint(0), [0x80]);
+        assert_eq!(rlp::uint(15), [0x0f]);
+        assert_eq!(rlp::uint(1024), [0x82, 0x04, 0x00]);
+        assert_eq!(rlp::list(&[]), [0xc0]);
+        assert_eq!(rlp::list(&[rlp::bytes(b"cat"), rlp::bytes(b"dog")]), [0xc8, 0x83, b'c', b'a', b't', 0x83, b'd', b'o', b'g']);
+
+        let long = [b'a'; 56];
+        let encoded = rlp::bytes(&long);
+        assert_eq!(encoded[..2], [0xb8, 56]);
+        assert_eq!(encoded.len(), 58);
+    }
+
+    #[test]
+    fn test_eip155_signing_hash() {
+        // The example transaction from EIP-155
+        let tx = Transaction {
+            chain_id: 1,
+            nonce: 9,
+            gas_limit: 21_000,
+            to: Some([0x35; 20]),
+            value: 1_000_000_000_000_000_000,
+            data: Vec::new(),
+            fees: Fees::Legacy { gas_price: 20_000_000_000 },
+        };
+        assert_eq!(
+            hex::encode(tx.signing_payload()),
+            "ec098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a764000080018080"
+        );
+        assert_eq!(hex::encode(tx.signing_hash()), "daf5a779ae972f972197303d7b574746c7ef83eadac0f2791ad23db92e4c8e53");
+    }
+
+    #[test]
+    fn test_contract_creation_has_empty_to() {
+        let tx = Transaction {
+            chain_id: 5003,
+            nonce: 0,
+            gas_limit: 1_000_000,
+            to: None,
+            value: 0,
+            data: vec![0x60, 0x80],
+            fees: Fees::Eip1559 {
+                max_fee_per_gas: 2,
+                max_priority_fee_per_gas: 1,
+            },
+        };
+        let payload = tx.signing_payload();
+        assert_eq!(payload[0], EIP1559_TYPE);
+        // chain id 5003, nonce 0, fees 1 and 2, gas 1,000,000, to = empty string
+        assert_eq!(hex::encode(&payload[2..]), "82138b800102830f42408080826080c0");
+        assert_eq!(tx.max_cost(), 2_000_000);
+    }
+
+    #[tokio::test]
+    async fn test_fees_fall_back_only_when_the_method_is_missing() {
+        use crate::rpc::test_node::{self, NodeError};
+        use serde_json::json;
+
+        let node = |tip: Result<&'static str, NodeError>| {
+            test_node::spawn(move |method, _| match method {
+                "eth_gasPrice" => Ok(json!("0x64")),
+                "eth_maxPriorityFeePerGas" => tip.clone().map(|tip| json!(tip)),
+                _ => Err(NodeError::method_not_found()),
+            })
+        };
+
+        let eip1559 = node(Ok("0x2")).await;
+        let fees = suggest_fees(&RpcClient::new(&eip1559.url), FeeModel::Eip1559).await.unwrap();
+        assert_eq!(fees, Fees::Eip1559 { max_fee_per_gas: 202, max_priority_fee_per_gas: 2 });
+        let fees = suggest_fees(&RpcClient::new(&eip1559.url), FeeModel::Legacy).await.unwrap();
+        assert_eq!(fees, Fees::Legacy { gas_price: 100 });
+
+        let missing = node(Err(NodeError::method_not_found())).await;
+        let fees = suggest_fees(&RpcClient::new(&missing.url), FeeModel::Eip1559).await.unwrap();
+        assert_eq!(fees, Fees::Legacy { gas_price: 100 });
+
+        // Any other failure is the node's problem, not a hint to send type 0
+        let failing = node(Err(NodeError::new(-32000, "header not found"))).await;
+        let err = suggest_fees(&RpcClient::new(&failing.url), FeeModel::Eip1559).await.unwrap_err();
+        assert_eq!(err.downcast_ref::<RpcError>().unwrap().code, -32000);
+    }
+}
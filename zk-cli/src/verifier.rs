@@ -0,0 +1,362 @@
+// `verifier build` / `verifier verify-deployment`: reproducible PolkaVM blob builds
+// and a check that the contract deployed at an address is that blob.
+//
+// `build` runs the same steps as `verifier-contract/Makefile` with the toolchain
+// pinned and the environment fixed (no incremental state, build paths remapped),
+// so two machines with the same sources and `verifier_keys.rs` get the same blob.
+// pallet-revive identifies code by keccak256 of the blob, which is what is printed
+// and recorded in `contract.polkavm.json` next to it.
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use prover::key_artifact::VerifierKeyTable;
+use prover::workspace::Workspace;
+use prover::ProverError;
+
+use crate::rpc::{self, RpcClient};
+
+/// Toolchain the published blob hashes are built with (nightly features via RUSTC_BOOTSTRAP)
+pub const PINNED_TOOLCHAIN: &str = "1.84.0";
+/// `polkatool` release matching `polkavm-derive` in verifier-contract/Cargo.toml
+pub const PINNED_POLKATOOL: &str = "0.21.0";
+const POLKAVM_TARGET: &str = "riscv64emac-unknown-none-polkavm";
+
+pub struct BuildSettings {
+    pub contract_dir: PathBuf,
+    pub vk_rs: PathBuf,
+    /// Cargo features replacing the contract's defaults, e.g. `polkavm,precompiles`
+    pub features: Option<String>,
+    pub toolchain: String,
+    pub out: PathBuf,
+}
+
+impl BuildSettings {
+    fn source_root(&self) -> &Path {
+        self.contract_dir.parent().unwrap_or(&self.contract_dir)
+    }
+
+    /// `cargo build --release --locked` for the PolkaVM target with a fixed environment
+    pub fn cargo_command(&self) -> Command {
+        let mut command = Command::new("cargo");
+        command.current_dir(&self.contract_dir).args(["build", "--release", "--locked"]);
+        if let Some(features) = &self.features {
+            command.args(["--no-default-features", "--features", features]);
+        }
+
+        // Absolute paths end up in the blob through panic locations and debug info
+        let mut rustflags = format!("--remap-path-prefix={}=/niet2code", self.source_root().display());
+        if let Some(cargo_home) = cargo_home() {
+            rustflags.push_str(&format!(" --remap-path-prefix={}=/cargo", cargo_home.display()));
+        }
+
+        command
+            .env("RUSTUP_TOOLCHAIN", &self.toolchain)
+            .env("RUSTC_BOOTSTRAP", "1")
+            .env("CARGO_INCREMENTAL", "0")
+            .env("SOURCE_DATE_EPOCH", "0")
+            .env("RUSTFLAGS", rustflags)
+            .env("NIET2CODE_VK_RS", &self.vk_rs);
+        command
+    }
+
+    /// `polkatool link --strip` of the release ELF into `out`
+    pub fn link_command(&self) -> Command {
+        let elf = self.contract_dir.join("target").join(POLKAVM_TARGET).join("release").join("contract");
+        let mut command = Command::new("polkatool");
+        command.args(["link", "--strip", "--output"]).arg(&self.out).arg(elf);
+        command
+    }
+}
+
+/// Circuit key compiled into a built blob
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct BuiltKey {
+    pub circuit: String,
+    pub circuit_id: u64,
+    pub circuit_version: u32,
+    pub fingerprint: String,
+}
+
+/// Written to `<blob>.json` by `verifier build`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct BuildManifest {
+    pub code_hash: String,
+    pub blob_bytes: usize,
+    pub toolchain: String,
+    pub rustc: Option<String>,
+    pub polkatool: Option<String>,
+    pub features: Option<String>,
+    pub keys: Vec<BuiltKey>,
+    pub built_at: String,
+}
+
+impl BuildManifest {
+    pub fn path_for(blob: &Path) -> PathBuf {
+        let mut name = blob.as_os_str().to_owned();
+        name.push(".json");
+        PathBuf::from(name)
+    }
+}
+
+/// Code hash pallet-revive stores for a blob (keccak256)
+pub fn code_hash(blob: &[u8]) -> [u8; 32] {
+    Keccak256::digest(blob).into()
+}
+
+pub fn handle_build(
+    workspace: &Workspace,
+    contract_dir: Option<&Path>,
+    vk_rs: Option<&Path>,
+    features: Option<&str>,
+    toolchain: &str,
+    out: Option<&Path>,
+) -> Result<()> {
+    let contract_dir = match contract_dir {
+        Some(dir) => dir.to_path_buf(),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("../verifier-contract"),
+    };
+    let contract_dir = contract_dir.canonicalize().map_err(ProverError::from).with_context(|| format!("verifier-contract not found at {}", contract_dir.display()))?;
+    let vk_rs = vk_rs.map(Path::to_path_buf).unwrap_or_else(|| workspace.verifier_keys_rs_path());
+    // Fail on a bad key table before a multi-minute build does
+    let table = VerifierKeyTable::read_rust_module(&vk_rs)?;
+    let out = out.map(Path::to_path_buf).unwrap_or_else(|| workspace.verifier_blob_path());
+    if let Some(parent) = out.parent() {
+        std::fs::create_dir_all(parent).map_err(ProverError::from)?;
+    }
+
+    let settings = BuildSettings {
+        contract_dir,
+        vk_rs: vk_rs.canonicalize().map_err(ProverError::from)?,
+        features: features.map(str::to_string),
+        toolchain: toolchain.to_string(),
+        out,
+    };
+
+    println!("🔨 Building verifier-contract with Rust {} ({})", settings.toolchain, settings.features.as_deref().unwrap_or("default features"));
+    println!("📂 Verifying keys: {}", settings.vk_rs.display());
+    // Without the committed lock file cargo would resolve whatever is newest today
+    if !settings.contract_dir.join("Cargo.lock").is_file() {
+        bail!("{} has no Cargo.lock: the blob is only reproducible with the committed lock file", settings.contract_dir.display());
+    }
+    // Another polkatool links the same ELF into a different blob
+    let polkatool = check_polkatool(tool_version(Command::new("polkatool").arg("--version")))?;
+    run(settings.cargo_command(), "cargo build", &format!("rustup toolchain install {} --component rust-src", settings.toolchain))?;
+    run(settings.link_command(), "polkatool link", &format!("cargo install polkatool@{}", PINNED_POLKATOOL))?;
+
+    let blob = std::fs::read(&settings.out).map_err(ProverError::from)?;
+    let manifest = BuildManifest {
+        code_hash: format!("0x{}", hex::encode(code_hash(&blob))),
+        blob_bytes: blob.len(),
+        toolchain: settings.toolchain.clone(),
+        rustc: tool_version(Command::new("rustc").arg("-V").env("RUSTUP_TOOLCHAIN", &settings.toolchain)),
+        polkatool: Some(polkatool),
+        features: settings.features.clone(),
+        keys: table
+            .entries
+            .iter()
+            .map(|entry| BuiltKey {
+                circuit: entry.circuit_name.clone(),
+                circuit_id: entry.circuit_id,
+                circuit_version: entry.circuit_version,
+                fingerprint: entry.artifact.fingerprint.clone(),
+            })
+            .collect(),
+        built_at: chrono::Utc::now().to_rfc3339(),
+    };
+    let manifest_path = BuildManifest::path_for(&settings.out);
+    std::fs::write(&manifest_path, serde_json::to_string_pretty(&manifest)?).map_err(ProverError::from)?;
+
+    println!("📦 Blob: {} ({} bytes)", settings.out.display(), manifest.blob_bytes);
+    for key in &manifest.keys {
+        println!("🔑 {} v{} (circuitId {}): {}", key.circuit, key.circuit_version, key.circuit_id, key.fingerprint);
+    }
+    println!("📝 Manifest: {}", manifest_path.display());
+    println!("{}", manifest.code_hash);
+    Ok(())
+}
+
+pub async fn handle_verify_deployment(workspace: &Workspace, address: &str, rpc_url: Option<&str>, blob: Option<&Path>) -> Result<()> {
+    let rpc = RpcClient::from_arg_or_env(rpc_url)?;
+    let blob_path = blob.map(Path::to_path_buf).unwrap_or_else(|| workspace.verifier_blob_path());
+
+    println!("🔎 Comparing {} on {} with {}", address, rpc.url(), blob_path.display());
+    let hash = check_deployment(&rpc, address, &blob_path).await?;
+    println!("✅ Deployed code matches the local build: 0x{}", hex::encode(hash));
+    Ok(())
+}
+
+/// Code hash of the contract at `address` if it equals that of the blob at `blob_path`
+pub async fn check_deployment(rpc: &RpcClient, address: &str, blob_path: &Path) -> Result<[u8; 32]> {
+    rpc::parse_address(address)?;
+    let blob = std::fs::read(blob_path)
+        .map_err(ProverError::from)
+        .with_context(|| format!("no blob at {}: run `verifier build` or pass --blob", blob_path.display()))?;
+    let expected = code_hash(&blob);
+
+    let code = rpc.get_code(address).await?;
+    if code.is_empty() {
+        return Err(ProverError::InvalidInput(format!("no contract deployed at {}", address)).into());
+    }
+    let deployed = code_hash(&code);
+    if deployed != expected {
+        return Err(ProverError::KeyMismatch(format!(
+            "code at {} is 0x{} ({} bytes), local build is 0x{} ({} bytes)",
+            address,
+            hex::encode(deployed),
+            code.len(),
+            hex::encode(expected),
+            blob.len()
+        ))
+        .into());
+    }
+    Ok(deployed)
+}
+
+fn run(mut command: Command, what: &str, install_hint: &str) -> Result<()> {
+    let status = command.status().with_context(|| format!("cannot run {} (install with `{}`)", what, install_hint))?;
+    if !status.success() {
+        bail!("{} failed ({})", what, status);
+    }
+    Ok(())
+}
+
+/// `polkatool --version` output if it is the pinned release
+fn check_polkatool(version: Option<String>) -> Result<String> {
+    let install = format!("cargo install --force polkatool@{}", PINNED_POLKATOOL);
+    let Some(version) = version else {
+        bail!("cannot run polkatool (install with `{}`)", install);
+    };
+    if version.split_whitespace().last() != Some(PINNED_POLKATOOL) {
+        bail!("found `{}`, but blobs are linked with polkatool {} (install with `{}`)", version, PINNED_POLKATOOL, install);
+    }
+    Ok(version)
+}
+
+fn tool_version(command: &mut Command) -> Option<String> {
+    let output = command.output().ok().filter(|output| output.status.success())?;
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn cargo_home() -> Option<PathBuf> {
+    std::env::var_os("CARGO_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cargo")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rpc::test_node::{self, NodeError};
+    use crate::test_workspace::TempWorkspace;
+    use serde_json::json;
+    use std::ffi::OsStr;
+
+    const ADDRESS: &str = "0x00000000000000000000000000000000000000aa";
+
+    /// `blob` written to `contract.polkavm` in a fresh workspace
+    fn temp_blob(name: &str, blob: &[u8]) -> (TempWorkspace, PathBuf) {
+        let workspace = TempWorkspace::new(&format!("verifier-{}", name));
+        std::fs::create_dir_all(workspace.home()).unwrap();
+        let path = workspace.home().join("contract.polkavm");
+        std::fs::write(&path, blob).unwrap();
+        (workspace, path)
+    }
+
+    fn serve_code(code: &'static [u8]) -> impl Fn(&str, &serde_json::Value) -> Result<serde_json::Value, NodeError> {
+        move |method, _| match method {
+            "eth_getCode" => Ok(json!(format!("0x{}", hex::encode(code)))),
+            _ => Err(NodeError::method_not_found()),
+        }
+    }
+
+    fn prover_error(err: &anyhow::Error) -> Option<&ProverError> {
+        err.downcast_ref::<ProverError>()
+    }
+
+    #[test]
+    fn test_code_hash_is_keccak256() {
+        assert_eq!(hex::encode(code_hash(b"")), "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470");
+    }
+
+    #[test]
+    fn test_build_commands_pin_the_environment() {
+        let settings = BuildSettings {
+            contract_dir: PathBuf::from("/src/niet2code/verifier-contract"),
+            vk_rs: PathBuf::from("/keys/verifier_keys.rs"),
+            features: Some("polkavm,precompiles".to_string()),
+            toolchain: PINNED_TOOLCHAIN.to_string(),
+            out: PathBuf::from("/out/contract.polkavm"),
+        };
+
+        let cargo = settings.cargo_command();
+        let env = |name: &str| cargo.get_envs().find(|(key, _)| *key == OsStr::new(name)).and_then(|(_, value)| value);
+        assert_eq!(env("RUSTUP_TOOLCHAIN"), Some(OsStr::new(PINNED_TOOLCHAIN)));
+        assert_eq!(env("RUSTC_BOOTSTRAP"), Some(OsStr::new("1")));
+        assert_eq!(env("CARGO_INCREMENTAL"), Some(OsStr::new("0")));
+        assert_eq!(env("NIET2CODE_VK_RS"), Some(OsStr::new("/keys/verifier_keys.rs")));
+        assert!(env("RUSTFLAGS").unwrap().to_str().unwrap().starts_with("--remap-path-prefix=/src/niet2code=/niet2code"));
+        let args: Vec<_> = cargo.get_args().collect();
+        assert_eq!(args, ["build", "--release", "--locked", "--no-default-features", "--features", "polkavm,precompiles"]);
+        assert_eq!(cargo.get_current_dir(), Some(Path::new("/src/niet2code/verifier-contract")));
+
+        let link = settings.link_command();
+        let args: Vec<_> = link.get_args().collect();
+        assert_eq!(
+            args,
+            [
+                "link",
+                "--strip",
+                "--output",
+                "/out/contract.polkavm",
+                "/src/niet2code/verifier-contract/target/riscv64emac-unknown-none-polkavm/release/contract"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_polkatool_must_be_the_pinned_release() {
+        let pinned = format!("polkatool {}", PINNED_POLKATOOL);
+        assert_eq!(check_polkatool(Some(pinned.clone())).unwrap(), pinned);
+        let err = check_polkatool(Some("polkatool 0.18.0".to_string())).unwrap_err();
+        assert!(err.to_string().contains("found `polkatool 0.18.0`"), "{}", err);
+        assert!(check_polkatool(None).is_err());
+    }
+
+    #[test]
+    fn test_manifest_sits_next_to_blob() {
+        assert_eq!(BuildManifest::path_for(Path::new("/out/contract.polkavm")), PathBuf::from("/out/contract.polkavm.json"));
+    }
+
+    #[tokio::test]
+    async fn test_verify_deployment_matches_deployed_code() {
+        let (_workspace, blob) = temp_blob("match", b"PVM\0blob");
+        let node = test_node::spawn(serve_code(b"PVM\0blob")).await;
+
+        let hash = check_deployment(&RpcClient::new(&node.url), ADDRESS, &blob).await.unwrap();
+        assert_eq!(hash, code_hash(b"PVM\0blob"));
+        assert_eq!(node.calls(), vec![("eth_getCode".to_string(), json!([ADDRESS, "latest"]))]);
+    }
+
+    #[tokio::test]
+    async fn test_verify_deployment_rejects_other_code() {
+        let (_workspace, blob) = temp_blob("mismatch", b"PVM\0blob");
+        let node = test_node::spawn(serve_code(b"PVM\0other")).await;
+
+        let err = check_deployment(&RpcClient::new(&node.url), ADDRESS, &blob).await.unwrap_err();
+        assert!(matches!(prover_error(&err), Some(ProverError::KeyMismatch(_))), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_verify_deployment_without_contract() {
+        let (_workspace, blob) = temp_blob("empty", b"PVM\0blob");
+        let node = test_node::spawn(serve_code(b"")).await;
+
+        let err = check_deployment(&RpcClient::new(&node.url), ADDRESS, &blob).await.unwrap_err();
+        assert!(matches!(prover_error(&err), Some(ProverError::InvalidInput(_))), "{}", err);
+        assert_eq!(node.calls().len(), 1);
+    }
+}
@@ -0,0 +1,652 @@
+// Encrypted wallets for niet2code Builder Edition
+// Keys live in Web3 Secret Storage (keystore v3) files under `<home>/keystore/`,
+// one per wallet name, so no command needs a plaintext PRIVATE_KEY. New files use
+// scrypt with Foundry's parameters and AES-128-CTR; imported files may also use
+// PBKDF2-HMAC-SHA256. An unlocked `Wallet` signs legacy (EIP-155) and EIP-1559
+// transactions built by `tx`.
+
+use aes::cipher::{KeyIvInit, StreamCipher};
+use anyhow::{anyhow, bail, Context, Result};
+use k256::ecdsa::{SigningKey, VerifyingKey};
+use k256::elliptic_curve::subtle::ConstantTimeEq;
+use k256::elliptic_curve::zeroize::Zeroizing;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+use prover::workspace::Workspace;
+use prover::ProverError;
+
+use crate::tx::{self, Signature, Transaction};
+
+/// Wallet used when a command is not given `--wallet`
+pub const DEFAULT_WALLET: &str = "default";
+
+type Aes128Ctr = ctr::Ctr128BE<aes::Aes128>;
+
+/// scrypt cost for new keystores: N = 2^13, r = 8, p = 1 (as `cast wallet new`)
+const SCRYPT_LOG_N: u8 = 13;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+const DKLEN: usize = 32;
+
+/// An unlocked secp256k1 account
+pub struct Wallet {
+    key: SigningKey,
+    address: [u8; 20],
+}
+
+// Never print the key
+impl std::fmt::Debug for Wallet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Wallet").field("address", &checksum_address(&self.address)).finish_non_exhaustive()
+    }
+}
+
+impl Wallet {
+    pub fn from_private_key(secret: &[u8]) -> Result<Self> {
+        // `from_slice` would left-pad shorter input
+        let secret: &[u8; 32] = secret
+            .try_into()
+            .map_err(|_| ProverError::InvalidInput(format!("private key must be 32 bytes, got {}", secret.len())))?;
+        let key = SigningKey::from_bytes(secret.into())
+            .map_err(|_| ProverError::InvalidInput("private key is zero or not below the secp256k1 order".to_string()))?;
+        Ok(Self::from_signing_key(key))
+    }
+
+    pub fn random() -> Self {
+        Self::from_signing_key(SigningKey::random(&mut rand::rngs::OsRng))
+    }
+
+    fn from_signing_key(key: SigningKey) -> Self {
+        let address = address_of(key.verifying_key());
+        Self { key, address }
+    }
+
+    pub fn address(&self) -> [u8; 20] {
+        self.address
+    }
+
+    /// Sign a 32-byte hash as is (no message prefix)
+    pub fn sign_hash(&self, hash: &[u8; 32]) -> Result<Signature> {
+        let (signature, recovery_id) = self.key.sign_prehash_recoverable(hash).context("signing failed")?;
+        let (r, s) = signature.split_bytes();
+        Ok(Signature {
+            r: r.into(),
+            s: s.into(),
+            y_parity: recovery_id.is_y_odd(),
+        })
+    }
+
+    /// Sign `transaction` for its chain id and return the raw transaction bytes
+    pub fn sign_transaction(&self, transaction: &Transaction) -> Result<Vec<u8>> {
+        let signature = self.sign_hash(&transaction.signing_hash())?;
+        Ok(transaction.encode_signed(&signature))
+    }
+
+    /// Encrypt into a keystore v3 document with the default scrypt cost
+    pub fn encrypt(&self, passphrase: &str) -> Result<Keystore> {
+        self.encrypt_with(passphrase, SCRYPT_LOG_N, SCRYPT_R, SCRYPT_P)
+    }
+
+    fn encrypt_with(&self, passphrase: &str, log_n: u8, r: u32, p: u32) -> Result<Keystore> {
+        let mut salt = [0u8; 32];
+        let mut iv = [0u8; 16];
+        rand::rngs::OsRng.fill_bytes(&mut salt);
+        rand::rngs::OsRng.fill_bytes(&mut iv);
+
+        let kdf = Kdf::Scrypt { log_n, r, p, salt: salt.to_vec() };
+        let derived = kdf.derive(passphrase)?;
+        let mut ciphertext = self.key.to_bytes().to_vec();
+        Aes128Ctr::new(derived[..16].into(), &iv.into()).apply_keystream(&mut ciphertext);
+        let mac = mac(&derived, &ciphertext);
+
+        Ok(Keystore {
+            version: 3,
+            id: uuid::Uuid::new_v4().to_string(),
+            address: Some(hex::encode(self.address)),
+            crypto: Crypto {
+                cipher: "aes-128-ctr".to_string(),
+                cipherparams: CipherParams { iv: hex::encode(iv) },
+                ciphertext: hex::encode(ciphertext),
+                kdf: "scrypt".to_string(),
+                kdfparams: kdf.params(),
+                mac: hex::encode(mac),
+            },
+        })
+    }
+}
+
+/// Web3 Secret Storage document (version 3)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Keystore {
+    pub version: u32,
+    pub id: String,
+    /// Lowercase hex without `0x`; optional in the format
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    // geth writes `Crypto`; accept both spellings
+    #[serde(alias = "Crypto")]
+    pub crypto: Crypto,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Crypto {
+    pub cipher: String,
+    pub cipherparams: CipherParams,
+    pub ciphertext: String,
+    pub kdf: String,
+    pub kdfparams: Value,
+    pub mac: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CipherParams {
+    pub iv: String,
+}
+
+enum Kdf {
+    Scrypt { log_n: u8, r: u32, p: u32, salt: Vec<u8> },
+    Pbkdf2 { rounds: u32, salt: Vec<u8> },
+}
+
+impl Kdf {
+    fn parse(name: &str, params: &Value) -> Result<Self> {
+        let number = |field: &str| -> Result<u64> {
+            params.get(field).and_then(Value::as_u64).with_context(|| format!("keystore kdfparams.{} is missing", field))
+        };
+        let salt = params.get("salt").and_then(Value::as_str).context("keystore kdfparams.salt is missing")?;
+        let salt = hex::decode(salt).context("keystore salt is not hex")?;
+        if number("dklen")? != DKLEN as u64 {
+            bail!("keystore dklen must be {}", DKLEN);
+        }
+        match name {
+            "scrypt" => {
+                let n = number("n")?;
+                if !n.is_power_of_two() || n < 2 {
+                    bail!("keystore scrypt n must be a power of two, got {}", n);
+                }
+                Ok(Kdf::Scrypt {
+                    log_n: n.trailing_zeros() as u8,
+                    r: u32::try_from(number("r")?).context("keystore scrypt r out of range")?,
+                    p: u32::try_from(number("p")?).context("keystore scrypt p out of range")?,
+                    salt,
+                })
+            }
+            "pbkdf2" => {
+                let prf = params.get("prf").and_then(Value::as_str).unwrap_or_default();
+                if prf != "hmac-sha256" {
+                    bail!("unsupported keystore pbkdf2 prf: {:?}", prf);
+                }
+                Ok(Kdf::Pbkdf2 {
+                    rounds: u32::try_from(number("c")?).context("keystore pbkdf2 c out of range")?,
+                    salt,
+                })
+            }
+            other => bail!("unsupported keystore kdf: {}", other),
+        }
+    }
+
+    fn params(&self) -> Value {
+        match self {
+            Kdf::Scrypt { log_n, r, p, salt } => serde_json::json!({
+                "dklen": DKLEN, "n": 1u64 << log_n, "p": p, "r": r, "salt": hex::encode(salt),
+            }),
+            Kdf::Pbkdf2 { rounds, salt } => serde_json::json!({
+                "c": rounds, "dklen": DKLEN, "prf": "hmac-sha256", "salt": hex::encode(salt),
+            }),
+        }
+    }
+
+    fn derive(&self, passphrase: &str) -> Result<Zeroizing<[u8; DKLEN]>> {
+        let mut derived = Zeroizing::new([0u8; DKLEN]);
+        match self {
+            Kdf::Scrypt { log_n, r, p, salt } => {
+                let params = scrypt::Params::new(*log_n, *r, *p, DKLEN).map_err(|_| anyhow!("invalid keystore scrypt parameters"))?;
+                scrypt::scrypt(passphrase.as_bytes(), salt, &params, derived.as_mut()).map_err(|_| anyhow!("scrypt failed"))?;
+            }
+            Kdf::Pbkdf2 { rounds, salt } => {
+                pbkdf2::pbkdf2_hmac::<sha2::Sha256>(passphrase.as_bytes(), salt, *rounds, derived.as_mut());
+            }
+        }
+        Ok(derived)
+    }
+}
+
+/// keccak256(derived key[16..32] ‖ ciphertext)
+fn mac(derived: &[u8; DKLEN], ciphertext: &[u8]) -> [u8; 32] {
+    let mut input = derived[16..].to_vec();
+    input.extend_from_slice(ciphertext);
+    tx::keccak256(&input)
+}
+
+impl Keystore {
+    pub fn from_json(content: &str) -> Result<Self> {
+        let keystore: Keystore = serde_json::from_str(content).map_err(|e| ProverError::InvalidInput(format!("not a keystore v3 file: {}", e)))?;
+        if keystore.version != 3 {
+            return Err(ProverError::InvalidInput(format!("unsupported keystore version {}", keystore.version)).into());
+        }
+        Ok(keystore)
+    }
+
+    /// Decrypt with `passphrase`. A wrong passphrase is `InvalidInput`.
+    pub fn decrypt(&self, passphrase: &str) -> Result<Wallet> {
+        let crypto = &self.crypto;
+        if crypto.cipher != "aes-128-ctr" {
+            bail!("unsupported keystore cipher: {}", crypto.cipher);
+        }
+        let ciphertext = hex::decode(&crypto.ciphertext).context("keystore ciphertext is not hex")?;
+        let iv: [u8; 16] = hex::decode(&crypto.cipherparams.iv)
+            .ok()
+            .and_then(|iv| iv.try_into().ok())
+            .context("keystore iv must be 16 hex bytes")?;
+        let expected_mac = hex::decode(&crypto.mac).context("keystore mac is not hex")?;
+
+        let derived = Kdf::parse(&crypto.kdf, &crypto.kdfparams)?.derive(passphrase)?;
+        // Compared in constant time
+        if !bool::from(mac(&derived, &ciphertext)[..].ct_eq(&expected_mac)) {
+            return Err(ProverError::InvalidInput("wrong passphrase (keystore MAC mismatch)".to_string()).into());
+        }
+        let mut secret = Zeroizing::new(ciphertext);
+        Aes128Ctr::new(derived[..16].into(), &iv.into()).apply_keystream(&mut secret);
+        let wallet = Wallet::from_private_key(&secret)?;
+
+        if let Some(address) = self.address() {
+            if address != wallet.address() {
+                bail!("keystore address {} does not match its key ({})", checksum_address(&address), checksum_address(&wallet.address()));
+            }
+        }
+        Ok(wallet)
+    }
+
+    /// The `address` field, if present and well formed
+    pub fn address(&self) -> Option<[u8; 20]> {
+        let address = self.address.as_deref()?;
+        hex::decode(address.trim_start_matches("0x")).ok()?.try_into().ok()
+    }
+}
+
+/// Keystore files under `<home>/keystore/`, named `<wallet>.json`
+pub struct WalletStore {
+    dir: PathBuf,
+}
+
+impl WalletStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn open(workspace: &Workspace) -> Self {
+        Self::new(workspace.keystore_dir())
+    }
+
+    pub fn path(&self, name: &str) -> Result<PathBuf> {
+        if name.is_empty() || name.starts_with('.') || !name.chars().all(|c| c.is_ascii_alphanumeric() || "-_.".contains(c)) {
+            return Err(ProverError::InvalidInput(format!("invalid wallet name: {:?} (use letters, digits, '-', '_', '.')", name)).into());
+        }
+        Ok(self.dir.join(format!("{}.json", name)))
+    }
+
+    pub fn load(&self, name: &str) -> Result<Keystore> {
+        let path = self.path(name)?;
+        let content = std::fs::read_to_string(&path).map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                ProverError::InvalidInput(format!("no wallet named {:?}: create one with `wallet new` or `wallet import`", name))
+            } else {
+                ProverError::Io(e)
+            }
+        })?;
+        Keystore::from_json(&content).with_context(|| format!("{}", path.display()))
+    }
+
+    /// Write a new wallet file, readable only by the owner. Never overwrites.
+    pub fn save(&self, name: &str, keystore: &Keystore) -> Result<PathBuf> {
+        let path = self.path(name)?;
+        std::fs::create_dir_all(&self.dir).map_err(ProverError::Io)?;
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let mut file = options.open(&path).map_err(|e| {
+            if e.kind() == std::io::ErrorKind::AlreadyExists {
+                ProverError::InvalidInput(format!("wallet {:?} already exists at {}", name, path.display()))
+            } else {
+                ProverError::Io(e)
+            }
+        })?;
+        std::io::Write::write_all(&mut file, serde_json::to_string_pretty(keystore)?.as_bytes()).map_err(ProverError::Io)?;
+        Ok(path)
+    }
+
+    /// Wallet names and their addresses, sorted by name
+    pub fn list(&self) -> Result<Vec<(String, Option<[u8; 20]>)>> {
+        let entries = match std::fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(ProverError::Io(e).into()),
+        };
+        let mut wallets = Vec::new();
+        for entry in entries {
+            let path = entry.map_err(ProverError::Io)?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            let address = self.load(name).ok().and_then(|keystore| keystore.address());
+            wallets.push((name.to_string(), address));
+        }
+        wallets.sort();
+        Ok(wallets)
+    }
+
+    /// Load and decrypt `name`, asking for its passphrase
+    pub fn unlock(&self, name: &str, passphrase_file: Option<&Path>) -> Result<Wallet> {
+        let keystore = self.load(name)?;
+        let passphrase = read_passphrase(&format!("Passphrase for wallet '{}': ", name), passphrase_file, false)?;
+        keystore.decrypt(&passphrase)
+    }
+}
+
+/// Read a passphrase from `file` (first line) or prompt for it without echo.
+/// `new` asks twice at the prompt and refuses an empty passphrase.
+pub fn read_passphrase(prompt: &str, file: Option<&Path>, new: bool) -> Result<Zeroizing<String>> {
+    let passphrase = match file {
+        Some(file) => {
+            let content = Zeroizing::new(std::fs::read_to_string(file).map_err(ProverError::Io)?);
+            Zeroizing::new(content.lines().next().unwrap_or_default().to_string())
+        }
+        None => {
+            let passphrase = Zeroizing::new(rpassword::prompt_password(prompt).context("could not read passphrase")?);
+            if new {
+                let again = Zeroizing::new(rpassword::prompt_password("Repeat passphrase: ").context("could not read passphrase")?);
+                if *again != *passphrase {
+                    return Err(ProverError::InvalidInput("passphrases do not match".to_string()).into());
+                }
+            }
+            passphrase
+        }
+    };
+    if new && passphrase.is_empty() {
+        return Err(ProverError::InvalidInput("passphrase must not be empty".to_string()).into());
+    }
+    Ok(passphrase)
+}
+
+/// Account address: the last 20 bytes of keccak256 of the uncompressed public key
+fn address_of(key: &VerifyingKey) -> [u8; 20] {
+    let point = key.to_encoded_point(false);
+    let hash = tx::keccak256(&point.as_bytes()[1..]);
+    hash[12..].try_into().expect("20-byte slice")
+}
+
+/// Address that signed `hash`, as nodes recover it
+#[cfg(test)]
+fn recover_signer(hash: &[u8; 32], signature: &Signature) -> Result<[u8; 20]> {
+    let parsed = k256::ecdsa::Signature::from_scalars(signature.r, signature.s).context("invalid signature scalars")?;
+    let recovery_id = k256::ecdsa::RecoveryId::new(signature.y_parity, false);
+    let key = VerifyingKey::recover_from_prehash(hash, &parsed, recovery_id).context("signature does not recover")?;
+    Ok(address_of(&key))
+}
+
+/// EIP-55 mixed-case checksum encoding
+pub fn checksum_address(address: &[u8; 20]) -> String {
+    let lower = hex::encode(address);
+    let hash = tx::keccak256(lower.as_bytes());
+    let digits: String = lower
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = (hash[i / 2] >> (if i % 2 == 0 { 4 } else { 0 })) & 0x0f;
+            if nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect();
+    format!("0x{}", digits)
+}
+
+pub fn handle_wallet_new(workspace: &Workspace, name: &str, passphrase_file: Option<&Path>) -> Result<()> {
+    let store = WalletStore::open(workspace);
+    store.path(name)?;
+    let passphrase = read_passphrase("New passphrase: ", passphrase_file, true)?;
+    let wallet = Wallet::random();
+    let path = store.save(name, &wallet.encrypt(&passphrase)?)?;
+    println!("🔑 Created wallet '{}'", name);
+    println!("📍 Address: {}", checksum_address(&wallet.address()));
+    println!("📁 Keystore: {}", path.display());
+    Ok(())
+}
+
+/// Import a keystore v3 file, or a private key typed at a hidden prompt
+pub fn handle_wallet_import(workspace: &Workspace, name: &str, keystore: Option<&Path>, passphrase_file: Option<&Path>) -> Result<()> {
+    let store = WalletStore::open(workspace);
+    store.path(name)?;
+    let (wallet, encrypted) = match keystore {
+        Some(file) => {
+            let content = std::fs::read_to_string(file).map_err(ProverError::Io)?;
+            let keystore = Keystore::from_json(&content)?;
+            let passphrase = read_passphrase("Keystore passphrase: ", passphrase_file, false)?;
+            let wallet = keystore.decrypt(&passphrase)?;
+            // Keep the original encryption; fill in the address if the file lacks one
+            let keystore = Keystore {
+                address: Some(hex::encode(wallet.address())),
+                ..keystore
+            };
+            (wallet, keystore)
+        }
+        None => {
+            let key = Zeroizing::new(rpassword::prompt_password("Private key (hex): ").context("could not read private key")?);
+            let digits = key.trim().trim_start_matches("0x");
+            let secret = Zeroizing::new(
+                hex::decode(digits).map_err(|_| ProverError::InvalidInput("private key is not hex".to_string()))?,
+            );
+            let wallet = Wallet::from_private_key(&secret)?;
+            let passphrase = read_passphrase("New passphrase: ", passphrase_file, true)?;
+            let encrypted = wallet.encrypt(&passphrase)?;
+            (wallet, encrypted)
+        }
+    };
+    let path = store.save(name, &encrypted)?;
+    println!("📥 Imported wallet '{}'", name);
+    println!("📍 Address: {}", checksum_address(&wallet.address()));
+    println!("📁 Keystore: {}", path.display());
+    Ok(())
+}
+
+pub fn handle_wallet_list(workspace: &Workspace) -> Result<()> {
+    let wallets = WalletStore::open(workspace).list()?;
+    if wallets.is_empty() {
+        println!("No wallets. Create one with `wallet new` or `wallet import`.");
+        return Ok(());
+    }
+    println!("🔑 Wallets ({})", workspace.keystore_dir().display());
+    for (name, address) in wallets {
+        let address = address.map(|a| checksum_address(&a)).unwrap_or_else(|| "(address not recorded)".to_string());
+        let marker = if name == DEFAULT_WALLET { " (default)" } else { "" };
+        println!("  {:<16} {}{}", name, address, marker);
+    }
+    Ok(())
+}
+
+pub fn handle_wallet_address(workspace: &Workspace, name: &str) -> Result<()> {
+    let keystore = WalletStore::open(workspace).load(name)?;
+    match keystore.address() {
+        Some(address) => println!("{}", checksum_address(&address)),
+        None => bail!("wallet {:?} does not record its address; re-import it with `wallet import --keystore`", name),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_workspace::TempWorkspace;
+    use crate::tx::Fees;
+
+    /// The sender key of the EIP-155 example
+    const EIP155_KEY: [u8; 32] = [0x46; 32];
+
+    fn invalid_input(err: &anyhow::Error) -> bool {
+        matches!(err.downcast_ref::<ProverError>(), Some(ProverError::InvalidInput(_)))
+    }
+
+    #[test]
+    fn test_address_and_checksum() {
+        let wallet = Wallet::from_private_key(&EIP155_KEY).unwrap();
+        assert_eq!(checksum_address(&wallet.address()), "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F");
+        assert!(Wallet::from_private_key(&[0u8; 32]).is_err());
+        assert!(Wallet::from_private_key(&[1u8; 31]).is_err());
+    }
+
+    #[test]
+    fn test_signs_eip155_example() {
+        let wallet = Wallet::from_private_key(&EIP155_KEY).unwrap();
+        let tx = Transaction {
+            chain_id: 1,
+            nonce: 9,
+            gas_limit: 21_000,
+            to: Some([0x35; 20]),
+            value: 1_000_000_000_000_000_000,
+            data: Vec::new(),
+            fees: Fees::Legacy { gas_price: 20_000_000_000 },
+        };
+        let raw = wallet.sign_transaction(&tx).unwrap();
+        assert_eq!(
+            hex::encode(&raw),
+            "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83"
+        );
+    }
+
+    #[test]
+    fn test_eip155_v_uses_chain_id() {
+        let wallet = Wallet::from_private_key(&EIP155_KEY).unwrap();
+        let tx = Transaction {
+            chain_id: 5003,
+            nonce: 0,
+            gas_limit: 21_000,
+            to: Some([0x35; 20]),
+            value: 0,
+            data: Vec::new(),
+            fees: Fees::Legacy { gas_price: 1 },
+        };
+        let signature = wallet.sign_hash(&tx.signing_hash()).unwrap();
+        let raw = tx.encode_signed(&signature);
+        // v = 5003 * 2 + 35 + parity = 10041 or 10042, RLP 0x82 0x27 0x39/0x3a
+        let v = 10_041 + u16::from(signature.y_parity);
+        let v_at = raw.len() - 2 * 33 - 3;
+        assert_eq!(raw[v_at..v_at + 3], [0x82, (v >> 8) as u8, v as u8]);
+    }
+
+    #[test]
+    fn test_eip1559_signature_recovers_sender() {
+        let wallet = Wallet::random();
+        let tx = Transaction {
+            chain_id: 5003,
+            nonce: 3,
+            gas_limit: 120_000,
+            to: Some([0x79; 20]),
+            value: 0,
+            data: vec![0x90, 0xaa, 0x26, 0x90],
+            fees: Fees::Eip1559 {
+                max_fee_per_gas: 30_000_000_000,
+                max_priority_fee_per_gas: 1_000_000_000,
+            },
+        };
+        let raw = wallet.sign_transaction(&tx).unwrap();
+        assert_eq!(raw[0], 0x02);
+
+        let signature = wallet.sign_hash(&tx.signing_hash()).unwrap();
+        assert_eq!(recover_signer(&tx.signing_hash(), &signature).unwrap(), wallet.address());
+        // Signing is deterministic (RFC 6979), so the raw bytes carry this signature
+        assert_eq!(raw, tx.encode_signed(&signature));
+
+        // Another chain id gives another signing hash
+        let other_chain = Transaction { chain_id: 5000, ..tx.clone() };
+        assert_ne!(other_chain.signing_hash(), tx.signing_hash());
+    }
+
+    #[test]
+    fn test_keystore_round_trip() {
+        let wallet = Wallet::random();
+        // Cheap scrypt so the test runs quickly in debug builds
+        let keystore = wallet.encrypt_with("correct horse", 4, 8, 1).unwrap();
+        assert_eq!(keystore.address(), Some(wallet.address()));
+        assert_eq!(keystore.crypto.kdfparams["n"], 16);
+
+        let json = serde_json::to_string(&keystore).unwrap();
+        let unlocked = Keystore::from_json(&json).unwrap().decrypt("correct horse").unwrap();
+        assert_eq!(unlocked.address(), wallet.address());
+
+        let err = keystore.decrypt("wrong horse").unwrap_err();
+        assert!(invalid_input(&err), "{}", err);
+    }
+
+    /// Private key of the Web3 Secret Storage test vectors (passphrase "testpassword")
+    const SPEC_KEY: &str = "7a28b5ba57c53603b0b07b56bba752f7784bf506fa95edc395f5cf6c7514fe9d";
+
+    #[test]
+    fn test_decrypts_spec_pbkdf2_vector() {
+        let keystore = Keystore::from_json(
+            r#"{
+                "crypto": {
+                    "cipher": "aes-128-ctr",
+                    "cipherparams": { "iv": "6087dab2f9fdbbfaddc31a909735c1e6" },
+                    "ciphertext": "5318b4d5bcd28de64ee5559e671353e16f075ecae9f99c7a79a38af5f869aa46",
+                    "kdf": "pbkdf2",
+                    "kdfparams": { "c": 262144, "dklen": 32, "prf": "hmac-sha256", "salt": "ae3cd4e7013836a3df6bd7241b12db061dbe2c6785853cce422d148a624ce0bd" },
+                    "mac": "517ead924a9d0dc3124507e3393d175ce3ff7c1e96529c6c555ce9e51205e9b2"
+                },
+                "id": "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+                "version": 3
+            }"#,
+        )
+        .unwrap();
+        let wallet = keystore.decrypt("testpassword").unwrap();
+        assert_eq!(hex::encode(wallet.key.to_bytes()), SPEC_KEY);
+    }
+
+    #[test]
+    fn test_rejects_unsupported_keystores() {
+        let wallet = Wallet::random();
+        let mut keystore = wallet.encrypt_with("pw", 4, 8, 1).unwrap();
+        keystore.crypto.cipher = "aes-128-cbc".to_string();
+        assert!(keystore.decrypt("pw").is_err());
+
+        let json = serde_json::to_string(&Keystore { version: 1, ..keystore }).unwrap();
+        assert!(invalid_input(&Keystore::from_json(&json).unwrap_err()));
+    }
+
+    #[test]
+    fn test_store_saves_lists_and_refuses_overwrite() {
+        let workspace = TempWorkspace::new("wallet-store");
+        let store = WalletStore::open(&workspace);
+        let wallet = Wallet::random();
+        let keystore = wallet.encrypt_with("pw", 4, 8, 1).unwrap();
+
+        let path = store.save("default", &keystore).unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(std::fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+        }
+        assert!(invalid_input(&store.save("default", &keystore).unwrap_err()));
+        store.save("ops-2", &keystore).unwrap();
+
+        assert_eq!(
+            store.list().unwrap(),
+            vec![("default".to_string(), Some(wallet.address())), ("ops-2".to_string(), Some(wallet.address()))]
+        );
+        assert_eq!(store.load("default").unwrap().decrypt("pw").unwrap().address(), wallet.address());
+
+        assert!(invalid_input(&store.load("missing").unwrap_err()));
+        for bad in ["", "../escape", ".hidden", "a/b"] {
+            assert!(invalid_input(&store.path(bad).unwrap_err()), "{:?}", bad);
+        }
+        let _ = std::fs::remove_dir_all(store.dir);
+    }
+}